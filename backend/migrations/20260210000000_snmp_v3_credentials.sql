@@ -0,0 +1,20 @@
+-- SNMPv3 USM parameters for SNMP credentials
+-- V2c credentials keep using the community column; V3 credentials use the columns below
+
+ALTER TABLE snmp_credentials
+    ADD COLUMN username TEXT,
+    ADD COLUMN security_level TEXT NOT NULL DEFAULT 'NoAuthNoPriv',
+    ADD COLUMN auth_protocol TEXT NOT NULL DEFAULT 'Sha',
+    ADD COLUMN auth_passphrase TEXT,
+    ADD COLUMN priv_protocol TEXT NOT NULL DEFAULT 'Aes',
+    ADD COLUMN priv_passphrase TEXT;
+
+ALTER TABLE snmp_credentials ALTER COLUMN community SET DEFAULT '';
+
+COMMENT ON COLUMN snmp_credentials.version IS 'SNMP version: V2c or V3';
+COMMENT ON COLUMN snmp_credentials.username IS 'SNMPv3 USM username';
+COMMENT ON COLUMN snmp_credentials.security_level IS 'SNMPv3 security level: NoAuthNoPriv, AuthNoPriv, AuthPriv';
+COMMENT ON COLUMN snmp_credentials.auth_protocol IS 'SNMPv3 authentication protocol: Md5, Sha, Sha224, Sha256, Sha384, Sha512';
+COMMENT ON COLUMN snmp_credentials.auth_passphrase IS 'SNMPv3 authentication passphrase (encrypted)';
+COMMENT ON COLUMN snmp_credentials.priv_protocol IS 'SNMPv3 privacy protocol: Des, Aes, Aes192, Aes256';
+COMMENT ON COLUMN snmp_credentials.priv_passphrase IS 'SNMPv3 privacy passphrase (encrypted)';
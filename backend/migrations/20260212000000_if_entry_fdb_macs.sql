@@ -0,0 +1,6 @@
+-- Bridge forwarding database (FDB) MACs learned on each switch port
+-- Used to place end hosts that don't speak LLDP/CDP on their access port
+
+ALTER TABLE if_entries ADD COLUMN fdb_macs MACADDR[] NOT NULL DEFAULT '{}';
+
+COMMENT ON COLUMN if_entries.fdb_macs IS 'MAC addresses learned on this port from dot1dTpFdbTable/dot1qTpFdbTable';
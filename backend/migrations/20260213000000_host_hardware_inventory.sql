@@ -0,0 +1,6 @@
+-- Per-host hardware inventory from ENTITY-MIB (entPhysicalTable)
+-- Chassis, modules, power supplies and transceivers with serial/model/vendor
+
+ALTER TABLE hosts ADD COLUMN hardware_inventory JSONB NOT NULL DEFAULT '[]';
+
+COMMENT ON COLUMN hosts.hardware_inventory IS 'Hardware components from ENTITY-MIB: chassis, modules, PSUs, transceivers';
@@ -0,0 +1,19 @@
+-- Organization-scoped service definitions, evaluated alongside the compiled-in registry
+
+CREATE TABLE custom_service_definitions (
+    id UUID PRIMARY KEY,
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    name TEXT NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    category TEXT NOT NULL,
+    logo_url TEXT NOT NULL DEFAULT '',
+    pattern JSONB NOT NULL,
+    UNIQUE(organization_id, name)
+);
+
+CREATE INDEX idx_custom_service_definitions_org ON custom_service_definitions(organization_id);
+
+COMMENT ON TABLE custom_service_definitions IS 'User-defined service definitions scoped to organization, shipped to daemons with network discovery';
+COMMENT ON COLUMN custom_service_definitions.pattern IS 'Declarative match pattern tree (AnyOf/AllOf/Not/Port/Endpoint/Header/MacVendor/SubnetIsType)';
@@ -0,0 +1,22 @@
+-- Per-discovery change history for hosts
+
+CREATE TABLE host_changes (
+    id UUID PRIMARY KEY,
+    network_id UUID NOT NULL REFERENCES networks(id) ON DELETE CASCADE,
+    host_id UUID NOT NULL REFERENCES hosts(id) ON DELETE CASCADE,
+    session_id UUID,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    change_type TEXT NOT NULL,
+    subject TEXT NOT NULL DEFAULT '',
+    previous_value TEXT,
+    new_value TEXT
+);
+
+CREATE INDEX idx_host_changes_host ON host_changes(host_id, created_at);
+CREATE INDEX idx_host_changes_session ON host_changes(session_id);
+CREATE INDEX idx_host_changes_network ON host_changes(network_id, created_at);
+
+COMMENT ON TABLE host_changes IS 'Changes observed on hosts by discovery: appeared/disappeared, ports opened/closed, services added/removed, hostname/MAC changes';
+COMMENT ON COLUMN host_changes.session_id IS 'Discovery session that observed the change; not a foreign key, sessions are in-memory';
+COMMENT ON COLUMN host_changes.subject IS 'Port ("443/tcp"), service definition ID, or interface IP. Empty for host-level changes';
@@ -0,0 +1,33 @@
+-- Host liveness: first/last seen timestamps on hosts and interfaces, missed scan
+-- tracking, and a per-network policy for marking stale hosts offline and hiding them
+
+ALTER TABLE hosts ADD COLUMN first_seen TIMESTAMPTZ;
+ALTER TABLE hosts ADD COLUMN last_seen TIMESTAMPTZ;
+ALTER TABLE hosts ADD COLUMN missed_scans INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE hosts ADD COLUMN offline BOOLEAN NOT NULL DEFAULT false;
+
+ALTER TABLE interfaces ADD COLUMN first_seen TIMESTAMPTZ;
+ALTER TABLE interfaces ADD COLUMN last_seen TIMESTAMPTZ;
+
+ALTER TABLE networks ADD COLUMN host_liveness JSONB NOT NULL
+    DEFAULT '{"offline_after_missed_scans": 3, "hide_offline_after_days": null}';
+
+-- Discovered hosts were first seen when created. last_seen stays null until the
+-- next observation, so existing hosts aren't counted as missing before then.
+UPDATE hosts SET first_seen = created_at
+WHERE source->>'type' IN ('Discovery', 'DiscoveryWithMatch');
+
+UPDATE interfaces SET first_seen = interfaces.created_at
+FROM hosts h
+WHERE h.id = interfaces.host_id
+  AND h.source->>'type' IN ('Discovery', 'DiscoveryWithMatch');
+
+CREATE INDEX idx_hosts_network_last_seen ON hosts(network_id, last_seen);
+
+COMMENT ON COLUMN hosts.first_seen IS 'When discovery first observed the host';
+COMMENT ON COLUMN hosts.last_seen IS 'When discovery last observed the host';
+COMMENT ON COLUMN hosts.missed_scans IS 'Consecutive network scans of the host''s subnets that did not find it';
+COMMENT ON COLUMN hosts.offline IS 'Set when missed_scans reaches the network host_liveness threshold';
+COMMENT ON COLUMN interfaces.first_seen IS 'When discovery first observed the interface';
+COMMENT ON COLUMN interfaces.last_seen IS 'When discovery last observed the interface';
+COMMENT ON COLUMN networks.host_liveness IS 'Stale host policy: offline_after_missed_scans, hide_offline_after_days';
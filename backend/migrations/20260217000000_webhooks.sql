@@ -0,0 +1,42 @@
+-- Outbound webhooks: organization-scoped subscriptions to entity and discovery events,
+-- plus a delivery log used for retries and troubleshooting
+
+CREATE TABLE webhooks (
+    id UUID PRIMARY KEY,
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    name TEXT NOT NULL,
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    enabled BOOLEAN NOT NULL DEFAULT true,
+    event_filter JSONB NOT NULL DEFAULT '{}',
+    UNIQUE(organization_id, name)
+);
+
+CREATE INDEX idx_webhooks_org ON webhooks(organization_id);
+
+CREATE TABLE webhook_deliveries (
+    id UUID PRIMARY KEY,
+    webhook_id UUID NOT NULL REFERENCES webhooks(id) ON DELETE CASCADE,
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    event_id UUID NOT NULL,
+    event_type TEXT NOT NULL,
+    payload JSONB NOT NULL,
+    status TEXT NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ,
+    response_status INTEGER,
+    error TEXT,
+    delivered_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_webhook_deliveries_webhook ON webhook_deliveries(webhook_id, created_at DESC);
+CREATE INDEX idx_webhook_deliveries_due ON webhook_deliveries(status, next_attempt_at);
+
+COMMENT ON TABLE webhooks IS 'Organization-scoped outbound webhooks fed by the server event bus';
+COMMENT ON COLUMN webhooks.secret IS 'Shared secret used to sign payloads (HMAC-SHA256, X-Scanopy-Signature header)';
+COMMENT ON COLUMN webhooks.event_filter IS 'Selected entity types/operations, discovery phases and networks';
+COMMENT ON TABLE webhook_deliveries IS 'Webhook delivery attempts; pending rows are retried with backoff';
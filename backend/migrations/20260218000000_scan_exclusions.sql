@@ -0,0 +1,5 @@
+-- Network-wide do-not-scan list, merged into every network discovery's own exclusions
+
+ALTER TABLE networks ADD COLUMN scan_exclusions JSONB NOT NULL DEFAULT '[]';
+
+COMMENT ON COLUMN networks.scan_exclusions IS 'Targets no discovery on this network may probe: IPs, ranges, CIDRs, MAC OUIs';
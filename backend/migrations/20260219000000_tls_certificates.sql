@@ -0,0 +1,29 @@
+-- TLS certificates presented on host ports, captured by network discovery
+
+CREATE TABLE tls_certificates (
+    id UUID PRIMARY KEY,
+    network_id UUID NOT NULL REFERENCES networks(id) ON DELETE CASCADE,
+    host_id UUID NOT NULL REFERENCES hosts(id) ON DELETE CASCADE,
+    port_id UUID NOT NULL REFERENCES ports(id) ON DELETE CASCADE,
+    service_id UUID REFERENCES services(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    fingerprint_sha256 TEXT NOT NULL,
+    subject TEXT NOT NULL DEFAULT '',
+    subject_alt_names TEXT[] NOT NULL DEFAULT '{}',
+    issuer TEXT NOT NULL DEFAULT '',
+    serial_number TEXT NOT NULL DEFAULT '',
+    not_before TIMESTAMPTZ NOT NULL,
+    not_after TIMESTAMPTZ NOT NULL,
+    key_type TEXT NOT NULL DEFAULT '',
+    self_signed BOOLEAN NOT NULL DEFAULT FALSE,
+    chain JSONB NOT NULL DEFAULT '[]',
+    UNIQUE (port_id)
+);
+
+CREATE INDEX idx_tls_certificates_host ON tls_certificates(host_id);
+CREATE INDEX idx_tls_certificates_network_expiry ON tls_certificates(network_id, not_after);
+
+COMMENT ON TABLE tls_certificates IS 'Leaf certificate currently presented on each TLS port, replaced when the port presents a different one';
+COMMENT ON COLUMN tls_certificates.service_id IS 'Service bound to the port when the certificate was captured';
+COMMENT ON COLUMN tls_certificates.chain IS 'Certificates presented after the leaf (intermediates, sometimes the root), in order';
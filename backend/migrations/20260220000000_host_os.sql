@@ -0,0 +1,7 @@
+-- Operating system fingerprint inferred by network discovery
+
+ALTER TABLE hosts ADD COLUMN os JSONB;
+
+CREATE INDEX idx_hosts_os_family ON hosts((os->>'family'));
+
+COMMENT ON COLUMN hosts.os IS 'Inferred OS: family, version, confidence and the evidence it was derived from';
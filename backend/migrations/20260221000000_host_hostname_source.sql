@@ -0,0 +1,5 @@
+-- Which lookup (DNS, NetBIOS, SMB, LLMNR, mDNS, SNMP) produced a discovered host's hostname
+
+ALTER TABLE hosts ADD COLUMN hostname_source TEXT;
+
+COMMENT ON COLUMN hosts.hostname_source IS 'Name source that resolved hostname during discovery; null when set by hand';
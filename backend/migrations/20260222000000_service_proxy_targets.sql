@@ -0,0 +1,5 @@
+-- Backends that reverse proxies forward to, read from the proxy's API during discovery
+
+ALTER TABLE services ADD COLUMN proxy_targets JSONB NOT NULL DEFAULT '[]';
+
+COMMENT ON COLUMN services.proxy_targets IS 'Reverse proxy backends: route name and configured address';
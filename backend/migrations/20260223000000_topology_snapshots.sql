@@ -0,0 +1,35 @@
+-- Named, immutable copies of a topology's graph and the entities it was built from
+
+CREATE TABLE topology_snapshots (
+    id UUID PRIMARY KEY,
+    topology_id UUID NOT NULL REFERENCES topologies(id) ON DELETE CASCADE,
+    network_id UUID NOT NULL REFERENCES networks(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    name TEXT NOT NULL,
+    description TEXT,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    captured_at TIMESTAMPTZ NOT NULL,
+    options JSONB NOT NULL,
+    nodes JSONB NOT NULL DEFAULT '[]',
+    edges JSONB NOT NULL DEFAULT '[]',
+    hosts JSONB NOT NULL DEFAULT '[]',
+    interfaces JSONB NOT NULL DEFAULT '[]',
+    subnets JSONB NOT NULL DEFAULT '[]',
+    groups JSONB NOT NULL DEFAULT '[]',
+    services JSONB NOT NULL DEFAULT '[]',
+    bindings JSONB NOT NULL DEFAULT '[]',
+    ports JSONB NOT NULL DEFAULT '[]',
+    if_entries JSONB NOT NULL DEFAULT '[]',
+    entity_tags JSONB NOT NULL DEFAULT '[]'
+);
+
+CREATE INDEX idx_topology_snapshots_topology ON topology_snapshots(topology_id, created_at);
+CREATE INDEX idx_topology_snapshots_network ON topology_snapshots(network_id);
+
+COMMENT ON TABLE topology_snapshots IS 'Saved copies of a topology at a point in time, compared against each other or the live topology';
+COMMENT ON COLUMN topology_snapshots.captured_at IS 'When the captured graph was last built (the topology''s last_refreshed at capture time)';
+
+ALTER TABLE shares ADD COLUMN compare_snapshot_id UUID REFERENCES topology_snapshots(id) ON DELETE SET NULL;
+
+COMMENT ON COLUMN shares.compare_snapshot_id IS 'Snapshot the shared topology is compared against, highlighting changes since it';
@@ -0,0 +1,37 @@
+-- Org-scoped audit log of authentication events and entity changes, recorded from the event bus
+
+CREATE TABLE audit_log_entries (
+    id UUID PRIMARY KEY,
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    network_id UUID,
+    event_id UUID NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    category TEXT NOT NULL,
+    operation TEXT NOT NULL,
+    entity_type TEXT,
+    entity_id UUID,
+    entity_name TEXT,
+    actor_type TEXT NOT NULL,
+    actor_id UUID,
+    user_id UUID,
+    actor_email TEXT,
+    ip_address INET,
+    user_agent TEXT,
+    metadata JSONB NOT NULL DEFAULT '{}'
+);
+
+CREATE INDEX idx_audit_log_entries_org ON audit_log_entries(organization_id, created_at);
+CREATE INDEX idx_audit_log_entries_entity ON audit_log_entries(entity_id, created_at);
+CREATE INDEX idx_audit_log_entries_user ON audit_log_entries(user_id, created_at);
+
+ALTER TABLE organizations ADD COLUMN audit_log_retention_days INTEGER NOT NULL DEFAULT 365;
+
+COMMENT ON TABLE audit_log_entries IS 'Who did what, when and from where: logins, key rotations, OIDC links and entity creates/updates/deletes';
+COMMENT ON COLUMN audit_log_entries.network_id IS 'Network of the changed entity; not a foreign key, entries outlive deleted networks';
+COMMENT ON COLUMN audit_log_entries.entity_id IS 'Changed entity; not a foreign key, entries outlive deleted entities';
+COMMENT ON COLUMN audit_log_entries.entity_name IS 'Name of the entity when the event was recorded';
+COMMENT ON COLUMN audit_log_entries.actor_id IS 'User, API key or daemon that acted';
+COMMENT ON COLUMN audit_log_entries.user_id IS 'User that acted, directly or through one of their API keys';
+COMMENT ON COLUMN audit_log_entries.ip_address IS 'Client IP of the request; null for background jobs';
+COMMENT ON COLUMN organizations.audit_log_retention_days IS 'Days audit log entries are kept before being pruned';
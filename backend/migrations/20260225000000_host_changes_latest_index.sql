@@ -0,0 +1,3 @@
+-- Latest change per (host, change type, subject), used when diffing reports and pruning history
+
+CREATE INDEX idx_host_changes_subject ON host_changes(host_id, change_type, subject, created_at);
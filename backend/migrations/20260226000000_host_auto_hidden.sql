@@ -0,0 +1,6 @@
+-- Distinguish hosts hidden by the stale host policy from hosts hidden by hand, so a
+-- new sighting can un-hide the former
+
+ALTER TABLE hosts ADD COLUMN auto_hidden BOOLEAN NOT NULL DEFAULT false;
+
+COMMENT ON COLUMN hosts.auto_hidden IS 'Hidden for staying offline past the network hide_offline_after_days; cleared when seen again';
@@ -0,0 +1,43 @@
+-- Organization-scoped scan profiles, referenced by id from network discoveries
+
+CREATE TABLE custom_scan_profiles (
+    id UUID PRIMARY KEY,
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    name TEXT NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    settings JSONB NOT NULL,
+    UNIQUE(organization_id, name)
+);
+
+CREATE INDEX idx_custom_scan_profiles_org ON custom_scan_profiles(organization_id);
+
+COMMENT ON TABLE custom_scan_profiles IS 'User-defined scan settings scoped to organization, resolved into network discoveries when a session starts';
+COMMENT ON COLUMN custom_scan_profiles.settings IS 'Port selections, endpoint probing, per-host timeout and rate overrides (ScanProfileSettings)';
+
+-- Move settings stored inline on discoveries into profiles. Each profile reuses its
+-- discovery's id so the reference can be rewritten in place.
+INSERT INTO custom_scan_profiles (id, organization_id, name, description, settings)
+SELECT
+    d.id,
+    n.organization_id,
+    LEFT(d.name, 31) || ' ' || LEFT(d.id::text, 8),
+    'Created from discovery ' || LEFT(d.name, 77),
+    d.discovery_type->'scan_profile'->'settings'
+FROM discovery d
+JOIN networks n ON n.id = d.network_id
+WHERE d.discovery_type->>'type' = 'Network'
+  AND d.discovery_type->'scan_profile'->>'type' = 'Custom'
+  AND d.discovery_type->'scan_profile'->'profile_id' IS NULL
+  AND d.discovery_type->'scan_profile'->'settings' IS NOT NULL
+  AND d.run_type->>'type' <> 'Historical';
+
+UPDATE discovery d
+SET discovery_type = jsonb_set(
+    d.discovery_type,
+    '{scan_profile}',
+    jsonb_build_object('type', 'Custom', 'profile_id', d.id)
+)
+FROM custom_scan_profiles p
+WHERE p.id = d.id;
@@ -129,6 +129,57 @@ async fn main() -> anyhow::Result<()> {
         }
     });
 
+    // Create stale host cleanup task (hides hosts offline past their network's retention)
+    let stale_host_service = state.services.host_service.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(60 * 60)); // Hourly
+        loop {
+            interval.tick().await;
+            stale_host_service.hide_stale_hosts().await;
+        }
+    });
+
+    // Create audit log retention task (prunes entries past each organization's retention)
+    let audit_log_service = state.services.audit_log_service.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(60 * 60)); // Hourly
+        loop {
+            interval.tick().await;
+            audit_log_service.prune_expired().await;
+        }
+    });
+
+    // Create host change retention task (prunes superseded changes past retention)
+    let host_change_service = state.services.host_change_service.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(60 * 60)); // Hourly
+        loop {
+            interval.tick().await;
+            host_change_service.prune_history().await;
+        }
+    });
+
+    // Create certificate expiry task (records certificates entering the warning window,
+    // including on hosts that aren't rescanned)
+    let certificate_expiry_service = state.services.host_change_service.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(60 * 60)); // Hourly
+        loop {
+            interval.tick().await;
+            certificate_expiry_service.check_certificate_expiry().await;
+        }
+    });
+
+    // Create webhook delivery retry task (also prunes the delivery log)
+    let webhook_retry_service = state.services.webhook_service.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(30)); // Every 30 seconds
+        loop {
+            interval.tick().await;
+            webhook_retry_service.retry_due_deliveries().await;
+        }
+    });
+
     // Start daemon polling loop for ServerPoll mode daemons
     let daemon_service = state.services.daemon_service.clone();
     tokio::spawn(async move {
@@ -1,11 +1,20 @@
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use anyhow::Result;
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+    sync::Arc,
+    time::Duration,
+};
 use tokio::sync::RwLock;
 use tokio::time::Instant;
 use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
 use crate::{
-    daemon::runtime::state::BufferedEntities,
+    daemon::{
+        discovery::journal::{EntityJournal, JournalRecord},
+        runtime::state::BufferedEntities,
+    },
     server::{
         hosts::r#impl::{api::DiscoveryHostRequest, api::HostResponse, base::Host},
         subnets::r#impl::base::Subnet,
@@ -43,11 +52,17 @@ impl<T> BufferedEntity<T> {
 /// In both modes, discovery adds entities to this buffer. The flush mechanism differs:
 /// - **DaemonPoll**: Entities are immediately sent to server and marked as Created
 /// - **ServerPoll**: Server polls pending entities and responds with Created confirmations
+///
+/// A persistent buffer (see [`EntityBuffer::with_journal`]) also writes every change to an
+/// on-disk journal. Pending entities then survive daemon restarts and session boundaries,
+/// and are uploaded once the server can be reached again.
 pub struct EntityBuffer {
     /// Subnets keyed by daemon-generated ID for lookup
     subnets: Arc<RwLock<HashMap<Uuid, BufferedEntity<Subnet>>>>,
     /// Hosts keyed by daemon-generated ID
     hosts: Arc<RwLock<HashMap<Uuid, BufferedEntity<DiscoveryHostRequest>>>>,
+    /// On-disk journal the buffer writes through to, if persistence is enabled
+    journal: Option<EntityJournal>,
 }
 
 impl EntityBuffer {
@@ -55,6 +70,61 @@ impl EntityBuffer {
         Self {
             subnets: Arc::new(RwLock::new(HashMap::new())),
             hosts: Arc::new(RwLock::new(HashMap::new())),
+            journal: None,
+        }
+    }
+
+    /// Create a buffer backed by the journal at `path`, replaying any pending entities
+    /// left over from a previous run.
+    pub async fn with_journal(path: impl AsRef<Path>) -> Result<Self> {
+        let (journal, records) = EntityJournal::open(path).await?;
+
+        // Replay without a journal attached so replayed records aren't written back
+        let mut buffer = Self::new();
+        for record in records {
+            match record {
+                JournalRecord::Subnet(subnet) => buffer.push_subnet(subnet).await,
+                JournalRecord::Host(host) => buffer.push_host(*host).await,
+                JournalRecord::SubnetCreated { pending_id, actual } => {
+                    buffer.mark_subnet_created(pending_id, actual).await;
+                }
+                JournalRecord::HostCreated { pending_id } => {
+                    buffer.hosts.write().await.remove(&pending_id);
+                }
+            }
+        }
+
+        buffer.journal = Some(journal);
+        buffer.clear_all().await;
+
+        let (hosts, subnets) = buffer.pending_count().await;
+        if hosts > 0 || subnets > 0 {
+            tracing::info!(
+                hosts = hosts,
+                subnets = subnets,
+                "Restored pending entities from entity journal"
+            );
+        }
+
+        Ok(buffer)
+    }
+
+    /// Whether pending entities are persisted to disk and kept across sessions.
+    pub fn is_persistent(&self) -> bool {
+        self.journal.is_some()
+    }
+
+    /// Append a record to the journal, if there is one. Failures are logged rather than
+    /// surfaced so a full or read-only disk doesn't stop discovery.
+    async fn record(&self, record: JournalRecord) {
+        if let Some(journal) = &self.journal
+            && let Err(e) = journal.append(&record).await
+        {
+            tracing::warn!(
+                path = %journal.path().display(),
+                error = %e,
+                "Failed to write entity journal"
+            );
         }
     }
 
@@ -65,28 +135,48 @@ impl EntityBuffer {
     /// Add a discovered subnet (pending state).
     pub async fn push_subnet(&self, subnet: Subnet) {
         let mut subnets = self.subnets.write().await;
+        self.record(JournalRecord::Subnet(subnet.clone())).await;
         subnets.insert(subnet.id, BufferedEntity::Pending(subnet));
     }
 
     /// Mark subnet as created with actual server data.
     /// Returns the ID mapping if it changed (pending_id, actual_id).
+    ///
+    /// Pending hosts with interfaces on the subnet are pointed at the actual ID, so hosts
+    /// discovered while the server was unreachable are uploaded with the deduplicated subnet.
     pub async fn mark_subnet_created(
         &self,
         pending_id: Uuid,
         actual: Subnet,
     ) -> Option<(Uuid, Uuid)> {
-        let mut subnets = self.subnets.write().await;
-        if let Some(entry) = subnets.get_mut(&pending_id) {
-            let id_changed = pending_id != actual.id;
-            *entry = BufferedEntity::Created {
+        let actual_id = actual.id;
+        {
+            let mut subnets = self.subnets.write().await;
+            let entry = subnets.get_mut(&pending_id)?;
+            self.record(JournalRecord::SubnetCreated {
                 pending_id,
                 actual: actual.clone(),
-            };
-            if id_changed {
-                return Some((pending_id, actual.id));
+            })
+            .await;
+            *entry = BufferedEntity::Created { pending_id, actual };
+        }
+
+        if pending_id == actual_id {
+            return None;
+        }
+
+        let mut hosts = self.hosts.write().await;
+        for entry in hosts.values_mut() {
+            if let BufferedEntity::Pending(host) = entry {
+                for interface in &mut host.interfaces {
+                    if interface.base.subnet_id == pending_id {
+                        interface.base.subnet_id = actual_id;
+                    }
+                }
             }
         }
-        None
+
+        Some((pending_id, actual_id))
     }
 
     /// Get a subnet by its pending (daemon-generated) ID.
@@ -136,6 +226,8 @@ impl EntityBuffer {
     /// This is critical for Docker discovery where all containers share the daemon's host_id.
     pub async fn push_host(&self, host: DiscoveryHostRequest) {
         let mut hosts = self.hosts.write().await;
+        self.record(JournalRecord::Host(Box::new(host.clone())))
+            .await;
 
         match hosts.get_mut(&host.host.id) {
             Some(BufferedEntity::Pending(existing)) => {
@@ -144,6 +236,7 @@ impl EntityBuffer {
                 existing.ports.extend(host.ports);
                 existing.services.extend(host.services);
                 existing.if_entries.extend(host.if_entries);
+                existing.certificates.extend(host.certificates);
             }
             Some(BufferedEntity::Created { .. }) | None => {
                 // No existing pending entry - insert new one
@@ -160,12 +253,15 @@ impl EntityBuffer {
             // Update the host in the request with the actual server data
             // Convert HostResponse to Host using the to_host() method
             if let BufferedEntity::Pending(_) = entry {
+                self.record(JournalRecord::HostCreated { pending_id }).await;
                 let updated_req = DiscoveryHostRequest {
                     host: actual.to_host(),
                     interfaces: actual.interfaces,
                     ports: actual.ports,
                     services: actual.services,
                     if_entries: actual.if_entries,
+                    // Already stored by the server, only needed on the request that reported them
+                    certificates: Vec::new(),
                 };
                 *entry = BufferedEntity::Created {
                     pending_id,
@@ -222,22 +318,35 @@ impl EntityBuffer {
     /// 2. Server processes entities → sends confirmation back
     /// 3. Daemon receives confirmation → mark_*_created() updates state
     /// 4. Session ends → clear_all() removes all entries
+    ///
+    /// A persistent buffer holds back hosts with interfaces on a still-pending subnet until
+    /// the subnet is confirmed, so they're never sent with a subnet ID the server doesn't know.
     pub async fn get_pending(&self) -> BufferedEntities {
-        let hosts = {
-            let hosts = self.hosts.read().await;
-            hosts
+        let (subnets, pending_subnet_ids): (Vec<Subnet>, HashSet<Uuid>) = {
+            let subnets = self.subnets.read().await;
+            let pending: Vec<Subnet> = subnets
                 .values()
                 .filter(|e| e.is_pending())
                 .map(|e| e.get_data().clone())
-                .collect()
+                .collect();
+            let ids = pending.iter().map(|s| s.id).collect();
+            (pending, ids)
         };
 
-        let subnets = {
-            let subnets = self.subnets.read().await;
-            subnets
+        let hosts = {
+            let hosts = self.hosts.read().await;
+            hosts
                 .values()
                 .filter(|e| e.is_pending())
-                .map(|e| e.get_data().clone())
+                .map(|e| e.get_data())
+                .filter(|h| {
+                    !self.is_persistent()
+                        || !h
+                            .interfaces
+                            .iter()
+                            .any(|i| pending_subnet_ids.contains(&i.base.subnet_id))
+                })
+                .cloned()
                 .collect()
         };
 
@@ -250,14 +359,37 @@ impl EntityBuffer {
     /// This is the cleanup step at the end of discovery sessions:
     /// - Created entries: confirmed by server, no longer needed
     /// - Pending entries: timed out or never confirmed, stale
+    ///
+    /// A persistent buffer keeps pending entries for a later upload and compacts its
+    /// journal down to them.
     pub async fn clear_all(&self) {
-        {
-            let mut hosts = self.hosts.write().await;
-            hosts.clear();
-        }
-        {
-            let mut subnets = self.subnets.write().await;
-            subnets.clear();
+        let Some(journal) = &self.journal else {
+            self.hosts.write().await.clear();
+            self.subnets.write().await.clear();
+            return;
+        };
+
+        let mut hosts = self.hosts.write().await;
+        let mut subnets = self.subnets.write().await;
+        hosts.retain(|_, e| e.is_pending());
+        subnets.retain(|_, e| e.is_pending());
+
+        let records: Vec<JournalRecord> = subnets
+            .values()
+            .map(|e| JournalRecord::Subnet(e.get_data().clone()))
+            .chain(
+                hosts
+                    .values()
+                    .map(|e| JournalRecord::Host(Box::new(e.get_data().clone()))),
+            )
+            .collect();
+
+        if let Err(e) = journal.compact(&records).await {
+            tracing::warn!(
+                path = %journal.path().display(),
+                error = %e,
+                "Failed to compact entity journal"
+            );
         }
     }
 
@@ -321,11 +453,20 @@ mod tests {
                 management_url: None,
                 chassis_id: None,
                 snmp_credential_id: None,
+                hardware_inventory: Vec::new(),
+                first_seen: None,
+                last_seen: None,
+                missed_scans: 0,
+                offline: false,
+                auto_hidden: false,
+                os: None,
+                hostname_source: None,
             }),
             interfaces: vec![],
             ports: vec![],
             services: vec![],
             if_entries: vec![],
+            certificates: vec![],
         };
         buffer.push_host(host).await;
 
@@ -368,11 +509,20 @@ mod tests {
                             management_url: None,
                             chassis_id: None,
                             snmp_credential_id: None,
+                            hardware_inventory: Vec::new(),
+                            first_seen: None,
+                            last_seen: None,
+                            missed_scans: 0,
+                            offline: false,
+                            auto_hidden: false,
+                            os: None,
+                            hostname_source: None,
                         }),
                         interfaces: vec![],
                         ports: vec![],
                         services: vec![],
                         if_entries: vec![],
+                        certificates: vec![],
                     };
                     buf.push_host(host).await;
                 })
@@ -666,6 +816,14 @@ mod tests {
                 management_url: None,
                 chassis_id: None,
                 snmp_credential_id: None,
+                hardware_inventory: Vec::new(),
+                first_seen: None,
+                last_seen: None,
+                missed_scans: 0,
+                offline: false,
+                auto_hidden: false,
+                os: None,
+                hostname_source: None,
             }),
             interfaces: vec![
                 Interface {
@@ -680,6 +838,8 @@ mod tests {
                         mac_address: None,
                         name: Some("eth0".to_string()),
                         position: 0,
+                        first_seen: None,
+                        last_seen: None,
                     },
                 },
                 Interface {
@@ -694,6 +854,8 @@ mod tests {
                         mac_address: None,
                         name: Some("eth1".to_string()),
                         position: 1,
+                        first_seen: None,
+                        last_seen: None,
                     },
                 },
             ],
@@ -710,6 +872,7 @@ mod tests {
                 },
             }],
             if_entries: vec![],
+            certificates: vec![],
         };
         // Set the host ID to match our shared host_id
         let mut host1 = host1;
@@ -734,6 +897,14 @@ mod tests {
                 management_url: None,
                 chassis_id: None,
                 snmp_credential_id: None,
+                hardware_inventory: Vec::new(),
+                first_seen: None,
+                last_seen: None,
+                missed_scans: 0,
+                offline: false,
+                auto_hidden: false,
+                os: None,
+                hostname_source: None,
             }),
             interfaces: vec![Interface {
                 id: Uuid::new_v4(),
@@ -747,6 +918,8 @@ mod tests {
                     mac_address: None,
                     name: Some("eth2".to_string()),
                     position: 2,
+                    first_seen: None,
+                    last_seen: None,
                 },
             }],
             ports: vec![],
@@ -762,6 +935,7 @@ mod tests {
                 },
             }],
             if_entries: vec![],
+            certificates: vec![],
         };
         let mut host2 = host2;
         host2.host.id = host_id;
@@ -795,4 +969,144 @@ mod tests {
         assert!(service_names.contains(&"container-1".to_string()));
         assert!(service_names.contains(&"container-2".to_string()));
     }
+
+    #[tokio::test]
+    async fn test_persistent_buffer_remaps_and_survives_restart() {
+        use crate::server::interfaces::r#impl::base::{Interface, InterfaceBase};
+        use crate::server::subnets::r#impl::{base::SubnetBase, types::SubnetType};
+        use chrono::Utc;
+        use cidr::{IpCidr, Ipv4Cidr};
+        use std::net::{IpAddr, Ipv4Addr};
+
+        let path = std::env::temp_dir().join(format!("entity-buffer-{}.jsonl", Uuid::new_v4()));
+        let network_id = Uuid::new_v4();
+        let now = Utc::now();
+
+        let subnet = |third_octet: u8| Subnet {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base: SubnetBase {
+                name: format!("subnet-{}", third_octet),
+                cidr: IpCidr::V4(
+                    Ipv4Cidr::new(Ipv4Addr::new(192, 168, third_octet, 0), 24).unwrap(),
+                ),
+                network_id,
+                description: None,
+                subnet_type: SubnetType::Unknown,
+                source: EntitySource::Manual,
+                tags: vec![],
+            },
+        };
+
+        let pending_subnet = subnet(1);
+        let host = Host::new(HostBase {
+            name: "offline-host".to_string(),
+            hostname: None,
+            tags: vec![],
+            network_id,
+            description: None,
+            source: EntitySource::Manual,
+            virtualization: None,
+            hidden: false,
+            sys_descr: None,
+            sys_object_id: None,
+            sys_location: None,
+            sys_contact: None,
+            management_url: None,
+            chassis_id: None,
+            snmp_credential_id: None,
+            hardware_inventory: Vec::new(),
+            first_seen: None,
+            last_seen: None,
+            missed_scans: 0,
+            offline: false,
+            auto_hidden: false,
+            os: None,
+            hostname_source: None,
+        });
+        let host_id = host.id;
+        let request = DiscoveryHostRequest {
+            host,
+            interfaces: vec![Interface {
+                id: Uuid::new_v4(),
+                created_at: now,
+                updated_at: now,
+                base: InterfaceBase {
+                    network_id,
+                    host_id,
+                    subnet_id: pending_subnet.id,
+                    ip_address: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10)),
+                    mac_address: None,
+                    name: Some("eth0".to_string()),
+                    position: 0,
+                    first_seen: None,
+                    last_seen: None,
+                },
+            }],
+            ports: vec![],
+            services: vec![],
+            if_entries: vec![],
+            certificates: vec![],
+        };
+
+        let buffer = EntityBuffer::with_journal(&path).await.unwrap();
+        assert!(buffer.is_persistent());
+        buffer.push_subnet(pending_subnet.clone()).await;
+        buffer.push_host(request).await;
+
+        // Host is held back while its subnet is still pending
+        let pending = buffer.get_pending().await;
+        assert_eq!(pending.subnets.len(), 1);
+        assert!(pending.hosts.is_empty());
+
+        // Server deduplicates the subnet to an existing one - host is remapped and released
+        let existing_subnet = Subnet {
+            id: Uuid::new_v4(),
+            ..pending_subnet.clone()
+        };
+        buffer
+            .mark_subnet_created(pending_subnet.id, existing_subnet.clone())
+            .await;
+        let pending = buffer.get_pending().await;
+        assert_eq!(pending.hosts.len(), 1);
+        assert_eq!(
+            pending.hosts[0].interfaces[0].base.subnet_id,
+            existing_subnet.id
+        );
+
+        // Session ends with another subnet still unconfirmed
+        let unconfirmed_subnet = subnet(2);
+        buffer.push_subnet(unconfirmed_subnet.clone()).await;
+        buffer.clear_all().await;
+        assert_eq!(buffer.count().await, (1, 1));
+        drop(buffer);
+
+        // Restart: only pending entities are restored, with the remapped subnet ID
+        let restored = EntityBuffer::with_journal(&path).await.unwrap();
+        let pending = restored.get_pending().await;
+        assert_eq!(pending.subnets.len(), 1);
+        assert_eq!(pending.subnets[0].id, unconfirmed_subnet.id);
+        assert_eq!(pending.hosts.len(), 1);
+        assert_eq!(
+            pending.hosts[0].interfaces[0].base.subnet_id,
+            existing_subnet.id
+        );
+
+        // Confirmed host is not restored after another restart
+        let response = HostResponse::from_host_with_children(
+            pending.hosts[0].host.clone(),
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+        );
+        restored.mark_host_created(host_id, response).await;
+        drop(restored);
+
+        let restored = EntityBuffer::with_journal(&path).await.unwrap();
+        assert_eq!(restored.pending_count().await, (0, 1));
+
+        let _ = std::fs::remove_file(&path);
+    }
 }
@@ -0,0 +1,131 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tokio::{
+    fs::{File, OpenOptions},
+    io::AsyncWriteExt,
+    sync::Mutex,
+};
+use uuid::Uuid;
+
+use crate::server::{hosts::r#impl::api::DiscoveryHostRequest, subnets::r#impl::base::Subnet};
+
+/// One change to the entity buffer, stored as a line of JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", content = "data", rename_all = "snake_case")]
+pub enum JournalRecord {
+    /// Subnet discovered (pending)
+    Subnet(Subnet),
+    /// Host discovered (pending). Replayed with the same merge semantics as `push_host`.
+    Host(Box<DiscoveryHostRequest>),
+    /// Subnet confirmed by server, possibly under a different ID
+    SubnetCreated { pending_id: Uuid, actual: Subnet },
+    /// Host confirmed by server
+    HostCreated { pending_id: Uuid },
+}
+
+/// Append-only, on-disk journal backing the entity buffer.
+///
+/// Every buffer mutation is appended as a [`JournalRecord`] before the buffer is updated in
+/// memory, so a daemon restart can rebuild the pending entities by replaying the file.
+/// The journal is compacted (rewritten with only pending entities) at session boundaries.
+pub struct EntityJournal {
+    path: PathBuf,
+    file: Mutex<File>,
+}
+
+impl EntityJournal {
+    /// Open (or create) the journal at `path`, returning it with the records it already holds.
+    pub async fn open(path: impl AsRef<Path>) -> Result<(Self, Vec<JournalRecord>)> {
+        let path = path.as_ref().to_path_buf();
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .context("Failed to create entity journal directory")?;
+        }
+
+        let records = match tokio::fs::read_to_string(&path).await {
+            Ok(content) => Self::parse(&content),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e).context("Failed to read entity journal"),
+        };
+
+        let file = Self::open_append(&path).await?;
+
+        Ok((
+            Self {
+                path,
+                file: Mutex::new(file),
+            },
+            records,
+        ))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Parse journal content. A line that fails to parse (e.g. a write cut short by a crash)
+    /// is skipped rather than discarding the whole journal.
+    fn parse(content: &str) -> Vec<JournalRecord> {
+        content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| match serde_json::from_str(line) {
+                Ok(record) => Some(record),
+                Err(e) => {
+                    tracing::warn!(error = %e, "Skipping unreadable entity journal record");
+                    None
+                }
+            })
+            .collect()
+    }
+
+    async fn open_append(path: &Path) -> Result<File> {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .await
+            .context("Failed to open entity journal")
+    }
+
+    /// Append a record and flush it to disk.
+    pub async fn append(&self, record: &JournalRecord) -> Result<()> {
+        let mut line = serde_json::to_vec(record)?;
+        line.push(b'\n');
+
+        let mut file = self.file.lock().await;
+        file.write_all(&line).await?;
+        file.sync_data().await?;
+        Ok(())
+    }
+
+    /// Replace the journal with `records`. Written to a temp file and renamed so a crash
+    /// mid-compaction leaves either the old or the new journal intact.
+    pub async fn compact(&self, records: &[JournalRecord]) -> Result<()> {
+        let mut content = Vec::new();
+        for record in records {
+            serde_json::to_writer(&mut content, record)?;
+            content.push(b'\n');
+        }
+
+        let mut file = self.file.lock().await;
+
+        let temp_path = self.path.with_extension("tmp");
+        let mut temp = File::create(&temp_path)
+            .await
+            .context("Failed to create temp entity journal")?;
+        temp.write_all(&content).await?;
+        temp.sync_all().await?;
+        drop(temp);
+
+        tokio::fs::rename(&temp_path, &self.path)
+            .await
+            .context("Failed to replace entity journal")?;
+
+        *file = Self::open_append(&self.path).await?;
+        Ok(())
+    }
+}
@@ -7,7 +7,10 @@ use crate::daemon::discovery::service::base::{
     DaemonDiscoveryService, DiscoveryRunner, RunsDiscovery,
 };
 use crate::daemon::discovery::service::docker::DockerScanDiscovery;
+use crate::daemon::discovery::service::kubernetes::KubernetesDiscovery;
 use crate::daemon::discovery::service::network::NetworkScanDiscovery;
+use crate::daemon::discovery::service::passive::PassiveDiscovery;
+use crate::daemon::discovery::service::proxmox::ProxmoxDiscovery;
 use crate::daemon::discovery::service::self_report::SelfReportDiscovery;
 use crate::daemon::runtime::service::LOG_TARGET;
 use crate::server::daemons::r#impl::api::DaemonDiscoveryRequest;
@@ -74,11 +77,41 @@ impl DaemonDiscoverySessionManager {
                 request.clone(),
                 cancel_token,
             ),
+            DiscoveryType::Kubernetes {
+                kubeconfig_path,
+                context,
+                host_naming_fallback,
+            } => self.clone().spawn_discovery(
+                DiscoveryRunner::new(
+                    self.discovery_service.clone(),
+                    self.clone(),
+                    KubernetesDiscovery::new(
+                        kubeconfig_path.clone(),
+                        context.clone(),
+                        *host_naming_fallback,
+                    ),
+                ),
+                request.clone(),
+                cancel_token,
+            ),
+            DiscoveryType::Proxmox(connection) => self.clone().spawn_discovery(
+                DiscoveryRunner::new(
+                    self.discovery_service.clone(),
+                    self.clone(),
+                    ProxmoxDiscovery::new(connection.clone()),
+                ),
+                request.clone(),
+                cancel_token,
+            ),
             DiscoveryType::Network {
                 subnet_ids,
                 host_naming_fallback,
                 snmp_credentials,
+                custom_service_definitions,
                 probe_raw_socket_ports,
+                scan_profile,
+                exclusions,
+                hostname_sources,
             } => self.clone().spawn_discovery(
                 DiscoveryRunner::new(
                     self.discovery_service.clone(),
@@ -87,7 +120,28 @@ impl DaemonDiscoverySessionManager {
                         subnet_ids.clone(),
                         *host_naming_fallback,
                         snmp_credentials.clone(),
+                        custom_service_definitions.clone(),
                         *probe_raw_socket_ports,
+                        scan_profile.clone(),
+                        exclusions.clone(),
+                        hostname_sources.clone(),
+                    ),
+                ),
+                request.clone(),
+                cancel_token,
+            ),
+            DiscoveryType::Passive {
+                subnet_ids,
+                host_naming_fallback,
+                listen_duration_secs,
+            } => self.clone().spawn_discovery(
+                DiscoveryRunner::new(
+                    self.discovery_service.clone(),
+                    self.clone(),
+                    PassiveDiscovery::new(
+                        subnet_ids.clone(),
+                        *host_naming_fallback,
+                        *listen_duration_secs,
                     ),
                 ),
                 request.clone(),
@@ -143,6 +197,18 @@ impl DaemonDiscoverySessionManager {
         }
     }
 
+    /// Upload entities left in a persistent entity buffer, unless a session is running
+    /// (the session uploads its own entities and compacts the buffer when it ends).
+    pub async fn upload_buffered_entities(&self) {
+        if self.is_discovery_running().await {
+            return;
+        }
+
+        if let Err(e) = self.discovery_service.upload_buffered_entities().await {
+            tracing::warn!("Failed to upload buffered entities: {}", e);
+        }
+    }
+
     /// Set the current discovery task for cancellation
     pub async fn start_new_session(&self) -> CancellationToken {
         *self.cancellation_token.write().await = CancellationToken::new();
@@ -1,5 +1,6 @@
 pub mod buffer;
 pub mod handlers;
+pub mod journal;
 pub mod manager;
 pub mod service;
 pub mod types;
@@ -14,18 +14,24 @@ use crate::{
             types::base::DiscoveryCriticalError,
         },
         shared::api_client::DaemonApiClient,
+        utils::proxy,
     },
     server::{
-        discovery::r#impl::types::{DiscoveryType, HostNamingFallback},
+        discovery::r#impl::{
+            exclusions::{ExcludedTarget, ExcludedTargetSummary, ScanExclusion},
+            types::{DiscoveryType, HostNamingFallback},
+        },
         services::{
-            definitions::{docker_container::DockerContainer, open_ports::OpenPorts},
+            definitions::{
+                docker_container::DockerContainer, kubernetes_pod::KubernetesPod,
+                open_ports::OpenPorts,
+            },
             r#impl::{
                 base::{
                     DiscoverySessionServiceMatchParams, ServiceMatchBaselineParams,
                     ServiceMatchServiceParams,
                 },
                 patterns::MatchConfidence,
-                virtualization::{DockerVirtualization, ServiceVirtualization},
             },
         },
         shared::types::entities::{DiscoveryMetadata, EntitySource},
@@ -43,6 +49,7 @@ use uuid::Uuid;
 use crate::{
     daemon::{
         discovery::types::base::{DiscoveryPhase, DiscoverySessionInfo, DiscoverySessionUpdate},
+        runtime::state::BufferedEntities,
         shared::config::ConfigStore,
         utils::base::{PlatformDaemonUtils, create_system_utils},
     },
@@ -59,7 +66,7 @@ use crate::{
         interfaces::r#impl::base::Interface,
         ports::r#impl::base::{Port, PortType},
         services::{
-            definitions::{ServiceDefinitionRegistry, gateway::Gateway},
+            definitions::gateway::Gateway,
             r#impl::{
                 base::Service,
                 definitions::{ServiceDefinition, ServiceDefinitionExt},
@@ -67,6 +74,7 @@ use crate::{
         },
         shared::types::metadata::HasId,
         subnets::r#impl::base::Subnet,
+        tls_certificates::r#impl::base::TlsCertificate,
     },
 };
 
@@ -96,6 +104,8 @@ pub struct DiscoverySession {
     pub gateway_ips: Vec<IpAddr>,
     pub last_progress: Arc<AtomicU8>,
     pub last_progress_report_time: Arc<AtomicU64>,
+    /// Every target skipped because a scan exclusion covered it, grouped by exclusion
+    pub excluded_targets: Arc<std::sync::Mutex<Vec<ExcludedTargetSummary>>>,
 }
 
 impl DiscoverySession {
@@ -105,8 +115,29 @@ impl DiscoverySession {
             gateway_ips,
             last_progress: Arc::new(AtomicU8::new(0)),
             last_progress_report_time: Arc::new(AtomicU64::new(0)),
+            excluded_targets: Arc::new(std::sync::Mutex::new(Vec::new())),
         }
     }
+
+    pub fn record_excluded(&self, exclusion: &ScanExclusion, target: ExcludedTarget) {
+        let mut summaries = self.excluded_targets.lock().unwrap();
+        ExcludedTargetSummary::record(&mut summaries, exclusion, target);
+    }
+
+    /// Excluded targets for a progress update, with only a few example hosts per exclusion
+    pub fn excluded_targets(&self) -> Vec<ExcludedTargetSummary> {
+        self.excluded_targets
+            .lock()
+            .unwrap()
+            .iter()
+            .map(ExcludedTargetSummary::sampled)
+            .collect()
+    }
+
+    /// Every excluded target, for the terminal update that becomes the historical record
+    pub fn all_excluded_targets(&self) -> Vec<ExcludedTargetSummary> {
+        self.excluded_targets.lock().unwrap().clone()
+    }
 }
 
 impl<T> AsRef<DaemonDiscoveryService> for DiscoveryRunner<T> {
@@ -148,6 +179,60 @@ impl DaemonDiscoveryService {
             .cloned()
             .ok_or_else(|| anyhow!("No active discovery session"))
     }
+
+    /// Upload entities left pending in a persistent entity buffer (DaemonPoll only).
+    ///
+    /// Subnets go first so hosts are remapped to the server's subnet IDs before they're
+    /// sent. Stops at the first failure; the rest stay journaled for the next attempt.
+    pub async fn upload_buffered_entities(&self) -> Result<(), Error> {
+        if !self.entity_buffer.is_persistent() {
+            return Ok(());
+        }
+
+        let pending = self.entity_buffer.get_pending().await;
+        if pending.subnets.is_empty() && pending.hosts.is_empty() {
+            return Ok(());
+        }
+
+        tracing::info!(
+            subnets = pending.subnets.len(),
+            hosts = pending.hosts.len(),
+            "Uploading entities buffered while server was unreachable"
+        );
+
+        let result = self.upload_pending(pending).await;
+
+        // Drop confirmed entries and compact the journal either way
+        self.entity_buffer.clear_all().await;
+
+        result
+    }
+
+    async fn upload_pending(&self, pending: BufferedEntities) -> Result<(), Error> {
+        for subnet in pending.subnets {
+            let actual: Subnet = self
+                .api_client
+                .post("/api/v1/subnets", &subnet, "Failed to create subnet")
+                .await?;
+            self.entity_buffer
+                .mark_subnet_created(subnet.id, actual)
+                .await;
+        }
+
+        // Re-read hosts: subnet confirmations may have remapped or released some
+        for host in self.entity_buffer.get_pending().await.hosts {
+            let pending_id = host.host.id;
+            let response: HostResponse = self
+                .api_client
+                .post("/api/v1/hosts/discovery", &host, "Failed to create host")
+                .await?;
+            self.entity_buffer
+                .mark_host_created(pending_id, response)
+                .await;
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -210,10 +295,18 @@ pub trait RunsDiscovery: AsRef<DaemonDiscoveryService> + Send + Sync {
         let session = self.as_ref().get_session().await?;
         let discovery_type = self.discovery_type();
 
+        // The terminal update becomes the historical record, so it lists every skipped target
+        let excluded_targets = if update.phase.is_terminal() {
+            session.all_excluded_targets()
+        } else {
+            session.excluded_targets()
+        };
+
         let payload = DiscoveryUpdatePayload::from_state_and_update(
             discovery_type,
             session.info.clone(),
             update,
+            excluded_targets,
         );
 
         let path = format!("/api/v1/discovery/{}/update", session.info.session_id);
@@ -395,6 +488,7 @@ pub trait DiscoversNetworkedEntities:
             self.discovery_type(),
             session.info.clone(),
             terminal_update,
+            session.all_excluded_targets(),
         );
         let mut stored_terminal = self.as_ref().terminal_payload.write().await;
         *stored_terminal = Some(terminal_payload);
@@ -458,12 +552,20 @@ pub trait DiscoversNetworkedEntities:
             management_url: None,
             chassis_id: None,
             snmp_credential_id: None,
+            hardware_inventory: Vec::new(),
+            first_seen: None,
+            last_seen: None,
+            missed_scans: 0,
+            offline: false,
+            auto_hidden: false,
+            os: None,
+            hostname_source: None,
         });
 
         // Store interfaces separately to pass to server
         let interfaces = vec![interface.clone()];
 
-        let (services, ports) = self.discover_services(
+        let (mut services, ports) = self.discover_services(
             &host,
             &params,
             &gateway_ips,
@@ -472,6 +574,13 @@ pub trait DiscoversNetworkedEntities:
             &discovery_type,
         )?;
 
+        proxy::collect_proxy_targets(
+            &mut services,
+            params.endpoint_responses,
+            interface.base.ip_address,
+        )
+        .await;
+
         // Determine host's name
         let best_service_name = services
             .iter()
@@ -511,7 +620,11 @@ pub trait DiscoversNetworkedEntities:
         network_id: &Uuid,
         discovery_type: &DiscoveryType,
     ) -> Result<(Vec<Service>, Vec<Port>), Error> {
-        let ServiceMatchBaselineParams { all_ports, .. } = baseline_params;
+        let ServiceMatchBaselineParams {
+            service_definitions,
+            all_ports,
+            ..
+        } = baseline_params;
 
         let mut services = Vec::new();
         let mut host_ports = Vec::new();
@@ -522,9 +635,7 @@ pub trait DiscoversNetworkedEntities:
         let mut container_matched = false;
 
         let mut sorted_service_definitions: Vec<Box<dyn ServiceDefinition>> =
-            ServiceDefinitionRegistry::all_service_definitions()
-                .into_iter()
-                .collect();
+            service_definitions.to_vec();
 
         sorted_service_definitions.sort_by_key(|s| {
             if !ServiceDefinitionExt::is_generic(s) {
@@ -532,12 +643,15 @@ pub trait DiscoversNetworkedEntities:
             } else if s.id() == OpenPorts.id() {
                 // Catch-all for open ports, should be dead last
                 3
-            } else if s.id() == DockerContainer.id() || s.id() == Gateway.id() {
-                // Docker Containers and Gateways need to go second to last last
+            } else if s.id() == DockerContainer.id()
+                || s.id() == KubernetesPod.id()
+                || s.id() == Gateway.id()
+            {
+                // Docker Containers, Kubernetes Pods and Gateways need to go second to last last
                 // Other generic services should be able to get matched first
                 2
             } else {
-                // Generic services that aren't Docker Container or Gateway
+                // Generic services that aren't Docker Container, Kubernetes Pod or Gateway
                 1
             }
         });
@@ -565,10 +679,11 @@ pub trait DiscoversNetworkedEntities:
                 && !container_matched
             {
                 // If a container was matched w the provided virtualization, no others can be matched
-                if let Some(ServiceVirtualization::Docker(DockerVirtualization {
-                    container_id: Some(_),
-                    ..
-                })) = &service.base.virtualization
+                if service
+                    .base
+                    .virtualization
+                    .as_ref()
+                    .is_some_and(|v| v.workload_id().is_some())
                 {
                     container_matched = true
                 }
@@ -619,9 +734,13 @@ pub trait CreatesDiscoveredEntities:
     AsRef<DaemonDiscoveryService> + Send + Sync + RunsDiscovery
 {
     /// Create a host with its children (interfaces, ports, services).
-    /// Pass an empty `if_entries` vec if SNMP data is not available (e.g., Docker discovery).
+    /// Pass an empty `if_entries` vec if SNMP data is not available (e.g., Docker discovery),
+    /// and an empty `certificates` vec if no TLS certificates were captured.
     /// In DaemonPoll mode: Immediately sends to server and returns the response.
     /// In ServerPoll mode: Buffers the host for server to poll, waits for confirmation.
+    /// With a persistent entity buffer, a host the server can't confirm is kept in the journal
+    /// and the pending data is returned instead of an error.
+    #[allow(clippy::too_many_arguments)]
     async fn create_host(
         &self,
         host: Host,
@@ -629,6 +748,7 @@ pub trait CreatesDiscoveredEntities:
         ports: Vec<Port>,
         services: Vec<Service>,
         if_entries: Vec<IfEntry>,
+        certificates: Vec<TlsCertificate>,
         cancel: &CancellationToken,
     ) -> Result<HostResponse, Error> {
         let service = self.as_ref();
@@ -641,6 +761,7 @@ pub trait CreatesDiscoveredEntities:
             ports,
             services,
             if_entries,
+            certificates,
         };
 
         // Always buffer first (for both modes)
@@ -650,7 +771,7 @@ pub trait CreatesDiscoveredEntities:
             DaemonMode::DaemonPoll => {
                 // Immediately send to server, get response (with retry on transient failures)
                 let api_client = &service.api_client;
-                let response: HostResponse = (|| async {
+                let result: Result<HostResponse, Error> = (|| async {
                     api_client
                         .post("/api/v1/hosts/discovery", &request, "Failed to create host")
                         .await
@@ -662,7 +783,27 @@ pub trait CreatesDiscoveredEntities:
                         .with_max_times(ENTITY_CREATION_MAX_RETRIES),
                 )
                 .notify(|e, dur| tracing::warn!("Retrying host creation after {:?}: {}", dur, e))
-                .await?;
+                .await;
+
+                let response = match result {
+                    Ok(response) => response,
+                    // Persistent buffer keeps the host for upload once the server is reachable
+                    Err(e) if service.entity_buffer.is_persistent() => {
+                        tracing::warn!(
+                            host_id = %pending_id,
+                            error = %e,
+                            "Host creation failed, keeping host in entity journal for later upload"
+                        );
+                        return Ok(HostResponse::from_host_with_children(
+                            request.host,
+                            request.interfaces,
+                            request.ports,
+                            request.services,
+                            request.if_entries,
+                        ));
+                    }
+                    Err(e) => return Err(e),
+                };
 
                 // Mark as created in buffer with actual server data
                 service
@@ -674,17 +815,31 @@ pub trait CreatesDiscoveredEntities:
             }
             DaemonMode::ServerPoll => {
                 // Wait for server to poll and confirm creation
-                let actual_host = service
+                let actual_host = match service
                     .entity_buffer
                     .await_host(&pending_id, SERVER_POLL_CONFIRMATION_TIMEOUT, cancel)
                     .await
-                    .ok_or_else(|| {
-                        if cancel.is_cancelled() {
-                            anyhow!("Discovery cancelled while waiting for host creation")
-                        } else {
-                            anyhow!("Timeout waiting for host creation confirmation from server")
-                        }
-                    })?;
+                {
+                    Some(actual_host) => actual_host,
+                    None if cancel.is_cancelled() => {
+                        return Err(anyhow!(
+                            "Discovery cancelled while waiting for host creation"
+                        ));
+                    }
+                    // Persistent buffer keeps the host pending until the server next polls
+                    None if service.entity_buffer.is_persistent() => {
+                        tracing::warn!(
+                            host_id = %pending_id,
+                            "Server didn't confirm host creation, keeping host in entity journal"
+                        );
+                        request.host.clone()
+                    }
+                    None => {
+                        return Err(anyhow!(
+                            "Timeout waiting for host creation confirmation from server"
+                        ));
+                    }
+                };
 
                 // Build a HostResponse from the confirmed host
                 // Note: In ServerPoll mode, we don't have hydrated children back
@@ -704,6 +859,8 @@ pub trait CreatesDiscoveredEntities:
     ///
     /// In DaemonPoll mode: Immediately sends to server and returns the response.
     /// In ServerPoll mode: Buffers the subnet for server to poll, waits for confirmation.
+    /// With a persistent entity buffer, a subnet the server can't confirm is kept in the journal
+    /// and the pending data is returned instead of an error.
     async fn create_subnet(
         &self,
         subnet: &Subnet,
@@ -720,7 +877,7 @@ pub trait CreatesDiscoveredEntities:
             DaemonMode::DaemonPoll => {
                 // Immediately send to server, get response (with retry on transient failures)
                 let api_client = &service.api_client;
-                let actual: Subnet = (|| async {
+                let result: Result<Subnet, Error> = (|| async {
                     api_client
                         .post("/api/v1/subnets", subnet, "Failed to create subnet")
                         .await
@@ -732,7 +889,21 @@ pub trait CreatesDiscoveredEntities:
                         .with_max_times(ENTITY_CREATION_MAX_RETRIES),
                 )
                 .notify(|e, dur| tracing::warn!("Retrying subnet creation after {:?}: {}", dur, e))
-                .await?;
+                .await;
+
+                let actual = match result {
+                    Ok(actual) => actual,
+                    // Persistent buffer keeps the subnet for upload once the server is reachable
+                    Err(e) if service.entity_buffer.is_persistent() => {
+                        tracing::warn!(
+                            subnet_id = %pending_id,
+                            error = %e,
+                            "Subnet creation failed, keeping subnet in entity journal for later upload"
+                        );
+                        return Ok(subnet.clone());
+                    }
+                    Err(e) => return Err(e),
+                };
 
                 // Mark as created in buffer with actual server data
                 service
@@ -744,17 +915,27 @@ pub trait CreatesDiscoveredEntities:
             }
             DaemonMode::ServerPoll => {
                 // Wait for server to poll and confirm creation
-                service
+                match service
                     .entity_buffer
                     .await_subnet(&pending_id, SERVER_POLL_CONFIRMATION_TIMEOUT, cancel)
                     .await
-                    .ok_or_else(|| {
-                        if cancel.is_cancelled() {
-                            anyhow!("Discovery cancelled while waiting for subnet creation")
-                        } else {
-                            anyhow!("Timeout waiting for subnet creation confirmation from server")
-                        }
-                    })
+                {
+                    Some(actual) => Ok(actual),
+                    None if cancel.is_cancelled() => Err(anyhow!(
+                        "Discovery cancelled while waiting for subnet creation"
+                    )),
+                    // Persistent buffer keeps the subnet pending until the server next polls
+                    None if service.entity_buffer.is_persistent() => {
+                        tracing::warn!(
+                            subnet_id = %pending_id,
+                            "Server didn't confirm subnet creation, keeping subnet in entity journal"
+                        );
+                        Ok(subnet.clone())
+                    }
+                    None => Err(anyhow!(
+                        "Timeout waiting for subnet creation confirmation from server"
+                    )),
+                }
             }
         }
     }
@@ -26,6 +26,8 @@ use crate::server::discovery::r#impl::types::{DiscoveryType, HostNamingFallback}
 use crate::server::hosts::r#impl::base::HostBase;
 use crate::server::interfaces::r#impl::base::ALL_INTERFACES_IP;
 use crate::server::ports::r#impl::base::Port;
+use crate::server::services::definitions::ServiceDefinitionRegistry;
+use crate::server::services::r#impl::advertisements::ServiceAdvertisements;
 use crate::server::services::r#impl::base::{Service, ServiceBase, ServiceMatchBaselineParams};
 use crate::server::services::r#impl::definitions::ServiceDefinition;
 use crate::server::services::r#impl::endpoints::{Endpoint, EndpointResponse};
@@ -317,6 +319,7 @@ impl DiscoveryRunner<DockerScanDiscovery> {
                 details: MatchDetails::new_certain("Docker daemon self-report"),
             },
             position: 0,
+            proxy_targets: Vec::new(),
         });
 
         let mut temp_docker_daemon_host = Host::new(HostBase {
@@ -338,6 +341,14 @@ impl DiscoveryRunner<DockerScanDiscovery> {
             management_url: None,
             chassis_id: None,
             snmp_credential_id: None,
+            hardware_inventory: Vec::new(),
+            first_seen: None,
+            last_seen: None,
+            missed_scans: 0,
+            offline: false,
+            auto_hidden: false,
+            os: None,
+            hostname_source: None,
         });
         temp_docker_daemon_host.id = self.domain.host_id;
 
@@ -349,6 +360,7 @@ impl DiscoveryRunner<DockerScanDiscovery> {
                 vec![], // No ports for docker daemon host
                 vec![docker_service],
                 vec![], // No SNMP if_entries for docker discovery
+                vec![],
                 cancel,
             )
             .await?;
@@ -511,11 +523,13 @@ impl DiscoveryRunner<DockerScanDiscovery> {
                 .get_optimal_concurrent_scans(1, port_batch_config)
                 .await?;
             let port_scan_batch_size = scan_params.port_batch_size;
+            let service_definitions = ServiceDefinitionRegistry::all_service_definitions();
 
             // Scan ports and any endpoints that match open ports
             let endpoint_responses = tokio::spawn(scan_endpoints(
                 host_ip,
                 cancel.clone(),
+                Service::all_discovery_endpoints(&service_definitions),
                 Some(open_ports.clone()),
                 None,
                 port_scan_batch_size,
@@ -533,10 +547,13 @@ impl DiscoveryRunner<DockerScanDiscovery> {
 
             for (interface, subnet) in container_interfaces_and_subnets {
                 let params = ServiceMatchBaselineParams {
+                    service_definitions: &service_definitions,
                     subnet,
                     interface,
                     all_ports: &open_ports,
                     endpoint_responses: &endpoint_responses,
+                    banners: &Vec::new(),
+                    advertisements: &ServiceAdvertisements::default(),
                     virtualization: &Some(ServiceVirtualization::Docker(DockerVirtualization {
                         container_name: container
                             .name
@@ -554,7 +571,7 @@ impl DiscoveryRunner<DockerScanDiscovery> {
                     host.id = self.domain.host_id;
 
                     if let Ok(host_response) = self
-                        .create_host(host, interfaces, ports, services, vec![], cancel)
+                        .create_host(host, interfaces, ports, services, vec![], vec![], cancel)
                         .await
                     {
                         return Ok::<Option<(Host, Vec<Service>)>, Error>(Some((
@@ -600,6 +617,8 @@ impl DiscoveryRunner<DockerScanDiscovery> {
         let (host_ip_to_host_ports, container_ips_to_container_ports, host_to_container_port_map) =
             self.get_ports_from_container(container_summary, container_interfaces_and_subnets);
 
+        let service_definitions = ServiceDefinitionRegistry::all_service_definitions();
+
         for (interface, subnet) in container_interfaces_and_subnets {
             if cancel.is_cancelled() {
                 return Err(Error::msg("Discovery was cancelled"));
@@ -607,6 +626,7 @@ impl DiscoveryRunner<DockerScanDiscovery> {
 
             let endpoint_responses = if let Some(name) = &container.name {
                 self.scan_container_endpoints(
+                    &service_definitions,
                     interface,
                     &host_to_container_port_map,
                     name.trim_start_matches("/"),
@@ -633,10 +653,13 @@ impl DiscoveryRunner<DockerScanDiscovery> {
             if let Ok(Some((mut host, mut interfaces, mut ports, mut services))) = self
                 .process_host(
                     ServiceMatchBaselineParams {
+                        service_definitions: &service_definitions,
                         subnet,
                         interface,
                         all_ports: container_ports_on_interface,
                         endpoint_responses: &endpoint_responses,
+                        banners: &Vec::new(),
+                        advertisements: &ServiceAdvertisements::default(),
                         virtualization: &Some(ServiceVirtualization::Docker(
                             DockerVirtualization {
                                 container_name: container
@@ -811,7 +834,15 @@ impl DiscoveryRunner<DockerScanDiscovery> {
                 });
 
                 if let Ok(host_response) = self
-                    .create_host(host, interfaces, ports, services.clone(), vec![], cancel)
+                    .create_host(
+                        host,
+                        interfaces,
+                        ports,
+                        services.clone(),
+                        vec![],
+                        vec![],
+                        cancel,
+                    )
                     .await
                 {
                     return Ok::<Option<(Host, Vec<Service>)>, Error>(Some((
@@ -871,6 +902,7 @@ impl DiscoveryRunner<DockerScanDiscovery> {
 
     async fn scan_container_endpoints(
         &self,
+        service_definitions: &[Box<dyn ServiceDefinition>],
         interface: &Interface,
         host_to_container_port_map: &HashMap<(IpAddr, u16), u16>,
         container_name: &str,
@@ -893,7 +925,7 @@ impl DiscoveryRunner<DockerScanDiscovery> {
             .get()
             .ok_or_else(|| anyhow!("Docker client unavailable"))?;
 
-        let all_endpoints = Service::all_discovery_endpoints();
+        let all_endpoints = Service::all_discovery_endpoints(service_definitions);
 
         let mut endpoint_responses = Vec::new();
 
@@ -1232,6 +1264,8 @@ impl DiscoveryRunner<DockerScanDiscovery> {
                                                 mac_address,
                                                 name: Some(network_name.to_owned()),
                                                 position: 0,
+                                                first_seen: None,
+                                                last_seen: None,
                                             }),
                                             subnet.clone(),
                                         ));
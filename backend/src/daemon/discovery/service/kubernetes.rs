@@ -0,0 +1,909 @@
+use anyhow::anyhow;
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use cidr::IpCidr;
+use futures::future::try_join_all;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::daemon::discovery::service::base::{
+    CreatesDiscoveredEntities, DiscoversNetworkedEntities, DiscoveryRunner, RunsDiscovery,
+};
+use crate::daemon::utils::base::DaemonUtils;
+use crate::daemon::utils::kubernetes::{
+    ClusterConfig, Ingress, KubeService, KubernetesClient, Node, Pod,
+};
+use crate::daemon::utils::scanner::scan_endpoints;
+use crate::server::bindings::r#impl::base::Binding;
+use crate::server::daemons::r#impl::api::DaemonDiscoveryRequest;
+use crate::server::discovery::r#impl::types::{DiscoveryType, HostNamingFallback};
+use crate::server::hosts::r#impl::base::{Host, HostBase};
+use crate::server::interfaces::r#impl::base::{Interface, InterfaceBase};
+use crate::server::ports::r#impl::base::{Port, PortType};
+use crate::server::services::definitions::ServiceDefinitionRegistry;
+use crate::server::services::definitions::kubernetes::Kubernetes;
+use crate::server::services::r#impl::advertisements::ServiceAdvertisements;
+use crate::server::services::r#impl::base::{Service, ServiceBase, ServiceMatchBaselineParams};
+use crate::server::services::r#impl::definitions::ServiceDefinition;
+use crate::server::services::r#impl::patterns::MatchDetails;
+use crate::server::services::r#impl::virtualization::{
+    KubernetesVirtualization, ServiceVirtualization,
+};
+use crate::server::shared::storage::traits::Storable;
+use crate::server::shared::types::entities::{DiscoveryMetadata, EntitySource};
+use crate::server::shared::types::metadata::HasId;
+use crate::server::subnets::r#impl::base::{Subnet, SubnetBase};
+use crate::server::subnets::r#impl::types::SubnetType;
+
+pub struct KubernetesDiscovery {
+    client: OnceLock<KubernetesClient>,
+    kubeconfig_path: Option<String>,
+    context: Option<String>,
+    host_naming_fallback: HostNamingFallback,
+}
+
+/// A node's host as created on the server, with the Kubernetes service its pods run under
+struct NodeHost {
+    host: Host,
+    interfaces: Vec<Interface>,
+    kubernetes_service_id: Uuid,
+}
+
+/// Cluster objects that decide which ports a pod serves and where it's reachable from
+struct ClusterExposure<'a> {
+    services: &'a [KubeService],
+    ingresses: &'a [Ingress],
+}
+
+#[async_trait]
+impl RunsDiscovery for DiscoveryRunner<KubernetesDiscovery> {
+    fn discovery_type(&self) -> DiscoveryType {
+        DiscoveryType::Kubernetes {
+            kubeconfig_path: self.domain.kubeconfig_path.clone(),
+            context: self.domain.context.clone(),
+            host_naming_fallback: self.domain.host_naming_fallback,
+        }
+    }
+
+    async fn discover(
+        &self,
+        request: DaemonDiscoveryRequest,
+        cancel: CancellationToken,
+    ) -> Result<(), Error> {
+        let config = ClusterConfig::load(
+            self.domain.kubeconfig_path.as_deref(),
+            self.domain.context.as_deref(),
+        )?;
+        self.domain
+            .client
+            .set(KubernetesClient::new(config)?)
+            .map_err(|_| anyhow!("Failed to set Kubernetes client"))?;
+
+        // Fail before the session starts if the API server can't be reached
+        let nodes = self.client()?.nodes().await?;
+
+        self.start_discovery(request).await?;
+
+        let discovery_result = self.discover_cluster(nodes, &cancel).await;
+
+        self.finish_discovery(discovery_result, cancel.clone())
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl KubernetesDiscovery {
+    pub fn new(
+        kubeconfig_path: Option<String>,
+        context: Option<String>,
+        host_naming_fallback: HostNamingFallback,
+    ) -> Self {
+        Self {
+            client: OnceLock::new(),
+            kubeconfig_path,
+            context,
+            host_naming_fallback,
+        }
+    }
+}
+
+impl CreatesDiscoveredEntities for DiscoveryRunner<KubernetesDiscovery> {}
+
+#[async_trait]
+impl DiscoversNetworkedEntities for DiscoveryRunner<KubernetesDiscovery> {
+    async fn get_gateway_ips(&self) -> Result<Vec<IpAddr>, Error> {
+        self.as_ref()
+            .utils
+            .get_own_routing_table_gateway_ips()
+            .await
+    }
+
+    /// Creates the daemon's own subnets and one pod network per node pod CIDR, and returns
+    /// them along with every subnet the server already knows, which node IPs are matched to
+    async fn discover_create_subnets(
+        &self,
+        cancel: &CancellationToken,
+    ) -> Result<Vec<Subnet>, Error> {
+        let daemon_id = self.as_ref().config_store.get_id().await?;
+        let network_id = self
+            .as_ref()
+            .config_store
+            .get_network_id()
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Network ID not set"))?;
+
+        let interface_filter = self.as_ref().config_store.get_interfaces().await?;
+        let (_, host_subnets, _) = self
+            .as_ref()
+            .utils
+            .get_own_interfaces(
+                self.discovery_type(),
+                daemon_id,
+                network_id,
+                &interface_filter,
+            )
+            .await?;
+
+        let pod_subnets: Vec<Subnet> = self
+            .client()?
+            .nodes()
+            .await?
+            .iter()
+            .flat_map(|node| {
+                node.spec.pod_cidrs.iter().filter_map(|cidr| {
+                    Some(Subnet::new(SubnetBase {
+                        cidr: IpCidr::from_str(cidr).ok()?,
+                        description: None,
+                        tags: Vec::new(),
+                        network_id,
+                        name: format!("{} pods", node.metadata.name),
+                        subnet_type: SubnetType::KubernetesPod,
+                        source: EntitySource::Discovery {
+                            metadata: vec![DiscoveryMetadata::new(
+                                self.discovery_type(),
+                                daemon_id,
+                            )],
+                        },
+                    }))
+                })
+            })
+            .collect();
+
+        let subnets: Vec<Subnet> = [host_subnets, pod_subnets].concat();
+        let subnet_futures = subnets
+            .iter()
+            .map(|subnet| self.create_subnet(subnet, cancel));
+        let mut subnets = try_join_all(subnet_futures).await?;
+
+        // Nodes usually sit on subnets found by network discovery rather than on the daemon's own
+        let known_subnets: Vec<Subnet> = match self
+            .as_ref()
+            .api_client
+            .get("/api/v1/subnets", "Failed to get subnets")
+            .await
+        {
+            Ok(known_subnets) => known_subnets,
+            Err(e) => {
+                tracing::warn!(error = %e, "Could not fetch known subnets, using the daemon's own");
+                Vec::new()
+            }
+        };
+        for subnet in known_subnets {
+            if subnet.base.network_id == network_id && !subnets.iter().any(|s| s.id == subnet.id) {
+                subnets.push(subnet);
+            }
+        }
+
+        Ok(subnets)
+    }
+}
+
+impl DiscoveryRunner<KubernetesDiscovery> {
+    fn client(&self) -> Result<&KubernetesClient, Error> {
+        self.domain
+            .client
+            .get()
+            .ok_or_else(|| anyhow!("Kubernetes client unavailable"))
+    }
+
+    async fn discover_cluster(&self, nodes: Vec<Node>, cancel: &CancellationToken) -> Result<()> {
+        let subnets = self.discover_create_subnets(cancel).await?;
+
+        let client = self.client()?;
+        let pods = client.pods().await?;
+        let services = client.services().await?;
+        // Older clusters and restricted service accounts may not expose networking.k8s.io
+        let ingresses = client.ingresses().await.unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "Could not list ingresses, skipping them");
+            Vec::new()
+        });
+        let exposure = ClusterExposure {
+            services: &services,
+            ingresses: &ingresses,
+        };
+
+        let pods: Vec<&Pod> = pods.iter().filter(|p| p.is_running()).collect();
+        let total = nodes.len() + pods.len();
+        let mut done = 0;
+
+        self.report_scanning_progress(0).await?;
+
+        let mut node_hosts: HashMap<&str, NodeHost> = HashMap::new();
+        for node in &nodes {
+            if cancel.is_cancelled() {
+                return Err(Error::msg("Kubernetes discovery session was cancelled"));
+            }
+
+            match self.create_node_host(node, &subnets, cancel).await {
+                Ok(Some(node_host)) => {
+                    node_hosts.insert(node.metadata.name.as_str(), node_host);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::warn!(node = %node.metadata.name, error = %e, "Node processing error")
+                }
+            }
+
+            done += 1;
+            self.report_scanning_progress((done * 100 / total.max(1)) as u8)
+                .await?;
+        }
+
+        let mut discovered = 0;
+        for pod in pods {
+            if cancel.is_cancelled() {
+                return Err(Error::msg("Kubernetes discovery session was cancelled"));
+            }
+
+            let node_host = pod
+                .spec
+                .node_name
+                .as_deref()
+                .and_then(|name| node_hosts.get(name));
+
+            if let Some(node_host) = node_host {
+                match self
+                    .process_pod(pod, node_host, &exposure, &subnets, cancel)
+                    .await
+                {
+                    Ok(true) => discovered += 1,
+                    Ok(false) => {}
+                    Err(e) => tracing::warn!(
+                        pod = %pod.metadata.name,
+                        namespace = %pod.namespace(),
+                        error = %e,
+                        "Pod processing error"
+                    ),
+                }
+            }
+
+            done += 1;
+            self.report_scanning_progress((done * 100 / total.max(1)) as u8)
+                .await?;
+        }
+
+        tracing::info!(
+            nodes = %node_hosts.len(),
+            pods = %discovered,
+            "Kubernetes scan complete"
+        );
+
+        Ok(())
+    }
+
+    /// Create the host for a node, with an interface per node IP on a known subnet and the
+    /// Kubernetes service its pods are virtualized by
+    async fn create_node_host(
+        &self,
+        node: &Node,
+        subnets: &[Subnet],
+        cancel: &CancellationToken,
+    ) -> Result<Option<NodeHost>> {
+        let daemon_id = self.as_ref().config_store.get_id().await?;
+        let network_id = self
+            .as_ref()
+            .config_store
+            .get_network_id()
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Network ID not set"))?;
+
+        let interfaces = node_interfaces(node, subnets, network_id);
+        if interfaces.is_empty() {
+            tracing::warn!(
+                node = %node.metadata.name,
+                "Skipping node, none of its addresses are on a known subnet"
+            );
+            return Ok(None);
+        }
+
+        let hostname = node
+            .addresses("Hostname")
+            .next()
+            .unwrap_or(&node.metadata.name)
+            .to_string();
+
+        let host = Host::new(HostBase {
+            name: node.metadata.name.clone(),
+            network_id,
+            hostname: Some(hostname),
+            source: EntitySource::Discovery {
+                metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
+            },
+            ..Default::default()
+        });
+
+        let kubernetes_service = Service::new(ServiceBase {
+            name: ServiceDefinition::name(&Kubernetes).to_string(),
+            service_definition: Box::new(Kubernetes),
+            bindings: vec![],
+            host_id: host.id,
+            tags: Vec::new(),
+            network_id,
+            virtualization: None,
+            source: EntitySource::DiscoveryWithMatch {
+                metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
+                details: MatchDetails::new_certain("Node reported by the Kubernetes API"),
+            },
+            position: 0,
+            proxy_targets: Vec::new(),
+        });
+
+        let host_response = self
+            .create_host(
+                host,
+                interfaces.clone(),
+                vec![],
+                vec![kubernetes_service],
+                vec![],
+                vec![],
+                cancel,
+            )
+            .await?;
+
+        let kubernetes_service_id = host_response
+            .services
+            .iter()
+            .find(|s| s.base.service_definition.id() == Kubernetes.id())
+            .map(|s| s.id)
+            .ok_or_else(|| anyhow!("Kubernetes service was not created for node"))?;
+
+        Ok(Some(NodeHost {
+            host: host_response.to_host(),
+            interfaces,
+            kubernetes_service_id,
+        }))
+    }
+
+    /// Match services for a pod and create them on its node's host. Returns whether any
+    /// services were created.
+    async fn process_pod(
+        &self,
+        pod: &Pod,
+        node_host: &NodeHost,
+        exposure: &ClusterExposure<'_>,
+        subnets: &[Subnet],
+        cancel: &CancellationToken,
+    ) -> Result<bool> {
+        let Some(ip) = pod.status.pod_ip.as_deref().and_then(|ip| ip.parse().ok()) else {
+            return Ok(false);
+        };
+
+        let Some(pod_interface) = interface_for_pod(pod, ip, &node_host.interfaces, subnets) else {
+            tracing::debug!(
+                pod = %pod.metadata.name,
+                ip = %ip,
+                "Skipping pod, its IP isn't on a known pod network"
+            );
+            return Ok(false);
+        };
+        let Some(subnet) = subnets
+            .iter()
+            .find(|s| s.id == pod_interface.base.subnet_id)
+        else {
+            return Ok(false);
+        };
+
+        let selecting = selecting_services(pod, exposure.services);
+        let pod_ports = served_ports(pod, &selecting);
+
+        let service_definitions = ServiceDefinitionRegistry::all_service_definitions();
+        let endpoint_responses = if pod_ports.is_empty() {
+            Vec::new()
+        } else {
+            let port_batch_config = self
+                .as_ref()
+                .config_store
+                .get_port_scan_batch_size()
+                .await?;
+            let scan_params = self
+                .as_ref()
+                .utils
+                .get_optimal_concurrent_scans(1, port_batch_config)
+                .await?;
+
+            // Pod IPs are only routable from inside the cluster, so this finds nothing when the
+            // daemon runs elsewhere and matching falls back to ports alone
+            scan_endpoints(
+                ip,
+                cancel.clone(),
+                Service::all_discovery_endpoints(&service_definitions),
+                Some(pod_ports.clone()),
+                None,
+                scan_params.port_batch_size,
+                false,
+            )
+            .await
+            .unwrap_or_default()
+        };
+
+        let Some((mut host, mut interfaces, mut ports, mut services)) = self
+            .process_host(
+                ServiceMatchBaselineParams {
+                    service_definitions: &service_definitions,
+                    subnet,
+                    interface: &pod_interface,
+                    all_ports: &pod_ports,
+                    endpoint_responses: &endpoint_responses,
+                    banners: &Vec::new(),
+                    advertisements: &ServiceAdvertisements::default(),
+                    virtualization: &Some(ServiceVirtualization::Kubernetes(
+                        KubernetesVirtualization {
+                            namespace: pod.namespace().to_string(),
+                            pod_name: Some(pod.metadata.name.clone()),
+                            pod_uid: Some(pod.metadata.uid.clone()),
+                            service_id: node_host.kubernetes_service_id,
+                        },
+                    )),
+                },
+                None,
+                self.domain.host_naming_fallback,
+            )
+            .await?
+        else {
+            return Ok(false);
+        };
+
+        // Services belong to the node, not to a host of their own
+        host.id = node_host.host.id;
+        host.base.name = node_host.host.base.name.clone();
+        host.base.hostname = node_host.host.base.hostname.clone();
+
+        for interface in &node_host.interfaces {
+            if !interfaces.contains(interface) {
+                interfaces.push(interface.clone());
+            }
+        }
+
+        // NodePorts, LoadBalancer IPs and Ingress addresses make the pod reachable on node IPs
+        for (port_type, node_ip) in exposed_ports(pod, &selecting, exposure.ingresses) {
+            let node_interfaces: Vec<&Interface> = node_host
+                .interfaces
+                .iter()
+                .filter(|i| node_ip.is_none_or(|ip| i.base.ip_address == ip))
+                .collect();
+            if node_interfaces.is_empty() {
+                continue;
+            }
+
+            let port = match ports.iter().find(|p| p.base.port_type == port_type) {
+                Some(port) => *port,
+                None => {
+                    let port = Port::new_hostless(port_type);
+                    ports.push(port);
+                    port
+                }
+            };
+
+            for service in services.iter_mut() {
+                for interface in &node_interfaces {
+                    let already_bound = service.base.bindings.iter().any(|b| {
+                        b.port_id() == Some(port.id) && b.interface_id() == Some(interface.id)
+                    });
+                    if !already_bound {
+                        service
+                            .base
+                            .bindings
+                            .push(Binding::new_port_serviceless(port.id, Some(interface.id)));
+                    }
+                }
+            }
+        }
+
+        self.create_host(host, interfaces, ports, services, vec![], vec![], cancel)
+            .await?;
+
+        Ok(true)
+    }
+}
+
+/// Interfaces for a node's internal and external IPs that are on a known, non-pod subnet
+fn node_interfaces(node: &Node, subnets: &[Subnet], network_id: Uuid) -> Vec<Interface> {
+    node.addresses("InternalIP")
+        .chain(node.addresses("ExternalIP"))
+        .filter_map(|ip| ip.parse::<IpAddr>().ok())
+        .filter_map(|ip| {
+            let subnet = subnets.iter().find(|s| {
+                !s.base.subnet_type.is_container_network() && s.base.cidr.contains(&ip)
+            })?;
+            Some(Interface::new(InterfaceBase {
+                network_id,
+                host_id: Uuid::nil(), // Placeholder - server will set correct host_id
+                subnet_id: subnet.id,
+                ip_address: ip,
+                mac_address: None,
+                name: None,
+                position: 0,
+                first_seen: None,
+                last_seen: None,
+            }))
+        })
+        .collect()
+}
+
+/// Interface a pod's services are bound to on its node's host. Host network pods share the
+/// node's interfaces, everything else gets one on the pod network. `None` if `ip` isn't on a
+/// known pod network.
+fn interface_for_pod(
+    pod: &Pod,
+    ip: IpAddr,
+    node_interfaces: &[Interface],
+    subnets: &[Subnet],
+) -> Option<Interface> {
+    if pod.spec.host_network {
+        return node_interfaces
+            .iter()
+            .find(|i| i.base.ip_address == ip)
+            .or(node_interfaces.first())
+            .cloned();
+    }
+
+    subnets
+        .iter()
+        .find(|s| s.base.subnet_type.is_container_network() && s.base.cidr.contains(&ip))
+        .map(|subnet| {
+            Interface::new(InterfaceBase {
+                network_id: subnet.base.network_id,
+                host_id: Uuid::nil(), // Placeholder - server will set correct host_id
+                subnet_id: subnet.id,
+                ip_address: ip,
+                mac_address: None,
+                name: Some(pod.metadata.name.clone()),
+                position: 0,
+                first_seen: None,
+                last_seen: None,
+            })
+        })
+}
+
+/// Services whose selector matches the pod
+fn selecting_services<'a>(pod: &Pod, services: &'a [KubeService]) -> Vec<&'a KubeService> {
+    services.iter().filter(|s| s.selects(pod)).collect()
+}
+
+/// Ports the pod declares plus any a selecting Service forwards to it
+fn served_ports(pod: &Pod, selecting: &[&KubeService]) -> Vec<PortType> {
+    let mut ports: Vec<PortType> = pod
+        .container_ports()
+        .map(|p| port_type(p.container_port, p.protocol.as_deref()))
+        .chain(selecting.iter().flat_map(|s| {
+            s.spec.ports.iter().filter_map(|p| {
+                KubeService::target_port(p, pod)
+                    .map(|target| port_type(target, p.protocol.as_deref()))
+            })
+        }))
+        .collect();
+    ports.sort_by_key(|p| (p.number(), p.protocol()));
+    ports.dedup();
+    ports
+}
+
+fn port_type(number: u16, protocol: Option<&str>) -> PortType {
+    match protocol {
+        Some("UDP") => PortType::new_udp(number),
+        _ => PortType::new_tcp(number),
+    }
+}
+
+/// Ports a pod is published on through its node, with the node IP they're limited to
+/// (`None` for every node IP, as with NodePorts)
+fn exposed_ports(
+    pod: &Pod,
+    selecting: &[&KubeService],
+    ingresses: &[Ingress],
+) -> Vec<(PortType, Option<IpAddr>)> {
+    let mut exposed = Vec::new();
+
+    for service in selecting {
+        for port in &service.spec.ports {
+            if let Some(node_port) = port.node_port {
+                exposed.push((port_type(node_port, port.protocol.as_deref()), None));
+            }
+            for ip in service.status.ips().filter_map(|ip| ip.parse().ok()) {
+                exposed.push((port_type(port.port, port.protocol.as_deref()), Some(ip)));
+            }
+        }
+    }
+
+    for ingress in ingresses {
+        let routes_to_pod = ingress.namespace() == pod.namespace()
+            && ingress
+                .backend_services()
+                .any(|name| selecting.iter().any(|s| s.metadata.name == name));
+        if !routes_to_pod {
+            continue;
+        }
+
+        for ip in ingress.status.ips().filter_map(|ip| ip.parse().ok()) {
+            exposed.push((PortType::new_tcp(80), Some(ip)));
+            if !ingress.spec.tls.is_empty() {
+                exposed.push((PortType::new_tcp(443), Some(ip)));
+            }
+        }
+    }
+
+    exposed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::utils::kubernetes::{
+        Container, ContainerPort, IngressBackend, IngressServiceBackend, IngressSpec, IntOrString,
+        LoadBalancerHolder, LoadBalancerIngress, LoadBalancerStatus, NodeAddress, NodeStatus,
+        ObjectMeta, PodSpec, PodStatus, ServicePort, ServiceSpec,
+    };
+
+    fn meta(name: &str, namespace: &str, labels: &[(&str, &str)]) -> ObjectMeta {
+        ObjectMeta {
+            name: name.to_string(),
+            namespace: Some(namespace.to_string()),
+            uid: format!("{}-uid", name),
+            labels: labels
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        }
+    }
+
+    fn container_port(name: &str, number: u16, protocol: Option<&str>) -> ContainerPort {
+        ContainerPort {
+            name: Some(name.to_string()),
+            container_port: number,
+            protocol: protocol.map(str::to_string),
+        }
+    }
+
+    fn pod(ip: &str, host_network: bool) -> Pod {
+        Pod {
+            metadata: meta("web-0", "apps", &[("app", "web"), ("tier", "frontend")]),
+            spec: PodSpec {
+                node_name: Some("node-1".to_string()),
+                host_network,
+                containers: vec![Container {
+                    name: "web".to_string(),
+                    ports: vec![
+                        container_port("http", 8080, None),
+                        container_port("dns", 5353, Some("UDP")),
+                    ],
+                }],
+            },
+            status: PodStatus {
+                phase: Some("Running".to_string()),
+                pod_ip: Some(ip.to_string()),
+            },
+        }
+    }
+
+    fn service_port(port: u16, target_port: Option<IntOrString>) -> ServicePort {
+        ServicePort {
+            port,
+            protocol: None,
+            node_port: None,
+            target_port,
+        }
+    }
+
+    fn service(name: &str, namespace: &str, selector: &[(&str, &str)]) -> KubeService {
+        KubeService {
+            metadata: meta(name, namespace, &[]),
+            spec: ServiceSpec {
+                selector: selector
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    fn subnet(cidr: &str, subnet_type: SubnetType) -> Subnet {
+        Subnet::new(SubnetBase {
+            cidr: cidr.parse().unwrap(),
+            subnet_type,
+            ..Default::default()
+        })
+    }
+
+    fn load_balancer(ip: &str) -> LoadBalancerHolder {
+        LoadBalancerHolder {
+            load_balancer: LoadBalancerStatus {
+                ingress: vec![LoadBalancerIngress {
+                    ip: Some(ip.to_string()),
+                }],
+            },
+        }
+    }
+
+    #[test]
+    fn test_served_ports_resolve_service_target_ports() {
+        let pod = pod("10.244.1.7", false);
+        let mut web = service("web", "apps", &[("app", "web")]);
+        web.spec.ports = vec![
+            // Named target resolved against the container port it duplicates
+            service_port(80, Some(IntOrString::String("http".to_string()))),
+            service_port(9090, Some(IntOrString::Int(9091))),
+            // No container port named "https", nothing to forward to
+            service_port(443, Some(IntOrString::String("https".to_string()))),
+            // Without a target port the service port is used
+            service_port(6379, None),
+        ];
+
+        assert_eq!(
+            served_ports(&pod, &[&web]),
+            vec![
+                PortType::new_udp(5353),
+                PortType::new_tcp(6379),
+                PortType::new_tcp(8080),
+                PortType::new_tcp(9091),
+            ]
+        );
+        assert_eq!(
+            served_ports(&pod, &[]),
+            vec![PortType::new_udp(5353), PortType::new_tcp(8080)]
+        );
+    }
+
+    #[test]
+    fn test_selecting_services_match_labels_and_namespace() {
+        let pod = pod("10.244.1.7", false);
+        let services = vec![
+            service("web", "apps", &[("app", "web")]),
+            service(
+                "web-frontend",
+                "apps",
+                &[("app", "web"), ("tier", "frontend")],
+            ),
+            service(
+                "web-backend",
+                "apps",
+                &[("app", "web"), ("tier", "backend")],
+            ),
+            service("web-default", "default", &[("app", "web")]),
+            // Services without a selector are backed by manually managed endpoints
+            service("external", "apps", &[]),
+        ];
+
+        let names: Vec<&str> = selecting_services(&pod, &services)
+            .iter()
+            .map(|s| s.metadata.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["web", "web-frontend"]);
+    }
+
+    #[test]
+    fn test_pods_land_on_node_interfaces() {
+        let lan = subnet("10.0.0.0/24", SubnetType::Lan);
+        let pods = subnet("10.244.1.0/24", SubnetType::KubernetesPod);
+        let subnets = vec![lan.clone(), pods.clone()];
+
+        let node = Node {
+            metadata: meta("node-1", "", &[]),
+            status: NodeStatus {
+                addresses: [
+                    "InternalIP:10.0.0.11",
+                    "ExternalIP:203.0.113.5",
+                    "Hostname:node-1",
+                ]
+                .iter()
+                .map(|a| {
+                    let (address_type, address) = a.split_once(':').unwrap();
+                    NodeAddress {
+                        address_type: address_type.to_string(),
+                        address: address.to_string(),
+                    }
+                })
+                .collect(),
+            },
+            ..Default::default()
+        };
+
+        // The external IP isn't on a known subnet
+        let node_interfaces = node_interfaces(&node, &subnets, lan.base.network_id);
+        assert_eq!(node_interfaces.len(), 1);
+        assert_eq!(node_interfaces[0].base.subnet_id, lan.id);
+        assert_eq!(
+            node_interfaces[0].base.ip_address,
+            "10.0.0.11".parse::<IpAddr>().unwrap()
+        );
+
+        let pod_ip: IpAddr = "10.244.1.7".parse().unwrap();
+        let interface = interface_for_pod(
+            &pod("10.244.1.7", false),
+            pod_ip,
+            &node_interfaces,
+            &subnets,
+        )
+        .unwrap();
+        assert_eq!(interface.base.subnet_id, pods.id);
+        assert_eq!(interface.base.ip_address, pod_ip);
+        assert_eq!(interface.base.name.as_deref(), Some("web-0"));
+
+        // Host network pods reuse the node's interface
+        let node_ip: IpAddr = "10.0.0.11".parse().unwrap();
+        let interface =
+            interface_for_pod(&pod("10.0.0.11", true), node_ip, &node_interfaces, &subnets)
+                .unwrap();
+        assert_eq!(interface.id, node_interfaces[0].id);
+
+        // Pod IPs outside any known pod network aren't placed
+        let unknown: IpAddr = "172.16.0.9".parse().unwrap();
+        assert!(
+            interface_for_pod(
+                &pod("172.16.0.9", false),
+                unknown,
+                &node_interfaces,
+                &subnets
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn test_exposed_ports_on_node_ips() {
+        let pod = pod("10.244.1.7", false);
+        let mut web = service("web", "apps", &[("app", "web")]);
+        web.spec.ports = vec![ServicePort {
+            node_port: Some(30080),
+            ..service_port(80, Some(IntOrString::String("http".to_string())))
+        }];
+        web.status = load_balancer("10.0.0.50");
+
+        let ingress = Ingress {
+            metadata: meta("web", "apps", &[]),
+            spec: IngressSpec {
+                default_backend: Some(IngressBackend {
+                    service: Some(IngressServiceBackend {
+                        name: "web".to_string(),
+                    }),
+                }),
+                tls: vec![serde_json::json!({ "hosts": ["web.example.com"] })],
+                ..Default::default()
+            },
+            status: load_balancer("10.0.0.60"),
+        };
+        // Same backend name in another namespace routes elsewhere
+        let other_namespace = Ingress {
+            metadata: meta("web", "staging", &[]),
+            status: load_balancer("10.0.0.70"),
+            ..ingress.clone()
+        };
+
+        let lb_ip: IpAddr = "10.0.0.50".parse().unwrap();
+        let ingress_ip: IpAddr = "10.0.0.60".parse().unwrap();
+        assert_eq!(
+            exposed_ports(&pod, &[&web], &[ingress, other_namespace]),
+            vec![
+                (PortType::new_tcp(30080), None),
+                (PortType::new_tcp(80), Some(lb_ip)),
+                (PortType::new_tcp(80), Some(ingress_ip)),
+                (PortType::new_tcp(443), Some(ingress_ip)),
+            ]
+        );
+    }
+}
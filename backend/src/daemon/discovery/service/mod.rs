@@ -1,4 +1,7 @@
 pub mod base;
 pub mod docker;
+pub mod kubernetes;
 pub mod network;
+pub mod passive;
+pub mod proxmox;
 pub mod self_report;
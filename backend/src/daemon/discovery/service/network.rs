@@ -2,20 +2,36 @@ use crate::daemon::discovery::service::base::{
     CreatesDiscoveredEntities, DiscoversNetworkedEntities, DiscoveryRunner, RunsDiscovery,
 };
 use crate::daemon::discovery::types::base::{DiscoveryCriticalError, DiscoverySessionUpdate};
+use crate::daemon::utils::advertisements;
 use crate::daemon::utils::arp::{self, ArpScanResult};
+use crate::daemon::utils::banners;
+use crate::daemon::utils::names;
+use crate::daemon::utils::ndp::{self, NdpScanResult};
 use crate::daemon::utils::scanner::{
     ScanConcurrencyController, can_arp_scan, scan_endpoints, scan_tcp_ports, scan_udp_ports,
 };
+use crate::daemon::utils::smb;
 use crate::daemon::utils::snmp::{self, IfTableEntry};
-use crate::server::discovery::r#impl::types::{DiscoveryType, HostNamingFallback};
+use crate::daemon::utils::tcp_fingerprint;
+use crate::daemon::utils::tls;
+use crate::server::custom_service_definitions::r#impl::base::CustomServiceDefinition;
+use crate::server::discovery::r#impl::exclusions::{ExcludedTarget, ScanExclusion};
+use crate::server::discovery::r#impl::scan_profile::ScanProfile;
+use crate::server::discovery::r#impl::types::{DiscoveryType, HostNamingFallback, HostnameSource};
+use crate::server::hosts::r#impl::hardware::{HardwareComponent, HardwareComponentClass};
+use crate::server::hosts::r#impl::os::{OsFingerprint, OsSignals};
 use crate::server::if_entries::r#impl::base::{IfAdminStatus, IfEntry, IfEntryBase, IfOperStatus};
 use crate::server::interfaces::r#impl::base::{Interface, InterfaceBase};
-use crate::server::ports::r#impl::base::PortType;
+use crate::server::ports::r#impl::base::{PortType, TransportProtocol};
+use crate::server::services::definitions::ServiceDefinitionRegistry;
+use crate::server::services::r#impl::advertisements::ServiceAdvertisements;
 use crate::server::services::r#impl::base::{Service, ServiceMatchBaselineParams};
+use crate::server::services::r#impl::definitions::ServiceDefinition;
 use crate::server::snmp_credentials::r#impl::discovery::{
     SnmpCredentialMapping, SnmpQueryCredential,
 };
 use crate::server::subnets::r#impl::types::SubnetTypeDiscriminants;
+use crate::server::tls_certificates::r#impl::base::TlsCertificate;
 use crate::{
     daemon::utils::base::DaemonUtils,
     server::{
@@ -26,13 +42,18 @@ use crate::{
 use anyhow::Error;
 use async_trait::async_trait;
 use cidr::IpCidr;
+use futures::future::{BoxFuture, FutureExt, Shared};
 use futures::{StreamExt, future::try_join_all};
 use mac_address::MacAddress;
+use mac_oui::Oui;
 use pnet::datalink;
 use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::{Duration, Instant};
-use std::{net::IpAddr, sync::Arc};
+use std::{
+    net::{IpAddr, Ipv4Addr},
+    sync::Arc,
+};
 use strum::IntoDiscriminant;
 use tokio::sync::mpsc as tokio_mpsc;
 use tokio::time::timeout;
@@ -50,32 +71,55 @@ const PROGRESS_ARP_PHASE: u8 = 30; // 0-30%: ARP discovery
 const PROGRESS_DEEP_SCAN_PHASE: u8 = 65; // 30-95%: Deep scanning
 const PROGRESS_GRACE_PHASE: u8 = 5; // 95-100%: Grace period
 
+/// IPv6 subnets with more host bits than this can't be enumerated address-by-address
+/// (a /64 has 2^64 addresses) - their hosts are found via NDP instead
+const MAX_ENUMERABLE_IPV6_HOST_BITS: u8 = 16;
+
+/// mDNS/SSDP answers for every queried subnet, keyed by responder address. Resolves once
+/// the query phase finishes; deep scans await it before matching services.
+type SubnetAdvertisements = Shared<BoxFuture<'static, Arc<HashMap<IpAddr, ServiceAdvertisements>>>>;
+
 #[derive(Default)]
 pub struct NetworkScanDiscovery {
     subnet_ids: Option<Vec<Uuid>>,
     host_naming_fallback: HostNamingFallback,
     snmp_credentials: SnmpCredentialMapping,
+    custom_service_definitions: Vec<CustomServiceDefinition>,
     probe_raw_socket_ports: bool,
+    scan_profile: ScanProfile,
+    exclusions: Vec<ScanExclusion>,
+    hostname_sources: Vec<HostnameSource>,
 }
 
 impl NetworkScanDiscovery {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         subnet_ids: Option<Vec<Uuid>>,
         host_naming_fallback: HostNamingFallback,
         snmp_credentials: SnmpCredentialMapping,
+        custom_service_definitions: Vec<CustomServiceDefinition>,
         probe_raw_socket_ports: bool,
+        scan_profile: ScanProfile,
+        exclusions: Vec<ScanExclusion>,
+        hostname_sources: Vec<HostnameSource>,
     ) -> Self {
         Self {
             subnet_ids,
             host_naming_fallback,
             snmp_credentials,
+            custom_service_definitions,
             probe_raw_socket_ports,
+            scan_profile,
+            exclusions,
+            hostname_sources,
         }
     }
 }
 
 pub struct DeepScanParams<'a> {
     ip: IpAddr,
+    /// Service definitions this session matches against
+    service_definitions: &'a [Box<dyn ServiceDefinition>],
     subnet: &'a Subnet,
     mac: Option<MacAddress>,
     cancel: CancellationToken,
@@ -95,6 +139,44 @@ pub struct DeepScanParams<'a> {
     scan_controller: Arc<ScanConcurrencyController>,
     /// Whether to probe raw-socket ports (9100-9107) during endpoint scanning
     probe_raw_socket_ports: bool,
+    /// TCP ports to scan, from the scan profile
+    tcp_ports: &'a [u16],
+    /// UDP ports to probe, from the scan profile
+    udp_ports: &'a [u16],
+    /// Whether to probe HTTP endpoints, from the scan profile
+    probe_endpoints: bool,
+    /// Abandon the host if scanning takes longer than this, from the scan profile
+    host_timeout: Option<Duration>,
+    /// mDNS/SSDP advertisements gathered from the host's subnet
+    advertisements: SubnetAdvertisements,
+}
+
+/// Non-interfaced scan targets after consulting the gateways' ARP caches
+struct GatewayArpTargets {
+    /// Listed in a gateway ARP cache, so known to be live
+    from_gateway_arp: Vec<(IpAddr, Subnet, MacAddress)>,
+    /// Not listed in any cache; swept with the responsiveness check
+    sweep: Vec<(IpAddr, Subnet)>,
+}
+
+/// Split non-interfaced IPs using harvested gateway ARP entries. Entries age out of a
+/// router's cache after a few minutes, so a missing IP may still be a live but quiet host
+/// and is swept like before rather than skipped.
+fn partition_by_gateway_arp(
+    ips: Vec<(IpAddr, Subnet)>,
+    gateway_arp: &HashMap<IpAddr, MacAddress>,
+) -> GatewayArpTargets {
+    let mut targets = GatewayArpTargets {
+        from_gateway_arp: Vec::new(),
+        sweep: Vec::new(),
+    };
+    for (ip, subnet) in ips {
+        match gateway_arp.get(&ip) {
+            Some(mac) => targets.from_gateway_arp.push((ip, subnet, *mac)),
+            None => targets.sweep.push((ip, subnet)),
+        }
+    }
+    targets
 }
 
 impl CreatesDiscoveredEntities for DiscoveryRunner<NetworkScanDiscovery> {}
@@ -106,7 +188,11 @@ impl RunsDiscovery for DiscoveryRunner<NetworkScanDiscovery> {
             subnet_ids: self.domain.subnet_ids.clone(),
             host_naming_fallback: self.domain.host_naming_fallback,
             snmp_credentials: self.domain.snmp_credentials.clone(),
+            custom_service_definitions: self.domain.custom_service_definitions.clone(),
             probe_raw_socket_ports: self.domain.probe_raw_socket_ports,
+            scan_profile: self.domain.scan_profile.clone(),
+            exclusions: self.domain.exclusions.clone(),
+            hostname_sources: self.domain.hostname_sources.clone(),
         }
     }
 
@@ -224,7 +310,14 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
             )
             .await?;
 
-        let all_ips_with_subnets: Vec<(IpAddr, Subnet)> = subnets
+        let (ndp_subnets, enumerable_subnets): (Vec<Subnet>, Vec<Subnet>) = subnets
+            .into_iter()
+            .partition(|subnet| match subnet.base.cidr {
+                IpCidr::V6(cidr) => 128 - cidr.network_length() > MAX_ENUMERABLE_IPV6_HOST_BITS,
+                IpCidr::V4(_) => false,
+            });
+
+        let all_ips_with_subnets: Vec<(IpAddr, Subnet)> = enumerable_subnets
             .iter()
             .flat_map(|subnet| {
                 self.determine_scan_order(&subnet.base.cidr)
@@ -232,23 +325,133 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
             })
             .collect();
 
+        // Drop excluded addresses before anything (ARP, port scans, SNMP) touches them
+        let mut excluded_count = 0;
+        let all_ips_with_subnets: Vec<(IpAddr, Subnet)> = all_ips_with_subnets
+            .into_iter()
+            .filter(
+                |(ip, _)| match ScanExclusion::find_match(&self.domain.exclusions, ip, None) {
+                    Some(exclusion) => {
+                        session.record_excluded(
+                            exclusion,
+                            ExcludedTarget {
+                                ip: *ip,
+                                mac_address: None,
+                            },
+                        );
+                        excluded_count += 1;
+                        false
+                    }
+                    None => true,
+                },
+            )
+            .collect();
+
+        if excluded_count > 0 {
+            tracing::info!(
+                count = excluded_count,
+                "Skipping addresses covered by scan exclusions"
+            );
+        }
+
         let total_ips = all_ips_with_subnets.len();
 
+        // Ask devices to describe themselves over mDNS and SSDP while ARP runs. Multicast
+        // stays on the link, so only subnets with a local IPv4 address can be queried.
+        let advertisement_sources: Vec<Ipv4Addr> = {
+            let cidrs: HashSet<IpCidr> = all_ips_with_subnets
+                .iter()
+                .map(|(_, subnet)| subnet.base.cidr)
+                .collect();
+            let local_ips: Vec<Ipv4Addr> = datalink::interfaces()
+                .iter()
+                .flat_map(|iface| iface.ips.iter())
+                .filter_map(|ip_net| match ip_net.ip() {
+                    IpAddr::V4(ip) if !ip.is_loopback() => Some(ip),
+                    _ => None,
+                })
+                .collect();
+            cidrs
+                .iter()
+                .filter_map(|cidr| {
+                    local_ips
+                        .iter()
+                        .find(|ip| cidr.contains(&IpAddr::V4(**ip)))
+                        .copied()
+                })
+                .collect()
+        };
+        let advertisements: SubnetAdvertisements = {
+            let cancel = cancel.clone();
+            let query = tokio::spawn(async move {
+                let queries = advertisement_sources
+                    .iter()
+                    .map(|source_ip| advertisements::query_subnet(*source_ip, &cancel));
+                let mut results = HashMap::new();
+                for subnet_results in futures::future::join_all(queries).await {
+                    results.extend(subnet_results);
+                }
+                tracing::info!(
+                    subnets = advertisement_sources.len(),
+                    hosts = results.len(),
+                    "Service advertisement queries complete"
+                );
+                Arc::new(results)
+            });
+            async move { query.await.unwrap_or_default() }
+                .boxed()
+                .shared()
+        };
+
         // Get ARP config
         let use_npcap = self.as_ref().config_store.get_use_npcap_arp().await?;
         let arp_retries = self.as_ref().config_store.get_arp_retries().await?;
         let arp_rate_pps = self.as_ref().config_store.get_arp_rate_pps().await?;
 
+        // Scan profile decides which ports are scanned and can override rate and batch size
+        let scan_settings = self.domain.scan_profile.settings();
+
         // Get port scan rate limit
-        let scan_rate_pps = self.as_ref().config_store.get_scan_rate_pps().await?;
+        let scan_rate_pps = match scan_settings.scan_rate_pps {
+            Some(scan_rate_pps) => scan_rate_pps,
+            None => self.as_ref().config_store.get_scan_rate_pps().await?,
+        };
 
         // Get port batch size from config, clamped to reasonable bounds
-        let port_scan_batch_size = self
-            .as_ref()
-            .config_store
-            .get_port_scan_batch_size()
-            .await?
-            .clamp(16, 1000);
+        let port_scan_batch_size = match scan_settings.port_scan_batch_size {
+            Some(port_scan_batch_size) => port_scan_batch_size,
+            None => {
+                self.as_ref()
+                    .config_store
+                    .get_port_scan_batch_size()
+                    .await?
+            }
+        }
+        .clamp(16, 1000);
+
+        // Built-in definitions plus the organization's custom ones shipped with this request
+        let service_definitions: Arc<Vec<Box<dyn ServiceDefinition>>> = Arc::new(
+            ServiceDefinitionRegistry::with_custom(&self.domain.custom_service_definitions),
+        );
+        let discovery_ports = Service::all_discovery_ports(&service_definitions);
+        let tcp_ports: Arc<Vec<u16>> = Arc::new(
+            scan_settings.tcp_ports.resolve(
+                discovery_ports
+                    .iter()
+                    .filter(|p| p.protocol() == TransportProtocol::Tcp)
+                    .map(|p| p.number()),
+            ),
+        );
+        let udp_ports: Arc<Vec<u16>> = Arc::new(
+            scan_settings.udp_ports.resolve(
+                discovery_ports
+                    .iter()
+                    .filter(|p| p.protocol() == TransportProtocol::Udp)
+                    .map(|p| p.number()),
+            ),
+        );
+        let probe_endpoints = scan_settings.probe_endpoints;
+        let host_timeout = scan_settings.host_timeout_secs.map(Duration::from_secs);
 
         // Check ARP capability once before partitioning
         let arp_available = can_arp_scan(use_npcap);
@@ -270,16 +473,26 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
         let arp_target_count = interfaced_ips.len() as u64;
         let total_rounds = 1 + arp_retries as u64;
         let send_time_per_round_secs = arp_target_count / arp_rate_pps.max(1) as u64;
-        let estimated_arp_duration = Duration::from_secs(
+        let mut estimated_arp_duration = Duration::from_secs(
             total_rounds * (send_time_per_round_secs + arp::ROUND_WAIT.as_secs())
                 + arp::POST_SCAN_RECEIVE.as_secs(),
         );
+        if !ndp_subnets.is_empty() {
+            let estimated_ndp_duration = Duration::from_secs(
+                total_rounds * ndp::SOLICIT_ROUND_WAIT.as_secs()
+                    + ndp::POST_SOLICIT_RECEIVE.as_secs(),
+            );
+            estimated_arp_duration = estimated_arp_duration.max(estimated_ndp_duration);
+        }
         let pipeline_start = Instant::now();
 
         tracing::info!(
             total_ips = total_ips,
             interfaced_ips = interfaced_ips.len(),
             non_interfaced_ips = non_interfaced_ips.len(),
+            ndp_subnets = ndp_subnets.len(),
+            scan_profile = %self.domain.scan_profile,
+            tcp_ports = tcp_ports.len(),
             estimated_arp_secs = estimated_arp_duration.as_secs(),
             arp_method = if cfg!(target_family = "windows") && !use_npcap {
                 "SendARP"
@@ -292,13 +505,13 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
         self.report_discovery_update(DiscoverySessionUpdate::scanning(0))
             .await?;
 
-        // Count unique subnets that will have ARP channels open
+        // Count unique subnets that will have ARP (or NDP) channels open
         let arp_subnet_count: usize = {
             let unique_cidrs: std::collections::HashSet<_> = interfaced_ips
                 .iter()
                 .map(|(_, subnet)| subnet.base.cidr)
                 .collect();
-            unique_cidrs.len()
+            unique_cidrs.len() + ndp_subnets.len()
         };
 
         // Use the port batch size from the coordinated calculation
@@ -467,18 +680,46 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
             }
         }
 
+        // Find live hosts in IPv6 prefixes via the neighbor cache and multicast solicitation
+        if !ndp_subnets.is_empty() {
+            self.start_ndp_discovery(
+                ndp_subnets,
+                &subnet_cidr_to_mac,
+                use_npcap,
+                arp_retries,
+                &host_tx,
+                &cancel,
+            )
+            .await?;
+        }
+
         // Send all non-interfaced IPs directly to deep scanner (no discovery phase).
         // Key insight: ARP filters to responsive hosts before expensive port scanning.
-        // For non-interfaced subnets where ARP isn't possible, just deep scan all IPs
-        // directly - we're going to port scan them anyway.
+        // For non-interfaced subnets where we can't ARP ourselves, borrow the gateways'
+        // ARP caches over SNMP. Hosts found there are known to be live and get their MAC,
+        // so they are queued first; the rest still go through the responsiveness check
+        // in deep_scan_host.
         if !non_interfaced_ips.is_empty() {
+            let queryable_gateways: Vec<IpAddr> = gateway_ips
+                .iter()
+                .filter(|ip| ScanExclusion::find_match(&self.domain.exclusions, ip, None).is_none())
+                .copied()
+                .collect();
+            let gateway_arp = self.harvest_gateway_arp_caches(&queryable_gateways).await;
+
+            let targets = partition_by_gateway_arp(non_interfaced_ips, &gateway_arp);
+
             tracing::info!(
-                count = non_interfaced_ips.len(),
+                count = targets.from_gateway_arp.len() + targets.sweep.len(),
+                from_gateway_arp = targets.from_gateway_arp.len(),
                 "Queuing non-interfaced IPs for deep scan (no ARP available)"
             );
 
             let host_tx = host_tx.clone();
-            for (ip, subnet) in non_interfaced_ips {
+            for (ip, subnet, mac) in targets.from_gateway_arp {
+                let _ = host_tx.send((ip, subnet, Some(mac))).await;
+            }
+            for (ip, subnet) in targets.sweep {
                 let _ = host_tx.send((ip, subnet, None)).await;
             }
         }
@@ -502,7 +743,7 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
 
         // Batch-level progress tracking for smoother UX
         // TCP port scanning is the bulk of deep scan work
-        let batches_per_host = 65535_usize.div_ceil(effective_batch_size);
+        let batches_per_host = tcp_ports.len().div_ceil(effective_batch_size);
         let total_batches = Arc::new(AtomicUsize::new(0));
         let batches_completed = Arc::new(AtomicUsize::new(0));
 
@@ -565,6 +806,22 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
                 host = host_rx.recv(), if !channel_closed => {
                     match host {
                         Some((ip, subnet, mac)) => {
+                            // Hosts found via NDP or gateway ARP caches, and MAC OUI
+                            // exclusions, can only be checked once the host has been seen
+                            if let Some(exclusion) =
+                                ScanExclusion::find_match(&self.domain.exclusions, &ip, mac.as_ref())
+                            {
+                                tracing::info!(ip = %ip, mac = ?mac, "Skipping host covered by scan exclusion");
+                                session.record_excluded(
+                                    exclusion,
+                                    ExcludedTarget {
+                                        ip,
+                                        mac_address: mac,
+                                    },
+                                );
+                                continue;
+                            }
+
                             hosts_discovered.fetch_add(1, Ordering::Relaxed);
                             *last_activity.lock().unwrap() = Instant::now();
 
@@ -585,10 +842,16 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
                                 }
                                 let snmp_credential = self.domain.snmp_credentials.get_credential_for_ip(&ip);
                                 let probe_raw_socket_ports = self.domain.probe_raw_socket_ports;
+                                let tcp_ports = tcp_ports.clone();
+                        let service_definitions = service_definitions.clone();
+                                let service_definitions = service_definitions.clone();
+                                let udp_ports = udp_ports.clone();
+                                let advertisements = advertisements.clone();
                                 pending_scans.push(Box::pin(async move {
                                     let result = self
                                         .deep_scan_host(DeepScanParams {
                                             ip,
+                                            service_definitions: &service_definitions,
                                             subnet: &subnet,
                                             mac,
                                             cancel,
@@ -601,6 +864,11 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
                                             snmp_credential,
                                             scan_controller,
                                             probe_raw_socket_ports,
+                                            tcp_ports: &tcp_ports,
+                                            udp_ports: &udp_ports,
+                                            probe_endpoints,
+                                            host_timeout,
+                                            advertisements,
                                         })
                                         .await;
 
@@ -674,11 +942,16 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
                         let snmp_credential = self.domain.snmp_credentials.get_credential_for_ip(&ip);
                         let scan_controller = scan_controller.clone();
                         let probe_raw_socket_ports = self.domain.probe_raw_socket_ports;
+                        let tcp_ports = tcp_ports.clone();
+                        let service_definitions = service_definitions.clone();
+                        let udp_ports = udp_ports.clone();
+                        let advertisements = advertisements.clone();
 
                         pending_scans.push(Box::pin(async move {
                             let result = self
                                 .deep_scan_host(DeepScanParams {
                                     ip,
+                                    service_definitions: &service_definitions,
                                     subnet: &subnet,
                                     mac,
                                     cancel,
@@ -691,6 +964,11 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
                                     snmp_credential,
                                     scan_controller,
                                     probe_raw_socket_ports,
+                                    tcp_ports: &tcp_ports,
+                                    udp_ports: &udp_ports,
+                                    probe_endpoints,
+                                    host_timeout,
+                                    advertisements,
                                 })
                                 .await;
 
@@ -789,9 +1067,199 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
         Ok(results)
     }
 
+    /// Queue hosts in large IPv6 subnets for deep scanning. Neighbor cache entries are
+    /// queued immediately; interfaced subnets are then solicited via ff02::1 and responders
+    /// are forwarded as they arrive, the same way ARP results are.
+    async fn start_ndp_discovery(
+        &self,
+        subnets: Vec<Subnet>,
+        subnet_cidr_to_mac: &HashMap<IpCidr, Option<MacAddress>>,
+        use_npcap: bool,
+        retries: u32,
+        host_tx: &tokio_mpsc::Sender<(IpAddr, Subnet, Option<MacAddress>)>,
+        cancel: &CancellationToken,
+    ) -> Result<(), Error> {
+        let neighbors = self
+            .as_ref()
+            .utils
+            .get_ipv6_neighbors()
+            .await
+            .unwrap_or_else(|e| {
+                tracing::debug!(error = %e, "Failed to read IPv6 neighbor cache");
+                Vec::new()
+            });
+        let ndp_available = ndp::is_available(use_npcap);
+
+        tracing::info!(
+            subnets = subnets.len(),
+            cached_neighbors = neighbors.len(),
+            solicitation = ndp_available,
+            "Starting NDP discovery"
+        );
+
+        for subnet in subnets {
+            if cancel.is_cancelled() {
+                return Err(Error::msg("Discovery session was cancelled"));
+            }
+
+            let IpCidr::V6(prefix) = subnet.base.cidr else {
+                continue;
+            };
+
+            // Neighbor cache hits are known-live - queue them straight away. Sent from a
+            // task since the deep scan loop that drains the channel hasn't started yet.
+            let mut known = HashSet::new();
+            let cached: Vec<NdpScanResult> = neighbors
+                .iter()
+                .filter(|n| prefix.contains(&n.ip) && known.insert(n.ip))
+                .cloned()
+                .collect();
+
+            tracing::debug!(
+                cidr = %prefix,
+                from_neighbor_cache = cached.len(),
+                "Queuing IPv6 neighbors"
+            );
+
+            let cache_tx = host_tx.clone();
+            let cache_subnet = subnet.clone();
+            tokio::spawn(async move {
+                for NdpScanResult { ip, mac } in cached {
+                    if cache_tx
+                        .send((IpAddr::V6(ip), cache_subnet.clone(), Some(mac)))
+                        .await
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            });
+
+            let Some(source_mac) = subnet_cidr_to_mac.get(&subnet.base.cidr).and_then(|m| *m)
+            else {
+                tracing::info!(
+                    cidr = %prefix,
+                    "Not interfaced with IPv6 subnet, only neighbor cache entries will be scanned"
+                );
+                continue;
+            };
+
+            if !ndp_available {
+                continue;
+            }
+
+            // Same interface matching as ARP - MAC plus an address in the target prefix,
+            // which is also the source address so hosts answer from their in-prefix address
+            let pnet_source_mac = pnet::util::MacAddr::from(source_mac.bytes());
+            let source = datalink::interfaces().into_iter().find_map(|iface| {
+                if iface.mac.unwrap_or_default() != pnet_source_mac {
+                    return None;
+                }
+                let source_ip = iface.ips.iter().find_map(|ip_net| match ip_net.ip() {
+                    IpAddr::V6(ip) if prefix.contains(&ip) => Some(ip),
+                    _ => None,
+                })?;
+                Some((iface, source_ip))
+            });
+
+            let Some((interface, source_ip)) = source else {
+                tracing::warn!(mac = %source_mac, cidr = %prefix, "No interface found for MAC, skipping NDP solicitation");
+                continue;
+            };
+
+            match ndp::solicit_subnet(&interface, source_ip, source_mac, prefix, known, retries) {
+                Ok(ndp_rx) => {
+                    let host_tx = host_tx.clone();
+                    std::thread::spawn(move || {
+                        let mut forwarded = 0u64;
+                        while let Ok(NdpScanResult { ip, mac }) = ndp_rx.recv() {
+                            if host_tx
+                                .blocking_send((IpAddr::V6(ip), subnet.clone(), Some(mac)))
+                                .is_err()
+                            {
+                                break;
+                            }
+                            forwarded += 1;
+                        }
+                        tracing::debug!(cidr = %prefix, forwarded, "NDP forwarder completed");
+                    });
+                }
+                Err(e) => {
+                    tracing::warn!(cidr = %prefix, error = %e, "NDP solicitation failed to start");
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Walk the ARP cache (ipNetToMediaTable) of each SNMP-reachable gateway.
+    /// Returns IP→MAC pairs for hosts on subnets the daemon has no interface on.
+    async fn harvest_gateway_arp_caches(
+        &self,
+        gateway_ips: &[IpAddr],
+    ) -> HashMap<IpAddr, MacAddress> {
+        let mut ip_to_mac = HashMap::new();
+
+        for gateway_ip in gateway_ips {
+            let Some(credential) = self
+                .domain
+                .snmp_credentials
+                .get_credential_for_ip(gateway_ip)
+            else {
+                continue;
+            };
+
+            match timeout(
+                snmp::SNMP_WALK_TIMEOUT,
+                snmp::walk_arp_table(*gateway_ip, &credential),
+            )
+            .await
+            {
+                Ok(Ok(entries)) => {
+                    tracing::debug!(
+                        gateway = %gateway_ip,
+                        count = entries.len(),
+                        "Gateway ARP cache harvested"
+                    );
+                    ip_to_mac.extend(
+                        entries
+                            .into_iter()
+                            .map(|entry| (IpAddr::V4(entry.ip), entry.mac)),
+                    );
+                }
+                Ok(Err(e)) => {
+                    tracing::debug!(gateway = %gateway_ip, error = %e, "Gateway ARP walk failed");
+                }
+                Err(_) => {
+                    tracing::debug!(gateway = %gateway_ip, "Gateway ARP walk timeout");
+                }
+            }
+        }
+
+        ip_to_mac
+    }
+
     async fn deep_scan_host(&self, params: DeepScanParams<'_>) -> Result<Option<Host>, Error> {
+        let ip = params.ip;
+        match params.host_timeout {
+            Some(host_timeout) => timeout(host_timeout, self.scan_host(params))
+                .await
+                .unwrap_or_else(|_| {
+                    Err(Error::msg(format!(
+                        "Scan of {} timed out after {}s",
+                        ip,
+                        host_timeout.as_secs()
+                    )))
+                }),
+            None => self.scan_host(params).await,
+        }
+    }
+
+    async fn scan_host(&self, params: DeepScanParams<'_>) -> Result<Option<Host>, Error> {
         let DeepScanParams {
             ip,
+            service_definitions,
             subnet,
             mac,
             cancel,
@@ -804,6 +1272,11 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
             snmp_credential,
             scan_controller,
             probe_raw_socket_ports,
+            tcp_ports,
+            udp_ports,
+            probe_endpoints,
+            host_timeout: _,
+            advertisements,
         } = params;
 
         if cancel.is_cancelled() {
@@ -817,7 +1290,7 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
         // This avoids full 65k port scans on hosts that aren't online.
         let mut responsiveness_ports: HashSet<u16> = HashSet::new();
         if mac.is_none() {
-            let discovery_ports: Vec<u16> = Service::all_discovery_ports()
+            let discovery_ports: Vec<u16> = Service::all_discovery_ports(service_definitions)
                 .iter()
                 .filter(|p| p.is_tcp())
                 .map(|p| p.number())
@@ -860,7 +1333,9 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
             responsiveness_ports.extend(responsive_ports.iter().map(|(p, _)| p.number()));
         }
 
-        let remaining_tcp_ports: Vec<u16> = (1..=65535)
+        let remaining_tcp_ports: Vec<u16> = tcp_ports
+            .iter()
+            .copied()
             .filter(|p| !responsiveness_ports.contains(p))
             .collect();
 
@@ -921,25 +1396,32 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
             scan_rate_pps,
             subnet.base.cidr,
             gateway_ips.to_vec(),
+            service_definitions,
+            Some(udp_ports),
         )
         .await?;
         open_ports.extend(udp_ports);
 
-        let mut ports_to_check = open_ports.clone();
-        let endpoint_only_ports = Service::endpoint_only_ports();
-        ports_to_check.extend(endpoint_only_ports);
-        ports_to_check.sort_by_key(|p| (p.number(), p.protocol()));
-        ports_to_check.dedup();
+        let endpoint_responses = if probe_endpoints {
+            let mut ports_to_check = open_ports.clone();
+            let endpoint_only_ports = Service::endpoint_only_ports(service_definitions);
+            ports_to_check.extend(endpoint_only_ports);
+            ports_to_check.sort_by_key(|p| (p.number(), p.protocol()));
+            ports_to_check.dedup();
 
-        let endpoint_responses = scan_endpoints(
-            ip,
-            cancel.clone(),
-            Some(ports_to_check),
-            Some(use_https_ports),
-            effective_batch_size,
-            probe_raw_socket_ports,
-        )
-        .await?;
+            scan_endpoints(
+                ip,
+                cancel.clone(),
+                Service::all_discovery_endpoints(service_definitions),
+                Some(ports_to_check),
+                Some(use_https_ports),
+                effective_batch_size,
+                probe_raw_socket_ports,
+            )
+            .await?
+        } else {
+            Vec::new()
+        };
 
         for endpoint_response in &endpoint_responses {
             let port = endpoint_response.endpoint.port_type;
@@ -951,6 +1433,52 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
         open_ports.sort_by_key(|p| (p.number(), p.protocol()));
         open_ports.dedup();
 
+        // Capture TLS certificates from every open TCP port that completes a handshake,
+        // not just known HTTPS ports, so LDAPS/IMAPS/etc. are covered too
+        let certificates = if probe_endpoints {
+            let tls_ports: Vec<u16> = open_ports
+                .iter()
+                .filter(|p| p.is_tcp() && (probe_raw_socket_ports || !p.is_raw_socket()))
+                .map(|p| p.number())
+                .collect();
+            tls::capture_certificates(ip, tls_ports, &cancel).await
+        } else {
+            HashMap::new()
+        };
+
+        // Grab banners from open TCP ports that didn't already identify as HTTP or TLS
+        let banners = if probe_endpoints {
+            let banner_ports: Vec<u16> = open_ports
+                .iter()
+                .filter(|p| p.is_tcp() && (probe_raw_socket_ports || !p.is_raw_socket()))
+                .filter(|p| {
+                    !endpoint_responses
+                        .iter()
+                        .any(|r| r.endpoint.port_type == **p)
+                })
+                .map(|p| p.number())
+                .filter(|port| !certificates.contains_key(port))
+                .collect();
+            banners::grab_banners(ip, banner_ports, &cancel).await
+        } else {
+            Vec::new()
+        };
+
+        // OS fingerprinting: the SYN-ACK of any open TCP port, plus the NTLM version an
+        // SMB server reports when endpoints are being probed. The SMB computer name is
+        // also a hostname source, so it's asked for whenever that source is enabled.
+        let tcp_fingerprint = match open_ports.iter().find(|p| p.is_tcp()) {
+            Some(port) => tcp_fingerprint::capture(ip, port.number()).await,
+            None => None,
+        };
+        let wants_smb_name = self.domain.hostname_sources.contains(&HostnameSource::Smb);
+        let smb_info =
+            if (probe_endpoints || wants_smb_name) && open_ports.contains(&PortType::Samba) {
+                smb::probe(ip).await
+            } else {
+                None
+            };
+
         if cancel.is_cancelled() {
             return Err(Error::msg("Discovery was cancelled"));
         }
@@ -958,92 +1486,154 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
         // SNMP polling - gather system info, interface table, and neighbor discovery
         // Only attempt if UDP 161 is open (saves time on hosts without SNMP)
         let snmp_port_open = open_ports.contains(&PortType::Snmp);
-        let (snmp_system_info, snmp_if_entries, lldp_neighbors, cdp_neighbors) =
-            if let Some(credential) = &snmp_credential
-                && snmp_port_open
-            {
-                match snmp::query_system_info(ip, credential).await {
-                    Ok(system_info) => {
-                        tracing::debug!(
-                            ip = %ip,
-                            sys_name = ?system_info.sys_name,
-                            "SNMP system info retrieved"
-                        );
+        let (
+            snmp_system_info,
+            snmp_if_entries,
+            lldp_neighbors,
+            cdp_neighbors,
+            fdb_entries,
+            physical_entities,
+        ) = if let Some(credential) = &snmp_credential
+            && snmp_port_open
+        {
+            match snmp::query_system_info(ip, credential).await {
+                Ok(system_info) => {
+                    tracing::debug!(
+                        ip = %ip,
+                        sys_name = ?system_info.sys_name,
+                        "SNMP system info retrieved"
+                    );
 
-                        // Walk interface table
-                        let if_entries = match snmp::walk_if_table(ip, credential).await {
-                            Ok(entries) => {
-                                tracing::debug!(
-                                    ip = %ip,
-                                    if_count = entries.len(),
-                                    "SNMP ifTable walked"
-                                );
-                                entries
-                            }
-                            Err(e) => {
-                                tracing::debug!(ip = %ip, error = %e, "SNMP ifTable walk failed");
-                                Vec::new()
-                            }
-                        };
-
-                        // Query LLDP neighbors
-                        let lldp = match snmp::query_lldp_neighbors(ip, credential).await {
-                            Ok(neighbors) => {
-                                tracing::debug!(
-                                    ip = %ip,
-                                    count = neighbors.len(),
-                                    "LLDP neighbors discovered"
-                                );
-                                neighbors
-                            }
-                            Err(e) => {
-                                tracing::debug!(ip = %ip, error = %e, "LLDP query failed");
-                                Vec::new()
-                            }
-                        };
-
-                        // Query CDP neighbors (Cisco devices)
-                        let cdp = match snmp::query_cdp_neighbors(ip, credential).await {
-                            Ok(neighbors) => {
-                                tracing::debug!(
-                                    ip = %ip,
-                                    count = neighbors.len(),
-                                    "CDP neighbors discovered"
-                                );
-                                neighbors
-                            }
-                            Err(e) => {
-                                tracing::debug!(ip = %ip, error = %e, "CDP query failed");
-                                Vec::new()
-                            }
-                        };
+                    // Walk interface table
+                    let if_entries = match snmp::walk_if_table(ip, credential).await {
+                        Ok(entries) => {
+                            tracing::debug!(
+                                ip = %ip,
+                                if_count = entries.len(),
+                                "SNMP ifTable walked"
+                            );
+                            entries
+                        }
+                        Err(e) => {
+                            tracing::debug!(ip = %ip, error = %e, "SNMP ifTable walk failed");
+                            Vec::new()
+                        }
+                    };
 
-                        (Some(system_info), if_entries, lldp, cdp)
-                    }
-                    Err(e) => {
-                        tracing::debug!(ip = %ip, error = %e, "SNMP query failed");
-                        (None, Vec::new(), Vec::new(), Vec::new())
-                    }
+                    // Query LLDP neighbors
+                    let lldp = match snmp::query_lldp_neighbors(ip, credential).await {
+                        Ok(neighbors) => {
+                            tracing::debug!(
+                                ip = %ip,
+                                count = neighbors.len(),
+                                "LLDP neighbors discovered"
+                            );
+                            neighbors
+                        }
+                        Err(e) => {
+                            tracing::debug!(ip = %ip, error = %e, "LLDP query failed");
+                            Vec::new()
+                        }
+                    };
+
+                    // Query CDP neighbors (Cisco devices)
+                    let cdp = match snmp::query_cdp_neighbors(ip, credential).await {
+                        Ok(neighbors) => {
+                            tracing::debug!(
+                                ip = %ip,
+                                count = neighbors.len(),
+                                "CDP neighbors discovered"
+                            );
+                            neighbors
+                        }
+                        Err(e) => {
+                            tracing::debug!(ip = %ip, error = %e, "CDP query failed");
+                            Vec::new()
+                        }
+                    };
+
+                    // Walk bridge forwarding table (switches only)
+                    let fdb = match snmp::walk_fdb_table(ip, credential).await {
+                        Ok(entries) => {
+                            tracing::debug!(
+                                ip = %ip,
+                                count = entries.len(),
+                                "Bridge FDB walked"
+                            );
+                            entries
+                        }
+                        Err(e) => {
+                            tracing::debug!(ip = %ip, error = %e, "Bridge FDB walk failed");
+                            Vec::new()
+                        }
+                    };
+
+                    // Walk ENTITY-MIB physical table for hardware inventory
+                    let entities = match snmp::walk_entity_table(ip, credential).await {
+                        Ok(entities) => {
+                            tracing::debug!(
+                                ip = %ip,
+                                count = entities.len(),
+                                "ENTITY-MIB physical table walked"
+                            );
+                            entities
+                        }
+                        Err(e) => {
+                            tracing::debug!(ip = %ip, error = %e, "ENTITY-MIB walk failed");
+                            Vec::new()
+                        }
+                    };
+
+                    (Some(system_info), if_entries, lldp, cdp, fdb, entities)
                 }
-            } else {
-                (None, Vec::new(), Vec::new(), Vec::new())
-            };
+                Err(e) => {
+                    tracing::debug!(ip = %ip, error = %e, "SNMP query failed");
+                    (
+                        None,
+                        Vec::new(),
+                        Vec::new(),
+                        Vec::new(),
+                        Vec::new(),
+                        Vec::new(),
+                    )
+                }
+            }
+        } else {
+            (
+                None,
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+            )
+        };
+
+        let advertisements = advertisements.await.get(&ip).cloned().unwrap_or_default();
 
         tracing::info!(
             ip = %ip,
             open_ports = open_ports.len(),
             endpoints = endpoint_responses.len(),
+            tls_certificates = certificates.len(),
+            banners = banners.len(),
             snmp_interfaces = snmp_if_entries.len(),
+            mdns_services = advertisements.mdns.len(),
+            upnp_devices = advertisements.upnp.len(),
             "Deep scan complete"
         );
 
-        // Use SNMP sysName for hostname if DNS lookup fails
-        let dns_hostname = self.get_hostname_for_ip(ip).await?;
-        let hostname = dns_hostname.or_else(|| {
-            snmp_system_info
-                .as_ref()
-                .and_then(|info| info.sys_name.clone())
-        });
+        let resolved_hostname = self
+            .resolve_hostname(
+                ip,
+                smb_info.as_ref(),
+                snmp_system_info
+                    .as_ref()
+                    .and_then(|info| info.sys_name.as_deref()),
+            )
+            .await;
+        let hostname_source = resolved_hostname.as_ref().map(|(_, source)| *source);
+        let hostname = resolved_hostname.map(|(name, _)| name);
 
         let interface = Interface::new(InterfaceBase {
             network_id: subnet.base.network_id,
@@ -1053,15 +1643,20 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
             ip_address: ip,
             mac_address: mac, // MAC populated from ARP discovery
             position: 0,
+            first_seen: None,
+            last_seen: None,
         });
 
         if let Ok(Some((mut host, interfaces, ports, services))) = self
             .process_host(
                 ServiceMatchBaselineParams {
+                    service_definitions,
                     subnet,
                     interface: &interface,
                     all_ports: &open_ports,
                     endpoint_responses: &endpoint_responses,
+                    banners: &banners,
+                    advertisements: &advertisements,
                     virtualization: &None,
                 },
                 hostname,
@@ -1075,9 +1670,27 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
                 host.base.sys_object_id = info.sys_object_id.clone();
                 host.base.sys_location = info.sys_location.clone();
                 host.base.sys_contact = info.sys_contact.clone();
+                host.base.hardware_inventory =
+                    Self::convert_snmp_physical_entities(&physical_entities);
             }
 
-            // Convert SNMP ifTable entries to IfEntry entities with LLDP/CDP data
+            host.base.hostname_source = hostname_source;
+            host.base.os = OsFingerprint::infer(&OsSignals {
+                tcp: tcp_fingerprint,
+                ssh_banner: banners
+                    .iter()
+                    .find(|b| b.banner.starts_with("SSH-"))
+                    .map(|b| b.banner.clone()),
+                ntlm: smb_info.as_ref().and_then(|info| info.version),
+                sys_descr: host.base.sys_descr.clone(),
+                http_servers: endpoint_responses
+                    .iter()
+                    .filter_map(|r| r.headers.get("server").cloned())
+                    .collect(),
+                mac_vendor: mac.and_then(Self::mac_vendor),
+            });
+
+            // Convert SNMP ifTable entries to IfEntry entities with LLDP/CDP/FDB data
             let if_entries: Vec<IfEntry> = snmp_if_entries
                 .into_iter()
                 .map(|entry| {
@@ -1086,15 +1699,36 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
                         subnet.base.network_id,
                         &lldp_neighbors,
                         &cdp_neighbors,
+                        &fdb_entries,
                     )
                 })
                 .collect();
 
+            // Reference the daemon's port IDs, the server resolves them to stored ports
+            let certificates: Vec<TlsCertificate> = ports
+                .iter()
+                .filter(|p| p.base.port_type.is_tcp())
+                .filter_map(|p| {
+                    let mut base = certificates.get(&p.base.port_type.number())?.clone();
+                    base.network_id = subnet.base.network_id;
+                    base.port_id = p.id;
+                    Some(TlsCertificate::new(base))
+                })
+                .collect();
+
             let services_count = services.len();
             let if_entries_count = if_entries.len();
 
             if let Ok(host_response) = self
-                .create_host(host, interfaces, ports, services, if_entries, &cancel)
+                .create_host(
+                    host,
+                    interfaces,
+                    ports,
+                    services,
+                    if_entries,
+                    certificates,
+                    &cancel,
+                )
                 .await
             {
                 tracing::info!(
@@ -1114,7 +1748,40 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
         Ok(None)
     }
 
-    /// Convert SNMP ifTable entry to IfEntry entity with LLDP/CDP neighbor data
+    /// Company the MAC address's OUI is registered to
+    fn mac_vendor(mac: MacAddress) -> Option<String> {
+        let oui_db = Oui::default().ok()?;
+        Oui::lookup_by_mac(&oui_db, &mac.to_string())
+            .ok()
+            .flatten()
+            .map(|entry| entry.company_name.clone())
+    }
+
+    /// Convert ENTITY-MIB rows into the host's hardware inventory, keeping only
+    /// chassis, modules, power supplies and transceivers
+    fn convert_snmp_physical_entities(entities: &[snmp::PhysicalEntity]) -> Vec<HardwareComponent> {
+        entities
+            .iter()
+            .filter_map(|e| {
+                let class = HardwareComponentClass::from_ent_physical_class(
+                    e.class?,
+                    e.descr.as_deref(),
+                    e.serial_num.is_some(),
+                )?;
+                Some(HardwareComponent {
+                    physical_index: e.index,
+                    class,
+                    name: e.name.clone(),
+                    description: e.descr.clone(),
+                    model_name: e.model_name.clone(),
+                    serial_number: e.serial_num.clone(),
+                    manufacturer: e.mfg_name.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Convert SNMP ifTable entry to IfEntry entity with LLDP/CDP neighbor and FDB data
     /// Uses Uuid::nil() for host_id as placeholder - server will set correct host_id
     fn convert_snmp_if_entry(
         &self,
@@ -1122,6 +1789,7 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
         network_id: Uuid,
         lldp_neighbors: &[snmp::LldpNeighbor],
         cdp_neighbors: &[snmp::CdpNeighbor],
+        fdb_entries: &[snmp::FdbEntry],
     ) -> IfEntry {
         use crate::server::snmp_credentials::resolution::lldp::{LldpChassisId, LldpPortId};
 
@@ -1155,6 +1823,15 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
                 .map(|id| LldpPortId::InterfaceName(id.clone()))
         });
 
+        // MACs learned on this port (deduplicated across VLANs)
+        let mut fdb_macs: Vec<MacAddress> = fdb_entries
+            .iter()
+            .filter(|f| f.if_index == entry.if_index)
+            .map(|f| f.mac)
+            .collect();
+        fdb_macs.sort_by_key(|m| m.bytes());
+        fdb_macs.dedup();
+
         IfEntry::new(IfEntryBase {
             host_id: Uuid::nil(), // Placeholder - server will set correct host_id
             network_id,
@@ -1180,17 +1857,49 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
             cdp_port_id: cdp_neighbor.and_then(|n| n.remote_port_id.clone()),
             cdp_platform: cdp_neighbor.and_then(|n| n.remote_platform.clone()),
             cdp_address: cdp_neighbor.and_then(|n| n.remote_address),
+            // Bridge FDB data - resolved to access-port edges in topology
+            fdb_macs,
         })
     }
 
-    async fn get_hostname_for_ip(&self, ip: IpAddr) -> Result<Option<String>, Error> {
+    /// Ask each of the discovery's hostname sources in turn, returning the first name found
+    /// along with the source that produced it
+    async fn resolve_hostname(
+        &self,
+        ip: IpAddr,
+        smb_info: Option<&smb::SmbHostInfo>,
+        snmp_sys_name: Option<&str>,
+    ) -> Option<(String, HostnameSource)> {
+        for source in &self.domain.hostname_sources {
+            let name = match source {
+                HostnameSource::Dns => self.get_hostname_for_ip(ip).await,
+                HostnameSource::NetBios => names::netbios_name(ip).await,
+                HostnameSource::Smb => smb_info.and_then(|info| {
+                    info.dns_computer_name
+                        .clone()
+                        .or_else(|| info.netbios_computer_name.clone())
+                }),
+                HostnameSource::Llmnr => names::llmnr_name(ip).await,
+                HostnameSource::Mdns => names::mdns_name(ip).await,
+                HostnameSource::Snmp => snmp_sys_name.map(str::to_string),
+            };
+
+            if let Some(name) = name.filter(|name| !name.trim().is_empty()) {
+                tracing::trace!(ip = %ip, hostname = %name, source = %source, "Resolved hostname");
+                return Some((name, *source));
+            }
+        }
+        None
+    }
+
+    async fn get_hostname_for_ip(&self, ip: IpAddr) -> Option<String> {
         match timeout(Duration::from_millis(800), async {
             tokio::task::spawn_blocking(move || dns_lookup::lookup_addr(&ip)).await?
         })
         .await
         {
-            Ok(Ok(hostname)) => Ok(Some(hostname)),
-            _ => Ok(None),
+            Ok(Ok(hostname)) => Some(hostname),
+            _ => None,
         }
     }
 
@@ -1202,7 +1911,13 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
         ips.sort_by_key(|ip| {
             let last_octet = match ip {
                 IpAddr::V4(ipv4) => ipv4.octets()[3],
-                IpAddr::V6(_) => return 9999, // IPv6 gets lowest priority for now
+                // Statically numbered IPv6 hosts sit at the bottom of the range (::1, ::2, ...)
+                IpAddr::V6(ipv6) => {
+                    return match ipv6.segments()[7] {
+                        0 => 9998,
+                        host => host.min(9997),
+                    };
+                }
             };
 
             match last_octet {
@@ -1247,3 +1962,45 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::shared::storage::traits::Storable;
+    use crate::server::subnets::r#impl::base::SubnetBase;
+    use std::net::Ipv4Addr;
+
+    #[test]
+    fn test_gateway_arp_lists_cached_ips_first_and_sweeps_the_rest() {
+        let covered = Subnet::new(SubnetBase::default());
+        let uncovered = Subnet::new(SubnetBase::default());
+        let ip = |last| IpAddr::V4(Ipv4Addr::new(10, 0, 0, last));
+        let mac = MacAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+
+        let ips = vec![
+            (ip(1), covered.clone()),
+            (ip(2), covered.clone()),
+            (ip(3), covered.clone()),
+            (ip(4), uncovered.clone()),
+            (ip(5), uncovered.clone()),
+        ];
+        let gateway_arp = HashMap::from([(ip(2), mac)]);
+
+        let targets = partition_by_gateway_arp(ips, &gateway_arp);
+
+        assert_eq!(targets.from_gateway_arp.len(), 1);
+        assert_eq!(targets.from_gateway_arp[0].0, ip(2));
+        assert_eq!(targets.from_gateway_arp[0].2, mac);
+
+        // A quiet host whose entry aged out of the cache is still swept, even though
+        // another address on its subnet was cached
+        let swept: Vec<(IpAddr, Uuid)> = targets
+            .sweep
+            .iter()
+            .map(|(ip, subnet)| (*ip, subnet.id))
+            .collect();
+        assert!(swept.contains(&(ip(1), covered.id)));
+        assert!(swept.contains(&(ip(3), covered.id)));
+        assert_eq!(swept.len(), 4);
+    }
+}
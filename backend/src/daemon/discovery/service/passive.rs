@@ -0,0 +1,475 @@
+use crate::daemon::discovery::service::base::{
+    CreatesDiscoveredEntities, DiscoversNetworkedEntities, DiscoveryRunner, RunsDiscovery,
+};
+use crate::daemon::utils::base::DaemonUtils;
+use crate::daemon::utils::passive::{self, PassiveObservation, PassiveSource};
+use crate::server::daemons::r#impl::api::DaemonDiscoveryRequest;
+use crate::server::discovery::r#impl::types::{DiscoveryType, HostNamingFallback};
+use crate::server::hosts::r#impl::base::Host;
+use crate::server::interfaces::r#impl::base::{Interface, InterfaceBase};
+use crate::server::services::definitions::ServiceDefinitionRegistry;
+use crate::server::services::r#impl::advertisements::{MdnsAdvertisement, ServiceAdvertisements};
+use crate::server::services::r#impl::base::ServiceMatchBaselineParams;
+use crate::server::subnets::r#impl::base::Subnet;
+use crate::server::subnets::r#impl::types::SubnetTypeDiscriminants;
+use anyhow::Error;
+use async_trait::async_trait;
+use futures::future::try_join_all;
+use mac_address::MacAddress;
+use pnet::datalink;
+use std::collections::{BTreeSet, HashMap};
+use std::net::IpAddr;
+use std::time::{Duration, Instant};
+use strum::IntoDiscriminant;
+use tokio::sync::mpsc as tokio_mpsc;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+/// How often listening progress is reported while waiting for the window to close
+const PROGRESS_TICK: Duration = Duration::from_secs(5);
+
+// Progress phase weights (must sum to 100)
+const PROGRESS_LISTEN_PHASE: u8 = 90; // 0-90%: Listening
+const PROGRESS_CREATE_PHASE: u8 = 10; // 90-100%: Creating hosts
+
+#[derive(Default)]
+pub struct PassiveDiscovery {
+    subnet_ids: Option<Vec<Uuid>>,
+    host_naming_fallback: HostNamingFallback,
+    listen_duration_secs: u64,
+}
+
+impl PassiveDiscovery {
+    pub fn new(
+        subnet_ids: Option<Vec<Uuid>>,
+        host_naming_fallback: HostNamingFallback,
+        listen_duration_secs: u64,
+    ) -> Self {
+        Self {
+            subnet_ids,
+            host_naming_fallback,
+            listen_duration_secs,
+        }
+    }
+}
+
+/// Everything heard from one device over the listening window
+#[derive(Debug, Default)]
+struct PassiveHost {
+    ips: BTreeSet<IpAddr>,
+    sources: BTreeSet<PassiveSource>,
+    hostname: Option<String>,
+    vendor_class: Option<String>,
+    mdns_services: BTreeSet<String>,
+    ssdp_server: Option<String>,
+    ssdp_location: Option<String>,
+    system_description: Option<String>,
+    platform: Option<String>,
+}
+
+impl PassiveHost {
+    fn merge(&mut self, observation: PassiveObservation) {
+        self.ips.extend(observation.ip);
+        self.sources.insert(observation.source);
+        self.mdns_services.extend(observation.mdns_services);
+
+        // First value heard wins - devices rarely change these mid-window
+        self.hostname = self.hostname.take().or(observation.hostname);
+        self.vendor_class = self.vendor_class.take().or(observation.vendor_class);
+        self.ssdp_server = self.ssdp_server.take().or(observation.ssdp_server);
+        self.ssdp_location = self.ssdp_location.take().or(observation.ssdp_location);
+        self.system_description = self
+            .system_description
+            .take()
+            .or(observation.system_description);
+        self.platform = self.platform.take().or(observation.platform);
+    }
+
+    /// Summarize what identified the device, for the host description
+    fn description(&self) -> String {
+        let sources: Vec<String> = self.sources.iter().map(|s| s.to_string()).collect();
+        let mut parts = vec![format!("Passively observed via {}", sources.join(", "))];
+
+        if let Some(vendor_class) = &self.vendor_class {
+            parts.push(format!("DHCP vendor class: {}", vendor_class));
+        }
+        if !self.mdns_services.is_empty() {
+            let services: Vec<&str> = self.mdns_services.iter().map(String::as_str).collect();
+            parts.push(format!("mDNS services: {}", services.join(", ")));
+        }
+        if let Some(server) = &self.ssdp_server {
+            parts.push(format!("UPnP server: {}", server));
+        }
+        if let Some(location) = &self.ssdp_location {
+            parts.push(format!("UPnP description: {}", location));
+        }
+        if let Some(platform) = &self.platform {
+            parts.push(format!("Platform: {}", platform));
+        }
+
+        parts.join("\n")
+    }
+}
+
+impl CreatesDiscoveredEntities for DiscoveryRunner<PassiveDiscovery> {}
+
+#[async_trait]
+impl RunsDiscovery for DiscoveryRunner<PassiveDiscovery> {
+    fn discovery_type(&self) -> DiscoveryType {
+        DiscoveryType::Passive {
+            subnet_ids: self.domain.subnet_ids.clone(),
+            host_naming_fallback: self.domain.host_naming_fallback,
+            listen_duration_secs: self.domain.listen_duration_secs,
+        }
+    }
+
+    async fn discover(
+        &self,
+        request: DaemonDiscoveryRequest,
+        cancel: CancellationToken,
+    ) -> Result<(), Error> {
+        let subnets: Vec<Subnet> = self.discover_create_subnets(&cancel).await?;
+
+        self.start_discovery(request).await?;
+
+        let discovery_result = self
+            .listen_and_process_hosts(subnets, cancel.clone())
+            .await
+            .map(|_| ());
+
+        self.finish_discovery(discovery_result, cancel.clone())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DiscoversNetworkedEntities for DiscoveryRunner<PassiveDiscovery> {
+    async fn get_gateway_ips(&self) -> Result<Vec<IpAddr>, Error> {
+        self.as_ref()
+            .utils
+            .get_own_routing_table_gateway_ips()
+            .await
+    }
+
+    async fn discover_create_subnets(
+        &self,
+        cancel: &CancellationToken,
+    ) -> Result<Vec<Subnet>, Error> {
+        let daemon_id = self.as_ref().config_store.get_id().await?;
+        let network_id = self
+            .as_ref()
+            .config_store
+            .get_network_id()
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Network ID not set"))?;
+
+        // Target specific subnets if provided in discovery type
+        if let Some(subnet_ids) = &self.domain.subnet_ids {
+            let all_subnets: Vec<Subnet> = self
+                .as_ref()
+                .api_client
+                .get("/api/v1/subnets", "Failed to get subnets")
+                .await?;
+            return Ok(all_subnets
+                .into_iter()
+                .filter(|s| subnet_ids.contains(&s.id))
+                .collect());
+        }
+
+        // Otherwise listen on every interfaced subnet, except docker bridges (only
+        // container traffic crosses them, which docker discovery already covers)
+        let interface_filter = self.as_ref().config_store.get_interfaces().await?;
+        let (_, subnets, _) = self
+            .as_ref()
+            .utils
+            .get_own_interfaces(
+                self.discovery_type(),
+                daemon_id,
+                network_id,
+                &interface_filter,
+            )
+            .await?;
+
+        let subnets: Vec<Subnet> = subnets
+            .into_iter()
+            .filter(|s| s.base.subnet_type.discriminant() != SubnetTypeDiscriminants::DockerBridge)
+            .collect();
+        let subnet_futures = subnets
+            .iter()
+            .map(|subnet| self.create_subnet(subnet, cancel));
+        try_join_all(subnet_futures).await
+    }
+}
+
+impl DiscoveryRunner<PassiveDiscovery> {
+    async fn listen_and_process_hosts(
+        &self,
+        subnets: Vec<Subnet>,
+        cancel: CancellationToken,
+    ) -> Result<Vec<Host>, Error> {
+        let session = self.as_ref().get_session().await?;
+
+        let interface_filter = self.as_ref().config_store.get_interfaces().await?;
+        let (_, _, subnet_cidr_to_mac) = self
+            .as_ref()
+            .utils
+            .get_own_interfaces(
+                self.discovery_type(),
+                session.info.daemon_id,
+                session.info.network_id,
+                &interface_filter,
+            )
+            .await?;
+
+        // Only local segments can be heard - find the interface attached to each subnet,
+        // matching by MAC and address like ARP scanning does
+        let mut listen_interfaces: HashMap<String, (datalink::NetworkInterface, MacAddress)> =
+            HashMap::new();
+        for subnet in &subnets {
+            let cidr = subnet.base.cidr;
+            let Some(own_mac) = subnet_cidr_to_mac.get(&cidr).and_then(|m| *m) else {
+                tracing::warn!(
+                    cidr = %cidr,
+                    "Not interfaced with subnet, passive discovery can't hear its traffic"
+                );
+                continue;
+            };
+
+            let pnet_mac = pnet::util::MacAddr::from(own_mac.bytes());
+            let interface = datalink::interfaces().into_iter().find(|iface| {
+                iface.mac.unwrap_or_default() == pnet_mac
+                    && iface.ips.iter().any(|ip| cidr.contains(&ip.ip()))
+            });
+
+            match interface {
+                Some(interface) => {
+                    listen_interfaces.insert(interface.name.clone(), (interface, own_mac));
+                }
+                None => {
+                    tracing::warn!(mac = %own_mac, cidr = %cidr, "No interface found for MAC, skipping subnet");
+                }
+            }
+        }
+
+        if listen_interfaces.is_empty() {
+            return Err(Error::msg(
+                "No local interfaces on the selected subnets to listen on",
+            ));
+        }
+
+        let listen_duration = Duration::from_secs(self.domain.listen_duration_secs);
+        let (observation_tx, mut observation_rx) =
+            tokio_mpsc::unbounded_channel::<PassiveObservation>();
+
+        for (name, (interface, own_mac)) in listen_interfaces {
+            let listener_rx =
+                passive::listen(&interface, Some(own_mac), listen_duration, cancel.clone())?;
+
+            tracing::info!(
+                interface = %name,
+                listen_secs = listen_duration.as_secs(),
+                "Listening for passive discovery traffic"
+            );
+
+            let observation_tx = observation_tx.clone();
+            std::thread::spawn(move || {
+                while let Ok(observation) = listener_rx.recv() {
+                    if observation_tx.send(observation).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        // Channel closes once every listener's window has elapsed
+        drop(observation_tx);
+
+        let started = Instant::now();
+        let mut heard: HashMap<MacAddress, PassiveHost> = HashMap::new();
+        let mut progress_tick = tokio::time::interval(PROGRESS_TICK);
+
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => {
+                    return Err(Error::msg("Discovery session was cancelled"));
+                }
+                observation = observation_rx.recv() => match observation {
+                    Some(observation) => {
+                        if !heard.contains_key(&observation.mac) {
+                            tracing::debug!(
+                                mac = %observation.mac,
+                                ip = ?observation.ip,
+                                source = %observation.source,
+                                "Passive: device heard"
+                            );
+                        }
+                        heard.entry(observation.mac).or_default().merge(observation);
+                    }
+                    None => break,
+                },
+                _ = progress_tick.tick() => {
+                    let elapsed = started.elapsed().as_secs_f64() / listen_duration.as_secs_f64();
+                    let percent = (elapsed.min(1.0) * PROGRESS_LISTEN_PHASE as f64) as u8;
+                    self.report_scanning_progress(percent).await?;
+                }
+            }
+        }
+
+        tracing::info!(
+            devices = heard.len(),
+            "Passive listening complete, creating hosts"
+        );
+
+        let total = heard.len().max(1);
+        let mut hosts = Vec::new();
+
+        for (index, (mac, passive_host)) in heard.into_iter().enumerate() {
+            if cancel.is_cancelled() {
+                return Err(Error::msg("Discovery session was cancelled"));
+            }
+
+            // IPv4 sorts first, so prefer it when a device was heard on both families
+            let Some((ip, subnet)) = passive_host.ips.iter().find_map(|ip| {
+                subnets
+                    .iter()
+                    .find(|s| s.base.cidr.contains(ip))
+                    .map(|s| (*ip, s))
+            }) else {
+                tracing::debug!(mac = %mac, ips = ?passive_host.ips, "No address on a selected subnet, skipping device");
+                continue;
+            };
+
+            if let Some(host) = self
+                .create_passive_host(mac, ip, subnet, passive_host, &cancel)
+                .await?
+            {
+                hosts.push(host);
+            }
+
+            let percent = PROGRESS_LISTEN_PHASE as usize
+                + (index + 1) * PROGRESS_CREATE_PHASE as usize / total;
+            self.report_scanning_progress(percent as u8).await?;
+        }
+
+        Ok(hosts)
+    }
+
+    async fn create_passive_host(
+        &self,
+        mac: MacAddress,
+        ip: IpAddr,
+        subnet: &Subnet,
+        passive_host: PassiveHost,
+        cancel: &CancellationToken,
+    ) -> Result<Option<Host>, Error> {
+        let interface = Interface::new(InterfaceBase {
+            network_id: subnet.base.network_id,
+            host_id: Uuid::nil(), // Placeholder - server will set correct host_id
+            name: None,
+            subnet_id: subnet.id,
+            ip_address: ip,
+            mac_address: Some(mac),
+            position: 0,
+            first_seen: None,
+            last_seen: None,
+        });
+
+        // Only service types heard in mDNS traffic are known - instances, TXT records and
+        // UPnP descriptions would need queries, and passive discovery never transmits
+        let advertisements = ServiceAdvertisements {
+            mdns: passive_host
+                .mdns_services
+                .iter()
+                .map(|service_type| MdnsAdvertisement {
+                    service_type: service_type.clone(),
+                    ..Default::default()
+                })
+                .collect(),
+            upnp: Vec::new(),
+        };
+
+        // No ports were scanned, so only address-based services (e.g. gateways) and ones
+        // identified by advertised mDNS service types can match
+        let Some((mut host, interfaces, ports, services)) = self
+            .process_host(
+                ServiceMatchBaselineParams {
+                    service_definitions: &ServiceDefinitionRegistry::all_service_definitions(),
+                    subnet,
+                    interface: &interface,
+                    all_ports: &Vec::new(),
+                    endpoint_responses: &Vec::new(),
+                    banners: &Vec::new(),
+                    advertisements: &advertisements,
+                    virtualization: &None,
+                },
+                passive_host.hostname.clone(),
+                self.domain.host_naming_fallback,
+            )
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        host.base.description = Some(passive_host.description());
+        host.base.sys_descr = passive_host.system_description;
+
+        match self
+            .create_host(
+                host,
+                interfaces,
+                ports,
+                services,
+                Vec::new(),
+                Vec::new(),
+                cancel,
+            )
+            .await
+        {
+            Ok(host_response) => {
+                tracing::info!(ip = %ip, mac = %mac, "Host created");
+                Ok(Some(host_response.to_host()))
+            }
+            Err(e) => {
+                tracing::warn!(ip = %ip, mac = %mac, error = %e, "Host creation failed");
+                Ok(None)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_passive_host_merges_observations() {
+        let mac = MacAddress::new([0x00, 0x1a, 0x2b, 0x01, 0x02, 0x03]);
+        let mut host = PassiveHost::default();
+
+        let mut dhcp = PassiveObservation::new(
+            PassiveSource::Dhcp,
+            mac,
+            Some("192.168.1.20".parse().unwrap()),
+        );
+        dhcp.hostname = Some("thermostat".to_string());
+        dhcp.vendor_class = Some("udhcp 1.30.1".to_string());
+        host.merge(dhcp);
+
+        let mut mdns =
+            PassiveObservation::new(PassiveSource::Mdns, mac, Some("fe80::1".parse().unwrap()));
+        mdns.hostname = Some("thermostat-2".to_string());
+        mdns.mdns_services = vec!["_hap._tcp".to_string()];
+        host.merge(mdns);
+
+        assert_eq!(host.hostname.as_deref(), Some("thermostat"));
+        assert_eq!(
+            host.ips.first(),
+            Some(&"192.168.1.20".parse::<IpAddr>().unwrap())
+        );
+        assert_eq!(
+            host.description(),
+            "Passively observed via DHCP, mDNS\nDHCP vendor class: udhcp 1.30.1\nmDNS services: _hap._tcp"
+        );
+    }
+}
@@ -0,0 +1,768 @@
+use anyhow::anyhow;
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use futures::future::try_join_all;
+use secrecy::ExposeSecret;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::OnceLock;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::daemon::discovery::service::base::{
+    CreatesDiscoveredEntities, DiscoversNetworkedEntities, DiscoveryRunner, RunsDiscovery,
+};
+use crate::daemon::utils::base::DaemonUtils;
+use crate::daemon::utils::proxmox::{
+    Guest, GuestAddress, GuestKind, GuestNic, NodeNetwork, ProxmoxClient, ProxmoxNode,
+};
+use crate::server::bindings::r#impl::base::Binding;
+use crate::server::daemons::r#impl::api::DaemonDiscoveryRequest;
+use crate::server::discovery::r#impl::types::{DiscoveryType, ProxmoxConnection};
+use crate::server::hosts::r#impl::base::{Host, HostBase};
+use crate::server::hosts::r#impl::virtualization::{HostVirtualization, ProxmoxVirtualization};
+use crate::server::interfaces::r#impl::base::{Interface, InterfaceBase};
+use crate::server::ports::r#impl::base::{Port, PortType};
+use crate::server::services::definitions::proxmox::Proxmox;
+use crate::server::services::r#impl::base::{Service, ServiceBase};
+use crate::server::services::r#impl::definitions::ServiceDefinition;
+use crate::server::services::r#impl::patterns::MatchDetails;
+use crate::server::shared::storage::traits::Storable;
+use crate::server::shared::types::entities::{DiscoveryMetadata, EntitySource};
+use crate::server::shared::types::metadata::HasId;
+use crate::server::subnets::r#impl::base::Subnet;
+use crate::server::subnets::r#impl::types::SubnetType;
+
+/// Port the Proxmox VE API and web UI listen on
+const PROXMOX_API_PORT: u16 = 8006;
+
+pub struct ProxmoxDiscovery {
+    client: OnceLock<ProxmoxClient>,
+    connection: ProxmoxConnection,
+}
+
+/// A node's host as created on the server, with the Proxmox service its guests run under
+struct NodeHost {
+    proxmox_service_id: Uuid,
+}
+
+#[async_trait]
+impl RunsDiscovery for DiscoveryRunner<ProxmoxDiscovery> {
+    fn discovery_type(&self) -> DiscoveryType {
+        DiscoveryType::Proxmox(self.domain.connection.clone())
+    }
+
+    async fn discover(
+        &self,
+        request: DaemonDiscoveryRequest,
+        cancel: CancellationToken,
+    ) -> Result<(), Error> {
+        self.domain
+            .client
+            .set(ProxmoxClient::new(
+                &self.domain.connection.url,
+                &self.domain.connection.token_id,
+                self.domain.connection.token_secret.expose_secret(),
+                self.domain.connection.verify_tls,
+            )?)
+            .map_err(|_| anyhow!("Failed to set Proxmox client"))?;
+
+        // Fail before the session starts if the API can't be reached or the token is rejected
+        let nodes: Vec<ProxmoxNode> = self
+            .client()?
+            .nodes()
+            .await?
+            .into_iter()
+            .filter(|n| n.is_online())
+            .collect();
+
+        self.start_discovery(request).await?;
+
+        let discovery_result = self.discover_cluster(nodes, &cancel).await;
+
+        self.finish_discovery(discovery_result, cancel.clone())
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl ProxmoxDiscovery {
+    pub fn new(connection: ProxmoxConnection) -> Self {
+        Self {
+            client: OnceLock::new(),
+            connection,
+        }
+    }
+}
+
+impl CreatesDiscoveredEntities for DiscoveryRunner<ProxmoxDiscovery> {}
+
+#[async_trait]
+impl DiscoversNetworkedEntities for DiscoveryRunner<ProxmoxDiscovery> {
+    async fn get_gateway_ips(&self) -> Result<Vec<IpAddr>, Error> {
+        self.as_ref()
+            .utils
+            .get_own_routing_table_gateway_ips()
+            .await
+    }
+
+    /// Creates the daemon's own subnets and those configured on each node's interfaces, and
+    /// returns them along with every subnet the server already knows, which guest IPs are
+    /// matched to
+    async fn discover_create_subnets(
+        &self,
+        cancel: &CancellationToken,
+    ) -> Result<Vec<Subnet>, Error> {
+        let daemon_id = self.as_ref().config_store.get_id().await?;
+        let network_id = self
+            .as_ref()
+            .config_store
+            .get_network_id()
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Network ID not set"))?;
+
+        let interface_filter = self.as_ref().config_store.get_interfaces().await?;
+        let (_, host_subnets, _) = self
+            .as_ref()
+            .utils
+            .get_own_interfaces(
+                self.discovery_type(),
+                daemon_id,
+                network_id,
+                &interface_filter,
+            )
+            .await?;
+
+        let client = self.client()?;
+        let mut node_subnets: Vec<Subnet> = Vec::new();
+        for node in client.nodes().await?.iter().filter(|n| n.is_online()) {
+            let network = match client.node_network(&node.node).await {
+                Ok(network) => network,
+                Err(e) => {
+                    tracing::warn!(node = %node.node, error = %e, "Could not read node network");
+                    continue;
+                }
+            };
+
+            for entry in &network {
+                for ip_network in entry.networks() {
+                    let Some(mut subnet) = Subnet::from_discovery(
+                        entry.iface.clone(),
+                        &ip_network,
+                        daemon_id,
+                        &self.discovery_type(),
+                        network_id,
+                    ) else {
+                        continue;
+                    };
+
+                    // Proxmox bridges are named vmbrN, which isn't a pattern the name
+                    // heuristic knows, but they're what guests attach to
+                    if entry.is_bridge() && subnet.base.subnet_type == SubnetType::Unknown {
+                        subnet.base.subnet_type = SubnetType::Lan;
+                    }
+
+                    if !node_subnets.iter().any(|s| s.base.cidr == subnet.base.cidr) {
+                        node_subnets.push(subnet);
+                    }
+                }
+            }
+        }
+
+        let subnets: Vec<Subnet> = [host_subnets, node_subnets].concat();
+        let subnet_futures = subnets
+            .iter()
+            .map(|subnet| self.create_subnet(subnet, cancel));
+        let mut subnets = try_join_all(subnet_futures).await?;
+
+        // Guests often sit on VLANs the nodes have no address on, which network discovery
+        // may already have found
+        let known_subnets: Vec<Subnet> = match self
+            .as_ref()
+            .api_client
+            .get("/api/v1/subnets", "Failed to get subnets")
+            .await
+        {
+            Ok(known_subnets) => known_subnets,
+            Err(e) => {
+                tracing::warn!(error = %e, "Could not fetch known subnets, using the daemon's own");
+                Vec::new()
+            }
+        };
+        for subnet in known_subnets {
+            if subnet.base.network_id == network_id && !subnets.iter().any(|s| s.id == subnet.id) {
+                subnets.push(subnet);
+            }
+        }
+
+        Ok(subnets)
+    }
+}
+
+impl DiscoveryRunner<ProxmoxDiscovery> {
+    fn client(&self) -> Result<&ProxmoxClient, Error> {
+        self.domain
+            .client
+            .get()
+            .ok_or_else(|| anyhow!("Proxmox client unavailable"))
+    }
+
+    async fn discover_cluster(
+        &self,
+        nodes: Vec<ProxmoxNode>,
+        cancel: &CancellationToken,
+    ) -> Result<()> {
+        let subnets = self.discover_create_subnets(cancel).await?;
+
+        let client = self.client()?;
+        let mut guests: HashMap<&str, Vec<(GuestKind, Guest)>> = HashMap::new();
+        for node in &nodes {
+            for kind in [GuestKind::Qemu, GuestKind::Lxc] {
+                match client.guests(&node.node, kind).await {
+                    Ok(node_guests) => guests.entry(node.node.as_str()).or_default().extend(
+                        node_guests
+                            .into_iter()
+                            .filter(|g| !g.template)
+                            .map(|g| (kind, g)),
+                    ),
+                    Err(e) => tracing::warn!(
+                        node = %node.node,
+                        kind = ?kind,
+                        error = %e,
+                        "Could not list guests"
+                    ),
+                }
+            }
+        }
+
+        let total = nodes.len() + guests.values().map(Vec::len).sum::<usize>();
+        let mut done = 0;
+        let mut node_count = 0;
+        let mut discovered = 0;
+
+        self.report_scanning_progress(0).await?;
+
+        for node in &nodes {
+            if cancel.is_cancelled() {
+                return Err(Error::msg("Proxmox discovery session was cancelled"));
+            }
+
+            let node_host = match self.create_node_host(node, &subnets, cancel).await {
+                Ok(node_host) => node_host,
+                Err(e) => {
+                    tracing::warn!(node = %node.node, error = %e, "Node processing error");
+                    None
+                }
+            };
+            if node_host.is_some() {
+                node_count += 1;
+            }
+
+            done += 1;
+            self.report_scanning_progress((done * 100 / total.max(1)) as u8)
+                .await?;
+
+            for (kind, guest) in guests.get(node.node.as_str()).into_iter().flatten() {
+                if cancel.is_cancelled() {
+                    return Err(Error::msg("Proxmox discovery session was cancelled"));
+                }
+
+                if let Some(node_host) = &node_host {
+                    match self
+                        .process_guest(&node.node, *kind, guest, node_host, &subnets, cancel)
+                        .await
+                    {
+                        Ok(true) => discovered += 1,
+                        Ok(false) => {}
+                        Err(e) => tracing::warn!(
+                            node = %node.node,
+                            vmid = %guest.vmid,
+                            error = %e,
+                            "Guest processing error"
+                        ),
+                    }
+                }
+
+                done += 1;
+                self.report_scanning_progress((done * 100 / total.max(1)) as u8)
+                    .await?;
+            }
+        }
+
+        tracing::info!(
+            nodes = %node_count,
+            guests = %discovered,
+            "Proxmox scan complete"
+        );
+
+        Ok(())
+    }
+
+    /// Create the host for a node, with an interface per configured address on a known subnet
+    /// and the Proxmox service its guests are virtualized by, bound to the API port
+    async fn create_node_host(
+        &self,
+        node: &ProxmoxNode,
+        subnets: &[Subnet],
+        cancel: &CancellationToken,
+    ) -> Result<Option<NodeHost>> {
+        let daemon_id = self.as_ref().config_store.get_id().await?;
+        let network_id = self
+            .as_ref()
+            .config_store
+            .get_network_id()
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Network ID not set"))?;
+
+        let network = self.client()?.node_network(&node.node).await?;
+        let interfaces = node_interfaces(&network, subnets, network_id);
+        if interfaces.is_empty() {
+            tracing::warn!(
+                node = %node.node,
+                "Skipping node, none of its addresses are on a known subnet"
+            );
+            return Ok(None);
+        }
+
+        let host = Host::new(HostBase {
+            name: node.node.clone(),
+            network_id,
+            hostname: Some(node.node.clone()),
+            source: EntitySource::Discovery {
+                metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
+            },
+            ..Default::default()
+        });
+
+        let api_port = Port::new_hostless(PortType::new_tcp(PROXMOX_API_PORT));
+
+        let proxmox_service = Service::new(ServiceBase {
+            name: ServiceDefinition::name(&Proxmox).to_string(),
+            service_definition: Box::new(Proxmox),
+            bindings: interfaces
+                .iter()
+                .map(|i| Binding::new_port_serviceless(api_port.id, Some(i.id)))
+                .collect(),
+            host_id: host.id,
+            tags: Vec::new(),
+            network_id,
+            virtualization: None,
+            source: EntitySource::DiscoveryWithMatch {
+                metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
+                details: MatchDetails::new_certain("Node reported by the Proxmox API"),
+            },
+            position: 0,
+            proxy_targets: Vec::new(),
+        });
+
+        let host_response = self
+            .create_host(
+                host,
+                interfaces,
+                vec![api_port],
+                vec![proxmox_service],
+                vec![],
+                vec![],
+                cancel,
+            )
+            .await?;
+
+        let proxmox_service_id = host_response
+            .services
+            .iter()
+            .find(|s| s.base.service_definition.id() == Proxmox.id())
+            .map(|s| s.id)
+            .ok_or_else(|| anyhow!("Proxmox service was not created for node"))?;
+
+        Ok(Some(NodeHost { proxmox_service_id }))
+    }
+
+    /// Create the host for a VM or container, with an interface per address on a known
+    /// subnet, virtualized by its node's Proxmox service. Returns whether a host was created.
+    async fn process_guest(
+        &self,
+        node: &str,
+        kind: GuestKind,
+        guest: &Guest,
+        node_host: &NodeHost,
+        subnets: &[Subnet],
+        cancel: &CancellationToken,
+    ) -> Result<bool> {
+        let daemon_id = self.as_ref().config_store.get_id().await?;
+        let network_id = self
+            .as_ref()
+            .config_store
+            .get_network_id()
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Network ID not set"))?;
+
+        let client = self.client()?;
+        let config = client.guest_config(node, kind, guest.vmid).await?;
+        let nics = GuestNic::from_config(&config);
+
+        // VMs without the guest agent and stopped guests only have what their config says
+        let addresses = if guest.is_running() {
+            client
+                .guest_addresses(node, kind, guest.vmid)
+                .await
+                .unwrap_or_else(|e| {
+                    tracing::debug!(vmid = %guest.vmid, error = %e, "No guest addresses reported");
+                    Vec::new()
+                })
+        } else {
+            Vec::new()
+        };
+
+        let interfaces = guest_interfaces(&nics, &addresses, subnets, network_id);
+        if interfaces.is_empty() {
+            tracing::debug!(
+                vmid = %guest.vmid,
+                "Skipping guest, none of its addresses are on a known subnet"
+            );
+            return Ok(false);
+        }
+
+        let hostname = match kind {
+            GuestKind::Lxc => config
+                .get("hostname")
+                .and_then(|h| h.as_str())
+                .map(String::from),
+            GuestKind::Qemu if guest.is_running() => {
+                client.guest_hostname(node, guest.vmid).await.ok()
+            }
+            GuestKind::Qemu => None,
+        };
+
+        let host = guest_host(
+            kind,
+            guest,
+            hostname,
+            node_host,
+            network_id,
+            EntitySource::Discovery {
+                metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
+            },
+        );
+
+        self.create_host(host, interfaces, vec![], vec![], vec![], vec![], cancel)
+            .await?;
+
+        Ok(true)
+    }
+}
+
+/// Interfaces for the addresses configured on a node that are on a known subnet
+fn node_interfaces(
+    network: &[NodeNetwork],
+    subnets: &[Subnet],
+    network_id: Uuid,
+) -> Vec<Interface> {
+    network
+        .iter()
+        .flat_map(|entry| {
+            entry
+                .networks()
+                .into_iter()
+                .map(move |n| (entry.iface.clone(), n.ip()))
+        })
+        .filter_map(|(name, ip)| {
+            let subnet = find_subnet(subnets, ip)?;
+            Some(Interface::new(InterfaceBase {
+                network_id,
+                host_id: Uuid::nil(), // Placeholder - server will set correct host_id
+                subnet_id: subnet.id,
+                ip_address: ip,
+                mac_address: None,
+                name: Some(name),
+                position: 0,
+                first_seen: None,
+                last_seen: None,
+            }))
+        })
+        .collect()
+}
+
+/// Interfaces for a guest's config NICs: static addresses plus whatever the running guest
+/// reports on the matching interface, limited to known subnets
+fn guest_interfaces(
+    nics: &[GuestNic],
+    addresses: &[GuestAddress],
+    subnets: &[Subnet],
+    network_id: Uuid,
+) -> Vec<Interface> {
+    let mut interfaces: Vec<Interface> = Vec::new();
+    for nic in nics {
+        let mut ips: Vec<IpAddr> = nic.static_ips.clone();
+        for address in addresses.iter().filter(|a| a.belongs_to(nic)) {
+            ips.extend(address.ips.iter().copied());
+        }
+        ips.sort();
+        ips.dedup();
+
+        let name = match nic.attachment() {
+            Some(attachment) => format!("{} ({})", nic.key, attachment),
+            None => nic.key.clone(),
+        };
+
+        for ip in ips {
+            if ip.is_loopback() || ip.is_unspecified() {
+                continue;
+            }
+            let Some(subnet) = find_subnet(subnets, ip) else {
+                continue;
+            };
+            interfaces.push(Interface::new(InterfaceBase {
+                network_id,
+                host_id: Uuid::nil(), // Placeholder - server will set correct host_id
+                subnet_id: subnet.id,
+                ip_address: ip,
+                mac_address: nic.mac,
+                name: Some(name.clone()),
+                position: 0,
+                first_seen: None,
+                last_seen: None,
+            }));
+        }
+    }
+    interfaces
+}
+
+/// Host for a guest, virtualized by its node's Proxmox service
+fn guest_host(
+    kind: GuestKind,
+    guest: &Guest,
+    hostname: Option<String>,
+    node_host: &NodeHost,
+    network_id: Uuid,
+    source: EntitySource,
+) -> Host {
+    let name = guest.name.clone().unwrap_or_else(|| match kind {
+        GuestKind::Qemu => format!("VM {}", guest.vmid),
+        GuestKind::Lxc => format!("CT {}", guest.vmid),
+    });
+
+    Host::new(HostBase {
+        name,
+        network_id,
+        hostname,
+        virtualization: Some(HostVirtualization::Proxmox(ProxmoxVirtualization {
+            vm_name: guest.name.clone(),
+            vm_id: Some(guest.vmid.to_string()),
+            service_id: node_host.proxmox_service_id,
+        })),
+        source,
+        ..Default::default()
+    })
+}
+
+/// The non-container subnet an address belongs to
+fn find_subnet(subnets: &[Subnet], ip: IpAddr) -> Option<&Subnet> {
+    subnets
+        .iter()
+        .find(|s| !s.base.subnet_type.is_container_network() && s.base.cidr.contains(&ip))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::subnets::r#impl::base::SubnetBase;
+
+    fn subnet(cidr: &str) -> Subnet {
+        Subnet::new(SubnetBase {
+            cidr: cidr.parse().unwrap(),
+            subnet_type: SubnetType::Lan,
+            ..Default::default()
+        })
+    }
+
+    fn guest_config(json: &str) -> HashMap<String, serde_json::Value> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_guests_map_to_proxmox_virtualization() {
+        // GET /nodes/pve1/qemu and /nodes/pve1/lxc
+        let vms: Vec<Guest> = serde_json::from_str(
+            r#"[
+                {"vmid": 100, "name": "web", "status": "running", "template": 0},
+                {"vmid": "101", "status": "stopped"}
+            ]"#,
+        )
+        .unwrap();
+        let containers: Vec<Guest> =
+            serde_json::from_str(r#"[{"vmid": 200, "name": "dns", "status": "running"}]"#).unwrap();
+
+        let node_host = NodeHost {
+            proxmox_service_id: Uuid::new_v4(),
+        };
+        let network_id = Uuid::new_v4();
+        let host = |kind, guest: &Guest| {
+            guest_host(
+                kind,
+                guest,
+                None,
+                &node_host,
+                network_id,
+                EntitySource::Unknown,
+            )
+        };
+
+        let web = host(GuestKind::Qemu, &vms[0]);
+        assert_eq!(web.base.name, "web");
+        assert_eq!(web.base.network_id, network_id);
+        assert_eq!(
+            web.base.virtualization,
+            Some(HostVirtualization::Proxmox(ProxmoxVirtualization {
+                vm_name: Some("web".to_string()),
+                vm_id: Some("100".to_string()),
+                service_id: node_host.proxmox_service_id,
+            }))
+        );
+
+        // Unnamed guests are named after their kind and VMID
+        let unnamed = host(GuestKind::Qemu, &vms[1]);
+        assert_eq!(unnamed.base.name, "VM 101");
+        assert_eq!(
+            unnamed.base.virtualization,
+            Some(HostVirtualization::Proxmox(ProxmoxVirtualization {
+                vm_name: None,
+                vm_id: Some("101".to_string()),
+                service_id: node_host.proxmox_service_id,
+            }))
+        );
+
+        let dns = host(GuestKind::Lxc, &containers[0]);
+        assert_eq!(dns.base.name, "dns");
+        assert!(matches!(
+            dns.base.virtualization,
+            Some(HostVirtualization::Proxmox(ProxmoxVirtualization { service_id, .. }))
+                if service_id == node_host.proxmox_service_id
+        ));
+    }
+
+    #[test]
+    fn test_node_interfaces_link_hypervisor_to_known_subnets() {
+        // GET /nodes/pve1/network
+        let network: Vec<NodeNetwork> = serde_json::from_str(
+            r#"[
+                {"iface": "eno1", "type": "eth"},
+                {"iface": "vmbr0", "type": "bridge", "cidr": "10.0.0.5/24"},
+                {"iface": "vmbr1", "type": "bridge", "address": "192.168.50.2", "netmask": "255.255.255.0"}
+            ]"#,
+        )
+        .unwrap();
+        let lan = subnet("10.0.0.0/24");
+        let subnets = vec![lan.clone()];
+
+        // vmbr1 isn't on a known subnet
+        let interfaces = node_interfaces(&network, &subnets, lan.base.network_id);
+        assert_eq!(interfaces.len(), 1);
+        assert_eq!(interfaces[0].base.name.as_deref(), Some("vmbr0"));
+        assert_eq!(interfaces[0].base.subnet_id, lan.id);
+        assert_eq!(
+            interfaces[0].base.ip_address,
+            "10.0.0.5".parse::<IpAddr>().unwrap()
+        );
+
+        let subnets = vec![lan.clone(), subnet("192.168.50.0/24")];
+        let interfaces = node_interfaces(&network, &subnets, lan.base.network_id);
+        assert_eq!(interfaces.len(), 2);
+    }
+
+    #[test]
+    fn test_guest_interfaces_use_agent_addresses() {
+        // GET /nodes/pve1/qemu/100/config
+        let config = guest_config(
+            r#"{
+                "name": "web",
+                "net0": "virtio=BC:24:11:00:00:01,bridge=vmbr0,tag=20",
+                "net1": "virtio=BC:24:11:00:00:02,bridge=vmbr1"
+            }"#,
+        );
+        let nics = GuestNic::from_config(&config);
+
+        // Parsed from GET /nodes/pve1/qemu/100/agent/network-get-interfaces
+        let addresses = vec![
+            GuestAddress {
+                name: "lo".to_string(),
+                mac: Some("00:00:00:00:00:00".parse().unwrap()),
+                ips: vec!["127.0.0.1".parse().unwrap(), "::1".parse().unwrap()],
+            },
+            GuestAddress {
+                name: "ens18".to_string(),
+                mac: Some("bc:24:11:00:00:01".parse().unwrap()),
+                ips: vec!["10.0.20.7".parse().unwrap(), "fe80::1".parse().unwrap()],
+            },
+            GuestAddress {
+                name: "ens19".to_string(),
+                mac: Some("bc:24:11:00:00:02".parse().unwrap()),
+                ips: vec!["172.16.9.9".parse().unwrap()],
+            },
+            // Bridges inside the guest aren't config NICs
+            GuestAddress {
+                name: "docker0".to_string(),
+                mac: Some("02:42:ac:11:00:01".parse().unwrap()),
+                ips: vec!["10.0.20.200".parse().unwrap()],
+            },
+        ];
+
+        let subnets = vec![subnet("10.0.20.0/24")];
+        let interfaces = guest_interfaces(&nics, &addresses, &subnets, Uuid::new_v4());
+        assert_eq!(interfaces.len(), 1);
+        assert_eq!(
+            interfaces[0].base.ip_address,
+            "10.0.20.7".parse::<IpAddr>().unwrap()
+        );
+        assert_eq!(
+            interfaces[0].base.mac_address,
+            Some("BC:24:11:00:00:01".parse().unwrap())
+        );
+        assert_eq!(
+            interfaces[0].base.name.as_deref(),
+            Some("net0 (vmbr0 VLAN 20)")
+        );
+
+        // Without an agent only the static addresses from the config are known
+        assert!(guest_interfaces(&nics, &[], &subnets, Uuid::new_v4()).is_empty());
+    }
+
+    #[test]
+    fn test_container_interfaces_merge_static_and_reported_addresses() {
+        // GET /nodes/pve1/lxc/200/config
+        let config = guest_config(
+            r#"{
+                "hostname": "dns",
+                "net0": "name=eth0,bridge=vmbr0,hwaddr=BC:24:11:00:00:03,ip=10.0.20.5/24,type=veth"
+            }"#,
+        );
+        let nics = GuestNic::from_config(&config);
+        let subnets = vec![subnet("10.0.20.0/24")];
+
+        // Stopped container: static address only
+        let interfaces = guest_interfaces(&nics, &[], &subnets, Uuid::new_v4());
+        assert_eq!(interfaces.len(), 1);
+        assert_eq!(
+            interfaces[0].base.ip_address,
+            "10.0.20.5".parse::<IpAddr>().unwrap()
+        );
+
+        // Running container reports the same address plus a second one
+        let addresses = vec![GuestAddress {
+            name: "eth0".to_string(),
+            mac: Some("bc:24:11:00:00:03".parse().unwrap()),
+            ips: vec!["10.0.20.5".parse().unwrap(), "10.0.20.6".parse().unwrap()],
+        }];
+        let ips: Vec<IpAddr> = guest_interfaces(&nics, &addresses, &subnets, Uuid::new_v4())
+            .iter()
+            .map(|i| i.base.ip_address)
+            .collect();
+        assert_eq!(
+            ips,
+            vec![
+                "10.0.20.5".parse::<IpAddr>().unwrap(),
+                "10.0.20.6".parse::<IpAddr>().unwrap()
+            ]
+        );
+    }
+}
@@ -10,7 +10,11 @@ use crate::{
         ports::r#impl::base::{Port, PortType},
         services::{
             definitions::scanopy_daemon::ScanopyDaemon,
-            r#impl::{base::ServiceBase, definitions::ServiceDefinition, patterns::MatchDetails},
+            r#impl::{
+                base::ServiceBase,
+                definitions::ServiceDefinition,
+                patterns::{MatchConfidence, MatchDetails},
+            },
         },
         shared::{
             storage::traits::Storable,
@@ -22,7 +26,10 @@ use crate::{
 use crate::{
     daemon::utils::base::DaemonUtils,
     server::{
-        hosts::r#impl::base::{Host, HostBase},
+        hosts::r#impl::{
+            base::{Host, HostBase},
+            os::{OsFamily, OsFingerprint},
+        },
         services::r#impl::base::Service,
     },
 };
@@ -369,6 +376,21 @@ impl DiscoveryRunner<SelfReportDiscovery> {
             management_url: None,
             chassis_id: None,
             snmp_credential_id: None,
+            hardware_inventory: Vec::new(),
+            first_seen: None,
+            last_seen: None,
+            missed_scans: 0,
+            offline: false,
+            auto_hidden: false,
+            // The daemon knows what it's running on
+            os: OsFamily::from_target_os(std::env::consts::OS).map(|family| OsFingerprint {
+                family,
+                version: None,
+                confidence: MatchConfidence::Certain,
+                evidence: vec!["Reported by the daemon running on the host".to_string()],
+            }),
+            // Read from the local system rather than looked up over the network
+            hostname_source: None,
         };
 
         // Ports to create with the host
@@ -401,6 +423,7 @@ impl DiscoveryRunner<SelfReportDiscovery> {
                 details: MatchDetails::new_certain("Scanopy Daemon self-report"),
             },
             position: 0,
+            proxy_targets: Vec::new(),
         });
 
         services.push(daemon_service);
@@ -418,8 +441,16 @@ impl DiscoveryRunner<SelfReportDiscovery> {
 
         // Pass interfaces and ports separately - server will create them with the correct host_id
         tracing::debug!("Creating host with interfaces, ports, and services");
-        self.create_host(host, interfaces.clone(), ports, services, vec![], &cancel)
-            .await?;
+        self.create_host(
+            host,
+            interfaces.clone(),
+            ports,
+            services,
+            vec![],
+            vec![],
+            &cancel,
+        )
+        .await?;
 
         Ok(())
     }
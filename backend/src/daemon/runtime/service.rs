@@ -233,6 +233,9 @@ impl DaemonRuntimeService {
                             .initiate_session(payload.into())
                             .await;
                     }
+
+                    // Server is reachable again - flush anything journaled while it wasn't
+                    self.discovery_manager.upload_buffered_entities().await;
                 }
                 Err(e) => {
                     // Check if daemon has been put on standby (plan downgrade)
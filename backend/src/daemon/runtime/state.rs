@@ -160,6 +160,7 @@ impl DaemonState {
                 error: None,
                 started_at: s.info.started_at,
                 finished_at: None,
+                excluded_targets: s.excluded_targets(),
             });
         }
         drop(session);
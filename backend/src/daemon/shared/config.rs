@@ -108,6 +108,10 @@ pub struct DaemonCli {
     /// Restrict daemon to specific network interface(s). Comma-separated for multiple (e.g., eth0,eth1). Leave empty for all interfaces. Only applies to network discovery
     #[arg(long, value_delimiter = ',')]
     interfaces: Option<Vec<String>>,
+
+    /// Keep discovered entities on disk until the server confirms them, so discovery results survive daemon restarts and server outages
+    #[arg(long)]
+    persist_entity_buffer: Option<bool>,
 }
 
 /// Unified configuration struct that handles both startup and runtime config
@@ -166,6 +170,9 @@ pub struct AppConfig {
     /// Network interfaces to restrict scanning to. Empty means all interfaces.
     #[serde(default)]
     pub interfaces: Vec<String>,
+    /// Journal pending discovered entities to disk so they're uploaded once the server is reachable
+    #[serde(default)]
+    pub persist_entity_buffer: bool,
     /// Daemon capabilities (docker socket availability, interfaced subnets)
     /// Updated after SelfReport discovery completes
     #[serde(default)]
@@ -219,6 +226,7 @@ impl Default for AppConfig {
             interfaces: Vec::new(),
             scan_rate_pps: default_scan_rate_pps(),
             port_scan_batch_size: default_port_scan_batch_size(),
+            persist_entity_buffer: false,
             capabilities: DaemonCapabilities::default(),
         }
     }
@@ -352,6 +360,9 @@ impl AppConfig {
         if let Some(interface) = cli_args.interfaces {
             figment = figment.merge(("interfaces", interface));
         }
+        if let Some(persist_entity_buffer) = cli_args.persist_entity_buffer {
+            figment = figment.merge(("persist_entity_buffer", persist_entity_buffer));
+        }
 
         let config: AppConfig = figment
             .extract()
@@ -545,6 +556,16 @@ impl ConfigStore {
         }
     }
 
+    pub async fn get_persist_entity_buffer(&self) -> Result<bool> {
+        let config = self.config.read().await;
+        Ok(config.persist_entity_buffer)
+    }
+
+    /// Path of the entity buffer journal, stored alongside the config file
+    pub fn get_entity_buffer_path(&self) -> PathBuf {
+        self.path.with_file_name("entity-buffer.jsonl")
+    }
+
     pub async fn get_heartbeat_interval(&self) -> Result<u64> {
         let config = self.config.read().await;
         Ok(config.heartbeat_interval)
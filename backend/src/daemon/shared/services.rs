@@ -22,7 +22,11 @@ impl DaemonServiceFactory {
         // Initialize services with proper dependencies
 
         // Create entity buffer first - shared between discovery service and daemon state
-        let entity_buffer = Arc::new(EntityBuffer::new());
+        let entity_buffer = if config.get_persist_entity_buffer().await? {
+            Arc::new(EntityBuffer::with_journal(config.get_entity_buffer_path()).await?)
+        } else {
+            Arc::new(EntityBuffer::new())
+        };
 
         let discovery_service = Arc::new(DaemonDiscoveryService::new(
             config.clone(),
@@ -0,0 +1,241 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::Result;
+use hickory_resolver::proto::op::{Message, MessageType, OpCode, Query};
+use hickory_resolver::proto::rr::{Name, RData, Record, RecordType};
+use tokio::net::UdpSocket;
+use tokio_util::sync::CancellationToken;
+
+use super::{multicast_socket, receive_until};
+use crate::daemon::utils::passive::parse::dns_sd_service_type;
+use crate::server::services::r#impl::advertisements::MdnsAdvertisement;
+
+const MDNS_ADDR: SocketAddrV4 = SocketAddrV4::new(Ipv4Addr::new(224, 0, 0, 251), 5353);
+
+/// Meta-query that every DNS-SD responder answers with the service types it offers
+const SERVICES_META_QUERY: &str = "_services._dns-sd._udp.local.";
+
+/// How long to collect answers after each query round
+pub const QUERY_WAIT: Duration = Duration::from_secs(2);
+
+/// Browse DNS-SD services on the subnet `source_ip` belongs to.
+///
+/// The first round asks for the service types on offer, the second browses each type for
+/// its instances. Queries are sent from an ephemeral port, so responders answer with
+/// unicast "legacy" responses straight to us (RFC 6762 section 6.7) and the daemon never
+/// competes with the host's own mDNS responder for port 5353.
+pub async fn browse(
+    source_ip: Ipv4Addr,
+    cancel: &CancellationToken,
+) -> Result<HashMap<IpAddr, Vec<MdnsAdvertisement>>> {
+    let socket = multicast_socket(source_ip)?;
+    let mut results: HashMap<IpAddr, Vec<MdnsAdvertisement>> = HashMap::new();
+
+    let meta_query = Name::from_str(SERVICES_META_QUERY)?;
+    collect_round(&socket, &[meta_query], &mut results, cancel).await?;
+
+    let service_types: HashSet<String> = results
+        .values()
+        .flatten()
+        .map(|a| a.service_type.clone())
+        .collect();
+    let service_names: Vec<Name> = service_types
+        .iter()
+        .filter_map(|t| Name::from_str(&format!("{}.local.", t)).ok())
+        .collect();
+
+    // Keep queries well inside a single datagram
+    for chunk in service_names.chunks(16) {
+        if cancel.is_cancelled() {
+            break;
+        }
+        collect_round(&socket, chunk, &mut results, cancel).await?;
+    }
+
+    // Instances make the type-only entries from the meta-query redundant
+    for advertisements in results.values_mut() {
+        let with_instances: HashSet<String> = advertisements
+            .iter()
+            .filter(|a| a.instance_name.is_some())
+            .map(|a| a.service_type.clone())
+            .collect();
+        advertisements
+            .retain(|a| a.instance_name.is_some() || !with_instances.contains(&a.service_type));
+    }
+
+    Ok(results)
+}
+
+async fn collect_round(
+    socket: &UdpSocket,
+    names: &[Name],
+    results: &mut HashMap<IpAddr, Vec<MdnsAdvertisement>>,
+    cancel: &CancellationToken,
+) -> Result<()> {
+    socket
+        .send_to(&query_packet(names)?, SocketAddr::V4(MDNS_ADDR))
+        .await?;
+
+    receive_until(socket, QUERY_WAIT, cancel, |source, packet| {
+        for advertisement in parse_response(packet) {
+            let advertisements = results.entry(source.ip()).or_default();
+            if !advertisements.contains(&advertisement) {
+                advertisements.push(advertisement);
+            }
+        }
+    })
+    .await;
+
+    Ok(())
+}
+
+fn query_packet(names: &[Name]) -> Result<Vec<u8>> {
+    let mut message = Message::new();
+    message
+        .set_id(0x5c4e)
+        .set_message_type(MessageType::Query)
+        .set_op_code(OpCode::Query);
+    for name in names {
+        message.add_query(Query::query(name.clone(), RecordType::PTR));
+    }
+    Ok(message.to_vec()?)
+}
+
+/// Pull DNS-SD advertisements out of a response. PTRs from the meta-query yield bare
+/// service types; PTRs for a service type yield instances, completed with the SRV port
+/// and TXT pairs when the responder included them.
+pub fn parse_response(packet: &[u8]) -> Vec<MdnsAdvertisement> {
+    let Ok(message) = Message::from_vec(packet) else {
+        return Vec::new();
+    };
+    if message.message_type() != MessageType::Response {
+        return Vec::new();
+    }
+
+    let records: Vec<&Record> = message
+        .answers()
+        .iter()
+        .chain(message.additionals())
+        .collect();
+
+    let mut ports: HashMap<String, u16> = HashMap::new();
+    let mut txts: HashMap<String, BTreeMap<String, String>> = HashMap::new();
+    for record in &records {
+        let owner = record.name().to_lowercase().to_utf8();
+        match record.data() {
+            RData::SRV(srv) => {
+                ports.insert(owner, srv.port());
+            }
+            RData::TXT(txt) => {
+                let pairs = txts.entry(owner).or_default();
+                for entry in txt.iter() {
+                    let entry = String::from_utf8_lossy(entry);
+                    let (key, value) = entry.split_once('=').unwrap_or((&entry, ""));
+                    if !key.is_empty() {
+                        pairs.insert(key.to_lowercase(), value.to_string());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut advertisements = Vec::new();
+    for record in &records {
+        let RData::PTR(target) = record.data() else {
+            continue;
+        };
+
+        let is_meta = record
+            .name()
+            .to_utf8()
+            .eq_ignore_ascii_case(SERVICES_META_QUERY);
+        if is_meta {
+            if let Some(service_type) = dns_sd_service_type(&target.0) {
+                advertisements.push(MdnsAdvertisement {
+                    service_type,
+                    ..Default::default()
+                });
+            }
+            continue;
+        }
+
+        let Some(service_type) = dns_sd_service_type(record.name()) else {
+            continue;
+        };
+        let instance = target.0.to_lowercase().to_utf8();
+        advertisements.push(MdnsAdvertisement {
+            service_type,
+            instance_name: target
+                .0
+                .iter()
+                .next()
+                .map(|label| String::from_utf8_lossy(label).into_owned()),
+            port: ports.get(&instance).copied(),
+            txt: txts.remove(&instance).unwrap_or_default(),
+        });
+    }
+
+    advertisements
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hickory_resolver::proto::rr::rdata::{PTR, SRV, TXT};
+
+    #[test]
+    fn test_parse_browse_response() {
+        let meta = Name::from_str(SERVICES_META_QUERY).unwrap();
+        let service = Name::from_str("_googlecast._tcp.local.").unwrap();
+        let instance = Name::from_str("living-room-tv._googlecast._tcp.local.").unwrap();
+        let target = Name::from_str("living-room.local.").unwrap();
+
+        let mut message = Message::new();
+        message.set_message_type(MessageType::Response);
+        message.add_answer(Record::from_rdata(
+            meta,
+            120,
+            RData::PTR(PTR(service.clone())),
+        ));
+        message.add_answer(Record::from_rdata(
+            service,
+            120,
+            RData::PTR(PTR(instance.clone())),
+        ));
+        message.add_additional(Record::from_rdata(
+            instance.clone(),
+            120,
+            RData::SRV(SRV::new(0, 0, 8009, target)),
+        ));
+        message.add_additional(Record::from_rdata(
+            instance,
+            120,
+            RData::TXT(TXT::new(vec![
+                "md=Chromecast Ultra".to_string(),
+                "FN=Living Room".to_string(),
+            ])),
+        ));
+
+        let advertisements = parse_response(&message.to_vec().unwrap());
+        assert_eq!(advertisements.len(), 2);
+        assert_eq!(advertisements[0].service_type, "_googlecast._tcp");
+        assert_eq!(advertisements[0].instance_name, None);
+
+        let instance = &advertisements[1];
+        assert_eq!(instance.service_type, "_googlecast._tcp");
+        assert_eq!(instance.instance_name.as_deref(), Some("living-room-tv"));
+        assert_eq!(instance.port, Some(8009));
+        assert_eq!(
+            instance.txt.get("md").map(String::as_str),
+            Some("Chromecast Ultra")
+        );
+        assert_eq!(
+            instance.txt.get("fn").map(String::as_str),
+            Some("Living Room")
+        );
+    }
+}
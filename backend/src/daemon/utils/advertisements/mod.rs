@@ -0,0 +1,96 @@
+//! Service advertisement queries for network discovery.
+//!
+//! Many consumer and IoT devices expose nothing identifiable over HTTP but describe
+//! themselves when asked over multicast:
+//! - mDNS / DNS-SD: service types, instance names, SRV ports and TXT records
+//!   (e.g. `_googlecast._tcp` with `md=Chromecast`)
+//! - SSDP / UPnP: the device description document behind each M-SEARCH response
+//!   (`deviceType`, `manufacturer`, `modelName`)
+//!
+//! Both use link-local multicast, so only subnets the daemon has an interface on can be
+//! queried. Sockets are plain UDP and don't need raw socket privileges.
+
+pub mod mdns;
+pub mod ssdp;
+
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::time::Duration;
+
+use anyhow::Result;
+use socket2::{Domain, Protocol, Socket, Type};
+use tokio::net::UdpSocket;
+use tokio_util::sync::CancellationToken;
+
+use crate::server::services::r#impl::advertisements::ServiceAdvertisements;
+
+/// Query mDNS and SSDP on the subnet `source_ip` belongs to, keyed by responder address.
+/// A protocol that fails (e.g. the multicast send is rejected) is logged and skipped.
+pub async fn query_subnet(
+    source_ip: Ipv4Addr,
+    cancel: &CancellationToken,
+) -> HashMap<IpAddr, ServiceAdvertisements> {
+    let (mdns, ssdp) = tokio::join!(
+        mdns::browse(source_ip, cancel),
+        ssdp::search(source_ip, cancel)
+    );
+
+    let mut results: HashMap<IpAddr, ServiceAdvertisements> = HashMap::new();
+
+    match mdns {
+        Ok(mdns) => {
+            for (ip, advertisements) in mdns {
+                results.entry(ip).or_default().mdns = advertisements;
+            }
+        }
+        Err(e) => tracing::debug!(source_ip = %source_ip, error = %e, "mDNS browse failed"),
+    }
+
+    match ssdp {
+        Ok(ssdp) => {
+            for (ip, descriptions) in ssdp {
+                results.entry(ip).or_default().upnp = descriptions;
+            }
+        }
+        Err(e) => tracing::debug!(source_ip = %source_ip, error = %e, "SSDP search failed"),
+    }
+
+    results
+}
+
+/// UDP socket on an ephemeral port of `source_ip` that sends multicast out of that
+/// address's interface rather than wherever the default route points
+fn multicast_socket(source_ip: Ipv4Addr) -> Result<UdpSocket> {
+    let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+    socket.set_multicast_if_v4(&source_ip)?;
+    socket.set_multicast_ttl_v4(1)?;
+    socket.set_nonblocking(true)?;
+    socket.bind(&SocketAddr::V4(SocketAddrV4::new(source_ip, 0)).into())?;
+
+    Ok(UdpSocket::from_std(socket.into())?)
+}
+
+/// Hand every datagram received within `wait` to `handle`
+async fn receive_until(
+    socket: &UdpSocket,
+    wait: Duration,
+    cancel: &CancellationToken,
+    mut handle: impl FnMut(SocketAddr, &[u8]),
+) {
+    let deadline = tokio::time::Instant::now() + wait;
+    let mut buffer = vec![0u8; 9000];
+
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => break,
+            _ = tokio::time::sleep_until(deadline) => break,
+            received = socket.recv_from(&mut buffer) => match received {
+                Ok((len, source)) => handle(source, &buffer[..len]),
+                Err(e) => {
+                    tracing::debug!(error = %e, "Advertisement receive failed");
+                    break;
+                }
+            },
+        }
+    }
+}
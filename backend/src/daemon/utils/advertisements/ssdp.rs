@@ -0,0 +1,190 @@
+use std::collections::{HashMap, HashSet};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use futures::StreamExt;
+use tokio_util::sync::CancellationToken;
+
+use super::{multicast_socket, receive_until};
+use crate::server::services::r#impl::advertisements::UpnpDescription;
+
+const SSDP_ADDR: SocketAddrV4 = SocketAddrV4::new(Ipv4Addr::new(239, 255, 255, 250), 1900);
+
+/// How long to collect M-SEARCH responses. Devices spread replies over up to MX seconds.
+pub const SEARCH_WAIT: Duration = Duration::from_secs(3);
+
+const DESCRIPTION_TIMEOUT: Duration = Duration::from_secs(3);
+const DESCRIPTION_CONCURRENCY: usize = 16;
+const MAX_DESCRIPTIONS_PER_HOST: usize = 4;
+
+const M_SEARCH: &str = "M-SEARCH * HTTP/1.1\r\n\
+    HOST: 239.255.255.250:1900\r\n\
+    MAN: \"ssdp:discover\"\r\n\
+    MX: 2\r\n\
+    ST: ssdp:all\r\n\r\n";
+
+/// Find UPnP devices on the subnet `source_ip` belongs to and fetch their device
+/// descriptions.
+///
+/// Responses arrive unicast from each device with a LOCATION header. Only descriptions
+/// hosted on the responding device itself are fetched, so a response can't point the
+/// daemon at some other address.
+pub async fn search(
+    source_ip: Ipv4Addr,
+    cancel: &CancellationToken,
+) -> Result<HashMap<IpAddr, Vec<UpnpDescription>>> {
+    let socket = multicast_socket(source_ip)?;
+    socket
+        .send_to(M_SEARCH.as_bytes(), SocketAddr::V4(SSDP_ADDR))
+        .await?;
+
+    let mut locations: HashMap<IpAddr, HashSet<String>> = HashMap::new();
+    receive_until(&socket, SEARCH_WAIT, cancel, |source, packet| {
+        if let Some(location) = parse_location(packet)
+            && location_host(&location) == Some(source.ip())
+        {
+            let host_locations = locations.entry(source.ip()).or_default();
+            if host_locations.len() < MAX_DESCRIPTIONS_PER_HOST {
+                host_locations.insert(location);
+            }
+        }
+    })
+    .await;
+
+    if cancel.is_cancelled() {
+        return Ok(HashMap::new());
+    }
+
+    let client = reqwest::Client::builder()
+        .timeout(DESCRIPTION_TIMEOUT)
+        .build()
+        .map_err(|e| anyhow!("Could not build client {}", e))?;
+
+    let fetches = locations.into_iter().flat_map(|(ip, urls)| {
+        let client = client.clone();
+        urls.into_iter().map(move |url| {
+            let client = client.clone();
+            async move {
+                let body = client.get(&url).send().await.ok()?.text().await.ok()?;
+                let description = parse_description(&body);
+                if description.is_none() {
+                    tracing::debug!(ip = %ip, url = %url, "No UPnP device in description");
+                }
+                Some((ip, description?))
+            }
+        })
+    });
+
+    let mut results: HashMap<IpAddr, Vec<UpnpDescription>> = HashMap::new();
+    let mut fetched = futures::stream::iter(fetches).buffer_unordered(DESCRIPTION_CONCURRENCY);
+    while let Some(result) = fetched.next().await {
+        if let Some((ip, description)) = result {
+            let descriptions = results.entry(ip).or_default();
+            if !descriptions.contains(&description) {
+                descriptions.push(description);
+            }
+        }
+    }
+
+    Ok(results)
+}
+
+/// LOCATION header of an M-SEARCH response
+fn parse_location(packet: &[u8]) -> Option<String> {
+    let text = std::str::from_utf8(packet).ok()?;
+    let mut lines = text.lines();
+    if !lines.next()?.starts_with("HTTP/1.1 200") {
+        return None;
+    }
+
+    lines.find_map(|line| {
+        let (header, value) = line.split_once(':')?;
+        header
+            .trim()
+            .eq_ignore_ascii_case("LOCATION")
+            .then(|| value.trim().to_string())
+            .filter(|v| v.starts_with("http://"))
+    })
+}
+
+fn location_host(location: &str) -> Option<IpAddr> {
+    let url = reqwest::Url::parse(location).ok()?;
+    url.host_str()?
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse()
+        .ok()
+}
+
+/// Identity fields of the root `<device>` in a UPnP description document. Embedded
+/// devices follow the root device's own fields, so the first occurrence of each is taken.
+pub fn parse_description(xml: &str) -> Option<UpnpDescription> {
+    let device = &xml[xml.find("<device>").or_else(|| xml.find("<device "))?..];
+    let description = UpnpDescription {
+        device_type: element_text(device, "deviceType"),
+        friendly_name: element_text(device, "friendlyName"),
+        manufacturer: element_text(device, "manufacturer"),
+        model_name: element_text(device, "modelName"),
+    };
+
+    (description != UpnpDescription::default()).then_some(description)
+}
+
+fn element_text(xml: &str, tag: &str) -> Option<String> {
+    let open = format!("<{}>", tag);
+    let close = format!("</{}>", tag);
+    let start = xml.find(&open)? + open.len();
+    let end = start + xml[start..].find(&close)?;
+    let text = xml[start..end]
+        .trim()
+        .replace("&amp;", "&")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'");
+
+    (!text.is_empty()).then_some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_search_response_and_description() {
+        let response = "HTTP/1.1 200 OK\r\nCACHE-CONTROL: max-age=1800\r\n\
+            Location: http://192.168.1.50:1400/xml/device_description.xml\r\n\
+            ST: upnp:rootdevice\r\n\r\n";
+        let location = parse_location(response.as_bytes()).unwrap();
+        assert_eq!(
+            location_host(&location),
+            Some("192.168.1.50".parse().unwrap())
+        );
+
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+            <root xmlns="urn:schemas-upnp-org:device-1-0">
+              <device>
+                <deviceType>urn:schemas-upnp-org:device:ZonePlayer:1</deviceType>
+                <friendlyName>192.168.1.50 - Sonos One</friendlyName>
+                <manufacturer>Sonos, Inc.</manufacturer>
+                <modelName>Sonos One</modelName>
+                <deviceList>
+                  <device>
+                    <deviceType>urn:schemas-upnp-org:device:MediaRenderer:1</deviceType>
+                    <manufacturer>Sonos &amp; Co</manufacturer>
+                  </device>
+                </deviceList>
+              </device>
+            </root>"#;
+
+        let description = parse_description(xml).unwrap();
+        assert_eq!(
+            description.device_type.as_deref(),
+            Some("urn:schemas-upnp-org:device:ZonePlayer:1")
+        );
+        assert_eq!(description.manufacturer.as_deref(), Some("Sonos, Inc."));
+        assert_eq!(description.model_name.as_deref(), Some("Sonos One"));
+        assert!(parse_description("<root></root>").is_none());
+    }
+}
@@ -0,0 +1,148 @@
+//! Banner grabbing for network discovery.
+//!
+//! Many non-HTTP protocols identify themselves as soon as a client connects (SSH version
+//! strings, SMTP/FTP/POP3/IMAP greetings, the MySQL handshake). Ports that stay silent are
+//! sent a couple of generic probes that well-known client-first protocols answer, so
+//! services can be matched on what the port actually speaks rather than its number.
+
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+use anyhow::Result;
+use futures::StreamExt;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+use tokio_util::sync::CancellationToken;
+
+use crate::daemon::utils::scanner::SCAN_TIMEOUT;
+use crate::server::ports::r#impl::base::PortType;
+use crate::server::services::r#impl::banners::{BannerResponse, MAX_BANNER_BYTES};
+
+/// How long to wait for a server-first protocol to send its greeting
+const GREETING_TIMEOUT: Duration = Duration::from_millis(1500);
+
+/// How long to wait for a reply to a probe
+const PROBE_TIMEOUT: Duration = Duration::from_millis(1500);
+
+const BANNER_CONCURRENCY: usize = 8;
+
+/// Inline command that Redis (and most line-based protocols) reply to
+const LINE_PROBE: &[u8] = b"PING\r\n";
+
+/// Banners from each of `ports` that sent anything back, either on connect or in reply
+/// to a probe. Ports that never respond are left out.
+pub async fn grab_banners(
+    ip: IpAddr,
+    ports: Vec<u16>,
+    cancel: &CancellationToken,
+) -> Vec<BannerResponse> {
+    let grabs = ports.into_iter().map(|port| async move {
+        match grab_banner(SocketAddr::new(ip, port)).await {
+            Ok(Some(bytes)) => Some(BannerResponse::from_bytes(PortType::new_tcp(port), &bytes)),
+            Ok(None) => None,
+            Err(e) => {
+                tracing::trace!(ip = %ip, port, error = %e, "Banner grab failed");
+                None
+            }
+        }
+    });
+
+    let mut results = Vec::new();
+    let mut grabs = futures::stream::iter(grabs).buffer_unordered(BANNER_CONCURRENCY);
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => break,
+            next = grabs.next() => match next {
+                Some(Some(banner)) => results.push(banner),
+                Some(None) => {}
+                None => break,
+            },
+        }
+    }
+
+    results.sort_by_key(|b| b.port_type.number());
+    results
+}
+
+async fn grab_banner(address: SocketAddr) -> Result<Option<Vec<u8>>> {
+    let mut stream = timeout(SCAN_TIMEOUT, TcpStream::connect(address)).await??;
+
+    // Server-first protocols greet straight away
+    if let Some(greeting) = read_response(&mut stream, GREETING_TIMEOUT).await {
+        return Ok(Some(greeting));
+    }
+
+    // Reuse the silent connection for the line probe
+    stream.write_all(LINE_PROBE).await?;
+    if let Some(reply) = read_response(&mut stream, PROBE_TIMEOUT).await {
+        return Ok(Some(reply));
+    }
+    drop(stream);
+
+    // PostgreSQL drops the connection on a malformed startup packet, so it gets its own
+    let mut stream = timeout(SCAN_TIMEOUT, TcpStream::connect(address)).await??;
+    stream.write_all(&postgres_startup_message()).await?;
+    Ok(read_response(&mut stream, PROBE_TIMEOUT).await)
+}
+
+/// Whatever arrives within `wait`, or None if the port sent nothing or closed
+async fn read_response(stream: &mut TcpStream, wait: Duration) -> Option<Vec<u8>> {
+    let mut buf = vec![0u8; MAX_BANNER_BYTES];
+    match timeout(wait, stream.read(&mut buf)).await {
+        Ok(Ok(read)) if read > 0 => {
+            buf.truncate(read);
+            Some(buf)
+        }
+        _ => None,
+    }
+}
+
+/// Protocol 3.0 StartupMessage. Servers answer with an authentication request, or a
+/// FATAL error if the role or database doesn't exist.
+fn postgres_startup_message() -> Vec<u8> {
+    let mut body = 196608u32.to_be_bytes().to_vec(); // 3 << 16
+    for (key, value) in [("user", "scanopy"), ("database", "scanopy")] {
+        body.extend_from_slice(key.as_bytes());
+        body.push(0);
+        body.extend_from_slice(value.as_bytes());
+        body.push(0);
+    }
+    body.push(0);
+
+    let mut message = ((body.len() + 4) as u32).to_be_bytes().to_vec();
+    message.extend(body);
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_postgres_startup_message() {
+        let message = postgres_startup_message();
+        let length = u32::from_be_bytes(message[0..4].try_into().unwrap()) as usize;
+        assert_eq!(length, message.len());
+        assert_eq!(&message[4..8], &[0, 3, 0, 0]);
+        assert!(message.ends_with(b"scanopy\0\0"));
+    }
+
+    #[test]
+    fn test_banner_escapes_binary() {
+        let banner = BannerResponse::from_bytes(
+            PortType::PostgreSQL,
+            b"R\x00\x00\x00\x0c\x00\x00\x00\x05\xde\xad\xbe\xef",
+        );
+        assert_eq!(
+            banner.banner,
+            r"R\x00\x00\x00\x0c\x00\x00\x00\x05\xde\xad\xbe\xef"
+        );
+
+        let banner = BannerResponse::from_bytes(
+            PortType::new_tcp(25),
+            b"220 mail.example.com ESMTP Postfix\r\n",
+        );
+        assert_eq!(banner.banner, "220 mail.example.com ESMTP Postfix");
+    }
+}
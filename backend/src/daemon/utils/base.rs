@@ -1,3 +1,4 @@
+use crate::daemon::utils::ndp::NdpScanResult;
 use crate::server::discovery::r#impl::types::DiscoveryType;
 use crate::server::interfaces::r#impl::base::{Interface, InterfaceBase};
 use crate::server::shared::storage::traits::Storable;
@@ -43,6 +44,11 @@ pub trait DaemonUtils {
     /// Get MAC address for an IP from ARP table
     async fn get_mac_address_for_ip(&self, ip: IpAddr) -> Result<Option<MacAddress>, Error>;
 
+    /// Get IPv6 neighbors from the OS neighbor cache (NDP equivalent of the ARP table)
+    async fn get_ipv6_neighbors(&self) -> Result<Vec<NdpScanResult>, Error> {
+        Ok(Vec::new())
+    }
+
     fn get_fd_limit() -> Result<usize, Error>;
 
     fn get_own_ip_address(&self) -> Result<IpAddr, Error> {
@@ -182,6 +188,8 @@ pub trait DaemonUtils {
                     ip_address: ip_addr,
                     mac_address,
                     position: interfaces.len() as i32,
+                    first_seen: None,
+                    last_seen: None,
                 }));
             }
         }
@@ -0,0 +1,652 @@
+//! Minimal read-only client for the Kubernetes API, covering what cluster discovery needs.
+//!
+//! Credentials come from an explicit kubeconfig, the pod's service account when the daemon
+//! runs inside the cluster, or `$KUBECONFIG` / `~/.kube/config` otherwise. Kubeconfigs that
+//! authenticate through an exec plugin or auth provider aren't supported; use a token or a
+//! client certificate instead.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Error, Result, anyhow};
+use base64ct::{Base64, Encoding};
+use directories_next::BaseDirs;
+use reqwest::{Certificate, Client, Identity};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use yaml_rust2::{Yaml, YamlLoader};
+
+const SERVICE_ACCOUNT_DIR: &str = "/var/run/secrets/kubernetes.io/serviceaccount";
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Everything needed to reach and authenticate against one cluster
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ClusterConfig {
+    pub server: String,
+    pub certificate_authority: Option<Vec<u8>>,
+    pub insecure_skip_tls_verify: bool,
+    pub token: Option<String>,
+    pub client_certificate: Option<Vec<u8>>,
+    pub client_key: Option<Vec<u8>>,
+}
+
+impl ClusterConfig {
+    /// Resolve credentials: an explicit kubeconfig first, then the in-cluster service
+    /// account, then `$KUBECONFIG` or `~/.kube/config`
+    pub fn load(kubeconfig_path: Option<&str>, context: Option<&str>) -> Result<Self> {
+        if let Some(path) = kubeconfig_path {
+            return Self::from_kubeconfig_file(Path::new(path), context);
+        }
+
+        if std::env::var_os("KUBERNETES_SERVICE_HOST").is_some() {
+            return Self::in_cluster();
+        }
+
+        let path = std::env::var_os("KUBECONFIG")
+            .and_then(|paths| std::env::split_paths(&paths).next())
+            .or_else(|| BaseDirs::new().map(|d| d.home_dir().join(".kube").join("config")))
+            .ok_or_else(|| anyhow!("No kubeconfig found and not running inside a cluster"))?;
+
+        Self::from_kubeconfig_file(&path, context)
+    }
+
+    fn in_cluster() -> Result<Self> {
+        let host = std::env::var("KUBERNETES_SERVICE_HOST")?;
+        let port = std::env::var("KUBERNETES_SERVICE_PORT").unwrap_or_else(|_| "443".to_string());
+        let dir = Path::new(SERVICE_ACCOUNT_DIR);
+
+        let token = std::fs::read_to_string(dir.join("token"))
+            .context("Could not read service account token")?;
+
+        // IPv6 service hosts need brackets in the URL
+        let host = if host.contains(':') {
+            format!("[{}]", host)
+        } else {
+            host
+        };
+
+        Ok(Self {
+            server: format!("https://{}:{}", host, port),
+            certificate_authority: std::fs::read(dir.join("ca.crt")).ok(),
+            insecure_skip_tls_verify: false,
+            token: Some(token.trim().to_string()),
+            client_certificate: None,
+            client_key: None,
+        })
+    }
+
+    fn from_kubeconfig_file(path: &Path, context: Option<&str>) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Could not read kubeconfig {}", path.display()))?;
+        let base_dir = path.parent().unwrap_or(Path::new("."));
+        Self::from_kubeconfig(&contents, context, base_dir)
+    }
+
+    /// Parse a kubeconfig, using `context` or the file's current context. Relative file
+    /// references are resolved against `base_dir`.
+    pub fn from_kubeconfig(contents: &str, context: Option<&str>, base_dir: &Path) -> Result<Self> {
+        let docs = YamlLoader::load_from_str(contents).context("Kubeconfig is not valid YAML")?;
+        let doc = docs.first().ok_or_else(|| anyhow!("Kubeconfig is empty"))?;
+
+        let context_name = context
+            .or_else(|| doc["current-context"].as_str())
+            .ok_or_else(|| anyhow!("Kubeconfig has no current context; choose one"))?;
+
+        let context = named_entry(doc, "contexts", "context", context_name)
+            .ok_or_else(|| anyhow!("Context '{}' not found in kubeconfig", context_name))?;
+
+        let cluster_name = context["cluster"]
+            .as_str()
+            .ok_or_else(|| anyhow!("Context '{}' has no cluster", context_name))?;
+        let cluster = named_entry(doc, "clusters", "cluster", cluster_name)
+            .ok_or_else(|| anyhow!("Cluster '{}' not found in kubeconfig", cluster_name))?;
+
+        let user = match context["user"].as_str() {
+            Some(user_name) => named_entry(doc, "users", "user", user_name)
+                .ok_or_else(|| anyhow!("User '{}' not found in kubeconfig", user_name))?,
+            None => &Yaml::BadValue,
+        };
+
+        if !user["exec"].is_badvalue() || !user["auth-provider"].is_badvalue() {
+            return Err(anyhow!(
+                "Kubeconfig user for context '{}' authenticates through a plugin, which isn't supported; use a token or client certificate",
+                context_name
+            ));
+        }
+
+        let server = cluster["server"]
+            .as_str()
+            .ok_or_else(|| anyhow!("Cluster '{}' has no server", cluster_name))?
+            .trim_end_matches('/')
+            .to_string();
+
+        let token = match (user["token"].as_str(), user["tokenFile"].as_str()) {
+            (Some(token), _) => Some(token.to_string()),
+            (None, Some(file)) => Some(
+                std::fs::read_to_string(base_dir.join(file))
+                    .context("Could not read kubeconfig token file")?
+                    .trim()
+                    .to_string(),
+            ),
+            (None, None) => None,
+        };
+
+        Ok(Self {
+            server,
+            certificate_authority: inline_or_file(
+                cluster,
+                "certificate-authority-data",
+                "certificate-authority",
+                base_dir,
+            )?,
+            insecure_skip_tls_verify: cluster["insecure-skip-tls-verify"]
+                .as_bool()
+                .unwrap_or(false),
+            token,
+            client_certificate: inline_or_file(
+                user,
+                "client-certificate-data",
+                "client-certificate",
+                base_dir,
+            )?,
+            client_key: inline_or_file(user, "client-key-data", "client-key", base_dir)?,
+        })
+    }
+}
+
+/// The `field` of the entry called `name` in one of the kubeconfig's named lists
+fn named_entry<'a>(doc: &'a Yaml, list: &str, field: &str, name: &str) -> Option<&'a Yaml> {
+    doc[list]
+        .as_vec()?
+        .iter()
+        .find(|entry| entry["name"].as_str() == Some(name))
+        .map(|entry| &entry[field])
+}
+
+/// Base64 `data_key` if present, otherwise the contents of the file at `path_key`
+fn inline_or_file(
+    entry: &Yaml,
+    data_key: &str,
+    path_key: &str,
+    base_dir: &Path,
+) -> Result<Option<Vec<u8>>> {
+    if let Some(data) = entry[data_key].as_str() {
+        return decode_base64(data).map(Some);
+    }
+    match entry[path_key].as_str() {
+        Some(path) => {
+            let path: PathBuf = base_dir.join(path);
+            std::fs::read(&path)
+                .with_context(|| format!("Could not read {}", path.display()))
+                .map(Some)
+        }
+        None => Ok(None),
+    }
+}
+
+fn decode_base64(data: &str) -> Result<Vec<u8>> {
+    let data: String = data.chars().filter(|c| !c.is_whitespace()).collect();
+    let mut buf = vec![0u8; data.len()];
+    let len = Base64::decode(&data, &mut buf)
+        .map_err(|e| anyhow!("Invalid base64 in kubeconfig: {}", e))?
+        .len();
+    buf.truncate(len);
+    Ok(buf)
+}
+
+pub struct KubernetesClient {
+    client: Client,
+    server: String,
+    token: Option<String>,
+}
+
+impl KubernetesClient {
+    pub fn new(config: ClusterConfig) -> Result<Self> {
+        let mut builder = Client::builder().timeout(REQUEST_TIMEOUT);
+
+        if config.insecure_skip_tls_verify {
+            builder = builder.danger_accept_invalid_certs(true);
+        } else if let Some(ca) = &config.certificate_authority {
+            for cert in Certificate::from_pem_bundle(ca)? {
+                builder = builder.add_root_certificate(cert);
+            }
+        }
+
+        if let (Some(cert), Some(key)) = (&config.client_certificate, &config.client_key) {
+            let pem = [cert.as_slice(), b"\n", key.as_slice()].concat();
+            builder = builder.identity(Identity::from_pem(&pem)?);
+        }
+
+        Ok(Self {
+            client: builder
+                .build()
+                .map_err(|e| anyhow!("Could not build client {}", e))?,
+            server: config.server,
+            token: config.token,
+        })
+    }
+
+    pub async fn nodes(&self) -> Result<Vec<Node>> {
+        self.list("/api/v1/nodes").await
+    }
+
+    pub async fn pods(&self) -> Result<Vec<Pod>> {
+        self.list("/api/v1/pods").await
+    }
+
+    pub async fn services(&self) -> Result<Vec<KubeService>> {
+        self.list("/api/v1/services").await
+    }
+
+    pub async fn ingresses(&self) -> Result<Vec<Ingress>> {
+        self.list("/apis/networking.k8s.io/v1/ingresses").await
+    }
+
+    async fn list<T: DeserializeOwned>(&self, path: &str) -> Result<Vec<T>> {
+        let mut request = self.client.get(format!("{}{}", self.server, path));
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(Error::msg(format!(
+                "Kubernetes API returned {} for {}: {}",
+                status, path, body
+            )));
+        }
+
+        Ok(response.json::<List<T>>().await?.items)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct List<T> {
+    items: Vec<T>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ObjectMeta {
+    pub name: String,
+    pub namespace: Option<String>,
+    pub uid: String,
+    pub labels: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Node {
+    pub metadata: ObjectMeta,
+    pub spec: NodeSpec,
+    pub status: NodeStatus,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct NodeSpec {
+    #[serde(rename = "podCIDRs")]
+    pub pod_cidrs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct NodeStatus {
+    pub addresses: Vec<NodeAddress>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct NodeAddress {
+    #[serde(rename = "type")]
+    pub address_type: String,
+    pub address: String,
+}
+
+impl Node {
+    /// Addresses of the given type (`InternalIP`, `ExternalIP`, `Hostname`)
+    pub fn addresses(&self, address_type: &str) -> impl Iterator<Item = &str> {
+        self.status
+            .addresses
+            .iter()
+            .filter(move |a| a.address_type == address_type)
+            .map(|a| a.address.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Pod {
+    pub metadata: ObjectMeta,
+    pub spec: PodSpec,
+    pub status: PodStatus,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PodSpec {
+    pub node_name: Option<String>,
+    pub host_network: bool,
+    pub containers: Vec<Container>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Container {
+    pub name: String,
+    pub ports: Vec<ContainerPort>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ContainerPort {
+    pub name: Option<String>,
+    pub container_port: u16,
+    pub protocol: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct PodStatus {
+    pub phase: Option<String>,
+    #[serde(rename = "podIP")]
+    pub pod_ip: Option<String>,
+}
+
+impl Pod {
+    pub fn namespace(&self) -> &str {
+        self.metadata.namespace.as_deref().unwrap_or("default")
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.status.phase.as_deref() == Some("Running")
+    }
+
+    pub fn container_ports(&self) -> impl Iterator<Item = &ContainerPort> {
+        self.spec.containers.iter().flat_map(|c| c.ports.iter())
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct KubeService {
+    pub metadata: ObjectMeta,
+    pub spec: ServiceSpec,
+    pub status: LoadBalancerHolder,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ServiceSpec {
+    #[serde(rename = "type")]
+    pub service_type: Option<String>,
+    #[serde(rename = "clusterIP")]
+    pub cluster_ip: Option<String>,
+    pub selector: HashMap<String, String>,
+    pub ports: Vec<ServicePort>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ServicePort {
+    pub port: u16,
+    pub protocol: Option<String>,
+    pub node_port: Option<u16>,
+    pub target_port: Option<IntOrString>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum IntOrString {
+    Int(u16),
+    String(String),
+}
+
+/// `status.loadBalancer`, shared by Services and Ingresses
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LoadBalancerHolder {
+    pub load_balancer: LoadBalancerStatus,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct LoadBalancerStatus {
+    pub ingress: Vec<LoadBalancerIngress>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct LoadBalancerIngress {
+    pub ip: Option<String>,
+}
+
+impl LoadBalancerHolder {
+    pub fn ips(&self) -> impl Iterator<Item = &str> {
+        self.load_balancer
+            .ingress
+            .iter()
+            .filter_map(|i| i.ip.as_deref())
+    }
+}
+
+impl KubeService {
+    pub fn namespace(&self) -> &str {
+        self.metadata.namespace.as_deref().unwrap_or("default")
+    }
+
+    /// Whether the Service routes to `pod`. Services without a selector never match.
+    pub fn selects(&self, pod: &Pod) -> bool {
+        !self.spec.selector.is_empty()
+            && self.namespace() == pod.namespace()
+            && self
+                .spec
+                .selector
+                .iter()
+                .all(|(k, v)| pod.metadata.labels.get(k) == Some(v))
+    }
+
+    /// Port the Service forwards `port` to on `pod`, resolving named target ports
+    /// against the pod's container ports
+    pub fn target_port(port: &ServicePort, pod: &Pod) -> Option<u16> {
+        match &port.target_port {
+            None => Some(port.port),
+            Some(IntOrString::Int(target)) => Some(*target),
+            Some(IntOrString::String(name)) => pod
+                .container_ports()
+                .find(|p| p.name.as_deref() == Some(name.as_str()))
+                .map(|p| p.container_port),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Ingress {
+    pub metadata: ObjectMeta,
+    pub spec: IngressSpec,
+    pub status: LoadBalancerHolder,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct IngressSpec {
+    pub default_backend: Option<IngressBackend>,
+    pub rules: Vec<IngressRule>,
+    pub tls: Vec<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct IngressRule {
+    pub http: Option<HttpIngressRuleValue>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct HttpIngressRuleValue {
+    pub paths: Vec<HttpIngressPath>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct HttpIngressPath {
+    pub backend: IngressBackend,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct IngressBackend {
+    pub service: Option<IngressServiceBackend>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct IngressServiceBackend {
+    pub name: String,
+}
+
+impl Ingress {
+    pub fn namespace(&self) -> &str {
+        self.metadata.namespace.as_deref().unwrap_or("default")
+    }
+
+    /// Names of the Services the Ingress routes to, all in its own namespace
+    pub fn backend_services(&self) -> impl Iterator<Item = &str> {
+        self.spec
+            .default_backend
+            .iter()
+            .chain(
+                self.spec
+                    .rules
+                    .iter()
+                    .filter_map(|r| r.http.as_ref())
+                    .flat_map(|h| h.paths.iter().map(|p| &p.backend)),
+            )
+            .filter_map(|b| b.service.as_ref().map(|s| s.name.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KUBECONFIG: &str = r#"
+apiVersion: v1
+kind: Config
+current-context: homelab
+clusters:
+  - name: homelab
+    cluster:
+      server: https://10.0.0.10:6443/
+      certificate-authority-data: aGVsbG8=
+  - name: staging
+    cluster:
+      server: https://staging.example.com
+      insecure-skip-tls-verify: true
+contexts:
+  - name: homelab
+    context:
+      cluster: homelab
+      user: admin
+  - name: staging
+    context:
+      cluster: staging
+      user: sso
+users:
+  - name: admin
+    user:
+      token: abc123
+  - name: sso
+    user:
+      exec:
+        command: kubelogin
+"#;
+
+    fn pod(namespace: &str, labels: &[(&str, &str)]) -> Pod {
+        Pod {
+            metadata: ObjectMeta {
+                name: "web-0".to_string(),
+                namespace: Some(namespace.to_string()),
+                uid: "uid".to_string(),
+                labels: labels
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            },
+            spec: PodSpec {
+                containers: vec![Container {
+                    name: "web".to_string(),
+                    ports: vec![ContainerPort {
+                        name: Some("http".to_string()),
+                        container_port: 8080,
+                        protocol: None,
+                    }],
+                }],
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_kubeconfig_current_context() {
+        let config = ClusterConfig::from_kubeconfig(KUBECONFIG, None, Path::new("/")).unwrap();
+
+        assert_eq!(
+            config,
+            ClusterConfig {
+                server: "https://10.0.0.10:6443".to_string(),
+                certificate_authority: Some(b"hello".to_vec()),
+                insecure_skip_tls_verify: false,
+                token: Some("abc123".to_string()),
+                client_certificate: None,
+                client_key: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_kubeconfig_rejects_exec_plugins_and_unknown_contexts() {
+        let err = ClusterConfig::from_kubeconfig(KUBECONFIG, Some("staging"), Path::new("/"))
+            .unwrap_err();
+        assert!(err.to_string().contains("plugin"));
+
+        assert!(ClusterConfig::from_kubeconfig(KUBECONFIG, Some("prod"), Path::new("/")).is_err());
+    }
+
+    #[test]
+    fn test_service_selects_pod_and_resolves_target_ports() {
+        let service: KubeService = serde_json::from_value(serde_json::json!({
+            "metadata": { "name": "web", "namespace": "apps" },
+            "spec": {
+                "type": "NodePort",
+                "selector": { "app": "web" },
+                "ports": [
+                    { "port": 80, "targetPort": "http", "nodePort": 30080 },
+                    { "port": 9090, "targetPort": 9091 },
+                    { "port": 443, "targetPort": "https" }
+                ]
+            }
+        }))
+        .unwrap();
+
+        let matching = pod("apps", &[("app", "web"), ("tier", "frontend")]);
+        assert!(service.selects(&matching));
+        assert!(!service.selects(&pod("default", &[("app", "web")])));
+        assert!(!service.selects(&pod("apps", &[("app", "api")])));
+
+        let targets: Vec<Option<u16>> = service
+            .spec
+            .ports
+            .iter()
+            .map(|p| KubeService::target_port(p, &matching))
+            .collect();
+        assert_eq!(targets, vec![Some(8080), Some(9091), None]);
+        assert_eq!(service.spec.ports[0].node_port, Some(30080));
+    }
+}
@@ -4,6 +4,8 @@ use crate::daemon::utils::base::DaemonUtils;
 #[cfg(target_os = "linux")]
 pub struct LinuxDaemonUtils;
 
+#[cfg(target_os = "linux")]
+use crate::daemon::utils::ndp::{NdpScanResult, neighbors::parse_ip_neigh_output};
 #[cfg(target_os = "linux")]
 use anyhow::{Error, Result, anyhow};
 #[cfg(target_os = "linux")]
@@ -127,4 +129,25 @@ impl DaemonUtils for LinuxDaemonUtils {
 
         Ok(None)
     }
+
+    async fn get_ipv6_neighbors(&self) -> Result<Vec<NdpScanResult>, Error> {
+        use tokio::process::Command;
+
+        let output = Command::new("ip")
+            .args(["-6", "neigh", "show"])
+            .output()
+            .await
+            .map_err(|e| anyhow!("Failed to run `ip -6 neigh show`: {}", e))?;
+
+        if !output.status.success() {
+            return Err(anyhow!(
+                "`ip -6 neigh show` failed with status: {}",
+                output.status
+            ));
+        }
+
+        Ok(parse_ip_neigh_output(&String::from_utf8_lossy(
+            &output.stdout,
+        )))
+    }
 }
@@ -5,6 +5,8 @@ use crate::daemon::utils::base::DaemonUtils;
 #[derive(Clone)]
 pub struct MacOsDaemonUtils;
 
+#[cfg(target_os = "macos")]
+use crate::daemon::utils::ndp::{NdpScanResult, neighbors::parse_ndp_output};
 #[cfg(target_os = "macos")]
 use anyhow::{Error, Result, anyhow};
 #[cfg(target_os = "macos")]
@@ -177,4 +179,16 @@ impl DaemonUtils for MacOsDaemonUtils {
 
         Ok(None)
     }
+
+    async fn get_ipv6_neighbors(&self) -> Result<Vec<NdpScanResult>, Error> {
+        use tokio::process::Command;
+
+        let output = Command::new("ndp").arg("-an").output().await?;
+
+        if !output.status.success() {
+            return Err(anyhow!("ndp command failed with status: {}", output.status));
+        }
+
+        Ok(parse_ndp_output(&String::from_utf8_lossy(&output.stdout)))
+    }
 }
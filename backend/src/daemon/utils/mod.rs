@@ -1,7 +1,18 @@
+pub mod advertisements;
 pub mod arp;
+pub mod banners;
 pub mod base;
+pub mod kubernetes;
 pub mod linux;
 pub mod macos;
+pub mod names;
+pub mod ndp;
+pub mod passive;
+pub mod proxmox;
+pub mod proxy;
 pub mod scanner;
+pub mod smb;
 pub mod snmp;
+pub mod tcp_fingerprint;
+pub mod tls;
 pub mod windows;
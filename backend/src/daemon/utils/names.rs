@@ -0,0 +1,231 @@
+//! Hostname lookups for network discovery beyond reverse DNS.
+//!
+//! Plenty of LAN hosts never get a PTR record: Windows machines announce themselves over
+//! NetBIOS and LLMNR, and Apple devices, printers and most Linux desktops answer mDNS.
+//! Each lookup here is a single unicast query sent straight to the host, so nothing is
+//! multicast and only the host being scanned is asked about its own name.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::time::Duration;
+
+use anyhow::Result;
+use hickory_resolver::proto::op::{Message, MessageType, OpCode, Query};
+use hickory_resolver::proto::rr::{Name, RData, RecordType};
+use tokio::net::UdpSocket;
+use tokio::time::timeout;
+
+const NETBIOS_NAME_PORT: u16 = 137;
+const LLMNR_PORT: u16 = 5355;
+const MDNS_PORT: u16 = 5353;
+
+/// How long to wait for each lookup's answer
+const LOOKUP_TIMEOUT: Duration = Duration::from_millis(800);
+
+/// NetBIOS node status request (NBSTAT, RFC 1002 section 4.2.17)
+const NBSTAT: u16 = 0x0021;
+
+/// Name table entries with this flag are group names (workgroup, domain), not the host
+const NETBIOS_GROUP_FLAG: u16 = 0x8000;
+
+/// Computer name from the host's NetBIOS name table. IPv4 only.
+pub async fn netbios_name(ip: IpAddr) -> Option<String> {
+    if !ip.is_ipv4() {
+        return None;
+    }
+    let response = query(ip, NETBIOS_NAME_PORT, &netbios_status_request())
+        .await
+        .ok()??;
+    parse_netbios_status(&response)
+}
+
+/// Name the host answers an LLMNR reverse lookup with
+pub async fn llmnr_name(ip: IpAddr) -> Option<String> {
+    reverse_lookup(ip, LLMNR_PORT).await
+}
+
+/// Name the host answers a unicast mDNS reverse lookup with
+pub async fn mdns_name(ip: IpAddr) -> Option<String> {
+    reverse_lookup(ip, MDNS_PORT).await
+}
+
+/// PTR lookup for the host's own address, asked of the responder on `ip:port`
+async fn reverse_lookup(ip: IpAddr, port: u16) -> Option<String> {
+    let request = ptr_request(ip).ok()?;
+    let response = query(ip, port, &request).await.ok()??;
+    parse_ptr_response(&response)
+}
+
+/// Send `request` to `ip:port` and wait for the first datagram it answers with
+async fn query(ip: IpAddr, port: u16, request: &[u8]) -> Result<Option<Vec<u8>>> {
+    let bind: SocketAddr = match ip {
+        IpAddr::V4(_) => (Ipv4Addr::UNSPECIFIED, 0).into(),
+        IpAddr::V6(_) => (Ipv6Addr::UNSPECIFIED, 0).into(),
+    };
+    let socket = UdpSocket::bind(bind).await?;
+    socket.connect((ip, port)).await?;
+    socket.send(request).await?;
+
+    let mut buf = [0u8; 1500];
+    match timeout(LOOKUP_TIMEOUT, socket.recv(&mut buf)).await {
+        Ok(Ok(read)) => Ok(Some(buf[..read].to_vec())),
+        Ok(Err(e)) => Err(e.into()),
+        Err(_) => Ok(None),
+    }
+}
+
+fn netbios_status_request() -> Vec<u8> {
+    // Transaction ID, flags, one question
+    let mut packet = vec![0x4e, 0x42, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0];
+
+    // The wildcard name "*" padded with nulls, in first-level encoding: each nibble
+    // becomes a letter from 'A'
+    packet.push(32);
+    for byte in std::iter::once(b'*').chain(std::iter::repeat_n(0, 15)) {
+        packet.push(b'A' + (byte >> 4));
+        packet.push(b'A' + (byte & 0x0f));
+    }
+    packet.push(0);
+
+    packet.extend_from_slice(&NBSTAT.to_be_bytes());
+    packet.extend_from_slice(&1u16.to_be_bytes());
+    packet
+}
+
+/// First unique workstation or server name in a node status response
+fn parse_netbios_status(packet: &[u8]) -> Option<String> {
+    let answers = u16::from_be_bytes([*packet.get(6)?, *packet.get(7)?]);
+    if answers == 0 {
+        return None;
+    }
+
+    // Skip the header and the answer name, which is either a compression pointer or
+    // the full encoded name echoed back
+    let mut offset = 12;
+    match *packet.get(offset)? {
+        length if length & 0xc0 == 0xc0 => offset += 2,
+        length => offset += usize::from(length) + 2,
+    }
+
+    // Type, class, TTL and RDATA length precede the name table
+    let record_type = u16::from_be_bytes([*packet.get(offset)?, *packet.get(offset + 1)?]);
+    if record_type != NBSTAT {
+        return None;
+    }
+    offset += 10;
+
+    let count = usize::from(*packet.get(offset)?);
+    offset += 1;
+
+    packet
+        .get(offset..offset + count * 18)?
+        .chunks_exact(18)
+        .find(|entry| {
+            let flags = u16::from_be_bytes([entry[16], entry[17]]);
+            matches!(entry[15], 0x00 | 0x20) && flags & NETBIOS_GROUP_FLAG == 0
+        })
+        .map(|entry| String::from_utf8_lossy(&entry[..15]).trim_end().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+fn ptr_request(ip: IpAddr) -> Result<Vec<u8>> {
+    let mut message = Message::new();
+    message
+        .set_id(0x4e4d)
+        .set_message_type(MessageType::Query)
+        .set_op_code(OpCode::Query)
+        .add_query(Query::query(Name::from(ip), RecordType::PTR));
+    Ok(message.to_vec()?)
+}
+
+/// Target of the first PTR answer, without the trailing dot
+fn parse_ptr_response(packet: &[u8]) -> Option<String> {
+    let message = Message::from_vec(packet).ok()?;
+    if message.message_type() != MessageType::Response {
+        return None;
+    }
+
+    message
+        .answers()
+        .iter()
+        .find_map(|record| match record.data() {
+            RData::PTR(target) => Some(target.0.to_utf8().trim_end_matches('.').to_string()),
+            _ => None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hickory_resolver::proto::rr::Record;
+    use hickory_resolver::proto::rr::rdata::PTR;
+    use std::str::FromStr;
+
+    fn name_entry(name: &str, suffix: u8, flags: u16) -> Vec<u8> {
+        let mut entry = format!("{:<15}", name).into_bytes();
+        entry.push(suffix);
+        entry.extend_from_slice(&flags.to_be_bytes());
+        entry
+    }
+
+    #[test]
+    fn test_netbios_status_request() {
+        let request = netbios_status_request();
+
+        assert_eq!(request.len(), 50);
+        assert_eq!(&request[12..15], &[32, b'C', b'K']);
+        assert!(request[15..45].iter().all(|&b| b == b'A'));
+        assert_eq!(&request[45..], &[0, 0x00, 0x21, 0x00, 0x01]);
+    }
+
+    #[test]
+    fn test_parse_netbios_status() {
+        let entries = [
+            name_entry("WORKGROUP", 0x00, 0x8400),
+            name_entry("DESKTOP-7QX2L", 0x00, 0x0400),
+            name_entry("DESKTOP-7QX2L", 0x20, 0x0400),
+        ];
+
+        let mut packet = vec![0x4e, 0x42, 0x84, 0, 0, 0, 0, 1, 0, 0, 0, 0];
+        packet.extend_from_slice(&netbios_status_request()[12..46]);
+        packet.extend_from_slice(&[0x00, 0x21, 0x00, 0x01, 0, 0, 0, 0]);
+        packet.extend_from_slice(&((entries.len() * 18 + 1) as u16).to_be_bytes());
+        packet.push(entries.len() as u8);
+        for entry in &entries {
+            packet.extend_from_slice(entry);
+        }
+
+        assert_eq!(
+            parse_netbios_status(&packet),
+            Some("DESKTOP-7QX2L".to_string())
+        );
+        // Truncated name table
+        assert_eq!(parse_netbios_status(&packet[..packet.len() - 20]), None);
+        // No answers
+        assert_eq!(parse_netbios_status(&packet[..12]), None);
+    }
+
+    #[test]
+    fn test_parse_ptr_response() {
+        let ip: IpAddr = "192.168.1.20".parse().unwrap();
+        let request = ptr_request(ip).unwrap();
+        let query = Message::from_vec(&request).unwrap();
+        assert_eq!(
+            query.queries()[0].name().to_utf8(),
+            "20.1.168.192.in-addr.arpa."
+        );
+
+        let mut message = Message::new();
+        message.set_message_type(MessageType::Response);
+        message.add_answer(Record::from_rdata(
+            Name::from(ip),
+            120,
+            RData::PTR(PTR(Name::from_str("office-printer.local.").unwrap())),
+        ));
+
+        assert_eq!(
+            parse_ptr_response(&message.to_vec().unwrap()),
+            Some("office-printer.local".to_string())
+        );
+        assert_eq!(parse_ptr_response(&request), None);
+    }
+}
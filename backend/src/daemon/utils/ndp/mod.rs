@@ -0,0 +1,40 @@
+//! IPv6 host discovery via the Neighbor Discovery Protocol.
+//!
+//! A /64 can't be swept address-by-address the way ARP sweeps an IPv4 subnet, so live
+//! hosts are found by:
+//! - Soliciting the all-nodes multicast group (ff02::1) with ICMPv6 echo requests from a
+//!   global source address, and collecting every in-prefix ICMPv6 sender that answers
+//!   (echo replies, plus the neighbor solicitations hosts send to resolve us)
+//! - Harvesting the OS neighbor cache, which also covers hosts that ignore multicast echo
+//!
+//! ## Platform Behavior
+//!
+//! | Platform | Multicast solicitation | Neighbor cache       |
+//! |----------|------------------------|----------------------|
+//! | Linux    | pnet datalink          | `ip -6 neigh`        |
+//! | macOS    | pnet datalink          | `ndp -an`            |
+//! | Windows  | Npcap only             | -                    |
+
+pub mod multicast;
+pub mod neighbors;
+pub mod types;
+
+pub use multicast::{POST_SOLICIT_RECEIVE, SOLICIT_ROUND_WAIT, solicit_subnet};
+pub use types::NdpScanResult;
+
+/// Check if multicast solicitation is available on this platform.
+///
+/// # Arguments
+/// * `use_npcap` - (Windows only) Solicitation needs Npcap for raw frame access
+pub fn is_available(use_npcap: bool) -> bool {
+    #[cfg(target_family = "windows")]
+    {
+        use_npcap && crate::daemon::utils::arp::broadcast::is_available()
+    }
+
+    #[cfg(not(target_family = "windows"))]
+    {
+        let _ = use_npcap;
+        crate::daemon::utils::arp::broadcast::is_available()
+    }
+}
@@ -0,0 +1,284 @@
+use std::collections::HashSet;
+use std::net::Ipv6Addr;
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, anyhow};
+use cidr::Ipv6Cidr;
+use mac_address::MacAddress;
+use pnet::datalink::{self, Channel, NetworkInterface};
+use pnet::packet::Packet;
+use pnet::packet::ethernet::{EtherTypes, EthernetPacket, MutableEthernetPacket};
+use pnet::packet::icmpv6::echo_request::MutableEchoRequestPacket;
+use pnet::packet::icmpv6::{self, Icmpv6Code, Icmpv6Packet, Icmpv6Types};
+use pnet::packet::ip::IpNextHeaderProtocols;
+use pnet::packet::ipv6::{Ipv6Packet, MutableIpv6Packet};
+use pnet::util::MacAddr;
+
+use super::types::NdpScanResult;
+
+/// Wait time after each solicitation round
+pub const SOLICIT_ROUND_WAIT: Duration = Duration::from_secs(3);
+/// Extra receive time after final round
+pub const POST_SOLICIT_RECEIVE: Duration = Duration::from_secs(5);
+
+/// All-nodes link-local multicast group
+const ALL_NODES: Ipv6Addr = Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 1);
+/// Ethernet multicast MAC for ff02::1 (33:33 + low 32 bits of the group)
+const ALL_NODES_MAC: MacAddr = MacAddr(0x33, 0x33, 0x00, 0x00, 0x00, 0x01);
+
+const ETHERNET_HEADER_LEN: usize = 14;
+const IPV6_HEADER_LEN: usize = 40;
+const ICMPV6_ECHO_LEN: usize = 8;
+
+/// Solicit all nodes on the interface's link and stream back hosts in `prefix` as they answer.
+///
+/// # Arguments
+/// * `interface` - Network interface to solicit on
+/// * `source_ip` - Source address; should be in `prefix` so hosts answer from their
+///   address in the same scope rather than their link-local one
+/// * `source_mac` - Source MAC for outgoing frames
+/// * `prefix` - Only senders within this prefix are reported
+/// * `known` - Addresses already found (e.g. from the neighbor cache) that shouldn't be re-reported
+/// * `retries` - Number of extra solicitation rounds (0 = single round)
+pub fn solicit_subnet(
+    interface: &NetworkInterface,
+    source_ip: Ipv6Addr,
+    source_mac: MacAddress,
+    prefix: Ipv6Cidr,
+    known: HashSet<Ipv6Addr>,
+    retries: u32,
+) -> Result<std::sync::mpsc::Receiver<NdpScanResult>> {
+    use std::sync::mpsc;
+
+    let config = pnet::datalink::Config {
+        read_timeout: Some(Duration::from_millis(50)),
+        read_buffer_size: 65536,
+        write_buffer_size: 65536,
+        ..Default::default()
+    };
+
+    let (mut tx, mut rx) = match datalink::channel(interface, config)? {
+        Channel::Ethernet(tx, rx) => (tx, rx),
+        _ => return Err(anyhow!("Unsupported channel type")),
+    };
+
+    let (result_tx, result_rx) = mpsc::channel();
+    let our_mac = MacAddr::from(source_mac.bytes());
+    let interface_name = interface.name.clone();
+    let total_rounds = 1 + retries;
+
+    std::thread::spawn(move || {
+        let start = Instant::now();
+        let identifier = std::process::id() as u16;
+        let mut found = known;
+        let mut discovered = 0u32;
+        let mut next_send = Instant::now();
+        let mut round = 0u32;
+
+        tracing::debug!(
+            interface = %interface_name,
+            source_ip = %source_ip,
+            prefix = %prefix,
+            total_rounds,
+            "Starting NDP multicast solicitation"
+        );
+
+        loop {
+            let now = Instant::now();
+
+            if round < total_rounds && now >= next_send {
+                round += 1;
+                let packet =
+                    build_multicast_echo_request(our_mac, source_ip, identifier, round as u16);
+                match tx.send_to(&packet, None) {
+                    Some(Ok(())) => {}
+                    Some(Err(e)) => {
+                        tracing::warn!(round, error = %e, "Failed to send ICMPv6 solicitation")
+                    }
+                    None => tracing::warn!(round, "Failed to send ICMPv6 solicitation"),
+                }
+                next_send = now + SOLICIT_ROUND_WAIT;
+            }
+
+            if round >= total_rounds && now >= next_send + POST_SOLICIT_RECEIVE {
+                break;
+            }
+
+            let Ok(frame) = rx.next() else {
+                continue;
+            };
+
+            let Some((ip, mac)) = parse_icmpv6_sender(frame) else {
+                continue;
+            };
+
+            if mac == source_mac || !prefix.contains(&ip) || !found.insert(ip) {
+                continue;
+            }
+
+            tracing::debug!(round, ip = %ip, mac = %mac, "NDP: Host discovered");
+            discovered += 1;
+
+            if result_tx.send(NdpScanResult { ip, mac }).is_err() {
+                // Receiver dropped, stop soliciting
+                break;
+            }
+        }
+
+        tracing::debug!(
+            interface = %interface_name,
+            elapsed_secs = start.elapsed().as_secs(),
+            hosts_found = discovered,
+            "NDP multicast solicitation completed"
+        );
+    });
+
+    Ok(result_rx)
+}
+
+/// Build an Ethernet frame carrying an ICMPv6 echo request to ff02::1
+fn build_multicast_echo_request(
+    source_mac: MacAddr,
+    source_ip: Ipv6Addr,
+    identifier: u16,
+    sequence: u16,
+) -> Vec<u8> {
+    let mut icmp_buffer = vec![0u8; ICMPV6_ECHO_LEN];
+    {
+        let mut echo = MutableEchoRequestPacket::new(&mut icmp_buffer).unwrap();
+        echo.set_icmpv6_type(Icmpv6Types::EchoRequest);
+        echo.set_icmpv6_code(Icmpv6Code(0));
+        echo.set_identifier(identifier);
+        echo.set_sequence_number(sequence);
+    }
+    let checksum = icmpv6::checksum(
+        &Icmpv6Packet::new(&icmp_buffer).unwrap(),
+        &source_ip,
+        &ALL_NODES,
+    );
+    MutableEchoRequestPacket::new(&mut icmp_buffer)
+        .unwrap()
+        .set_checksum(checksum);
+
+    let mut ipv6_buffer = vec![0u8; IPV6_HEADER_LEN + ICMPV6_ECHO_LEN];
+    {
+        let mut ipv6 = MutableIpv6Packet::new(&mut ipv6_buffer).unwrap();
+        ipv6.set_version(6);
+        ipv6.set_payload_length(ICMPV6_ECHO_LEN as u16);
+        ipv6.set_next_header(IpNextHeaderProtocols::Icmpv6);
+        ipv6.set_hop_limit(255);
+        ipv6.set_source(source_ip);
+        ipv6.set_destination(ALL_NODES);
+        ipv6.set_payload(&icmp_buffer);
+    }
+
+    let mut ethernet_buffer = vec![0u8; ETHERNET_HEADER_LEN + ipv6_buffer.len()];
+    let mut ethernet = MutableEthernetPacket::new(&mut ethernet_buffer).unwrap();
+    ethernet.set_destination(ALL_NODES_MAC);
+    ethernet.set_source(source_mac);
+    ethernet.set_ethertype(EtherTypes::Ipv6);
+    ethernet.set_payload(&ipv6_buffer);
+
+    ethernet_buffer
+}
+
+/// Extract (source IPv6, source MAC) from an ICMPv6 frame that proves a host is live:
+/// an echo reply, or the neighbor solicitation/advertisement it sends to reach us.
+fn parse_icmpv6_sender(frame: &[u8]) -> Option<(Ipv6Addr, MacAddress)> {
+    let ethernet = EthernetPacket::new(frame)?;
+    if ethernet.get_ethertype() != EtherTypes::Ipv6 {
+        return None;
+    }
+
+    let ipv6 = Ipv6Packet::new(ethernet.payload())?;
+    if ipv6.get_next_header() != IpNextHeaderProtocols::Icmpv6 {
+        return None;
+    }
+
+    let icmp = Icmpv6Packet::new(ipv6.payload())?;
+    let icmp_type = icmp.get_icmpv6_type();
+    if icmp_type != Icmpv6Types::EchoReply
+        && icmp_type != Icmpv6Types::NeighborSolicit
+        && icmp_type != Icmpv6Types::NeighborAdvert
+    {
+        return None;
+    }
+
+    let source = ipv6.get_source();
+    if source.is_unspecified() || source.is_multicast() {
+        return None;
+    }
+
+    Some((source, MacAddress::new(ethernet.get_source().octets())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_icmpv6_frame(icmp_type: u8, source_mac: MacAddr, source_ip: Ipv6Addr) -> Vec<u8> {
+        let mut frame = vec![0u8; ETHERNET_HEADER_LEN + IPV6_HEADER_LEN + ICMPV6_ECHO_LEN];
+        let mut ipv6_buffer = vec![0u8; IPV6_HEADER_LEN + ICMPV6_ECHO_LEN];
+        {
+            let mut ipv6 = MutableIpv6Packet::new(&mut ipv6_buffer).unwrap();
+            ipv6.set_version(6);
+            ipv6.set_payload_length(ICMPV6_ECHO_LEN as u16);
+            ipv6.set_next_header(IpNextHeaderProtocols::Icmpv6);
+            ipv6.set_source(source_ip);
+            ipv6.set_destination(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x64));
+            ipv6.set_payload(&[icmp_type, 0, 0, 0, 0, 0, 0, 0]);
+        }
+        let mut eth = MutableEthernetPacket::new(&mut frame).unwrap();
+        eth.set_source(source_mac);
+        eth.set_ethertype(EtherTypes::Ipv6);
+        eth.set_payload(&ipv6_buffer);
+        frame
+    }
+
+    #[test]
+    fn test_build_multicast_echo_request_creates_valid_packet() {
+        let source_mac = MacAddr::new(0x00, 0x11, 0x22, 0x33, 0x44, 0x55);
+        let source_ip = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x64);
+
+        let packet = build_multicast_echo_request(source_mac, source_ip, 0x1234, 1);
+        assert_eq!(packet.len(), 62);
+
+        let eth = EthernetPacket::new(&packet).unwrap();
+        assert_eq!(eth.get_destination(), ALL_NODES_MAC);
+        assert_eq!(eth.get_source(), source_mac);
+        assert_eq!(eth.get_ethertype(), EtherTypes::Ipv6);
+
+        let ipv6 = Ipv6Packet::new(eth.payload()).unwrap();
+        assert_eq!(ipv6.get_version(), 6);
+        assert_eq!(ipv6.get_next_header(), IpNextHeaderProtocols::Icmpv6);
+        assert_eq!(ipv6.get_source(), source_ip);
+        assert_eq!(ipv6.get_destination(), ALL_NODES);
+
+        let icmp = Icmpv6Packet::new(ipv6.payload()).unwrap();
+        assert_eq!(icmp.get_icmpv6_type(), Icmpv6Types::EchoRequest);
+        assert_eq!(
+            icmp.get_checksum(),
+            icmpv6::checksum(&icmp, &source_ip, &ALL_NODES)
+        );
+    }
+
+    #[test]
+    fn test_parse_icmpv6_sender() {
+        let mac = MacAddr::new(0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF);
+        let ip = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x10);
+
+        // Echo reply and neighbor solicitation both identify a live host
+        for icmp_type in [129, 135] {
+            let (parsed_ip, parsed_mac) =
+                parse_icmpv6_sender(&build_icmpv6_frame(icmp_type, mac, ip)).unwrap();
+            assert_eq!(parsed_ip, ip);
+            assert_eq!(parsed_mac.bytes(), [0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF]);
+        }
+
+        // Echo requests (including our own) and DAD probes from :: are ignored
+        assert!(parse_icmpv6_sender(&build_icmpv6_frame(128, mac, ip)).is_none());
+        assert!(
+            parse_icmpv6_sender(&build_icmpv6_frame(135, mac, Ipv6Addr::UNSPECIFIED)).is_none()
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use std::net::Ipv6Addr;
+use std::str::FromStr;
+
+use mac_address::MacAddress;
+
+use super::types::NdpScanResult;
+
+/// Parse Linux `ip -6 neigh show` output.
+///
+/// Lines look like `2001:db8::5 dev eth0 lladdr 52:54:00:12:34:56 router REACHABLE`.
+/// Entries without a link-layer address (INCOMPLETE, FAILED) are skipped.
+pub fn parse_ip_neigh_output(output: &str) -> Vec<NdpScanResult> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut tokens = line.split_whitespace();
+            let ip = Ipv6Addr::from_str(tokens.next()?).ok()?;
+            tokens.find(|t| *t == "lladdr")?;
+            let mac = MacAddress::from_str(tokens.next()?).ok()?;
+            Some(NdpScanResult { ip, mac })
+        })
+        .collect()
+}
+
+/// Parse macOS `ndp -an` output.
+///
+/// Lines look like `fe80::1%en0  0:11:22:33:44:55  en0 23h59m58s S R`; macOS drops
+/// leading zeros from MAC octets. Header and `(incomplete)` entries are skipped.
+pub fn parse_ndp_output(output: &str) -> Vec<NdpScanResult> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut tokens = line.split_whitespace();
+            let ip_token = tokens.next()?;
+            let ip_str = ip_token.split('%').next()?;
+            let ip = Ipv6Addr::from_str(ip_str).ok()?;
+
+            let octets: Vec<u8> = tokens
+                .next()?
+                .split(':')
+                .map(|o| u8::from_str_radix(o, 16))
+                .collect::<Result<_, _>>()
+                .ok()?;
+            let mac = MacAddress::new(octets.try_into().ok()?);
+
+            Some(NdpScanResult { ip, mac })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ip_neigh_output() {
+        let output = "\
+2001:db8::5 dev eth0 lladdr 52:54:00:12:34:56 REACHABLE
+fe80::1 dev eth0 lladdr 00:11:22:33:44:55 router STALE
+2001:db8::9 dev eth0  FAILED
+2001:db8::a dev eth0  INCOMPLETE
+";
+        let results = parse_ip_neigh_output(output);
+        assert_eq!(results.len(), 2);
+        assert_eq!(
+            results[0].ip,
+            Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 5)
+        );
+        assert_eq!(results[0].mac.bytes(), [0x52, 0x54, 0x00, 0x12, 0x34, 0x56]);
+        assert_eq!(results[1].ip, Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1));
+    }
+
+    #[test]
+    fn test_parse_ndp_output() {
+        let output = "\
+Neighbor                        Linklayer Address  Netif Expire    St Flgs Prbs
+2001:db8::1                     0:11:22:33:44:55     en0 23h59m58s S  R
+fe80::1%en0                     a:b:c:d:e:f          en0 23h59m58s S  R
+2001:db8::2                     (incomplete)         en0 expired   N
+";
+        let results = parse_ndp_output(output);
+        assert_eq!(results.len(), 2);
+        assert_eq!(
+            results[0].ip,
+            Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)
+        );
+        assert_eq!(results[0].mac.bytes(), [0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+        assert_eq!(results[1].ip, Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1));
+        assert_eq!(results[1].mac.bytes(), [0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f]);
+    }
+}
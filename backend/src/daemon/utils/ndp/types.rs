@@ -0,0 +1,9 @@
+use mac_address::MacAddress;
+use std::net::Ipv6Addr;
+
+/// IPv6 neighbor found via NDP (solicitation reply or neighbor cache)
+#[derive(Debug, Clone)]
+pub struct NdpScanResult {
+    pub ip: Ipv6Addr,
+    pub mac: MacAddress,
+}
@@ -0,0 +1,89 @@
+//! Passive host discovery from sniffed traffic.
+//!
+//! Listens on an interface without transmitting anything and learns about devices from
+//! the broadcast and multicast chatter they send on their own:
+//! - ARP requests, replies and gratuitous announcements (MAC + IPv4)
+//! - DHCP client messages (MAC, requested address, hostname, vendor class)
+//! - mDNS queries and announcements (hostname, DNS-SD service types)
+//! - SSDP NOTIFY / M-SEARCH (UPnP server string and description URL)
+//! - LLDP and CDP frames (system name and description, management address)
+//!
+//! Only traffic that reaches the daemon's interface can be heard, so hosts on a switched
+//! segment show up once they broadcast - or, with a mirror/SPAN port, from all their traffic.
+
+pub mod parse;
+pub mod types;
+
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, anyhow};
+use mac_address::MacAddress;
+use pnet::datalink::{self, Channel, NetworkInterface};
+use tokio_util::sync::CancellationToken;
+
+pub use parse::parse_frame;
+pub use types::{PassiveObservation, PassiveSource};
+
+/// Listen on an interface until `duration` elapses or `cancel` fires.
+///
+/// The capture channel is opened before returning, so missing raw socket privileges surface
+/// as an error here. Observations are streamed from a background thread as frames arrive;
+/// the receiver disconnects when listening stops. Frames sent by `own_mac` are ignored.
+pub fn listen(
+    interface: &NetworkInterface,
+    own_mac: Option<MacAddress>,
+    duration: Duration,
+    cancel: CancellationToken,
+) -> Result<std::sync::mpsc::Receiver<PassiveObservation>> {
+    let config = datalink::Config {
+        read_timeout: Some(Duration::from_millis(200)),
+        read_buffer_size: 65536,
+        promiscuous: true,
+        ..Default::default()
+    };
+
+    // The transmit half is dropped immediately - passive discovery never sends
+    let mut rx = match datalink::channel(interface, config)? {
+        Channel::Ethernet(_, rx) => rx,
+        _ => return Err(anyhow!("Unsupported channel type")),
+    };
+
+    let (tx, result_rx) = std::sync::mpsc::channel();
+    let interface_name = interface.name.clone();
+
+    std::thread::spawn(move || {
+        let deadline = Instant::now() + duration;
+        let mut frames = 0u64;
+        let mut observations = 0u64;
+
+        while Instant::now() < deadline && !cancel.is_cancelled() {
+            // Read timeouts surface as errors - they just let us re-check the deadline
+            let Ok(frame) = rx.next() else {
+                continue;
+            };
+            frames += 1;
+
+            let Some(observation) = parse_frame(frame) else {
+                continue;
+            };
+            if own_mac.is_some_and(|mac| mac == observation.mac) {
+                continue;
+            }
+
+            observations += 1;
+            if tx.send(observation).is_err() {
+                break;
+            }
+        }
+
+        tracing::debug!(
+            interface = %interface_name,
+            frames,
+            observations,
+            cancelled = cancel.is_cancelled(),
+            "Passive listening finished"
+        );
+    });
+
+    Ok(result_rx)
+}
@@ -0,0 +1,498 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use dhcproto::v4::{self, Decodable, DhcpOption, Message, OptionCode};
+use hickory_resolver::proto::op::Message as DnsMessage;
+use hickory_resolver::proto::rr::{Name, RData};
+use mac_address::MacAddress;
+use pnet::packet::Packet;
+use pnet::packet::arp::ArpPacket;
+use pnet::packet::ip::IpNextHeaderProtocols;
+use pnet::packet::ipv4::Ipv4Packet;
+use pnet::packet::ipv6::Ipv6Packet;
+use pnet::packet::udp::UdpPacket;
+
+use super::types::{PassiveObservation, PassiveSource};
+
+const ETHERTYPE_ARP: u16 = 0x0806;
+const ETHERTYPE_IPV4: u16 = 0x0800;
+const ETHERTYPE_IPV6: u16 = 0x86dd;
+const ETHERTYPE_VLAN: u16 = 0x8100;
+const ETHERTYPE_LLDP: u16 = 0x88cc;
+
+/// CDP is sent to this multicast address in 802.3 frames with an LLC/SNAP header
+const CDP_DESTINATION: [u8; 6] = [0x01, 0x00, 0x0c, 0xcc, 0xcc, 0xcc];
+const CDP_SNAP_HEADER: [u8; 8] = [0xaa, 0xaa, 0x03, 0x00, 0x00, 0x0c, 0x20, 0x00];
+
+const DHCP_SERVER_PORT: u16 = 67;
+const DHCP_CLIENT_PORT: u16 = 68;
+const MDNS_PORT: u16 = 5353;
+const SSDP_PORT: u16 = 1900;
+
+/// Parse a captured Ethernet frame into what it reveals about its sender.
+///
+/// Returns `None` for frames from protocols passive discovery doesn't learn from, and for
+/// malformed frames.
+pub fn parse_frame(frame: &[u8]) -> Option<PassiveObservation> {
+    let destination: [u8; 6] = frame.get(0..6)?.try_into().ok()?;
+    let source = MacAddress::new(frame.get(6..12)?.try_into().ok()?);
+
+    if !is_unicast_mac(&source) {
+        return None;
+    }
+
+    if destination == CDP_DESTINATION {
+        return parse_cdp(source, frame.get(14..)?);
+    }
+
+    let (ethertype, payload) = match u16::from_be_bytes([frame[12], frame[13]]) {
+        ETHERTYPE_VLAN => (
+            u16::from_be_bytes([*frame.get(16)?, *frame.get(17)?]),
+            frame.get(18..)?,
+        ),
+        ethertype => (ethertype, frame.get(14..)?),
+    };
+
+    match ethertype {
+        ETHERTYPE_ARP => parse_arp(payload),
+        ETHERTYPE_IPV4 => {
+            let ip = Ipv4Packet::new(payload)?;
+            if ip.get_next_level_protocol() != IpNextHeaderProtocols::Udp {
+                return None;
+            }
+            parse_udp(source, IpAddr::V4(ip.get_source()), ip.payload())
+        }
+        ETHERTYPE_IPV6 => {
+            let ip = Ipv6Packet::new(payload)?;
+            if ip.get_next_header() != IpNextHeaderProtocols::Udp {
+                return None;
+            }
+            parse_udp(source, IpAddr::V6(ip.get_source()), ip.payload())
+        }
+        ETHERTYPE_LLDP => parse_lldp(source, payload),
+        _ => None,
+    }
+}
+
+/// Any ARP packet - request, reply, gratuitous or probe - names its sender
+fn parse_arp(payload: &[u8]) -> Option<PassiveObservation> {
+    let arp = ArpPacket::new(payload)?;
+    let mac = MacAddress::new(arp.get_sender_hw_addr().octets());
+    if !is_unicast_mac(&mac) {
+        return None;
+    }
+
+    // ARP probes (RFC 5227) use an unspecified sender address
+    let ip = Some(arp.get_sender_proto_addr())
+        .filter(|ip| !ip.is_unspecified())
+        .map(IpAddr::V4);
+
+    Some(PassiveObservation::new(PassiveSource::Arp, mac, ip))
+}
+
+fn parse_udp(source: MacAddress, ip: IpAddr, payload: &[u8]) -> Option<PassiveObservation> {
+    let udp = UdpPacket::new(payload)?;
+    let (src_port, dst_port) = (udp.get_source(), udp.get_destination());
+
+    if src_port == DHCP_CLIENT_PORT && dst_port == DHCP_SERVER_PORT {
+        parse_dhcp(udp.payload())
+    } else if src_port == MDNS_PORT || dst_port == MDNS_PORT {
+        parse_mdns(source, ip, udp.payload())
+    } else if dst_port == SSDP_PORT || src_port == SSDP_PORT {
+        parse_ssdp(source, ip, udp.payload())
+    } else {
+        None
+    }
+}
+
+/// DHCP client messages carry the client's hardware address, requested or current address,
+/// hostname and vendor class
+fn parse_dhcp(payload: &[u8]) -> Option<PassiveObservation> {
+    let message = Message::decode(&mut dhcproto::Decoder::new(payload)).ok()?;
+    if message.opcode() != v4::Opcode::BootRequest {
+        return None;
+    }
+
+    // chaddr rather than the frame source, which is the relay's MAC for relayed requests
+    let mac = MacAddress::new(message.chaddr().get(0..6)?.try_into().ok()?);
+    if !is_unicast_mac(&mac) {
+        return None;
+    }
+
+    let ip = Some(message.ciaddr())
+        .filter(|ip| !ip.is_unspecified())
+        .or_else(
+            || match message.opts().get(OptionCode::RequestedIpAddress) {
+                Some(DhcpOption::RequestedIpAddress(ip)) => Some(*ip),
+                _ => None,
+            },
+        )
+        .map(IpAddr::V4);
+
+    let mut observation = PassiveObservation::new(PassiveSource::Dhcp, mac, ip);
+    if let Some(DhcpOption::Hostname(hostname)) = message.opts().get(OptionCode::Hostname) {
+        observation.hostname = non_empty(hostname);
+    }
+    if let Some(DhcpOption::ClassIdentifier(class)) =
+        message.opts().get(OptionCode::ClassIdentifier)
+    {
+        observation.vendor_class = non_empty(&String::from_utf8_lossy(class));
+    }
+
+    Some(observation)
+}
+
+/// mDNS queries only show the sender exists; responses and announcements also carry its
+/// hostname (A/AAAA owner names) and the DNS-SD service types it advertises
+fn parse_mdns(source: MacAddress, ip: IpAddr, payload: &[u8]) -> Option<PassiveObservation> {
+    let message = DnsMessage::from_vec(payload).ok()?;
+    let mut observation = PassiveObservation::new(PassiveSource::Mdns, source, Some(ip));
+
+    let records = message.answers().iter().chain(message.additionals());
+    for record in records {
+        match record.data() {
+            RData::A(a) if observation.hostname.is_none() || IpAddr::V4(a.0) == ip => {
+                observation.hostname = mdns_hostname(record.name());
+            }
+            RData::AAAA(aaaa) if observation.hostname.is_none() || IpAddr::V6(aaaa.0) == ip => {
+                observation.hostname = mdns_hostname(record.name());
+            }
+            RData::PTR(ptr) => {
+                // "_services._dns-sd._udp.local PTR _ipp._tcp.local" enumerates types, while
+                // "_ipp._tcp.local PTR Printer._ipp._tcp.local" names an instance of one
+                let service_type =
+                    dns_sd_service_type(record.name()).or_else(|| dns_sd_service_type(&ptr.0));
+                if let Some(service_type) = service_type
+                    && !observation.mdns_services.contains(&service_type)
+                {
+                    observation.mdns_services.push(service_type);
+                }
+            }
+            RData::SRV(srv) if observation.hostname.is_none() => {
+                observation.hostname = mdns_hostname(srv.target());
+            }
+            _ => {}
+        }
+    }
+
+    Some(observation)
+}
+
+/// "printer.local." -> "printer"
+fn mdns_hostname(name: &Name) -> Option<String> {
+    let name = name.to_utf8();
+    let name = name.trim_end_matches('.');
+    non_empty(name.strip_suffix(".local").unwrap_or(name))
+}
+
+/// Extract the "_service._proto" pair from a DNS-SD name, skipping the meta-query type
+pub fn dns_sd_service_type(name: &Name) -> Option<String> {
+    let name = name.to_utf8();
+    let labels: Vec<&str> = name.split('.').filter(|l| !l.is_empty()).collect();
+    let proto = labels.iter().rposition(|l| *l == "_tcp" || *l == "_udp")?;
+    let service = labels.get(proto.checked_sub(1)?)?;
+
+    if !service.starts_with('_') || *service == "_dns-sd" {
+        return None;
+    }
+
+    Some(format!("{}.{}", service, labels[proto]))
+}
+
+/// SSDP NOTIFY announcements and search responses carry SERVER and LOCATION headers;
+/// M-SEARCH requests from control points only show the sender exists
+fn parse_ssdp(source: MacAddress, ip: IpAddr, payload: &[u8]) -> Option<PassiveObservation> {
+    let text = std::str::from_utf8(payload).ok()?;
+    let mut lines = text.lines();
+    let start_line = lines.next()?;
+    if !(start_line.starts_with("NOTIFY")
+        || start_line.starts_with("M-SEARCH")
+        || start_line.starts_with("HTTP/1.1"))
+    {
+        return None;
+    }
+
+    let mut observation = PassiveObservation::new(PassiveSource::Ssdp, source, Some(ip));
+    for line in lines {
+        let Some((header, value)) = line.split_once(':') else {
+            continue;
+        };
+        match header.trim().to_ascii_uppercase().as_str() {
+            "SERVER" => observation.ssdp_server = non_empty(value),
+            "LOCATION" => observation.ssdp_location = non_empty(value),
+            _ => {}
+        }
+    }
+
+    Some(observation)
+}
+
+/// LLDP TLVs: 5 = system name, 6 = system description, 8 = management address
+fn parse_lldp(source: MacAddress, payload: &[u8]) -> Option<PassiveObservation> {
+    let mut observation = PassiveObservation::new(PassiveSource::Lldp, source, None);
+    let mut rest = payload;
+
+    while rest.len() >= 2 {
+        let header = u16::from_be_bytes([rest[0], rest[1]]);
+        let (tlv_type, len) = (header >> 9, (header & 0x01ff) as usize);
+        let value = rest.get(2..2 + len)?;
+        rest = &rest[2 + len..];
+
+        match tlv_type {
+            0 => break,
+            5 => observation.hostname = non_empty(&String::from_utf8_lossy(value)),
+            6 => observation.system_description = non_empty(&String::from_utf8_lossy(value)),
+            // Address string length (subtype + address), IANA address family, address
+            8 if observation.ip.is_none() => {
+                let addr_len = (*value.first()? as usize).checked_sub(1)?;
+                let addr = value.get(2..2 + addr_len)?;
+                observation.ip = match (value[1], addr_len) {
+                    (1, 4) => Some(IpAddr::V4(Ipv4Addr::from(<[u8; 4]>::try_from(addr).ok()?))),
+                    (2, 16) => Some(IpAddr::V6(Ipv6Addr::from(<[u8; 16]>::try_from(addr).ok()?))),
+                    _ => None,
+                };
+            }
+            _ => {}
+        }
+    }
+
+    Some(observation)
+}
+
+/// CDP TLVs: 1 = device ID, 2 = addresses, 5 = software version, 6 = platform
+fn parse_cdp(source: MacAddress, llc: &[u8]) -> Option<PassiveObservation> {
+    if llc.get(0..8)? != CDP_SNAP_HEADER {
+        return None;
+    }
+
+    let mut observation = PassiveObservation::new(PassiveSource::Cdp, source, None);
+    // Skip the CDP header: version, TTL, checksum
+    let mut rest = llc.get(12..)?;
+
+    while rest.len() >= 4 {
+        let tlv_type = u16::from_be_bytes([rest[0], rest[1]]);
+        let len = u16::from_be_bytes([rest[2], rest[3]]) as usize;
+        if len < 4 {
+            break;
+        }
+        let value = rest.get(4..len)?;
+        rest = &rest[len..];
+
+        match tlv_type {
+            1 => observation.hostname = non_empty(&String::from_utf8_lossy(value)),
+            2 => observation.ip = cdp_ipv4_address(value).map(IpAddr::V4),
+            5 => observation.system_description = non_empty(&String::from_utf8_lossy(value)),
+            6 => observation.platform = non_empty(&String::from_utf8_lossy(value)),
+            _ => {}
+        }
+    }
+
+    Some(observation)
+}
+
+/// First IPv4 entry (NLPID protocol 0xCC) in a CDP addresses TLV
+fn cdp_ipv4_address(value: &[u8]) -> Option<Ipv4Addr> {
+    let count = u32::from_be_bytes(value.get(0..4)?.try_into().ok()?);
+    let mut rest = &value[4..];
+
+    for _ in 0..count {
+        let protocol_len = *rest.get(1)? as usize;
+        let protocol = rest.get(2..2 + protocol_len)?;
+        let addr_start = 2 + protocol_len;
+        let addr_len =
+            u16::from_be_bytes([*rest.get(addr_start)?, *rest.get(addr_start + 1)?]) as usize;
+        let addr = rest.get(addr_start + 2..addr_start + 2 + addr_len)?;
+
+        if protocol == [0xcc] && addr_len == 4 {
+            return Some(Ipv4Addr::new(addr[0], addr[1], addr[2], addr[3]));
+        }
+        rest = &rest[addr_start + 2 + addr_len..];
+    }
+
+    None
+}
+
+fn is_unicast_mac(mac: &MacAddress) -> bool {
+    let bytes = mac.bytes();
+    bytes[0] & 0x01 == 0 && bytes != [0; 6]
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let value = value.trim().trim_matches(char::from(0));
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dhcproto::Encodable;
+    use hickory_resolver::proto::op::{MessageType, Query};
+    use hickory_resolver::proto::rr::rdata::{A, PTR};
+    use hickory_resolver::proto::rr::{Record, RecordType};
+    use std::str::FromStr;
+
+    const SENDER: [u8; 6] = [0x00, 0x1a, 0x2b, 0x01, 0x02, 0x03];
+
+    fn ethernet(destination: [u8; 6], ethertype: u16, payload: &[u8]) -> Vec<u8> {
+        let mut frame = destination.to_vec();
+        frame.extend_from_slice(&SENDER);
+        frame.extend_from_slice(&ethertype.to_be_bytes());
+        frame.extend_from_slice(payload);
+        frame
+    }
+
+    fn udp_frame(src: Ipv4Addr, dst: Ipv4Addr, ports: (u16, u16), payload: &[u8]) -> Vec<u8> {
+        let udp_len = 8 + payload.len() as u16;
+        let mut ip = vec![0x45, 0, 0, 0, 0, 0, 0, 0, 64, 17, 0, 0];
+        ip[2..4].copy_from_slice(&(20 + udp_len).to_be_bytes());
+        ip.extend_from_slice(&src.octets());
+        ip.extend_from_slice(&dst.octets());
+        ip.extend_from_slice(&ports.0.to_be_bytes());
+        ip.extend_from_slice(&ports.1.to_be_bytes());
+        ip.extend_from_slice(&udp_len.to_be_bytes());
+        ip.extend_from_slice(&[0, 0]);
+        ip.extend_from_slice(payload);
+        ethernet([0xff; 6], ETHERTYPE_IPV4, &ip)
+    }
+
+    #[test]
+    fn test_parse_gratuitous_arp() {
+        let mut arp = vec![0, 1, 8, 0, 6, 4, 0, 1];
+        arp.extend_from_slice(&SENDER);
+        arp.extend_from_slice(&[192, 168, 1, 20]);
+        arp.extend_from_slice(&[0; 6]);
+        arp.extend_from_slice(&[192, 168, 1, 20]);
+
+        let observation = parse_frame(&ethernet([0xff; 6], ETHERTYPE_ARP, &arp)).unwrap();
+        assert_eq!(observation.source, PassiveSource::Arp);
+        assert_eq!(observation.mac, MacAddress::new(SENDER));
+        assert_eq!(observation.ip, Some("192.168.1.20".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_parse_dhcp_request() {
+        let mut message = Message::default();
+        message.set_chaddr(&SENDER);
+        message
+            .opts_mut()
+            .insert(DhcpOption::MessageType(v4::MessageType::Request));
+        message
+            .opts_mut()
+            .insert(DhcpOption::RequestedIpAddress(Ipv4Addr::new(10, 0, 0, 42)));
+        message
+            .opts_mut()
+            .insert(DhcpOption::Hostname("plc-line-3".to_string()));
+        message
+            .opts_mut()
+            .insert(DhcpOption::ClassIdentifier(b"MSFT 5.0".to_vec()));
+        let payload = message.to_vec().unwrap();
+
+        let frame = udp_frame(
+            Ipv4Addr::UNSPECIFIED,
+            Ipv4Addr::BROADCAST,
+            (DHCP_CLIENT_PORT, DHCP_SERVER_PORT),
+            &payload,
+        );
+        let observation = parse_frame(&frame).unwrap();
+        assert_eq!(observation.source, PassiveSource::Dhcp);
+        assert_eq!(observation.ip, Some("10.0.0.42".parse().unwrap()));
+        assert_eq!(observation.hostname.as_deref(), Some("plc-line-3"));
+        assert_eq!(observation.vendor_class.as_deref(), Some("MSFT 5.0"));
+    }
+
+    #[test]
+    fn test_parse_mdns_announcement() {
+        let host = Name::from_str("office-printer.local.").unwrap();
+        let service = Name::from_str("_ipp._tcp.local.").unwrap();
+        let instance = Name::from_str("Office-Printer._ipp._tcp.local.").unwrap();
+
+        let mut message = DnsMessage::new();
+        message.set_message_type(MessageType::Response);
+        message.add_query(Query::query(service.clone(), RecordType::PTR));
+        message.add_answer(Record::from_rdata(service, 120, RData::PTR(PTR(instance))));
+        message.add_additional(Record::from_rdata(
+            host,
+            120,
+            RData::A(A(Ipv4Addr::new(192, 168, 1, 30))),
+        ));
+        let payload = message.to_vec().unwrap();
+
+        let frame = udp_frame(
+            Ipv4Addr::new(192, 168, 1, 30),
+            Ipv4Addr::new(224, 0, 0, 251),
+            (MDNS_PORT, MDNS_PORT),
+            &payload,
+        );
+        let observation = parse_frame(&frame).unwrap();
+        assert_eq!(observation.source, PassiveSource::Mdns);
+        assert_eq!(observation.hostname.as_deref(), Some("office-printer"));
+        assert_eq!(observation.mdns_services, vec!["_ipp._tcp".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_ssdp_notify() {
+        let payload = "NOTIFY * HTTP/1.1\r\nHOST: 239.255.255.250:1900\r\n\
+            LOCATION: http://192.168.1.40:49152/description.xml\r\n\
+            Server: Linux/3.14 UPnP/1.0 IpBridge/1.50.0\r\nNTS: ssdp:alive\r\n\r\n";
+        let frame = udp_frame(
+            Ipv4Addr::new(192, 168, 1, 40),
+            Ipv4Addr::new(239, 255, 255, 250),
+            (SSDP_PORT, SSDP_PORT),
+            payload.as_bytes(),
+        );
+
+        let observation = parse_frame(&frame).unwrap();
+        assert_eq!(observation.source, PassiveSource::Ssdp);
+        assert_eq!(
+            observation.ssdp_server.as_deref(),
+            Some("Linux/3.14 UPnP/1.0 IpBridge/1.50.0")
+        );
+        assert_eq!(
+            observation.ssdp_location.as_deref(),
+            Some("http://192.168.1.40:49152/description.xml")
+        );
+    }
+
+    #[test]
+    fn test_parse_lldp_and_cdp() {
+        let tlv = |tlv_type: u16, value: &[u8]| {
+            let mut bytes = ((tlv_type << 9) | value.len() as u16)
+                .to_be_bytes()
+                .to_vec();
+            bytes.extend_from_slice(value);
+            bytes
+        };
+        let mut lldp = tlv(5, b"core-switch");
+        lldp.extend(tlv(6, b"Switch OS 12.1"));
+        lldp.extend(tlv(8, &[5, 1, 10, 0, 0, 2, 2, 0, 0, 0, 1, 0]));
+        lldp.extend(tlv(0, &[]));
+
+        let frame = ethernet([0x01, 0x80, 0xc2, 0, 0, 0x0e], ETHERTYPE_LLDP, &lldp);
+        let observation = parse_frame(&frame).unwrap();
+        assert_eq!(observation.source, PassiveSource::Lldp);
+        assert_eq!(observation.hostname.as_deref(), Some("core-switch"));
+        assert_eq!(
+            observation.system_description.as_deref(),
+            Some("Switch OS 12.1")
+        );
+        assert_eq!(observation.ip, Some("10.0.0.2".parse().unwrap()));
+
+        let cdp_tlv = |tlv_type: u16, value: &[u8]| {
+            let mut bytes = tlv_type.to_be_bytes().to_vec();
+            bytes.extend_from_slice(&(4 + value.len() as u16).to_be_bytes());
+            bytes.extend_from_slice(value);
+            bytes
+        };
+        let mut cdp = CDP_SNAP_HEADER.to_vec();
+        cdp.extend_from_slice(&[2, 180, 0, 0]);
+        cdp.extend(cdp_tlv(1, b"access-sw-2"));
+        cdp.extend(cdp_tlv(2, &[0, 0, 0, 1, 1, 1, 0xcc, 0, 4, 10, 0, 0, 3]));
+        cdp.extend(cdp_tlv(6, b"cisco WS-C2960"));
+
+        let frame = ethernet(CDP_DESTINATION, cdp.len() as u16, &cdp);
+        let observation = parse_frame(&frame).unwrap();
+        assert_eq!(observation.source, PassiveSource::Cdp);
+        assert_eq!(observation.hostname.as_deref(), Some("access-sw-2"));
+        assert_eq!(observation.platform.as_deref(), Some("cisco WS-C2960"));
+        assert_eq!(observation.ip, Some("10.0.0.3".parse().unwrap()));
+    }
+}
@@ -0,0 +1,62 @@
+use mac_address::MacAddress;
+use std::net::IpAddr;
+use strum::Display;
+
+/// Protocol a passive observation was learned from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Display)]
+pub enum PassiveSource {
+    #[strum(serialize = "ARP")]
+    Arp,
+    #[strum(serialize = "DHCP")]
+    Dhcp,
+    #[strum(serialize = "mDNS")]
+    Mdns,
+    #[strum(serialize = "SSDP")]
+    Ssdp,
+    #[strum(serialize = "LLDP")]
+    Lldp,
+    #[strum(serialize = "CDP")]
+    Cdp,
+}
+
+/// What a single sniffed frame revealed about the device that sent it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassiveObservation {
+    pub source: PassiveSource,
+    /// Sender's hardware address
+    pub mac: MacAddress,
+    /// Sender's address, if the frame carried one
+    pub ip: Option<IpAddr>,
+    /// Name the device announced (DHCP option 12, mDNS A/AAAA owner, LLDP system name,
+    /// CDP device ID)
+    pub hostname: Option<String>,
+    /// DHCP vendor class identifier (option 60)
+    pub vendor_class: Option<String>,
+    /// mDNS service types the device advertised (e.g. "_ipp._tcp")
+    pub mdns_services: Vec<String>,
+    /// SSDP SERVER header
+    pub ssdp_server: Option<String>,
+    /// SSDP LOCATION header, the device's UPnP description URL
+    pub ssdp_location: Option<String>,
+    /// LLDP system description or CDP software version
+    pub system_description: Option<String>,
+    /// CDP platform string
+    pub platform: Option<String>,
+}
+
+impl PassiveObservation {
+    pub fn new(source: PassiveSource, mac: MacAddress, ip: Option<IpAddr>) -> Self {
+        Self {
+            source,
+            mac,
+            ip,
+            hostname: None,
+            vendor_class: None,
+            mdns_services: Vec::new(),
+            ssdp_server: None,
+            ssdp_location: None,
+            system_description: None,
+            platform: None,
+        }
+    }
+}
@@ -0,0 +1,472 @@
+//! Minimal read-only client for the Proxmox VE API, covering what virtualization discovery
+//! needs. Authenticates with an API token; the token needs `Sys.Audit` on `/nodes` and
+//! `VM.Audit` (plus `VM.Monitor` for guest agent queries) on `/vms`.
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::time::Duration;
+
+use anyhow::{Error, Result, anyhow};
+use ipnetwork::IpNetwork;
+use mac_address::MacAddress;
+use reqwest::Client;
+use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderValue};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub struct ProxmoxClient {
+    client: Client,
+    url: String,
+}
+
+impl ProxmoxClient {
+    pub fn new(url: &str, token_id: &str, token_secret: &str, verify_tls: bool) -> Result<Self> {
+        let mut auth = HeaderValue::from_str(&format!("PVEAPIToken={}={}", token_id, token_secret))
+            .map_err(|_| anyhow!("Proxmox API token contains invalid characters"))?;
+        auth.set_sensitive(true);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, auth);
+
+        Ok(Self {
+            client: Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .default_headers(headers)
+                .danger_accept_invalid_certs(!verify_tls)
+                .build()
+                .map_err(|e| anyhow!("Could not build client {}", e))?,
+            url: url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    pub async fn nodes(&self) -> Result<Vec<ProxmoxNode>> {
+        self.get("/nodes").await
+    }
+
+    pub async fn node_network(&self, node: &str) -> Result<Vec<NodeNetwork>> {
+        self.get(&format!("/nodes/{}/network", node)).await
+    }
+
+    pub async fn guests(&self, node: &str, kind: GuestKind) -> Result<Vec<Guest>> {
+        self.get(&format!("/nodes/{}/{}", node, kind.path())).await
+    }
+
+    pub async fn guest_config(
+        &self,
+        node: &str,
+        kind: GuestKind,
+        vmid: u32,
+    ) -> Result<HashMap<String, serde_json::Value>> {
+        self.get(&format!("/nodes/{}/{}/{}/config", node, kind.path(), vmid))
+            .await
+    }
+
+    /// Addresses a running guest reports: through the QEMU guest agent for VMs, and from the
+    /// container's network namespace for LXC
+    pub async fn guest_addresses(
+        &self,
+        node: &str,
+        kind: GuestKind,
+        vmid: u32,
+    ) -> Result<Vec<GuestAddress>> {
+        match kind {
+            GuestKind::Qemu => {
+                let result: AgentResult<Vec<AgentInterface>> = self
+                    .get(&format!(
+                        "/nodes/{}/qemu/{}/agent/network-get-interfaces",
+                        node, vmid
+                    ))
+                    .await?;
+                Ok(result.result.into_iter().map(GuestAddress::from).collect())
+            }
+            GuestKind::Lxc => {
+                let interfaces: Vec<LxcInterface> = self
+                    .get(&format!("/nodes/{}/lxc/{}/interfaces", node, vmid))
+                    .await?;
+                Ok(interfaces.into_iter().map(GuestAddress::from).collect())
+            }
+        }
+    }
+
+    /// Hostname a VM's guest agent reports
+    pub async fn guest_hostname(&self, node: &str, vmid: u32) -> Result<String> {
+        let result: AgentResult<AgentHostName> = self
+            .get(&format!(
+                "/nodes/{}/qemu/{}/agent/get-host-name",
+                node, vmid
+            ))
+            .await?;
+        Ok(result.result.host_name)
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let response = self
+            .client
+            .get(format!("{}/api2/json{}", self.url, path))
+            .send()
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(Error::msg(format!(
+                "Proxmox API returned {} for {}: {}",
+                status, path, body
+            )));
+        }
+
+        Ok(response.json::<Data<T>>().await?.data)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Data<T> {
+    data: T,
+}
+
+#[derive(Debug, Deserialize)]
+struct AgentResult<T> {
+    result: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuestKind {
+    Qemu,
+    Lxc,
+}
+
+impl GuestKind {
+    fn path(&self) -> &'static str {
+        match self {
+            GuestKind::Qemu => "qemu",
+            GuestKind::Lxc => "lxc",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ProxmoxNode {
+    pub node: String,
+    pub status: Option<String>,
+}
+
+impl ProxmoxNode {
+    pub fn is_online(&self) -> bool {
+        self.status.as_deref().is_none_or(|s| s == "online")
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct NodeNetwork {
+    pub iface: String,
+    #[serde(rename = "type")]
+    pub iface_type: String,
+    pub cidr: Option<String>,
+    pub address: Option<String>,
+    pub netmask: Option<String>,
+    pub cidr6: Option<String>,
+}
+
+impl NodeNetwork {
+    pub fn is_bridge(&self) -> bool {
+        self.iface_type == "bridge" || self.iface_type == "OVSBridge"
+    }
+
+    /// Networks configured on the interface, from `cidr`/`cidr6` or `address` + `netmask`
+    pub fn networks(&self) -> Vec<IpNetwork> {
+        let v4 = self
+            .cidr
+            .as_deref()
+            .and_then(|c| c.parse().ok())
+            .or_else(|| {
+                let address: IpAddr = self.address.as_deref()?.parse().ok()?;
+                let netmask = self.netmask.as_deref()?;
+                let prefix = match netmask.parse::<u8>() {
+                    Ok(prefix) => prefix,
+                    Err(_) => ipnetwork::ipv4_mask_to_prefix(netmask.parse().ok()?).ok()?,
+                };
+                IpNetwork::new(address, prefix).ok()
+            });
+        let v6 = self.cidr6.as_deref().and_then(|c| c.parse().ok());
+
+        v4.into_iter().chain(v6).collect()
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Guest {
+    #[serde(deserialize_with = "number_or_string")]
+    pub vmid: u32,
+    pub name: Option<String>,
+    pub status: String,
+    #[serde(deserialize_with = "flag")]
+    pub template: bool,
+}
+
+impl Guest {
+    pub fn is_running(&self) -> bool {
+        self.status == "running"
+    }
+}
+
+/// A `netN` entry from a guest's config
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GuestNic {
+    /// Config key, e.g. `net0`
+    pub key: String,
+    /// Interface name inside the container (LXC only)
+    pub name: Option<String>,
+    pub mac: Option<MacAddress>,
+    pub bridge: Option<String>,
+    pub vlan_tag: Option<u16>,
+    /// Static address from an LXC `ip=`/`ip6=` setting
+    pub static_ips: Vec<IpAddr>,
+}
+
+impl GuestNic {
+    /// Parse every `netN` entry of a guest config, ordered by index
+    pub fn from_config(config: &HashMap<String, serde_json::Value>) -> Vec<Self> {
+        let mut nics: Vec<(u32, Self)> = config
+            .iter()
+            .filter_map(|(key, value)| {
+                let index = key.strip_prefix("net")?.parse::<u32>().ok()?;
+                Some((index, Self::parse(key, value.as_str()?)))
+            })
+            .collect();
+        nics.sort_by_key(|(index, _)| *index);
+        nics.into_iter().map(|(_, nic)| nic).collect()
+    }
+
+    /// Parse a property string like `virtio=BC:24:11:2A:3B:4C,bridge=vmbr0,tag=20` (QEMU) or
+    /// `name=eth0,bridge=vmbr0,hwaddr=BC:24:11:2A:3B:4C,ip=10.0.20.5/24,type=veth` (LXC)
+    pub fn parse(key: &str, value: &str) -> Self {
+        let mut nic = Self {
+            key: key.to_string(),
+            ..Default::default()
+        };
+
+        for (k, v) in value.split(',').filter_map(|part| part.split_once('=')) {
+            match k {
+                "bridge" => nic.bridge = Some(v.to_string()),
+                "tag" => nic.vlan_tag = v.parse().ok(),
+                "name" => nic.name = Some(v.to_string()),
+                "ip" | "ip6" => {
+                    if let Ok(network) = v.parse::<IpNetwork>() {
+                        nic.static_ips.push(network.ip());
+                    }
+                }
+                // QEMU puts the MAC on the model key (virtio=, e1000=, ...) or on macaddr=
+                _ => {
+                    if let Ok(mac) = v.parse::<MacAddress>() {
+                        nic.mac = Some(mac);
+                    }
+                }
+            }
+        }
+
+        nic
+    }
+
+    /// Where the NIC attaches on the node, e.g. `vmbr0` or `vmbr0 VLAN 20`
+    pub fn attachment(&self) -> Option<String> {
+        let bridge = self.bridge.as_deref()?;
+        Some(match self.vlan_tag {
+            Some(tag) => format!("{} VLAN {}", bridge, tag),
+            None => bridge.to_string(),
+        })
+    }
+}
+
+/// An interface as seen from inside a running guest
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GuestAddress {
+    pub name: String,
+    pub mac: Option<MacAddress>,
+    pub ips: Vec<IpAddr>,
+}
+
+impl GuestAddress {
+    /// Whether this is the guest side of the given config NIC
+    pub fn belongs_to(&self, nic: &GuestNic) -> bool {
+        match (self.mac, nic.mac) {
+            (Some(mac), Some(nic_mac)) => mac == nic_mac,
+            _ => nic.name.as_deref() == Some(self.name.as_str()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct AgentInterface {
+    name: String,
+    #[serde(rename = "hardware-address")]
+    hardware_address: Option<String>,
+    #[serde(rename = "ip-addresses", default)]
+    ip_addresses: Vec<AgentIpAddress>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AgentIpAddress {
+    #[serde(rename = "ip-address")]
+    ip_address: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct AgentHostName {
+    #[serde(rename = "host-name")]
+    host_name: String,
+}
+
+impl From<AgentInterface> for GuestAddress {
+    fn from(interface: AgentInterface) -> Self {
+        Self {
+            name: interface.name,
+            mac: interface.hardware_address.and_then(|mac| mac.parse().ok()),
+            ips: interface
+                .ip_addresses
+                .iter()
+                .filter_map(|a| a.ip_address.parse().ok())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct LxcInterface {
+    name: String,
+    hwaddr: Option<String>,
+    inet: Option<String>,
+    inet6: Option<String>,
+}
+
+impl From<LxcInterface> for GuestAddress {
+    fn from(interface: LxcInterface) -> Self {
+        Self {
+            name: interface.name,
+            mac: interface.hwaddr.and_then(|mac| mac.parse().ok()),
+            ips: [interface.inet, interface.inet6]
+                .iter()
+                .flatten()
+                .flat_map(|addresses| addresses.split_whitespace())
+                .filter_map(|a| a.parse::<IpNetwork>().ok())
+                .map(|n| n.ip())
+                .collect(),
+        }
+    }
+}
+
+/// VMIDs are numbers in most endpoints but strings in some older ones
+fn number_or_string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Value {
+        Number(u32),
+        String(String),
+    }
+
+    match Value::deserialize(deserializer)? {
+        Value::Number(n) => Ok(n),
+        Value::String(s) => s.parse().map_err(serde::de::Error::custom),
+    }
+}
+
+/// Proxmox booleans arrive as `0`/`1`, and occasionally as strings or real booleans
+fn flag<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    Ok(match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Bool(b) => b,
+        serde_json::Value::Number(n) => n.as_u64().is_some_and(|n| n != 0),
+        serde_json::Value::String(s) => s == "1",
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_guest_nics() {
+        let config: HashMap<String, serde_json::Value> = serde_json::from_str(
+            r#"{
+                "name": "web",
+                "net1": "name=eth1,bridge=vmbr1,hwaddr=BC:24:11:00:00:02,ip=dhcp,type=veth",
+                "net0": "virtio=BC:24:11:00:00:01,bridge=vmbr0,firewall=1,tag=20",
+                "netboot": "0"
+            }"#,
+        )
+        .unwrap();
+
+        let nics = GuestNic::from_config(&config);
+
+        assert_eq!(nics.len(), 2);
+        assert_eq!(nics[0].key, "net0");
+        assert_eq!(nics[0].mac, Some("BC:24:11:00:00:01".parse().unwrap()));
+        assert_eq!(nics[0].attachment().as_deref(), Some("vmbr0 VLAN 20"));
+        assert_eq!(nics[1].name.as_deref(), Some("eth1"));
+        assert_eq!(nics[1].attachment().as_deref(), Some("vmbr1"));
+        assert!(nics[1].static_ips.is_empty());
+
+        let lxc = GuestNic::parse(
+            "net0",
+            "name=eth0,bridge=vmbr0,ip=10.0.20.5/24,gw=10.0.20.1",
+        );
+        assert_eq!(lxc.static_ips, vec!["10.0.20.5".parse::<IpAddr>().unwrap()]);
+    }
+
+    #[test]
+    fn test_deserialize_guests_and_addresses() {
+        let guests: Vec<Guest> = serde_json::from_str(
+            r#"[
+                {"vmid": 100, "name": "web", "status": "running"},
+                {"vmid": "101", "name": "tmpl", "status": "stopped", "template": 1}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(guests[0].vmid, 100);
+        assert!(guests[0].is_running() && !guests[0].template);
+        assert_eq!(guests[1].vmid, 101);
+        assert!(guests[1].template);
+
+        let agent: AgentResult<Vec<AgentInterface>> = serde_json::from_str(
+            r#"{"result": [{
+                "name": "ens18",
+                "hardware-address": "bc:24:11:00:00:01",
+                "ip-addresses": [
+                    {"ip-address": "10.0.20.7", "ip-address-type": "ipv4", "prefix": 24}
+                ]
+            }]}"#,
+        )
+        .unwrap();
+        let address = GuestAddress::from(agent.result.into_iter().next().unwrap());
+        let nic = GuestNic::parse("net0", "virtio=BC:24:11:00:00:01,bridge=vmbr0");
+        assert!(address.belongs_to(&nic));
+        assert_eq!(address.ips, vec!["10.0.20.7".parse::<IpAddr>().unwrap()]);
+    }
+
+    #[test]
+    fn test_node_network_prefers_cidr_and_accepts_netmasks() {
+        let bridge = NodeNetwork {
+            iface: "vmbr0".to_string(),
+            iface_type: "bridge".to_string(),
+            address: Some("192.168.1.10".to_string()),
+            netmask: Some("255.255.255.0".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            bridge.networks(),
+            vec!["192.168.1.10/24".parse::<IpNetwork>().unwrap()]
+        );
+
+        let with_cidr = NodeNetwork {
+            cidr: Some("10.0.0.2/16".to_string()),
+            ..bridge
+        };
+        assert_eq!(
+            with_cidr.networks(),
+            vec!["10.0.0.2/16".parse::<IpNetwork>().unwrap()]
+        );
+    }
+}
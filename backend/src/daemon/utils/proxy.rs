@@ -0,0 +1,96 @@
+//! Reverse proxy route harvesting for network discovery.
+//!
+//! Proxies that expose their routing over an API are asked which backends they forward
+//! to, so topology can draw proxy → backend dependencies. Caddy's admin API already
+//! returned its upstreams while endpoints were probed; Traefik's services are fetched
+//! from the API that served its dashboard. Nginx Proxy Manager's API requires a login,
+//! so its proxy hosts aren't read.
+
+use std::net::{IpAddr, SocketAddr};
+
+use crate::daemon::utils::scanner::SCAN_TIMEOUT;
+use crate::server::services::definitions::caddy::Caddy;
+use crate::server::services::definitions::traefik::Traefik;
+use crate::server::services::r#impl::base::Service;
+use crate::server::services::r#impl::endpoints::EndpointResponse;
+use crate::server::services::r#impl::proxy::ProxyTarget;
+use crate::server::shared::types::metadata::HasId;
+
+const CADDY_UPSTREAMS_PATH: &str = "/reverse_proxy/upstreams";
+const TRAEFIK_DASHBOARD_PATH: &str = "/dashboard";
+const TRAEFIK_SERVICES_PATH: &str = "/api/http/services";
+
+/// Fill in `proxy_targets` for matched reverse proxies. Proxies whose routes can't be
+/// read are left without targets.
+pub async fn collect_proxy_targets(
+    services: &mut [Service],
+    endpoint_responses: &[EndpointResponse],
+    ip: IpAddr,
+) {
+    for service in services.iter_mut() {
+        let definition_id = service.base.service_definition.id();
+
+        let targets = if definition_id == Caddy.id() {
+            endpoint_responses
+                .iter()
+                .find(|r| r.endpoint.path == CADDY_UPSTREAMS_PATH)
+                .map(|r| ProxyTarget::from_caddy_upstreams(&r.body))
+                .unwrap_or_default()
+        } else if definition_id == Traefik.id() {
+            match endpoint_responses
+                .iter()
+                .find(|r| r.endpoint.path == TRAEFIK_DASHBOARD_PATH)
+            {
+                Some(dashboard) => fetch_traefik_targets(dashboard, ip).await,
+                None => Vec::new(),
+            }
+        } else {
+            continue;
+        };
+
+        tracing::debug!(
+            ip = %ip,
+            service = %service.base.name,
+            targets = targets.len(),
+            "Read reverse proxy targets"
+        );
+        service.base.proxy_targets = targets;
+    }
+}
+
+/// Ask the Traefik API on the dashboard's port for its HTTP services. The dashboard may
+/// have answered over either scheme, so both are tried like endpoint probing does.
+async fn fetch_traefik_targets(dashboard: &EndpointResponse, ip: IpAddr) -> Vec<ProxyTarget> {
+    let Ok(client) = reqwest::Client::builder()
+        .timeout(SCAN_TIMEOUT)
+        .danger_accept_invalid_certs(true)
+        .build()
+    else {
+        return Vec::new();
+    };
+
+    let socket = SocketAddr::new(
+        dashboard.endpoint.ip.unwrap_or(ip),
+        dashboard.endpoint.port_type.number(),
+    );
+
+    for scheme in ["http", "https"] {
+        let url = format!("{}://{}{}", scheme, socket, TRAEFIK_SERVICES_PATH);
+
+        match client.get(&url).send().await {
+            Ok(response) if response.status().is_success() => {
+                if let Ok(body) = response.text().await {
+                    return ProxyTarget::from_traefik_services(&body);
+                }
+            }
+            Ok(response) => {
+                tracing::debug!(url = %url, status = %response.status(), "Traefik API unavailable");
+            }
+            Err(e) => {
+                tracing::trace!(url = %url, error = %e, "Traefik API request failed");
+            }
+        }
+    }
+
+    Vec::new()
+}
@@ -1,5 +1,7 @@
 use crate::daemon::discovery::types::base::DiscoveryCriticalError;
+use crate::server::services::definitions::ServiceDefinitionRegistry;
 use crate::server::services::r#impl::base::Service;
+use crate::server::services::r#impl::definitions::ServiceDefinition;
 use crate::server::services::r#impl::endpoints::{Endpoint, EndpointResponse};
 use anyhow::anyhow;
 use anyhow::{Error, Result};
@@ -357,6 +359,8 @@ pub async fn scan_ports_and_endpoints(
         return Err(anyhow!("Operation cancelled"));
     }
 
+    let service_definitions = ServiceDefinitionRegistry::all_service_definitions();
+
     // Scan UDP ports with batching and rate limiting
     let udp_ports = scan_udp_ports(
         ip,
@@ -365,6 +369,8 @@ pub async fn scan_ports_and_endpoints(
         scan_rate_pps,
         cidr,
         gateway_ips,
+        &service_definitions,
+        None,
     )
     .await?;
     open_ports.extend(udp_ports);
@@ -377,7 +383,7 @@ pub async fn scan_ports_and_endpoints(
     let mut ports_to_check = tcp_ports.clone();
 
     // Also add endpoint-only ports that we didn't scan during port scanning
-    let endpoint_only_ports = Service::endpoint_only_ports();
+    let endpoint_only_ports = Service::endpoint_only_ports(&service_definitions);
     ports_to_check.extend(endpoint_only_ports);
     ports_to_check.sort_by_key(|p| (p.number(), p.protocol()));
     ports_to_check.dedup();
@@ -385,6 +391,7 @@ pub async fn scan_ports_and_endpoints(
     let endpoints = scan_endpoints(
         ip,
         cancel.clone(),
+        Service::all_discovery_endpoints(&service_definitions),
         Some(ports_to_check),
         Some(use_https_ports),
         port_scan_batch_size,
@@ -551,6 +558,7 @@ pub async fn scan_tcp_ports(
     Ok(open_ports)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn scan_udp_ports(
     ip: IpAddr,
     cancel: CancellationToken,
@@ -558,12 +566,15 @@ pub async fn scan_udp_ports(
     scan_rate_pps: u32,
     cidr: IpCidr,
     gateway_ips: Vec<IpAddr>,
+    service_definitions: &[Box<dyn ServiceDefinition>],
+    filter_ports: Option<&[u16]>,
 ) -> Result<Vec<PortType>, Error> {
-    let discovery_ports = Service::all_discovery_ports();
+    let discovery_ports = Service::all_discovery_ports(service_definitions);
     let ports: Vec<u16> = discovery_ports
         .iter()
         .filter(|p| p.protocol() == TransportProtocol::Udp)
         .map(|p| p.number())
+        .filter(|p| filter_ports.is_none_or(|f| f.contains(p)))
         .collect();
 
     // UDP is slower and less reliable, cap at 10 concurrent
@@ -620,9 +631,12 @@ pub async fn scan_udp_ports(
     Ok(open_ports)
 }
 
+/// Probe `candidates` (typically `Service::all_discovery_endpoints` for the session's
+/// definitions) on `ip`, limited to `filter_ports` when given.
 pub async fn scan_endpoints(
     ip: IpAddr,
     cancel: CancellationToken,
+    candidates: Vec<Endpoint>,
     filter_ports: Option<Vec<PortType>>,
     use_https_ports: Option<HashMap<u16, bool>>,
     batch_size: usize,
@@ -636,7 +650,7 @@ pub async fn scan_endpoints(
         .build()
         .map_err(|e| anyhow!("Could not build client {}", e))?;
 
-    let all_endpoints: Vec<Endpoint> = Service::all_discovery_endpoints()
+    let all_endpoints: Vec<Endpoint> = candidates
         .into_iter()
         .filter_map(|e| {
             if !probe_raw_socket_ports && e.port_type.is_raw_socket() {
@@ -786,7 +800,7 @@ pub async fn test_dns_service(ip: IpAddr) -> Result<Option<u16>, Error> {
 
 pub async fn test_ntp_service(ip: IpAddr) -> Result<Option<u16>, Error> {
     let client = AsyncSntpClient::new();
-    let server_addr = format!("{}:123", ip);
+    let server_addr = SocketAddr::new(ip, 123).to_string();
 
     match timeout(
         Duration::from_millis(2000),
@@ -812,7 +826,7 @@ pub async fn test_ntp_service(ip: IpAddr) -> Result<Option<u16>, Error> {
 }
 
 pub async fn test_snmp_service(ip: IpAddr) -> Result<Option<u16>, Error> {
-    let target = format!("{}:161", ip);
+    let target = SocketAddr::new(ip, 161).to_string();
     let community = b"public";
 
     // Wrap session creation with timeout to prevent hanging
@@ -997,7 +1011,8 @@ async fn wait_for_dhcp_responses(
 
 /// Test if a host is running a BACnet service on UDP port 47808
 pub async fn test_bacnet_service(ip: IpAddr) -> Result<Option<u16>, Error> {
-    let socket = UdpSocket::bind("0.0.0.0:0").await?;
+    let bind_addr = if ip.is_ipv4() { "0.0.0.0:0" } else { "[::]:0" };
+    let socket = UdpSocket::bind(bind_addr).await?;
     let target = SocketAddr::new(ip, 47808);
 
     // BACnet Who-Is probe packet
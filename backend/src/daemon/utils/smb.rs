@@ -0,0 +1,338 @@
+//! SMB2 host identification for network discovery.
+//!
+//! An anonymous SMB2 session setup is answered with an NTLMSSP challenge before any
+//! credentials are checked. The challenge carries the server's Windows version and build
+//! number, along with its NetBIOS and DNS computer and domain names. Nothing past the
+//! challenge is sent, so no session is ever established.
+
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+
+use crate::daemon::utils::scanner::SCAN_TIMEOUT;
+use crate::server::hosts::r#impl::os::NtlmVersion;
+
+const SMB_PORT: u16 = 445;
+
+/// How long to wait for each SMB response
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Largest response read; challenges are a few hundred bytes
+const MAX_RESPONSE_BYTES: usize = 4096;
+
+const NTLMSSP_SIGNATURE: &[u8] = b"NTLMSSP\0";
+
+/// UNICODE | REQUEST_TARGET | NTLM | ALWAYS_SIGN | EXTENDED_SESSIONSECURITY | VERSION | 128 | 56
+const NTLMSSP_NEGOTIATE_FLAGS: u32 = 0xA208_8205;
+const NTLMSSP_NEGOTIATE_VERSION: u32 = 0x0200_0000;
+
+/// What an SMB server reveals about itself in its NTLMSSP challenge
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SmbHostInfo {
+    /// Server OS version. Windows reports its real build; Samba reports build 0.
+    pub version: Option<NtlmVersion>,
+    pub netbios_computer_name: Option<String>,
+    pub netbios_domain_name: Option<String>,
+    pub dns_computer_name: Option<String>,
+    pub dns_domain_name: Option<String>,
+}
+
+/// Identify the SMB server on `ip`, or None if port 445 doesn't speak SMB2
+pub async fn probe(ip: IpAddr) -> Option<SmbHostInfo> {
+    match probe_smb(SocketAddr::new(ip, SMB_PORT)).await {
+        Ok(info) => Some(info),
+        Err(e) => {
+            tracing::trace!(ip = %ip, error = %e, "SMB probe failed");
+            None
+        }
+    }
+}
+
+async fn probe_smb(address: SocketAddr) -> Result<SmbHostInfo> {
+    let mut stream = timeout(SCAN_TIMEOUT, TcpStream::connect(address)).await??;
+
+    stream.write_all(&negotiate_request()).await?;
+    let response = read_message(&mut stream).await?;
+    if !response.starts_with(b"\xFESMB") {
+        return Err(anyhow!("Server did not negotiate SMB2"));
+    }
+
+    stream.write_all(&session_setup_request()).await?;
+    let response = read_message(&mut stream).await?;
+    let start = response
+        .windows(NTLMSSP_SIGNATURE.len())
+        .position(|w| w == NTLMSSP_SIGNATURE)
+        .ok_or_else(|| anyhow!("Session setup response has no NTLMSSP challenge"))?;
+
+    parse_challenge(&response[start..]).ok_or_else(|| anyhow!("Malformed NTLMSSP challenge"))
+}
+
+/// Read one NetBIOS session message and return its payload
+async fn read_message(stream: &mut TcpStream) -> Result<Vec<u8>> {
+    let mut header = [0u8; 4];
+    timeout(RESPONSE_TIMEOUT, stream.read_exact(&mut header)).await??;
+    let length = u32::from_be_bytes([0, header[1], header[2], header[3]]) as usize;
+    if length > MAX_RESPONSE_BYTES {
+        return Err(anyhow!("SMB response too large ({} bytes)", length));
+    }
+
+    let mut message = vec![0u8; length];
+    timeout(RESPONSE_TIMEOUT, stream.read_exact(&mut message)).await??;
+    Ok(message)
+}
+
+/// Prefix an SMB2 message with its NetBIOS session header
+fn netbios_message(payload: Vec<u8>) -> Vec<u8> {
+    let mut message = (payload.len() as u32).to_be_bytes().to_vec();
+    message[0] = 0; // Session message
+    message.extend(payload);
+    message
+}
+
+fn smb2_header(command: u16, message_id: u64) -> Vec<u8> {
+    let mut header = b"\xFESMB".to_vec();
+    header.extend_from_slice(&64u16.to_le_bytes()); // StructureSize
+    header.extend_from_slice(&0u16.to_le_bytes()); // CreditCharge
+    header.extend_from_slice(&0u32.to_le_bytes()); // Status
+    header.extend_from_slice(&command.to_le_bytes());
+    header.extend_from_slice(&1u16.to_le_bytes()); // CreditRequest
+    header.extend_from_slice(&0u32.to_le_bytes()); // Flags
+    header.extend_from_slice(&0u32.to_le_bytes()); // NextCommand
+    header.extend_from_slice(&message_id.to_le_bytes());
+    header.extend_from_slice(&0u32.to_le_bytes()); // Reserved
+    header.extend_from_slice(&0u32.to_le_bytes()); // TreeId
+    header.extend_from_slice(&0u64.to_le_bytes()); // SessionId
+    header.extend_from_slice(&[0u8; 16]); // Signature
+    header
+}
+
+/// SMB2 NEGOTIATE offering 2.0.2 through 3.0.2, which avoids 3.1.1 negotiate contexts
+fn negotiate_request() -> Vec<u8> {
+    const DIALECTS: [u16; 4] = [0x0202, 0x0210, 0x0300, 0x0302];
+
+    let mut message = smb2_header(0, 0);
+    message.extend_from_slice(&36u16.to_le_bytes()); // StructureSize
+    message.extend_from_slice(&(DIALECTS.len() as u16).to_le_bytes());
+    message.extend_from_slice(&1u16.to_le_bytes()); // SecurityMode: signing enabled
+    message.extend_from_slice(&0u16.to_le_bytes()); // Reserved
+    message.extend_from_slice(&0u32.to_le_bytes()); // Capabilities
+    message.extend_from_slice(uuid::Uuid::new_v4().as_bytes()); // ClientGuid
+    message.extend_from_slice(&0u64.to_le_bytes()); // ClientStartTime
+    for dialect in DIALECTS {
+        message.extend_from_slice(&dialect.to_le_bytes());
+    }
+    netbios_message(message)
+}
+
+/// SMB2 SESSION_SETUP carrying an NTLMSSP NEGOTIATE wrapped in SPNEGO
+fn session_setup_request() -> Vec<u8> {
+    const SECURITY_BUFFER_OFFSET: u16 = 64 + 24;
+
+    let security_blob = spnego_init(&ntlmssp_negotiate());
+
+    let mut message = smb2_header(1, 1);
+    message.extend_from_slice(&25u16.to_le_bytes()); // StructureSize
+    message.push(0); // Flags
+    message.push(1); // SecurityMode: signing enabled
+    message.extend_from_slice(&0u32.to_le_bytes()); // Capabilities
+    message.extend_from_slice(&0u32.to_le_bytes()); // Channel
+    message.extend_from_slice(&SECURITY_BUFFER_OFFSET.to_le_bytes());
+    message.extend_from_slice(&(security_blob.len() as u16).to_le_bytes());
+    message.extend_from_slice(&0u64.to_le_bytes()); // PreviousSessionId
+    message.extend(security_blob);
+    netbios_message(message)
+}
+
+fn ntlmssp_negotiate() -> Vec<u8> {
+    let mut message = NTLMSSP_SIGNATURE.to_vec();
+    message.extend_from_slice(&1u32.to_le_bytes()); // MessageType: NEGOTIATE
+    message.extend_from_slice(&NTLMSSP_NEGOTIATE_FLAGS.to_le_bytes());
+    message.extend_from_slice(&[0u8; 8]); // DomainNameFields
+    message.extend_from_slice(&[0u8; 8]); // WorkstationFields
+    // Version: 6.1.7601, NTLMSSP revision 15
+    message.extend_from_slice(&[6, 1]);
+    message.extend_from_slice(&7601u16.to_le_bytes());
+    message.extend_from_slice(&[0, 0, 0, 15]);
+    message
+}
+
+/// GSS-API InitialContextToken with a SPNEGO NegTokenInit offering only NTLMSSP
+fn spnego_init(mech_token: &[u8]) -> Vec<u8> {
+    const SPNEGO_OID: &[u8] = &[0x2b, 0x06, 0x01, 0x05, 0x05, 0x02];
+    const NTLMSSP_OID: &[u8] = &[0x2b, 0x06, 0x01, 0x04, 0x01, 0x82, 0x37, 0x02, 0x02, 0x0a];
+
+    let mech_types = der(0xa0, &der(0x30, &der(0x06, NTLMSSP_OID)));
+    let mech_token = der(0xa2, &der(0x04, mech_token));
+    let neg_token_init = der(0xa0, &der(0x30, &[mech_types, mech_token].concat()));
+
+    der(0x60, &[der(0x06, SPNEGO_OID), neg_token_init].concat())
+}
+
+/// DER tag-length-value
+fn der(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut encoded = vec![tag];
+    let length = content.len();
+    if length < 0x80 {
+        encoded.push(length as u8);
+    } else {
+        let bytes: Vec<u8> = length
+            .to_be_bytes()
+            .into_iter()
+            .skip_while(|b| *b == 0)
+            .collect();
+        encoded.push(0x80 | bytes.len() as u8);
+        encoded.extend(bytes);
+    }
+    encoded.extend_from_slice(content);
+    encoded
+}
+
+/// Parse an NTLMSSP CHALLENGE message starting at its signature
+fn parse_challenge(message: &[u8]) -> Option<SmbHostInfo> {
+    let u16_at = |offset: usize| -> Option<u16> {
+        Some(u16::from_le_bytes(
+            message.get(offset..offset + 2)?.try_into().ok()?,
+        ))
+    };
+    let u32_at = |offset: usize| -> Option<u32> {
+        Some(u32::from_le_bytes(
+            message.get(offset..offset + 4)?.try_into().ok()?,
+        ))
+    };
+
+    if !message.starts_with(NTLMSSP_SIGNATURE) || u32_at(8)? != 2 {
+        return None;
+    }
+    let flags = u32_at(20)?;
+
+    let mut info = SmbHostInfo::default();
+
+    if flags & NTLMSSP_NEGOTIATE_VERSION != 0 {
+        let version = message.get(48..56)?;
+        info.version = Some(NtlmVersion {
+            major: version[0],
+            minor: version[1],
+            build: u16::from_le_bytes([version[2], version[3]]),
+        });
+    }
+
+    let target_info_length = usize::from(u16_at(40)?);
+    let target_info_offset = u32_at(44)? as usize;
+    let mut target_info = message
+        .get(target_info_offset..target_info_offset + target_info_length)
+        .unwrap_or_default();
+
+    // AV_PAIR list: AvId, AvLen, then a UTF-16LE value, ending with MsvAvEOL
+    while target_info.len() >= 4 {
+        let id = u16::from_le_bytes([target_info[0], target_info[1]]);
+        let length = usize::from(u16::from_le_bytes([target_info[2], target_info[3]]));
+        let Some(value) = target_info.get(4..4 + length) else {
+            break;
+        };
+        let text = || {
+            let units: Vec<u16> = value
+                .chunks_exact(2)
+                .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                .collect();
+            Some(String::from_utf16_lossy(&units)).filter(|s| !s.is_empty())
+        };
+        match id {
+            0 => break,
+            1 => info.netbios_computer_name = text(),
+            2 => info.netbios_domain_name = text(),
+            3 => info.dns_computer_name = text(),
+            4 => info.dns_domain_name = text(),
+            _ => {}
+        }
+        target_info = &target_info[4 + length..];
+    }
+
+    Some(info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf16(text: &str) -> Vec<u8> {
+        text.encode_utf16().flat_map(|u| u.to_le_bytes()).collect()
+    }
+
+    fn challenge(version: [u8; 8]) -> Vec<u8> {
+        let mut target_info = Vec::new();
+        for (id, value) in [
+            (1u16, "FILESERVER"),
+            (2, "CORP"),
+            (3, "fileserver.corp.local"),
+        ] {
+            let value = utf16(value);
+            target_info.extend_from_slice(&id.to_le_bytes());
+            target_info.extend_from_slice(&(value.len() as u16).to_le_bytes());
+            target_info.extend(value);
+        }
+        target_info.extend_from_slice(&[0, 0, 0, 0]);
+
+        let mut message = NTLMSSP_SIGNATURE.to_vec();
+        message.extend_from_slice(&2u32.to_le_bytes());
+        message.extend_from_slice(&[0u8; 8]); // TargetNameFields
+        message.extend_from_slice(&(NTLMSSP_NEGOTIATE_FLAGS | 0x0080_0000).to_le_bytes());
+        message.extend_from_slice(&[0x11; 8]); // ServerChallenge
+        message.extend_from_slice(&[0u8; 8]); // Reserved
+        message.extend_from_slice(&(target_info.len() as u16).to_le_bytes());
+        message.extend_from_slice(&(target_info.len() as u16).to_le_bytes());
+        message.extend_from_slice(&56u32.to_le_bytes());
+        message.extend_from_slice(&version);
+        message.extend(target_info);
+        message
+    }
+
+    #[test]
+    fn test_parse_challenge() {
+        let mut version = [10, 0, 0, 0, 0, 0, 0, 15];
+        version[2..4].copy_from_slice(&20348u16.to_le_bytes());
+
+        let info = parse_challenge(&challenge(version)).unwrap();
+        assert_eq!(
+            info.version,
+            Some(NtlmVersion {
+                major: 10,
+                minor: 0,
+                build: 20348
+            })
+        );
+        assert_eq!(info.netbios_computer_name.as_deref(), Some("FILESERVER"));
+        assert_eq!(info.netbios_domain_name.as_deref(), Some("CORP"));
+        assert_eq!(
+            info.dns_computer_name.as_deref(),
+            Some("fileserver.corp.local")
+        );
+        assert_eq!(info.dns_domain_name, None);
+
+        // Truncated
+        assert_eq!(parse_challenge(&challenge(version)[..30]), None);
+    }
+
+    #[test]
+    fn test_session_setup_request() {
+        let request = session_setup_request();
+        let length = u32::from_be_bytes(request[0..4].try_into().unwrap()) as usize;
+        assert_eq!(length, request.len() - 4);
+
+        let blob_offset = 4 + 64 + 24;
+        let blob_length = u16::from_le_bytes([request[4 + 64 + 14], request[4 + 64 + 15]]);
+        assert_eq!(request.len(), blob_offset + blob_length as usize);
+        assert_eq!(request[blob_offset], 0x60);
+        assert!(request.ends_with(&ntlmssp_negotiate()));
+    }
+
+    #[test]
+    fn test_der_long_length() {
+        let encoded = der(0x04, &[0u8; 300]);
+        assert_eq!(&encoded[..4], &[0x04, 0x82, 0x01, 0x2c]);
+        assert_eq!(encoded.len(), 304);
+    }
+}
@@ -1,18 +1,26 @@
 //! SNMP Collection Module
 //!
 //! Provides functions to query SNMP-enabled devices during network discovery.
-//! Supports system MIB queries, ifTable walks, and LLDP/CDP neighbor discovery.
+//! Supports SNMPv2c and SNMPv3 (USM) sessions, system MIB queries, ifTable walks,
+//! LLDP/CDP neighbor discovery, bridge forwarding table (FDB) walks, ARP cache
+//! (ipNetToMediaTable) harvesting, and ENTITY-MIB hardware inventory walks.
 
 pub mod oids;
 pub mod queries;
 pub mod session;
 pub mod types;
+pub mod usm;
 pub mod values;
 
 // Re-export commonly used items
-pub use queries::{query_cdp_neighbors, query_lldp_neighbors, query_system_info, walk_if_table};
+pub use queries::{
+    query_cdp_neighbors, query_lldp_neighbors, query_system_info, walk_arp_table,
+    walk_entity_table, walk_fdb_table, walk_if_table,
+};
 pub use session::SNMP_WALK_TIMEOUT;
-pub use types::{CdpNeighbor, IfTableEntry, LldpNeighbor, SystemInfo};
+pub use types::{
+    ArpEntry, CdpNeighbor, FdbEntry, IfTableEntry, LldpNeighbor, PhysicalEntity, SystemInfo,
+};
 
 use anyhow::Result;
 use std::net::IpAddr;
@@ -22,7 +30,7 @@ use tracing::debug;
 use crate::server::snmp_credentials::r#impl::discovery::SnmpQueryCredential;
 
 /// Perform a complete SNMP poll of a device
-/// Returns system info, interface table, and neighbor information
+/// Returns system info, interface table, neighbor information, and learned FDB entries
 #[allow(dead_code)] // Used during SNMP discovery integration
 pub async fn poll_device(
     ip: IpAddr,
@@ -32,6 +40,7 @@ pub async fn poll_device(
     Vec<IfTableEntry>,
     Vec<LldpNeighbor>,
     Vec<CdpNeighbor>,
+    Vec<FdbEntry>,
 )> {
     debug!("Starting SNMP poll of {}", ip);
 
@@ -58,20 +67,35 @@ pub async fn poll_device(
         .unwrap_or(Ok(vec![]))
         .unwrap_or_default();
 
+    // Walk bridge forwarding table (only present on switches/bridges)
+    let fdb_entries = timeout(SNMP_WALK_TIMEOUT, walk_fdb_table(ip, credential))
+        .await
+        .unwrap_or(Ok(vec![]))
+        .unwrap_or_default();
+
     debug!(
-        "SNMP poll of {} complete: {} interfaces, {} LLDP neighbors, {} CDP neighbors",
+        "SNMP poll of {} complete: {} interfaces, {} LLDP neighbors, {} CDP neighbors, {} FDB entries",
         ip,
         if_entries.len(),
         lldp_neighbors.len(),
-        cdp_neighbors.len()
+        cdp_neighbors.len(),
+        fdb_entries.len()
     );
 
-    Ok((system_info, if_entries, lldp_neighbors, cdp_neighbors))
+    Ok((
+        system_info,
+        if_entries,
+        lldp_neighbors,
+        cdp_neighbors,
+        fdb_entries,
+    ))
 }
 
 #[cfg(test)]
 mod tests {
-    use super::values::{value_to_i32, value_to_mac, value_to_string};
+    use super::values::{
+        arp_index_to_ip, fdb_index_to_mac, value_to_i32, value_to_mac, value_to_string,
+    };
     use snmp2::Value;
 
     #[test]
@@ -93,4 +117,28 @@ mod tests {
         let mac = value_to_mac(&value).unwrap();
         assert_eq!(mac.bytes(), [0xDE, 0xAD, 0xBE, 0xEF, 0x12, 0x34]);
     }
+
+    #[test]
+    fn test_fdb_index_to_mac() {
+        // dot1dTpFdbTable index: MAC only
+        let mac = fdb_index_to_mac(&[0, 17, 50, 170, 187, 204]).unwrap();
+        assert_eq!(mac.bytes(), [0x00, 0x11, 0x32, 0xAA, 0xBB, 0xCC]);
+
+        // dot1qTpFdbTable index: FDB ID followed by MAC
+        let mac = fdb_index_to_mac(&[10, 0, 17, 50, 170, 187, 204]).unwrap();
+        assert_eq!(mac.bytes(), [0x00, 0x11, 0x32, 0xAA, 0xBB, 0xCC]);
+
+        assert!(fdb_index_to_mac(&[1, 2, 3]).is_none());
+        assert!(fdb_index_to_mac(&[0, 17, 50, 170, 187, 300]).is_none());
+    }
+
+    #[test]
+    fn test_arp_index_to_ip() {
+        let (if_index, ip) = arp_index_to_ip(&[3, 10, 0, 20, 5]).unwrap();
+        assert_eq!(if_index, 3);
+        assert_eq!(ip, std::net::Ipv4Addr::new(10, 0, 20, 5));
+
+        assert!(arp_index_to_ip(&[3, 10, 0, 20]).is_none());
+        assert!(arp_index_to_ip(&[3, 10, 0, 20, 256]).is_none());
+    }
 }
@@ -259,6 +259,9 @@ pub mod arp {
         /// ipNetToMediaType - Entry type (1=other, 2=invalid, 3=dynamic, 4=static)
         pub const IP_NET_TO_MEDIA_TYPE: &str = "1.3.6.1.2.1.4.22.1.4";
     }
+
+    /// ipNetToMediaType value for invalidated (stale) entries
+    pub const IP_NET_TO_MEDIA_TYPE_INVALID: i32 = 2;
 }
 
 /// Bridge MIB OIDs (RFC 4188) - MAC forwarding table
@@ -280,6 +283,21 @@ pub mod bridge {
         /// dot1dTpFdbStatus - Entry status
         pub const DOT1D_TP_FDB_STATUS: &str = "1.3.6.1.2.1.17.4.3.1.3";
     }
+
+    /// dot1qTpFdbEntry columns (indexed by dot1qFdbId + MAC address)
+    pub mod q_fdb_entry {
+        /// dot1qTpFdbPort - Bridge port number
+        pub const DOT1Q_TP_FDB_PORT: &str = "1.3.6.1.2.1.17.7.1.2.2.1.2";
+
+        /// dot1qTpFdbStatus - Entry status
+        pub const DOT1Q_TP_FDB_STATUS: &str = "1.3.6.1.2.1.17.7.1.2.2.1.3";
+    }
+
+    /// dot1dBasePortIfIndex - Maps bridge port numbers to ifIndex
+    pub const DOT1D_BASE_PORT_IF_INDEX: &str = "1.3.6.1.2.1.17.1.4.1.2";
+
+    /// dot1dTpFdbStatus / dot1qTpFdbStatus value for dynamically learned entries
+    pub const FDB_STATUS_LEARNED: i32 = 3;
 }
 
 #[cfg(test)]
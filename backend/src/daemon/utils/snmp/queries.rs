@@ -12,10 +12,13 @@ use tracing::{debug, trace, warn};
 use crate::server::snmp_credentials::r#impl::discovery::SnmpQueryCredential;
 
 use super::oids::{self, oid_to_vec, parse_oid};
-use super::session::{MAX_WALK_ENTRIES, SNMP_TIMEOUT, create_session};
-use super::types::{CdpNeighbor, IfTableEntry, LldpNeighbor, SystemInfo};
+use super::session::{MAX_WALK_ENTRIES, SNMP_TIMEOUT, SnmpSession, create_session};
+use super::types::{
+    ArpEntry, CdpNeighbor, FdbEntry, IfTableEntry, LldpNeighbor, PhysicalEntity, SystemInfo,
+};
 use super::values::{
-    parse_lldp_mgmt_addr, value_to_i32, value_to_mac, value_to_string, value_to_u64,
+    arp_index_to_ip, fdb_index_to_mac, parse_lldp_mgmt_addr, value_to_i32, value_to_mac,
+    value_to_string, value_to_u64,
 };
 
 /// Query system MIB information from a device
@@ -43,8 +46,8 @@ pub async fn query_system_info(ip: IpAddr, credential: &SnmpQueryCredential) ->
         };
 
         match timeout(SNMP_TIMEOUT, session.get(&oid)).await {
-            Ok(Ok(mut response)) => {
-                if let Some((resp_oid, value)) = response.varbinds.next() {
+            Ok(Ok(mut varbinds)) => {
+                if let Some((resp_oid, value)) = varbinds.next() {
                     trace!("SNMP {} from {}: {:?} = {:?}", name, ip, resp_oid, value);
                     match name {
                         "sysDescr" => info.sys_descr = value_to_string(&value),
@@ -118,8 +121,8 @@ pub async fn walk_if_table(
             }
 
             match timeout(SNMP_TIMEOUT, session.getnext(&current_oid)).await {
-                Ok(Ok(mut response)) => {
-                    if let Some((resp_oid, value)) = response.varbinds.next() {
+                Ok(Ok(mut varbinds)) => {
+                    if let Some((resp_oid, value)) = varbinds.next() {
                         // Check if we're still in the same subtree
                         let response_parts = oid_to_vec(&resp_oid);
                         if response_parts.len() <= base_parts.len()
@@ -253,8 +256,8 @@ pub async fn query_lldp_neighbors(
             }
 
             match timeout(SNMP_TIMEOUT, session.getnext(&current_oid)).await {
-                Ok(Ok(mut response)) => {
-                    if let Some((resp_oid, value)) = response.varbinds.next() {
+                Ok(Ok(mut varbinds)) => {
+                    if let Some((resp_oid, value)) = varbinds.next() {
                         let response_parts = oid_to_vec(&resp_oid);
                         if response_parts.len() <= base_parts.len()
                             || !response_parts.starts_with(&base_parts)
@@ -373,8 +376,8 @@ pub async fn query_cdp_neighbors(
             }
 
             match timeout(SNMP_TIMEOUT, session.getnext(&current_oid)).await {
-                Ok(Ok(mut response)) => {
-                    if let Some((resp_oid, value)) = response.varbinds.next() {
+                Ok(Ok(mut varbinds)) => {
+                    if let Some((resp_oid, value)) = varbinds.next() {
                         let response_parts = oid_to_vec(&resp_oid);
                         if response_parts.len() <= base_parts.len()
                             || !response_parts.starts_with(&base_parts)
@@ -433,3 +436,251 @@ pub async fn query_cdp_neighbors(
 
     Ok(result)
 }
+
+/// Walk the bridge forwarding database to find which MACs were learned on which port.
+/// Prefers the VLAN-aware dot1qTpFdbTable and falls back to dot1dTpFdbTable.
+pub async fn walk_fdb_table(ip: IpAddr, credential: &SnmpQueryCredential) -> Result<Vec<FdbEntry>> {
+    let mut session = create_session(ip, credential).await?;
+
+    // Bridge port numbers are not ifIndexes - map them via dot1dBasePortIfIndex
+    let port_to_if_index: HashMap<i32, i32> =
+        walk_i32_column(&mut session, ip, oids::bridge::DOT1D_BASE_PORT_IF_INDEX)
+            .await?
+            .into_iter()
+            .filter_map(|(suffix, if_index)| Some((*suffix.first()? as i32, if_index)))
+            .collect();
+
+    let mut tables = [
+        (
+            oids::bridge::q_fdb_entry::DOT1Q_TP_FDB_PORT,
+            oids::bridge::q_fdb_entry::DOT1Q_TP_FDB_STATUS,
+        ),
+        (
+            oids::bridge::fdb_entry::DOT1D_TP_FDB_PORT,
+            oids::bridge::fdb_entry::DOT1D_TP_FDB_STATUS,
+        ),
+    ]
+    .into_iter();
+
+    let mut result = Vec::new();
+
+    while result.is_empty()
+        && let Some((port_oid, status_oid)) = tables.next()
+    {
+        let ports = walk_i32_column(&mut session, ip, port_oid).await?;
+        if ports.is_empty() {
+            continue;
+        }
+
+        // Status is optional on some agents - without it, trust any non-zero port
+        let statuses: HashMap<Vec<u64>, i32> = walk_i32_column(&mut session, ip, status_oid)
+            .await?
+            .into_iter()
+            .collect();
+
+        result = ports
+            .into_iter()
+            .filter(|(suffix, port)| {
+                *port != 0
+                    && statuses
+                        .get(suffix)
+                        .is_none_or(|status| *status == oids::bridge::FDB_STATUS_LEARNED)
+            })
+            .filter_map(|(suffix, bridge_port)| {
+                Some(FdbEntry {
+                    mac: fdb_index_to_mac(&suffix)?,
+                    bridge_port,
+                    if_index: port_to_if_index
+                        .get(&bridge_port)
+                        .copied()
+                        .unwrap_or(bridge_port),
+                })
+            })
+            .collect();
+    }
+
+    debug!("FDB walk from {} returned {} entries", ip, result.len());
+
+    Ok(result)
+}
+
+/// Walk the ipNetToMediaTable to get a router's ARP cache (IPv4 neighbor MACs)
+pub async fn walk_arp_table(ip: IpAddr, credential: &SnmpQueryCredential) -> Result<Vec<ArpEntry>> {
+    let mut session = create_session(ip, credential).await?;
+
+    let phys_addresses = walk_column(
+        &mut session,
+        ip,
+        oids::arp::entry::IP_NET_TO_MEDIA_PHYS_ADDRESS,
+        value_to_mac,
+    )
+    .await?;
+
+    // Type is optional on some agents - without it, keep every entry
+    let types: HashMap<Vec<u64>, i32> =
+        walk_i32_column(&mut session, ip, oids::arp::entry::IP_NET_TO_MEDIA_TYPE)
+            .await?
+            .into_iter()
+            .collect();
+
+    let result: Vec<ArpEntry> = phys_addresses
+        .into_iter()
+        .filter(|(suffix, mac)| {
+            types.get(suffix).copied() != Some(oids::arp::IP_NET_TO_MEDIA_TYPE_INVALID)
+                && mac.bytes() != [0u8; 6]
+                && mac.bytes() != [0xffu8; 6]
+        })
+        .filter_map(|(suffix, mac)| {
+            let (if_index, ip) = arp_index_to_ip(&suffix)?;
+            Some(ArpEntry { if_index, ip, mac })
+        })
+        .collect();
+
+    debug!(
+        "ARP table walk from {} returned {} entries",
+        ip,
+        result.len()
+    );
+
+    Ok(result)
+}
+
+/// Setter for a string column of an entPhysicalEntry
+type SetEntityField = fn(&mut PhysicalEntity, String);
+
+/// Walk the ENTITY-MIB entPhysicalTable to get a device's hardware inventory
+pub async fn walk_entity_table(
+    ip: IpAddr,
+    credential: &SnmpQueryCredential,
+) -> Result<Vec<PhysicalEntity>> {
+    use oids::entity::entry;
+
+    let mut session = create_session(ip, credential).await?;
+
+    // Class is mandatory - if the agent doesn't implement ENTITY-MIB, stop here
+    let classes = walk_i32_column(&mut session, ip, entry::ENT_PHYSICAL_CLASS).await?;
+    if classes.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut entities: HashMap<i32, PhysicalEntity> = classes
+        .into_iter()
+        .filter_map(|(suffix, class)| {
+            let index = i32::try_from(*suffix.first()?).ok()?;
+            Some((
+                index,
+                PhysicalEntity {
+                    index,
+                    class: Some(class),
+                    ..Default::default()
+                },
+            ))
+        })
+        .collect();
+
+    let string_columns: [(&str, SetEntityField); 5] = [
+        (entry::ENT_PHYSICAL_DESCR, |e, v| e.descr = Some(v)),
+        (entry::ENT_PHYSICAL_NAME, |e, v| e.name = Some(v)),
+        (entry::ENT_PHYSICAL_SERIAL_NUM, |e, v| {
+            e.serial_num = Some(v)
+        }),
+        (entry::ENT_PHYSICAL_MFG_NAME, |e, v| e.mfg_name = Some(v)),
+        (entry::ENT_PHYSICAL_MODEL_NAME, |e, v| {
+            e.model_name = Some(v)
+        }),
+    ];
+
+    for (column, set) in string_columns {
+        for (suffix, value) in walk_column(&mut session, ip, column, value_to_string).await? {
+            let value = value.trim();
+            if value.is_empty() {
+                continue;
+            }
+            let entity = suffix
+                .first()
+                .and_then(|i| i32::try_from(*i).ok())
+                .and_then(|i| entities.get_mut(&i));
+            if let Some(entity) = entity {
+                set(entity, value.to_string());
+            }
+        }
+    }
+
+    let mut result: Vec<PhysicalEntity> = entities.into_values().collect();
+    result.sort_by_key(|e| e.index);
+
+    debug!(
+        "entPhysicalTable walk from {} returned {} entries",
+        ip,
+        result.len()
+    );
+
+    Ok(result)
+}
+
+/// Walk a single integer column, returning (index suffix, value) pairs
+async fn walk_i32_column(
+    session: &mut SnmpSession,
+    ip: IpAddr,
+    base_oid_str: &str,
+) -> Result<Vec<(Vec<u64>, i32)>> {
+    walk_column(session, ip, base_oid_str, value_to_i32).await
+}
+
+/// Walk a single column, returning (index suffix, converted value) pairs.
+/// Rows whose value can't be converted are skipped.
+async fn walk_column<T>(
+    session: &mut SnmpSession,
+    ip: IpAddr,
+    base_oid_str: &str,
+    convert: fn(&Value) -> Option<T>,
+) -> Result<Vec<(Vec<u64>, T)>> {
+    let base_oid = parse_oid(base_oid_str)?;
+    let base_parts = oid_to_vec(&base_oid);
+
+    let mut current_oid = base_oid;
+    let mut rows = Vec::new();
+    let mut count = 0;
+
+    loop {
+        if count >= MAX_WALK_ENTRIES {
+            warn!("Walk limit reached for {} on {}", base_oid_str, ip);
+            break;
+        }
+
+        match timeout(SNMP_TIMEOUT, session.getnext(&current_oid)).await {
+            Ok(Ok(mut varbinds)) => {
+                let Some((resp_oid, value)) = varbinds.next() else {
+                    break;
+                };
+
+                let response_parts = oid_to_vec(&resp_oid);
+                if response_parts.len() <= base_parts.len()
+                    || !response_parts.starts_with(&base_parts)
+                {
+                    break;
+                }
+
+                if let Some(v) = convert(&value) {
+                    rows.push((response_parts[base_parts.len()..].to_vec(), v));
+                }
+
+                current_oid = Oid::from(response_parts.as_slice())
+                    .map_err(|e| anyhow!("Invalid response OID: {:?}", e))?;
+                count += 1;
+            }
+            Ok(Err(e)) => {
+                debug!("Walk {} failed on {}: {:?}", base_oid_str, ip, e);
+                break;
+            }
+            Err(_) => {
+                debug!("Walk {} timeout on {}", base_oid_str, ip);
+                break;
+            }
+        }
+    }
+
+    trace!("Walked {} entries for {} from {}", count, base_oid_str, ip);
+
+    Ok(rows)
+}
@@ -3,14 +3,16 @@
 //! Functions for creating and managing SNMP sessions.
 
 use anyhow::{Result, anyhow};
-use snmp2::AsyncSession;
-use std::net::IpAddr;
+use snmp2::{AsyncSession, Oid, Varbinds};
+use std::net::{IpAddr, SocketAddr};
 use std::time::Duration;
 use tokio::time::timeout;
 
 use crate::server::snmp_credentials::r#impl::base::SnmpVersion;
 use crate::server::snmp_credentials::r#impl::discovery::SnmpQueryCredential;
 
+use super::usm::{UsmSession, UsmUser};
+
 /// Default timeout for SNMP operations
 pub const SNMP_TIMEOUT: Duration = Duration::from_secs(5);
 
@@ -23,19 +25,42 @@ pub const SNMP_WALK_TIMEOUT: Duration = Duration::from_secs(30);
 /// Maximum number of varbinds to process in a single walk
 pub const MAX_WALK_ENTRIES: usize = 10000;
 
-/// Create an SNMP session with the given credentials
-pub async fn create_session(ip: IpAddr, credential: &SnmpQueryCredential) -> Result<AsyncSession> {
-    let target = format!("{}:161", ip);
+/// An open SNMP session, either community based (v2c) or USM (v3)
+pub enum SnmpSession {
+    V2c(Box<AsyncSession>),
+    V3(Box<UsmSession>),
+}
+
+impl SnmpSession {
+    /// Issue a GET for a single OID and return the response varbinds
+    pub async fn get(&mut self, oid: &Oid<'_>) -> Result<Varbinds<'_>> {
+        match self {
+            SnmpSession::V2c(session) => Ok(session.get(oid).await?.varbinds),
+            SnmpSession::V3(session) => session.get(oid).await,
+        }
+    }
+
+    /// Issue a GETNEXT for a single OID and return the response varbinds
+    pub async fn getnext(&mut self, oid: &Oid<'_>) -> Result<Varbinds<'_>> {
+        match self {
+            SnmpSession::V2c(session) => Ok(session.getnext(oid).await?.varbinds),
+            SnmpSession::V3(session) => session.getnext(oid).await,
+        }
+    }
+}
 
+/// Create an SNMP session with the given credentials
+pub async fn create_session(ip: IpAddr, credential: &SnmpQueryCredential) -> Result<SnmpSession> {
     match credential.version {
         SnmpVersion::V2c => {
+            let target = SocketAddr::new(ip, 161).to_string();
             match timeout(
                 SNMP_SESSION_TIMEOUT,
                 AsyncSession::new_v2c(&target, credential.community.as_bytes(), 0),
             )
             .await
             {
-                Ok(Ok(session)) => Ok(session),
+                Ok(Ok(session)) => Ok(SnmpSession::V2c(Box::new(session))),
                 Ok(Err(e)) => Err(anyhow!(
                     "Failed to create SNMPv2c session to {}: {:?}",
                     ip,
@@ -49,8 +74,97 @@ pub async fn create_session(ip: IpAddr, credential: &SnmpQueryCredential) -> Res
             }
         }
         SnmpVersion::V3 => {
-            // V3 support would require additional auth/priv parameters
-            Err(anyhow!("SNMPv3 not yet implemented"))
+            let user = build_usm_user(credential)?;
+            let mut session = UsmSession::new(SocketAddr::new(ip, 161), user)
+                .await
+                .map_err(|e| anyhow!("Failed to create SNMPv3 session to {}: {}", ip, e))?;
+
+            // Discover the authoritative engine ID, boots and time before any request
+            match timeout(SNMP_TIMEOUT, session.discover()).await {
+                Ok(Ok(())) => Ok(SnmpSession::V3(Box::new(session))),
+                Ok(Err(e)) => Err(anyhow!("SNMPv3 engine discovery failed for {}: {}", ip, e)),
+                Err(_) => Err(anyhow!(
+                    "Timeout during SNMPv3 engine discovery for {} ({}s)",
+                    ip,
+                    SNMP_TIMEOUT.as_secs()
+                )),
+            }
         }
     }
 }
+
+/// Build the USM user for a V3 credential, checking the fields its security level needs
+fn build_usm_user(credential: &SnmpQueryCredential) -> Result<UsmUser> {
+    let username = credential
+        .username
+        .as_deref()
+        .filter(|u| !u.is_empty())
+        .ok_or_else(|| anyhow!("SNMPv3 credential is missing a username"))?;
+
+    let auth_passphrase = credential.auth_passphrase.clone().unwrap_or_default();
+    if credential.security_level.requires_auth() && auth_passphrase.is_empty() {
+        return Err(anyhow!(
+            "SNMPv3 {} credential is missing an authentication passphrase",
+            credential.security_level
+        ));
+    }
+
+    let priv_passphrase = credential.priv_passphrase.clone().unwrap_or_default();
+    if credential.security_level.requires_priv() && priv_passphrase.is_empty() {
+        return Err(anyhow!(
+            "SNMPv3 {} credential is missing a privacy passphrase",
+            credential.security_level
+        ));
+    }
+
+    Ok(UsmUser {
+        username: username.to_string(),
+        security_level: credential.security_level,
+        auth_protocol: credential.auth_protocol,
+        auth_passphrase,
+        priv_protocol: credential.priv_protocol,
+        priv_passphrase,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::snmp_credentials::r#impl::base::SnmpSecurityLevel;
+
+    fn v3_credential(security_level: SnmpSecurityLevel) -> SnmpQueryCredential {
+        SnmpQueryCredential {
+            version: SnmpVersion::V3,
+            username: Some("scanopy".to_string()),
+            security_level,
+            auth_passphrase: Some("authpass123".to_string()),
+            priv_passphrase: Some("privpass123".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_build_usm_user_auth_priv() {
+        let user = build_usm_user(&v3_credential(SnmpSecurityLevel::AuthPriv)).unwrap();
+        assert_eq!(user.username, "scanopy");
+        assert_eq!(user.security_level, SnmpSecurityLevel::AuthPriv);
+    }
+
+    #[test]
+    fn test_build_usm_user_requires_username() {
+        let mut credential = v3_credential(SnmpSecurityLevel::NoAuthNoPriv);
+        credential.username = None;
+        assert!(build_usm_user(&credential).is_err());
+    }
+
+    #[test]
+    fn test_build_usm_user_requires_passphrases() {
+        let mut credential = v3_credential(SnmpSecurityLevel::AuthPriv);
+        credential.priv_passphrase = None;
+        assert!(build_usm_user(&credential).is_err());
+
+        let mut credential = v3_credential(SnmpSecurityLevel::AuthNoPriv);
+        credential.auth_passphrase = None;
+        assert!(build_usm_user(&credential).is_err());
+    }
+}
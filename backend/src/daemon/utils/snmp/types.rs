@@ -3,7 +3,7 @@
 //! Data structures for SNMP query results.
 
 use mac_address::MacAddress;
-use std::net::IpAddr;
+use std::net::{IpAddr, Ipv4Addr};
 
 /// System MIB information retrieved from a device
 #[derive(Debug, Clone, Default)]
@@ -80,3 +80,44 @@ pub struct CdpNeighbor {
     /// Remote device IP address
     pub remote_address: Option<IpAddr>,
 }
+
+/// Learned MAC address from the bridge forwarding database (dot1d/dot1qTpFdbTable)
+#[derive(Debug, Clone)]
+pub struct FdbEntry {
+    /// MAC address learned on the port
+    pub mac: MacAddress,
+    /// Bridge port number the MAC was learned on
+    pub bridge_port: i32,
+    /// ifIndex of the bridge port (from dot1dBasePortIfIndex, falls back to bridge_port)
+    pub if_index: i32,
+}
+
+/// IP to MAC mapping from a device's ARP cache (ipNetToMediaTable)
+#[derive(Debug, Clone)]
+pub struct ArpEntry {
+    /// ifIndex of the interface the entry was learned on
+    pub if_index: i32,
+    /// IPv4 address of the neighbor
+    pub ip: Ipv4Addr,
+    /// MAC address of the neighbor
+    pub mac: MacAddress,
+}
+
+/// Physical component from the ENTITY-MIB entPhysicalTable
+#[derive(Debug, Clone, Default)]
+pub struct PhysicalEntity {
+    /// entPhysicalIndex - Unique value for each physical entity
+    pub index: i32,
+    /// entPhysicalClass - Entity class (3=chassis, 6=powerSupply, 9=module, 10=port, ...)
+    pub class: Option<i32>,
+    /// entPhysicalDescr - Textual description
+    pub descr: Option<String>,
+    /// entPhysicalName - Textual name
+    pub name: Option<String>,
+    /// entPhysicalSerialNum - Vendor-specific serial number
+    pub serial_num: Option<String>,
+    /// entPhysicalMfgName - Manufacturer name
+    pub mfg_name: Option<String>,
+    /// entPhysicalModelName - Vendor-specific model name
+    pub model_name: Option<String>,
+}
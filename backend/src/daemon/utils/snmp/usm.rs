@@ -0,0 +1,868 @@
+//! SNMPv3 User-based Security Model (RFC 3414)
+//!
+//! A small USM client covering what discovery needs: engine discovery, key
+//! localization, HMAC authentication (RFC 3414 / RFC 7860) and DES / AES
+//! privacy (RFC 3414 / RFC 3826). Built on RustCrypto primitives so the daemon
+//! stays free of OpenSSL. Decoded responses are handed back as `snmp2`
+//! varbinds, so queries treat v2c and v3 sessions the same way.
+
+use std::net::SocketAddr;
+use std::ops::Range;
+use std::time::Instant;
+
+use anyhow::{Result, anyhow, bail};
+use cbc::cipher::block_padding::NoPadding;
+use cbc::cipher::{AsyncStreamCipher, BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+use hmac::digest::core_api::BlockSizeUser;
+use hmac::{Mac, SimpleHmac};
+use md5::Md5;
+use sha1::Sha1;
+use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
+use snmp2::asn1::{
+    TYPE_INTEGER, TYPE_NULL, TYPE_OBJECTIDENTIFIER, TYPE_OCTETSTRING, TYPE_SEQUENCE,
+};
+use snmp2::snmp::{MSG_GET, MSG_GET_NEXT, MSG_REPORT, MSG_RESPONSE};
+use snmp2::{AsnReader, Oid, Varbinds};
+use tokio::net::UdpSocket;
+
+use crate::server::snmp_credentials::r#impl::base::{
+    SnmpAuthProtocol, SnmpPrivProtocol, SnmpSecurityLevel,
+};
+
+/// Largest UDP payload we accept (and advertise as msgMaxSize)
+const MAX_MESSAGE_SIZE: usize = 65507;
+
+/// USM security model number (RFC 3411)
+const SECURITY_MODEL_USM: i64 = 3;
+
+const FLAG_AUTH: u8 = 0x01;
+const FLAG_PRIV: u8 = 0x02;
+const FLAG_REPORTABLE: u8 = 0x04;
+
+/// Number of password bytes hashed when deriving a key (RFC 3414 A.2)
+const PASSWORD_EXPANSION_LEN: usize = 1_048_576;
+
+/// usmStats report OIDs (RFC 3414 section 5), prefix 1.3.6.1.6.3.15.1.1
+const USM_STATS_PREFIX: [u64; 9] = [1, 3, 6, 1, 6, 3, 15, 1, 1];
+const USM_STATS_NOT_IN_TIME_WINDOWS: u64 = 2;
+
+/// SNMPv3 user and security settings for a session
+#[derive(Clone)]
+pub struct UsmUser {
+    pub username: String,
+    pub security_level: SnmpSecurityLevel,
+    pub auth_protocol: SnmpAuthProtocol,
+    pub auth_passphrase: String,
+    pub priv_protocol: SnmpPrivProtocol,
+    pub priv_passphrase: String,
+}
+
+/// Keys localized to the authoritative engine
+struct LocalizedKeys {
+    auth: Vec<u8>,
+    privacy: Vec<u8>,
+}
+
+/// Authoritative engine state learned during discovery
+struct EngineState {
+    id: Vec<u8>,
+    boots: u32,
+    time: u32,
+    synced_at: Instant,
+}
+
+impl EngineState {
+    fn current_time(&self) -> u32 {
+        self.time
+            .saturating_add(self.synced_at.elapsed().as_secs() as u32)
+    }
+}
+
+/// Outcome of processing a response message
+enum Received {
+    Response,
+    NotInTimeWindow,
+}
+
+/// An SNMPv3 session with an agent using the User-based Security Model
+pub struct UsmSession {
+    socket: UdpSocket,
+    user: UsmUser,
+    engine: Option<EngineState>,
+    keys: Option<LocalizedKeys>,
+    msg_id: i32,
+    req_id: i32,
+    salt: u64,
+    plaintext: Vec<u8>,
+}
+
+impl UsmSession {
+    /// Open a UDP socket to the agent. Call [`UsmSession::discover`] before issuing requests.
+    pub async fn new(target: SocketAddr, user: UsmUser) -> Result<Self> {
+        let bind_addr: SocketAddr = if target.is_ipv4() {
+            "0.0.0.0:0".parse()?
+        } else {
+            "[::]:0".parse()?
+        };
+        let socket = UdpSocket::bind(bind_addr).await?;
+        socket.connect(target).await?;
+
+        Ok(Self {
+            socket,
+            user,
+            engine: None,
+            keys: None,
+            msg_id: rand::random::<i32>().abs().max(1),
+            req_id: rand::random::<i32>().abs().max(1),
+            salt: rand::random(),
+            plaintext: Vec::new(),
+        })
+    }
+
+    /// Discover the authoritative engine ID, boots and time, then localize keys.
+    pub async fn discover(&mut self) -> Result<()> {
+        let msg_id = self.next_msg_id();
+        let req_id = self.next_req_id();
+
+        let pdu = encode_pdu(MSG_GET, req_id, None);
+        let scoped_pdu = encode_scoped_pdu(&[], &pdu);
+        let params = encode_usm_params(&[], 0, 0, &[], &[], &[]);
+        let message = encode_message(msg_id, FLAG_REPORTABLE, &params, &scoped_pdu);
+        self.socket.send(&message).await?;
+
+        let mut buf = vec![0u8; MAX_MESSAGE_SIZE];
+        loop {
+            let len = self.socket.recv(&mut buf).await?;
+            let Ok(response) = ParsedMessage::parse(&buf[..len]) else {
+                continue;
+            };
+            if response.msg_id != msg_id as i64 {
+                continue;
+            }
+            if response.engine_id.is_empty() {
+                bail!("Agent did not report an authoritative engine ID");
+            }
+
+            self.engine = Some(EngineState {
+                id: response.engine_id.to_vec(),
+                boots: response.engine_boots,
+                time: response.engine_time,
+                synced_at: Instant::now(),
+            });
+            break;
+        }
+
+        if self.user.security_level.requires_auth() {
+            let engine_id = &self.engine.as_ref().expect("engine discovered").id;
+            let auth = localize_key(
+                self.user.auth_protocol,
+                self.user.auth_passphrase.as_bytes(),
+                engine_id,
+            )?;
+            let privacy = if self.user.security_level.requires_priv() {
+                let key = localize_key(
+                    self.user.auth_protocol,
+                    self.user.priv_passphrase.as_bytes(),
+                    engine_id,
+                )?;
+                extend_key(
+                    self.user.auth_protocol,
+                    key,
+                    priv_key_len(self.user.priv_protocol),
+                )
+            } else {
+                Vec::new()
+            };
+            self.keys = Some(LocalizedKeys { auth, privacy });
+        }
+
+        Ok(())
+    }
+
+    /// Issue a GetRequest for a single OID
+    pub async fn get(&mut self, oid: &Oid<'_>) -> Result<Varbinds<'_>> {
+        self.request(MSG_GET, oid).await
+    }
+
+    /// Issue a GetNextRequest for a single OID
+    pub async fn getnext(&mut self, oid: &Oid<'_>) -> Result<Varbinds<'_>> {
+        self.request(MSG_GET_NEXT, oid).await
+    }
+
+    async fn request(&mut self, pdu_type: u8, oid: &Oid<'_>) -> Result<Varbinds<'_>> {
+        // A notInTimeWindows report carries the agent's clock; retry once after resyncing
+        for _ in 0..2 {
+            let msg_id = self.next_msg_id();
+            let req_id = self.next_req_id();
+            let message = self.encode_request(msg_id, req_id, pdu_type, oid)?;
+            self.socket.send(&message).await?;
+
+            match self.receive(msg_id, req_id).await? {
+                Received::Response => return response_varbinds(&self.plaintext),
+                Received::NotInTimeWindow => continue,
+            }
+        }
+
+        Err(anyhow!(
+            "SNMPv3 agent clock out of time window after resync"
+        ))
+    }
+
+    fn encode_request(
+        &mut self,
+        msg_id: i32,
+        req_id: i32,
+        pdu_type: u8,
+        oid: &Oid<'_>,
+    ) -> Result<Vec<u8>> {
+        let engine = self
+            .engine
+            .as_ref()
+            .ok_or_else(|| anyhow!("SNMPv3 engine discovery has not completed"))?;
+        let engine_time = engine.current_time();
+
+        let pdu = encode_pdu(pdu_type, req_id, Some(oid));
+        let scoped_pdu = encode_scoped_pdu(&engine.id, &pdu);
+
+        let level = self.user.security_level;
+        let mut flags = FLAG_REPORTABLE;
+        let mut priv_params = Vec::new();
+        let msg_data = match (&self.keys, level.requires_priv()) {
+            (Some(keys), true) => {
+                flags |= FLAG_AUTH | FLAG_PRIV;
+                self.salt = self.salt.wrapping_add(1);
+                let (ciphertext, salt) = encrypt(
+                    self.user.priv_protocol,
+                    &keys.privacy,
+                    engine.boots,
+                    engine_time,
+                    self.salt,
+                    &scoped_pdu,
+                )?;
+                priv_params = salt;
+                tlv(TYPE_OCTETSTRING, &ciphertext)
+            }
+            (Some(_), false) => {
+                flags |= FLAG_AUTH;
+                scoped_pdu
+            }
+            (None, _) => scoped_pdu,
+        };
+
+        let auth_placeholder = match &self.keys {
+            Some(_) => vec![0u8; mac_len(self.user.auth_protocol)],
+            None => Vec::new(),
+        };
+        let params = encode_usm_params(
+            &engine.id,
+            engine.boots,
+            engine_time,
+            self.user.username.as_bytes(),
+            &auth_placeholder,
+            &priv_params,
+        );
+        let mut message = encode_message(msg_id, flags, &params, &msg_data);
+
+        if let Some(keys) = &self.keys {
+            let range = ParsedMessage::parse(&message)?.auth_range;
+            let mac = hmac(self.user.auth_protocol, &keys.auth, &message);
+            message[range].copy_from_slice(&mac);
+        }
+
+        Ok(message)
+    }
+
+    /// Wait for the response to `msg_id`, verifying and decrypting it into `self.plaintext`
+    async fn receive(&mut self, msg_id: i32, req_id: i32) -> Result<Received> {
+        let mut buf = vec![0u8; MAX_MESSAGE_SIZE];
+        loop {
+            let len = self.socket.recv(&mut buf).await?;
+            let bytes = &buf[..len];
+            let Ok(message) = ParsedMessage::parse(bytes) else {
+                continue;
+            };
+            if message.msg_id != msg_id as i64 {
+                continue;
+            }
+
+            let authenticated = message.flags & FLAG_AUTH != 0;
+            if let Some(keys) = &self.keys
+                && authenticated
+            {
+                let mut unsigned = bytes.to_vec();
+                unsigned[message.auth_range.clone()].fill(0);
+                let expected = hmac(self.user.auth_protocol, &keys.auth, &unsigned);
+                if expected.as_slice() != &bytes[message.auth_range.clone()] {
+                    bail!("SNMPv3 response failed authentication");
+                }
+            }
+
+            self.plaintext = if message.flags & FLAG_PRIV != 0 {
+                let keys = self
+                    .keys
+                    .as_ref()
+                    .ok_or_else(|| anyhow!("Encrypted SNMPv3 response without privacy keys"))?;
+                decrypt(
+                    self.user.priv_protocol,
+                    &keys.privacy,
+                    message.engine_boots,
+                    message.engine_time,
+                    message.priv_params,
+                    message.data,
+                )?
+            } else {
+                message.data.to_vec()
+            };
+
+            let (pdu_type, response_req_id, error_status) = scoped_pdu_header(&self.plaintext)?;
+
+            if pdu_type == MSG_REPORT {
+                let report_oid = response_varbinds(&self.plaintext)?
+                    .next()
+                    .map(|(oid, _)| oid.to_id_string())
+                    .unwrap_or_default();
+                let not_in_time_window = format!(
+                    "{}.{}",
+                    USM_STATS_PREFIX.map(|c| c.to_string()).join("."),
+                    USM_STATS_NOT_IN_TIME_WINDOWS
+                );
+
+                if report_oid.starts_with(&not_in_time_window) && authenticated {
+                    if let Some(engine) = self.engine.as_mut() {
+                        engine.boots = message.engine_boots;
+                        engine.time = message.engine_time;
+                        engine.synced_at = Instant::now();
+                    }
+                    return Ok(Received::NotInTimeWindow);
+                }
+                bail!("SNMPv3 agent returned report {}", report_name(&report_oid));
+            }
+
+            if pdu_type != MSG_RESPONSE || response_req_id != req_id as i64 {
+                continue;
+            }
+            if self.keys.is_some() && !authenticated {
+                bail!("SNMPv3 response was not authenticated");
+            }
+            if error_status != 0 {
+                bail!("SNMPv3 agent returned error status {}", error_status);
+            }
+
+            return Ok(Received::Response);
+        }
+    }
+
+    fn next_msg_id(&mut self) -> i32 {
+        self.msg_id = self.msg_id.checked_add(1).unwrap_or(1);
+        self.msg_id
+    }
+
+    fn next_req_id(&mut self) -> i32 {
+        self.req_id = self.req_id.checked_add(1).unwrap_or(1);
+        self.req_id
+    }
+}
+
+fn report_name(oid: &str) -> String {
+    let prefix = USM_STATS_PREFIX.map(|c| c.to_string()).join(".");
+    let name = oid
+        .strip_prefix(&prefix)
+        .and_then(|rest| rest.trim_start_matches('.').split('.').next())
+        .and_then(|stat| match stat {
+            "1" => Some("usmStatsUnsupportedSecLevels"),
+            "2" => Some("usmStatsNotInTimeWindows"),
+            "3" => Some("usmStatsUnknownUserNames"),
+            "4" => Some("usmStatsUnknownEngineIDs"),
+            "5" => Some("usmStatsWrongDigests"),
+            "6" => Some("usmStatsDecryptionErrors"),
+            _ => None,
+        });
+    match name {
+        Some(name) => format!("{} ({})", name, oid),
+        None => oid.to_string(),
+    }
+}
+
+// =============================================================================
+// Message parsing
+// =============================================================================
+
+/// The parts of an SNMPv3 message the USM client needs
+struct ParsedMessage<'a> {
+    msg_id: i64,
+    flags: u8,
+    engine_id: &'a [u8],
+    engine_boots: u32,
+    engine_time: u32,
+    priv_params: &'a [u8],
+    /// Position of msgAuthenticationParameters within the whole message
+    auth_range: Range<usize>,
+    /// ScopedPDU contents (plaintext) or the encrypted PDU octets
+    data: &'a [u8],
+}
+
+impl<'a> ParsedMessage<'a> {
+    fn parse(bytes: &'a [u8]) -> Result<Self> {
+        let mut rdr = AsnReader::from_bytes(AsnReader::from_bytes(bytes).read_raw(TYPE_SEQUENCE)?);
+
+        if rdr.read_asn_integer()? != 3 {
+            bail!("Not an SNMPv3 message");
+        }
+
+        let mut global = AsnReader::from_bytes(rdr.read_raw(TYPE_SEQUENCE)?);
+        let msg_id = global.read_asn_integer()?;
+        let _max_size = global.read_asn_integer()?;
+        let flags = *global
+            .read_asn_octetstring()?
+            .first()
+            .ok_or_else(|| anyhow!("Empty msgFlags"))?;
+        if global.read_asn_integer()? != SECURITY_MODEL_USM {
+            bail!("Unsupported SNMPv3 security model");
+        }
+
+        let security = rdr.read_asn_octetstring()?;
+        let mut usm =
+            AsnReader::from_bytes(AsnReader::from_bytes(security).read_raw(TYPE_SEQUENCE)?);
+        let engine_id = usm.read_asn_octetstring()?;
+        let engine_boots = u32::try_from(usm.read_asn_integer()?)?;
+        let engine_time = u32::try_from(usm.read_asn_integer()?)?;
+        let _username = usm.read_asn_octetstring()?;
+        let auth_params = usm.read_asn_octetstring()?;
+        let priv_params = usm.read_asn_octetstring()?;
+
+        let data = if flags & FLAG_PRIV != 0 {
+            rdr.read_asn_octetstring()?
+        } else {
+            rdr.read_raw(TYPE_SEQUENCE)?
+        };
+
+        let auth_start = auth_params.as_ptr() as usize - bytes.as_ptr() as usize;
+
+        Ok(Self {
+            msg_id,
+            flags,
+            engine_id,
+            engine_boots,
+            engine_time,
+            priv_params,
+            auth_range: auth_start..auth_start + auth_params.len(),
+            data,
+        })
+    }
+}
+
+/// Read the PDU type, request ID and error status from ScopedPDU contents
+fn scoped_pdu_header(scoped_pdu: &[u8]) -> Result<(u8, i64, i64)> {
+    let mut rdr = AsnReader::from_bytes(scoped_pdu);
+    let _context_engine_id = rdr.read_asn_octetstring()?;
+    let _context_name = rdr.read_asn_octetstring()?;
+    let pdu_type = rdr.peek_byte()?;
+    let mut pdu = AsnReader::from_bytes(rdr.read_raw(pdu_type)?);
+    let req_id = pdu.read_asn_integer()?;
+    let error_status = pdu.read_asn_integer()?;
+    Ok((pdu_type, req_id, error_status))
+}
+
+/// Varbinds of the PDU inside ScopedPDU contents
+fn response_varbinds(scoped_pdu: &[u8]) -> Result<Varbinds<'_>> {
+    let mut rdr = AsnReader::from_bytes(scoped_pdu);
+    let _context_engine_id = rdr.read_asn_octetstring()?;
+    let _context_name = rdr.read_asn_octetstring()?;
+    let pdu_type = rdr.peek_byte()?;
+    let mut pdu = AsnReader::from_bytes(rdr.read_raw(pdu_type)?);
+    let _req_id = pdu.read_asn_integer()?;
+    let _error_status = pdu.read_asn_integer()?;
+    let _error_index = pdu.read_asn_integer()?;
+    Ok(Varbinds::from_bytes(pdu.read_raw(TYPE_SEQUENCE)?))
+}
+
+// =============================================================================
+// BER encoding
+// =============================================================================
+
+fn tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(content.len() + 6);
+    out.push(tag);
+    let len = content.len();
+    if len < 0x80 {
+        out.push(len as u8);
+    } else {
+        let len_bytes = len.to_be_bytes();
+        let skip = len_bytes.iter().take_while(|b| **b == 0).count();
+        out.push(0x80 | (len_bytes.len() - skip) as u8);
+        out.extend_from_slice(&len_bytes[skip..]);
+    }
+    out.extend_from_slice(content);
+    out
+}
+
+fn integer(value: i64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let mut start = 0;
+    while start < bytes.len() - 1
+        && ((bytes[start] == 0x00 && bytes[start + 1] & 0x80 == 0)
+            || (bytes[start] == 0xff && bytes[start + 1] & 0x80 != 0))
+    {
+        start += 1;
+    }
+    tlv(TYPE_INTEGER, &bytes[start..])
+}
+
+fn encode_pdu(pdu_type: u8, req_id: i32, oid: Option<&Oid<'_>>) -> Vec<u8> {
+    let varbinds = match oid {
+        Some(oid) => tlv(
+            TYPE_SEQUENCE,
+            &[
+                tlv(TYPE_OBJECTIDENTIFIER, oid.as_bytes()),
+                tlv(TYPE_NULL, &[]),
+            ]
+            .concat(),
+        ),
+        None => Vec::new(),
+    };
+    tlv(
+        pdu_type,
+        &[
+            integer(req_id as i64),
+            integer(0),
+            integer(0),
+            tlv(TYPE_SEQUENCE, &varbinds),
+        ]
+        .concat(),
+    )
+}
+
+fn encode_scoped_pdu(context_engine_id: &[u8], pdu: &[u8]) -> Vec<u8> {
+    tlv(
+        TYPE_SEQUENCE,
+        &[
+            tlv(TYPE_OCTETSTRING, context_engine_id),
+            tlv(TYPE_OCTETSTRING, &[]),
+            pdu.to_vec(),
+        ]
+        .concat(),
+    )
+}
+
+fn encode_usm_params(
+    engine_id: &[u8],
+    engine_boots: u32,
+    engine_time: u32,
+    username: &[u8],
+    auth_params: &[u8],
+    priv_params: &[u8],
+) -> Vec<u8> {
+    tlv(
+        TYPE_SEQUENCE,
+        &[
+            tlv(TYPE_OCTETSTRING, engine_id),
+            integer(engine_boots as i64),
+            integer(engine_time as i64),
+            tlv(TYPE_OCTETSTRING, username),
+            tlv(TYPE_OCTETSTRING, auth_params),
+            tlv(TYPE_OCTETSTRING, priv_params),
+        ]
+        .concat(),
+    )
+}
+
+fn encode_message(msg_id: i32, flags: u8, usm_params: &[u8], msg_data: &[u8]) -> Vec<u8> {
+    let global = tlv(
+        TYPE_SEQUENCE,
+        &[
+            integer(msg_id as i64),
+            integer(MAX_MESSAGE_SIZE as i64),
+            tlv(TYPE_OCTETSTRING, &[flags]),
+            integer(SECURITY_MODEL_USM),
+        ]
+        .concat(),
+    );
+    tlv(
+        TYPE_SEQUENCE,
+        &[
+            integer(3),
+            global,
+            tlv(TYPE_OCTETSTRING, usm_params),
+            msg_data.to_vec(),
+        ]
+        .concat(),
+    )
+}
+
+// =============================================================================
+// Keys and authentication
+// =============================================================================
+
+/// Truncated HMAC length for each protocol (RFC 3414 HMAC-96, RFC 7860 for SHA-2)
+fn mac_len(protocol: SnmpAuthProtocol) -> usize {
+    match protocol {
+        SnmpAuthProtocol::Md5 | SnmpAuthProtocol::Sha => 12,
+        SnmpAuthProtocol::Sha224 => 16,
+        SnmpAuthProtocol::Sha256 => 24,
+        SnmpAuthProtocol::Sha384 => 32,
+        SnmpAuthProtocol::Sha512 => 48,
+    }
+}
+
+/// Key material required by each privacy protocol (DES uses the second half as pre-IV)
+fn priv_key_len(protocol: SnmpPrivProtocol) -> usize {
+    match protocol {
+        SnmpPrivProtocol::Des | SnmpPrivProtocol::Aes => 16,
+        SnmpPrivProtocol::Aes192 => 24,
+        SnmpPrivProtocol::Aes256 => 32,
+    }
+}
+
+fn digest(protocol: SnmpAuthProtocol, data: &[u8]) -> Vec<u8> {
+    match protocol {
+        SnmpAuthProtocol::Md5 => Md5::digest(data).to_vec(),
+        SnmpAuthProtocol::Sha => Sha1::digest(data).to_vec(),
+        SnmpAuthProtocol::Sha224 => Sha224::digest(data).to_vec(),
+        SnmpAuthProtocol::Sha256 => Sha256::digest(data).to_vec(),
+        SnmpAuthProtocol::Sha384 => Sha384::digest(data).to_vec(),
+        SnmpAuthProtocol::Sha512 => Sha512::digest(data).to_vec(),
+    }
+}
+
+fn localize_key(protocol: SnmpAuthProtocol, password: &[u8], engine_id: &[u8]) -> Result<Vec<u8>> {
+    match protocol {
+        SnmpAuthProtocol::Md5 => password_to_key::<Md5>(password, engine_id),
+        SnmpAuthProtocol::Sha => password_to_key::<Sha1>(password, engine_id),
+        SnmpAuthProtocol::Sha224 => password_to_key::<Sha224>(password, engine_id),
+        SnmpAuthProtocol::Sha256 => password_to_key::<Sha256>(password, engine_id),
+        SnmpAuthProtocol::Sha384 => password_to_key::<Sha384>(password, engine_id),
+        SnmpAuthProtocol::Sha512 => password_to_key::<Sha512>(password, engine_id),
+    }
+}
+
+/// Password-to-key and key localization (RFC 3414 A.2)
+fn password_to_key<D: Digest>(password: &[u8], engine_id: &[u8]) -> Result<Vec<u8>> {
+    if password.is_empty() {
+        bail!("SNMPv3 passphrase must not be empty");
+    }
+
+    let mut hasher = D::new();
+    let mut chunk = [0u8; 64];
+    let mut index = 0;
+    for _ in 0..PASSWORD_EXPANSION_LEN / chunk.len() {
+        for byte in chunk.iter_mut() {
+            *byte = password[index % password.len()];
+            index += 1;
+        }
+        hasher.update(chunk);
+    }
+    let ku = hasher.finalize();
+
+    let mut localizer = D::new();
+    localizer.update(&ku);
+    localizer.update(engine_id);
+    localizer.update(&ku);
+    Ok(localizer.finalize().to_vec())
+}
+
+/// Extend a localized key to `len` bytes (Blumenthal key extension, as used by Net-SNMP)
+fn extend_key(protocol: SnmpAuthProtocol, mut key: Vec<u8>, len: usize) -> Vec<u8> {
+    while key.len() < len {
+        let next = digest(protocol, &key);
+        key.extend_from_slice(&next);
+    }
+    key.truncate(len);
+    key
+}
+
+fn hmac(protocol: SnmpAuthProtocol, key: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut mac = match protocol {
+        SnmpAuthProtocol::Md5 => hmac_digest::<Md5>(key, message),
+        SnmpAuthProtocol::Sha => hmac_digest::<Sha1>(key, message),
+        SnmpAuthProtocol::Sha224 => hmac_digest::<Sha224>(key, message),
+        SnmpAuthProtocol::Sha256 => hmac_digest::<Sha256>(key, message),
+        SnmpAuthProtocol::Sha384 => hmac_digest::<Sha384>(key, message),
+        SnmpAuthProtocol::Sha512 => hmac_digest::<Sha512>(key, message),
+    };
+    mac.truncate(mac_len(protocol));
+    mac
+}
+
+fn hmac_digest<D: Digest + BlockSizeUser>(key: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut mac = <SimpleHmac<D> as Mac>::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(message);
+    mac.finalize().into_bytes().to_vec()
+}
+
+// =============================================================================
+// Privacy
+// =============================================================================
+
+/// Encrypt a ScopedPDU, returning the ciphertext and msgPrivacyParameters (salt)
+fn encrypt(
+    protocol: SnmpPrivProtocol,
+    key: &[u8],
+    engine_boots: u32,
+    engine_time: u32,
+    salt: u64,
+    plaintext: &[u8],
+) -> Result<(Vec<u8>, Vec<u8>)> {
+    match protocol {
+        SnmpPrivProtocol::Des => {
+            // RFC 3414 8.1.1.1: salt is engineBoots followed by a local counter
+            let salt = [engine_boots.to_be_bytes(), (salt as u32).to_be_bytes()].concat();
+            let iv = des_iv(key, &salt);
+            let mut buf = plaintext.to_vec();
+            let padded_len = buf.len().div_ceil(8) * 8;
+            buf.resize(padded_len, 0);
+            cbc::Encryptor::<des::Des>::new_from_slices(&key[..8], &iv)
+                .map_err(|e| anyhow!("Invalid DES key: {}", e))?
+                .encrypt_padded_mut::<NoPadding>(&mut buf, padded_len)
+                .map_err(|e| anyhow!("DES encryption failed: {}", e))?;
+            Ok((buf, salt))
+        }
+        SnmpPrivProtocol::Aes | SnmpPrivProtocol::Aes192 | SnmpPrivProtocol::Aes256 => {
+            let salt = salt.to_be_bytes().to_vec();
+            let iv = aes_iv(engine_boots, engine_time, &salt);
+            let mut buf = plaintext.to_vec();
+            aes_cfb_apply(protocol, key, &iv, &mut buf, true)?;
+            Ok((buf, salt))
+        }
+    }
+}
+
+/// Decrypt an encryptedPDU into ScopedPDU contents
+fn decrypt(
+    protocol: SnmpPrivProtocol,
+    key: &[u8],
+    engine_boots: u32,
+    engine_time: u32,
+    salt: &[u8],
+    ciphertext: &[u8],
+) -> Result<Vec<u8>> {
+    if salt.len() != 8 {
+        bail!("Invalid SNMPv3 privacy parameters");
+    }
+
+    let mut buf = ciphertext.to_vec();
+    match protocol {
+        SnmpPrivProtocol::Des => {
+            if !buf.len().is_multiple_of(8) {
+                bail!("DES ciphertext is not a multiple of the block size");
+            }
+            let iv = des_iv(key, salt);
+            cbc::Decryptor::<des::Des>::new_from_slices(&key[..8], &iv)
+                .map_err(|e| anyhow!("Invalid DES key: {}", e))?
+                .decrypt_padded_mut::<NoPadding>(&mut buf)
+                .map_err(|e| anyhow!("DES decryption failed: {}", e))?;
+        }
+        SnmpPrivProtocol::Aes | SnmpPrivProtocol::Aes192 | SnmpPrivProtocol::Aes256 => {
+            let iv = aes_iv(engine_boots, engine_time, salt);
+            aes_cfb_apply(protocol, key, &iv, &mut buf, false)?;
+        }
+    }
+
+    // Strip the ScopedPDU SEQUENCE header; DES padding after it is ignored
+    Ok(AsnReader::from_bytes(&buf)
+        .read_raw(TYPE_SEQUENCE)?
+        .to_vec())
+}
+
+/// DES IV is the pre-IV (second half of the privacy key) XOR the salt
+fn des_iv(key: &[u8], salt: &[u8]) -> [u8; 8] {
+    let mut iv = [0u8; 8];
+    for (i, byte) in iv.iter_mut().enumerate() {
+        *byte = key[8 + i] ^ salt[i];
+    }
+    iv
+}
+
+/// AES IV is engineBoots || engineTime || salt (RFC 3826 3.1.2.1)
+fn aes_iv(engine_boots: u32, engine_time: u32, salt: &[u8]) -> Vec<u8> {
+    [
+        engine_boots.to_be_bytes().as_slice(),
+        engine_time.to_be_bytes().as_slice(),
+        salt,
+    ]
+    .concat()
+}
+
+fn aes_cfb_apply(
+    protocol: SnmpPrivProtocol,
+    key: &[u8],
+    iv: &[u8],
+    buf: &mut [u8],
+    encrypt: bool,
+) -> Result<()> {
+    macro_rules! apply {
+        ($cipher:ty) => {
+            if encrypt {
+                cfb_mode::Encryptor::<$cipher>::new_from_slices(key, iv)
+                    .map_err(|e| anyhow!("Invalid AES key: {}", e))?
+                    .encrypt(buf)
+            } else {
+                cfb_mode::Decryptor::<$cipher>::new_from_slices(key, iv)
+                    .map_err(|e| anyhow!("Invalid AES key: {}", e))?
+                    .decrypt(buf)
+            }
+        };
+    }
+
+    match protocol {
+        SnmpPrivProtocol::Aes => apply!(aes::Aes128),
+        SnmpPrivProtocol::Aes192 => apply!(aes::Aes192),
+        SnmpPrivProtocol::Aes256 => apply!(aes::Aes256),
+        SnmpPrivProtocol::Des => bail!("DES is not an AES privacy protocol"),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RFC_ENGINE_ID: [u8; 12] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2];
+
+    #[test]
+    fn test_localize_key_rfc3414_vectors() {
+        // RFC 3414 A.3.1 / A.3.2
+        let md5 = localize_key(SnmpAuthProtocol::Md5, b"maplesyrup", &RFC_ENGINE_ID).unwrap();
+        assert_eq!(hex::encode(md5), "526f5eed9fcce26f8964c2930787d82b");
+
+        let sha = localize_key(SnmpAuthProtocol::Sha, b"maplesyrup", &RFC_ENGINE_ID).unwrap();
+        assert_eq!(hex::encode(sha), "6695febc9288e36282235fc7151f128497b38f3f");
+    }
+
+    #[test]
+    fn test_privacy_round_trip() {
+        let scoped_pdu = encode_scoped_pdu(b"engine", &encode_pdu(MSG_RESPONSE, 7, None));
+        for protocol in [
+            SnmpPrivProtocol::Des,
+            SnmpPrivProtocol::Aes,
+            SnmpPrivProtocol::Aes192,
+            SnmpPrivProtocol::Aes256,
+        ] {
+            let key = extend_key(
+                SnmpAuthProtocol::Sha,
+                localize_key(SnmpAuthProtocol::Sha, b"privpass123", &RFC_ENGINE_ID).unwrap(),
+                priv_key_len(protocol),
+            );
+            let (ciphertext, salt) = encrypt(protocol, &key, 3, 1200, 42, &scoped_pdu).unwrap();
+            let plaintext = decrypt(protocol, &key, 3, 1200, &salt, &ciphertext).unwrap();
+
+            let (pdu_type, req_id, _) = scoped_pdu_header(&plaintext).unwrap();
+            assert_eq!(pdu_type, MSG_RESPONSE, "{protocol}");
+            assert_eq!(req_id, 7, "{protocol}");
+        }
+    }
+
+    #[test]
+    fn test_encoded_message_round_trips_auth_range() {
+        let params = encode_usm_params(&RFC_ENGINE_ID, 1, 2, b"scanopy", &[0u8; 12], &[]);
+        let scoped_pdu = encode_scoped_pdu(&RFC_ENGINE_ID, &encode_pdu(MSG_GET, 1, None));
+        let message = encode_message(42, FLAG_AUTH | FLAG_REPORTABLE, &params, &scoped_pdu);
+
+        let parsed = ParsedMessage::parse(&message).unwrap();
+        assert_eq!(parsed.msg_id, 42);
+        assert_eq!(parsed.engine_id, RFC_ENGINE_ID);
+        assert_eq!(parsed.engine_boots, 1);
+        assert_eq!(parsed.engine_time, 2);
+        assert_eq!(parsed.auth_range.len(), 12);
+        assert!(message[parsed.auth_range].iter().all(|b| *b == 0));
+    }
+}
@@ -4,7 +4,7 @@
 
 use mac_address::MacAddress;
 use snmp2::Value;
-use std::net::IpAddr;
+use std::net::{IpAddr, Ipv4Addr};
 
 /// Extract a string value from an SNMP varbind value
 pub fn value_to_string(value: &Value) -> Option<String> {
@@ -73,3 +73,34 @@ pub fn parse_lldp_mgmt_addr(bytes: &[u8]) -> Option<IpAddr> {
         _ => None,
     }
 }
+
+/// Extract the MAC address from a bridge FDB table index.
+///
+/// dot1dTpFdbTable is indexed by the 6 MAC octets, dot1qTpFdbTable by
+/// dot1qFdbId followed by the 6 MAC octets - either way the MAC is the tail.
+pub fn fdb_index_to_mac(index: &[u64]) -> Option<MacAddress> {
+    let octets = index.get(index.len().checked_sub(6)?..)?;
+    let mut bytes = [0u8; 6];
+    for (byte, octet) in bytes.iter_mut().zip(octets) {
+        *byte = u8::try_from(*octet).ok()?;
+    }
+    Some(MacAddress::new(bytes))
+}
+
+/// Extract the ifIndex and IPv4 address from an ipNetToMediaTable index.
+///
+/// The table is indexed by ipNetToMediaIfIndex followed by the 4 address octets.
+pub fn arp_index_to_ip(index: &[u64]) -> Option<(i32, Ipv4Addr)> {
+    let [if_index, a, b, c, d] = index else {
+        return None;
+    };
+    Some((
+        i32::try_from(*if_index).ok()?,
+        Ipv4Addr::new(
+            u8::try_from(*a).ok()?,
+            u8::try_from(*b).ok()?,
+            u8::try_from(*c).ok()?,
+            u8::try_from(*d).ok()?,
+        ),
+    ))
+}
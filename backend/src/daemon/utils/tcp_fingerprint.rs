@@ -0,0 +1,145 @@
+//! TCP stack fingerprinting for OS detection.
+//!
+//! Operating systems start outgoing packets from different TTLs (64 for Linux/BSD/macOS,
+//! 128 for Windows, 255 for most network gear) and advertise characteristic receive
+//! windows. Both are read from the SYN-ACK a host sends when a normal connection is opened
+//! to one of its open ports, captured with a raw socket listening alongside the connect.
+//! Raw sockets need CAP_NET_RAW, and only Linux delivers inbound TCP to them, so other
+//! platforms (and unprivileged daemons) skip the fingerprint.
+
+use std::net::{IpAddr, Ipv4Addr};
+
+use crate::server::hosts::r#impl::os::TcpFingerprint;
+
+/// TTL and window of the SYN-ACK `ip` answers a connection to `port` with.
+/// IPv4 only; None if the connection fails or the SYN-ACK can't be captured.
+#[cfg(target_os = "linux")]
+pub async fn capture(ip: IpAddr, port: u16) -> Option<TcpFingerprint> {
+    let IpAddr::V4(ip) = ip else {
+        return None;
+    };
+
+    match tokio::task::spawn_blocking(move || capture_syn_ack(ip, port)).await {
+        Ok(Ok(fingerprint)) => fingerprint,
+        Ok(Err(e)) => {
+            tracing::trace!(ip = %ip, port, error = %e, "TCP fingerprint capture failed");
+            None
+        }
+        Err(_) => None,
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub async fn capture(_ip: IpAddr, _port: u16) -> Option<TcpFingerprint> {
+    None
+}
+
+#[cfg(target_os = "linux")]
+fn capture_syn_ack(ip: Ipv4Addr, port: u16) -> std::io::Result<Option<TcpFingerprint>> {
+    use crate::daemon::utils::scanner::SCAN_TIMEOUT;
+    use socket2::{Domain, Protocol, Socket, Type};
+    use std::io::Read;
+    use std::net::{SocketAddr, TcpStream};
+    use std::time::Instant;
+
+    // Opened before connecting so the SYN-ACK is already queued when we start reading
+    let raw = Socket::new(Domain::IPV4, Type::RAW, Some(Protocol::TCP))?;
+    raw.set_read_timeout(Some(SCAN_TIMEOUT))?;
+
+    let stream = TcpStream::connect_timeout(&SocketAddr::new(ip.into(), port), SCAN_TIMEOUT)?;
+    let local_port = stream.local_addr()?.port();
+    drop(stream);
+
+    // The raw socket sees every inbound TCP segment, so skip past unrelated traffic
+    let deadline = Instant::now() + SCAN_TIMEOUT;
+    let mut buf = [0u8; 1500];
+    while Instant::now() < deadline {
+        let read = (&raw).read(&mut buf)?;
+        if let Some(fingerprint) = parse_syn_ack(&buf[..read], ip, port, local_port) {
+            return Ok(Some(fingerprint));
+        }
+    }
+    Ok(None)
+}
+
+/// Fingerprint from an IPv4 packet if it's the SYN-ACK from `ip:port` to `local_port`
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_syn_ack(
+    packet: &[u8],
+    ip: Ipv4Addr,
+    port: u16,
+    local_port: u16,
+) -> Option<TcpFingerprint> {
+    const SYN_ACK: u8 = 0x12;
+
+    if packet.len() < 20 || packet[0] >> 4 != 4 || packet[9] != 6 {
+        return None;
+    }
+    let header_len = usize::from(packet[0] & 0x0f) * 4;
+    let tcp = packet.get(header_len..header_len + 20)?;
+
+    let source = Ipv4Addr::new(packet[12], packet[13], packet[14], packet[15]);
+    let source_port = u16::from_be_bytes([tcp[0], tcp[1]]);
+    let destination_port = u16::from_be_bytes([tcp[2], tcp[3]]);
+    if source != ip || source_port != port || destination_port != local_port {
+        return None;
+    }
+    if tcp[13] & SYN_ACK != SYN_ACK {
+        return None;
+    }
+
+    Some(TcpFingerprint {
+        ttl: packet[8],
+        window: u16::from_be_bytes([tcp[14], tcp[15]]),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet(ttl: u8, source_port: u16, flags: u8, window: u16) -> Vec<u8> {
+        let mut packet = vec![
+            0x45, 0, 0, 40, 0, 0, 0x40, 0, ttl, 6, 0, 0, 192, 168, 1, 10, 192, 168, 1, 2,
+        ];
+        packet.extend_from_slice(&source_port.to_be_bytes());
+        packet.extend_from_slice(&50000u16.to_be_bytes());
+        packet.extend_from_slice(&[0; 8]);
+        packet.extend_from_slice(&[0x50, flags]);
+        packet.extend_from_slice(&window.to_be_bytes());
+        packet.extend_from_slice(&[0; 4]);
+        packet
+    }
+
+    #[test]
+    fn test_parse_syn_ack() {
+        let ip = Ipv4Addr::new(192, 168, 1, 10);
+
+        assert_eq!(
+            parse_syn_ack(&packet(128, 445, 0x12, 65535), ip, 445, 50000),
+            Some(TcpFingerprint {
+                ttl: 128,
+                window: 65535
+            })
+        );
+        // Wrong port, bare ACK, different host
+        assert_eq!(
+            parse_syn_ack(&packet(64, 22, 0x12, 64240), ip, 445, 50000),
+            None
+        );
+        assert_eq!(
+            parse_syn_ack(&packet(64, 445, 0x10, 64240), ip, 445, 50000),
+            None
+        );
+        assert_eq!(
+            parse_syn_ack(
+                &packet(64, 445, 0x12, 64240),
+                Ipv4Addr::new(10, 0, 0, 1),
+                445,
+                50000
+            ),
+            None
+        );
+        assert_eq!(parse_syn_ack(&[0x45, 0, 0], ip, 445, 50000), None);
+    }
+}
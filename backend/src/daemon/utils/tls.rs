@@ -0,0 +1,354 @@
+//! TLS certificate capture for network discovery.
+//!
+//! The endpoint scanner's HTTP client accepts any certificate and never exposes it, so
+//! certificates are captured with a separate handshake that records the chain the server
+//! presents without validating it. The connection is closed as soon as the handshake
+//! completes; nothing is sent over it.
+
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use sha2::{Digest, Sha256};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+use tokio_rustls::TlsConnector;
+use tokio_rustls::rustls::client::danger::{
+    HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier,
+};
+use tokio_rustls::rustls::crypto::{
+    CryptoProvider, ring, verify_tls12_signature, verify_tls13_signature,
+};
+use tokio_rustls::rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use tokio_rustls::rustls::{ClientConfig, DigitallySignedStruct, SignatureScheme};
+use tokio_util::sync::CancellationToken;
+use x509_cert::Certificate;
+use x509_cert::der::asn1::{ObjectIdentifier, UintRef};
+use x509_cert::der::{Decode, Reader, SliceReader};
+use x509_cert::ext::pkix::SubjectAltName;
+use x509_cert::ext::pkix::name::GeneralName;
+use x509_cert::spki::SubjectPublicKeyInfoOwned;
+use x509_cert::time::Time;
+
+use crate::server::tls_certificates::r#impl::base::{CertificateChainEntry, TlsCertificateBase};
+
+pub const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(3);
+const HANDSHAKE_CONCURRENCY: usize = 8;
+
+const OID_RSA: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+const OID_EC: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+const OID_ED25519: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+const OID_ED448: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.113");
+const OID_P256: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+const OID_P384: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.34");
+const OID_P521: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.35");
+const OID_SUBJECT_ALT_NAME: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.29.17");
+
+/// Certificates presented on each of `ports` that completes a TLS handshake. Ports that
+/// don't speak TLS fail the handshake and are left out.
+///
+/// Returned certificates have nil host/port/network IDs for the caller to fill in.
+pub async fn capture_certificates(
+    ip: IpAddr,
+    ports: Vec<u16>,
+    cancel: &CancellationToken,
+) -> HashMap<u16, TlsCertificateBase> {
+    let connector = connector();
+
+    let handshakes = ports.into_iter().map(|port| {
+        let connector = connector.clone();
+        async move {
+            match handshake(&connector, SocketAddr::new(ip, port)).await {
+                Ok(chain) => {
+                    let certificate = parse_chain(&chain);
+                    if certificate.is_none() {
+                        tracing::debug!(ip = %ip, port, "Could not parse presented certificate");
+                    }
+                    Some((port, certificate?))
+                }
+                Err(e) => {
+                    tracing::trace!(ip = %ip, port, error = %e, "No TLS handshake");
+                    None
+                }
+            }
+        }
+    });
+
+    let mut results = HashMap::new();
+    let mut handshakes = futures::stream::iter(handshakes).buffer_unordered(HANDSHAKE_CONCURRENCY);
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => break,
+            next = handshakes.next() => match next {
+                Some(Some((port, certificate))) => {
+                    results.insert(port, certificate);
+                }
+                Some(None) => {}
+                None => break,
+            },
+        }
+    }
+
+    results
+}
+
+fn connector() -> TlsConnector {
+    let provider = Arc::new(ring::default_provider());
+    let config = ClientConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()
+        .expect("ring provider supports the default protocol versions")
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(AcceptAnyCertificate(provider)))
+        .with_no_client_auth();
+
+    TlsConnector::from(Arc::new(config))
+}
+
+async fn handshake(
+    connector: &TlsConnector,
+    address: SocketAddr,
+) -> Result<Vec<CertificateDer<'static>>> {
+    let stream = timeout(HANDSHAKE_TIMEOUT, TcpStream::connect(address)).await??;
+    let tls = timeout(
+        HANDSHAKE_TIMEOUT,
+        connector.connect(ServerName::from(address.ip()), stream),
+    )
+    .await??;
+
+    let (_, connection) = tls.get_ref();
+    let chain = connection
+        .peer_certificates()
+        .ok_or_else(|| anyhow!("No certificates presented"))?;
+
+    Ok(chain.iter().map(|c| c.clone().into_owned()).collect())
+}
+
+/// Records whatever the server presents. Handshake signatures are still checked so the
+/// captured certificate is the one the server holds the key for.
+#[derive(Debug)]
+struct AcceptAnyCertificate(Arc<CryptoProvider>);
+
+impl ServerCertVerifier for AcceptAnyCertificate {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, tokio_rustls::rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, tokio_rustls::rustls::Error> {
+        verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, tokio_rustls::rustls::Error> {
+        verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+/// Describe a presented chain, leaf first. `None` if the leaf can't be parsed;
+/// unparseable certificates further up the chain are skipped.
+pub fn parse_chain(chain: &[impl AsRef<[u8]>]) -> Option<TlsCertificateBase> {
+    let (leaf_der, rest) = chain.split_first()?;
+    let leaf = Certificate::from_der(leaf_der.as_ref()).ok()?;
+    let tbs = &leaf.tbs_certificate;
+
+    let subject = tbs.subject.to_string();
+    let issuer = tbs.issuer.to_string();
+
+    let subject_alt_names = tbs
+        .extensions
+        .iter()
+        .flatten()
+        .filter(|e| e.extn_id == OID_SUBJECT_ALT_NAME)
+        .filter_map(|e| SubjectAltName::from_der(e.extn_value.as_bytes()).ok())
+        .flat_map(|san| san.0)
+        .filter_map(|name| match name {
+            GeneralName::DnsName(dns) => Some(dns.to_string()),
+            GeneralName::IpAddress(ip) => ip_from_bytes(ip.as_bytes()).map(|ip| ip.to_string()),
+            _ => None,
+        })
+        .collect();
+
+    let chain = rest
+        .iter()
+        .filter_map(|der| {
+            let certificate = Certificate::from_der(der.as_ref()).ok()?;
+            let tbs = certificate.tbs_certificate;
+            Some(CertificateChainEntry {
+                subject: tbs.subject.to_string(),
+                issuer: tbs.issuer.to_string(),
+                not_before: to_datetime(&tbs.validity.not_before),
+                not_after: to_datetime(&tbs.validity.not_after),
+                fingerprint_sha256: fingerprint(der.as_ref()),
+            })
+        })
+        .collect();
+
+    Some(TlsCertificateBase {
+        fingerprint_sha256: fingerprint(leaf_der.as_ref()),
+        self_signed: subject == issuer,
+        subject,
+        subject_alt_names,
+        issuer,
+        serial_number: hex::encode(tbs.serial_number.as_bytes()),
+        not_before: to_datetime(&tbs.validity.not_before),
+        not_after: to_datetime(&tbs.validity.not_after),
+        key_type: describe_key(&tbs.subject_public_key_info),
+        chain,
+        ..Default::default()
+    })
+}
+
+fn fingerprint(der: &[u8]) -> String {
+    hex::encode(Sha256::digest(der))
+}
+
+fn to_datetime(time: &Time) -> DateTime<Utc> {
+    DateTime::<Utc>::from(time.to_system_time())
+}
+
+fn ip_from_bytes(bytes: &[u8]) -> Option<IpAddr> {
+    match bytes.len() {
+        4 => Some(IpAddr::from(<[u8; 4]>::try_from(bytes).ok()?)),
+        16 => Some(IpAddr::from(<[u8; 16]>::try_from(bytes).ok()?)),
+        _ => None,
+    }
+}
+
+/// Key algorithm with its size or curve, e.g. "RSA 2048", "EC P-256"
+fn describe_key(spki: &SubjectPublicKeyInfoOwned) -> String {
+    let oid = spki.algorithm.oid;
+
+    if oid == OID_RSA {
+        return match rsa_modulus_bits(spki.subject_public_key.raw_bytes()) {
+            Some(bits) => format!("RSA {}", bits),
+            None => "RSA".to_string(),
+        };
+    }
+
+    if oid == OID_EC {
+        let curve = spki
+            .algorithm
+            .parameters
+            .as_ref()
+            .and_then(|p| p.decode_as::<ObjectIdentifier>().ok());
+        return match curve {
+            Some(c) if c == OID_P256 => "EC P-256".to_string(),
+            Some(c) if c == OID_P384 => "EC P-384".to_string(),
+            Some(c) if c == OID_P521 => "EC P-521".to_string(),
+            Some(c) => format!("EC {}", c),
+            None => "EC".to_string(),
+        };
+    }
+
+    if oid == OID_ED25519 {
+        return "Ed25519".to_string();
+    }
+    if oid == OID_ED448 {
+        return "Ed448".to_string();
+    }
+
+    oid.to_string()
+}
+
+/// Size of the modulus in a PKCS#1 RSAPublicKey
+fn rsa_modulus_bits(public_key: &[u8]) -> Option<usize> {
+    let mut reader = SliceReader::new(public_key).ok()?;
+    let modulus = reader
+        .sequence(|r| {
+            let modulus = UintRef::decode(r)?;
+            let _exponent = UintRef::decode(r)?;
+            Ok(modulus)
+        })
+        .ok()?;
+
+    let bytes = modulus.as_bytes();
+    let first = *bytes.first()?;
+    Some((bytes.len() - 1) * 8 + (8 - first.leading_zeros() as usize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Self-signed EC P-256 certificate for CN=nas.example.com with DNS and IP SANs
+    const CERTIFICATE_HEX: &str = concat!(
+        "308201ce30820175a00302010202147f9cb04f342fe94f20e85a3079e1a284db0ed8f9300a06082a",
+        "8648ce3d040302302c3118301606035504030c0f6e61732e6578616d706c652e636f6d3110300e06",
+        "0355040a0c074578616d706c65301e170d3236313031383134343132395a170d3336313031353134",
+        "343132395a302c3118301606035504030c0f6e61732e6578616d706c652e636f6d3110300e060355",
+        "040a0c074578616d706c653059301306072a8648ce3d020106082a8648ce3d03010703420004d1db",
+        "dbe52e9fcab47a14cb3db03aa50529aa393e270397cccbf4eb1dacc87825ba8de0d93c2767cff0a6",
+        "1ff4b0cc35bf4546fe4f0962746923df750ad092ceaba3753073301d0603551d0e04160414d328d9",
+        "6fb4828e0e96899f5aef86002b16ec1ed6301f0603551d23041830168014d328d96fb4828e0e9689",
+        "9f5aef86002b16ec1ed6300f0603551d130101ff040530030101ff30200603551d1104193017820f",
+        "6e61732e6578616d706c652e636f6d8704c0a8010a300a06082a8648ce3d04030203470030440220",
+        "0af114f8e32205e99c6009b0d483dca49b8b92a6c56ac397385821821de1492002203803c4407aed",
+        "bb6064c65f70c302b63fcb1cdc29bb0e402c26b99d738ad70bee",
+    );
+
+    #[test]
+    fn test_parse_chain() {
+        let der = hex::decode(CERTIFICATE_HEX).unwrap();
+        let certificate = parse_chain(&[der.clone(), der]).unwrap();
+
+        assert!(certificate.subject.contains("CN=nas.example.com"));
+        assert!(certificate.self_signed);
+        assert_eq!(
+            certificate.subject_alt_names,
+            vec!["nas.example.com".to_string(), "192.168.1.10".to_string()]
+        );
+        assert_eq!(
+            certificate.serial_number,
+            "7f9cb04f342fe94f20e85a3079e1a284db0ed8f9"
+        );
+        assert_eq!(
+            certificate.fingerprint_sha256,
+            "a9dc28ec4a054e5908d9f9589f9e410c849924ae4dddc8d36eb8104699609c81"
+        );
+        assert_eq!(certificate.key_type, "EC P-256");
+        assert_eq!(
+            certificate.not_after.to_rfc3339(),
+            "2036-10-15T14:41:29+00:00"
+        );
+        assert_eq!(certificate.chain.len(), 1);
+        assert_eq!(
+            certificate.chain[0].fingerprint_sha256,
+            certificate.fingerprint_sha256
+        );
+
+        assert!(parse_chain(&[vec![0u8; 8]]).is_none());
+    }
+}
@@ -0,0 +1,428 @@
+use crate::server::audit_logs::r#impl::base::{AuditLogCategory, AuditLogEntry, AuditLogSettings};
+use crate::server::audit_logs::service::AuditLogService;
+use crate::server::auth::middleware::auth::AuthMethod;
+use crate::server::auth::middleware::permissions::{Admin, Authorized, Viewer};
+use crate::server::config::AppState;
+use crate::server::organizations::r#impl::base::Organization;
+use crate::server::shared::entities::EntityDiscriminants;
+use crate::server::shared::handlers::csv::export_csv_handler;
+use crate::server::shared::handlers::ordering::OrderField;
+use crate::server::shared::handlers::query::{
+    FilterQueryExtractor, OrderDirection, PaginationParams,
+};
+use crate::server::shared::handlers::traits::{CrudHandlers, get_by_id_handler};
+use crate::server::shared::services::traits::CrudService;
+use crate::server::shared::storage::filter::StorableFilter;
+use crate::server::shared::storage::traits::{Entity, Storable};
+use crate::server::shared::types::api::{
+    ApiError, ApiErrorResponse, ApiResponse, ApiResult, PaginatedApiResponse,
+};
+use axum::Json;
+use axum::body::Body;
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, HeaderValue, header};
+use axum::response::IntoResponse;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use std::sync::Arc;
+use utoipa::IntoParams;
+use utoipa_axum::{router::OpenApiRouter, routes};
+use uuid::Uuid;
+use validator::Validate;
+
+impl CrudHandlers for AuditLogEntry {
+    type Service = AuditLogService;
+    type FilterQuery = AuditLogEntryFilterQuery;
+
+    fn get_service(state: &AppState) -> &Self::Service {
+        &state.services.audit_log_service
+    }
+}
+
+// ============================================================================
+// Audit Log Ordering
+// ============================================================================
+
+/// Fields that audit log entries can be ordered/grouped by.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditLogEntryOrderField {
+    #[default]
+    CreatedAt,
+    Category,
+    Operation,
+    EntityType,
+    ActorType,
+    UserId,
+}
+
+impl OrderField for AuditLogEntryOrderField {
+    fn to_sql(&self) -> &'static str {
+        match self {
+            Self::CreatedAt => "audit_log_entries.created_at",
+            Self::Category => "audit_log_entries.category",
+            Self::Operation => "audit_log_entries.operation",
+            Self::EntityType => "audit_log_entries.entity_type",
+            Self::ActorType => "audit_log_entries.actor_type",
+            Self::UserId => "audit_log_entries.user_id",
+        }
+    }
+}
+
+// ============================================================================
+// Audit Log Filter Query
+// ============================================================================
+
+/// Query parameters for filtering and ordering audit log entries.
+#[derive(Deserialize, Default, Debug, Clone, IntoParams)]
+pub struct AuditLogEntryFilterQuery {
+    /// Filter by acting user, including actions through their API keys
+    pub user_id: Option<Uuid>,
+    /// Filter by acting user, API key or daemon ID
+    pub actor_id: Option<Uuid>,
+    /// Filter by how the actor authenticated
+    pub actor_type: Option<AuthMethod>,
+    /// Filter by event category
+    pub category: Option<AuditLogCategory>,
+    /// Filter by operation, e.g. `deleted` or `login_failed`
+    pub operation: Option<String>,
+    /// Filter by entity type
+    pub entity_type: Option<EntityDiscriminants>,
+    /// Filter by entity ID (entity history)
+    pub entity_id: Option<Uuid>,
+    /// Filter by network ID
+    pub network_id: Option<Uuid>,
+    /// Filter by client IP address
+    #[param(value_type = Option<String>)]
+    pub ip_address: Option<IpAddr>,
+    /// Only entries recorded at or after this time
+    pub from: Option<DateTime<Utc>>,
+    /// Only entries recorded before this time
+    pub to: Option<DateTime<Utc>>,
+    /// Primary ordering field (used for grouping). Always sorts ASC to keep groups together.
+    pub group_by: Option<AuditLogEntryOrderField>,
+    /// Secondary ordering field (sorting within groups or standalone sort).
+    pub order_by: Option<AuditLogEntryOrderField>,
+    /// Direction for order_by field (group_by always uses ASC).
+    pub order_direction: Option<OrderDirection>,
+    /// Maximum number of results to return (1-1000, default: 50). Use 0 for no limit.
+    #[param(minimum = 0, maximum = 1000)]
+    pub limit: Option<u32>,
+    /// Number of results to skip. Default: 0.
+    #[param(minimum = 0)]
+    pub offset: Option<u32>,
+}
+
+impl AuditLogEntryFilterQuery {
+    /// Build the ORDER BY clause. Newest entries first unless specified.
+    pub fn apply_ordering(
+        &self,
+        filter: StorableFilter<AuditLogEntry>,
+    ) -> (StorableFilter<AuditLogEntry>, String) {
+        crate::server::shared::handlers::ordering::apply_ordering(
+            self.group_by,
+            self.order_by,
+            self.order_direction,
+            filter,
+            "audit_log_entries.created_at DESC",
+        )
+    }
+}
+
+impl FilterQueryExtractor for AuditLogEntryFilterQuery {
+    fn apply_to_filter<T: Storable>(
+        &self,
+        filter: StorableFilter<T>,
+        _user_network_ids: &[Uuid],
+        _user_organization_id: Uuid,
+    ) -> StorableFilter<T> {
+        let filter = match self.user_id {
+            Some(id) => filter.user_id(&id),
+            None => filter,
+        };
+        let filter = match self.actor_id {
+            Some(id) => filter.uuid_column("actor_id", &id),
+            None => filter,
+        };
+        let filter = match self.actor_type {
+            Some(actor_type) => filter.string_column("actor_type", &actor_type.to_string()),
+            None => filter,
+        };
+        let filter = match self.category {
+            Some(category) => filter.string_column("category", &category.to_string()),
+            None => filter,
+        };
+        let filter = match &self.operation {
+            Some(operation) => filter.string_column("operation", operation),
+            None => filter,
+        };
+        let filter = match self.entity_type {
+            Some(entity_type) => filter.string_column("entity_type", &entity_type.to_string()),
+            None => filter,
+        };
+        let filter = match self.entity_id {
+            Some(id) => filter.uuid_column("entity_id", &id),
+            None => filter,
+        };
+        let filter = match self.network_id {
+            Some(id) => filter.uuid_column("network_id", &id),
+            None => filter,
+        };
+        let filter = match self.ip_address {
+            Some(ip) => filter.ip_address(ip),
+            None => filter,
+        };
+        let filter = match self.from {
+            Some(from) => filter.created_after(from),
+            None => filter,
+        };
+        match self.to {
+            Some(to) => filter.created_before(to),
+            None => filter,
+        }
+    }
+
+    fn pagination(&self) -> PaginationParams {
+        PaginationParams {
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+// The audit log is read-only and admin-only. Entries are only recorded from the event bus.
+pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(get_all_audit_log_entries))
+        .routes(routes!(get_audit_log_settings, update_audit_log_settings))
+        .routes(routes!(export_audit_log_csv))
+        .routes(routes!(export_audit_log_json))
+        .routes(routes!(get_audit_log_entry))
+}
+
+/// List all Audit Log Entries
+///
+/// Returns the organization's audit log, newest first. Filter by `entity_id` for an
+/// entity's history, by `user_id` or `actor_id` for what someone did, and by `from`/`to`
+/// for a time range. Requires admin permissions.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = AuditLogEntry::ENTITY_NAME_PLURAL,
+    params(AuditLogEntryFilterQuery),
+    responses(
+        (status = 200, description = "List of audit log entries", body = PaginatedApiResponse<AuditLogEntry>),
+        (status = 403, description = "Admin permissions required", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_all_audit_log_entries(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    crate::server::shared::extractors::Query(query): crate::server::shared::extractors::Query<
+        AuditLogEntryFilterQuery,
+    >,
+) -> ApiResult<Json<PaginatedApiResponse<AuditLogEntry>>> {
+    let network_ids = auth.network_ids();
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(ApiError::organization_required)?;
+
+    let base_filter = StorableFilter::<AuditLogEntry>::new_from_org_id(&organization_id);
+    let filter = query.apply_to_filter(base_filter, &network_ids, organization_id);
+
+    let pagination = query.pagination();
+    let filter = pagination.apply_to_filter(filter);
+    let (filter, order_by) = query.apply_ordering(filter);
+
+    let result = state
+        .services
+        .audit_log_service
+        .get_paginated_ordered(filter, &order_by)
+        .await?;
+
+    let limit = pagination.effective_limit().unwrap_or(0);
+    let offset = pagination.effective_offset();
+
+    Ok(Json(PaginatedApiResponse::success(
+        result.items,
+        result.total_count,
+        limit,
+        offset,
+    )))
+}
+
+/// Get Audit Log Entry by ID
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    tag = AuditLogEntry::ENTITY_NAME_PLURAL,
+    params(("id" = Uuid, Path, description = "Audit log entry ID")),
+    responses(
+        (status = 200, description = "Audit log entry", body = ApiResponse<AuditLogEntry>),
+        (status = 403, description = "Admin permissions required", body = ApiErrorResponse),
+        (status = 404, description = "Audit log entry not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_audit_log_entry(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    path: Path<Uuid>,
+) -> ApiResult<Json<ApiResponse<AuditLogEntry>>> {
+    get_by_id_handler::<AuditLogEntry>(state, auth.into_permission::<Viewer>(), path).await
+}
+
+/// Export Audit Log Entries to CSV
+///
+/// Exports all entries matching the filter criteria. Ignores pagination parameters
+/// (limit/offset). Requires admin permissions.
+#[utoipa::path(
+    get,
+    path = "/export/csv",
+    tag = AuditLogEntry::ENTITY_NAME_PLURAL,
+    params(AuditLogEntryFilterQuery),
+    responses(
+        (status = 200, description = "CSV file containing audit log entries", content_type = "text/csv"),
+        (status = 403, description = "Admin permissions required", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn export_audit_log_csv(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    query: crate::server::shared::extractors::Query<AuditLogEntryFilterQuery>,
+) -> ApiResult<impl IntoResponse> {
+    export_csv_handler::<AuditLogEntry>(state, auth.into_permission::<Viewer>(), query).await
+}
+
+/// Export Audit Log Entries to JSON
+///
+/// Exports all entries matching the filter criteria as a JSON array, newest first.
+/// Ignores pagination parameters (limit/offset). Requires admin permissions.
+#[utoipa::path(
+    get,
+    path = "/export/json",
+    tag = AuditLogEntry::ENTITY_NAME_PLURAL,
+    params(AuditLogEntryFilterQuery),
+    responses(
+        (status = 200, description = "JSON file containing audit log entries", body = Vec<AuditLogEntry>),
+        (status = 403, description = "Admin permissions required", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn export_audit_log_json(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    crate::server::shared::extractors::Query(query): crate::server::shared::extractors::Query<
+        AuditLogEntryFilterQuery,
+    >,
+) -> ApiResult<impl IntoResponse> {
+    let network_ids = auth.network_ids();
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(ApiError::organization_required)?;
+
+    let base_filter = StorableFilter::<AuditLogEntry>::new_from_org_id(&organization_id);
+    let filter = query.apply_to_filter(base_filter, &network_ids, organization_id);
+    let (filter, order_by) = query.apply_ordering(filter);
+
+    // No pagination applied, so this returns every matching entry
+    let entries = state
+        .services
+        .audit_log_service
+        .get_paginated_ordered(filter, &order_by)
+        .await?
+        .items;
+
+    let body = serde_json::to_vec(&entries)
+        .map_err(|e| ApiError::internal_error(&format!("Failed to build JSON: {}", e)))?;
+
+    let filename = format!("{}.json", AuditLogEntry::entity_name_plural());
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/json"),
+    );
+    headers.insert(
+        header::CONTENT_DISPOSITION,
+        HeaderValue::from_str(&format!("attachment; filename=\"{}\"", filename))
+            .unwrap_or_else(|_| HeaderValue::from_static("attachment; filename=\"export.json\"")),
+    );
+
+    Ok((headers, Body::from(body)))
+}
+
+/// Get audit log settings
+#[utoipa::path(
+    get,
+    path = "/settings",
+    tag = AuditLogEntry::ENTITY_NAME_PLURAL,
+    responses(
+        (status = 200, description = "Audit log settings", body = ApiResponse<AuditLogSettings>),
+        (status = 403, description = "Admin permissions required", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_audit_log_settings(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+) -> ApiResult<Json<ApiResponse<AuditLogSettings>>> {
+    let organization_id = auth.require_organization_id()?;
+    let organization = state
+        .services
+        .organization_service
+        .get_by_id(&organization_id)
+        .await?
+        .ok_or_else(|| ApiError::entity_not_found::<Organization>(organization_id))?;
+
+    Ok(Json(ApiResponse::success(AuditLogSettings {
+        retention_days: organization.base.audit_log_retention_days,
+    })))
+}
+
+/// Update audit log settings
+///
+/// Entries older than the retention period are pruned hourly. The change is itself
+/// recorded in the audit log as an organization update.
+#[utoipa::path(
+    put,
+    path = "/settings",
+    tag = AuditLogEntry::ENTITY_NAME_PLURAL,
+    request_body = AuditLogSettings,
+    responses(
+        (status = 200, description = "Audit log settings updated", body = ApiResponse<AuditLogSettings>),
+        (status = 400, description = "Invalid retention period", body = ApiErrorResponse),
+        (status = 403, description = "Admin permissions required", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn update_audit_log_settings(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    Json(request): Json<AuditLogSettings>,
+) -> ApiResult<Json<ApiResponse<AuditLogSettings>>> {
+    request
+        .validate()
+        .map_err(|e| ApiError::bad_request(&e.to_string()))?;
+
+    let organization_id = auth.require_organization_id()?;
+    let mut organization = state
+        .services
+        .organization_service
+        .get_by_id(&organization_id)
+        .await?
+        .ok_or_else(|| ApiError::entity_not_found::<Organization>(organization_id))?;
+
+    organization.base.audit_log_retention_days = request.retention_days;
+    let organization = state
+        .services
+        .organization_service
+        .update(&mut organization, auth.into_entity())
+        .await?;
+
+    Ok(Json(ApiResponse::success(AuditLogSettings {
+        retention_days: organization.base.audit_log_retention_days,
+    })))
+}
@@ -0,0 +1,126 @@
+use crate::server::{
+    auth::middleware::auth::AuthMethod,
+    shared::entities::{ChangeTriggersTopologyStaleness, EntityDiscriminants},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, net::IpAddr};
+use strum::{Display as StrumDisplay, EnumString};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Kind of event an audit log entry was recorded from
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    ToSchema,
+    StrumDisplay,
+    EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditLogCategory {
+    /// Logins, password and key changes, OIDC links
+    Auth,
+    /// Entity created, updated or deleted
+    #[default]
+    Entity,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, Default, Validate)]
+pub struct AuditLogEntryBase {
+    pub organization_id: Uuid,
+    /// Network of the changed entity, if it belongs to one
+    pub network_id: Option<Uuid>,
+    /// Event the entry was recorded from
+    pub event_id: Uuid,
+    pub category: AuditLogCategory,
+    /// Auth operation (e.g. `login_failed`, `rotate_key`) or entity operation
+    /// (`created`, `updated`, `deleted`)
+    pub operation: String,
+    pub entity_type: Option<EntityDiscriminants>,
+    pub entity_id: Option<Uuid>,
+    /// Name of the entity when the event was recorded
+    pub entity_name: Option<String>,
+    /// How the actor authenticated
+    pub actor_type: AuthMethod,
+    /// User, API key or daemon that acted
+    pub actor_id: Option<Uuid>,
+    /// User that acted, directly or through one of their API keys
+    pub user_id: Option<Uuid>,
+    pub actor_email: Option<String>,
+    /// Client IP of the request. Empty for background jobs.
+    #[schema(value_type = Option<String>)]
+    pub ip_address: Option<IpAddr>,
+    pub user_agent: Option<String>,
+    /// Operation details, e.g. the login method or the email of a failed login
+    pub metadata: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, Default, Validate)]
+pub struct AuditLogEntry {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub base: AuditLogEntryBase,
+}
+
+/// Organization-wide audit log settings
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, Validate)]
+pub struct AuditLogSettings {
+    /// Days entries are kept before being pruned
+    #[validate(range(min = 1, max = 3650))]
+    #[schema(minimum = 1, maximum = 3650, example = 365)]
+    pub retention_days: u32,
+}
+
+impl ChangeTriggersTopologyStaleness<AuditLogEntry> for AuditLogEntry {
+    fn triggers_staleness(&self, _other: Option<AuditLogEntry>) -> bool {
+        false
+    }
+}
+
+impl AuditLogEntry {
+    pub fn new(base: AuditLogEntryBase) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+}
+
+impl Display for AuditLogEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.base.entity_type, self.base.entity_id) {
+            (Some(entity_type), Some(entity_id)) => write!(
+                f,
+                "AuditLogEntry {}: {} {} {} by {}",
+                self.id, self.base.operation, entity_type, entity_id, self.base.actor_type
+            ),
+            _ => write!(
+                f,
+                "AuditLogEntry {}: {} by {}",
+                self.id, self.base.operation, self.base.actor_type
+            ),
+        }
+    }
+}
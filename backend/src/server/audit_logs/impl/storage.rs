@@ -0,0 +1,238 @@
+use chrono::{DateTime, Utc};
+use ipnetwork::IpNetwork;
+use serde::Serialize;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+use crate::server::{
+    audit_logs::r#impl::base::{AuditLogEntry, AuditLogEntryBase},
+    shared::{
+        entities::EntityDiscriminants,
+        entity_metadata::EntityCategory,
+        storage::traits::{Entity, SqlValue, Storable},
+    },
+};
+
+/// CSV row representation for AuditLogEntry export
+#[derive(Serialize)]
+pub struct AuditLogEntryCsvRow {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub category: String,
+    pub operation: String,
+    pub entity_type: Option<String>,
+    pub entity_id: Option<Uuid>,
+    pub entity_name: Option<String>,
+    pub network_id: Option<Uuid>,
+    pub actor_type: String,
+    pub actor_id: Option<Uuid>,
+    pub user_id: Option<Uuid>,
+    pub actor_email: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub metadata: String,
+}
+
+impl Storable for AuditLogEntry {
+    type BaseData = AuditLogEntryBase;
+
+    fn table_name() -> &'static str {
+        "audit_log_entries"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            updated_at,
+            base:
+                Self::BaseData {
+                    organization_id,
+                    network_id,
+                    event_id,
+                    category,
+                    operation,
+                    entity_type,
+                    entity_id,
+                    entity_name,
+                    actor_type,
+                    actor_id,
+                    user_id,
+                    actor_email,
+                    ip_address,
+                    user_agent,
+                    metadata,
+                },
+        } = self.clone();
+
+        Ok((
+            vec![
+                "id",
+                "organization_id",
+                "network_id",
+                "event_id",
+                "category",
+                "operation",
+                "entity_type",
+                "entity_id",
+                "entity_name",
+                "actor_type",
+                "actor_id",
+                "user_id",
+                "actor_email",
+                "ip_address",
+                "user_agent",
+                "metadata",
+                "created_at",
+                "updated_at",
+            ],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::Uuid(organization_id),
+                SqlValue::OptionalUuid(network_id),
+                SqlValue::Uuid(event_id),
+                SqlValue::String(category.to_string()),
+                SqlValue::String(operation),
+                SqlValue::OptionalString(entity_type.map(|t| t.to_string())),
+                SqlValue::OptionalUuid(entity_id),
+                SqlValue::OptionalString(entity_name),
+                SqlValue::String(actor_type.to_string()),
+                SqlValue::OptionalUuid(actor_id),
+                SqlValue::OptionalUuid(user_id),
+                SqlValue::OptionalString(actor_email),
+                SqlValue::OptionalIpAddr(ip_address),
+                SqlValue::OptionalString(user_agent),
+                SqlValue::JsonValue(metadata),
+                SqlValue::Timestamp(created_at),
+                SqlValue::Timestamp(updated_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        let category: String = row.get("category");
+        let category = category
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Failed to parse category: {}", e))?;
+        let actor_type: String = row.get("actor_type");
+        let actor_type = actor_type
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Failed to parse actor_type: {}", e))?;
+        let entity_type = row
+            .get::<Option<String>, _>("entity_type")
+            .map(|t| serde_json::from_value(serde_json::Value::String(t)))
+            .transpose()
+            .map_err(|e| anyhow::anyhow!("Failed to parse entity_type: {}", e))?;
+        let ip_address = row
+            .try_get::<Option<IpNetwork>, _>("ip_address")
+            .map_err(|e| anyhow::anyhow!("Failed to read ip_address: {}", e))?
+            .map(|n| n.ip());
+
+        Ok(AuditLogEntry {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            base: AuditLogEntryBase {
+                organization_id: row.get("organization_id"),
+                network_id: row.get("network_id"),
+                event_id: row.get("event_id"),
+                category,
+                operation: row.get("operation"),
+                entity_type,
+                entity_id: row.get("entity_id"),
+                entity_name: row.get("entity_name"),
+                actor_type,
+                actor_id: row.get("actor_id"),
+                user_id: row.get("user_id"),
+                actor_email: row.get("actor_email"),
+                ip_address,
+                user_agent: row.get("user_agent"),
+                metadata: row.get("metadata"),
+            },
+        })
+    }
+}
+
+impl Entity for AuditLogEntry {
+    type CsvRow = AuditLogEntryCsvRow;
+
+    fn to_csv_row(&self) -> Self::CsvRow {
+        AuditLogEntryCsvRow {
+            id: self.id,
+            created_at: self.created_at,
+            category: self.base.category.to_string(),
+            operation: self.base.operation.clone(),
+            entity_type: self.base.entity_type.map(|t| t.to_string()),
+            entity_id: self.base.entity_id,
+            entity_name: self.base.entity_name.clone(),
+            network_id: self.base.network_id,
+            actor_type: self.base.actor_type.to_string(),
+            actor_id: self.base.actor_id,
+            user_id: self.base.user_id,
+            actor_email: self.base.actor_email.clone(),
+            ip_address: self.base.ip_address.map(|ip| ip.to_string()),
+            user_agent: self.base.user_agent.clone(),
+            metadata: self.base.metadata.to_string(),
+        }
+    }
+
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::AuditLogEntry
+    }
+
+    const ENTITY_NAME_SINGULAR: &'static str = "Audit Log Entry";
+    const ENTITY_NAME_PLURAL: &'static str = "Audit Log Entries";
+    const ENTITY_DESCRIPTION: &'static str = "Who did what, when and from where. Logins, key rotations, OIDC links and entity creates, updates and deletes, kept for the organization's retention period.";
+
+    fn entity_category() -> EntityCategory {
+        EntityCategory::OrganizationsAndUsers
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        Some(self.base.organization_id)
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    fn set_updated_at(&mut self, time: DateTime<Utc>) {
+        self.updated_at = time;
+    }
+}
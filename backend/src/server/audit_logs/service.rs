@@ -0,0 +1,409 @@
+use crate::server::{
+    audit_logs::r#impl::base::{AuditLogCategory, AuditLogEntry, AuditLogEntryBase},
+    auth::middleware::{auth::AuthenticatedEntity, logging::RequestOrigin},
+    networks::service::NetworkService,
+    organizations::{r#impl::base::Organization, service::OrganizationService},
+    shared::{
+        entities::{Entity, EntityDiscriminants},
+        events::{
+            bus::EventBus,
+            types::{AuthEvent, AuthOperation, EntityEvent, EntityOperation, Event},
+        },
+        services::traits::{CrudService, EventBusService},
+        storage::{filter::StorableFilter, generic::GenericPostgresStorage, traits::Storage},
+    },
+    tags::entity_tags::EntityTagService,
+    users::{r#impl::base::User, service::UserService},
+};
+use anyhow::Result;
+use chrono::{Duration as ChronoDuration, Utc};
+use email_address::EmailAddress;
+use serde_json::json;
+use std::{str::FromStr, sync::Arc};
+use strum::IntoDiscriminant;
+use uuid::Uuid;
+
+/// Whether changes to an entity type are recorded. Derived records whose churn would
+/// bury user actions (delivery logs, host changes, topology rebuilds) are left out.
+pub fn is_audited(entity_type: EntityDiscriminants) -> bool {
+    !matches!(
+        entity_type,
+        EntityDiscriminants::WebhookDelivery
+            | EntityDiscriminants::HostChange
+            | EntityDiscriminants::Topology
+            | EntityDiscriminants::AuditLogEntry
+            | EntityDiscriminants::Unknown
+    )
+}
+
+/// Who acted: (actor_id, user_id, actor_email)
+fn actor(authentication: &AuthenticatedEntity) -> (Option<Uuid>, Option<Uuid>, Option<String>) {
+    (
+        authentication.entity_id(),
+        authentication.user_id(),
+        authentication.email().map(|e| e.to_string()),
+    )
+}
+
+/// Display name of an entity, from its `name` field or, for users, its email
+fn entity_name(entity: &Entity) -> Option<String> {
+    let value = serde_json::to_value(entity).ok()?;
+    let fields = value.as_object()?.values().next()?;
+    fields
+        .get("name")
+        .or_else(|| fields.get("email"))
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+}
+
+/// Audit log entry for an entity change, or None if the event isn't recorded.
+///
+/// Reads are never recorded. Updates made by daemons or the server itself (discovery
+/// merges, heartbeats, status refreshes) are skipped; their creates and deletes are kept.
+pub fn entity_entry(
+    event: &EntityEvent,
+    organization_id: Uuid,
+    origin: Option<RequestOrigin>,
+) -> Option<AuditLogEntryBase> {
+    let entity_type = event.entity_type.discriminant();
+    if !is_audited(entity_type) {
+        return None;
+    }
+
+    match event.operation {
+        EntityOperation::Get | EntityOperation::GetAll => return None,
+        EntityOperation::Updated
+            if matches!(
+                event.authentication,
+                AuthenticatedEntity::Daemon { .. } | AuthenticatedEntity::System
+            ) =>
+        {
+            return None;
+        }
+        _ => {}
+    }
+
+    let (actor_id, user_id, actor_email) = actor(&event.authentication);
+
+    Some(AuditLogEntryBase {
+        organization_id,
+        network_id: event.network_id,
+        event_id: event.id,
+        category: AuditLogCategory::Entity,
+        operation: event.operation.to_string(),
+        entity_type: Some(entity_type),
+        entity_id: Some(event.entity_id),
+        entity_name: entity_name(&event.entity_type),
+        actor_type: event.authentication.auth_method(),
+        actor_id,
+        user_id,
+        actor_email,
+        ip_address: origin.as_ref().map(|o| o.ip_address),
+        user_agent: origin.and_then(|o| o.user_agent),
+        metadata: json!({}),
+    })
+}
+
+/// Audit log entry for an authentication event
+pub fn auth_entry(event: &AuthEvent, organization_id: Uuid) -> AuditLogEntryBase {
+    let (actor_id, user_id, actor_email) = actor(&event.authentication);
+
+    // Failed logins are anonymous; the attempted email is the best actor we have
+    let actor_email = actor_email.or_else(|| {
+        event
+            .metadata
+            .get("email")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+    });
+
+    AuditLogEntryBase {
+        organization_id,
+        network_id: None,
+        event_id: event.id,
+        category: AuditLogCategory::Auth,
+        operation: event.operation.to_string(),
+        entity_type: event.user_id.map(|_| EntityDiscriminants::User),
+        entity_id: event.user_id,
+        entity_name: None,
+        actor_type: event.authentication.auth_method(),
+        actor_id: actor_id.or(event.user_id),
+        user_id: user_id.or(event.user_id),
+        actor_email,
+        ip_address: Some(event.ip_address),
+        user_agent: event.user_agent.clone(),
+        metadata: event.metadata.clone(),
+    }
+}
+
+pub struct AuditLogService {
+    storage: Arc<GenericPostgresStorage<AuditLogEntry>>,
+    network_service: Arc<NetworkService>,
+    user_service: Arc<UserService>,
+    organization_service: Arc<OrganizationService>,
+    event_bus: Arc<EventBus>,
+}
+
+impl EventBusService<AuditLogEntry> for AuditLogService {
+    fn event_bus(&self) -> &Arc<EventBus> {
+        &self.event_bus
+    }
+
+    fn get_network_id(&self, _entity: &AuditLogEntry) -> Option<Uuid> {
+        None
+    }
+
+    fn get_organization_id(&self, entity: &AuditLogEntry) -> Option<Uuid> {
+        Some(entity.base.organization_id)
+    }
+}
+
+impl CrudService<AuditLogEntry> for AuditLogService {
+    fn storage(&self) -> &Arc<GenericPostgresStorage<AuditLogEntry>> {
+        &self.storage
+    }
+
+    fn entity_tag_service(&self) -> Option<&Arc<EntityTagService>> {
+        None
+    }
+}
+
+impl AuditLogService {
+    pub fn new(
+        storage: Arc<GenericPostgresStorage<AuditLogEntry>>,
+        network_service: Arc<NetworkService>,
+        user_service: Arc<UserService>,
+        organization_service: Arc<OrganizationService>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
+        Self {
+            storage,
+            network_service,
+            user_service,
+            organization_service,
+            event_bus,
+        }
+    }
+
+    async fn organization_for_network(&self, network_id: Option<Uuid>) -> Option<Uuid> {
+        let network_id = network_id?;
+        match self.network_service.get_by_id(&network_id).await {
+            Ok(Some(network)) => Some(network.base.organization_id),
+            _ => None,
+        }
+    }
+
+    async fn organization_for_auth_event(&self, event: &AuthEvent) -> Option<Uuid> {
+        if let Some(organization_id) = event
+            .organization_id
+            .or(event.authentication.organization_id())
+        {
+            return Some(organization_id);
+        }
+
+        if let Some(user_id) = event.user_id {
+            let user = self.user_service.get_by_id(&user_id).await.ok().flatten();
+            return user.map(|u| u.base.organization_id);
+        }
+
+        // Failed logins only carry the attempted email
+        if event.operation == AuthOperation::LoginFailed
+            && let Some(email) = event.metadata.get("email").and_then(|v| v.as_str())
+            && let Ok(email) = EmailAddress::from_str(email)
+        {
+            let user = self
+                .user_service
+                .get_one(StorableFilter::<User>::new_from_email(&email))
+                .await
+                .ok()
+                .flatten();
+            return user.map(|u| u.base.organization_id);
+        }
+
+        None
+    }
+
+    /// Record an event in its organization's audit log. Events that can't be tied to an
+    /// organization (e.g. an unknown API key) are dropped. Entries are written to storage
+    /// directly so the log doesn't feed back into the event bus.
+    pub async fn record(&self, event: &Event) -> Result<Option<AuditLogEntry>> {
+        let base = match event {
+            Event::Entity(entity_event) => {
+                let organization_id = match entity_event.organization_id {
+                    Some(id) => Some(id),
+                    None => self
+                        .organization_for_network(entity_event.network_id)
+                        .await
+                        .or(entity_event.authentication.organization_id()),
+                };
+                organization_id.and_then(|organization_id| {
+                    entity_entry(entity_event, organization_id, RequestOrigin::current())
+                })
+            }
+            Event::Auth(auth_event) => self
+                .organization_for_auth_event(auth_event)
+                .await
+                .map(|organization_id| auth_entry(auth_event, organization_id)),
+            Event::Telemetry(_) | Event::Discovery(_) => None,
+        };
+
+        let Some(base) = base else {
+            return Ok(None);
+        };
+
+        let entry = self.storage.create(&AuditLogEntry::new(base)).await?;
+        Ok(Some(entry))
+    }
+
+    /// Delete entries older than each organization's retention period.
+    /// Called periodically from the server's background tasks.
+    pub async fn prune_expired(&self) {
+        let organizations = match self
+            .organization_service
+            .get_all(StorableFilter::<Organization>::new_unscoped())
+            .await
+        {
+            Ok(organizations) => organizations,
+            Err(e) => {
+                tracing::error!("Failed to query organizations for audit log pruning: {}", e);
+                return;
+            }
+        };
+
+        for organization in organizations {
+            let cutoff = Utc::now()
+                - ChronoDuration::days(organization.base.audit_log_retention_days.into());
+
+            match self
+                .storage
+                .delete_by_filter(
+                    StorableFilter::<AuditLogEntry>::new_from_org_id(&organization.id)
+                        .created_before(cutoff),
+                )
+                .await
+            {
+                Ok(0) => {}
+                Ok(count) => tracing::debug!(
+                    organization_id = %organization.id,
+                    "Pruned {} audit log entries older than {} days",
+                    count,
+                    organization.base.audit_log_retention_days
+                ),
+                Err(e) => tracing::error!(
+                    "Failed to prune audit log for organization {}: {}",
+                    organization.id,
+                    e
+                ),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::{
+        hosts::r#impl::base::Host, users::r#impl::permissions::UserOrgPermissions,
+    };
+    use std::net::{IpAddr, Ipv4Addr};
+
+    fn host_event(operation: EntityOperation, authentication: AuthenticatedEntity) -> EntityEvent {
+        let mut host = Host::default();
+        host.base.name = "nas".to_string();
+        EntityEvent {
+            id: Uuid::new_v4(),
+            entity_id: host.id,
+            entity_type: host.into(),
+            network_id: Some(Uuid::new_v4()),
+            organization_id: None,
+            operation,
+            timestamp: Utc::now(),
+            authentication,
+            metadata: json!({}),
+        }
+    }
+
+    fn user() -> AuthenticatedEntity {
+        AuthenticatedEntity::User {
+            user_id: Uuid::new_v4(),
+            organization_id: Uuid::new_v4(),
+            permissions: UserOrgPermissions::Admin,
+            network_ids: vec![],
+            email: EmailAddress::new_unchecked("admin@example.com"),
+        }
+    }
+
+    #[test]
+    fn test_entity_entry_records_who_and_where() {
+        let authentication = user();
+        let event = host_event(EntityOperation::Deleted, authentication.clone());
+        let origin = RequestOrigin {
+            ip_address: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 7)),
+            user_agent: Some("curl/8.0".to_string()),
+        };
+
+        let entry = entity_entry(&event, Uuid::new_v4(), Some(origin)).unwrap();
+
+        assert_eq!(entry.operation, "deleted");
+        assert_eq!(entry.entity_type, Some(EntityDiscriminants::Host));
+        assert_eq!(entry.entity_name.as_deref(), Some("nas"));
+        assert_eq!(entry.user_id, authentication.user_id());
+        assert_eq!(entry.actor_email.as_deref(), Some("admin@example.com"));
+        assert_eq!(
+            entry.ip_address,
+            Some(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 7)))
+        );
+        assert_eq!(entry.user_agent.as_deref(), Some("curl/8.0"));
+    }
+
+    #[test]
+    fn test_entity_entry_skips_reads_and_background_updates() {
+        let org = Uuid::new_v4();
+
+        assert!(entity_entry(&host_event(EntityOperation::Get, user()), org, None).is_none());
+        assert!(
+            entity_entry(
+                &host_event(EntityOperation::Updated, AuthenticatedEntity::System),
+                org,
+                None
+            )
+            .is_none()
+        );
+        assert!(
+            entity_entry(
+                &host_event(EntityOperation::Deleted, AuthenticatedEntity::System),
+                org,
+                None
+            )
+            .is_some()
+        );
+        assert!(entity_entry(&host_event(EntityOperation::Updated, user()), org, None).is_some());
+    }
+
+    #[test]
+    fn test_auth_entry_uses_attempted_email_for_failed_login() {
+        let event = AuthEvent {
+            id: Uuid::new_v4(),
+            user_id: None,
+            organization_id: None,
+            operation: AuthOperation::LoginFailed,
+            timestamp: Utc::now(),
+            ip_address: IpAddr::V4(Ipv4Addr::new(203, 0, 113, 9)),
+            user_agent: None,
+            metadata: json!({ "method": "password", "email": "admin@example.com" }),
+            authentication: AuthenticatedEntity::Anonymous,
+        };
+
+        let entry = auth_entry(&event, Uuid::new_v4());
+
+        assert_eq!(entry.category, AuditLogCategory::Auth);
+        assert_eq!(entry.operation, "login_failed");
+        assert_eq!(entry.actor_email.as_deref(), Some("admin@example.com"));
+        assert_eq!(
+            entry.ip_address,
+            Some(IpAddr::V4(Ipv4Addr::new(203, 0, 113, 9)))
+        );
+        assert_eq!(entry.user_id, None);
+    }
+}
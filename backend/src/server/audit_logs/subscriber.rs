@@ -0,0 +1,57 @@
+//! Event subscriber implementation for AuditLogService.
+//!
+//! Runs without debouncing so entries are written in the request task, where the
+//! client IP and user agent of entity changes are still available.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use strum::IntoEnumIterator;
+
+use crate::server::audit_logs::service::{AuditLogService, is_audited};
+use crate::server::shared::entities::EntityDiscriminants;
+use crate::server::shared::events::bus::{EventFilter, EventSubscriber};
+use crate::server::shared::events::types::{EntityOperation, Event};
+
+#[async_trait]
+impl EventSubscriber for AuditLogService {
+    fn event_filter(&self) -> EventFilter {
+        let entity_operations = EntityDiscriminants::iter()
+            .filter(|t| is_audited(*t))
+            .map(|t| {
+                (
+                    t,
+                    Some(vec![
+                        EntityOperation::Created,
+                        EntityOperation::Updated,
+                        EntityOperation::Deleted,
+                    ]),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+
+        EventFilter {
+            entity_operations: Some(entity_operations),
+            auth_operations: None,
+            telemetry_operations: Some(vec![]),
+            discovery_phases: Some(vec![]),
+            network_ids: None,
+        }
+    }
+
+    async fn handle_events(&self, events: Vec<Event>) -> Result<(), anyhow::Error> {
+        for event in events {
+            if let Err(e) = self.record(&event).await {
+                tracing::warn!(
+                    event_id = %event.id(),
+                    error = %e,
+                    "Failed to record audit log entry"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "audit-log"
+    }
+}
@@ -558,6 +558,7 @@ async fn apply_pending_setup(
             version,
             community: SecretString::new(community.clone().into()),
             tags: Vec::new(),
+            ..Default::default()
         });
 
         let created_credential = state
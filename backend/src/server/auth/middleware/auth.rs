@@ -25,7 +25,9 @@ use chrono::Utc;
 use email_address::EmailAddress;
 use serde::Deserialize;
 use serde::Serialize;
+use strum::EnumString;
 use tower_sessions::Session;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 pub struct AuthError(pub ApiError);
@@ -37,8 +39,11 @@ impl IntoResponse for AuthError {
 }
 
 /// Represents how an entity authenticated - used for audit logging
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default, ToSchema, EnumString,
+)]
 #[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
 pub enum AuthMethod {
     /// User authenticated via session cookie
     Session,
@@ -51,6 +56,7 @@ pub enum AuthMethod {
     /// System-level operation (internal)
     System,
     /// No authentication
+    #[default]
     Anonymous,
 }
 
@@ -245,6 +251,18 @@ impl AuthenticatedEntity {
             _ => None,
         }
     }
+
+    /// How this entity authenticated
+    pub fn auth_method(&self) -> AuthMethod {
+        match self {
+            AuthenticatedEntity::User { .. } => AuthMethod::Session,
+            AuthenticatedEntity::Daemon { .. } => AuthMethod::DaemonApiKey,
+            AuthenticatedEntity::ApiKey { .. } => AuthMethod::UserApiKey,
+            AuthenticatedEntity::ExternalService { .. } => AuthMethod::ExternalService,
+            AuthenticatedEntity::System => AuthMethod::System,
+            AuthenticatedEntity::Anonymous => AuthMethod::Anonymous,
+        }
+    }
 }
 
 impl From<User> for AuthenticatedEntity {
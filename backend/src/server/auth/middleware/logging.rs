@@ -5,10 +5,32 @@ use axum::{
 };
 use axum_client_ip::ClientIp;
 use reqwest::header;
-use std::{sync::Arc, time::Instant};
+use std::{net::IpAddr, sync::Arc, time::Instant};
 
 use crate::server::{auth::middleware::auth::AuthenticatedEntity, config::AppState};
 
+/// Where the request being handled came from.
+///
+/// Entity events don't carry client details, so this is scoped around each request
+/// for code running in the request task (e.g. synchronous event subscribers) to read.
+#[derive(Debug, Clone)]
+pub struct RequestOrigin {
+    pub ip_address: IpAddr,
+    pub user_agent: Option<String>,
+}
+
+tokio::task_local! {
+    static REQUEST_ORIGIN: RequestOrigin;
+}
+
+impl RequestOrigin {
+    /// Origin of the current request, or None outside of a request (background jobs,
+    /// spawned tasks).
+    pub fn current() -> Option<RequestOrigin> {
+        REQUEST_ORIGIN.try_with(|origin| origin.clone()).ok()
+    }
+}
+
 /// Normalizes a path for metrics labels to prevent high cardinality.
 fn normalize_path_for_metrics(path: &str) -> String {
     // SvelteKit immutable assets (cache-busted hashes)
@@ -61,6 +83,15 @@ pub async fn request_logging_middleware(
         .and_then(|v| v.parse::<u64>().ok())
         .unwrap_or(0);
 
+    let origin = RequestOrigin {
+        ip_address: ip,
+        user_agent: parts
+            .headers
+            .get(header::USER_AGENT)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string()),
+    };
+
     let request = Request::from_parts(parts, body);
 
     // Track in-flight requests (BEFORE processing)
@@ -72,7 +103,7 @@ pub async fn request_logging_middleware(
     .increment(1.0);
 
     // Process request
-    let response = next.run(request).await;
+    let response = REQUEST_ORIGIN.scope(origin, next.run(request)).await;
 
     // Capture response info
     let duration = start.elapsed();
@@ -9,7 +9,7 @@ use crate::server::{
     },
     email::traits::EmailService,
     organizations::{
-        r#impl::base::{Organization, OrganizationBase},
+        r#impl::base::{DEFAULT_AUDIT_LOG_RETENTION_DAYS, Organization, OrganizationBase},
         service::OrganizationService,
     },
     shared::{
@@ -239,6 +239,7 @@ impl AuthService {
                         has_payment_method: false,
                         trial_end_date: None,
                         brevo_company_id: None,
+                        audit_log_retention_days: DEFAULT_AUDIT_LOG_RETENTION_DAYS,
                     }),
                     AuthenticatedEntity::System,
                 )
@@ -185,7 +185,7 @@ mod tests {
     #[test]
     fn test_feature_ids_match_billing_plan_features_fields() {
         // Get all Feature IDs
-        let feature_ids: HashSet<&str> = Feature::iter().map(|f| f.id()).collect();
+        let feature_ids: HashSet<String> = Feature::iter().map(|f| f.id().to_string()).collect();
 
         // Get all keys from BillingPlanFeatures by serializing an instance
         let features = BillingPlan::default().features();
@@ -194,8 +194,7 @@ mod tests {
             .as_object()
             .expect("Features should be an object");
 
-        let billing_plan_features: HashSet<&str> =
-            features_map.keys().map(|s| s.as_str()).collect();
+        let billing_plan_features: HashSet<String> = features_map.keys().cloned().collect();
 
         // Check that every Feature ID exists in BillingPlanFeatures
         for feature_id in &feature_ids {
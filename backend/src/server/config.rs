@@ -20,6 +20,8 @@ use tower_sessions_sqlx_store::PostgresStore;
 use utoipa::ToSchema;
 
 use crate::server::shared::storage::factory::StorageFactory;
+use crate::server::shared::storage::secrets::{ensure_secret_storage, init_secret_encryption};
+use crate::server::tls_certificates::r#impl::base::DEFAULT_EXPIRY_WARNING_DAYS;
 
 #[derive(Parser)]
 #[command(name = "scanopy-server")]
@@ -97,6 +99,22 @@ pub struct ServerCli {
 
     #[arg(long)]
     pub brevo_api_key: Option<String>,
+
+    /// Base64-encoded 32-byte key used to encrypt stored credential secrets
+    #[arg(long)]
+    pub secret_key: Option<String>,
+
+    /// Store credential secrets in plaintext when no secret key is set
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    pub allow_unencrypted_secrets: Option<bool>,
+
+    /// Allow webhooks to target loopback, link-local and cloud metadata addresses
+    #[arg(long, num_args = 0..=1, default_missing_value = "true")]
+    pub allow_private_webhook_targets: Option<bool>,
+
+    /// Days before a TLS certificate expires that it's recorded as expiring
+    #[arg(long)]
+    pub cert_expiry_warning_days: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -134,6 +152,21 @@ pub struct ServerConfig {
     // Brevo CRM integration
     pub brevo_api_key: Option<String>,
 
+    // Encrypts credential secrets (SNMP communities and passphrases) at rest
+    pub secret_key: Option<String>,
+
+    // Opt-out that stores credential secrets in plaintext when secret_key is unset
+    #[serde(default)]
+    pub allow_unencrypted_secrets: bool,
+
+    // Lets webhooks target loopback, link-local and cloud metadata addresses
+    #[serde(default)]
+    pub allow_private_webhook_targets: bool,
+
+    // Days before a TLS certificate expires that it's recorded as expiring
+    #[serde(default = "default_cert_expiry_warning_days")]
+    pub cert_expiry_warning_days: u32,
+
     // External service IP restrictions
     // Maps service name (lowercase) to list of allowed IPs/CIDRs
     // Populated from SCANOPY_EXTERNAL_SERVICE_<NAME>_ALLOWED_IPS env vars
@@ -164,6 +197,10 @@ pub struct PublicConfigResponse {
     pub deployment_type: DeploymentType,
 }
 
+fn default_cert_expiry_warning_days() -> u32 {
+    DEFAULT_EXPIRY_WARNING_DAYS
+}
+
 impl Default for ServerConfig {
     fn default() -> Self {
         Self {
@@ -189,6 +226,10 @@ impl Default for ServerConfig {
             enforce_billing_for_testing: false,
             metrics_token: None,
             brevo_api_key: None,
+            secret_key: None,
+            allow_unencrypted_secrets: false,
+            allow_private_webhook_targets: false,
+            cert_expiry_warning_days: DEFAULT_EXPIRY_WARNING_DAYS,
             external_service_allowed_ips: HashMap::new(),
         }
     }
@@ -259,6 +300,21 @@ impl ServerConfig {
         if let Some(brevo_api_key) = cli_args.brevo_api_key {
             figment = figment.merge(("brevo_api_key", brevo_api_key));
         }
+        if let Some(secret_key) = cli_args.secret_key {
+            figment = figment.merge(("secret_key", secret_key));
+        }
+        if let Some(allow_unencrypted_secrets) = cli_args.allow_unencrypted_secrets {
+            figment = figment.merge(("allow_unencrypted_secrets", allow_unencrypted_secrets));
+        }
+        if let Some(allow_private_webhook_targets) = cli_args.allow_private_webhook_targets {
+            figment = figment.merge((
+                "allow_private_webhook_targets",
+                allow_private_webhook_targets,
+            ));
+        }
+        if let Some(cert_expiry_warning_days) = cli_args.cert_expiry_warning_days {
+            figment = figment.merge(("cert_expiry_warning_days", cert_expiry_warning_days));
+        }
 
         let mut config: ServerConfig = figment
             .extract()
@@ -320,8 +376,14 @@ pub struct AppState {
 
 impl AppState {
     pub async fn new(config: ServerConfig) -> Result<Arc<Self>, Error> {
+        init_secret_encryption(
+            config.secret_key.as_deref(),
+            config.allow_unencrypted_secrets,
+        )?;
+
         let storage =
             StorageFactory::new(&config.database_url(), config.use_secure_session_cookies).await?;
+        ensure_secret_storage(&storage.pool).await?;
         let services = ServiceFactory::new(&storage, Some(config.clone())).await?;
 
         Ok(Arc::new(Self {
@@ -0,0 +1,345 @@
+use crate::server::auth::middleware::permissions::{Authorized, Member, Viewer};
+use crate::server::custom_scan_profiles::r#impl::base::CustomScanProfile;
+use crate::server::custom_scan_profiles::service::CustomScanProfileService;
+use crate::server::discovery::r#impl::base::Discovery;
+use crate::server::discovery::r#impl::scan_profile::ScanProfile;
+use crate::server::discovery::r#impl::types::{DiscoveryType, RunType};
+use crate::server::networks::r#impl::Network;
+use crate::server::shared::handlers::ordering::OrderField;
+use crate::server::shared::handlers::query::{
+    FilterQueryExtractor, OrderDirection, PaginationParams,
+};
+use crate::server::shared::handlers::traits::{
+    BulkDeleteResponse, CrudHandlers, bulk_delete_handler, create_handler, delete_handler,
+};
+use crate::server::shared::services::traits::CrudService;
+use crate::server::shared::storage::filter::StorableFilter;
+use crate::server::shared::storage::traits::{Entity, Storable};
+use crate::server::shared::types::api::{
+    ApiError, ApiErrorResponse, EmptyApiResponse, PaginatedApiResponse,
+};
+use crate::server::{
+    config::AppState,
+    shared::types::api::{ApiResponse, ApiResult},
+};
+use axum::extract::Path;
+use axum::{extract::State, response::Json};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::IntoParams;
+use utoipa_axum::{router::OpenApiRouter, routes};
+use uuid::Uuid;
+
+impl CrudHandlers for CustomScanProfile {
+    type Service = CustomScanProfileService;
+    type FilterQuery = CustomScanProfileFilterQuery;
+
+    fn get_service(state: &AppState) -> &Self::Service {
+        &state.services.custom_scan_profile_service
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        validator::Validate::validate(self).map_err(|e| e.to_string())?;
+        self.base.settings.validate()
+    }
+}
+
+// ============================================================================
+// Custom Scan Profile Ordering
+// ============================================================================
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomScanProfileOrderField {
+    #[default]
+    CreatedAt,
+    Name,
+    UpdatedAt,
+}
+
+impl OrderField for CustomScanProfileOrderField {
+    fn to_sql(&self) -> &'static str {
+        match self {
+            Self::CreatedAt => "custom_scan_profiles.created_at",
+            Self::Name => "custom_scan_profiles.name",
+            Self::UpdatedAt => "custom_scan_profiles.updated_at",
+        }
+    }
+}
+
+// ============================================================================
+// Custom Scan Profile Filter Query
+// ============================================================================
+
+#[derive(Deserialize, Default, Debug, Clone, IntoParams)]
+pub struct CustomScanProfileFilterQuery {
+    /// Primary ordering field (used for grouping). Always sorts ASC to keep groups together.
+    pub group_by: Option<CustomScanProfileOrderField>,
+    /// Secondary ordering field (sorting within groups or standalone sort).
+    pub order_by: Option<CustomScanProfileOrderField>,
+    /// Direction for order_by field (group_by always uses ASC).
+    pub order_direction: Option<OrderDirection>,
+    /// Maximum number of results to return (1-1000, default: 50). Use 0 for no limit.
+    #[param(minimum = 0, maximum = 1000)]
+    pub limit: Option<u32>,
+    /// Number of results to skip. Default: 0.
+    #[param(minimum = 0)]
+    pub offset: Option<u32>,
+}
+
+impl CustomScanProfileFilterQuery {
+    pub fn apply_ordering(
+        &self,
+        filter: StorableFilter<CustomScanProfile>,
+    ) -> (StorableFilter<CustomScanProfile>, String) {
+        crate::server::shared::handlers::ordering::apply_ordering(
+            self.group_by,
+            self.order_by,
+            self.order_direction,
+            filter,
+            "custom_scan_profiles.created_at ASC",
+        )
+    }
+}
+
+impl FilterQueryExtractor for CustomScanProfileFilterQuery {
+    fn apply_to_filter<T: Storable>(
+        &self,
+        filter: StorableFilter<T>,
+        _user_network_ids: &[Uuid],
+        _user_organization_id: Uuid,
+    ) -> StorableFilter<T> {
+        filter
+    }
+
+    fn pagination(&self) -> PaginationParams {
+        PaginationParams {
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+// Generated handlers for operations that use generic CRUD logic
+mod generated {
+    use super::*;
+    crate::crud_get_by_id_handler!(CustomScanProfile);
+    crate::crud_update_handler!(CustomScanProfile);
+    crate::crud_export_csv_handler!(CustomScanProfile);
+}
+
+pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(
+            get_all_custom_scan_profiles,
+            create_custom_scan_profile
+        ))
+        .routes(routes!(generated::export_csv))
+        .routes(routes!(
+            generated::get_by_id,
+            generated::update,
+            delete_custom_scan_profile
+        ))
+        .routes(routes!(bulk_delete_custom_scan_profiles))
+}
+
+/// Reject deleting profiles that scheduled or ad-hoc discoveries still reference, since
+/// those discoveries could no longer start
+async fn ensure_profiles_unused(
+    state: &AppState,
+    organization_id: Uuid,
+    profile_ids: &[Uuid],
+) -> Result<(), ApiError> {
+    let network_ids: Vec<Uuid> = state
+        .services
+        .network_service
+        .get_all(StorableFilter::<Network>::new_from_org_id(&organization_id))
+        .await?
+        .iter()
+        .map(|n| n.id)
+        .collect();
+
+    let discoveries = state
+        .services
+        .discovery_service
+        .get_all(StorableFilter::<Discovery>::new_from_network_ids(
+            &network_ids,
+        ))
+        .await?;
+
+    let in_use = discoveries.iter().find(|d| {
+        !matches!(d.base.run_type, RunType::Historical { .. })
+            && matches!(
+                &d.base.discovery_type,
+                DiscoveryType::Network {
+                    scan_profile: ScanProfile::Custom { profile_id, .. },
+                    ..
+                } if profile_ids.contains(profile_id)
+            )
+    });
+
+    if let Some(discovery) = in_use {
+        return Err(ApiError::conflict(&format!(
+            "Scan profile is used by discovery \"{}\"; choose a different profile there first",
+            discovery.base.name
+        )));
+    }
+    Ok(())
+}
+
+/// Delete Custom Scan Profile
+///
+/// Profiles used by a discovery can't be deleted until the discovery uses a different profile.
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    tag = CustomScanProfile::ENTITY_NAME_PLURAL,
+    params(
+        ("id" = Uuid, Path, description = "custom_scan_profile ID")
+    ),
+    responses(
+        (status = 200, description = "custom_scan_profile deleted successfully", body = EmptyApiResponse),
+        (status = 404, description = "custom_scan_profile not found", body = ApiErrorResponse),
+        (status = 409, description = "Scan profile is used by a discovery", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn delete_custom_scan_profile(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Member>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<ApiResponse<()>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+    ensure_profiles_unused(&state, organization_id, &[id]).await?;
+
+    delete_handler::<CustomScanProfile>(State(state), auth, Path(id)).await
+}
+
+/// Bulk delete Custom Scan Profiles
+#[utoipa::path(
+    post,
+    path = "/bulk-delete",
+    tag = CustomScanProfile::ENTITY_NAME_PLURAL,
+    request_body = Vec<Uuid>,
+    responses(
+        (status = 200, description = "Custom scan profiles deleted successfully", body = ApiResponse<BulkDeleteResponse>),
+        (status = 400, description = "Validation error", body = ApiErrorResponse),
+        (status = 409, description = "A scan profile is used by a discovery", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn bulk_delete_custom_scan_profiles(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Member>,
+    Json(ids): Json<Vec<Uuid>>,
+) -> ApiResult<Json<ApiResponse<BulkDeleteResponse>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+    ensure_profiles_unused(&state, organization_id, &ids).await?;
+
+    bulk_delete_handler::<CustomScanProfile>(State(state), auth, Json(ids)).await
+}
+
+/// List all Custom Scan Profiles
+///
+/// Returns all custom scan profiles in the authenticated user's organization.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = CustomScanProfile::ENTITY_NAME_PLURAL,
+    params(CustomScanProfileFilterQuery),
+    responses(
+        (status = 200, description = "List of custom scan profiles", body = PaginatedApiResponse<CustomScanProfile>),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_all_custom_scan_profiles(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Viewer>,
+    crate::server::shared::extractors::Query(query): crate::server::shared::extractors::Query<
+        CustomScanProfileFilterQuery,
+    >,
+) -> ApiResult<Json<PaginatedApiResponse<CustomScanProfile>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+    let base_filter = StorableFilter::<CustomScanProfile>::new_from_org_id(&organization_id);
+
+    let pagination = query.pagination();
+    let filter = pagination.apply_to_filter(base_filter);
+    let (filter, order_by) = query.apply_ordering(filter);
+
+    let result = state
+        .services
+        .custom_scan_profile_service
+        .get_paginated_ordered(filter, &order_by)
+        .await?;
+
+    let limit = pagination.effective_limit().unwrap_or(0);
+    let offset = pagination.effective_offset();
+
+    Ok(Json(PaginatedApiResponse::success(
+        result.items,
+        result.total_count,
+        limit,
+        offset,
+    )))
+}
+
+/// Create a new Custom Scan Profile
+///
+/// Creates a scan profile scoped to your organization. Network discoveries reference it with
+/// a `Custom` scan profile, and the server sends its current settings to the daemon each
+/// time one of them runs.
+///
+/// ### Validation
+///
+/// - Name must be 1-40 characters and unique within your organization
+/// - Description must be at most 100 characters
+/// - Port ranges must be within 1-65535 with start not exceeding end
+/// - Host timeout, scan rate and batch size must be greater than 0 when set
+#[utoipa::path(
+    post,
+    path = "",
+    tag = CustomScanProfile::ENTITY_NAME_PLURAL,
+    request_body = CustomScanProfile,
+    responses(
+        (status = 200, description = "Custom scan profile created successfully", body = ApiResponse<CustomScanProfile>),
+        (status = 400, description = "Validation error", body = ApiErrorResponse),
+        (status = 409, description = "Profile name already exists in this organization", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+pub async fn create_custom_scan_profile(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Member>,
+    Json(profile): Json<CustomScanProfile>,
+) -> ApiResult<Json<ApiResponse<CustomScanProfile>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+    // Check for duplicate name
+    let name_filter = StorableFilter::<CustomScanProfile>::new_from_org_id(&organization_id)
+        .name(profile.base.name.clone());
+
+    if let Some(existing) = state
+        .services
+        .custom_scan_profile_service
+        .get_one(name_filter)
+        .await?
+    {
+        return Err(ApiError::conflict(&format!(
+            "Scan profile names must be unique; a profile named \"{}\" already exists",
+            existing.base.name
+        )));
+    }
+
+    create_handler::<CustomScanProfile>(State(state), auth, Json(profile)).await
+}
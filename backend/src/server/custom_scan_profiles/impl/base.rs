@@ -0,0 +1,79 @@
+use crate::server::{
+    discovery::r#impl::scan_profile::{ScanProfile, ScanProfileSettings},
+    shared::entities::ChangeTriggersTopologyStaleness,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Validate, Serialize, Deserialize, ToSchema)]
+pub struct CustomScanProfileBase {
+    pub organization_id: Uuid,
+    #[validate(length(
+        min = 1,
+        max = 40,
+        message = "Scan profile name must be between 1 and 40 characters"
+    ))]
+    pub name: String,
+    #[serde(default)]
+    #[validate(length(max = 100, message = "Description must be at most 100 characters"))]
+    pub description: String,
+    /// Ports, probing depth, and timing used when scanning each host
+    pub settings: ScanProfileSettings,
+}
+
+impl Default for CustomScanProfileBase {
+    fn default() -> Self {
+        Self {
+            organization_id: Uuid::nil(),
+            name: "New Scan Profile".to_string(),
+            description: String::new(),
+            settings: ScanProfile::Standard.settings(),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default, ToSchema, Validate,
+)]
+pub struct CustomScanProfile {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub base: CustomScanProfileBase,
+}
+
+impl ChangeTriggersTopologyStaleness<CustomScanProfile> for CustomScanProfile {
+    fn triggers_staleness(&self, _other: Option<CustomScanProfile>) -> bool {
+        false
+    }
+}
+
+impl Display for CustomScanProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CustomScanProfile {}: {}", self.id, self.base.name)
+    }
+}
+
+impl CustomScanProfile {
+    pub fn new(base: CustomScanProfileBase) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+use crate::server::{
+    custom_scan_profiles::r#impl::base::{CustomScanProfile, CustomScanProfileBase},
+    shared::{
+        entities::EntityDiscriminants,
+        entity_metadata::EntityCategory,
+        storage::traits::{Entity, SqlValue, Storable},
+    },
+};
+
+/// CSV row representation for CustomScanProfile export
+#[derive(Serialize)]
+pub struct CustomScanProfileCsvRow {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub name: String,
+    pub description: String,
+    pub probe_endpoints: bool,
+    pub host_timeout_secs: Option<u64>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Storable for CustomScanProfile {
+    type BaseData = CustomScanProfileBase;
+
+    fn table_name() -> &'static str {
+        "custom_scan_profiles"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            updated_at,
+            base:
+                Self::BaseData {
+                    organization_id,
+                    name,
+                    description,
+                    settings,
+                },
+        } = self.clone();
+
+        Ok((
+            vec![
+                "id",
+                "organization_id",
+                "name",
+                "description",
+                "settings",
+                "created_at",
+                "updated_at",
+            ],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::Uuid(organization_id),
+                SqlValue::String(name),
+                SqlValue::String(description),
+                SqlValue::JsonValue(serde_json::to_value(&settings)?),
+                SqlValue::Timestamp(created_at),
+                SqlValue::Timestamp(updated_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        let settings = serde_json::from_value(row.get("settings"))
+            .map_err(|e| anyhow::anyhow!("Failed to deserialize settings: {}", e))?;
+
+        Ok(CustomScanProfile {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            base: CustomScanProfileBase {
+                organization_id: row.get("organization_id"),
+                name: row.get("name"),
+                description: row.get("description"),
+                settings,
+            },
+        })
+    }
+}
+
+impl Entity for CustomScanProfile {
+    type CsvRow = CustomScanProfileCsvRow;
+
+    fn to_csv_row(&self) -> Self::CsvRow {
+        CustomScanProfileCsvRow {
+            id: self.id,
+            organization_id: self.base.organization_id,
+            name: self.base.name.clone(),
+            description: self.base.description.clone(),
+            probe_endpoints: self.base.settings.probe_endpoints,
+            host_timeout_secs: self.base.settings.host_timeout_secs,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::CustomScanProfile
+    }
+
+    const ENTITY_NAME_SINGULAR: &'static str = "Custom Scan Profile";
+    const ENTITY_NAME_PLURAL: &'static str = "Custom Scan Profiles";
+    const ENTITY_DESCRIPTION: &'static str = "Saved network scan settings. Port sets, endpoint probing and timing shared by any network discovery in the organization.";
+
+    fn entity_category() -> EntityCategory {
+        EntityCategory::DiscoveryAndDaemons
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        Some(self.base.organization_id)
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    fn set_updated_at(&mut self, time: DateTime<Utc>) {
+        self.updated_at = time;
+    }
+}
@@ -0,0 +1,84 @@
+use crate::server::{
+    custom_scan_profiles::r#impl::base::CustomScanProfile,
+    discovery::r#impl::scan_profile::ScanProfile,
+    shared::{
+        events::bus::EventBus,
+        services::traits::{CrudService, EventBusService},
+        storage::generic::GenericPostgresStorage,
+    },
+    tags::entity_tags::EntityTagService,
+};
+use anyhow::{Error, anyhow};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct CustomScanProfileService {
+    storage: Arc<GenericPostgresStorage<CustomScanProfile>>,
+    event_bus: Arc<EventBus>,
+}
+
+impl EventBusService<CustomScanProfile> for CustomScanProfileService {
+    fn event_bus(&self) -> &Arc<EventBus> {
+        &self.event_bus
+    }
+
+    fn get_network_id(&self, _entity: &CustomScanProfile) -> Option<Uuid> {
+        None
+    }
+
+    fn get_organization_id(&self, entity: &CustomScanProfile) -> Option<Uuid> {
+        Some(entity.base.organization_id)
+    }
+}
+
+impl CrudService<CustomScanProfile> for CustomScanProfileService {
+    fn storage(&self) -> &Arc<GenericPostgresStorage<CustomScanProfile>> {
+        &self.storage
+    }
+
+    fn entity_tag_service(&self) -> Option<&Arc<EntityTagService>> {
+        None
+    }
+}
+
+impl CustomScanProfileService {
+    pub fn new(
+        storage: Arc<GenericPostgresStorage<CustomScanProfile>>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
+        Self { storage, event_bus }
+    }
+
+    /// Profile referenced by a discovery, if it exists in the organization
+    pub async fn get_for_organization(
+        &self,
+        organization_id: Uuid,
+        profile_id: &Uuid,
+    ) -> Result<Option<CustomScanProfile>, Error> {
+        Ok(self
+            .get_by_id(profile_id)
+            .await?
+            .filter(|p| p.base.organization_id == organization_id))
+    }
+
+    /// Fill in the settings of a custom scan profile before it is shipped to the daemon
+    pub async fn build_scan_profile_for_discovery(
+        &self,
+        organization_id: Uuid,
+        scan_profile: ScanProfile,
+    ) -> Result<ScanProfile, Error> {
+        let ScanProfile::Custom { profile_id, .. } = scan_profile else {
+            return Ok(scan_profile);
+        };
+
+        let profile = self
+            .get_for_organization(organization_id, &profile_id)
+            .await?
+            .ok_or_else(|| anyhow!("Scan profile {} no longer exists", profile_id))?;
+
+        Ok(ScanProfile::Custom {
+            profile_id,
+            settings: Some(Box::new(profile.base.settings)),
+        })
+    }
+}
@@ -0,0 +1,360 @@
+use crate::server::auth::middleware::permissions::{Admin, Authorized, Member, Viewer};
+use crate::server::custom_service_definitions::r#impl::base::CustomServiceDefinition;
+use crate::server::custom_service_definitions::service::CustomServiceDefinitionService;
+use crate::server::services::r#impl::definitions::ServiceDefinition;
+use crate::server::shared::handlers::ordering::OrderField;
+use crate::server::shared::handlers::query::{
+    FilterQueryExtractor, OrderDirection, PaginationParams,
+};
+use crate::server::shared::handlers::traits::{
+    BulkDeleteResponse, CrudHandlers, bulk_delete_handler, create_handler, delete_handler,
+    update_handler,
+};
+use crate::server::shared::services::traits::CrudService;
+use crate::server::shared::storage::filter::StorableFilter;
+use crate::server::shared::storage::traits::{Entity, Storable};
+use crate::server::shared::types::api::{
+    ApiError, ApiErrorResponse, EmptyApiResponse, PaginatedApiResponse,
+};
+use crate::server::shared::types::metadata::{MetadataProvider, TypeMetadata};
+use crate::server::{
+    config::AppState,
+    shared::types::api::{ApiResponse, ApiResult},
+};
+use axum::{extract::State, response::Json};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::IntoParams;
+use utoipa_axum::{router::OpenApiRouter, routes};
+use uuid::Uuid;
+
+impl CrudHandlers for CustomServiceDefinition {
+    type Service = CustomServiceDefinitionService;
+    type FilterQuery = CustomServiceDefinitionFilterQuery;
+
+    fn get_service(state: &AppState) -> &Self::Service {
+        &state.services.custom_service_definition_service
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        validator::Validate::validate(self).map_err(|e| e.to_string())?;
+        self.base.validate_definition()
+    }
+}
+
+// ============================================================================
+// Custom Service Definition Ordering
+// ============================================================================
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomServiceDefinitionOrderField {
+    #[default]
+    CreatedAt,
+    Name,
+    Category,
+    UpdatedAt,
+}
+
+impl OrderField for CustomServiceDefinitionOrderField {
+    fn to_sql(&self) -> &'static str {
+        match self {
+            Self::CreatedAt => "custom_service_definitions.created_at",
+            Self::Name => "custom_service_definitions.name",
+            Self::Category => "custom_service_definitions.category",
+            Self::UpdatedAt => "custom_service_definitions.updated_at",
+        }
+    }
+}
+
+// ============================================================================
+// Custom Service Definition Filter Query
+// ============================================================================
+
+#[derive(Deserialize, Default, Debug, Clone, IntoParams)]
+pub struct CustomServiceDefinitionFilterQuery {
+    /// Primary ordering field (used for grouping). Always sorts ASC to keep groups together.
+    pub group_by: Option<CustomServiceDefinitionOrderField>,
+    /// Secondary ordering field (sorting within groups or standalone sort).
+    pub order_by: Option<CustomServiceDefinitionOrderField>,
+    /// Direction for order_by field (group_by always uses ASC).
+    pub order_direction: Option<OrderDirection>,
+    /// Maximum number of results to return (1-1000, default: 50). Use 0 for no limit.
+    #[param(minimum = 0, maximum = 1000)]
+    pub limit: Option<u32>,
+    /// Number of results to skip. Default: 0.
+    #[param(minimum = 0)]
+    pub offset: Option<u32>,
+}
+
+impl CustomServiceDefinitionFilterQuery {
+    pub fn apply_ordering(
+        &self,
+        filter: StorableFilter<CustomServiceDefinition>,
+    ) -> (StorableFilter<CustomServiceDefinition>, String) {
+        crate::server::shared::handlers::ordering::apply_ordering(
+            self.group_by,
+            self.order_by,
+            self.order_direction,
+            filter,
+            "custom_service_definitions.created_at ASC",
+        )
+    }
+}
+
+impl FilterQueryExtractor for CustomServiceDefinitionFilterQuery {
+    fn apply_to_filter<T: Storable>(
+        &self,
+        filter: StorableFilter<T>,
+        _user_network_ids: &[Uuid],
+        _user_organization_id: Uuid,
+    ) -> StorableFilter<T> {
+        filter
+    }
+
+    fn pagination(&self) -> PaginationParams {
+        PaginationParams {
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+// Generated handler for read-only operations
+mod generated {
+    use super::*;
+    crate::crud_get_by_id_handler!(CustomServiceDefinition);
+    crate::crud_export_csv_handler!(CustomServiceDefinition);
+}
+
+pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(
+            get_all_custom_service_definitions,
+            create_custom_service_definition
+        ))
+        .routes(routes!(generated::export_csv))
+        .routes(routes!(get_custom_service_definition_metadata))
+        .routes(routes!(
+            generated::get_by_id,
+            update_custom_service_definition,
+            delete_custom_service_definition
+        ))
+        .routes(routes!(bulk_delete_custom_service_definitions))
+}
+
+/// Update Custom Service Definition
+#[utoipa::path(
+    put,
+    path = "/{id}",
+    tag = CustomServiceDefinition::ENTITY_NAME_PLURAL,
+    params(
+        ("id" = Uuid, Path, description = "custom_service_definition ID")
+    ),
+    request_body = CustomServiceDefinition,
+    responses(
+        (status = 200, description = "custom_service_definition updated successfully", body = ApiResponse<CustomServiceDefinition>),
+        (status = 400, description = "Validation error", body = ApiErrorResponse),
+        (status = 404, description = "custom_service_definition not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn update_custom_service_definition(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    id: axum::extract::Path<Uuid>,
+    entity: Json<CustomServiceDefinition>,
+) -> ApiResult<Json<ApiResponse<CustomServiceDefinition>>> {
+    update_handler::<CustomServiceDefinition>(state, auth.into_permission::<Member>(), id, entity)
+        .await
+}
+
+/// Delete Custom Service Definition
+///
+/// Services already matched by this definition keep their bindings but are shown
+/// as an unknown service.
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    tag = CustomServiceDefinition::ENTITY_NAME_PLURAL,
+    params(
+        ("id" = Uuid, Path, description = "custom_service_definition ID")
+    ),
+    responses(
+        (status = 200, description = "custom_service_definition deleted successfully", body = EmptyApiResponse),
+        (status = 404, description = "custom_service_definition not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn delete_custom_service_definition(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    id: axum::extract::Path<Uuid>,
+) -> ApiResult<Json<ApiResponse<()>>> {
+    delete_handler::<CustomServiceDefinition>(state, auth.into_permission::<Member>(), id).await
+}
+
+/// Bulk delete Custom Service Definitions
+#[utoipa::path(
+    post,
+    path = "/bulk-delete",
+    tag = CustomServiceDefinition::ENTITY_NAME_PLURAL,
+    request_body = Vec<Uuid>,
+    responses(
+        (status = 200, description = "Custom service definitions deleted successfully", body = ApiResponse<BulkDeleteResponse>),
+        (status = 400, description = "Validation error", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn bulk_delete_custom_service_definitions(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    ids: Json<Vec<Uuid>>,
+) -> ApiResult<Json<ApiResponse<BulkDeleteResponse>>> {
+    bulk_delete_handler::<CustomServiceDefinition>(state, auth.into_permission::<Member>(), ids)
+        .await
+}
+
+/// List all Custom Service Definitions
+///
+/// Returns all custom service definitions in the authenticated user's organization.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = CustomServiceDefinition::ENTITY_NAME_PLURAL,
+    params(CustomServiceDefinitionFilterQuery),
+    responses(
+        (status = 200, description = "List of custom service definitions", body = PaginatedApiResponse<CustomServiceDefinition>),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_all_custom_service_definitions(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Viewer>,
+    crate::server::shared::extractors::Query(query): crate::server::shared::extractors::Query<
+        CustomServiceDefinitionFilterQuery,
+    >,
+) -> ApiResult<Json<PaginatedApiResponse<CustomServiceDefinition>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+    let base_filter = StorableFilter::<CustomServiceDefinition>::new_from_org_id(&organization_id);
+
+    let pagination = query.pagination();
+    let filter = pagination.apply_to_filter(base_filter);
+    let (filter, order_by) = query.apply_ordering(filter);
+
+    let result = state
+        .services
+        .custom_service_definition_service
+        .get_paginated_ordered(filter, &order_by)
+        .await?;
+
+    let limit = pagination.effective_limit().unwrap_or(0);
+    let offset = pagination.effective_offset();
+
+    Ok(Json(PaginatedApiResponse::success(
+        result.items,
+        result.total_count,
+        limit,
+        offset,
+    )))
+}
+
+/// Get Custom Service Definition metadata
+///
+/// Returns the organization's custom service definitions in the same shape as
+/// `service_definitions` in `/api/metadata`, so clients can resolve services
+/// matched by them.
+#[utoipa::path(
+    get,
+    path = "/metadata",
+    tag = CustomServiceDefinition::ENTITY_NAME_PLURAL,
+    responses(
+        (status = 200, description = "Custom service definition metadata", body = ApiResponse<Vec<TypeMetadata>>),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_custom_service_definition_metadata(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Viewer>,
+) -> ApiResult<Json<ApiResponse<Vec<TypeMetadata>>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+    let filter = StorableFilter::<CustomServiceDefinition>::new_from_org_id(&organization_id);
+    let definitions = state
+        .services
+        .custom_service_definition_service
+        .get_all(filter)
+        .await?;
+
+    let metadata = definitions
+        .iter()
+        .map(|d| {
+            let definition: Box<dyn ServiceDefinition> = d.into();
+            definition.to_metadata()
+        })
+        .collect();
+
+    Ok(Json(ApiResponse::success(metadata)))
+}
+
+/// Create a new Custom Service Definition
+///
+/// Creates a service definition scoped to your organization. It is shipped to daemons
+/// with each network discovery and evaluated alongside the built-in definitions.
+///
+/// ### Validation
+///
+/// - Name must be 1-40 characters and unique within your organization
+/// - Description must be at most 100 characters
+/// - Scanopy and OpenPorts categories are reserved
+/// - Pattern must include a Port, Endpoint, Header, Banner, MacVendor, mDNS or UPnP match, and
+///   AnyOf/AllOf groups must not be empty
+#[utoipa::path(
+    post,
+    path = "",
+    tag = CustomServiceDefinition::ENTITY_NAME_PLURAL,
+    request_body = CustomServiceDefinition,
+    responses(
+        (status = 200, description = "Custom service definition created successfully", body = ApiResponse<CustomServiceDefinition>),
+        (status = 400, description = "Validation error", body = ApiErrorResponse),
+        (status = 409, description = "Definition name already exists in this organization", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+pub async fn create_custom_service_definition(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    Json(definition): Json<CustomServiceDefinition>,
+) -> ApiResult<Json<ApiResponse<CustomServiceDefinition>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+    // Check for duplicate name
+    let name_filter = StorableFilter::<CustomServiceDefinition>::new_from_org_id(&organization_id)
+        .name(definition.base.name.clone());
+
+    if let Some(existing) = state
+        .services
+        .custom_service_definition_service
+        .get_one(name_filter)
+        .await?
+    {
+        return Err(ApiError::conflict(&format!(
+            "Service definition names must be unique; a definition named \"{}\" already exists",
+            existing.base.name
+        )));
+    }
+
+    create_handler::<CustomServiceDefinition>(
+        State(state),
+        auth.into_permission::<Member>(),
+        Json(definition),
+    )
+    .await
+}
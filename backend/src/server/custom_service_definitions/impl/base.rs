@@ -0,0 +1,110 @@
+use crate::server::{
+    custom_service_definitions::r#impl::patterns::CustomPattern, ports::r#impl::base::PortType,
+    services::r#impl::categories::ServiceCategory,
+    shared::entities::ChangeTriggersTopologyStaleness,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Validate, Serialize, Deserialize, ToSchema)]
+pub struct CustomServiceDefinitionBase {
+    pub organization_id: Uuid,
+    #[validate(length(
+        min = 1,
+        max = 40,
+        message = "Service name must be between 1 and 40 characters"
+    ))]
+    pub name: String,
+    #[serde(default)]
+    #[validate(length(max = 100, message = "Description must be at most 100 characters"))]
+    pub description: String,
+    pub category: ServiceCategory,
+    /// URL of icon, or static path if serving from /logos. Empty for the category icon.
+    #[serde(default)]
+    #[validate(length(max = 2048, message = "Logo URL must be at most 2048 characters"))]
+    pub logo_url: String,
+    /// How the service is identified during discovery
+    pub pattern: CustomPattern,
+}
+
+impl Default for CustomServiceDefinitionBase {
+    fn default() -> Self {
+        Self {
+            organization_id: Uuid::nil(),
+            name: "New Service Definition".to_string(),
+            description: String::new(),
+            category: ServiceCategory::Custom,
+            logo_url: String::new(),
+            pattern: CustomPattern::Port {
+                port: PortType::default(),
+            },
+        }
+    }
+}
+
+impl CustomServiceDefinitionBase {
+    /// Check fields that can't be expressed as validator attributes
+    pub fn validate_definition(&self) -> Result<(), String> {
+        if matches!(
+            self.category,
+            ServiceCategory::Scanopy | ServiceCategory::OpenPorts
+        ) {
+            return Err(format!(
+                "Category {} is reserved for built-in services",
+                self.category
+            ));
+        }
+
+        self.pattern.validate_pattern()
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default, ToSchema, Validate,
+)]
+pub struct CustomServiceDefinition {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub base: CustomServiceDefinitionBase,
+}
+
+impl ChangeTriggersTopologyStaleness<CustomServiceDefinition> for CustomServiceDefinition {
+    fn triggers_staleness(&self, _other: Option<CustomServiceDefinition>) -> bool {
+        false
+    }
+}
+
+impl Display for CustomServiceDefinition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CustomServiceDefinition {}: {} ({})",
+            self.id, self.base.name, self.base.category
+        )
+    }
+}
+
+impl CustomServiceDefinition {
+    pub fn new(base: CustomServiceDefinitionBase) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+}
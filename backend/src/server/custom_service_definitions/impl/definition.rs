@@ -0,0 +1,143 @@
+use uuid::Uuid;
+
+use crate::server::{
+    custom_service_definitions::r#impl::{base::CustomServiceDefinition, patterns::CustomPattern},
+    services::r#impl::{
+        categories::ServiceCategory, definitions::ServiceDefinition, patterns::Pattern,
+    },
+};
+
+/// Prefix for user-defined service definition ids, keeping them apart from built-in names
+pub const CUSTOM_DEFINITION_ID_PREFIX: &str = "Custom:";
+
+/// Service definition id for a user-defined definition
+pub fn custom_definition_id(id: &Uuid) -> String {
+    format!("{}{}", CUSTOM_DEFINITION_ID_PREFIX, id)
+}
+
+/// A user-defined service definition, evaluated alongside the built-in registry
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RuntimeServiceDefinition {
+    id: String,
+    name: String,
+    description: String,
+    category: ServiceCategory,
+    logo_url: String,
+    /// None for a definition only known by id; it never matches anything
+    pattern: Option<CustomPattern>,
+}
+
+impl RuntimeServiceDefinition {
+    /// A custom definition known only by id, as read back from a stored service. Resolve it
+    /// against the owning organization's definitions wherever name or category matter.
+    pub fn unresolved(id: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            name: "Custom Service".to_string(),
+            description: String::new(),
+            category: ServiceCategory::Unknown,
+            logo_url: String::new(),
+            pattern: None,
+        }
+    }
+}
+
+impl From<&CustomServiceDefinition> for RuntimeServiceDefinition {
+    fn from(value: &CustomServiceDefinition) -> Self {
+        Self {
+            id: custom_definition_id(&value.id),
+            name: value.base.name.clone(),
+            description: value.base.description.clone(),
+            category: value.base.category,
+            logo_url: value.base.logo_url.clone(),
+            pattern: Some(value.base.pattern.clone()),
+        }
+    }
+}
+
+impl From<&CustomServiceDefinition> for Box<dyn ServiceDefinition> {
+    fn from(value: &CustomServiceDefinition) -> Self {
+        Box::new(RuntimeServiceDefinition::from(value))
+    }
+}
+
+impl ServiceDefinition for RuntimeServiceDefinition {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn definition_id(&self) -> &str {
+        &self.id
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn category(&self) -> ServiceCategory {
+        self.category
+    }
+
+    fn discovery_pattern(&self) -> Pattern<'_> {
+        self.pattern
+            .as_ref()
+            .map(CustomPattern::to_pattern)
+            .unwrap_or(Pattern::None)
+    }
+
+    fn logo_url(&self) -> &str {
+        &self.logo_url
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::{
+        custom_service_definitions::r#impl::base::CustomServiceDefinitionBase,
+        ports::r#impl::base::PortType, shared::types::metadata::HasId,
+    };
+
+    #[test]
+    fn test_runtime_definition_uses_namespaced_id() {
+        let definition = CustomServiceDefinition::new(CustomServiceDefinitionBase {
+            name: "Billing API".to_string(),
+            category: ServiceCategory::Development,
+            pattern: CustomPattern::Port {
+                port: PortType::new_tcp(8765),
+            },
+            ..Default::default()
+        });
+
+        let runtime: Box<dyn ServiceDefinition> = (&definition).into();
+        assert_eq!(runtime.id(), custom_definition_id(&definition.id));
+        assert_eq!(ServiceDefinition::name(&runtime), "Billing API");
+        assert_eq!(
+            runtime.discovery_pattern().ports(),
+            vec![PortType::new_tcp(8765)]
+        );
+
+        // Serialized services reference the definition by id, so the id round-trips
+        let serialized = serde_json::to_value(&runtime).unwrap();
+        assert_eq!(serialized, serde_json::json!(runtime.id()));
+    }
+
+    #[test]
+    fn test_stored_custom_reference_keeps_its_id() {
+        let id = custom_definition_id(&Uuid::new_v4());
+
+        // No registry to look the definition up in, so the id must survive a round trip
+        let stored: Box<dyn ServiceDefinition> =
+            serde_json::from_value(serde_json::json!(id)).unwrap();
+        assert_eq!(stored.id(), id);
+        assert_eq!(
+            ServiceDefinition::category(&stored),
+            ServiceCategory::Unknown
+        );
+        assert_eq!(stored.discovery_pattern(), Pattern::None);
+        assert_eq!(
+            serde_json::to_value(&stored).unwrap(),
+            serde_json::json!(id)
+        );
+    }
+}
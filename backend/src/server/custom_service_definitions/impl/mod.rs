@@ -0,0 +1,4 @@
+pub mod base;
+pub mod definition;
+pub mod patterns;
+pub mod storage;
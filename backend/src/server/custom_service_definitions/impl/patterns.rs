@@ -0,0 +1,406 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+use utoipa::ToSchema;
+
+use crate::server::{
+    ports::r#impl::base::PortType,
+    services::r#impl::{advertisements::UpnpField, patterns::Pattern},
+    subnets::r#impl::types::SubnetType,
+};
+
+/// Maximum nesting of AnyOf/AllOf/Not groups
+pub const MAX_PATTERN_DEPTH: usize = 8;
+
+/// Maximum length of a banner regex
+pub const MAX_BANNER_REGEX_LENGTH: usize = 200;
+
+/// HTTP status code range, end exclusive (e.g. 200..300)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+pub struct StatusCodeRange {
+    pub start: u16,
+    pub end: u16,
+}
+
+impl From<StatusCodeRange> for Range<u16> {
+    fn from(value: StatusCodeRange) -> Self {
+        value.start..value.end
+    }
+}
+
+/// Declarative match pattern for a user-defined service.
+/// Mirrors the subset of the built-in `Pattern` that can be expressed as data.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type")]
+pub enum CustomPattern {
+    /// Match any of the listed patterns
+    AnyOf {
+        #[schema(no_recursion)]
+        patterns: Vec<CustomPattern>,
+    },
+    /// Must match all of the listed patterns
+    AllOf {
+        #[schema(no_recursion)]
+        patterns: Vec<CustomPattern>,
+    },
+    /// Inverse of pattern
+    Not {
+        #[schema(no_recursion)]
+        pattern: Box<CustomPattern>,
+    },
+    /// A specific port is open on the host
+    Port { port: PortType },
+    /// An HTTP endpoint on the port returns a body containing `response`
+    Endpoint {
+        port: PortType,
+        /// Request path, e.g. "/" or "/api/health"
+        path: String,
+        /// String to match in the response body
+        response: String,
+        /// Accepted status codes; defaults to any ok or redirect
+        #[serde(default)]
+        status_codes: Option<StatusCodeRange>,
+    },
+    /// A response header contains `value`
+    Header {
+        /// Port to check; any port if omitted
+        #[serde(default)]
+        port: Option<PortType>,
+        header: String,
+        value: String,
+        /// Accepted status codes; defaults to any ok or redirect
+        #[serde(default)]
+        status_codes: Option<StatusCodeRange>,
+    },
+    /// The banner a TCP port sent on connect, or in reply to a generic probe, matches `regex`
+    Banner {
+        port: PortType,
+        /// Regular expression; non-printable bytes in the banner are escaped as `\xNN`
+        regex: String,
+    },
+    /// The vendor derived from the host's MAC address matches
+    MacVendor { vendor: String },
+    /// The host advertises a DNS-SD service type over mDNS, e.g. "_airplay._tcp"
+    MdnsService { service_type: String },
+    /// A TXT record of an mDNS service advertised by the host contains `value`
+    MdnsTxt {
+        service_type: String,
+        key: String,
+        value: String,
+    },
+    /// A field of the host's UPnP device description contains `value`
+    Upnp { field: UpnpField, value: String },
+    /// The subnet the host was found on is of this type
+    SubnetIsType { subnet_type: SubnetType },
+}
+
+impl CustomPattern {
+    /// Convert to the pattern evaluated during discovery
+    pub fn to_pattern(&self) -> Pattern<'_> {
+        match self {
+            CustomPattern::AnyOf { patterns } => {
+                Pattern::AnyOf(patterns.iter().map(Self::to_pattern).collect())
+            }
+            CustomPattern::AllOf { patterns } => {
+                Pattern::AllOf(patterns.iter().map(Self::to_pattern).collect())
+            }
+            CustomPattern::Not { pattern } => Pattern::Not(Box::new(pattern.to_pattern())),
+            CustomPattern::Port { port } => Pattern::Port(*port),
+            CustomPattern::Endpoint {
+                port,
+                path,
+                response,
+                status_codes,
+            } => Pattern::Endpoint(*port, path, response, status_codes.map(Into::into)),
+            CustomPattern::Header {
+                port,
+                header,
+                value,
+                status_codes,
+            } => Pattern::Header(*port, header, value, status_codes.map(Into::into)),
+            CustomPattern::Banner { port, regex } => Pattern::Banner(*port, regex),
+            CustomPattern::MacVendor { vendor } => Pattern::MacVendor(vendor),
+            CustomPattern::MdnsService { service_type } => Pattern::MdnsService(service_type),
+            CustomPattern::MdnsTxt {
+                service_type,
+                key,
+                value,
+            } => Pattern::MdnsTxt(service_type, key, value),
+            CustomPattern::Upnp { field, value } => Pattern::Upnp(*field, value),
+            CustomPattern::SubnetIsType { subnet_type } => Pattern::SubnetIsType(*subnet_type),
+        }
+    }
+
+    /// Check that the pattern is well-formed and can identify a service on its own
+    pub fn validate_pattern(&self) -> Result<(), String> {
+        self.validate_at_depth(0)?;
+
+        if !self.has_positive_match() {
+            return Err(
+                "Pattern must include a Port, Endpoint, Header, Banner, MacVendor, mDNS or UPnP match"
+                    .to_string(),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn validate_at_depth(&self, depth: usize) -> Result<(), String> {
+        if depth > MAX_PATTERN_DEPTH {
+            return Err(format!(
+                "Pattern cannot be nested more than {} levels deep",
+                MAX_PATTERN_DEPTH
+            ));
+        }
+
+        let validate_status = |status_codes: &Option<StatusCodeRange>| match status_codes {
+            Some(range) if range.start >= range.end => {
+                Err("Status code range start must be less than end".to_string())
+            }
+            _ => Ok(()),
+        };
+
+        match self {
+            CustomPattern::AnyOf { patterns } | CustomPattern::AllOf { patterns } => {
+                if patterns.is_empty() {
+                    return Err("AnyOf and AllOf patterns must not be empty".to_string());
+                }
+                patterns
+                    .iter()
+                    .try_for_each(|p| p.validate_at_depth(depth + 1))
+            }
+            CustomPattern::Not { pattern } => pattern.validate_at_depth(depth + 1),
+            CustomPattern::Port { .. } | CustomPattern::SubnetIsType { .. } => Ok(()),
+            CustomPattern::Endpoint {
+                path,
+                response,
+                status_codes,
+                ..
+            } => {
+                if !path.starts_with('/') {
+                    return Err("Endpoint path must start with '/'".to_string());
+                }
+                if response.is_empty() {
+                    return Err("Endpoint response match must not be empty".to_string());
+                }
+                validate_status(status_codes)
+            }
+            CustomPattern::Header {
+                header,
+                value,
+                status_codes,
+                ..
+            } => {
+                if header.is_empty() || value.is_empty() {
+                    return Err("Header name and value must not be empty".to_string());
+                }
+                validate_status(status_codes)
+            }
+            CustomPattern::Banner { port, regex } => {
+                if !port.is_tcp() {
+                    return Err("Banner port must be a TCP port".to_string());
+                }
+                if regex.is_empty() || regex.len() > MAX_BANNER_REGEX_LENGTH {
+                    return Err(format!(
+                        "Banner regex must be 1-{} characters",
+                        MAX_BANNER_REGEX_LENGTH
+                    ));
+                }
+                Regex::new(regex)
+                    .map(|_| ())
+                    .map_err(|e| format!("Invalid banner regex: {}", e))
+            }
+            CustomPattern::MacVendor { vendor } => {
+                if vendor.is_empty() {
+                    return Err("MAC vendor must not be empty".to_string());
+                }
+                Ok(())
+            }
+            CustomPattern::MdnsService { service_type } => validate_service_type(service_type),
+            CustomPattern::MdnsTxt {
+                service_type,
+                key,
+                value,
+            } => {
+                if key.is_empty() || value.is_empty() {
+                    return Err("mDNS TXT key and value must not be empty".to_string());
+                }
+                validate_service_type(service_type)
+            }
+            CustomPattern::Upnp { value, .. } => {
+                if value.is_empty() {
+                    return Err("UPnP match value must not be empty".to_string());
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Whether the pattern requires observing something on the host
+    /// (a pattern made only of Not/SubnetIsType would match every host)
+    fn has_positive_match(&self) -> bool {
+        match self {
+            CustomPattern::AnyOf { patterns } => patterns.iter().all(Self::has_positive_match),
+            CustomPattern::AllOf { patterns } => patterns.iter().any(Self::has_positive_match),
+            CustomPattern::Not { .. } | CustomPattern::SubnetIsType { .. } => false,
+            CustomPattern::Port { .. }
+            | CustomPattern::Endpoint { .. }
+            | CustomPattern::Header { .. }
+            | CustomPattern::Banner { .. }
+            | CustomPattern::MacVendor { .. }
+            | CustomPattern::MdnsService { .. }
+            | CustomPattern::MdnsTxt { .. }
+            | CustomPattern::Upnp { .. } => true,
+        }
+    }
+}
+
+/// DNS-SD service types look like "_service._tcp" or "_service._udp"
+fn validate_service_type(service_type: &str) -> Result<(), String> {
+    let valid = service_type
+        .strip_suffix("._tcp")
+        .or_else(|| service_type.strip_suffix("._udp"))
+        .is_some_and(|service| service.len() > 1 && service.starts_with('_'));
+
+    if !valid {
+        return Err(format!(
+            "mDNS service type '{}' must look like \"_service._tcp\" or \"_service._udp\"",
+            service_type
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn port(number: u16) -> CustomPattern {
+        CustomPattern::Port {
+            port: PortType::new_tcp(number),
+        }
+    }
+
+    #[test]
+    fn test_to_pattern() {
+        let custom = CustomPattern::AllOf {
+            patterns: vec![
+                port(8765),
+                CustomPattern::Endpoint {
+                    port: PortType::new_tcp(8765),
+                    path: "/health".to_string(),
+                    response: "billing-api".to_string(),
+                    status_codes: None,
+                },
+            ],
+        };
+
+        let pattern = custom.to_pattern();
+        assert_eq!(pattern.ports(), vec![PortType::new_tcp(8765)]);
+        assert_eq!(pattern.endpoints().len(), 1);
+        assert_eq!(pattern.endpoints()[0].path, "/health");
+
+        let ranged = CustomPattern::Header {
+            port: None,
+            header: "Server".to_string(),
+            value: "billing".to_string(),
+            status_codes: Some(StatusCodeRange {
+                start: 200,
+                end: 300,
+            }),
+        };
+        assert!(matches!(
+            ranged.to_pattern(),
+            Pattern::Header(None, "Server", "billing", Some(range)) if range == (200..300)
+        ));
+    }
+
+    #[test]
+    fn test_validate_pattern() {
+        assert!(port(8765).validate_pattern().is_ok());
+
+        // Nothing to observe on the host
+        let subnet_only = CustomPattern::SubnetIsType {
+            subnet_type: SubnetType::Lan,
+        };
+        assert!(subnet_only.validate_pattern().is_err());
+        assert!(
+            CustomPattern::Not {
+                pattern: Box::new(port(22))
+            }
+            .validate_pattern()
+            .is_err()
+        );
+        // AnyOf is only positive if every branch is
+        assert!(
+            CustomPattern::AnyOf {
+                patterns: vec![port(22), subnet_only.clone()]
+            }
+            .validate_pattern()
+            .is_err()
+        );
+        assert!(
+            CustomPattern::AllOf {
+                patterns: vec![port(22), subnet_only]
+            }
+            .validate_pattern()
+            .is_ok()
+        );
+
+        assert!(
+            CustomPattern::AnyOf { patterns: vec![] }
+                .validate_pattern()
+                .is_err()
+        );
+        assert!(
+            CustomPattern::Endpoint {
+                port: PortType::new_tcp(80),
+                path: "health".to_string(),
+                response: "ok".to_string(),
+                status_codes: None,
+            }
+            .validate_pattern()
+            .is_err()
+        );
+
+        assert!(
+            CustomPattern::MdnsService {
+                service_type: "_airplay._tcp".to_string()
+            }
+            .validate_pattern()
+            .is_ok()
+        );
+        assert!(
+            CustomPattern::MdnsService {
+                service_type: "_airplay._tcp.local".to_string()
+            }
+            .validate_pattern()
+            .is_err()
+        );
+
+        assert!(
+            CustomPattern::Banner {
+                port: PortType::new_tcp(2222),
+                regex: r"^SSH-\d+\.\d+-".to_string()
+            }
+            .validate_pattern()
+            .is_ok()
+        );
+        assert!(
+            CustomPattern::Banner {
+                port: PortType::new_tcp(2222),
+                regex: "^SSH-(".to_string()
+            }
+            .validate_pattern()
+            .is_err()
+        );
+
+        let mut nested = port(22);
+        for _ in 0..=MAX_PATTERN_DEPTH {
+            nested = CustomPattern::AllOf {
+                patterns: vec![nested],
+            };
+        }
+        assert!(nested.validate_pattern().is_err());
+    }
+}
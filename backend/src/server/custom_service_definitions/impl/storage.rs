@@ -0,0 +1,176 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+use crate::server::{
+    custom_service_definitions::r#impl::base::{
+        CustomServiceDefinition, CustomServiceDefinitionBase,
+    },
+    shared::{
+        entities::EntityDiscriminants,
+        entity_metadata::EntityCategory,
+        storage::traits::{Entity, SqlValue, Storable},
+    },
+};
+
+/// CSV row representation for CustomServiceDefinition export
+#[derive(Serialize)]
+pub struct CustomServiceDefinitionCsvRow {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub name: String,
+    pub description: String,
+    pub category: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Storable for CustomServiceDefinition {
+    type BaseData = CustomServiceDefinitionBase;
+
+    fn table_name() -> &'static str {
+        "custom_service_definitions"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            updated_at,
+            base:
+                Self::BaseData {
+                    organization_id,
+                    name,
+                    description,
+                    category,
+                    logo_url,
+                    pattern,
+                },
+        } = self.clone();
+
+        Ok((
+            vec![
+                "id",
+                "organization_id",
+                "name",
+                "description",
+                "category",
+                "logo_url",
+                "pattern",
+                "created_at",
+                "updated_at",
+            ],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::Uuid(organization_id),
+                SqlValue::String(name),
+                SqlValue::String(description),
+                SqlValue::String(category.to_string()),
+                SqlValue::String(logo_url),
+                SqlValue::JsonValue(serde_json::to_value(&pattern)?),
+                SqlValue::Timestamp(created_at),
+                SqlValue::Timestamp(updated_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        let category: String = row.get("category");
+        let category = serde_json::from_value(serde_json::Value::String(category))
+            .map_err(|e| anyhow::anyhow!("Failed to deserialize category: {}", e))?;
+
+        let pattern = serde_json::from_value(row.get("pattern"))
+            .map_err(|e| anyhow::anyhow!("Failed to deserialize pattern: {}", e))?;
+
+        Ok(CustomServiceDefinition {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            base: CustomServiceDefinitionBase {
+                organization_id: row.get("organization_id"),
+                name: row.get("name"),
+                description: row.get("description"),
+                category,
+                logo_url: row.get("logo_url"),
+                pattern,
+            },
+        })
+    }
+}
+
+impl Entity for CustomServiceDefinition {
+    type CsvRow = CustomServiceDefinitionCsvRow;
+
+    fn to_csv_row(&self) -> Self::CsvRow {
+        CustomServiceDefinitionCsvRow {
+            id: self.id,
+            organization_id: self.base.organization_id,
+            name: self.base.name.clone(),
+            description: self.base.description.clone(),
+            category: self.base.category.to_string(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::CustomServiceDefinition
+    }
+
+    const ENTITY_NAME_SINGULAR: &'static str = "Custom Service Definition";
+    const ENTITY_NAME_PLURAL: &'static str = "Custom Service Definitions";
+    const ENTITY_DESCRIPTION: &'static str = "User-defined service definitions. Identify in-house applications during discovery alongside the built-in service definitions.";
+
+    fn entity_category() -> EntityCategory {
+        EntityCategory::DiscoveryAndDaemons
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        Some(self.base.organization_id)
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    fn set_updated_at(&mut self, time: DateTime<Utc>) {
+        self.updated_at = time;
+    }
+}
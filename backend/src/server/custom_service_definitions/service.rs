@@ -0,0 +1,96 @@
+use crate::server::{
+    custom_service_definitions::r#impl::{
+        base::CustomServiceDefinition,
+        definition::{CUSTOM_DEFINITION_ID_PREFIX, custom_definition_id},
+    },
+    services::r#impl::{base::Service, definitions::ServiceDefinition},
+    shared::{
+        events::bus::EventBus,
+        services::traits::{CrudService, EventBusService},
+        storage::{filter::StorableFilter, generic::GenericPostgresStorage},
+        types::metadata::HasId,
+    },
+    tags::entity_tags::EntityTagService,
+};
+use anyhow::Error;
+use std::{collections::HashMap, sync::Arc};
+use uuid::Uuid;
+
+pub struct CustomServiceDefinitionService {
+    storage: Arc<GenericPostgresStorage<CustomServiceDefinition>>,
+    event_bus: Arc<EventBus>,
+}
+
+impl EventBusService<CustomServiceDefinition> for CustomServiceDefinitionService {
+    fn event_bus(&self) -> &Arc<EventBus> {
+        &self.event_bus
+    }
+
+    fn get_network_id(&self, _entity: &CustomServiceDefinition) -> Option<Uuid> {
+        None
+    }
+
+    fn get_organization_id(&self, entity: &CustomServiceDefinition) -> Option<Uuid> {
+        Some(entity.base.organization_id)
+    }
+}
+
+impl CrudService<CustomServiceDefinition> for CustomServiceDefinitionService {
+    fn storage(&self) -> &Arc<GenericPostgresStorage<CustomServiceDefinition>> {
+        &self.storage
+    }
+
+    fn entity_tag_service(&self) -> Option<&Arc<EntityTagService>> {
+        None
+    }
+}
+
+impl CustomServiceDefinitionService {
+    pub fn new(
+        storage: Arc<GenericPostgresStorage<CustomServiceDefinition>>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
+        Self { storage, event_bus }
+    }
+
+    /// Definitions shipped to the daemon with a network discovery request
+    pub async fn build_definitions_for_discovery(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<CustomServiceDefinition>, Error> {
+        let filter = StorableFilter::<CustomServiceDefinition>::new_from_org_id(&organization_id);
+        self.get_all(filter).await
+    }
+
+    /// Replace id-only references to the organization's custom definitions on stored
+    /// services with the full definitions, so their name and category are known.
+    pub async fn resolve_service_definitions(
+        &self,
+        organization_id: Uuid,
+        services: &mut [Service],
+    ) -> Result<(), Error> {
+        let references_custom = services.iter().any(|s| {
+            s.base
+                .service_definition
+                .id()
+                .starts_with(CUSTOM_DEFINITION_ID_PREFIX)
+        });
+        if !references_custom {
+            return Ok(());
+        }
+
+        let definitions: HashMap<String, Box<dyn ServiceDefinition>> = self
+            .build_definitions_for_discovery(organization_id)
+            .await?
+            .iter()
+            .map(|d| (custom_definition_id(&d.id), d.into()))
+            .collect();
+
+        for service in services {
+            if let Some(definition) = definitions.get(service.base.service_definition.id()) {
+                service.base.service_definition = definition.clone();
+            }
+        }
+        Ok(())
+    }
+}
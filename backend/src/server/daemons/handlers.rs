@@ -23,7 +23,7 @@ use crate::server::{
     daemons::r#impl::{
         api::{
             DaemonCapabilities, DaemonRegistrationRequest, DaemonRegistrationResponse,
-            DaemonResponse, DaemonStartupRequest, DiscoveryUpdatePayload, ServerCapabilities,
+            DaemonResponse, DaemonStartupRequest, DaemonWorkPayload, ServerCapabilities,
         },
         base::{Daemon, DaemonBase, DaemonMode},
         version::DaemonVersionPolicy,
@@ -438,7 +438,7 @@ async fn receive_work_request(
     auth: Authorized<IsDaemon>,
     Path(daemon_id): Path<Uuid>,
     Json(request): Json<DaemonStatusPayload>,
-) -> ApiResult<Json<ApiResponse<(Option<DiscoveryUpdatePayload>, bool)>>> {
+) -> ApiResult<Json<ApiResponse<(Option<DaemonWorkPayload>, bool)>>> {
     let daemon_network_id = auth.network_ids()[0];
 
     // Validate daemon exists and belongs to the authenticated daemon's network
@@ -500,7 +500,10 @@ async fn receive_work_request(
         );
     }
 
-    Ok(Json(ApiResponse::success((next_session, has_cancellation))))
+    Ok(Json(ApiResponse::success((
+        next_session.map(DaemonWorkPayload),
+        has_cancellation,
+    ))))
 }
 
 /// Receive daemon heartbeat (DEPRECATED - for backwards compatibility with pre-v0.14.0 daemons)
@@ -636,6 +639,14 @@ async fn provision_daemon(
         management_url: None,
         chassis_id: None,
         snmp_credential_id: None,
+        hardware_inventory: Vec::new(),
+        first_seen: None,
+        last_seen: None,
+        missed_scans: 0,
+        offline: false,
+        auto_hidden: false,
+        os: None,
+        hostname_source: None,
     });
 
     let created_host = state
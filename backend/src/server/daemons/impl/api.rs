@@ -9,12 +9,15 @@ use crate::{
             base::{Daemon, DaemonBase, DaemonMode},
             version::{DaemonVersionStatus, DeprecationSeverity, DeprecationWarning},
         },
-        discovery::r#impl::types::DiscoveryType,
+        discovery::r#impl::{
+            exclusions::ExcludedTargetSummary,
+            types::{DiscoveryType, serialize_with_secrets},
+        },
     },
 };
 use chrono::{DateTime, Utc};
 use semver::Version;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -73,6 +76,7 @@ pub struct DaemonRegistrationResponse {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DaemonDiscoveryRequest {
     pub session_id: Uuid,
+    #[serde(serialize_with = "serialize_with_secrets")]
     pub discovery_type: DiscoveryType,
 }
 
@@ -103,6 +107,10 @@ pub struct DiscoveryUpdatePayload {
     pub error: Option<String>,
     pub started_at: Option<DateTime<Utc>>,
     pub finished_at: Option<DateTime<Utc>>,
+    /// Targets skipped so far because a scan exclusion covered them, grouped by exclusion
+    #[serde(default)]
+    #[schema(required)]
+    pub excluded_targets: Vec<ExcludedTargetSummary>,
 }
 
 impl DiscoveryUpdatePayload {
@@ -122,6 +130,7 @@ impl DiscoveryUpdatePayload {
             error: None,
             started_at: None,
             finished_at: None,
+            excluded_targets: Vec::new(),
         }
     }
 
@@ -129,6 +138,7 @@ impl DiscoveryUpdatePayload {
         discovery_type: DiscoveryType,
         info: DiscoverySessionInfo,
         update: DiscoverySessionUpdate,
+        excluded_targets: Vec<ExcludedTargetSummary>,
     ) -> Self {
         Self {
             session_id: info.session_id,
@@ -140,10 +150,33 @@ impl DiscoveryUpdatePayload {
             error: update.error,
             started_at: info.started_at,
             finished_at: update.finished_at,
+            excluded_targets,
         }
     }
 }
 
+/// Pending session handed to a DaemonPoll daemon when it requests work. Serializes as the
+/// wrapped `DiscoveryUpdatePayload`, but with the discovery's secrets the daemon needs to run it.
+#[derive(Debug, Clone)]
+pub struct DaemonWorkPayload(pub DiscoveryUpdatePayload);
+
+impl Serialize for DaemonWorkPayload {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::Error;
+
+        let mut value = serde_json::to_value(&self.0).map_err(S::Error::custom)?;
+        value["discovery_type"] = self
+            .0
+            .discovery_type
+            .to_value_with_secrets()
+            .map_err(S::Error::custom)?;
+        value.serialize(serializer)
+    }
+}
+
 /// Legacy heartbeat payload for backwards compatibility with pre-v0.14.0 daemons.
 /// Old daemons call POST /api/daemons/{id}/heartbeat with this payload.
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -33,7 +33,10 @@ use crate::server::daemons::r#impl::api::{
 use crate::server::daemons::r#impl::base::{Daemon, DaemonBase, DaemonMode};
 use crate::server::daemons::r#impl::version::DaemonVersionPolicy;
 use crate::server::discovery::r#impl::base::{Discovery, DiscoveryBase};
-use crate::server::discovery::r#impl::types::{DiscoveryType, HostNamingFallback, RunType};
+use crate::server::discovery::r#impl::scan_profile::ScanProfile;
+use crate::server::discovery::r#impl::types::{
+    DiscoveryType, HostNamingFallback, HostnameSource, RunType,
+};
 use crate::server::discovery::service::DiscoveryService;
 use crate::server::hosts::r#impl::base::{Host, HostBase};
 use crate::server::hosts::service::HostService;
@@ -617,6 +620,14 @@ impl DaemonService {
             management_url: None,
             chassis_id: None,
             snmp_credential_id: None,
+            hardware_inventory: Vec::new(),
+            first_seen: None,
+            last_seen: None,
+            missed_scans: 0,
+            offline: false,
+            auto_hidden: false,
+            os: None,
+            hostname_source: None,
         });
 
         let host_response = host_service
@@ -626,6 +637,7 @@ impl DaemonService {
                 vec![],
                 vec![],
                 vec![],
+                vec![],
                 auth.clone(),
                 None,
             )
@@ -754,6 +766,7 @@ impl DaemonService {
                     host_request.ports,
                     host_request.services,
                     host_request.if_entries,
+                    host_request.certificates,
                     auth.clone(),
                     host_limit,
                 )
@@ -959,7 +972,11 @@ impl DaemonService {
             subnet_ids: None,
             host_naming_fallback: HostNamingFallback::BestService,
             snmp_credentials: SnmpCredentialMapping::default(),
+            custom_service_definitions: Vec::new(),
             probe_raw_socket_ports: false,
+            scan_profile: ScanProfile::default(),
+            exclusions: Vec::new(),
+            hostname_sources: HostnameSource::default_order(),
         };
 
         let network_discovery = self
@@ -1327,17 +1344,6 @@ impl DaemonService {
             Ok(poll_response) => {
                 let auth = AuthenticatedEntity::System;
 
-                // Process progress update if available
-                if let Some(progress) = poll_response.progress
-                    && let Err(e) = self.process_discovery_progress(progress).await
-                {
-                    tracing::warn!(
-                        daemon_id = %daemon.id,
-                        error = ?e,
-                        "Failed to process discovery progress"
-                    );
-                }
-
                 // Process entities if any
                 if !poll_response.entities.is_empty() {
                     match self
@@ -1366,6 +1372,18 @@ impl DaemonService {
                         }
                     }
                 }
+
+                // Process progress after entities: a terminal update ends the session, and
+                // entities from the final poll must still be attributed to it
+                if let Some(progress) = poll_response.progress
+                    && let Err(e) = self.process_discovery_progress(progress).await
+                {
+                    tracing::warn!(
+                        daemon_id = %daemon.id,
+                        error = ?e,
+                        "Failed to process discovery progress"
+                    );
+                }
             }
             Err(e) => {
                 tracing::debug!(
@@ -4,6 +4,7 @@ use crate::server::{
     daemons::r#impl::api::DiscoveryUpdatePayload,
     discovery::r#impl::{
         base::Discovery,
+        scan_profile::ScanProfile,
         types::{DiscoveryType, RunType},
     },
     networks::r#impl::Network,
@@ -78,7 +79,7 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
 pub async fn create_discovery(
     State(state): State<Arc<AppState>>,
     auth: Authorized<Member>,
-    Json(discovery): Json<Discovery>,
+    Json(mut discovery): Json<Discovery>,
 ) -> ApiResult<Json<ApiResponse<Discovery>>> {
     if let RunType::Historical { .. } = discovery.base.run_type {
         return Err(ApiError::discovery_historical_read_only());
@@ -106,7 +107,7 @@ pub async fn create_discovery(
     // Custom validation: Check if any subnets aren't on the same network as the discovery
     #[allow(clippy::single_match)]
     match &discovery.base.discovery_type {
-        DiscoveryType::Network { subnet_ids, .. } => {
+        DiscoveryType::Network { subnet_ids, .. } | DiscoveryType::Passive { subnet_ids, .. } => {
             for subnet_id in subnet_ids.as_ref().unwrap_or(&vec![]) {
                 if let Some(subnet) = state.services.subnet_service.get_by_id(subnet_id).await?
                     && subnet.base.network_id != discovery.base.network_id
@@ -117,9 +118,14 @@ pub async fn create_discovery(
                 }
             }
         }
-        DiscoveryType::Docker { .. } | DiscoveryType::SelfReport { .. } => (),
+        DiscoveryType::Docker { .. }
+        | DiscoveryType::Kubernetes { .. }
+        | DiscoveryType::Proxmox { .. }
+        | DiscoveryType::SelfReport { .. } => (),
     }
 
+    check_scan_profile_reference(&state, auth.organization_id(), &mut discovery).await?;
+
     // Delegate to generic handler (handles validation, auth checks, creation)
     create_handler::<Discovery>(State(state), auth, Json(discovery)).await
 }
@@ -142,7 +148,7 @@ pub async fn update_discovery(
     state: State<Arc<AppState>>,
     auth: Authorized<Member>,
     id: Path<Uuid>,
-    discovery: Json<Discovery>,
+    Json(mut discovery): Json<Discovery>,
 ) -> ApiResult<Json<ApiResponse<Discovery>>> {
     if let RunType::Historical { .. } = discovery.base.run_type {
         return Err(ApiError::discovery_historical_read_only());
@@ -167,7 +173,42 @@ pub async fn update_discovery(
         ));
     }
 
-    update_handler::<Discovery>(state, auth, id, discovery).await
+    check_scan_profile_reference(&state, auth.organization_id(), &mut discovery).await?;
+
+    update_handler::<Discovery>(state, auth, id, Json(discovery)).await
+}
+
+/// Check that a custom scan profile belongs to the organization, and keep only the reference
+/// so the profile's current settings are used whenever the discovery runs
+async fn check_scan_profile_reference(
+    state: &AppState,
+    organization_id: Option<Uuid>,
+    discovery: &mut Discovery,
+) -> Result<(), ApiError> {
+    let DiscoveryType::Network { scan_profile, .. } = &mut discovery.base.discovery_type else {
+        return Ok(());
+    };
+
+    if let ScanProfile::Custom { profile_id, .. } = scan_profile {
+        let organization_id =
+            organization_id.ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+        if state
+            .services
+            .custom_scan_profile_service
+            .get_for_organization(organization_id, profile_id)
+            .await?
+            .is_none()
+        {
+            return Err(ApiError::bad_request(&format!(
+                "Scan profile {} not found",
+                profile_id
+            )));
+        }
+    }
+
+    *scan_profile = scan_profile.without_settings();
+    Ok(())
 }
 
 /// Receive discovery progress update from daemon
@@ -0,0 +1,304 @@
+use cidr::IpCidr;
+use mac_address::MacAddress;
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use utoipa::ToSchema;
+
+/// A target that network discovery must never probe.
+///
+/// IP-based exclusions are enforced before ARP and port scanning. A MAC OUI is only known
+/// once a host has answered ARP (or appears in a gateway's ARP cache), so OUI exclusions
+/// stop port scanning and service probing but can't keep a host out of the ARP sweep.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+#[serde(tag = "type")]
+pub enum ScanExclusion {
+    /// A single address
+    #[schema(title = "Ip")]
+    Ip {
+        #[schema(value_type = String)]
+        ip: IpAddr,
+    },
+    /// An inclusive address range
+    #[schema(title = "Range")]
+    Range {
+        #[schema(value_type = String)]
+        start: IpAddr,
+        #[schema(value_type = String)]
+        end: IpAddr,
+    },
+    /// Every address in a CIDR block
+    #[schema(title = "Cidr")]
+    Cidr {
+        #[schema(value_type = String)]
+        cidr: IpCidr,
+    },
+    /// Every device whose MAC address starts with this vendor prefix (e.g. "00:1A:2B")
+    #[schema(title = "MacOui")]
+    MacOui { oui: String },
+}
+
+impl ScanExclusion {
+    pub fn matches_ip(&self, ip: &IpAddr) -> bool {
+        match self {
+            ScanExclusion::Ip { ip: excluded } => excluded == ip,
+            ScanExclusion::Range { start, end } => {
+                start.is_ipv4() == ip.is_ipv4() && start <= ip && ip <= end
+            }
+            ScanExclusion::Cidr { cidr } => cidr.contains(ip),
+            ScanExclusion::MacOui { .. } => false,
+        }
+    }
+
+    pub fn matches_mac(&self, mac: &MacAddress) -> bool {
+        match self {
+            ScanExclusion::MacOui { oui } => {
+                parse_oui(oui).is_some_and(|prefix| mac.bytes()[..3] == prefix)
+            }
+            _ => false,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            ScanExclusion::Range { start, end } => {
+                if start.is_ipv4() != end.is_ipv4() {
+                    return Err(format!(
+                        "Invalid exclusion range {}-{}: start and end must be the same IP version",
+                        start, end
+                    ));
+                }
+                if start > end {
+                    return Err(format!(
+                        "Invalid exclusion range {}-{}: start must not exceed end",
+                        start, end
+                    ));
+                }
+                Ok(())
+            }
+            ScanExclusion::MacOui { oui } => match parse_oui(oui) {
+                Some(_) => Ok(()),
+                None => Err(format!(
+                    "Invalid MAC OUI '{}': expected three hex octets, e.g. 00:1A:2B",
+                    oui
+                )),
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Find the first exclusion covering a host, checking its IP and then its MAC if known.
+    pub fn find_match<'a>(
+        exclusions: &'a [ScanExclusion],
+        ip: &IpAddr,
+        mac: Option<&MacAddress>,
+    ) -> Option<&'a ScanExclusion> {
+        exclusions
+            .iter()
+            .find(|e| e.matches_ip(ip))
+            .or_else(|| mac.and_then(|mac| exclusions.iter().find(|e| e.matches_mac(mac))))
+    }
+
+    pub fn validate_all(exclusions: &[ScanExclusion]) -> Result<(), String> {
+        exclusions.iter().try_for_each(ScanExclusion::validate)
+    }
+}
+
+/// Parse "00:1A:2B", "00-1A-2B" or "001A2B" into the three OUI bytes.
+fn parse_oui(oui: &str) -> Option<[u8; 3]> {
+    let hex: String = oui
+        .chars()
+        .filter(|c| !matches!(c, ':' | '-' | '.'))
+        .collect();
+    if hex.len() != 6 {
+        return None;
+    }
+
+    let mut bytes = [0u8; 3];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(hex.get(i * 2..i * 2 + 2)?, 16).ok()?;
+    }
+    Some(bytes)
+}
+
+/// How many skipped hosts per exclusion progress updates carry. The final update lists them all.
+pub const EXCLUDED_TARGET_SAMPLES: usize = 5;
+
+/// A host that discovery skipped because an exclusion covered it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+pub struct ExcludedTarget {
+    #[schema(value_type = String)]
+    pub ip: IpAddr,
+    /// Known when the host was skipped after ARP because of a MAC OUI exclusion
+    #[schema(value_type = Option<String>)]
+    pub mac_address: Option<MacAddress>,
+}
+
+/// Hosts skipped by a single exclusion during a session
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+pub struct ExcludedTargetSummary {
+    pub exclusion: ScanExclusion,
+    /// Total number of hosts this exclusion caused discovery to skip
+    pub count: u32,
+    /// Skipped hosts. Progress updates carry only the first few so they stay small; the
+    /// final update, which is kept on the historical run, lists every one.
+    pub targets: Vec<ExcludedTarget>,
+}
+
+impl ExcludedTargetSummary {
+    /// Record `target` against the summary for `exclusion`, adding one if needed.
+    pub fn record(summaries: &mut Vec<Self>, exclusion: &ScanExclusion, target: ExcludedTarget) {
+        let summary = match summaries.iter().position(|s| &s.exclusion == exclusion) {
+            Some(i) => &mut summaries[i],
+            None => {
+                summaries.push(Self {
+                    exclusion: exclusion.clone(),
+                    count: 0,
+                    targets: Vec::new(),
+                });
+                summaries.last_mut().expect("just pushed")
+            }
+        };
+
+        summary.count = summary.count.saturating_add(1);
+        summary.targets.push(target);
+    }
+
+    /// Copy carrying only the first few targets, for progress updates
+    pub fn sampled(&self) -> Self {
+        Self {
+            exclusion: self.exclusion.clone(),
+            count: self.count,
+            targets: self
+                .targets
+                .iter()
+                .take(EXCLUDED_TARGET_SAMPLES)
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    #[test]
+    fn test_exclusions_match_ips_and_ouis() {
+        let exclusions = vec![
+            ScanExclusion::Ip {
+                ip: "192.168.1.5".parse().unwrap(),
+            },
+            ScanExclusion::Range {
+                start: "192.168.1.100".parse().unwrap(),
+                end: "192.168.1.110".parse().unwrap(),
+            },
+            ScanExclusion::Cidr {
+                cidr: "10.0.0.0/30".parse().unwrap(),
+            },
+            ScanExclusion::MacOui {
+                oui: "00-1a-2b".to_string(),
+            },
+        ];
+
+        let ip = |s: &str| -> IpAddr { s.parse().unwrap() };
+        let plc = MacAddress::new([0x00, 0x1A, 0x2B, 0x01, 0x02, 0x03]);
+        let other = MacAddress::new([0x00, 0x1A, 0x2C, 0x01, 0x02, 0x03]);
+
+        assert_eq!(
+            ScanExclusion::find_match(&exclusions, &ip("192.168.1.5"), None),
+            Some(&exclusions[0])
+        );
+        assert_eq!(
+            ScanExclusion::find_match(&exclusions, &ip("192.168.1.105"), None),
+            Some(&exclusions[1])
+        );
+        assert_eq!(
+            ScanExclusion::find_match(&exclusions, &ip("10.0.0.3"), None),
+            Some(&exclusions[2])
+        );
+        assert_eq!(
+            ScanExclusion::find_match(&exclusions, &ip("192.168.1.20"), Some(&plc)),
+            Some(&exclusions[3])
+        );
+        assert!(
+            ScanExclusion::find_match(&exclusions, &ip("192.168.1.20"), Some(&other)).is_none()
+        );
+        assert!(ScanExclusion::find_match(&exclusions, &ip("192.168.1.111"), None).is_none());
+        assert!(!exclusions[1].matches_ip(&IpAddr::V6(
+            Ipv4Addr::new(192, 168, 1, 105).to_ipv6_mapped()
+        )));
+    }
+
+    #[test]
+    fn test_exclusion_validation() {
+        assert!(
+            ScanExclusion::MacOui {
+                oui: "00:1A:2B".to_string()
+            }
+            .validate()
+            .is_ok()
+        );
+        assert!(
+            ScanExclusion::MacOui {
+                oui: "00:1A".to_string()
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            ScanExclusion::Range {
+                start: "10.0.0.9".parse().unwrap(),
+                end: "10.0.0.1".parse().unwrap(),
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            ScanExclusion::Range {
+                start: "10.0.0.1".parse().unwrap(),
+                end: "::1".parse().unwrap(),
+            }
+            .validate()
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_excluded_targets_aggregate_per_exclusion() {
+        let range = ScanExclusion::Range {
+            start: "192.168.1.0".parse().unwrap(),
+            end: "192.168.1.255".parse().unwrap(),
+        };
+        let single = ScanExclusion::Ip {
+            ip: "10.0.0.1".parse().unwrap(),
+        };
+        let target = |ip: &str| ExcludedTarget {
+            ip: ip.parse().unwrap(),
+            mac_address: None,
+        };
+
+        let mut summaries = Vec::new();
+        for i in 0..20 {
+            ExcludedTargetSummary::record(
+                &mut summaries,
+                &range,
+                target(&format!("192.168.1.{i}")),
+            );
+        }
+        ExcludedTargetSummary::record(&mut summaries, &single, target("10.0.0.1"));
+
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].exclusion, range);
+        assert_eq!(summaries[0].count, 20);
+        assert_eq!(summaries[0].targets.len(), 20);
+        assert_eq!(summaries[1].count, 1);
+        assert_eq!(summaries[1].targets, vec![target("10.0.0.1")]);
+
+        // Progress updates only carry a few, but keep the full count
+        let sampled = summaries[0].sampled();
+        assert_eq!(sampled.count, 20);
+        assert_eq!(sampled.targets.len(), EXCLUDED_TARGET_SAMPLES);
+        assert_eq!(sampled.targets[0], target("192.168.1.0"));
+    }
+}
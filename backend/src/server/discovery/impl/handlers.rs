@@ -1,6 +1,13 @@
 use crate::server::{
     config::AppState,
-    discovery::{r#impl::base::Discovery, service::DiscoveryService},
+    discovery::{
+        r#impl::{
+            base::Discovery,
+            exclusions::ScanExclusion,
+            types::{DiscoveryType, PASSIVE_LISTEN_DURATION_SECS, ProxmoxConnection},
+        },
+        service::DiscoveryService,
+    },
     shared::handlers::{query::DiscoveryQuery, traits::CrudHandlers},
 };
 
@@ -11,4 +18,37 @@ impl CrudHandlers for Discovery {
     fn get_service(state: &AppState) -> &Self::Service {
         &state.services.discovery_service
     }
+
+    fn validate(&self) -> Result<(), String> {
+        validator::Validate::validate(self).map_err(|e| e.to_string())?;
+        match &self.base.discovery_type {
+            DiscoveryType::Network { exclusions, .. } => {
+                ScanExclusion::validate_all(exclusions)?;
+            }
+            DiscoveryType::Passive {
+                listen_duration_secs,
+                ..
+            } => {
+                if !PASSIVE_LISTEN_DURATION_SECS.contains(listen_duration_secs) {
+                    return Err(format!(
+                        "Listen duration must be between {} and {} seconds",
+                        PASSIVE_LISTEN_DURATION_SECS.start(),
+                        PASSIVE_LISTEN_DURATION_SECS.end()
+                    ));
+                }
+            }
+            DiscoveryType::Proxmox(ProxmoxConnection { url, token_id, .. }) => {
+                if !(url.starts_with("https://") || url.starts_with("http://")) {
+                    return Err("Proxmox URL must use http or https".to_string());
+                }
+                if !token_id.contains('!') {
+                    return Err("Proxmox token ID must be in user@realm!token form".to_string());
+                }
+            }
+            DiscoveryType::SelfReport { .. }
+            | DiscoveryType::Docker { .. }
+            | DiscoveryType::Kubernetes { .. } => (),
+        }
+        Ok(())
+    }
 }
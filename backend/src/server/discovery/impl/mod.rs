@@ -1,4 +1,6 @@
 pub mod base;
+pub mod exclusions;
 pub mod handlers;
+pub mod scan_profile;
 pub mod storage;
 pub mod types;
@@ -0,0 +1,263 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use strum::Display;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// How thoroughly network discovery scans each host.
+///
+/// The presets trade coverage for speed: `Quick` only checks ports used by known services,
+/// `Standard` adds the well-known range, and `Full` sweeps every TCP port. `Custom` uses an
+/// organization's saved scan profile, so several discoveries can share the same settings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default, Display, ToSchema)]
+#[serde(tag = "type")]
+pub enum ScanProfile {
+    /// Known service ports only, no endpoint probing, 1 minute per host
+    #[schema(title = "Quick")]
+    Quick,
+    /// Known service ports plus 1-1024, with endpoint probing, 5 minutes per host
+    #[schema(title = "Standard")]
+    Standard,
+    /// Every TCP port with endpoint probing and no per-host timeout
+    #[default]
+    #[schema(title = "Full")]
+    Full,
+    #[schema(title = "Custom")]
+    Custom {
+        /// ID of the organization's custom scan profile
+        #[serde(default)]
+        #[schema(required)]
+        profile_id: Uuid,
+        /// Settings of the referenced profile
+        /// Server fills this in before initiating discovery
+        #[serde(default)]
+        #[schema(read_only)]
+        settings: Option<Box<ScanProfileSettings>>,
+    },
+}
+
+impl ScanProfile {
+    /// Resolve the profile to concrete settings. A custom profile whose settings weren't
+    /// filled in by the server falls back to the default profile.
+    pub fn settings(&self) -> ScanProfileSettings {
+        match self {
+            ScanProfile::Quick => ScanProfileSettings {
+                tcp_ports: PortSelection::known(),
+                udp_ports: PortSelection::known(),
+                probe_endpoints: false,
+                host_timeout_secs: Some(60),
+                scan_rate_pps: None,
+                port_scan_batch_size: None,
+            },
+            ScanProfile::Standard => ScanProfileSettings {
+                tcp_ports: PortSelection {
+                    known: true,
+                    ranges: vec![PortRange {
+                        start: 1,
+                        end: 1024,
+                    }],
+                },
+                udp_ports: PortSelection::known(),
+                probe_endpoints: true,
+                host_timeout_secs: Some(300),
+                scan_rate_pps: None,
+                port_scan_batch_size: None,
+            },
+            ScanProfile::Full => ScanProfileSettings {
+                tcp_ports: PortSelection {
+                    known: true,
+                    ranges: vec![PortRange::ALL],
+                },
+                udp_ports: PortSelection::known(),
+                probe_endpoints: true,
+                host_timeout_secs: None,
+                scan_rate_pps: None,
+                port_scan_batch_size: None,
+            },
+            ScanProfile::Custom {
+                settings: Some(settings),
+                ..
+            } => (**settings).clone(),
+            ScanProfile::Custom { settings: None, .. } => ScanProfile::default().settings(),
+        }
+    }
+
+    /// Copy that keeps only the reference to a custom profile, without its settings.
+    pub fn without_settings(&self) -> Self {
+        match self {
+            ScanProfile::Custom { profile_id, .. } => ScanProfile::Custom {
+                profile_id: *profile_id,
+                settings: None,
+            },
+            other => other.clone(),
+        }
+    }
+}
+
+/// Concrete scan settings a profile resolves to.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+pub struct ScanProfileSettings {
+    /// TCP ports to scan
+    pub tcp_ports: PortSelection,
+    /// UDP ports to scan. Only ports with a protocol-specific probe (DNS, NTP, SNMP, DHCP,
+    /// BACnet) can be detected; others in the selection are skipped.
+    pub udp_ports: PortSelection,
+    /// Probe HTTP endpoints and headers to identify services
+    pub probe_endpoints: bool,
+    /// Abandon a host if scanning it takes longer than this many seconds
+    #[serde(default)]
+    pub host_timeout_secs: Option<u64>,
+    /// Override the daemon's port scan rate (probes per second)
+    #[serde(default)]
+    pub scan_rate_pps: Option<u32>,
+    /// Override the daemon's port scan batch size (ports scanned concurrently per host)
+    #[serde(default)]
+    pub port_scan_batch_size: Option<usize>,
+}
+
+impl ScanProfileSettings {
+    pub fn validate(&self) -> Result<(), String> {
+        self.tcp_ports.validate("TCP")?;
+        self.udp_ports.validate("UDP")?;
+
+        if self.host_timeout_secs == Some(0) {
+            return Err("Host timeout must be greater than 0 seconds".to_string());
+        }
+        if self.scan_rate_pps == Some(0) {
+            return Err("Scan rate must be greater than 0 probes per second".to_string());
+        }
+        if self.port_scan_batch_size == Some(0) {
+            return Err("Port scan batch size must be greater than 0".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// A set of ports: those used by known service definitions and/or explicit ranges.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default, ToSchema)]
+pub struct PortSelection {
+    /// Include the ports used by built-in and custom service definitions
+    #[serde(default)]
+    pub known: bool,
+    /// Additional ports, as inclusive ranges (use the same start and end for a single port)
+    #[serde(default)]
+    pub ranges: Vec<PortRange>,
+}
+
+impl PortSelection {
+    pub fn known() -> Self {
+        Self {
+            known: true,
+            ranges: Vec::new(),
+        }
+    }
+
+    /// Resolve to a sorted, deduplicated list of port numbers. `known_ports` are the ports
+    /// used by service definitions for the relevant protocol.
+    pub fn resolve(&self, known_ports: impl IntoIterator<Item = u16>) -> Vec<u16> {
+        let mut ports: BTreeSet<u16> = self.ranges.iter().flat_map(|r| r.start..=r.end).collect();
+
+        if self.known {
+            ports.extend(known_ports);
+        }
+
+        ports.into_iter().collect()
+    }
+
+    fn validate(&self, protocol: &str) -> Result<(), String> {
+        for range in &self.ranges {
+            if range.start == 0 || range.start > range.end {
+                return Err(format!(
+                    "Invalid {} port range {}-{}: ports must be between 1 and 65535 and start must not exceed end",
+                    protocol, range.start, range.end
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Inclusive range of port numbers.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+pub struct PortRange {
+    pub start: u16,
+    pub end: u16,
+}
+
+impl PortRange {
+    pub const ALL: PortRange = PortRange {
+        start: 1,
+        end: 65535,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_port_selection_resolves_ranges_and_known_ports() {
+        let selection = PortSelection {
+            known: true,
+            ranges: vec![
+                PortRange { start: 20, end: 23 },
+                PortRange { start: 22, end: 22 },
+            ],
+        };
+
+        assert_eq!(
+            selection.resolve([443, 22, 80]),
+            vec![20, 21, 22, 23, 80, 443]
+        );
+        assert!(PortSelection::default().resolve([80, 443]).is_empty());
+    }
+
+    #[test]
+    fn test_full_profile_scans_every_tcp_port() {
+        let settings = ScanProfile::Full.settings();
+        assert_eq!(settings.tcp_ports.resolve([]).len(), 65535);
+        assert!(settings.probe_endpoints);
+        assert_eq!(settings.host_timeout_secs, None);
+    }
+
+    #[test]
+    fn test_custom_profile_settings() {
+        let settings = ScanProfile::Quick.settings();
+        let profile_id = Uuid::new_v4();
+        let profile = ScanProfile::Custom {
+            profile_id,
+            settings: Some(Box::new(settings.clone())),
+        };
+        assert_eq!(profile.settings(), settings);
+
+        let reference = profile.without_settings();
+        assert_eq!(
+            reference,
+            ScanProfile::Custom {
+                profile_id,
+                settings: None,
+            }
+        );
+        assert_eq!(reference.settings(), ScanProfile::default().settings());
+    }
+
+    #[test]
+    fn test_scan_profile_settings_validation() {
+        let mut settings = ScanProfile::Quick.settings();
+        assert!(settings.validate().is_ok());
+
+        settings.tcp_ports.ranges = vec![PortRange {
+            start: 1000,
+            end: 10,
+        }];
+        assert!(settings.validate().is_err());
+
+        settings.tcp_ports.ranges = vec![PortRange { start: 0, end: 10 }];
+        assert!(settings.validate().is_err());
+
+        settings.tcp_ports.ranges.clear();
+        settings.host_timeout_secs = Some(0);
+        assert!(settings.validate().is_err());
+    }
+}
@@ -108,9 +108,10 @@ impl Storable for Discovery {
     }
 
     fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
-        let discovery_type: DiscoveryType =
+        let mut discovery_type: DiscoveryType =
             serde_json::from_value(row.get::<serde_json::Value, _>("discovery_type"))
                 .map_err(|e| anyhow::anyhow!("Failed to deserialize discovery_type: {}", e))?;
+        discovery_type.decrypt_stored_secrets()?;
 
         let run_type: RunType = serde_json::from_value(row.get::<serde_json::Value, _>("run_type"))
             .map_err(|e| anyhow::anyhow!("Failed to deserialize run_type: {}", e))?;
@@ -175,6 +176,13 @@ impl Entity for Discovery {
         self.updated_at = time;
     }
 
+    fn preserve_immutable_fields(&mut self, existing: &Self) {
+        // Secrets are redacted in responses, so clients send the placeholder back unchanged
+        self.base
+            .discovery_type
+            .keep_redacted_secrets(&existing.base.discovery_type);
+    }
+
     fn get_tags(&self) -> Option<&Vec<Uuid>> {
         Some(&self.base.tags)
     }
@@ -1,12 +1,20 @@
 use chrono::{DateTime, Utc};
+use secrecy::{ExposeSecret, SecretString};
 use serde::Deserialize;
 use serde::Serialize;
+use serde::Serializer;
 use std::fmt::Display;
-use strum::{Display, EnumDiscriminants, EnumIter, IntoStaticStr};
+use std::hash::{Hash, Hasher};
+use strum::{Display, EnumDiscriminants, EnumIter, EnumString, IntoStaticStr};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::server::custom_service_definitions::r#impl::base::CustomServiceDefinition;
+use crate::server::discovery::r#impl::exclusions::ScanExclusion;
+use crate::server::discovery::r#impl::scan_profile::ScanProfile;
 use crate::server::shared::entities::EntityDiscriminants;
+use crate::server::shared::storage::secrets::{decrypt_secret, encrypt_secret};
+use crate::server::snmp_credentials::r#impl::base::{REDACTED_SECRET, redact_secret};
 use crate::server::snmp_credentials::r#impl::discovery::SnmpCredentialMapping;
 use crate::server::{
     daemons::r#impl::api::DiscoveryUpdatePayload,
@@ -47,10 +55,40 @@ pub enum DiscoveryType {
         /// Server builds this mapping before initiating discovery
         #[serde(default)]
         snmp_credentials: SnmpCredentialMapping,
+        /// Organization's custom service definitions, matched alongside the built-in ones
+        /// Server builds this list before initiating discovery
+        #[serde(default)]
+        custom_service_definitions: Vec<CustomServiceDefinition>,
         /// Whether to probe raw-socket ports (9100-9107) during endpoint scanning.
         /// Disabled by default to prevent ghost printing on JetDirect printers.
         #[serde(default)]
         probe_raw_socket_ports: bool,
+        /// Ports, probing depth, and timing used when scanning each host
+        #[serde(default)]
+        #[schema(required)]
+        scan_profile: ScanProfile,
+        /// Addresses and devices this discovery must never probe
+        /// Server adds the network's exclusions before initiating discovery
+        #[serde(default)]
+        #[schema(required)]
+        exclusions: Vec<ScanExclusion>,
+        /// Where to look up each host's hostname, in order. The first source that returns
+        /// a name wins; sources left out are never queried.
+        #[serde(default = "HostnameSource::default_order")]
+        #[schema(required)]
+        hostname_sources: Vec<HostnameSource>,
+    },
+    #[schema(title = "Passive")]
+    Passive {
+        #[schema(required)]
+        subnet_ids: Option<Vec<Uuid>>,
+        #[serde(default)]
+        #[schema(required)]
+        host_naming_fallback: HostNamingFallback,
+        /// How long to listen for traffic before reporting the hosts that were heard
+        #[serde(default = "default_passive_listen_duration_secs")]
+        #[schema(required)]
+        listen_duration_secs: u64,
     },
     #[schema(title = "Docker")]
     Docker {
@@ -60,6 +98,87 @@ pub enum DiscoveryType {
         #[schema(required)]
         host_naming_fallback: HostNamingFallback,
     },
+    #[schema(title = "Kubernetes")]
+    Kubernetes {
+        /// Kubeconfig file on the daemon's host. When unset the daemon uses its in-cluster
+        /// service account, falling back to `$KUBECONFIG` and then `~/.kube/config`.
+        #[serde(default)]
+        #[schema(required)]
+        kubeconfig_path: Option<String>,
+        /// Kubeconfig context to use instead of the file's current context
+        #[serde(default)]
+        #[schema(required)]
+        context: Option<String>,
+        #[serde(default)]
+        #[schema(required)]
+        host_naming_fallback: HostNamingFallback,
+    },
+    #[schema(title = "Proxmox")]
+    Proxmox(ProxmoxConnection),
+}
+
+/// How the daemon reaches the Proxmox VE API
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProxmoxConnection {
+    /// Base URL of the Proxmox VE API, e.g. `https://pve.local:8006`
+    pub url: String,
+    /// API token ID in `user@realm!token` form
+    pub token_id: String,
+    /// API token secret (redacted in API responses)
+    #[serde(serialize_with = "redact_secret")]
+    #[schema(value_type = String)]
+    pub token_secret: SecretString,
+    /// Verify the API's TLS certificate. Off by default since Proxmox ships a self-signed one.
+    #[serde(default)]
+    #[schema(required)]
+    pub verify_tls: bool,
+}
+
+impl Default for ProxmoxConnection {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            token_id: String::new(),
+            token_secret: SecretString::from(String::new()),
+            verify_tls: false,
+        }
+    }
+}
+
+impl PartialEq for ProxmoxConnection {
+    fn eq(&self, other: &Self) -> bool {
+        self.url == other.url
+            && self.token_id == other.token_id
+            && self.token_secret.expose_secret() == other.token_secret.expose_secret()
+            && self.verify_tls == other.verify_tls
+    }
+}
+
+impl Eq for ProxmoxConnection {}
+
+impl Hash for ProxmoxConnection {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.url.hash(state);
+        self.token_id.hash(state);
+        self.token_secret.expose_secret().hash(state);
+        self.verify_tls.hash(state);
+    }
+}
+
+impl ProxmoxConnection {
+    /// Keep the stored secret when an update echoes back the redacted placeholder
+    pub fn keep_redacted_secret(&mut self, existing: &ProxmoxConnection) {
+        if self.token_secret.expose_secret() == REDACTED_SECRET {
+            self.token_secret = existing.token_secret.clone();
+        }
+    }
+}
+
+/// Shortest and longest listening window accepted for passive discovery
+pub const PASSIVE_LISTEN_DURATION_SECS: std::ops::RangeInclusive<u64> = 10..=86_400;
+
+fn default_passive_listen_duration_secs() -> u64 {
+    900
 }
 
 impl Default for DiscoveryType {
@@ -71,8 +190,9 @@ impl Default for DiscoveryType {
 }
 
 impl DiscoveryType {
-    /// Create a sanitized copy with sensitive data (SNMP credentials) redacted.
+    /// Create a sanitized copy with sensitive data (SNMP credentials, API tokens) redacted.
     /// Used when storing EntitySource to prevent credential leakage in API responses.
+    /// Custom service definitions are dropped since they're only needed by the daemon.
     pub fn sanitized(&self) -> Self {
         match self {
             DiscoveryType::Network {
@@ -80,15 +200,77 @@ impl DiscoveryType {
                 host_naming_fallback,
                 snmp_credentials,
                 probe_raw_socket_ports,
+                scan_profile,
+                exclusions,
+                hostname_sources,
+                ..
             } => DiscoveryType::Network {
                 subnet_ids: subnet_ids.clone(),
                 host_naming_fallback: *host_naming_fallback,
                 snmp_credentials: snmp_credentials.sanitized(),
+                custom_service_definitions: Vec::new(),
                 probe_raw_socket_ports: *probe_raw_socket_ports,
+                scan_profile: scan_profile.clone(),
+                exclusions: exclusions.clone(),
+                hostname_sources: hostname_sources.clone(),
             },
+            DiscoveryType::Proxmox(connection) => DiscoveryType::Proxmox(ProxmoxConnection {
+                token_secret: SecretString::from(REDACTED_SECRET),
+                ..connection.clone()
+            }),
             other => other.clone(),
         }
     }
+
+    /// Serialize with secrets intact. Plain serialization redacts them, which is right for
+    /// API responses and events but not for the daemon running the discovery.
+    pub fn to_value_with_secrets(&self) -> serde_json::Result<serde_json::Value> {
+        let mut value = serde_json::to_value(self)?;
+        if let DiscoveryType::Proxmox(connection) = self {
+            value["token_secret"] = connection.token_secret.expose_secret().into();
+        }
+        Ok(value)
+    }
+
+    /// Serialize for the database, with secrets encrypted under the server key
+    pub fn to_stored_value(&self) -> anyhow::Result<serde_json::Value> {
+        let mut value = serde_json::to_value(self)?;
+        if let DiscoveryType::Proxmox(connection) = self {
+            value["token_secret"] = encrypt_secret(&connection.token_secret)?.into();
+        }
+        Ok(value)
+    }
+
+    /// Decrypt secrets read back from the database
+    pub fn decrypt_stored_secrets(&mut self) -> anyhow::Result<()> {
+        if let DiscoveryType::Proxmox(connection) = self {
+            connection.token_secret = decrypt_secret(connection.token_secret.expose_secret())?;
+        }
+        Ok(())
+    }
+
+    /// Keep stored secrets when an update echoes back the redacted placeholders
+    pub fn keep_redacted_secrets(&mut self, existing: &DiscoveryType) {
+        if let (DiscoveryType::Proxmox(connection), DiscoveryType::Proxmox(existing)) =
+            (self, existing)
+        {
+            connection.keep_redacted_secret(existing);
+        }
+    }
+}
+
+/// `serialize_with` helper for payloads that carry a discovery type to the daemon
+pub fn serialize_with_secrets<S>(
+    discovery_type: &DiscoveryType,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    discovery_type
+        .to_value_with_secrets()
+        .map_err(serde::ser::Error::custom)?
+        .serialize(serializer)
 }
 
 impl Display for DiscoveryType {
@@ -96,7 +278,10 @@ impl Display for DiscoveryType {
         match self {
             DiscoveryType::SelfReport { .. } => write!(f, "Self Report"),
             DiscoveryType::Network { .. } => write!(f, "Network Discovery"),
+            DiscoveryType::Passive { .. } => write!(f, "Passive Discovery"),
             DiscoveryType::Docker { .. } => write!(f, "Docker Discovery"),
+            DiscoveryType::Kubernetes { .. } => write!(f, "Kubernetes Discovery"),
+            DiscoveryType::Proxmox { .. } => write!(f, "Proxmox Discovery"),
         }
     }
 }
@@ -110,6 +295,50 @@ pub enum HostNamingFallback {
     BestService,
 }
 
+/// Where network discovery got a host's hostname from
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Eq,
+    PartialEq,
+    Hash,
+    Display,
+    EnumString,
+    EnumIter,
+    ToSchema,
+)]
+pub enum HostnameSource {
+    /// Reverse DNS (PTR record)
+    Dns,
+    /// NetBIOS node status query (UDP 137)
+    NetBios,
+    /// Computer name from an SMB session setup
+    Smb,
+    /// LLMNR reverse lookup (UDP 5355)
+    Llmnr,
+    /// Unicast mDNS reverse lookup (UDP 5353)
+    Mdns,
+    /// SNMP sysName
+    Snmp,
+}
+
+impl HostnameSource {
+    /// Resolution order for discoveries that don't set one
+    pub fn default_order() -> Vec<Self> {
+        vec![
+            Self::Dns,
+            Self::NetBios,
+            Self::Smb,
+            Self::Llmnr,
+            Self::Mdns,
+            Self::Snmp,
+        ]
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
 #[serde(tag = "type")]
 pub enum RunType {
@@ -123,7 +352,9 @@ pub enum RunType {
     },
     #[schema(title = "Historical")]
     /// Historical discovery runs are created by the server and cannot be submitted via API
-    Historical { results: DiscoveryUpdatePayload },
+    Historical {
+        results: Box<DiscoveryUpdatePayload>,
+    },
     #[schema(title = "AdHoc")]
     AdHoc {
         #[serde(default)]
@@ -155,7 +386,7 @@ impl EntityMetadataProvider for DiscoveryType {
 }
 
 impl TypeMetadataProvider for DiscoveryType {
-    fn name(&self) -> &'static str {
+    fn name(&self) -> &str {
         self.id()
     }
     fn description(&self) -> &'static str {
@@ -163,12 +394,82 @@ impl TypeMetadataProvider for DiscoveryType {
             DiscoveryType::Docker { .. } => {
                 "Discover Docker containers and their configurations on the daemon's host"
             }
+            DiscoveryType::Kubernetes { .. } => {
+                "Map a Kubernetes cluster's nodes, pods, Services and Ingresses through its API"
+            }
             DiscoveryType::Network { .. } => {
                 "Scan network subnets to discover hosts, open ports, and running services"
             }
+            DiscoveryType::Passive { .. } => {
+                "Listen for ARP, DHCP, mDNS, SSDP, LLDP and CDP traffic to discover hosts without sending any probes"
+            }
+            DiscoveryType::Proxmox { .. } => {
+                "Map Proxmox VE nodes, virtual machines and containers through the Proxmox API"
+            }
             DiscoveryType::SelfReport { .. } => {
                 "The daemon reports its own host configuration and network details"
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::{
+        daemons::r#impl::api::DaemonDiscoveryRequest,
+        discovery::r#impl::base::{Discovery, DiscoveryBase},
+    };
+
+    const SECRET: &str = "3f1c2a9e-6b7d-4e21-9a0f-proxmox-token";
+
+    fn proxmox(secret: &str) -> DiscoveryType {
+        DiscoveryType::Proxmox(ProxmoxConnection {
+            url: "https://pve.local:8006".to_string(),
+            token_id: "scanopy@pve!discovery".to_string(),
+            token_secret: SecretString::from(secret),
+            verify_tls: false,
+        })
+    }
+
+    #[test]
+    fn test_proxmox_token_secret_redacted_when_serialized() {
+        let discovery = Discovery {
+            base: DiscoveryBase {
+                discovery_type: proxmox(SECRET),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&discovery).unwrap();
+        assert!(!json.contains(SECRET));
+        assert!(json.contains(REDACTED_SECRET));
+
+        // The daemon still gets the real secret
+        let stored = discovery
+            .base
+            .discovery_type
+            .to_value_with_secrets()
+            .unwrap();
+        assert_eq!(stored["token_secret"], SECRET);
+        let request = DaemonDiscoveryRequest {
+            session_id: Uuid::new_v4(),
+            discovery_type: discovery.base.discovery_type.clone(),
+        };
+        let received: DaemonDiscoveryRequest =
+            serde_json::from_str(&serde_json::to_string(&request).unwrap()).unwrap();
+        assert_eq!(received.discovery_type, discovery.base.discovery_type);
+    }
+
+    #[test]
+    fn test_keep_redacted_proxmox_token_secret() {
+        let mut updated = proxmox(REDACTED_SECRET);
+        updated.keep_redacted_secrets(&proxmox(SECRET));
+        assert_eq!(updated, proxmox(SECRET));
+
+        let mut rotated = proxmox("new-secret");
+        rotated.keep_redacted_secrets(&proxmox(SECRET));
+        assert_eq!(rotated, proxmox("new-secret"));
+    }
+}
@@ -1,6 +1,8 @@
 use crate::daemon::discovery::types::base::DiscoveryPhase;
 use crate::daemon::runtime::service::LOG_TARGET;
 use crate::server::auth::middleware::auth::AuthenticatedEntity;
+use crate::server::custom_scan_profiles::service::CustomScanProfileService;
+use crate::server::custom_service_definitions::service::CustomServiceDefinitionService;
 use crate::server::daemons::r#impl::api::DiscoveryUpdatePayload;
 use crate::server::discovery::r#impl::base::Discovery;
 use crate::server::discovery::r#impl::types::{DiscoveryType, RunType};
@@ -38,6 +40,8 @@ pub struct DiscoveryService {
     event_bus: Arc<EventBus>,
     entity_tag_service: Arc<EntityTagService>,
     snmp_credential_service: Arc<SnmpCredentialService>,
+    custom_service_definition_service: Arc<CustomServiceDefinitionService>,
+    custom_scan_profile_service: Arc<CustomScanProfileService>,
     network_service: Arc<NetworkService>,
     organization_service: Arc<OrganizationService>,
 }
@@ -67,11 +71,14 @@ impl CrudService<Discovery> for DiscoveryService {
 }
 
 impl DiscoveryService {
+    #[allow(clippy::too_many_arguments)]
     pub async fn new(
         discovery_storage: Arc<GenericPostgresStorage<Discovery>>,
         event_bus: Arc<EventBus>,
         entity_tag_service: Arc<EntityTagService>,
         snmp_credential_service: Arc<SnmpCredentialService>,
+        custom_service_definition_service: Arc<CustomServiceDefinitionService>,
+        custom_scan_profile_service: Arc<CustomScanProfileService>,
         network_service: Arc<NetworkService>,
         organization_service: Arc<OrganizationService>,
     ) -> Result<Arc<Self>> {
@@ -90,6 +97,8 @@ impl DiscoveryService {
             event_bus,
             entity_tag_service,
             snmp_credential_service,
+            custom_service_definition_service,
+            custom_scan_profile_service,
             network_service,
             organization_service,
         }))
@@ -175,6 +184,22 @@ impl DiscoveryService {
         })
     }
 
+    /// The session the daemon is currently running, if any.
+    /// Daemons run one session at a time, so entities it reports belong to this session.
+    pub async fn active_session_for_daemon(&self, daemon_id: &Uuid) -> Option<Uuid> {
+        let daemon_session_ids = self.daemon_sessions.read().await;
+        let session_ids = daemon_session_ids.get(daemon_id)?;
+
+        let all_sessions = self.sessions.read().await;
+
+        session_ids.iter().copied().find(|session_id| {
+            all_sessions
+                .get(session_id)
+                .map(|s| !s.phase.is_terminal() && s.phase != DiscoveryPhase::Pending)
+                .unwrap_or(false)
+        })
+    }
+
     /// Transition a session from Pending to Starting phase.
     /// Called when the session is dispatched to the daemon.
     pub async fn transition_session_to_starting(&self, session_id: Uuid) {
@@ -560,14 +585,45 @@ impl DiscoveryService {
     ) -> Result<DiscoveryUpdatePayload, anyhow::Error> {
         let session_id = Uuid::new_v4();
 
-        // Hydrate SNMP credentials
+        // Hydrate SNMP credentials, custom service definitions, the custom scan profile's
+        // settings and network exclusions
         let discovery_type = if let DiscoveryType::Network {
             host_naming_fallback,
             subnet_ids,
             probe_raw_socket_ports,
+            scan_profile,
+            mut exclusions,
+            hostname_sources,
             ..
         } = discovery.base.discovery_type
         {
+            let (custom_service_definitions, scan_profile) = match self
+                .network_service
+                .get_by_id(&discovery.base.network_id)
+                .await?
+            {
+                Some(network) => {
+                    for exclusion in network.base.scan_exclusions {
+                        if !exclusions.contains(&exclusion) {
+                            exclusions.push(exclusion);
+                        }
+                    }
+
+                    (
+                        self.custom_service_definition_service
+                            .build_definitions_for_discovery(network.base.organization_id)
+                            .await?,
+                        self.custom_scan_profile_service
+                            .build_scan_profile_for_discovery(
+                                network.base.organization_id,
+                                scan_profile,
+                            )
+                            .await?,
+                    )
+                }
+                None => (Vec::new(), scan_profile),
+            };
+
             DiscoveryType::Network {
                 subnet_ids,
                 host_naming_fallback,
@@ -575,7 +631,11 @@ impl DiscoveryService {
                     .snmp_credential_service
                     .build_credentials_for_discovery(discovery.base.network_id)
                     .await?,
+                custom_service_definitions,
                 probe_raw_socket_ports,
+                scan_profile,
+                exclusions,
+                hostname_sources,
             }
         } else {
             discovery.base.discovery_type
@@ -732,7 +792,7 @@ impl DiscoveryService {
                     tags: Vec::new(),
                     discovery_type: session.discovery_type.clone(),
                     run_type: RunType::Historical {
-                        results: session.clone(),
+                        results: Box::new(session.clone()),
                     },
                 },
             };
@@ -829,6 +889,7 @@ impl DiscoveryService {
             started_at: session.started_at,
             finished_at: Some(Utc::now()),
             discovery_type: session.discovery_type,
+            excluded_targets: session.excluded_targets,
         };
 
         // Handle based on current phase
@@ -991,6 +1052,7 @@ impl DiscoveryService {
                 started_at: session.started_at,
                 finished_at: Some(Utc::now()),
                 discovery_type: session.discovery_type.clone(),
+                excluded_targets: session.excluded_targets.clone(),
             };
 
             if let Err(e) = self
@@ -1081,7 +1143,9 @@ impl DiscoveryService {
                         tags: Vec::new(),
                         name: "Discovery Run (Stalled)".to_string(),
                         discovery_type: session.discovery_type.clone(),
-                        run_type: RunType::Historical { results: session },
+                        run_type: RunType::Historical {
+                            results: Box::new(session),
+                        },
                     },
                 };
 
@@ -0,0 +1,198 @@
+use crate::server::auth::middleware::permissions::{Authorized, Viewer};
+use crate::server::config::AppState;
+use crate::server::host_changes::r#impl::base::{HostChange, HostChangeType};
+use crate::server::host_changes::service::HostChangeService;
+use crate::server::shared::handlers::ordering::OrderField;
+use crate::server::shared::handlers::query::{
+    FilterQueryExtractor, OrderDirection, PaginationParams,
+};
+use crate::server::shared::handlers::traits::CrudHandlers;
+use crate::server::shared::services::traits::CrudService;
+use crate::server::shared::storage::filter::StorableFilter;
+use crate::server::shared::storage::traits::{Entity, Storable};
+use crate::server::shared::types::api::{ApiError, ApiResponse, ApiResult, PaginatedApiResponse};
+use axum::Json;
+use axum::extract::State;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::IntoParams;
+use utoipa_axum::{router::OpenApiRouter, routes};
+use uuid::Uuid;
+
+impl CrudHandlers for HostChange {
+    type Service = HostChangeService;
+    type FilterQuery = HostChangeFilterQuery;
+
+    fn get_service(state: &AppState) -> &Self::Service {
+        &state.services.host_change_service
+    }
+}
+
+// ============================================================================
+// Host Change Ordering
+// ============================================================================
+
+/// Fields that host changes can be ordered/grouped by.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HostChangeOrderField {
+    #[default]
+    CreatedAt,
+    ChangeType,
+    HostId,
+    SessionId,
+}
+
+impl OrderField for HostChangeOrderField {
+    fn to_sql(&self) -> &'static str {
+        match self {
+            Self::CreatedAt => "host_changes.created_at",
+            Self::ChangeType => "host_changes.change_type",
+            Self::HostId => "host_changes.host_id",
+            Self::SessionId => "host_changes.session_id",
+        }
+    }
+}
+
+// ============================================================================
+// Host Change Filter Query
+// ============================================================================
+
+/// Query parameters for filtering and ordering host changes.
+#[derive(Deserialize, Default, Debug, Clone, IntoParams)]
+pub struct HostChangeFilterQuery {
+    /// Filter by network ID
+    pub network_id: Option<Uuid>,
+    /// Filter by host ID (host timeline)
+    pub host_id: Option<Uuid>,
+    /// Filter by discovery session ID (session diff)
+    pub session_id: Option<Uuid>,
+    /// Filter by change type
+    pub change_type: Option<HostChangeType>,
+    /// Primary ordering field (used for grouping). Always sorts ASC to keep groups together.
+    pub group_by: Option<HostChangeOrderField>,
+    /// Secondary ordering field (sorting within groups or standalone sort).
+    pub order_by: Option<HostChangeOrderField>,
+    /// Direction for order_by field (group_by always uses ASC).
+    pub order_direction: Option<OrderDirection>,
+    /// Maximum number of results to return (1-1000, default: 50). Use 0 for no limit.
+    #[param(minimum = 0, maximum = 1000)]
+    pub limit: Option<u32>,
+    /// Number of results to skip. Default: 0.
+    #[param(minimum = 0)]
+    pub offset: Option<u32>,
+}
+
+impl HostChangeFilterQuery {
+    /// Build the ORDER BY clause. Newest changes first unless specified.
+    pub fn apply_ordering(
+        &self,
+        filter: StorableFilter<HostChange>,
+    ) -> (StorableFilter<HostChange>, String) {
+        crate::server::shared::handlers::ordering::apply_ordering(
+            self.group_by,
+            self.order_by,
+            self.order_direction,
+            filter,
+            "host_changes.created_at DESC",
+        )
+    }
+}
+
+impl FilterQueryExtractor for HostChangeFilterQuery {
+    fn apply_to_filter<T: Storable>(
+        &self,
+        filter: StorableFilter<T>,
+        user_network_ids: &[Uuid],
+        _user_organization_id: Uuid,
+    ) -> StorableFilter<T> {
+        let filter = match self.host_id {
+            Some(id) => filter.host_id(&id),
+            None => filter,
+        };
+        let filter = match self.session_id {
+            Some(id) => filter.uuid_column("session_id", &id),
+            None => filter,
+        };
+        let filter = match self.change_type {
+            Some(change_type) => filter.change_type(change_type),
+            None => filter,
+        };
+        match self.network_id {
+            Some(id) if user_network_ids.contains(&id) => filter.network_ids(&[id]),
+            Some(_) => filter.network_ids(&[]), // User doesn't have access - return empty
+            None => filter.network_ids(user_network_ids),
+        }
+    }
+
+    fn pagination(&self) -> PaginationParams {
+        PaginationParams {
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+// Generated handlers for read-only operations. Changes are only recorded by discovery.
+mod generated {
+    use super::*;
+    crate::crud_get_by_id_handler!(HostChange);
+    crate::crud_export_csv_handler!(HostChange);
+}
+
+pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(get_all_host_changes))
+        .routes(routes!(generated::export_csv))
+        .routes(routes!(generated::get_by_id))
+}
+
+/// List all Host Changes
+///
+/// Returns changes observed on hosts by discovery, newest first. Filter by `host_id`
+/// for a host's timeline, or by `session_id` for what a discovery session changed.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = HostChange::ENTITY_NAME_PLURAL,
+    params(HostChangeFilterQuery),
+    responses(
+        (status = 200, description = "List of host changes", body = PaginatedApiResponse<HostChange>),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_all_host_changes(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Viewer>,
+    crate::server::shared::extractors::Query(query): crate::server::shared::extractors::Query<
+        HostChangeFilterQuery,
+    >,
+) -> ApiResult<Json<PaginatedApiResponse<HostChange>>> {
+    let network_ids = auth.network_ids();
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(ApiError::organization_required)?;
+
+    let base_filter = StorableFilter::<HostChange>::new_from_network_ids(&network_ids);
+    let filter = query.apply_to_filter(base_filter, &network_ids, organization_id);
+
+    let pagination = query.pagination();
+    let filter = pagination.apply_to_filter(filter);
+    let (filter, order_by) = query.apply_ordering(filter);
+
+    let result = state
+        .services
+        .host_change_service
+        .get_paginated_ordered(filter, &order_by)
+        .await?;
+
+    let limit = pagination.effective_limit().unwrap_or(0);
+    let offset = pagination.effective_offset();
+
+    Ok(Json(PaginatedApiResponse::success(
+        result.items,
+        result.total_count,
+        limit,
+        offset,
+    )))
+}
@@ -0,0 +1,119 @@
+use crate::server::shared::entities::ChangeTriggersTopologyStaleness;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use strum::{Display as StrumDisplay, EnumString};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Kind of change observed on a host between discoveries
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    ToSchema,
+    StrumDisplay,
+    EnumString,
+)]
+pub enum HostChangeType {
+    /// Host was discovered for the first time, or seen again after disappearing
+    #[default]
+    HostAppeared,
+    /// Host was not found by a network scan covering its subnet
+    HostDisappeared,
+    /// Port found open that was not open before
+    PortOpened,
+    /// Port previously open was not found by a network scan of the host
+    PortClosed,
+    /// Service matched that was not matched before
+    ServiceAdded,
+    /// Service previously matched was not matched by a network scan of the host
+    ServiceRemoved,
+    /// Discovery resolved a different hostname than the one stored
+    HostnameChanged,
+    /// Discovery found a different MAC address on a known interface IP
+    MacAddressChanged,
+    /// Port presented a different TLS certificate than the one stored
+    CertificateChanged,
+    /// TLS certificate on a port has expired or expires within 30 days
+    CertificateExpiring,
+}
+
+impl HostChangeType {
+    /// Whether the change describes the host itself rather than one of its ports/services/interfaces
+    pub fn is_host_presence(&self) -> bool {
+        matches!(
+            self,
+            HostChangeType::HostAppeared | HostChangeType::HostDisappeared
+        )
+    }
+}
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema, Default, Validate,
+)]
+pub struct HostChangeBase {
+    pub network_id: Uuid,
+    pub host_id: Uuid,
+    /// Discovery session that observed the change, if it came from one
+    pub session_id: Option<Uuid>,
+    pub change_type: HostChangeType,
+    /// What changed: port ("443/tcp"), service definition ID, or interface IP.
+    /// Empty for host-level changes.
+    pub subject: String,
+    pub previous_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema, Default, Validate,
+)]
+pub struct HostChange {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub base: HostChangeBase,
+}
+
+impl ChangeTriggersTopologyStaleness<HostChange> for HostChange {
+    fn triggers_staleness(&self, _other: Option<HostChange>) -> bool {
+        false
+    }
+}
+
+impl Display for HostChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "HostChange {}: {} {} on host {}",
+            self.id, self.base.change_type, self.base.subject, self.base.host_id
+        )
+    }
+}
+
+impl HostChange {
+    pub fn new(base: HostChangeBase) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+}
@@ -0,0 +1,585 @@
+use crate::server::{
+    host_changes::r#impl::base::{HostChange, HostChangeBase, HostChangeType},
+    hosts::r#impl::base::Host,
+    interfaces::r#impl::base::Interface,
+    ports::r#impl::base::Port,
+    services::r#impl::{base::Service, definitions::ServiceDefinitionExt},
+    shared::types::metadata::HasId,
+    tls_certificates::r#impl::base::TlsCertificate,
+};
+use chrono::{DateTime, Duration, Utc};
+use mac_address::MacAddress;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    net::IpAddr,
+};
+use uuid::Uuid;
+
+/// The parts of a host the change log tracks
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HostSnapshot {
+    pub name: String,
+    pub hostname: Option<String>,
+    /// Interface IP -> MAC address
+    pub macs: BTreeMap<IpAddr, MacAddress>,
+    /// Port labels, e.g. "443/tcp"
+    pub ports: BTreeSet<String>,
+    /// Service definition ID -> service name. Excludes the OpenPorts catch-all,
+    /// its ports are tracked individually.
+    pub services: BTreeMap<String, String>,
+    /// Port label -> TLS certificate presented on it
+    pub certificates: BTreeMap<String, CertificateSnapshot>,
+}
+
+/// The parts of a TLS certificate the change log tracks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CertificateSnapshot {
+    pub fingerprint_sha256: String,
+    pub not_after: DateTime<Utc>,
+}
+
+impl HostSnapshot {
+    pub fn new(
+        host: &Host,
+        interfaces: &[Interface],
+        ports: &[Port],
+        services: &[Service],
+    ) -> Self {
+        Self {
+            name: host.base.name.clone(),
+            hostname: host.base.hostname.clone(),
+            macs: interfaces
+                .iter()
+                .filter_map(|i| i.base.mac_address.map(|mac| (i.base.ip_address, mac)))
+                .collect(),
+            ports: ports.iter().map(|p| p.base.port_type.to_string()).collect(),
+            services: services
+                .iter()
+                .filter(|s| !s.base.service_definition.is_open_ports())
+                .map(|s| {
+                    (
+                        s.base.service_definition.id().to_string(),
+                        s.base.name.clone(),
+                    )
+                })
+                .collect(),
+            certificates: BTreeMap::new(),
+        }
+    }
+
+    /// Add the certificates presented on `ports`. Certificates for a port not in `ports`
+    /// are ignored.
+    pub fn with_certificates(mut self, ports: &[Port], certificates: &[TlsCertificate]) -> Self {
+        self.certificates = certificates
+            .iter()
+            .filter_map(|c| {
+                let port = ports.iter().find(|p| p.id == c.base.port_id)?;
+                Some((
+                    port.base.port_type.to_string(),
+                    CertificateSnapshot {
+                        fingerprint_sha256: c.base.fingerprint_sha256.clone(),
+                        not_after: c.base.not_after,
+                    },
+                ))
+            })
+            .collect();
+        self
+    }
+}
+
+/// Groups change types that describe the same subject, so the latest entry
+/// per subject gives its current state (e.g. PortOpened/PortClosed for "443/tcp")
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ChangeFamily {
+    Presence,
+    Port,
+    Service,
+    Hostname,
+    MacAddress,
+    Certificate,
+    CertificateExpiry,
+}
+
+impl From<HostChangeType> for ChangeFamily {
+    fn from(value: HostChangeType) -> Self {
+        match value {
+            HostChangeType::HostAppeared | HostChangeType::HostDisappeared => Self::Presence,
+            HostChangeType::PortOpened | HostChangeType::PortClosed => Self::Port,
+            HostChangeType::ServiceAdded | HostChangeType::ServiceRemoved => Self::Service,
+            HostChangeType::HostnameChanged => Self::Hostname,
+            HostChangeType::MacAddressChanged => Self::MacAddress,
+            HostChangeType::CertificateChanged => Self::Certificate,
+            HostChangeType::CertificateExpiring => Self::CertificateExpiry,
+        }
+    }
+}
+
+/// Most recent recorded change per subject of a host
+#[derive(Debug, Clone, Default)]
+pub struct LatestChanges(HashMap<(ChangeFamily, String), HostChangeBase>);
+
+impl LatestChanges {
+    /// Build from a host's change log in chronological order
+    pub fn from_history(history: impl IntoIterator<Item = HostChange>) -> Self {
+        let mut latest = HashMap::new();
+        for change in history {
+            latest.insert(
+                (change.base.change_type.into(), change.base.subject.clone()),
+                change.base,
+            );
+        }
+        Self(latest)
+    }
+
+    fn get(&self, change_type: HostChangeType, subject: &str) -> Option<&HostChangeBase> {
+        self.0.get(&(change_type.into(), subject.to_string()))
+    }
+
+    fn latest_type(&self, change_type: HostChangeType, subject: &str) -> Option<HostChangeType> {
+        self.get(change_type, subject).map(|c| c.change_type)
+    }
+
+    /// Value from the latest change of this kind, falling back to the stored value
+    fn effective_value(
+        &self,
+        change_type: HostChangeType,
+        subject: &str,
+        stored: Option<String>,
+    ) -> Option<String> {
+        self.get(change_type, subject)
+            .and_then(|c| c.new_value.clone())
+            .or(stored)
+    }
+}
+
+/// Host and session a set of changes is recorded against
+#[derive(Debug, Clone, Copy)]
+pub struct ChangeContext {
+    pub network_id: Uuid,
+    pub host_id: Uuid,
+    pub session_id: Option<Uuid>,
+}
+
+impl ChangeContext {
+    fn change(
+        &self,
+        change_type: HostChangeType,
+        subject: impl Into<String>,
+        previous_value: Option<String>,
+        new_value: Option<String>,
+    ) -> HostChangeBase {
+        HostChangeBase {
+            network_id: self.network_id,
+            host_id: self.host_id,
+            session_id: self.session_id,
+            change_type,
+            subject: subject.into(),
+            previous_value,
+            new_value,
+        }
+    }
+}
+
+/// Changes visible from a single discovery report: a new or returning host, newly
+/// opened ports, newly matched services, hostname/MAC changes, replaced TLS certificates
+/// and certificates expiring within `expiry_warning`.
+///
+/// `previous` is the stored state before the report was merged, `None` for a new host.
+/// Closed ports and removed services can't be known from one report, see [`diff_removals`].
+pub fn diff_report(
+    ctx: ChangeContext,
+    previous: Option<&HostSnapshot>,
+    current: &HostSnapshot,
+    latest: &LatestChanges,
+    expiry_warning: Duration,
+) -> Vec<HostChangeBase> {
+    let Some(previous) = previous else {
+        let mut changes = vec![ctx.change(
+            HostChangeType::HostAppeared,
+            "",
+            None,
+            Some(current.name.clone()),
+        )];
+        changes.extend(diff_certificate_expiry(
+            ctx,
+            current,
+            latest,
+            Utc::now(),
+            expiry_warning,
+        ));
+        return changes;
+    };
+
+    let mut changes = Vec::new();
+
+    if latest.latest_type(HostChangeType::HostAppeared, "") == Some(HostChangeType::HostDisappeared)
+    {
+        changes.push(ctx.change(
+            HostChangeType::HostAppeared,
+            "",
+            None,
+            Some(current.name.clone()),
+        ));
+    }
+
+    for port in &current.ports {
+        let reopened = latest.latest_type(HostChangeType::PortOpened, port)
+            == Some(HostChangeType::PortClosed);
+        if !previous.ports.contains(port) || reopened {
+            changes.push(ctx.change(HostChangeType::PortOpened, port, None, Some(port.clone())));
+        }
+    }
+
+    for (definition_id, name) in &current.services {
+        let readded = latest.latest_type(HostChangeType::ServiceAdded, definition_id)
+            == Some(HostChangeType::ServiceRemoved);
+        if !previous.services.contains_key(definition_id) || readded {
+            changes.push(ctx.change(
+                HostChangeType::ServiceAdded,
+                definition_id,
+                None,
+                Some(name.clone()),
+            ));
+        }
+    }
+
+    // Discovery doesn't overwrite a stored hostname, so compare against the last one observed
+    if let Some(hostname) = &current.hostname
+        && let Some(previous_hostname) = latest.effective_value(
+            HostChangeType::HostnameChanged,
+            "",
+            previous.hostname.clone(),
+        )
+        && *hostname != previous_hostname
+    {
+        changes.push(ctx.change(
+            HostChangeType::HostnameChanged,
+            "",
+            Some(previous_hostname),
+            Some(hostname.clone()),
+        ));
+    }
+
+    for (ip, mac) in &current.macs {
+        let subject = ip.to_string();
+        let mac = mac.to_string();
+        if let Some(previous_mac) = latest.effective_value(
+            HostChangeType::MacAddressChanged,
+            &subject,
+            previous.macs.get(ip).map(|m| m.to_string()),
+        ) && mac != previous_mac
+        {
+            changes.push(ctx.change(
+                HostChangeType::MacAddressChanged,
+                subject,
+                Some(previous_mac),
+                Some(mac),
+            ));
+        }
+    }
+
+    for (port, certificate) in &current.certificates {
+        if let Some(previous_certificate) = previous.certificates.get(port)
+            && previous_certificate.fingerprint_sha256 != certificate.fingerprint_sha256
+        {
+            changes.push(ctx.change(
+                HostChangeType::CertificateChanged,
+                port,
+                Some(previous_certificate.fingerprint_sha256.clone()),
+                Some(certificate.fingerprint_sha256.clone()),
+            ));
+        }
+    }
+
+    changes.extend(diff_certificate_expiry(
+        ctx,
+        current,
+        latest,
+        Utc::now(),
+        expiry_warning,
+    ));
+
+    changes
+}
+
+/// Certificates that have expired or expire within `expiry_warning` of `now`.
+/// Recorded once per certificate: the new value is its expiry, so a renewed certificate
+/// that is itself close to expiry is recorded again.
+pub fn diff_certificate_expiry(
+    ctx: ChangeContext,
+    current: &HostSnapshot,
+    latest: &LatestChanges,
+    now: DateTime<Utc>,
+    expiry_warning: Duration,
+) -> Vec<HostChangeBase> {
+    current
+        .certificates
+        .iter()
+        .filter(|(_, c)| c.not_after <= now + expiry_warning)
+        .filter_map(|(port, c)| {
+            let not_after = c.not_after.to_rfc3339();
+            let recorded = latest
+                .get(HostChangeType::CertificateExpiring, port)
+                .and_then(|change| change.new_value.as_deref())
+                == Some(not_after.as_str());
+            (!recorded).then(|| {
+                ctx.change(
+                    HostChangeType::CertificateExpiring,
+                    port,
+                    None,
+                    Some(not_after),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Ports and services stored on a host that a completed network scan didn't observe.
+pub fn diff_removals(
+    ctx: ChangeContext,
+    stored: &HostSnapshot,
+    observed_ports: &BTreeSet<String>,
+    observed_services: &BTreeSet<String>,
+    latest: &LatestChanges,
+) -> Vec<HostChangeBase> {
+    let closed_ports = stored
+        .ports
+        .iter()
+        .filter(|port| !observed_ports.contains(*port))
+        .filter(|port| {
+            latest.latest_type(HostChangeType::PortClosed, port) != Some(HostChangeType::PortClosed)
+        })
+        .map(|port| ctx.change(HostChangeType::PortClosed, port, Some(port.clone()), None));
+
+    let removed_services = stored
+        .services
+        .iter()
+        .filter(|(definition_id, _)| !observed_services.contains(*definition_id))
+        .filter(|(definition_id, _)| {
+            latest.latest_type(HostChangeType::ServiceRemoved, definition_id)
+                != Some(HostChangeType::ServiceRemoved)
+        })
+        .map(|(definition_id, name)| {
+            ctx.change(
+                HostChangeType::ServiceRemoved,
+                definition_id,
+                Some(name.clone()),
+                None,
+            )
+        });
+
+    closed_ports.chain(removed_services).collect()
+}
+
+/// A host on a scanned subnet that the scan didn't observe, unless already recorded as gone.
+pub fn diff_disappeared(
+    ctx: ChangeContext,
+    host_name: &str,
+    latest: &LatestChanges,
+) -> Option<HostChangeBase> {
+    (latest.latest_type(HostChangeType::HostDisappeared, "")
+        != Some(HostChangeType::HostDisappeared))
+    .then(|| {
+        ctx.change(
+            HostChangeType::HostDisappeared,
+            "",
+            Some(host_name.to_string()),
+            None,
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctx() -> ChangeContext {
+        ChangeContext {
+            network_id: Uuid::new_v4(),
+            host_id: Uuid::new_v4(),
+            session_id: Some(Uuid::new_v4()),
+        }
+    }
+
+    fn snapshot(ports: &[&str], services: &[&str]) -> HostSnapshot {
+        HostSnapshot {
+            name: "nas".to_string(),
+            hostname: Some("nas.local".to_string()),
+            macs: BTreeMap::new(),
+            ports: ports.iter().map(|p| p.to_string()).collect(),
+            services: services
+                .iter()
+                .map(|s| (s.to_string(), s.to_string()))
+                .collect(),
+            certificates: BTreeMap::new(),
+        }
+    }
+
+    fn certificate(fingerprint: &str, days_left: i64) -> CertificateSnapshot {
+        CertificateSnapshot {
+            fingerprint_sha256: fingerprint.to_string(),
+            not_after: Utc::now() + Duration::days(days_left),
+        }
+    }
+
+    fn warning() -> Duration {
+        Duration::days(30)
+    }
+
+    fn history(changes: Vec<HostChangeBase>) -> LatestChanges {
+        LatestChanges::from_history(changes.into_iter().map(HostChange::new))
+    }
+
+    fn types(changes: &[HostChangeBase]) -> Vec<(HostChangeType, &str)> {
+        changes
+            .iter()
+            .map(|c| (c.change_type, c.subject.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_new_host_records_only_appearance() {
+        let changes = diff_report(
+            ctx(),
+            None,
+            &snapshot(&["22/tcp"], &["SSH"]),
+            &LatestChanges::default(),
+            warning(),
+        );
+        assert_eq!(types(&changes), vec![(HostChangeType::HostAppeared, "")]);
+    }
+
+    #[test]
+    fn test_report_diff() {
+        let previous = snapshot(&["22/tcp"], &["SSH"]);
+        let mut current = snapshot(&["22/tcp", "443/tcp"], &["SSH", "Nginx"]);
+        current.hostname = Some("nas.lan".to_string());
+
+        let changes = diff_report(
+            ctx(),
+            Some(&previous),
+            &current,
+            &LatestChanges::default(),
+            warning(),
+        );
+        assert_eq!(
+            types(&changes),
+            vec![
+                (HostChangeType::PortOpened, "443/tcp"),
+                (HostChangeType::ServiceAdded, "Nginx"),
+                (HostChangeType::HostnameChanged, ""),
+            ]
+        );
+        assert_eq!(changes[2].previous_value.as_deref(), Some("nas.local"));
+
+        // Same report again: hostname change already recorded, stored hostname unchanged
+        let latest = history(changes);
+        let previous = snapshot(&["22/tcp", "443/tcp"], &["SSH", "Nginx"]);
+        assert!(diff_report(ctx(), Some(&previous), &current, &latest, warning()).is_empty());
+    }
+
+    #[test]
+    fn test_removals_recorded_once_and_reopen() {
+        let ctx = ctx();
+        let stored = snapshot(&["22/tcp", "443/tcp"], &["SSH", "Nginx"]);
+        let observed_ports = BTreeSet::from(["22/tcp".to_string()]);
+        let observed_services = BTreeSet::from(["SSH".to_string()]);
+
+        let removals = diff_removals(
+            ctx,
+            &stored,
+            &observed_ports,
+            &observed_services,
+            &LatestChanges::default(),
+        );
+        assert_eq!(
+            types(&removals),
+            vec![
+                (HostChangeType::PortClosed, "443/tcp"),
+                (HostChangeType::ServiceRemoved, "Nginx"),
+            ]
+        );
+
+        // Next scan still doesn't see them - nothing new to record
+        let latest = history(removals);
+        assert!(
+            diff_removals(ctx, &stored, &observed_ports, &observed_services, &latest).is_empty()
+        );
+
+        // Port comes back while still stored on the host
+        let changes = diff_report(ctx, Some(&stored), &stored, &latest, warning());
+        assert_eq!(
+            types(&changes),
+            vec![
+                (HostChangeType::PortOpened, "443/tcp"),
+                (HostChangeType::ServiceAdded, "Nginx"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_disappeared_and_reappeared() {
+        let ctx = ctx();
+        let gone = diff_disappeared(ctx, "nas", &LatestChanges::default()).unwrap();
+        let latest = history(vec![gone]);
+        assert!(diff_disappeared(ctx, "nas", &latest).is_none());
+
+        let stored = snapshot(&["22/tcp"], &[]);
+        let changes = diff_report(ctx, Some(&stored), &stored, &latest, warning());
+        assert_eq!(types(&changes), vec![(HostChangeType::HostAppeared, "")]);
+    }
+
+    #[test]
+    fn test_certificate_changed_and_expiring() {
+        let ctx = ctx();
+        let mut previous = snapshot(&["443/tcp", "8443/tcp"], &[]);
+        previous
+            .certificates
+            .insert("443/tcp".to_string(), certificate("aa", 200));
+        previous
+            .certificates
+            .insert("8443/tcp".to_string(), certificate("bb", 200));
+
+        let mut current = previous.clone();
+        current
+            .certificates
+            .insert("443/tcp".to_string(), certificate("cc", 365));
+        current
+            .certificates
+            .insert("8443/tcp".to_string(), certificate("bb", 10));
+
+        let changes = diff_report(
+            ctx,
+            Some(&previous),
+            &current,
+            &LatestChanges::default(),
+            warning(),
+        );
+        assert_eq!(
+            types(&changes),
+            vec![
+                (HostChangeType::CertificateChanged, "443/tcp"),
+                (HostChangeType::CertificateExpiring, "8443/tcp"),
+            ]
+        );
+        assert_eq!(changes[0].previous_value.as_deref(), Some("aa"));
+        assert_eq!(changes[0].new_value.as_deref(), Some("cc"));
+
+        // Expiry already recorded for this certificate
+        let latest = history(changes);
+        assert!(diff_report(ctx, Some(&current), &current, &latest, warning()).is_empty());
+
+        // Outside a shorter warning window
+        let now = Utc::now();
+        let short = Duration::days(7);
+        assert!(
+            diff_certificate_expiry(ctx, &current, &LatestChanges::default(), now, short)
+                .is_empty()
+        );
+        assert_eq!(
+            diff_certificate_expiry(ctx, &current, &LatestChanges::default(), now + short, short)
+                .len(),
+            1
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+use crate::server::{
+    host_changes::r#impl::base::{HostChange, HostChangeBase},
+    shared::{
+        entities::EntityDiscriminants,
+        entity_metadata::EntityCategory,
+        storage::traits::{Entity, SqlValue, Storable},
+    },
+};
+
+/// CSV row representation for HostChange export
+#[derive(Serialize)]
+pub struct HostChangeCsvRow {
+    pub id: Uuid,
+    pub network_id: Uuid,
+    pub host_id: Uuid,
+    pub session_id: Option<Uuid>,
+    pub change_type: String,
+    pub subject: String,
+    pub previous_value: Option<String>,
+    pub new_value: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Storable for HostChange {
+    type BaseData = HostChangeBase;
+
+    fn table_name() -> &'static str {
+        "host_changes"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            updated_at,
+            base:
+                Self::BaseData {
+                    network_id,
+                    host_id,
+                    session_id,
+                    change_type,
+                    subject,
+                    previous_value,
+                    new_value,
+                },
+        } = self.clone();
+
+        Ok((
+            vec![
+                "id",
+                "network_id",
+                "host_id",
+                "session_id",
+                "change_type",
+                "subject",
+                "previous_value",
+                "new_value",
+                "created_at",
+                "updated_at",
+            ],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::Uuid(network_id),
+                SqlValue::Uuid(host_id),
+                SqlValue::OptionalUuid(session_id),
+                SqlValue::String(change_type.to_string()),
+                SqlValue::String(subject),
+                SqlValue::OptionalString(previous_value),
+                SqlValue::OptionalString(new_value),
+                SqlValue::Timestamp(created_at),
+                SqlValue::Timestamp(updated_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        let change_type: String = row.get("change_type");
+        let change_type = change_type
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Failed to parse change_type: {}", e))?;
+
+        Ok(HostChange {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            base: HostChangeBase {
+                network_id: row.get("network_id"),
+                host_id: row.get("host_id"),
+                session_id: row.get("session_id"),
+                change_type,
+                subject: row.get("subject"),
+                previous_value: row.get("previous_value"),
+                new_value: row.get("new_value"),
+            },
+        })
+    }
+}
+
+impl Entity for HostChange {
+    type CsvRow = HostChangeCsvRow;
+
+    fn to_csv_row(&self) -> Self::CsvRow {
+        HostChangeCsvRow {
+            id: self.id,
+            network_id: self.base.network_id,
+            host_id: self.base.host_id,
+            session_id: self.base.session_id,
+            change_type: self.base.change_type.to_string(),
+            subject: self.base.subject.clone(),
+            previous_value: self.base.previous_value.clone(),
+            new_value: self.base.new_value.clone(),
+            created_at: self.created_at,
+        }
+    }
+
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::HostChange
+    }
+
+    const ENTITY_NAME_SINGULAR: &'static str = "Host Change";
+    const ENTITY_NAME_PLURAL: &'static str = "Host Changes";
+    const ENTITY_DESCRIPTION: &'static str = "Changes observed on hosts between discoveries. Hosts appearing or disappearing, ports opening or closing, services added or removed, and hostname or MAC address changes.";
+
+    fn entity_category() -> EntityCategory {
+        EntityCategory::DiscoveryAndDaemons
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        Some(self.base.network_id)
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    fn set_updated_at(&mut self, time: DateTime<Utc>) {
+        self.updated_at = time;
+    }
+}
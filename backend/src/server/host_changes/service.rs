@@ -0,0 +1,454 @@
+use crate::server::{
+    auth::middleware::auth::AuthenticatedEntity,
+    discovery::{r#impl::types::DiscoveryType, service::DiscoveryService},
+    host_changes::r#impl::{
+        base::{HostChange, HostChangeBase},
+        diff::{
+            ChangeContext, HostSnapshot, LatestChanges, diff_certificate_expiry, diff_disappeared,
+            diff_removals, diff_report,
+        },
+    },
+    hosts::{r#impl::base::Host, service::HostService},
+    interfaces::{r#impl::base::Interface, service::InterfaceService},
+    ports::{r#impl::base::Port, service::PortService},
+    services::{r#impl::base::Service, service::ServiceService},
+    shared::{
+        events::{bus::EventBus, types::DiscoverySessionEvent},
+        services::traits::{CrudService, EventBusService},
+        storage::{filter::StorableFilter, generic::GenericPostgresStorage, traits::Storage},
+        types::entities::EntitySource,
+    },
+    tags::entity_tags::EntityTagService,
+    tls_certificates::{r#impl::base::TlsCertificate, service::TlsCertificateService},
+};
+use anyhow::Result;
+use chrono::{Duration as ChronoDuration, Utc};
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    sync::{Arc, OnceLock},
+};
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+/// Change history retention. The latest change per subject is always kept.
+const HOST_CHANGE_RETENTION_DAYS: i64 = 180;
+
+/// What a network discovery session reported for one host
+#[derive(Debug, Default)]
+struct HostObservation {
+    ports: BTreeSet<String>,
+    services: BTreeSet<String>,
+    /// Whether any report included open ports. Hosts only found via ARP/NDP
+    /// weren't port scanned, so missing ports say nothing about them.
+    port_scanned: bool,
+}
+
+#[derive(Debug, Default)]
+struct SessionObservations {
+    hosts: HashMap<Uuid, HostObservation>,
+    subnet_ids: HashSet<Uuid>,
+}
+
+pub struct HostChangeService {
+    storage: Arc<GenericPostgresStorage<HostChange>>,
+    interface_service: Arc<InterfaceService>,
+    port_service: Arc<PortService>,
+    service_service: Arc<ServiceService>,
+    discovery_service: Arc<DiscoveryService>,
+    tls_certificate_service: Arc<TlsCertificateService>,
+    host_service: OnceLock<Arc<HostService>>,
+    event_bus: Arc<EventBus>,
+    /// How long before expiry a certificate is recorded as expiring (`cert_expiry_warning_days`)
+    expiry_warning: ChronoDuration,
+    /// session_id -> hosts reported so far, used to detect removals once the session completes
+    observations: RwLock<HashMap<Uuid, SessionObservations>>,
+}
+
+impl EventBusService<HostChange> for HostChangeService {
+    fn event_bus(&self) -> &Arc<EventBus> {
+        &self.event_bus
+    }
+
+    fn get_network_id(&self, entity: &HostChange) -> Option<Uuid> {
+        Some(entity.base.network_id)
+    }
+
+    fn get_organization_id(&self, _entity: &HostChange) -> Option<Uuid> {
+        None
+    }
+}
+
+impl CrudService<HostChange> for HostChangeService {
+    fn storage(&self) -> &Arc<GenericPostgresStorage<HostChange>> {
+        &self.storage
+    }
+
+    fn entity_tag_service(&self) -> Option<&Arc<EntityTagService>> {
+        None
+    }
+}
+
+fn is_network_discovery(source: &EntitySource) -> bool {
+    source
+        .discovery_metadata()
+        .iter()
+        .any(|m| matches!(m.discovery_type, DiscoveryType::Network { .. }))
+}
+
+impl HostChangeService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        storage: Arc<GenericPostgresStorage<HostChange>>,
+        interface_service: Arc<InterfaceService>,
+        port_service: Arc<PortService>,
+        service_service: Arc<ServiceService>,
+        discovery_service: Arc<DiscoveryService>,
+        tls_certificate_service: Arc<TlsCertificateService>,
+        event_bus: Arc<EventBus>,
+        expiry_warning_days: u32,
+    ) -> Self {
+        Self {
+            storage,
+            interface_service,
+            port_service,
+            service_service,
+            discovery_service,
+            tls_certificate_service,
+            host_service: OnceLock::new(),
+            event_bus,
+            expiry_warning: ChronoDuration::days(expiry_warning_days.into()),
+            observations: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn set_host_service(&self, host_service: Arc<HostService>) -> Result<(), Arc<HostService>> {
+        self.host_service.set(host_service)
+    }
+
+    /// Latest recorded change per subject for each host
+    async fn latest_changes(&self, host_ids: &[Uuid]) -> Result<HashMap<Uuid, LatestChanges>> {
+        let history = self
+            .storage
+            .get_all_ordered(
+                StorableFilter::<HostChange>::new_from_host_ids(host_ids).superseded_is(false),
+                "created_at ASC",
+            )
+            .await?;
+
+        let mut by_host: HashMap<Uuid, Vec<HostChange>> = HashMap::new();
+        for change in history {
+            by_host.entry(change.base.host_id).or_default().push(change);
+        }
+
+        Ok(by_host
+            .into_iter()
+            .map(|(host_id, changes)| (host_id, LatestChanges::from_history(changes)))
+            .collect())
+    }
+
+    async fn create_changes(&self, changes: Vec<HostChangeBase>) -> Result<()> {
+        for change in changes {
+            self.create(HostChange::new(change), AuthenticatedEntity::System)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Record changes visible from a host reported by discovery.
+    ///
+    /// `previous` is the stored host before the report was merged (`None` if the host is new),
+    /// `reported` is what the daemon sent. Network discovery reports are also remembered
+    /// until the session ends, so ports/services/hosts it didn't see can be recorded as gone.
+    pub async fn record_report(
+        &self,
+        host_id: Uuid,
+        network_id: Uuid,
+        previous: Option<HostSnapshot>,
+        reported: HostSnapshot,
+        reported_interfaces: &[Interface],
+        reported_source: &EntitySource,
+    ) -> Result<()> {
+        let metadata = reported_source.discovery_metadata().first();
+        let session_id = match metadata {
+            Some(m) => {
+                self.discovery_service
+                    .active_session_for_daemon(&m.daemon_id)
+                    .await
+            }
+            None => None,
+        };
+
+        let ctx = ChangeContext {
+            network_id,
+            host_id,
+            session_id,
+        };
+
+        let latest = if previous.is_some() {
+            self.latest_changes(&[host_id])
+                .await?
+                .remove(&host_id)
+                .unwrap_or_default()
+        } else {
+            LatestChanges::default()
+        };
+
+        let changes = diff_report(
+            ctx,
+            previous.as_ref(),
+            &reported,
+            &latest,
+            self.expiry_warning,
+        );
+        if !changes.is_empty() {
+            tracing::debug!(
+                host_id = %host_id,
+                session_id = ?session_id,
+                count = changes.len(),
+                "Recording host changes from discovery"
+            );
+        }
+        self.create_changes(changes).await?;
+
+        if let Some(session_id) = session_id
+            && metadata.is_some_and(|m| matches!(m.discovery_type, DiscoveryType::Network { .. }))
+        {
+            let mut observations = self.observations.write().await;
+            let session = observations.entry(session_id).or_default();
+            session
+                .subnet_ids
+                .extend(reported_interfaces.iter().map(|i| i.base.subnet_id));
+
+            let observation = session.hosts.entry(host_id).or_default();
+            observation.port_scanned |= !reported.ports.is_empty();
+            observation.ports.extend(reported.ports);
+            observation.services.extend(reported.services.into_keys());
+        }
+
+        Ok(())
+    }
+
+    /// Record what a completed network discovery session no longer found: closed ports and
+    /// removed services on scanned hosts, and hosts on scanned subnets that didn't respond.
+    pub async fn complete_session(&self, event: &DiscoverySessionEvent) -> Result<()> {
+        let Some(session) = self.observations.write().await.remove(&event.session_id) else {
+            return Ok(());
+        };
+
+        let DiscoveryType::Network { subnet_ids, .. } = &event.discovery_type else {
+            return Ok(());
+        };
+
+        // A scan that found nothing more likely failed than emptied the network
+        if session.hosts.is_empty() {
+            return Ok(());
+        }
+
+        let host_service = self
+            .host_service
+            .get()
+            .expect("host_service not initialized");
+
+        let ctx = |host_id: Uuid| ChangeContext {
+            network_id: event.network_id,
+            host_id,
+            session_id: Some(event.session_id),
+        };
+
+        // Hosts with an interface on a scanned subnet. Without explicit subnets the daemon
+        // scanned its own, which are the subnets hosts were reported on.
+        let scanned_subnet_ids: Vec<Uuid> = match subnet_ids {
+            Some(ids) => ids.clone(),
+            None => session.subnet_ids.iter().copied().collect(),
+        };
+        let subnet_host_ids: HashSet<Uuid> = self
+            .interface_service
+            .get_all(
+                StorableFilter::<Interface>::new_from_network_ids(&[event.network_id])
+                    .uuids_column("subnet_id", &scanned_subnet_ids),
+            )
+            .await?
+            .into_iter()
+            .map(|i| i.base.host_id)
+            .collect();
+
+        let scanned_host_ids: Vec<Uuid> = session
+            .hosts
+            .iter()
+            .filter(|(_, o)| o.port_scanned)
+            .map(|(id, _)| *id)
+            .collect();
+        let missing_host_ids: Vec<Uuid> = subnet_host_ids
+            .into_iter()
+            .filter(|id| !session.hosts.contains_key(id))
+            .collect();
+
+        let all_host_ids: Vec<Uuid> = scanned_host_ids
+            .iter()
+            .chain(missing_host_ids.iter())
+            .copied()
+            .collect();
+        if all_host_ids.is_empty() {
+            return Ok(());
+        }
+
+        let hosts: HashMap<Uuid, Host> = host_service
+            .get_all(StorableFilter::<Host>::new_from_entity_ids(&all_host_ids))
+            .await?
+            .into_iter()
+            .map(|h| (h.id, h))
+            .collect();
+        let mut latest = self.latest_changes(&all_host_ids).await?;
+        let mut changes = Vec::new();
+
+        if !scanned_host_ids.is_empty() {
+            let mut ports = self.port_service.get_for_hosts(&scanned_host_ids).await?;
+            let mut services: HashMap<Uuid, Vec<Service>> = HashMap::new();
+            for service in self
+                .service_service
+                .get_all(StorableFilter::<Service>::new_from_host_ids(
+                    &scanned_host_ids,
+                ))
+                .await?
+            {
+                // Services from Docker or manual entry can't be seen by a network scan
+                if is_network_discovery(&service.base.source) {
+                    services
+                        .entry(service.base.host_id)
+                        .or_default()
+                        .push(service);
+                }
+            }
+
+            for host_id in &scanned_host_ids {
+                let (Some(host), Some(observation)) =
+                    (hosts.get(host_id), session.hosts.get(host_id))
+                else {
+                    continue;
+                };
+
+                let stored = HostSnapshot::new(
+                    host,
+                    &[],
+                    &ports.remove(host_id).unwrap_or_default(),
+                    &services.remove(host_id).unwrap_or_default(),
+                );
+                changes.extend(diff_removals(
+                    ctx(*host_id),
+                    &stored,
+                    &observation.ports,
+                    &observation.services,
+                    &latest.remove(host_id).unwrap_or_default(),
+                ));
+            }
+        }
+
+        for host_id in &missing_host_ids {
+            let Some(host) = hosts.get(host_id) else {
+                continue;
+            };
+            // Manually added hosts may not be reachable by a scan at all
+            if !is_network_discovery(&host.base.source) {
+                continue;
+            }
+            changes.extend(diff_disappeared(
+                ctx(*host_id),
+                &host.base.name,
+                &latest.remove(host_id).unwrap_or_default(),
+            ));
+        }
+
+        tracing::debug!(
+            session_id = %event.session_id,
+            count = changes.len(),
+            "Recording host changes from completed discovery"
+        );
+
+        self.create_changes(changes).await
+    }
+
+    /// Record certificates that have come within the expiry warning window, including on
+    /// hosts no discovery has rescanned since. Called periodically from the server's
+    /// background tasks.
+    pub async fn check_certificate_expiry(&self) {
+        match self.record_expiring_certificates().await {
+            Ok(0) => {}
+            Ok(count) => tracing::debug!("Recorded {} expiring certificates.", count),
+            Err(e) => tracing::error!("Failed to check certificate expiry: {}", e),
+        }
+    }
+
+    async fn record_expiring_certificates(&self) -> Result<usize> {
+        let now = Utc::now();
+        let certificates = self
+            .tls_certificate_service
+            .get_all(
+                StorableFilter::<TlsCertificate>::new_unscoped()
+                    .not_after_before(now + self.expiry_warning),
+            )
+            .await?;
+        if certificates.is_empty() {
+            return Ok(0);
+        }
+
+        let mut by_host: HashMap<Uuid, Vec<TlsCertificate>> = HashMap::new();
+        for certificate in certificates {
+            by_host
+                .entry(certificate.base.host_id)
+                .or_default()
+                .push(certificate);
+        }
+        let host_ids: Vec<Uuid> = by_host.keys().copied().collect();
+        let ports = self
+            .port_service
+            .get_all(StorableFilter::<Port>::new_from_host_ids(&host_ids))
+            .await?;
+        let mut latest = self.latest_changes(&host_ids).await?;
+
+        let mut changes = Vec::new();
+        for (host_id, certificates) in by_host {
+            let ctx = ChangeContext {
+                network_id: certificates[0].base.network_id,
+                host_id,
+                session_id: None,
+            };
+            let current = HostSnapshot::default().with_certificates(&ports, &certificates);
+            changes.extend(diff_certificate_expiry(
+                ctx,
+                &current,
+                &latest.remove(&host_id).unwrap_or_default(),
+                now,
+                self.expiry_warning,
+            ));
+        }
+
+        let count = changes.len();
+        self.create_changes(changes).await?;
+        Ok(count)
+    }
+
+    /// Delete changes past the retention period. Each subject's latest change is kept
+    /// regardless of age, since later reports are diffed against it.
+    /// Called periodically from the server's background tasks.
+    pub async fn prune_history(&self) {
+        let cutoff = Utc::now() - ChronoDuration::days(HOST_CHANGE_RETENTION_DAYS);
+        match self
+            .storage
+            .delete_by_filter(
+                StorableFilter::<HostChange>::new_unscoped()
+                    .created_before(cutoff)
+                    .superseded_is(true),
+            )
+            .await
+        {
+            Ok(0) => {}
+            Ok(count) => tracing::debug!("Pruned {} old host changes.", count),
+            Err(e) => tracing::error!("Failed to prune host changes: {}", e),
+        }
+    }
+
+    /// Forget what a session reported without recording removals (failed or cancelled)
+    pub async fn discard_session(&self, session_id: &Uuid) {
+        self.observations.write().await.remove(session_id);
+    }
+}
@@ -0,0 +1,49 @@
+//! Event subscriber implementation for HostChangeService.
+//!
+//! Records removals once a discovery session completes, since a single host report
+//! can't tell a closed port from one the scan didn't reach yet.
+
+use async_trait::async_trait;
+
+use crate::daemon::discovery::types::base::DiscoveryPhase;
+use crate::server::host_changes::service::HostChangeService;
+use crate::server::shared::events::bus::{EventFilter, EventSubscriber};
+use crate::server::shared::events::types::Event;
+
+#[async_trait]
+impl EventSubscriber for HostChangeService {
+    fn event_filter(&self) -> EventFilter {
+        EventFilter::discovery_only(Some(vec![
+            DiscoveryPhase::Complete,
+            DiscoveryPhase::Failed,
+            DiscoveryPhase::Cancelled,
+        ]))
+    }
+
+    async fn handle_events(&self, events: Vec<Event>) -> Result<(), anyhow::Error> {
+        for event in events {
+            let Event::Discovery(discovery_event) = event else {
+                continue;
+            };
+
+            if discovery_event.phase != DiscoveryPhase::Complete {
+                self.discard_session(&discovery_event.session_id).await;
+                continue;
+            }
+
+            if let Err(e) = self.complete_session(&discovery_event).await {
+                tracing::warn!(
+                    session_id = %discovery_event.session_id,
+                    network_id = %discovery_event.network_id,
+                    error = %e,
+                    "Failed to record host changes after discovery completion"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "host-change-discovery-events"
+    }
+}
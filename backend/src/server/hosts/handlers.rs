@@ -27,6 +27,7 @@ use crate::server::{
         api::{CreateHostRequest, DiscoveryHostRequest, HostResponse, UpdateHostRequest},
         base::Host,
         legacy::{HostCreateRequestBody, HostCreateResponse, LegacyHostWithServicesResponse},
+        os::OsFamily,
     },
     shared::types::api::{ApiError, ApiResponse, ApiResult, PaginatedApiResponse},
 };
@@ -62,6 +63,9 @@ pub enum HostOrderField {
     /// Sort by virtualizing service name. Requires JOIN to services table.
     VirtualizedBy,
     NetworkId,
+    LastSeen,
+    /// Sort by inferred OS family
+    OsFamily,
 }
 
 impl OrderField for HostOrderField {
@@ -72,6 +76,8 @@ impl OrderField for HostOrderField {
             Self::Hostname => "hosts.hostname",
             Self::UpdatedAt => "hosts.updated_at",
             Self::NetworkId => "hosts.network_id",
+            Self::LastSeen => "hosts.last_seen",
+            Self::OsFamily => "hosts.os->>'family'",
             Self::VirtualizedBy => "COALESCE(virt_service.name, '')",
         }
     }
@@ -100,6 +106,10 @@ pub struct HostFilterQuery {
     pub ids: Option<Vec<Uuid>>,
     /// Filter by tag IDs (returns hosts that have ANY of the specified tags)
     pub tag_ids: Option<Vec<Uuid>>,
+    /// Filter by liveness (true returns hosts marked offline after missed scans)
+    pub offline: Option<bool>,
+    /// Filter by the OS family inferred by discovery
+    pub os_family: Option<OsFamily>,
     /// Primary ordering field (used for grouping). Always sorts ASC to keep groups together.
     pub group_by: Option<HostOrderField>,
     /// Secondary ordering field (sorting within groups or standalone sort).
@@ -140,6 +150,14 @@ impl FilterQueryExtractor for HostFilterQuery {
             Some(ids) if !ids.is_empty() => filter.entity_ids(ids),
             _ => filter,
         };
+        let filter = match self.offline {
+            Some(offline) => filter.offline_is(offline),
+            None => filter,
+        };
+        let filter = match self.os_family {
+            Some(family) => filter.os_family_is(family),
+            None => filter,
+        };
         // Then apply network filter
         match self.network_id {
             Some(id) if user_network_ids.contains(&id) => filter.network_ids(&[id]),
@@ -404,10 +422,20 @@ async fn create_host(
                 ports,
                 services,
                 if_entries,
+                certificates,
             } = discovery_request;
 
             let host_response = host_service
-                .discover_host(host, interfaces, ports, services, if_entries, entity, None)
+                .discover_host(
+                    host,
+                    interfaces,
+                    ports,
+                    services,
+                    if_entries,
+                    certificates,
+                    entity,
+                    None,
+                )
                 .await?;
 
             let legacy_response = LegacyHostWithServicesResponse::from_host_response(host_response);
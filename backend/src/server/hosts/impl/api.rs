@@ -8,8 +8,11 @@ use validator::Validate;
 
 use crate::server::{
     bindings::r#impl::base::{Binding, BindingBase, BindingType},
+    discovery::r#impl::types::HostnameSource,
     hosts::r#impl::{
         base::{Host, HostBase},
+        hardware::HardwareComponent,
+        os::OsFingerprint,
         virtualization::HostVirtualization,
     },
     if_entries::r#impl::base::{IfAdminStatus, IfEntry, IfEntryBase, IfOperStatus},
@@ -56,6 +59,9 @@ pub struct DiscoveryHostRequest {
     /// SNMP interface entries (ifTable data) - optional, populated when SNMP is enabled
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub if_entries: Vec<crate::server::if_entries::r#impl::base::IfEntry>,
+    /// TLS certificates presented on the host's ports, referencing ports by their IDs in this request
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub certificates: Vec<crate::server::tls_certificates::r#impl::base::TlsCertificate>,
 }
 
 // =============================================================================
@@ -100,6 +106,8 @@ impl InterfaceInput {
                 mac_address: self.mac_address,
                 name: self.name,
                 position: self.position.unwrap_or(0),
+                first_seen: None,
+                last_seen: None,
             },
         }
     }
@@ -208,6 +216,7 @@ impl ServiceInput {
                 source,
                 tags: self.tags,
                 position: self.position.unwrap_or(0),
+                proxy_targets: Vec::new(),
             },
         }
     }
@@ -358,6 +367,7 @@ impl IfEntryInput {
                 cdp_port_id: None,
                 cdp_platform: None,
                 cdp_address: None,
+                fdb_macs: Vec::new(),
             },
         }
     }
@@ -403,6 +413,9 @@ pub struct CreateHostRequest {
     pub chassis_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub snmp_credential_id: Option<Uuid>,
+    /// Hardware inventory (chassis, modules, PSUs, transceivers)
+    #[serde(default)]
+    pub hardware_inventory: Vec<HardwareComponent>,
 
     /// Interfaces to create with this host (client provides UUIDs)
     #[serde(default)]
@@ -500,6 +513,21 @@ pub struct HostResponse {
     pub chassis_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub snmp_credential_id: Option<Uuid>,
+    /// Hardware inventory from ENTITY-MIB (chassis, modules, PSUs, transceivers)
+    #[serde(default)]
+    pub hardware_inventory: Vec<HardwareComponent>,
+
+    // Liveness (maintained by discovery)
+    pub first_seen: Option<DateTime<Utc>>,
+    pub last_seen: Option<DateTime<Utc>>,
+    pub missed_scans: u32,
+    pub offline: bool,
+    /// Hidden for staying offline past retention; un-hidden when seen again
+    pub auto_hidden: bool,
+    /// Operating system inferred by discovery
+    pub os: Option<OsFingerprint>,
+    /// Which lookup produced `hostname` during discovery
+    pub hostname_source: Option<HostnameSource>,
 
     // Children (fetched by service layer)
     pub interfaces: Vec<Interface>,
@@ -533,6 +561,14 @@ impl HostResponse {
             management_url,
             chassis_id,
             snmp_credential_id,
+            hardware_inventory,
+            first_seen,
+            last_seen,
+            missed_scans,
+            offline,
+            auto_hidden,
+            os,
+            hostname_source,
             interfaces: _,
             ports: _,
             services: _,
@@ -559,6 +595,14 @@ impl HostResponse {
                 management_url: management_url.clone(),
                 chassis_id: chassis_id.clone(),
                 snmp_credential_id: *snmp_credential_id,
+                hardware_inventory: hardware_inventory.clone(),
+                first_seen: *first_seen,
+                last_seen: *last_seen,
+                missed_scans: *missed_scans,
+                offline: *offline,
+                auto_hidden: *auto_hidden,
+                os: os.clone(),
+                hostname_source: *hostname_source,
             },
         }
     }
@@ -598,6 +642,14 @@ impl HostResponse {
             management_url,
             chassis_id,
             snmp_credential_id,
+            hardware_inventory,
+            first_seen,
+            last_seen,
+            missed_scans,
+            offline,
+            auto_hidden,
+            os,
+            hostname_source,
         } = base;
 
         Self {
@@ -619,6 +671,14 @@ impl HostResponse {
             management_url,
             chassis_id,
             snmp_credential_id,
+            hardware_inventory,
+            first_seen,
+            last_seen,
+            missed_scans,
+            offline,
+            auto_hidden,
+            os,
+            hostname_source,
             interfaces,
             ports,
             services,
@@ -1,3 +1,6 @@
+use crate::server::discovery::r#impl::types::HostnameSource;
+use crate::server::hosts::r#impl::hardware::HardwareComponent;
+use crate::server::hosts::r#impl::os::OsFingerprint;
 use crate::server::hosts::r#impl::virtualization::HostVirtualization;
 use crate::server::shared::entities::ChangeTriggersTopologyStaleness;
 use crate::server::shared::types::api::deserialize_empty_string_as_none;
@@ -54,6 +57,38 @@ pub struct HostBase {
     /// Per-host SNMP credential override (null = use network default)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub snmp_credential_id: Option<Uuid>,
+    /// Hardware inventory from ENTITY-MIB (chassis, modules, PSUs, transceivers)
+    #[serde(default)]
+    pub hardware_inventory: Vec<HardwareComponent>,
+    /// When discovery first observed this host (null for hosts never discovered)
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub first_seen: Option<DateTime<Utc>>,
+    /// When discovery last observed this host (network scan, ARP/NDP, Docker or self-report)
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub last_seen: Option<DateTime<Utc>>,
+    /// Consecutive network scans covering this host's subnets that didn't find it
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub missed_scans: u32,
+    /// Set once `missed_scans` reaches the network's liveness threshold, cleared when seen again
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub offline: bool,
+    /// Set when the host was hidden for staying offline past the network's retention,
+    /// rather than by hand. Seeing the host again un-hides it and clears this.
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub auto_hidden: bool,
+    /// Operating system inferred by discovery (null until a scan yields any OS signal)
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub os: Option<OsFingerprint>,
+    /// Which lookup produced `hostname` during discovery (null for hostnames set by hand)
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub hostname_source: Option<HostnameSource>,
 }
 
 impl Default for HostBase {
@@ -74,6 +109,34 @@ impl Default for HostBase {
             management_url: None,
             chassis_id: None,
             snmp_credential_id: None,
+            hardware_inventory: Vec::new(),
+            first_seen: None,
+            last_seen: None,
+            missed_scans: 0,
+            offline: false,
+            auto_hidden: false,
+            os: None,
+            hostname_source: None,
+        }
+    }
+}
+
+impl HostBase {
+    /// Hide a host that stayed offline past its network's retention period
+    pub fn auto_hide(&mut self) {
+        self.hidden = true;
+        self.auto_hidden = true;
+    }
+
+    /// Record a discovery sighting. Resets liveness, and un-hides the host if it was only
+    /// hidden for being stale.
+    pub fn mark_seen(&mut self, last_seen: DateTime<Utc>) {
+        self.last_seen = Some(last_seen);
+        self.missed_scans = 0;
+        self.offline = false;
+        if self.auto_hidden {
+            self.hidden = false;
+            self.auto_hidden = false;
         }
     }
 }
@@ -131,8 +194,49 @@ impl ChangeTriggersTopologyStaleness<Host> for Host {
             self.base.hostname != other_host.base.hostname
                 || self.base.virtualization != other_host.base.virtualization
                 || self.base.hidden != other_host.base.hidden
+                || self.base.offline != other_host.base.offline
         } else {
             true
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_auto_hidden_host_reappears_when_seen() {
+        let seen = Utc::now() - Duration::days(40);
+        let mut host = HostBase {
+            last_seen: Some(seen),
+            missed_scans: 5,
+            offline: true,
+            ..Default::default()
+        };
+
+        host.auto_hide();
+        assert!(host.hidden && host.auto_hidden);
+
+        let now = Utc::now();
+        host.mark_seen(now);
+        assert!(!host.hidden && !host.auto_hidden);
+        assert!(!host.offline);
+        assert_eq!(host.missed_scans, 0);
+        assert_eq!(host.last_seen, Some(now));
+    }
+
+    #[test]
+    fn test_manually_hidden_host_stays_hidden_when_seen() {
+        let mut host = HostBase {
+            hidden: true,
+            offline: true,
+            ..Default::default()
+        };
+
+        host.mark_seen(Utc::now());
+        assert!(host.hidden);
+        assert!(!host.offline);
+    }
+}
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use std::hash::Hash;
+use utoipa::ToSchema;
+
+/// Kind of physical component, derived from ENTITY-MIB entPhysicalClass
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+pub enum HardwareComponentClass {
+    Chassis,
+    Module,
+    PowerSupply,
+    /// Pluggable optic (SFP/QSFP) - reported as a serialized port or module
+    Transceiver,
+}
+
+impl HardwareComponentClass {
+    /// Map an entPhysicalClass value to an inventory class.
+    /// Returns None for classes not tracked in the inventory (fans, sensors, containers, ...).
+    /// Ports are only kept when serialized, since that's how agents report transceivers.
+    pub fn from_ent_physical_class(
+        class: i32,
+        descr: Option<&str>,
+        has_serial: bool,
+    ) -> Option<Self> {
+        match class {
+            3 => Some(Self::Chassis),
+            6 => Some(Self::PowerSupply),
+            9 if descr.is_some_and(is_transceiver_descr) => Some(Self::Transceiver),
+            9 => Some(Self::Module),
+            10 if has_serial => Some(Self::Transceiver),
+            _ => None,
+        }
+    }
+}
+
+fn is_transceiver_descr(descr: &str) -> bool {
+    let descr = descr.to_ascii_lowercase();
+    ["sfp", "xfp", "transceiver", "gbic"]
+        .iter()
+        .any(|kw| descr.contains(kw))
+}
+
+/// Physical component from a device's ENTITY-MIB (entPhysicalTable)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+pub struct HardwareComponent {
+    /// entPhysicalIndex - stable identifier within the device
+    pub physical_index: i32,
+    pub class: HardwareComponentClass,
+    /// entPhysicalName (e.g., "Switch 1 - Power Supply A")
+    pub name: Option<String>,
+    /// entPhysicalDescr
+    pub description: Option<String>,
+    /// entPhysicalModelName
+    pub model_name: Option<String>,
+    /// entPhysicalSerialNum
+    pub serial_number: Option<String>,
+    /// entPhysicalMfgName
+    pub manufacturer: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_ent_physical_class() {
+        use HardwareComponentClass as C;
+
+        assert_eq!(C::from_ent_physical_class(3, None, true), Some(C::Chassis));
+        assert_eq!(
+            C::from_ent_physical_class(6, None, false),
+            Some(C::PowerSupply)
+        );
+        assert_eq!(
+            C::from_ent_physical_class(9, Some("Linecard 48x1G"), true),
+            Some(C::Module)
+        );
+        assert_eq!(
+            C::from_ent_physical_class(9, Some("1000BaseSX SFP"), true),
+            Some(C::Transceiver)
+        );
+        assert_eq!(
+            C::from_ent_physical_class(10, None, true),
+            Some(C::Transceiver)
+        );
+        assert_eq!(C::from_ent_physical_class(10, None, false), None);
+        // Fans and sensors are not tracked
+        assert_eq!(C::from_ent_physical_class(7, None, true), None);
+        assert_eq!(C::from_ent_physical_class(8, None, true), None);
+    }
+}
@@ -123,6 +123,8 @@ impl LegacyInterface {
                 mac_address: self.mac_address,
                 name: self.name,
                 position: 0,
+                first_seen: None,
+                last_seen: None,
             },
         }
     }
@@ -279,6 +281,7 @@ impl LegacyService {
                 source: EntitySource::Discovery { metadata: vec![] },
                 tags: self.tags,
                 position: 0,
+                proxy_targets: Vec::new(),
             },
         }
     }
@@ -338,6 +341,14 @@ impl LegacyHostWithServicesRequest {
                 management_url: None,
                 chassis_id: None,
                 snmp_credential_id: None,
+                hardware_inventory: Vec::new(),
+                first_seen: None,
+                last_seen: None,
+                missed_scans: 0,
+                offline: false,
+                auto_hidden: false,
+                os: None,
+                hostname_source: None,
             },
         };
 
@@ -347,6 +358,7 @@ impl LegacyHostWithServicesRequest {
             ports,
             services,
             if_entries: vec![], // Legacy requests don't include SNMP data
+            certificates: vec![],
         }
     }
 }
@@ -1,6 +1,8 @@
 pub mod api;
 pub mod base;
 pub mod handlers;
+pub mod hardware;
 pub mod legacy;
+pub mod os;
 pub mod storage;
 pub mod virtualization;
@@ -0,0 +1,598 @@
+use crate::server::services::r#impl::patterns::MatchConfidence;
+use serde::{Deserialize, Serialize};
+use std::hash::Hash;
+use strum::{Display as StrumDisplay, EnumIter, EnumString, IntoStaticStr};
+use utoipa::ToSchema;
+
+/// Operating system family of a host
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    ToSchema,
+    StrumDisplay,
+    EnumString,
+    EnumIter,
+    IntoStaticStr,
+)]
+pub enum OsFamily {
+    Linux,
+    Windows,
+    MacOs,
+    FreeBsd,
+    OpenBsd,
+    Solaris,
+    /// Cisco IOS, IOS-XE and NX-OS
+    CiscoIos,
+    JunOs,
+    RouterOs,
+    Esxi,
+}
+
+impl OsFamily {
+    /// Family for a Rust target OS name (`std::env::consts::OS`)
+    pub fn from_target_os(os: &str) -> Option<Self> {
+        match os {
+            "linux" | "android" => Some(Self::Linux),
+            "windows" => Some(Self::Windows),
+            "macos" => Some(Self::MacOs),
+            "freebsd" => Some(Self::FreeBsd),
+            "openbsd" => Some(Self::OpenBsd),
+            "solaris" | "illumos" => Some(Self::Solaris),
+            _ => None,
+        }
+    }
+}
+
+/// Operating system inferred by discovery from what a host exposes on the network
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+pub struct OsFingerprint {
+    pub family: OsFamily,
+    /// Release or version when a signal carries one (e.g. "Ubuntu 24.04", "11 (10.0.22631)")
+    pub version: Option<String>,
+    /// How strongly the signals agree on the family
+    pub confidence: MatchConfidence,
+    /// Signals that voted for the family, strongest first
+    pub evidence: Vec<String>,
+}
+
+/// TTL and window size of a SYN-ACK received from the host
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TcpFingerprint {
+    pub ttl: u8,
+    pub window: u16,
+}
+
+/// Version from an NTLMSSP challenge (SMB session setup)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NtlmVersion {
+    pub major: u8,
+    pub minor: u8,
+    pub build: u16,
+}
+
+/// Everything discovery observed about a host that says something about its OS
+#[derive(Debug, Clone, Default)]
+pub struct OsSignals {
+    pub tcp: Option<TcpFingerprint>,
+    /// SSH identification string, e.g. "SSH-2.0-OpenSSH_9.6p1 Ubuntu-3ubuntu13"
+    pub ssh_banner: Option<String>,
+    pub ntlm: Option<NtlmVersion>,
+    /// SNMP sysDescr.0
+    pub sys_descr: Option<String>,
+    /// Server headers from HTTP endpoint responses
+    pub http_servers: Vec<String>,
+    /// Company name registered for the MAC address OUI
+    pub mac_vendor: Option<String>,
+}
+
+struct Vote {
+    family: OsFamily,
+    weight: u32,
+    version: Option<String>,
+    evidence: String,
+}
+
+impl Vote {
+    fn new(family: OsFamily, weight: u32, evidence: String) -> Self {
+        Self {
+            family,
+            weight,
+            version: None,
+            evidence,
+        }
+    }
+
+    fn version(mut self, version: Option<String>) -> Self {
+        self.version = version;
+        self
+    }
+}
+
+// Vote weights. A host describing itself (SNMP, NTLM) outweighs everything else combined;
+// a distro-tagged SSH banner beats an HTTP header, which beats the TCP stack and MAC vendor.
+const SELF_DESCRIBED: u32 = 5;
+const SSH_BANNER: u32 = 3;
+const HTTP_SERVER: u32 = 2;
+
+impl OsFingerprint {
+    /// Infer an OS from the observed signals. Each signal votes for a family with a weight;
+    /// the family with the most weight wins, and the margin over the runner-up sets the
+    /// confidence. Returns None if no signal says anything about the OS.
+    pub fn infer(signals: &OsSignals) -> Option<Self> {
+        let mut votes: Vec<Vote> = Vec::new();
+        votes.extend(signals.sys_descr.as_deref().and_then(sys_descr_vote));
+        votes.extend(signals.ntlm.map(ntlm_vote));
+        votes.extend(signals.ssh_banner.as_deref().and_then(ssh_vote));
+        votes.extend(
+            signals
+                .http_servers
+                .iter()
+                .filter_map(|h| http_server_vote(h)),
+        );
+        votes.extend(signals.tcp.and_then(tcp_vote));
+        votes.extend(signals.mac_vendor.as_deref().and_then(mac_vendor_vote));
+
+        // Families in order of their first (strongest) vote, so ties go to the stronger signal
+        let mut scores: Vec<(OsFamily, u32)> = Vec::new();
+        for vote in &votes {
+            match scores.iter_mut().find(|(family, _)| *family == vote.family) {
+                Some((_, score)) => *score += vote.weight,
+                None => scores.push((vote.family, vote.weight)),
+            }
+        }
+
+        let (family, score) = scores
+            .iter()
+            .rev()
+            .max_by_key(|(_, score)| *score)
+            .copied()?;
+        let runner_up = scores
+            .iter()
+            .filter(|(f, _)| *f != family)
+            .map(|(_, score)| *score)
+            .max()
+            .unwrap_or(0);
+
+        let confidence = match score.saturating_sub(runner_up) {
+            5.. => MatchConfidence::Certain,
+            3..=4 => MatchConfidence::High,
+            2 => MatchConfidence::Medium,
+            _ => MatchConfidence::Low,
+        };
+
+        let mut winning: Vec<Vote> = votes.into_iter().filter(|v| v.family == family).collect();
+        winning.sort_by_key(|v| std::cmp::Reverse(v.weight));
+
+        Some(Self {
+            family,
+            version: winning.iter().find_map(|v| v.version.clone()),
+            confidence,
+            evidence: winning.into_iter().map(|v| v.evidence).collect(),
+        })
+    }
+}
+
+/// Token following `marker` in `text`, up to whitespace or punctuation that ends a field
+fn token_after(text: &str, marker: &str) -> Option<String> {
+    let start = text.find(marker)? + marker.len();
+    let token: String = text[start..]
+        .trim_start()
+        .chars()
+        .take_while(|c| !c.is_whitespace() && !matches!(c, ',' | ';' | ':' | ']'))
+        .collect();
+    (!token.is_empty()).then_some(token)
+}
+
+fn sys_descr_vote(sys_descr: &str) -> Option<Vote> {
+    let evidence = format!(
+        "SNMP sysDescr: {}",
+        sys_descr.lines().next().unwrap_or_default()
+    );
+    let lower = sys_descr.to_ascii_lowercase();
+
+    let (family, version) = if lower.contains("cisco ios")
+        || lower.contains("cisco internetwork operating system")
+        || lower.contains("cisco nx-os")
+    {
+        (OsFamily::CiscoIos, token_after(sys_descr, "Version "))
+    } else if lower.contains("junos") {
+        (
+            OsFamily::JunOs,
+            token_after(&lower, "junos").map(|v| v.to_uppercase()),
+        )
+    } else if lower.contains("routeros") {
+        (OsFamily::RouterOs, None)
+    } else if lower.contains("vmware esxi") {
+        (OsFamily::Esxi, token_after(sys_descr, "ESXi"))
+    } else if lower.contains("windows") {
+        let version = token_after(sys_descr, "Windows Version ").map(|v| {
+            let build = token_after(sys_descr, "Build ").and_then(|b| b.parse().ok());
+            match (v.split_once('.'), build) {
+                (Some((major, minor)), Some(build)) => {
+                    match (major.parse::<u8>(), minor.parse::<u8>()) {
+                        (Ok(major), Ok(minor)) => windows_version(NtlmVersion {
+                            major,
+                            minor,
+                            build,
+                        }),
+                        _ => v,
+                    }
+                }
+                _ => v,
+            }
+        });
+        (OsFamily::Windows, version)
+    } else if lower.starts_with("darwin") || lower.contains("darwin kernel") {
+        let version = token_after(sys_descr, "Darwin Kernel Version ")
+            .and_then(|kernel| macos_from_darwin(&kernel));
+        (OsFamily::MacOs, version)
+    } else if lower.starts_with("freebsd") {
+        (
+            OsFamily::FreeBsd,
+            sys_descr.split_whitespace().nth(2).map(str::to_string),
+        )
+    } else if lower.starts_with("openbsd") {
+        (
+            OsFamily::OpenBsd,
+            sys_descr.split_whitespace().nth(2).map(str::to_string),
+        )
+    } else if lower.starts_with("sunos") {
+        (
+            OsFamily::Solaris,
+            sys_descr.split_whitespace().nth(2).map(str::to_string),
+        )
+    } else if lower.starts_with("linux") {
+        let kernel = sys_descr.split_whitespace().nth(2);
+        (OsFamily::Linux, kernel.map(|k| format!("kernel {}", k)))
+    } else {
+        return None;
+    };
+
+    Some(Vote::new(family, SELF_DESCRIBED, evidence).version(version))
+}
+
+/// macOS release for a Darwin kernel version (Darwin 20 is macOS 11)
+fn macos_from_darwin(kernel: &str) -> Option<String> {
+    let major: u32 = kernel.split('.').next()?.parse().ok()?;
+    (major >= 20).then(|| format!("{} (Darwin {})", major - 9, kernel))
+}
+
+fn ntlm_vote(version: NtlmVersion) -> Vote {
+    // Samba fills in the version fields but leaves the build number at zero
+    if version.build == 0 {
+        return Vote::new(
+            OsFamily::Linux,
+            1,
+            "SMB served by Samba (NTLM build 0)".to_string(),
+        );
+    }
+
+    Vote::new(
+        OsFamily::Windows,
+        SELF_DESCRIBED,
+        format!(
+            "SMB NTLM version {}.{}.{}",
+            version.major, version.minor, version.build
+        ),
+    )
+    .version(Some(windows_version(version)))
+}
+
+/// Windows release name for an NT version, e.g. "11 (10.0.22631)"
+fn windows_version(version: NtlmVersion) -> String {
+    let NtlmVersion {
+        mut major,
+        mut minor,
+        build,
+    } = version;
+    // Windows 10 and later still report 6.3 in some places (e.g. SNMP); the build number doesn't lie
+    if build >= 10240 {
+        (major, minor) = (10, 0);
+    }
+    let release = match (major, minor) {
+        (5, 1) => "XP",
+        (5, 2) => "Server 2003",
+        (6, 0) => "Vista / Server 2008",
+        (6, 1) => "7 / Server 2008 R2",
+        (6, 2) => "8 / Server 2012",
+        (6, 3) => "8.1 / Server 2012 R2",
+        (10, 0) => match build {
+            14393 => "10 / Server 2016",
+            17763 => "10 / Server 2019",
+            20348 => "Server 2022",
+            26100.. => "11 / Server 2025",
+            22000.. => "11",
+            _ => "10",
+        },
+        _ => return format!("{}.{}.{}", major, minor, build),
+    };
+    format!("{} ({}.{}.{})", release, major, minor, build)
+}
+
+fn ssh_vote(banner: &str) -> Option<Vote> {
+    let banner = banner.lines().next().unwrap_or_default().trim();
+    let evidence = format!("SSH banner: {}", banner);
+    let openssh = token_after(banner, "OpenSSH_");
+    let lower = banner.to_ascii_lowercase();
+
+    let vote = if lower.contains("ubuntu") {
+        let release = openssh.as_deref().and_then(|v| match v {
+            "9.6p1" => Some("24.04"),
+            "8.9p1" => Some("22.04"),
+            "8.2p1" => Some("20.04"),
+            "7.6p1" => Some("18.04"),
+            _ => None,
+        });
+        Vote::new(OsFamily::Linux, SSH_BANNER, evidence)
+            .version(Some(distro_version("Ubuntu", release)))
+    } else if lower.contains("debian") {
+        let release = openssh.as_deref().and_then(|v| match v {
+            "9.2p1" => Some("12"),
+            "8.4p1" => Some("11"),
+            "7.9p1" => Some("10"),
+            "7.4p1" => Some("9"),
+            _ => None,
+        });
+        Vote::new(OsFamily::Linux, SSH_BANNER, evidence)
+            .version(Some(distro_version("Debian", release)))
+    } else if lower.contains("raspbian") {
+        Vote::new(OsFamily::Linux, SSH_BANNER, evidence)
+            .version(Some("Raspberry Pi OS".to_string()))
+    } else if lower.contains("freebsd") {
+        Vote::new(OsFamily::FreeBsd, SSH_BANNER, evidence)
+    } else if lower.contains("openssh_for_windows") {
+        Vote::new(OsFamily::Windows, SSH_BANNER, evidence)
+    } else if lower.contains("cisco") {
+        Vote::new(OsFamily::CiscoIos, SSH_BANNER, evidence)
+    } else if lower.contains("rosssh") {
+        Vote::new(OsFamily::RouterOs, SSH_BANNER, evidence)
+    } else if lower.contains("dropbear") {
+        // Dropbear is almost exclusively used on embedded Linux
+        Vote::new(OsFamily::Linux, 1, evidence)
+    } else {
+        return None;
+    };
+
+    Some(vote)
+}
+
+fn distro_version(distro: &str, release: Option<&str>) -> String {
+    match release {
+        Some(release) => format!("{} {}", distro, release),
+        None => distro.to_string(),
+    }
+}
+
+fn http_server_vote(server: &str) -> Option<Vote> {
+    let evidence = format!("HTTP Server header: {}", server);
+    let lower = server.to_ascii_lowercase();
+
+    if lower.contains("microsoft-iis") || lower.contains("microsoft-httpapi") {
+        return Some(Vote::new(OsFamily::Windows, HTTP_SERVER, evidence));
+    }
+    if lower.contains("(win64)") || lower.contains("(win32)") {
+        return Some(Vote::new(OsFamily::Windows, HTTP_SERVER, evidence));
+    }
+    if lower.contains("(freebsd)") {
+        return Some(Vote::new(OsFamily::FreeBsd, HTTP_SERVER, evidence));
+    }
+
+    let distro = [
+        ("(ubuntu)", "Ubuntu"),
+        ("(debian)", "Debian"),
+        ("(raspbian)", "Raspberry Pi OS"),
+        ("(centos)", "CentOS"),
+        ("(red hat", "Red Hat Enterprise Linux"),
+        ("(rocky linux)", "Rocky Linux"),
+        ("(almalinux)", "AlmaLinux"),
+        ("(fedora)", "Fedora"),
+    ]
+    .into_iter()
+    .find(|(marker, _)| lower.contains(marker))?;
+
+    Some(Vote::new(OsFamily::Linux, HTTP_SERVER, evidence).version(Some(distro.1.to_string())))
+}
+
+fn tcp_vote(tcp: TcpFingerprint) -> Option<Vote> {
+    // Hosts start from a TTL of 64, 128 or 255; the received value is that minus the hops
+    let vote = match tcp.ttl {
+        0..=64 if tcp.window == 65535 => Vote::new(
+            OsFamily::MacOs,
+            1,
+            format!("TCP TTL {} (initial 64), window 65535", tcp.ttl),
+        ),
+        0..=64 => Vote::new(
+            OsFamily::Linux,
+            1,
+            format!("TCP TTL {} (initial 64)", tcp.ttl),
+        ),
+        65..=128 => Vote::new(
+            OsFamily::Windows,
+            2,
+            format!("TCP TTL {} (initial 128)", tcp.ttl),
+        ),
+        // Cisco IOS advertises a 4128 byte window
+        _ if tcp.window == 4128 => Vote::new(
+            OsFamily::CiscoIos,
+            1,
+            format!("TCP TTL {} (initial 255), window 4128", tcp.ttl),
+        ),
+        _ => return None,
+    };
+    Some(vote)
+}
+
+fn mac_vendor_vote(vendor: &str) -> Option<Vote> {
+    let lower = vendor.to_ascii_lowercase();
+    let (family, weight) = if lower.contains("raspberry pi")
+        || lower.contains("synology")
+        || lower.contains("qnap")
+        || lower.contains("ubiquiti")
+    {
+        (OsFamily::Linux, 2)
+    } else if lower.contains("mikrotik") || lower.contains("routerboard") {
+        (OsFamily::RouterOs, 2)
+    } else if lower.contains("juniper") {
+        (OsFamily::JunOs, 2)
+    } else if lower.contains("cisco") {
+        (OsFamily::CiscoIos, 1)
+    } else if lower.starts_with("apple") {
+        (OsFamily::MacOs, 1)
+    } else {
+        return None;
+    };
+
+    Some(Vote::new(family, weight, format!("MAC vendor: {}", vendor)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_infer_without_signals() {
+        assert_eq!(OsFingerprint::infer(&OsSignals::default()), None);
+
+        let signals = OsSignals {
+            http_servers: vec!["nginx/1.25.3".to_string()],
+            mac_vendor: Some("Intel Corporate".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(OsFingerprint::infer(&signals), None);
+    }
+
+    #[test]
+    fn test_infer_ubuntu_from_ssh_and_ttl() {
+        let signals = OsSignals {
+            tcp: Some(TcpFingerprint {
+                ttl: 63,
+                window: 64240,
+            }),
+            ssh_banner: Some("SSH-2.0-OpenSSH_9.6p1 Ubuntu-3ubuntu13.5".to_string()),
+            ..Default::default()
+        };
+        let os = OsFingerprint::infer(&signals).unwrap();
+        assert_eq!(os.family, OsFamily::Linux);
+        assert_eq!(os.version.as_deref(), Some("Ubuntu 24.04"));
+        assert_eq!(os.confidence, MatchConfidence::High);
+        assert_eq!(os.evidence.len(), 2);
+        assert!(os.evidence[0].starts_with("SSH banner"));
+    }
+
+    #[test]
+    fn test_infer_windows_from_ntlm() {
+        let signals = OsSignals {
+            tcp: Some(TcpFingerprint {
+                ttl: 127,
+                window: 65535,
+            }),
+            ntlm: Some(NtlmVersion {
+                major: 10,
+                minor: 0,
+                build: 22631,
+            }),
+            ..Default::default()
+        };
+        let os = OsFingerprint::infer(&signals).unwrap();
+        assert_eq!(os.family, OsFamily::Windows);
+        assert_eq!(os.version.as_deref(), Some("11 (10.0.22631)"));
+        assert_eq!(os.confidence, MatchConfidence::Certain);
+    }
+
+    #[test]
+    fn test_infer_sys_descr_outweighs_other_signals() {
+        let signals = OsSignals {
+            sys_descr: Some(
+                "Cisco IOS Software, C2960 Software (C2960-LANBASEK9-M), Version 15.0(2)SE11, RELEASE SOFTWARE (fc3)"
+                    .to_string(),
+            ),
+            tcp: Some(TcpFingerprint {
+                ttl: 64,
+                window: 29200,
+            }),
+            http_servers: vec!["Apache/2.4.41 (Ubuntu)".to_string()],
+            ..Default::default()
+        };
+        let os = OsFingerprint::infer(&signals).unwrap();
+        assert_eq!(os.family, OsFamily::CiscoIos);
+        assert_eq!(os.version.as_deref(), Some("15.0(2)SE11"));
+        assert_eq!(os.confidence, MatchConfidence::Medium);
+    }
+
+    #[test]
+    fn test_sys_descr_versions() {
+        let cases = [
+            (
+                "Linux nas 5.15.0-91-generic #101-Ubuntu SMP Tue Nov 14 13:30:08 UTC 2023 x86_64",
+                OsFamily::Linux,
+                Some("kernel 5.15.0-91-generic"),
+            ),
+            (
+                "Hardware: Intel64 Family 6 Model 85 - Software: Windows Version 6.3 (Build 17763 Multiprocessor Free)",
+                OsFamily::Windows,
+                Some("10 / Server 2019 (10.0.17763)"),
+            ),
+            (
+                "Darwin mac.local 23.1.0 Darwin Kernel Version 23.1.0: Mon Oct  9 21:27:24 PDT 2023",
+                OsFamily::MacOs,
+                Some("14 (Darwin 23.1.0)"),
+            ),
+            (
+                "FreeBSD pfsense 14.0-CURRENT FreeBSD 14.0-CURRENT amd64",
+                OsFamily::FreeBsd,
+                Some("14.0-CURRENT"),
+            ),
+            ("RouterOS RB4011iGS+", OsFamily::RouterOs, None),
+            (
+                "Juniper Networks, Inc. ex2300-24t Ethernet Switch, kernel JUNOS 21.4R3-S4.9",
+                OsFamily::JunOs,
+                Some("21.4R3-S4.9"),
+            ),
+        ];
+
+        for (sys_descr, family, version) in cases {
+            let vote = sys_descr_vote(sys_descr).unwrap();
+            assert_eq!(vote.family, family, "{}", sys_descr);
+            assert_eq!(vote.version.as_deref(), version, "{}", sys_descr);
+        }
+    }
+
+    #[test]
+    fn test_samba_is_not_windows() {
+        let signals = OsSignals {
+            ntlm: Some(NtlmVersion {
+                major: 6,
+                minor: 1,
+                build: 0,
+            }),
+            mac_vendor: Some("Synology Incorporated".to_string()),
+            ..Default::default()
+        };
+        let os = OsFingerprint::infer(&signals).unwrap();
+        assert_eq!(os.family, OsFamily::Linux);
+        assert_eq!(os.confidence, MatchConfidence::High);
+    }
+
+    #[test]
+    fn test_conflicting_signals_lower_confidence() {
+        let signals = OsSignals {
+            tcp: Some(TcpFingerprint {
+                ttl: 128,
+                window: 8192,
+            }),
+            http_servers: vec!["Apache/2.4.58 (Debian)".to_string()],
+            ..Default::default()
+        };
+        let os = OsFingerprint::infer(&signals).unwrap();
+        // Tie at 2 goes to the stronger signal type
+        assert_eq!(os.family, OsFamily::Linux);
+        assert_eq!(os.version.as_deref(), Some("Debian"));
+        assert_eq!(os.confidence, MatchConfidence::Low);
+    }
+}
@@ -5,8 +5,11 @@ use sqlx::postgres::PgRow;
 use uuid::Uuid;
 
 use crate::server::{
+    discovery::r#impl::types::HostnameSource,
     hosts::r#impl::{
         base::{Host, HostBase},
+        hardware::{HardwareComponent, HardwareComponentClass},
+        os::OsFingerprint,
         virtualization::HostVirtualization,
     },
     shared::{
@@ -27,6 +30,17 @@ pub struct HostCsvRow {
     pub network_id: Uuid,
     pub source: String,
     pub hidden: bool,
+    pub chassis_model: Option<String>,
+    pub chassis_serial_number: Option<String>,
+    pub chassis_manufacturer: Option<String>,
+    pub hardware_inventory: Option<String>,
+    pub first_seen: Option<DateTime<Utc>>,
+    pub last_seen: Option<DateTime<Utc>>,
+    pub offline: bool,
+    pub os_family: Option<String>,
+    pub os_version: Option<String>,
+    pub os_confidence: Option<String>,
+    pub hostname_source: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -91,6 +105,14 @@ impl Storable for Host {
                     management_url,
                     chassis_id,
                     snmp_credential_id,
+                    hardware_inventory,
+                    first_seen,
+                    last_seen,
+                    missed_scans,
+                    offline,
+                    auto_hidden,
+                    os,
+                    hostname_source,
                 },
         } = self.clone();
 
@@ -113,6 +135,14 @@ impl Storable for Host {
                 "management_url",
                 "chassis_id",
                 "snmp_credential_id",
+                "hardware_inventory",
+                "first_seen",
+                "last_seen",
+                "missed_scans",
+                "offline",
+                "auto_hidden",
+                "os",
+                "hostname_source",
             ],
             vec![
                 SqlValue::Uuid(id),
@@ -132,6 +162,14 @@ impl Storable for Host {
                 SqlValue::OptionalString(management_url),
                 SqlValue::OptionalString(chassis_id),
                 SqlValue::OptionalUuid(snmp_credential_id),
+                SqlValue::JsonValue(serde_json::to_value(&hardware_inventory)?),
+                SqlValue::OptionTimestamp(first_seen),
+                SqlValue::OptionTimestamp(last_seen),
+                SqlValue::I32(missed_scans.try_into().unwrap_or(i32::MAX)),
+                SqlValue::Bool(offline),
+                SqlValue::Bool(auto_hidden),
+                SqlValue::JsonValue(serde_json::to_value(&os)?),
+                SqlValue::OptionalString(hostname_source.map(|s| s.to_string())),
             ],
         ))
     }
@@ -144,6 +182,17 @@ impl Storable for Host {
         let virtualization: Option<HostVirtualization> =
             serde_json::from_value(row.get::<serde_json::Value, _>("virtualization"))
                 .map_err(|e| anyhow::anyhow!("Failed to deserialize virtualization: {}", e))?;
+        let hardware_inventory: Vec<HardwareComponent> =
+            serde_json::from_value(row.get::<serde_json::Value, _>("hardware_inventory"))
+                .map_err(|e| anyhow::anyhow!("Failed to deserialize hardware_inventory: {}", e))?;
+        let os: Option<OsFingerprint> = serde_json::from_value(
+            row.get::<Option<serde_json::Value>, _>("os")
+                .unwrap_or_default(),
+        )
+        .map_err(|e| anyhow::anyhow!("Failed to deserialize os: {}", e))?;
+        let hostname_source: Option<HostnameSource> = row
+            .get::<Option<String>, _>("hostname_source")
+            .and_then(|s| s.parse().ok());
 
         Ok(Host {
             id: row.get("id"),
@@ -165,6 +214,14 @@ impl Storable for Host {
                 management_url: row.get("management_url"),
                 chassis_id: row.get("chassis_id"),
                 snmp_credential_id: row.get("snmp_credential_id"),
+                hardware_inventory,
+                first_seen: row.get("first_seen"),
+                last_seen: row.get("last_seen"),
+                missed_scans: row.get::<i32, _>("missed_scans").max(0) as u32,
+                offline: row.get("offline"),
+                auto_hidden: row.get("auto_hidden"),
+                os,
+                hostname_source,
             },
         })
     }
@@ -174,6 +231,12 @@ impl Entity for Host {
     type CsvRow = HostCsvRow;
 
     fn to_csv_row(&self) -> Self::CsvRow {
+        let chassis = self
+            .base
+            .hardware_inventory
+            .iter()
+            .find(|c| c.class == HardwareComponentClass::Chassis);
+
         HostCsvRow {
             id: self.id,
             name: self.base.name.clone(),
@@ -182,6 +245,23 @@ impl Entity for Host {
             network_id: self.base.network_id,
             source: format!("{:?}", self.base.source),
             hidden: self.base.hidden,
+            chassis_model: chassis.and_then(|c| c.model_name.clone()),
+            chassis_serial_number: chassis.and_then(|c| c.serial_number.clone()),
+            chassis_manufacturer: chassis.and_then(|c| c.manufacturer.clone()),
+            hardware_inventory: (!self.base.hardware_inventory.is_empty())
+                .then(|| serde_json::to_string(&self.base.hardware_inventory).ok())
+                .flatten(),
+            first_seen: self.base.first_seen,
+            last_seen: self.base.last_seen,
+            offline: self.base.offline,
+            os_family: self.base.os.as_ref().map(|os| os.family.to_string()),
+            os_version: self.base.os.as_ref().and_then(|os| os.version.clone()),
+            os_confidence: self
+                .base
+                .os
+                .as_ref()
+                .map(|os| os.confidence.as_str().to_string()),
+            hostname_source: self.base.hostname_source.map(|s| s.to_string()),
             created_at: self.created_at,
             updated_at: self.updated_at,
         }
@@ -231,6 +311,21 @@ impl Entity for Host {
     fn preserve_immutable_fields(&mut self, existing: &Self) {
         // source is set at creation time (Manual or Discovery), cannot be changed
         self.base.source = existing.base.source.clone();
+        // Liveness is only tracked by discovery
+        self.base.first_seen = existing.base.first_seen;
+        self.base.last_seen = existing.base.last_seen;
+        self.base.missed_scans = existing.base.missed_scans;
+        self.base.offline = existing.base.offline;
+        // Un-hiding by hand ends an automatic hide
+        self.base.auto_hidden = existing.base.auto_hidden && self.base.hidden;
+        // OS is only inferred by discovery
+        self.base.os = existing.base.os.clone();
+        // The source only describes the hostname discovery found
+        self.base.hostname_source = if self.base.hostname == existing.base.hostname {
+            existing.base.hostname_source
+        } else {
+            None
+        };
         self.created_at = existing.created_at;
         self.updated_at = existing.updated_at;
     }
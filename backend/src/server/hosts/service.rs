@@ -2,6 +2,8 @@ use crate::server::{
     auth::middleware::auth::AuthenticatedEntity,
     bindings::r#impl::base::{Binding, BindingType},
     daemons::{r#impl::base::Daemon, service::DaemonService},
+    discovery::r#impl::types::DiscoveryType,
+    host_changes::{r#impl::diff::HostSnapshot, service::HostChangeService},
     hosts::r#impl::{
         api::{
             BindingInput, ConflictBehavior, CreateHostRequest, HostResponse, InterfaceInput,
@@ -11,13 +13,14 @@ use crate::server::{
     },
     if_entries::{r#impl::base::IfEntry, service::IfEntryService},
     interfaces::{r#impl::base::Interface, service::InterfaceService},
+    networks::{r#impl::Network, service::NetworkService},
     ports::{r#impl::base::Port, service::PortService},
     services::{r#impl::base::Service, service::ServiceService},
     shared::{
         entities::{ChangeTriggersTopologyStaleness, EntityDiscriminants},
         events::{
             bus::EventBus,
-            types::{EntityEvent, EntityOperation},
+            types::{DiscoverySessionEvent, EntityEvent, EntityOperation},
         },
         position::resolve_and_validate_input_positions,
         services::traits::{ChildCrudService, CrudService, EventBusService},
@@ -33,11 +36,15 @@ use crate::server::{
     },
     snmp_credentials::resolution::{lldp::LldpResolver, resolver::LldpResolverImpl},
     tags::entity_tags::EntityTagService,
+    tls_certificates::{r#impl::base::TlsCertificate, service::TlsCertificateService},
 };
 use anyhow::{Error, Result, anyhow};
 use async_trait::async_trait;
 use chrono::Utc;
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 use strum::IntoDiscriminant;
 use tokio::sync::Mutex;
 use uuid::Uuid;
@@ -49,6 +56,9 @@ pub struct HostService {
     service_service: Arc<ServiceService>,
     if_entry_service: Arc<IfEntryService>,
     pub daemon_service: Arc<DaemonService>,
+    host_change_service: Arc<HostChangeService>,
+    tls_certificate_service: Arc<TlsCertificateService>,
+    network_service: Arc<NetworkService>,
     host_locks: Arc<Mutex<HashMap<Uuid, Arc<Mutex<()>>>>>,
     event_bus: Arc<EventBus>,
     entity_tag_service: Arc<EntityTagService>,
@@ -222,6 +232,9 @@ impl HostService {
         service_service: Arc<ServiceService>,
         if_entry_service: Arc<IfEntryService>,
         daemon_service: Arc<DaemonService>,
+        host_change_service: Arc<HostChangeService>,
+        tls_certificate_service: Arc<TlsCertificateService>,
+        network_service: Arc<NetworkService>,
         event_bus: Arc<EventBus>,
         entity_tag_service: Arc<EntityTagService>,
     ) -> Self {
@@ -232,6 +245,9 @@ impl HostService {
             service_service,
             if_entry_service,
             daemon_service,
+            host_change_service,
+            tls_certificate_service,
+            network_service,
             host_locks: Arc::new(Mutex::new(HashMap::new())),
             event_bus,
             entity_tag_service,
@@ -446,6 +462,7 @@ impl HostService {
             management_url,
             chassis_id,
             snmp_credential_id,
+            hardware_inventory,
             interfaces: interface_inputs,
             ports: port_inputs,
             services: service_inputs,
@@ -482,6 +499,14 @@ impl HostService {
             management_url,
             chassis_id,
             snmp_credential_id,
+            hardware_inventory,
+            first_seen: None,
+            last_seen: None,
+            missed_scans: 0,
+            offline: false,
+            auto_hidden: false,
+            os: None,
+            hostname_source: None,
         };
         let host = Host::new(host_base);
 
@@ -628,7 +653,10 @@ impl HostService {
                 if let Some(existing_iface) =
                     self.interface_service.get_by_id(&interface.id).await?
                 {
-                    created_interfaces.push(existing_iface);
+                    created_interfaces.push(
+                        self.mark_interface_seen(existing_iface, &interface, &authentication)
+                            .await?,
+                    );
                     continue;
                 }
 
@@ -642,19 +670,26 @@ impl HostService {
                     .into_iter()
                     .find(|i| i.base.ip_address == interface.base.ip_address)
                 {
-                    created_interfaces.push(existing_iface);
+                    created_interfaces.push(
+                        self.mark_interface_seen(existing_iface, &interface, &authentication)
+                            .await?,
+                    );
                     continue;
                 }
 
                 // MAC fallback: find by (host_id, mac_address) when subnet differs
-                // This handles cases where subnet_id changed between discovery runs
+                // This handles cases where subnet_id changed between discovery runs.
+                // Only same-family addresses match - a dual-stack NIC has one MAC but
+                // separate IPv4 and IPv6 interfaces.
                 if let Some(mac) = &interface.base.mac_address {
                     let mac_filter =
                         StorableFilter::<Interface>::new_from_host_ids(&[interface.base.host_id])
                             .mac_address(mac);
                     let existing_by_mac: Vec<Interface> =
                         self.interface_service.get_all(mac_filter).await?;
-                    if let Some(existing_iface) = existing_by_mac.into_iter().next() {
+                    if let Some(existing_iface) = existing_by_mac.into_iter().find(|i| {
+                        i.base.ip_address.is_ipv4() == interface.base.ip_address.is_ipv4()
+                    }) {
                         tracing::debug!(
                             interface_ip = %interface.base.ip_address,
                             interface_mac = %mac,
@@ -662,7 +697,10 @@ impl HostService {
                             incoming_subnet_id = %interface.base.subnet_id,
                             "Found existing interface by MAC address (subnet_id differs)"
                         );
-                        created_interfaces.push(existing_iface);
+                        created_interfaces.push(
+                            self.mark_interface_seen(existing_iface, &interface, &authentication)
+                                .await?,
+                        );
                         continue;
                     }
                 }
@@ -811,6 +849,7 @@ impl HostService {
                 source: EntitySource::Discovery { metadata: vec![] },
                 tags: Vec::new(),
                 position: 0,
+                proxy_targets: Vec::new(),
             });
 
             // The singleton upsert in service.create() will merge bindings
@@ -912,6 +951,13 @@ impl HostService {
             .into());
         }
 
+        // The source only describes the hostname discovery found
+        let hostname_source = if hostname == existing.base.hostname {
+            existing.base.hostname_source
+        } else {
+            None
+        };
+
         let mut updated_host = Host {
             id,
             created_at: existing.created_at,
@@ -933,6 +979,17 @@ impl HostService {
                 management_url: existing.base.management_url.clone(),
                 chassis_id: existing.base.chassis_id.clone(),
                 snmp_credential_id: existing.base.snmp_credential_id,
+                hardware_inventory: existing.base.hardware_inventory.clone(),
+                // Liveness is only tracked by discovery
+                first_seen: existing.base.first_seen,
+                last_seen: existing.base.last_seen,
+                missed_scans: existing.base.missed_scans,
+                offline: existing.base.offline,
+                // Un-hiding by hand ends an automatic hide
+                auto_hidden: existing.base.auto_hidden && hidden,
+                // OS is only inferred by discovery
+                os: existing.base.os.clone(),
+                hostname_source,
             },
         };
 
@@ -1192,14 +1249,43 @@ impl HostService {
     #[allow(clippy::too_many_arguments)]
     pub async fn discover_host(
         &self,
-        host: Host,
-        interfaces: Vec<Interface>,
+        mut host: Host,
+        mut interfaces: Vec<Interface>,
         ports: Vec<Port>,
         services: Vec<Service>,
         if_entries: Vec<crate::server::if_entries::r#impl::base::IfEntry>,
+        certificates: Vec<TlsCertificate>,
         authentication: AuthenticatedEntity,
         host_limit: Option<u64>,
     ) -> Result<HostResponse> {
+        // Stored state before the report is merged, for the host change log
+        let previous = match self.load_host_snapshot(&host, &interfaces).await {
+            Ok(previous) => previous,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to load host state for change tracking");
+                None
+            }
+        };
+        let reported = HostSnapshot::new(&host, &interfaces, &ports, &services)
+            .with_certificates(&ports, &certificates);
+        let reported_ports = ports.clone();
+        let reported_source = host.base.source.clone();
+
+        // Stamp liveness with when the daemon observed the host, not when the report
+        // arrived - buffered reports can be delivered well after the scan
+        let seen_at = reported_source
+            .discovery_metadata()
+            .first()
+            .map(|m| m.date)
+            .unwrap_or_else(Utc::now);
+        host.base.first_seen = Some(seen_at);
+        host.base.last_seen = Some(seen_at);
+        for interface in &mut interfaces {
+            interface.base.first_seen = Some(seen_at);
+            interface.base.last_seen = Some(seen_at);
+        }
+        let reported_interfaces = interfaces.clone();
+
         let host_response = self
             .create_with_children(
                 host,
@@ -1216,15 +1302,239 @@ impl HostService {
         // Link IfEntries to Interfaces via MAC address matching (if any were created)
         if !if_entries.is_empty()
             && let Err(e) = self
-                .link_if_entries_to_interfaces(&host_response.id, authentication)
+                .link_if_entries_to_interfaces(&host_response.id, authentication.clone())
                 .await
         {
             tracing::warn!(error = %e, "Failed to link IfEntries to Interfaces");
         }
 
+        if let Err(e) = self
+            .tls_certificate_service
+            .record_report(
+                &host_response,
+                &reported_ports,
+                certificates,
+                authentication,
+            )
+            .await
+        {
+            tracing::warn!(
+                host_id = %host_response.id,
+                error = %e,
+                "Failed to store TLS certificates"
+            );
+        }
+
+        if let Err(e) = self
+            .host_change_service
+            .record_report(
+                host_response.id,
+                host_response.network_id,
+                previous,
+                reported,
+                &reported_interfaces,
+                &reported_source,
+            )
+            .await
+        {
+            // Non-fatal: the host is stored, change history is best-effort
+            tracing::warn!(
+                host_id = %host_response.id,
+                error = %e,
+                "Failed to record host changes"
+            );
+        }
+
         Ok(host_response)
     }
 
+    /// Stored state of the host a discovery report will be merged into, `None` if it's new.
+    /// Mirrors the matching in `create_with_children`: by interfaces, then by ID.
+    async fn load_host_snapshot(
+        &self,
+        host: &Host,
+        interfaces: &[Interface],
+    ) -> Result<Option<HostSnapshot>> {
+        let existing = match self
+            .find_matching_host_by_interfaces(&host.base.network_id, interfaces)
+            .await?
+        {
+            Some((existing, _)) => Some(existing),
+            None => self.get_by_id(&host.id).await?,
+        };
+
+        let Some(existing) = existing else {
+            return Ok(None);
+        };
+
+        let (interfaces, ports, services, _) = self.load_children_for_host(&existing.id).await?;
+        let certificates = self
+            .tls_certificate_service
+            .get_for_host(&existing.id)
+            .await?;
+        Ok(Some(
+            HostSnapshot::new(&existing, &interfaces, &ports, &services)
+                .with_certificates(&ports, &certificates),
+        ))
+    }
+
+    /// Refresh an existing interface's `last_seen` from the matching interface in a discovery report
+    async fn mark_interface_seen(
+        &self,
+        mut existing: Interface,
+        reported: &Interface,
+        authentication: &AuthenticatedEntity,
+    ) -> Result<Interface> {
+        let Some(seen_at) = reported.base.last_seen else {
+            return Ok(existing);
+        };
+        if existing
+            .base
+            .last_seen
+            .is_some_and(|last_seen| last_seen >= seen_at)
+        {
+            return Ok(existing);
+        }
+
+        existing.base.first_seen.get_or_insert(seen_at);
+        existing.base.last_seen = Some(seen_at);
+        self.interface_service
+            .update(&mut existing, authentication.clone())
+            .await
+    }
+
+    /// Count a missed scan for each host on the subnets a completed network discovery
+    /// scanned that it didn't find, marking hosts offline per the network's liveness policy.
+    pub async fn record_missed_scans(&self, event: &DiscoverySessionEvent) -> Result<()> {
+        let DiscoveryType::Network { subnet_ids, .. } = &event.discovery_type else {
+            return Ok(());
+        };
+        let Some(started_at) = event.started_at else {
+            return Ok(());
+        };
+        let Some(network) = self.network_service.get_by_id(&event.network_id).await? else {
+            return Ok(());
+        };
+
+        let interfaces = self
+            .interface_service
+            .get_all(StorableFilter::<Interface>::new_from_network_ids(&[
+                event.network_id
+            ]))
+            .await?;
+
+        // Without explicit subnets the daemon scanned its own, which are the subnets
+        // it found hosts on during this session
+        let scanned_subnet_ids: HashSet<Uuid> = match subnet_ids {
+            Some(ids) => ids.iter().copied().collect(),
+            None => interfaces
+                .iter()
+                .filter(|i| i.base.last_seen.is_some_and(|t| t >= started_at))
+                .map(|i| i.base.subnet_id)
+                .collect(),
+        };
+        let host_ids: Vec<Uuid> = interfaces
+            .iter()
+            .filter(|i| scanned_subnet_ids.contains(&i.base.subnet_id))
+            .map(|i| i.base.host_id)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        if host_ids.is_empty() {
+            return Ok(());
+        }
+
+        let hosts = self
+            .get_all(StorableFilter::<Host>::new_from_entity_ids(&host_ids))
+            .await?;
+
+        // A scan that found nothing more likely failed than emptied the network
+        if !hosts
+            .iter()
+            .any(|h| h.base.last_seen.is_some_and(|t| t >= started_at))
+        {
+            return Ok(());
+        }
+
+        let policy = network.base.host_liveness;
+        for mut host in hosts {
+            // Hosts discovery has never seen (e.g. added manually) aren't expected to respond
+            let Some(last_seen) = host.base.last_seen else {
+                continue;
+            };
+            if last_seen >= started_at {
+                continue;
+            }
+
+            host.base.missed_scans = host.base.missed_scans.saturating_add(1);
+            host.base.offline = policy.is_offline(host.base.missed_scans);
+
+            tracing::debug!(
+                host_id = %host.id,
+                session_id = %event.session_id,
+                missed_scans = host.base.missed_scans,
+                offline = host.base.offline,
+                "Host missed network scan"
+            );
+
+            self.update(&mut host, AuthenticatedEntity::System).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Hide offline hosts that haven't been seen within their network's retention period
+    pub async fn hide_stale_hosts(&self) {
+        let networks = match self
+            .network_service
+            .get_all(StorableFilter::<Network>::new_unscoped())
+            .await
+        {
+            Ok(networks) => networks,
+            Err(e) => {
+                tracing::error!("Failed to query networks for stale host cleanup: {}", e);
+                return;
+            }
+        };
+
+        for network in networks {
+            let Some(days) = network.base.host_liveness.hide_offline_after_days else {
+                continue;
+            };
+            let cutoff = Utc::now() - chrono::Duration::days(days.into());
+
+            let filter = StorableFilter::<Host>::new_from_network_ids(&[network.id])
+                .offline_is(true)
+                .hidden_is(false)
+                .last_seen_before(cutoff);
+
+            let stale_hosts = match self.get_all(filter).await {
+                Ok(hosts) => hosts,
+                Err(e) => {
+                    tracing::error!(
+                        "Failed to query stale hosts for network {}: {}",
+                        network.id,
+                        e
+                    );
+                    continue;
+                }
+            };
+
+            for mut host in stale_hosts {
+                host.base.auto_hide();
+                match self.update(&mut host, AuthenticatedEntity::System).await {
+                    Ok(_) => tracing::debug!(
+                        host_id = %host.id,
+                        network_id = %network.id,
+                        "Hid host offline longer than {} days",
+                        days
+                    ),
+                    Err(e) => tracing::error!("Failed to hide stale host {}: {}", host.id, e),
+                }
+            }
+        }
+    }
+
     /// Link IfEntry records to Interface records for a host by matching MAC addresses.
     ///
     /// For each IfEntry with a MAC address, finds an Interface on the same host with
@@ -1366,6 +1676,7 @@ impl HostService {
         if existing_host.base.hostname.is_none() && new_host_data.base.hostname.is_some() {
             has_updates = true;
             existing_host.base.hostname = new_host_data.base.hostname;
+            existing_host.base.hostname_source = new_host_data.base.hostname_source;
         }
 
         // Update SNMP fields if not set
@@ -1397,6 +1708,56 @@ impl HostService {
             existing_host.base.chassis_id = new_host_data.base.chassis_id;
         }
 
+        // Hardware inventory is refreshed on every SNMP poll (modules/optics get swapped)
+        if !new_host_data.base.hardware_inventory.is_empty()
+            && existing_host.base.hardware_inventory != new_host_data.base.hardware_inventory
+        {
+            has_updates = true;
+            existing_host.base.hardware_inventory = new_host_data.base.hardware_inventory;
+        }
+
+        // Hypervisor APIs know which VM a host is, so their answer replaces whatever was inferred
+        if new_host_data.base.virtualization.is_some()
+            && existing_host.base.virtualization != new_host_data.base.virtualization
+        {
+            has_updates = true;
+            existing_host.base.virtualization = new_host_data.base.virtualization;
+        }
+
+        // A new OS guess replaces the stored one unless it's less certain, so a quick scan
+        // that only saw the TTL doesn't undo what an SSH banner or SNMP established
+        if let Some(os) = new_host_data.base.os
+            && existing_host.base.os.as_ref() != Some(&os)
+            && existing_host
+                .base
+                .os
+                .as_ref()
+                .is_none_or(|existing| os.confidence >= existing.confidence)
+        {
+            has_updates = true;
+            existing_host.base.os = Some(os);
+        }
+
+        // Any discovery report means the host is up
+        if let Some(first_seen) = new_host_data.base.first_seen
+            && existing_host
+                .base
+                .first_seen
+                .is_none_or(|existing| first_seen < existing)
+        {
+            has_updates = true;
+            existing_host.base.first_seen = Some(first_seen);
+        }
+        if let Some(last_seen) = new_host_data.base.last_seen
+            && existing_host
+                .base
+                .last_seen
+                .is_none_or(|existing| last_seen > existing)
+        {
+            has_updates = true;
+            existing_host.base.mark_seen(last_seen);
+        }
+
         // Merge entity source metadata
         existing_host.base.source = match (existing_host.base.source, new_host_data.base.source) {
             (
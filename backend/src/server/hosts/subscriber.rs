@@ -17,6 +17,17 @@ impl EventSubscriber for HostService {
     async fn handle_events(&self, events: Vec<Event>) -> Result<(), anyhow::Error> {
         for event in events {
             if let Event::Discovery(discovery_event) = event {
+                if discovery_event.phase == DiscoveryPhase::Complete
+                    && let Err(e) = self.record_missed_scans(&discovery_event).await
+                {
+                    tracing::warn!(
+                        session_id = %discovery_event.session_id,
+                        network_id = %discovery_event.network_id,
+                        error = %e,
+                        "Failed to update host liveness after discovery completion"
+                    );
+                }
+
                 // Check if daemon is ServerPoll mode and reachable
                 let Some(daemon) = self
                     .daemon_service
@@ -160,6 +160,12 @@ pub struct IfEntryBase {
     /// Remote management IP from CDP (cdpCacheAddress)
     #[schema(value_type = Option<String>)]
     pub cdp_address: Option<std::net::IpAddr>,
+
+    // Bridge forwarding database (from SNMP dot1d/dot1qTpFdbTable, switches only)
+    /// MAC addresses learned on this port - used to place end hosts on access ports
+    #[serde(default)]
+    #[schema(value_type = Vec<String>)]
+    pub fdb_macs: Vec<MacAddress>,
 }
 
 impl Default for IfEntryBase {
@@ -187,6 +193,7 @@ impl Default for IfEntryBase {
             cdp_port_id: None,
             cdp_platform: None,
             cdp_address: None,
+            fdb_macs: Vec::new(),
         }
     }
 }
@@ -216,6 +223,7 @@ impl ChangeTriggersTopologyStaleness<IfEntry> for IfEntry {
             self.base.neighbor != other_entry.base.neighbor
                 || self.base.interface_id != other_entry.base.interface_id
                 || self.base.host_id != other_entry.base.host_id
+                || self.base.fdb_macs != other_entry.base.fdb_macs
         } else {
             true // New or deleted entry triggers staleness
         }
@@ -286,6 +294,11 @@ impl IfEntry {
     pub fn has_neighbor_discovery_data(&self) -> bool {
         self.has_lldp_data() || self.has_cdp_data()
     }
+
+    /// Returns true if this port has learned MACs in the bridge forwarding table
+    pub fn has_fdb_data(&self) -> bool {
+        !self.base.fdb_macs.is_empty()
+    }
 }
 
 /// Common IANAifType values for reference
@@ -114,6 +114,7 @@ impl Storable for IfEntry {
                     cdp_port_id,
                     cdp_platform,
                     cdp_address,
+                    fdb_macs,
                 },
         } = self.clone();
 
@@ -158,6 +159,7 @@ impl Storable for IfEntry {
             "cdp_port_id",
             "cdp_platform",
             "cdp_address",
+            "fdb_macs",
             "created_at",
             "updated_at",
         ];
@@ -186,6 +188,7 @@ impl Storable for IfEntry {
             SqlValue::OptionalString(cdp_port_id),
             SqlValue::OptionalString(cdp_platform),
             SqlValue::OptionalIpAddr(cdp_address),
+            SqlValue::MacAddressArray(fdb_macs),
             SqlValue::Timestamp(created_at),
             SqlValue::Timestamp(updated_at),
         ];
@@ -275,6 +278,7 @@ impl Storable for IfEntry {
                 cdp_port_id: row.get("cdp_port_id"),
                 cdp_platform: row.get("cdp_platform"),
                 cdp_address: row.try_get("cdp_address").ok().flatten(),
+                fdb_macs: row.get("fdb_macs"),
             },
         })
     }
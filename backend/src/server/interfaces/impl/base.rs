@@ -29,6 +29,14 @@ pub struct InterfaceBase {
     /// Position of this interface in the host's interface list (for ordering)
     #[serde(default)]
     pub position: i32,
+    /// When discovery first observed this interface (null if never discovered)
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub first_seen: Option<DateTime<Utc>>,
+    /// When discovery last observed this interface
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub last_seen: Option<DateTime<Utc>>,
 }
 
 impl Default for InterfaceBase {
@@ -41,6 +49,8 @@ impl Default for InterfaceBase {
             mac_address: None,
             name: None,
             position: 0,
+            first_seen: None,
+            last_seen: None,
         }
     }
 }
@@ -59,6 +69,8 @@ impl InterfaceBase {
             mac_address: None,
             name: Some(subnet.base.name.clone()),
             position: 0,
+            first_seen: None,
+            last_seen: None,
         }
     }
 }
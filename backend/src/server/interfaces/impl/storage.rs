@@ -31,6 +31,8 @@ pub struct InterfaceCsvRow {
     pub subnet_id: Uuid,
     pub network_id: Uuid,
     pub position: i32,
+    pub first_seen: Option<DateTime<Utc>>,
+    pub last_seen: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -87,6 +89,8 @@ impl Storable for Interface {
                     mac_address,
                     name,
                     position,
+                    first_seen,
+                    last_seen,
                 },
         } = self.clone();
 
@@ -100,6 +104,8 @@ impl Storable for Interface {
                 "mac_address",
                 "name",
                 "position",
+                "first_seen",
+                "last_seen",
                 "created_at",
                 "updated_at",
             ],
@@ -112,6 +118,8 @@ impl Storable for Interface {
                 SqlValue::OptionalMacAddress(mac_address),
                 SqlValue::OptionalString(name),
                 SqlValue::I32(position),
+                SqlValue::OptionTimestamp(first_seen),
+                SqlValue::OptionTimestamp(last_seen),
                 SqlValue::Timestamp(created_at),
                 SqlValue::Timestamp(updated_at),
             ],
@@ -142,6 +150,8 @@ impl Storable for Interface {
                 mac_address,
                 name: row.get("name"),
                 position: row.get("position"),
+                first_seen: row.get("first_seen"),
+                last_seen: row.get("last_seen"),
             },
         })
     }
@@ -160,6 +170,8 @@ impl Entity for Interface {
             subnet_id: self.base.subnet_id,
             network_id: self.base.network_id,
             position: self.base.position,
+            first_seen: self.base.first_seen,
+            last_seen: self.base.last_seen,
             created_at: self.created_at,
             updated_at: self.updated_at,
         }
@@ -195,6 +207,9 @@ impl Entity for Interface {
 
     fn preserve_immutable_fields(&mut self, existing: &Self) {
         self.created_at = existing.created_at;
+        // Liveness is only tracked by discovery
+        self.base.first_seen = existing.base.first_seen;
+        self.base.last_seen = existing.base.last_seen;
         // MAC address is immutable once set
         if existing.base.mac_address.is_some() {
             self.base.mac_address = existing.base.mac_address;
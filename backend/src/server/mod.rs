@@ -1,14 +1,18 @@
+pub mod audit_logs;
 pub mod auth;
 pub mod billing;
 pub mod bindings;
 pub mod brevo;
 pub mod config;
+pub mod custom_scan_profiles;
+pub mod custom_service_definitions;
 pub mod daemon_api_keys;
 pub mod daemons;
 pub mod discovery;
 pub mod email;
 pub mod github;
 pub mod groups;
+pub mod host_changes;
 pub mod hosts;
 pub mod if_entries;
 pub mod interfaces;
@@ -26,6 +30,9 @@ pub mod shares;
 pub mod snmp_credentials;
 pub mod subnets;
 pub mod tags;
+pub mod tls_certificates;
 pub mod topology;
+pub mod topology_snapshots;
 pub mod user_api_keys;
 pub mod users;
+pub mod webhooks;
@@ -2,6 +2,7 @@ use std::fmt::Display;
 
 use crate::server::{
     config::AppState,
+    discovery::r#impl::exclusions::ScanExclusion,
     networks::service::NetworkService,
     shared::{
         entities::{ChangeTriggersTopologyStaleness, EntityDiscriminants},
@@ -43,6 +44,46 @@ pub struct NetworkBase {
     /// When set, SNMP discovery is enabled for this network.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub snmp_credential_id: Option<Uuid>,
+    /// When discovered hosts are marked offline and hidden
+    #[serde(default)]
+    #[validate(nested)]
+    pub host_liveness: HostLivenessPolicy,
+    /// Addresses and devices that no discovery on this network may probe
+    #[serde(default)]
+    #[schema(required)]
+    pub scan_exclusions: Vec<ScanExclusion>,
+}
+
+/// Stale-host lifecycle for a network. Hosts that network scans stop finding are first
+/// marked offline, then hidden once they've been gone for the retention period.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Validate, PartialEq, Eq, Hash, ToSchema)]
+pub struct HostLivenessPolicy {
+    /// Consecutive missed network scans before a host is marked offline. Null never marks hosts offline.
+    #[validate(range(min = 1, max = 1000))]
+    #[schema(required)]
+    pub offline_after_missed_scans: Option<u32>,
+    /// Days since an offline host was last seen before it's hidden, until discovery sees it again.
+    /// Null keeps offline hosts visible.
+    #[validate(range(min = 1, max = 3650))]
+    #[schema(required)]
+    pub hide_offline_after_days: Option<u32>,
+}
+
+impl Default for HostLivenessPolicy {
+    fn default() -> Self {
+        Self {
+            offline_after_missed_scans: Some(3),
+            hide_offline_after_days: None,
+        }
+    }
+}
+
+impl HostLivenessPolicy {
+    /// Whether a host that has missed this many consecutive scans is offline
+    pub fn is_offline(&self, missed_scans: u32) -> bool {
+        self.offline_after_missed_scans
+            .is_some_and(|threshold| missed_scans >= threshold)
+    }
 }
 
 impl NetworkBase {
@@ -52,6 +93,8 @@ impl NetworkBase {
             organization_id,
             tags: Vec::new(),
             snmp_credential_id: None,
+            host_liveness: HostLivenessPolicy::default(),
+            scan_exclusions: Vec::new(),
         }
     }
 }
@@ -88,6 +131,11 @@ impl CrudHandlers for Network {
     fn get_service(state: &AppState) -> &Self::Service {
         &state.services.network_service
     }
+
+    fn validate(&self) -> Result<(), String> {
+        validator::Validate::validate(self).map_err(|e| e.to_string())?;
+        ScanExclusion::validate_all(&self.base.scan_exclusions)
+    }
 }
 
 impl ChangeTriggersTopologyStaleness<Network> for Network {
@@ -144,6 +192,8 @@ impl Storable for Network {
                     organization_id,
                     tags: _, // Stored in entity_tags junction table
                     snmp_credential_id,
+                    host_liveness,
+                    scan_exclusions,
                 },
         } = self.clone();
 
@@ -155,6 +205,8 @@ impl Storable for Network {
                 "name",
                 "organization_id",
                 "snmp_credential_id",
+                "host_liveness",
+                "scan_exclusions",
             ],
             vec![
                 SqlValue::Uuid(id),
@@ -163,11 +215,20 @@ impl Storable for Network {
                 SqlValue::String(name),
                 SqlValue::Uuid(organization_id),
                 SqlValue::OptionalUuid(snmp_credential_id),
+                SqlValue::JsonValue(serde_json::to_value(host_liveness)?),
+                SqlValue::JsonValue(serde_json::to_value(scan_exclusions)?),
             ],
         ))
     }
 
     fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        let host_liveness: HostLivenessPolicy =
+            serde_json::from_value(row.get::<serde_json::Value, _>("host_liveness"))
+                .map_err(|e| anyhow::anyhow!("Failed to deserialize host_liveness: {}", e))?;
+        let scan_exclusions: Vec<ScanExclusion> =
+            serde_json::from_value(row.get::<serde_json::Value, _>("scan_exclusions"))
+                .map_err(|e| anyhow::anyhow!("Failed to deserialize scan_exclusions: {}", e))?;
+
         Ok(Network {
             id: row.get("id"),
             created_at: row.get("created_at"),
@@ -177,6 +238,8 @@ impl Storable for Network {
                 organization_id: row.get("organization_id"),
                 tags: Vec::new(), // Hydrated from entity_tags junction table
                 snmp_credential_id: row.get("snmp_credential_id"),
+                host_liveness,
+                scan_exclusions,
             },
         })
     }
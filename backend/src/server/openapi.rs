@@ -16,14 +16,22 @@ use utoipa::openapi::security::{ApiKey, ApiKeyValue, SecurityScheme};
 use utoipa::openapi::{Components, OpenApi, PathItem};
 use utoipa_scalar::{Scalar, Servable};
 
+use crate::server::audit_logs::handlers::AuditLogEntryOrderField;
+use crate::server::audit_logs::r#impl::base::AuditLogEntry;
 use crate::server::bindings::r#impl::base::Binding;
 use crate::server::config::AppState;
+use crate::server::custom_scan_profiles::handlers::CustomScanProfileOrderField;
+use crate::server::custom_scan_profiles::r#impl::base::CustomScanProfile;
+use crate::server::custom_service_definitions::handlers::CustomServiceDefinitionOrderField;
+use crate::server::custom_service_definitions::r#impl::base::CustomServiceDefinition;
 use crate::server::daemon_api_keys::r#impl::base::DaemonApiKey;
 use crate::server::daemons::handlers::DaemonOrderField;
 use crate::server::daemons::r#impl::base::Daemon;
 use crate::server::discovery::r#impl::base::Discovery;
 use crate::server::groups::handlers::GroupOrderField;
 use crate::server::groups::r#impl::base::Group;
+use crate::server::host_changes::handlers::HostChangeOrderField;
+use crate::server::host_changes::r#impl::base::HostChange;
 use crate::server::hosts::handlers::HostOrderField;
 use crate::server::hosts::r#impl::base::Host;
 use crate::server::if_entries::r#impl::base::IfEntry;
@@ -43,9 +51,15 @@ use crate::server::subnets::handlers::SubnetOrderField;
 use crate::server::subnets::r#impl::base::Subnet;
 use crate::server::tags::handlers::TagOrderField;
 use crate::server::tags::r#impl::base::Tag;
+use crate::server::tls_certificates::handlers::TlsCertificateOrderField;
+use crate::server::tls_certificates::r#impl::base::TlsCertificate;
 use crate::server::topology::types::base::Topology;
+use crate::server::topology_snapshots::handlers::TopologySnapshotOrderField;
+use crate::server::topology_snapshots::r#impl::base::TopologySnapshot;
 use crate::server::user_api_keys::r#impl::base::UserApiKey;
 use crate::server::users::r#impl::base::User;
+use crate::server::webhooks::handlers::WebhookOrderField;
+use crate::server::webhooks::r#impl::base::Webhook;
 
 /// Tag used to mark endpoints that should be hidden from public documentation
 /// but included in the full OpenAPI spec for client generation.
@@ -67,7 +81,14 @@ pub const SERVER_VERSION: &str = env!("CARGO_PKG_VERSION");
         GroupOrderField,
         SubnetOrderField,
         DaemonOrderField,
-        SnmpCredentialOrderField
+        SnmpCredentialOrderField,
+        CustomServiceDefinitionOrderField,
+        CustomScanProfileOrderField,
+        HostChangeOrderField,
+        WebhookOrderField,
+        TlsCertificateOrderField,
+        TopologySnapshotOrderField,
+        AuditLogEntryOrderField
     )),
     info(
         title = "Scanopy API",
@@ -177,12 +198,16 @@ Resources are scoped to your **organization** and **network(s)**:
     ),
     tags(
         // Entity tags - descriptions sourced from Entity trait for consistency
+        (name = AuditLogEntry::ENTITY_NAME_PLURAL, description = AuditLogEntry::ENTITY_DESCRIPTION),
         (name = Binding::ENTITY_NAME_PLURAL, description = Binding::ENTITY_DESCRIPTION),
+        (name = CustomScanProfile::ENTITY_NAME_PLURAL, description = CustomScanProfile::ENTITY_DESCRIPTION),
+        (name = CustomServiceDefinition::ENTITY_NAME_PLURAL, description = CustomServiceDefinition::ENTITY_DESCRIPTION),
         (name = Daemon::ENTITY_NAME_PLURAL, description = Daemon::ENTITY_DESCRIPTION),
         (name = DaemonApiKey::ENTITY_NAME_PLURAL, description = DaemonApiKey::ENTITY_DESCRIPTION),
         (name = Discovery::ENTITY_NAME_PLURAL, description = Discovery::ENTITY_DESCRIPTION),
         (name = Group::ENTITY_NAME_PLURAL, description = Group::ENTITY_DESCRIPTION),
         (name = Host::ENTITY_NAME_PLURAL, description = Host::ENTITY_DESCRIPTION),
+        (name = HostChange::ENTITY_NAME_PLURAL, description = HostChange::ENTITY_DESCRIPTION),
         (name = IfEntry::ENTITY_NAME_PLURAL, description = IfEntry::ENTITY_DESCRIPTION),
         (name = Interface::ENTITY_NAME_PLURAL, description = Interface::ENTITY_DESCRIPTION),
         (name = Invite::ENTITY_NAME_PLURAL, description = Invite::ENTITY_DESCRIPTION),
@@ -194,9 +219,12 @@ Resources are scoped to your **organization** and **network(s)**:
         (name = SnmpCredential::ENTITY_NAME_PLURAL, description = SnmpCredential::ENTITY_DESCRIPTION),
         (name = Subnet::ENTITY_NAME_PLURAL, description = Subnet::ENTITY_DESCRIPTION),
         (name = Tag::ENTITY_NAME_PLURAL, description = Tag::ENTITY_DESCRIPTION),
+        (name = TlsCertificate::ENTITY_NAME_PLURAL, description = TlsCertificate::ENTITY_DESCRIPTION),
         (name = Topology::ENTITY_NAME_PLURAL, description = Topology::ENTITY_DESCRIPTION),
+        (name = TopologySnapshot::ENTITY_NAME_PLURAL, description = TopologySnapshot::ENTITY_DESCRIPTION),
         (name = User::ENTITY_NAME_PLURAL, description = User::ENTITY_DESCRIPTION),
         (name = UserApiKey::ENTITY_NAME_PLURAL, description = UserApiKey::ENTITY_DESCRIPTION),
+        (name = Webhook::ENTITY_NAME_PLURAL, description = Webhook::ENTITY_DESCRIPTION),
         // Non-entity tags with inline descriptions
         (name = "auth", description = "Authentication and session management. Handle user login, logout, and session state."),
         (name = "config", description = "Server configuration. Public configuration settings for client applications."),
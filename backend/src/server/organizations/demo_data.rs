@@ -14,7 +14,8 @@ use crate::server::{
     },
     discovery::r#impl::{
         base::{Discovery, DiscoveryBase},
-        types::{DiscoveryType, HostNamingFallback, RunType},
+        scan_profile::ScanProfile,
+        types::{DiscoveryType, HostNamingFallback, HostnameSource, RunType},
     },
     groups::r#impl::{
         base::{Group, GroupBase},
@@ -23,7 +24,7 @@ use crate::server::{
     hosts::r#impl::base::{Host, HostBase},
     if_entries::r#impl::base::{IfAdminStatus, IfEntry, IfEntryBase, IfOperStatus, Neighbor},
     interfaces::r#impl::base::{Interface, InterfaceBase},
-    networks::r#impl::{Network, NetworkBase},
+    networks::r#impl::{HostLivenessPolicy, Network, NetworkBase},
     ports::r#impl::base::{Port, PortType},
     services::{
         definitions::ServiceDefinitionRegistry,
@@ -229,6 +230,7 @@ fn generate_snmp_credentials(organization_id: Uuid, now: DateTime<Utc>) -> Vec<S
                 version: SnmpVersion::V2c,
                 community: SecretString::from("public".to_string()),
                 tags: Vec::new(),
+                ..Default::default()
             },
         },
         SnmpCredential {
@@ -241,6 +243,7 @@ fn generate_snmp_credentials(organization_id: Uuid, now: DateTime<Utc>) -> Vec<S
                 version: SnmpVersion::V2c,
                 community: SecretString::from("acme-network".to_string()),
                 tags: Vec::new(),
+                ..Default::default()
             },
         },
     ]
@@ -285,6 +288,8 @@ fn generate_networks(
                 organization_id,
                 tags: production_tag.into_iter().collect(),
                 snmp_credential_id: default_snmpv2c,
+                host_liveness: HostLivenessPolicy::default(),
+                scan_exclusions: Vec::new(),
             },
         },
         Network {
@@ -296,6 +301,8 @@ fn generate_networks(
                 organization_id,
                 tags: production_tag.into_iter().collect(),
                 snmp_credential_id: None,
+                host_liveness: HostLivenessPolicy::default(),
+                scan_exclusions: Vec::new(),
             },
         },
         Network {
@@ -307,6 +314,8 @@ fn generate_networks(
                 organization_id,
                 tags: vec![],
                 snmp_credential_id: default_snmpv2c,
+                host_liveness: HostLivenessPolicy::default(),
+                scan_exclusions: Vec::new(),
             },
         },
         Network {
@@ -318,6 +327,8 @@ fn generate_networks(
                 organization_id,
                 tags: managed_client_tag.into_iter().collect(),
                 snmp_credential_id: network_devices_cred,
+                host_liveness: HostLivenessPolicy::default(),
+                scan_exclusions: Vec::new(),
             },
         },
     ]
@@ -558,6 +569,8 @@ fn create_host(
             mac_address: None,
             name: Some("eth0".to_string()),
             position: 0,
+            first_seen: None,
+            last_seen: None,
         },
     };
     let host = Host {
@@ -580,6 +593,14 @@ fn create_host(
             management_url: None,
             chassis_id: None,
             snmp_credential_id,
+            hardware_inventory: Vec::new(),
+            first_seen: None,
+            last_seen: None,
+            missed_scans: 0,
+            offline: false,
+            auto_hidden: false,
+            os: None,
+            hostname_source: None,
         },
     };
     (host, interface)
@@ -622,6 +643,7 @@ fn create_service(
                 source: EntitySource::Manual,
                 tags,
                 position: 0,
+                proxy_targets: Vec::new(),
             },
         },
         port,
@@ -1627,6 +1649,7 @@ fn generate_if_entries(
                 cdp_port_id: None,
                 cdp_platform: None,
                 cdp_address: None,
+                fdb_macs: Vec::new(),
             },
         });
 
@@ -1659,6 +1682,7 @@ fn generate_if_entries(
                 cdp_port_id: None,
                 cdp_platform: None,
                 cdp_address: None,
+                fdb_macs: Vec::new(),
             },
         });
         neighbor_updates.push(NeighborUpdate {
@@ -1696,6 +1720,7 @@ fn generate_if_entries(
                 cdp_port_id: None,
                 cdp_platform: None,
                 cdp_address: None,
+                fdb_macs: Vec::new(),
             },
         });
     }
@@ -1737,6 +1762,7 @@ fn generate_if_entries(
                 cdp_port_id: None,
                 cdp_platform: None,
                 cdp_address: None,
+                fdb_macs: Vec::new(),
             },
         });
         neighbor_updates.push(NeighborUpdate {
@@ -1784,6 +1810,7 @@ fn generate_if_entries(
                 cdp_port_id: None,
                 cdp_platform: None,
                 cdp_address: None,
+                fdb_macs: Vec::new(),
             },
         });
         neighbor_updates.push(NeighborUpdate {
@@ -1821,6 +1848,7 @@ fn generate_if_entries(
                 cdp_port_id: None,
                 cdp_platform: None,
                 cdp_address: None,
+                fdb_macs: Vec::new(),
             },
         });
     }
@@ -1873,6 +1901,7 @@ fn generate_if_entries(
                 cdp_port_id: None,
                 cdp_platform: None,
                 cdp_address: None,
+                fdb_macs: Vec::new(),
             },
         });
         neighbor_updates.push(NeighborUpdate {
@@ -1913,6 +1942,7 @@ fn generate_if_entries(
                 cdp_port_id: None,
                 cdp_platform: None,
                 cdp_address: None,
+                fdb_macs: Vec::new(),
             },
         });
         neighbor_updates.push(NeighborUpdate {
@@ -1953,6 +1983,7 @@ fn generate_if_entries(
                 cdp_port_id: None,
                 cdp_platform: None,
                 cdp_address: None,
+                fdb_macs: Vec::new(),
             },
         });
         neighbor_updates.push(NeighborUpdate {
@@ -1992,6 +2023,7 @@ fn generate_if_entries(
                 cdp_port_id: None,
                 cdp_platform: None,
                 cdp_address: None,
+                fdb_macs: Vec::new(),
             },
         });
 
@@ -2024,6 +2056,7 @@ fn generate_if_entries(
                     cdp_port_id: None,
                     cdp_platform: None,
                     cdp_address: None,
+                    fdb_macs: Vec::new(),
                 },
             });
         }
@@ -2301,13 +2334,20 @@ fn generate_discoveries(
                     subnet_ids: Some(hq_subnet_ids),
                     host_naming_fallback: HostNamingFallback::BestService,
                     snmp_credentials: SnmpCredentialMapping {
-                        default_credential: default_cred.map(|_| SnmpQueryCredential {
-                            version: SnmpVersion::V2c,
-                            community: "public".to_string(),
+                        default_credential: default_cred.map(|_| {
+                            Box::new(SnmpQueryCredential {
+                                version: SnmpVersion::V2c,
+                                community: "public".to_string(),
+                                ..Default::default()
+                            })
                         }),
                         ip_overrides: vec![],
                     },
+                    custom_service_definitions: Vec::new(),
                     probe_raw_socket_ports: false,
+                    scan_profile: ScanProfile::default(),
+                    exclusions: Vec::new(),
+                    hostname_sources: HostnameSource::default_order(),
                 },
                 run_type: RunType::AdHoc {
                     last_run: Some(now - Duration::days(2)),
@@ -2357,7 +2397,11 @@ fn generate_discoveries(
                         default_credential: None,
                         ip_overrides: vec![],
                     },
+                    custom_service_definitions: Vec::new(),
                     probe_raw_socket_ports: false,
+                    scan_profile: ScanProfile::default(),
+                    exclusions: Vec::new(),
+                    hostname_sources: HostnameSource::default_order(),
                 },
                 run_type: RunType::AdHoc {
                     last_run: Some(now - Duration::days(3)),
@@ -2382,13 +2426,20 @@ fn generate_discoveries(
                     subnet_ids: Some(denver_subnet_ids),
                     host_naming_fallback: HostNamingFallback::BestService,
                     snmp_credentials: SnmpCredentialMapping {
-                        default_credential: default_cred.map(|_| SnmpQueryCredential {
-                            version: SnmpVersion::V2c,
-                            community: "public".to_string(),
+                        default_credential: default_cred.map(|_| {
+                            Box::new(SnmpQueryCredential {
+                                version: SnmpVersion::V2c,
+                                community: "public".to_string(),
+                                ..Default::default()
+                            })
                         }),
                         ip_overrides: vec![],
                     },
+                    custom_service_definitions: Vec::new(),
                     probe_raw_socket_ports: false,
+                    scan_profile: ScanProfile::default(),
+                    exclusions: Vec::new(),
+                    hostname_sources: HostnameSource::default_order(),
                 },
                 run_type: RunType::AdHoc {
                     last_run: Some(now - Duration::days(7)),
@@ -2417,10 +2468,14 @@ fn generate_discoveries(
                         default_credential: None,
                         ip_overrides: vec![],
                     },
+                    custom_service_definitions: Vec::new(),
                     probe_raw_socket_ports: false,
+                    scan_profile: ScanProfile::default(),
+                    exclusions: Vec::new(),
+                    hostname_sources: HostnameSource::default_order(),
                 },
                 run_type: RunType::Historical {
-                    results: DiscoveryUpdatePayload {
+                    results: Box::new(DiscoveryUpdatePayload {
                         session_id: Uuid::new_v4(),
                         daemon_id: daemon.id,
                         network_id: hq.id,
@@ -2432,13 +2487,18 @@ fn generate_discoveries(
                                 default_credential: None,
                                 ip_overrides: vec![],
                             },
+                            custom_service_definitions: Vec::new(),
                             probe_raw_socket_ports: false,
+                            scan_profile: ScanProfile::default(),
+                            exclusions: Vec::new(),
+                            hostname_sources: HostnameSource::default_order(),
                         },
                         progress: 100,
                         error: None,
                         started_at: Some(three_weeks_ago),
                         finished_at: Some(three_weeks_ago + Duration::minutes(12)),
-                    },
+                        excluded_targets: Vec::new(),
+                    }),
                 },
                 name: "HQ Scan - Jan 15".to_string(),
                 daemon_id: daemon.id,
@@ -2460,10 +2520,14 @@ fn generate_discoveries(
                         default_credential: None,
                         ip_overrides: vec![],
                     },
+                    custom_service_definitions: Vec::new(),
                     probe_raw_socket_ports: false,
+                    scan_profile: ScanProfile::default(),
+                    exclusions: Vec::new(),
+                    hostname_sources: HostnameSource::default_order(),
                 },
                 run_type: RunType::Historical {
-                    results: DiscoveryUpdatePayload {
+                    results: Box::new(DiscoveryUpdatePayload {
                         session_id: Uuid::new_v4(),
                         daemon_id: daemon.id,
                         network_id: hq.id,
@@ -2475,13 +2539,18 @@ fn generate_discoveries(
                                 default_credential: None,
                                 ip_overrides: vec![],
                             },
+                            custom_service_definitions: Vec::new(),
                             probe_raw_socket_ports: false,
+                            scan_profile: ScanProfile::default(),
+                            exclusions: Vec::new(),
+                            hostname_sources: HostnameSource::default_order(),
                         },
                         progress: 100,
                         error: None,
                         started_at: Some(one_week_ago),
                         finished_at: Some(one_week_ago + Duration::minutes(8)),
-                    },
+                        excluded_targets: Vec::new(),
+                    }),
                 },
                 name: "HQ Scan - Jan 28".to_string(),
                 daemon_id: daemon.id,
@@ -2506,10 +2575,14 @@ fn generate_discoveries(
                         default_credential: None,
                         ip_overrides: vec![],
                     },
+                    custom_service_definitions: Vec::new(),
                     probe_raw_socket_ports: false,
+                    scan_profile: ScanProfile::default(),
+                    exclusions: Vec::new(),
+                    hostname_sources: HostnameSource::default_order(),
                 },
                 run_type: RunType::Historical {
-                    results: DiscoveryUpdatePayload {
+                    results: Box::new(DiscoveryUpdatePayload {
                         session_id: Uuid::new_v4(),
                         daemon_id: daemon.id,
                         network_id: cloud.id,
@@ -2521,13 +2594,18 @@ fn generate_discoveries(
                                 default_credential: None,
                                 ip_overrides: vec![],
                             },
+                            custom_service_definitions: Vec::new(),
                             probe_raw_socket_ports: false,
+                            scan_profile: ScanProfile::default(),
+                            exclusions: Vec::new(),
+                            hostname_sources: HostnameSource::default_order(),
                         },
                         progress: 100,
                         error: Some("Connection timeout: daemon lost connectivity to subnet 172.16.1.0/24 during scan".to_string()),
                         started_at: Some(two_weeks_ago),
                         finished_at: Some(two_weeks_ago + Duration::minutes(3)),
-                    },
+                        excluded_targets: Vec::new(),
+                    }),
                 },
                 name: "Cloud Scan - Jan 20".to_string(),
                 daemon_id: daemon.id,
@@ -2570,6 +2648,7 @@ fn generate_shares(
                 expires_at: None,
                 password_hash: None,
                 allowed_domains: None,
+                compare_snapshot_id: None,
                 options: ShareOptions {
                     show_inspect_panel: true,
                     show_zoom_controls: true,
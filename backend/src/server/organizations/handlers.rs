@@ -250,6 +250,7 @@ pub async fn populate_demo_data(
                 host_with_services.ports,
                 host_with_services.services,
                 host_if_entries,
+                vec![],
                 entity.clone(),
                 None, // Demo data seeding - no host limit
             )
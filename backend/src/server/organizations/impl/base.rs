@@ -10,9 +10,14 @@ use crate::server::{
     shared::{entities::ChangeTriggersTopologyStaleness, events::types::TelemetryOperation},
 };
 
-#[derive(
-    Debug, Clone, Serialize, Validate, Deserialize, Default, PartialEq, Eq, Hash, ToSchema,
-)]
+/// Audit log entries are kept for a year unless the organization says otherwise.
+pub const DEFAULT_AUDIT_LOG_RETENTION_DAYS: u32 = 365;
+
+fn default_audit_log_retention_days() -> u32 {
+    DEFAULT_AUDIT_LOG_RETENTION_DAYS
+}
+
+#[derive(Debug, Clone, Serialize, Validate, Deserialize, PartialEq, Eq, Hash, ToSchema)]
 pub struct OrganizationBase {
     /// Stripe customer ID - internal, not exposed to API
     #[serde(default, skip_serializing)]
@@ -36,6 +41,27 @@ pub struct OrganizationBase {
     /// Brevo company ID - internal, not exposed to API
     #[serde(default, skip_serializing)]
     pub brevo_company_id: Option<String>,
+    /// Days audit log entries are kept before being pruned
+    #[serde(default = "default_audit_log_retention_days")]
+    #[validate(range(min = 1, max = 3650))]
+    #[schema(required, minimum = 1, maximum = 3650)]
+    pub audit_log_retention_days: u32,
+}
+
+impl Default for OrganizationBase {
+    fn default() -> Self {
+        Self {
+            stripe_customer_id: None,
+            name: String::new(),
+            plan: None,
+            plan_status: None,
+            onboarding: Vec::new(),
+            has_payment_method: false,
+            trial_end_date: None,
+            brevo_company_id: None,
+            audit_log_retention_days: DEFAULT_AUDIT_LOG_RETENTION_DAYS,
+        }
+    }
 }
 
 #[derive(
@@ -78,6 +78,7 @@ impl Storable for Organization {
                     has_payment_method,
                     trial_end_date,
                     brevo_company_id,
+                    audit_log_retention_days,
                 },
         } = self.clone();
 
@@ -94,6 +95,7 @@ impl Storable for Organization {
                 "has_payment_method",
                 "trial_end_date",
                 "brevo_company_id",
+                "audit_log_retention_days",
             ],
             vec![
                 SqlValue::Uuid(id),
@@ -107,6 +109,7 @@ impl Storable for Organization {
                 SqlValue::Bool(has_payment_method),
                 SqlValue::OptionTimestamp(trial_end_date),
                 SqlValue::OptionalString(brevo_company_id),
+                SqlValue::I32(audit_log_retention_days as i32),
             ],
         ))
     }
@@ -134,6 +137,7 @@ impl Storable for Organization {
                 has_payment_method: row.get("has_payment_method"),
                 trial_end_date: row.get("trial_end_date"),
                 brevo_company_id: row.get("brevo_company_id"),
+                audit_log_retention_days: row.get::<i32, _>("audit_log_retention_days") as u32,
             },
         })
     }
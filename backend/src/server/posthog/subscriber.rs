@@ -54,7 +54,7 @@ fn auth_properties(event: &Event) -> serde_json::Value {
 
 /// Convert a PascalCase entity discriminant name to snake_case.
 /// e.g. "DaemonApiKey" -> "daemon_api_key", "Host" -> "host"
-fn to_snake_case(s: &str) -> String {
+pub(crate) fn to_snake_case(s: &str) -> String {
     let mut result = String::with_capacity(s.len() + 4);
     for (i, ch) in s.chars().enumerate() {
         if ch.is_uppercase() && i > 0 {
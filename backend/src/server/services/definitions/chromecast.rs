@@ -21,10 +21,15 @@ impl ServiceDefinition for ChromecastDevice {
     }
 
     fn discovery_pattern(&self) -> Pattern<'_> {
-        Pattern::AllOf(vec![
-            Pattern::MacVendor(Vendor::GOOGLE),
-            Pattern::Port(PortType::new_tcp(8008)),
-            Pattern::Port(PortType::new_tcp(8009)),
+        Pattern::AnyOf(vec![
+            // Cast devices report their model in the "md" TXT record; Google Home and
+            // Nest speakers advertise _googlecast._tcp too, so the service type alone isn't enough
+            Pattern::MdnsTxt("_googlecast._tcp", "md", "Chromecast"),
+            Pattern::AllOf(vec![
+                Pattern::MacVendor(Vendor::GOOGLE),
+                Pattern::Port(PortType::new_tcp(8008)),
+                Pattern::Port(PortType::new_tcp(8009)),
+            ]),
         ])
     }
 
@@ -18,9 +18,13 @@ impl ServiceDefinition for FileZillaServer {
         ServiceCategory::Storage
     }
     fn discovery_pattern(&self) -> Pattern<'_> {
-        Pattern::AllOf(vec![
-            Pattern::Port(PortType::Ftp),
-            Pattern::Port(PortType::new_tcp(14147)), // Admin interface
+        Pattern::AnyOf(vec![
+            // FTP greeting, ie "220-FileZilla Server 1.8.1"
+            Pattern::Banner(PortType::Ftp, r"^220[ -]FileZilla Server"),
+            Pattern::AllOf(vec![
+                Pattern::Port(PortType::Ftp),
+                Pattern::Port(PortType::new_tcp(14147)), // Admin interface
+            ]),
         ])
     }
     fn logo_url(&self) -> &'static str {
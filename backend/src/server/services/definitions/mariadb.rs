@@ -1,3 +1,4 @@
+use crate::server::ports::r#impl::base::PortType;
 use crate::server::services::definitions::{ServiceDefinitionFactory, create_service};
 use crate::server::services::r#impl::categories::ServiceCategory;
 use crate::server::services::r#impl::definitions::ServiceDefinition;
@@ -17,7 +18,9 @@ impl ServiceDefinition for MariaDB {
         ServiceCategory::Database
     }
     fn discovery_pattern(&self) -> Pattern<'_> {
-        Pattern::None
+        // MariaDB reports itself in the version string of the MySQL handshake, ie
+        // "5.5.5-10.11.6-MariaDB". Not generic, so it's matched before MySQL binds the port.
+        Pattern::Banner(PortType::MySql, "MariaDB")
     }
     fn logo_url(&self) -> &'static str {
         "https://cdn.jsdelivr.net/gh/homarr-labs/dashboard-icons/svg/mariadb.svg"
@@ -25,9 +28,6 @@ impl ServiceDefinition for MariaDB {
     fn logo_needs_white_background(&self) -> bool {
         true
     }
-    fn is_generic(&self) -> bool {
-        true
-    }
 }
 
 inventory::submit!(ServiceDefinitionFactory::new(create_service::<MariaDB>));
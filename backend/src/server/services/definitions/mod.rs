@@ -1,3 +1,4 @@
+use crate::server::custom_service_definitions::r#impl::base::CustomServiceDefinition;
 use crate::server::services::r#impl::definitions::ServiceDefinition;
 use crate::server::shared::types::metadata::HasId;
 use inventory;
@@ -34,6 +35,14 @@ impl ServiceDefinitionRegistry {
             .collect()
     }
 
+    /// Registered services followed by an organization's user-defined ones. Discovery
+    /// sessions match against this set; it is built per session, never shared.
+    pub fn with_custom(custom: &[CustomServiceDefinition]) -> Vec<Box<dyn ServiceDefinition>> {
+        let mut definitions = Self::all_service_definitions();
+        definitions.extend(custom.iter().map(Into::into));
+        definitions
+    }
+
     pub fn service_exists(id: &str) -> bool {
         inventory::iter::<ServiceDefinitionFactory>().any(|factory| factory.create().id() == id)
     }
@@ -166,6 +175,7 @@ pub mod docker_daemon;
 pub mod docker_swarm;
 pub mod dockge;
 pub mod kubernetes;
+pub mod kubernetes_pod;
 pub mod nomad;
 pub mod openshift;
 pub mod portainer;
@@ -18,7 +18,16 @@ impl ServiceDefinition for MySQL {
         ServiceCategory::Database
     }
     fn discovery_pattern(&self) -> Pattern<'_> {
-        Pattern::Port(PortType::MySql)
+        Pattern::AnyOf(vec![
+            // Protocol 10 handshake packet carrying the server version, or the error sent in
+            // its place to clients that aren't allowed to connect. MariaDB servers send the
+            // same handshake but are matched first by their version string.
+            Pattern::Banner(
+                PortType::MySql,
+                r"^.{1,12}\\x00\n\d+\.\d+\.\d+|is not allowed to connect to this MySQL server",
+            ),
+            Pattern::Port(PortType::MySql),
+        ])
     }
     fn logo_url(&self) -> &'static str {
         "https://cdn.jsdelivr.net/gh/homarr-labs/dashboard-icons/svg/mysql.svg"
@@ -19,7 +19,14 @@ impl ServiceDefinition for PostgreSQL {
         ServiceCategory::Database
     }
     fn discovery_pattern(&self) -> Pattern<'_> {
-        Pattern::Port(PortType::PostgreSQL)
+        Pattern::AnyOf(vec![
+            // Authentication request or FATAL error in reply to a startup message
+            Pattern::Banner(
+                PortType::PostgreSQL,
+                r"^(R\\x00\\x00\\x00|E\\x00\\x00.{1,8}SFATAL)",
+            ),
+            Pattern::Port(PortType::PostgreSQL),
+        ])
     }
     fn logo_url(&self) -> &'static str {
         "https://cdn.jsdelivr.net/gh/homarr-labs/dashboard-icons/svg/postgresql.svg"
@@ -18,7 +18,11 @@ impl ServiceDefinition for RedisDB {
         ServiceCategory::Database
     }
     fn discovery_pattern(&self) -> Pattern<'_> {
-        Pattern::Port(PortType::Redis)
+        Pattern::AnyOf(vec![
+            // Reply to an inline PING, with or without auth / protected mode
+            Pattern::Banner(PortType::Redis, r"^(\+PONG|-NOAUTH|-DENIED Redis)"),
+            Pattern::Port(PortType::Redis),
+        ])
     }
     fn logo_url(&self) -> &'static str {
         "https://cdn.jsdelivr.net/gh/homarr-labs/dashboard-icons/svg/redis.svg"
@@ -1,5 +1,6 @@
 use crate::server::ports::r#impl::base::PortType;
 use crate::server::services::definitions::{ServiceDefinitionFactory, create_service};
+use crate::server::services::r#impl::advertisements::UpnpField;
 use crate::server::services::r#impl::categories::ServiceCategory;
 use crate::server::services::r#impl::definitions::ServiceDefinition;
 use crate::server::services::r#impl::patterns::{Pattern, Vendor};
@@ -21,9 +22,12 @@ impl ServiceDefinition for RokuDevice {
     }
 
     fn discovery_pattern(&self) -> Pattern<'_> {
-        Pattern::AllOf(vec![
-            Pattern::MacVendor(Vendor::ROKU),
-            Pattern::Port(PortType::new_tcp(8060)),
+        Pattern::AnyOf(vec![
+            Pattern::Upnp(UpnpField::DeviceType, "urn:roku-com:device:player"),
+            Pattern::AllOf(vec![
+                Pattern::MacVendor(Vendor::ROKU),
+                Pattern::Port(PortType::new_tcp(8060)),
+            ]),
         ])
     }
 
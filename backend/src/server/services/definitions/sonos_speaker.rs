@@ -1,5 +1,6 @@
 use crate::server::ports::r#impl::base::PortType;
 use crate::server::services::definitions::{ServiceDefinitionFactory, create_service};
+use crate::server::services::r#impl::advertisements::UpnpField;
 use crate::server::services::r#impl::categories::ServiceCategory;
 use crate::server::services::r#impl::definitions::ServiceDefinition;
 use crate::server::services::r#impl::patterns::{Pattern, Vendor};
@@ -23,17 +24,22 @@ impl ServiceDefinition for SonosSpeaker {
     fn discovery_pattern(&self) -> Pattern<'_> {
         // Sonos speakers have very distinctive port signature:
         // TCP 1400 (HTTP API), 1443 (HTTPS API), 4444 (control)
-        Pattern::AllOf(vec![
-            Pattern::MacVendor(Vendor::SONOS),
-            Pattern::AnyOf(vec![
-                Pattern::Port(PortType::Samba),
-                Pattern::Port(PortType::new_tcp(3445)),
-                Pattern::Port(PortType::new_tcp(1400)),
-                Pattern::Port(PortType::new_tcp(1410)),
-                Pattern::Port(PortType::new_tcp(1843)),
-                Pattern::Port(PortType::new_tcp(3400)),
-                Pattern::Port(PortType::new_tcp(3401)),
-                Pattern::Port(PortType::new_tcp(3500)),
+        // They also announce themselves as UPnP ZonePlayers and over mDNS as _sonos._tcp
+        Pattern::AnyOf(vec![
+            Pattern::MdnsService("_sonos._tcp"),
+            Pattern::Upnp(UpnpField::DeviceType, "ZonePlayer"),
+            Pattern::AllOf(vec![
+                Pattern::MacVendor(Vendor::SONOS),
+                Pattern::AnyOf(vec![
+                    Pattern::Port(PortType::Samba),
+                    Pattern::Port(PortType::new_tcp(3445)),
+                    Pattern::Port(PortType::new_tcp(1400)),
+                    Pattern::Port(PortType::new_tcp(1410)),
+                    Pattern::Port(PortType::new_tcp(1843)),
+                    Pattern::Port(PortType::new_tcp(3400)),
+                    Pattern::Port(PortType::new_tcp(3401)),
+                    Pattern::Port(PortType::new_tcp(3500)),
+                ]),
             ]),
         ])
     }
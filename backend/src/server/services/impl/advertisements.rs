@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+/// What a host announced about itself over mDNS and SSDP, used alongside ports and
+/// endpoint responses when matching services
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ServiceAdvertisements {
+    pub mdns: Vec<MdnsAdvertisement>,
+    pub upnp: Vec<UpnpDescription>,
+}
+
+/// A DNS-SD service instance, e.g. "Living Room._googlecast._tcp.local"
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MdnsAdvertisement {
+    /// Service type without the domain, e.g. "_googlecast._tcp"
+    pub service_type: String,
+    pub instance_name: Option<String>,
+    /// Port from the instance's SRV record
+    pub port: Option<u16>,
+    /// TXT record key/value pairs, keys lowercased
+    pub txt: BTreeMap<String, String>,
+}
+
+/// Identity fields from a UPnP device description document
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UpnpDescription {
+    pub device_type: Option<String>,
+    pub friendly_name: Option<String>,
+    pub manufacturer: Option<String>,
+    pub model_name: Option<String>,
+}
+
+impl UpnpDescription {
+    pub fn field(&self, field: UpnpField) -> Option<&str> {
+        match field {
+            UpnpField::DeviceType => self.device_type.as_deref(),
+            UpnpField::Manufacturer => self.manufacturer.as_deref(),
+            UpnpField::ModelName => self.model_name.as_deref(),
+        }
+    }
+}
+
+/// UPnP description fields that service patterns can match on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+pub enum UpnpField {
+    DeviceType,
+    Manufacturer,
+    ModelName,
+}
+
+impl Display for UpnpField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            UpnpField::DeviceType => "deviceType",
+            UpnpField::Manufacturer => "manufacturer",
+            UpnpField::ModelName => "modelName",
+        };
+
+        write!(f, "{}", str)
+    }
+}
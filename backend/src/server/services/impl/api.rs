@@ -116,6 +116,7 @@ impl CreateServiceRequest {
                 source,
                 tags,
                 position: 0, // Position assigned during creation based on existing services
+                proxy_targets: Vec::new(),
             },
         }
     }
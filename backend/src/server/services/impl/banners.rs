@@ -0,0 +1,48 @@
+use crate::server::ports::r#impl::base::PortType;
+use std::fmt::{Display, Write};
+
+/// Longest banner kept from a port, in bytes received
+pub const MAX_BANNER_BYTES: usize = 512;
+
+/// What a TCP port sent back on connect (or in reply to a probe), used alongside ports and
+/// endpoint responses when matching services
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BannerResponse {
+    pub port_type: PortType,
+    /// Received bytes as text. Printable ASCII, tab, CR and LF are kept as-is and any
+    /// other byte is written as a `\xNN` escape, so binary handshakes (MySQL, PostgreSQL)
+    /// can still be matched with a regex, e.g. `^R\\x00\\x00\\x00`
+    pub banner: String,
+}
+
+impl BannerResponse {
+    pub fn from_bytes(port_type: PortType, bytes: &[u8]) -> Self {
+        let mut banner = String::with_capacity(bytes.len());
+        for byte in bytes.iter().take(MAX_BANNER_BYTES) {
+            match byte {
+                b'\t' | b'\r' | b'\n' | 0x20..=0x7e => banner.push(*byte as char),
+                _ => {
+                    let _ = write!(banner, "\\x{:02x}", byte);
+                }
+            }
+        }
+
+        Self {
+            port_type,
+            banner: banner.trim_end().to_string(),
+        }
+    }
+}
+
+impl Display for BannerResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let first_line = self.banner.lines().next().unwrap_or_default();
+        let length = first_line.len().min(40);
+        write!(
+            f,
+            "Banner from port {} -- {}...",
+            self.port_type.number(),
+            &first_line[0..length]
+        )
+    }
+}
@@ -2,14 +2,14 @@ use crate::server::bindings::r#impl::base::Binding;
 use crate::server::discovery::r#impl::types::DiscoveryType;
 use crate::server::interfaces::r#impl::base::Interface;
 use crate::server::ports::r#impl::base::{Port, PortType};
-use crate::server::services::definitions::ServiceDefinitionRegistry;
+use crate::server::services::r#impl::advertisements::ServiceAdvertisements;
+use crate::server::services::r#impl::banners::BannerResponse;
 use crate::server::services::r#impl::definitions::ServiceDefinitionExt;
 use crate::server::services::r#impl::definitions::{DefaultServiceDefinition, ServiceDefinition};
 use crate::server::services::r#impl::endpoints::{Endpoint, EndpointResponse};
 use crate::server::services::r#impl::patterns::{MatchConfidence, MatchReason};
-use crate::server::services::r#impl::virtualization::{
-    DockerVirtualization, ServiceVirtualization,
-};
+use crate::server::services::r#impl::proxy::ProxyTarget;
+use crate::server::services::r#impl::virtualization::ServiceVirtualization;
 use crate::server::shared::entities::ChangeTriggersTopologyStaleness;
 use crate::server::shared::position::Positioned;
 use crate::server::shared::storage::traits::Storable;
@@ -44,6 +44,11 @@ pub struct ServiceBase {
     #[serde(default)]
     #[schema(required)]
     pub position: i32,
+    /// Backends this service forwards requests to, for reverse proxies whose routes
+    /// discovery could read
+    #[serde(default)]
+    #[schema(required, read_only)]
+    pub proxy_targets: Vec<ProxyTarget>,
 }
 
 impl Default for ServiceBase {
@@ -58,6 +63,7 @@ impl Default for ServiceBase {
             source: EntitySource::Unknown,
             tags: Vec::new(),
             position: 0,
+            proxy_targets: Vec::new(),
         }
     }
 }
@@ -68,6 +74,7 @@ impl ChangeTriggersTopologyStaleness<Service> for Service {
             self.base.bindings != other_service.base.bindings
                 || self.base.host_id != other_service.base.host_id
                 || self.base.virtualization != other_service.base.virtualization
+                || self.base.proxy_targets != other_service.base.proxy_targets
         } else {
             true
         }
@@ -104,10 +111,14 @@ pub struct DiscoverySessionServiceMatchParams<'a> {
 
 #[derive(Debug, Clone)]
 pub struct ServiceMatchBaselineParams<'a> {
+    /// Definitions the session matches against: built-in plus the organization's custom ones
+    pub service_definitions: &'a [Box<dyn ServiceDefinition>],
     pub subnet: &'a Subnet,
     pub interface: &'a Interface,
     pub all_ports: &'a Vec<PortType>,
     pub endpoint_responses: &'a Vec<EndpointResponse>,
+    pub banners: &'a Vec<BannerResponse>,
+    pub advertisements: &'a ServiceAdvertisements,
     pub virtualization: &'a Option<ServiceVirtualization>,
 }
 
@@ -158,16 +169,9 @@ impl PartialEq for Service {
         // === GENERIC SERVICE EQUALITY ===
         // All possible permutations of generic services on the same host:
 
-        // Extract virtualization info
-        let self_docker = self.base.virtualization.as_ref().map(|v| {
-            let ServiceVirtualization::Docker(dv) = v;
-            dv
-        });
-
-        let other_docker = other.base.virtualization.as_ref().map(|v| {
-            let ServiceVirtualization::Docker(dv) = v;
-            dv
-        });
+        // Extract virtualization info (Docker container or Kubernetes pod)
+        let self_workload = self.base.virtualization.as_ref();
+        let other_workload = other.base.virtualization.as_ref();
 
         // Extract port IDs from bindings
         let self_port_ids: std::collections::HashSet<_> = self
@@ -188,7 +192,7 @@ impl PartialEq for Service {
             && !other_port_ids.is_empty()
             && !self_port_ids.is_disjoint(&other_port_ids);
 
-        match (self_docker, other_docker) {
+        match (self_workload, other_workload) {
             // ========================================
             // CASE 1: Both containerized
             // ========================================
@@ -197,7 +201,7 @@ impl PartialEq for Service {
                 // Match Method: Container ID equality
                 // Example: PostgreSQL container discovered via docker scan vs network scan
                 if let (Some(self_cid), Some(other_cid)) =
-                    (&self_dv.container_id, &other_dv.container_id)
+                    (self_dv.workload_id(), other_dv.workload_id())
                 {
                     return self_cid == other_cid;
                 }
@@ -205,7 +209,7 @@ impl PartialEq for Service {
                 // CASE 1B: Only one has container ID
                 // Match Method: Different services
                 // Example: Shouldn't happen in practice, but treat as different
-                if self_dv.container_id.is_some() || other_dv.container_id.is_some() {
+                if self_dv.workload_id().is_some() || other_dv.workload_id().is_some() {
                     return false;
                 }
 
@@ -213,7 +217,7 @@ impl PartialEq for Service {
                 // Match Method: Container name equality
                 // Example: Edge case where container_id wasn't captured
                 if let (Some(self_cname), Some(other_cname)) =
-                    (&self_dv.container_name, &other_dv.container_name)
+                    (self_dv.workload_name(), other_dv.workload_name())
                 {
                     return self_cname == other_cname;
                 }
@@ -301,8 +305,8 @@ impl Service {
             .collect()
     }
 
-    pub fn all_discovery_ports() -> Vec<PortType> {
-        let mut ports: Vec<PortType> = ServiceDefinitionRegistry::all_service_definitions()
+    pub fn all_discovery_ports(definitions: &[Box<dyn ServiceDefinition>]) -> Vec<PortType> {
+        let mut ports: Vec<PortType> = definitions
             .iter()
             .flat_map(|s| s.discovery_pattern().ports())
             .collect();
@@ -312,8 +316,8 @@ impl Service {
         ports
     }
 
-    pub fn all_discovery_endpoints() -> Vec<Endpoint> {
-        let mut endpoints: Vec<Endpoint> = ServiceDefinitionRegistry::all_service_definitions()
+    pub fn all_discovery_endpoints(definitions: &[Box<dyn ServiceDefinition>]) -> Vec<Endpoint> {
+        let mut endpoints: Vec<Endpoint> = definitions
             .iter()
             .flat_map(|s| s.discovery_pattern().endpoints())
             .collect();
@@ -324,9 +328,9 @@ impl Service {
     }
 
     /// Get ports that appear ONLY in endpoint patterns, not in port scan patterns
-    pub fn endpoint_only_ports() -> Vec<PortType> {
-        let port_scan_ports = Self::all_discovery_ports();
-        let endpoint_ports: Vec<PortType> = Self::all_discovery_endpoints()
+    pub fn endpoint_only_ports(definitions: &[Box<dyn ServiceDefinition>]) -> Vec<PortType> {
+        let port_scan_ports = Self::all_discovery_ports(definitions);
+        let endpoint_ports: Vec<PortType> = Self::all_discovery_endpoints(definitions)
             .iter()
             .map(|e| e.port_type)
             .collect();
@@ -391,12 +395,9 @@ impl Service {
             let mut name = service_definition.name().to_string();
 
             if ServiceDefinitionExt::is_generic(&service_definition) {
-                if let Some(ServiceVirtualization::Docker(DockerVirtualization {
-                    container_name: Some(c_name),
-                    ..
-                })) = virtualization
+                if let Some(workload_name) = virtualization.as_ref().and_then(|v| v.workload_name())
                 {
-                    name = c_name.clone()
+                    name = workload_name.to_string()
                 }
 
                 // Confidence not applicable for generic services
@@ -437,6 +438,7 @@ impl Service {
                     details: result.details.clone(),
                 },
                 position: 0, // Discovery services get position assigned during merge
+                proxy_targets: Vec::new(),
             });
 
             Some((service, ports, result.endpoint))
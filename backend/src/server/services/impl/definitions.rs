@@ -1,3 +1,6 @@
+use crate::server::custom_service_definitions::r#impl::definition::{
+    CUSTOM_DEFINITION_ID_PREFIX, RuntimeServiceDefinition,
+};
 use crate::server::services::definitions::ServiceDefinitionRegistry;
 use crate::server::services::definitions::docker_daemon::Docker;
 use crate::server::services::definitions::proxmox::Proxmox;
@@ -19,10 +22,16 @@ use utoipa::{PartialSchema, ToSchema};
 // Main trait used in service definition implementation
 pub trait ServiceDefinition: HasId + DynClone + DynHash + DynEq + Send + Sync {
     /// Service name, will also be used as unique identifier. < 40 characters.
-    fn name(&self) -> &'static str;
+    fn name(&self) -> &str;
+
+    /// Unique identifier. Defaults to the name; user-defined definitions use a namespaced
+    /// id since their names only need to be unique within an organization.
+    fn definition_id(&self) -> &str {
+        self.name()
+    }
 
     /// Service description. < 100 characters.
-    fn description(&self) -> &'static str;
+    fn description(&self) -> &str;
 
     /// Category from ServiceCategory enum
     fn category(&self) -> ServiceCategory;
@@ -41,7 +50,7 @@ pub trait ServiceDefinition: HasId + DynClone + DynHash + DynEq + Send + Sync {
     /// Simple Icons: Home Assistant -> https://simpleicons.org/icons/homeassistant.svg.
     /// Vector Logo Icons: Akamai -> https://www.vectorlogo.zone/logos/akamai/akamai-icon.svg
     /// Static file: Scanopy -> /logos/scanopy-logo.png
-    fn logo_url(&self) -> &'static str {
+    fn logo_url(&self) -> &str {
         ""
     }
 
@@ -55,21 +64,25 @@ impl<T: ServiceDefinition> HasId for T
 where
     T: ServiceDefinition,
 {
-    fn id(&self) -> &'static str {
-        self.name()
+    fn id(&self) -> &str {
+        self.definition_id()
     }
 }
 
 impl ServiceDefinition for Box<dyn ServiceDefinition> {
-    fn name(&self) -> &'static str {
+    fn name(&self) -> &str {
         ServiceDefinition::name(&**self)
     }
 
-    fn description(&self) -> &'static str {
+    fn definition_id(&self) -> &str {
+        ServiceDefinition::definition_id(&**self)
+    }
+
+    fn description(&self) -> &str {
         ServiceDefinition::description(&**self)
     }
 
-    fn logo_url(&self) -> &'static str {
+    fn logo_url(&self) -> &str {
         ServiceDefinition::logo_url(&**self)
     }
 
@@ -158,14 +171,14 @@ impl EntityMetadataProvider for Box<dyn ServiceDefinition> {
 }
 
 impl TypeMetadataProvider for Box<dyn ServiceDefinition> {
-    fn name(&self) -> &'static str {
+    fn name(&self) -> &str {
         ServiceDefinition::name(self)
     }
-    fn description(&self) -> &'static str {
+    fn description(&self) -> &str {
         ServiceDefinition::description(self)
     }
     fn category(&self) -> &'static str {
-        ServiceDefinition::category(self).id()
+        ServiceDefinition::category(self).into()
     }
     fn metadata(&self) -> serde_json::Value {
         serde_json::json!({
@@ -219,6 +232,10 @@ impl<'de> Deserialize<'de> for Box<dyn ServiceDefinition> {
         let id = String::deserialize(deserializer)?;
         match ServiceDefinitionRegistry::find_by_id(&id) {
             Some(def) => Ok(def),
+            // User-defined definitions are organization-scoped, so only the id is known here
+            None if id.starts_with(CUSTOM_DEFINITION_ID_PREFIX) => {
+                Ok(Box::new(RuntimeServiceDefinition::unresolved(&id)))
+            }
             None => {
                 // Log a warning but don't fail deserialization
                 tracing::warn!(
@@ -1,10 +1,13 @@
+pub mod advertisements;
 pub mod api;
+pub mod banners;
 pub mod base;
 pub mod categories;
 pub mod definitions;
 pub mod endpoints;
 pub mod handlers;
 pub mod patterns;
+pub mod proxy;
 pub mod storage;
 #[cfg(test)]
 pub mod tests;
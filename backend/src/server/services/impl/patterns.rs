@@ -1,13 +1,12 @@
 use crate::server::{
-    services::{
-        definitions::ServiceDefinitionRegistry,
-        r#impl::{
-            base::{
-                DiscoverySessionServiceMatchParams, ServiceMatchBaselineParams,
-                ServiceMatchServiceParams,
-            },
-            virtualization::ServiceVirtualization,
+    services::r#impl::{
+        advertisements::{MdnsAdvertisement, UpnpField},
+        banners::BannerResponse,
+        base::{
+            DiscoverySessionServiceMatchParams, ServiceMatchBaselineParams,
+            ServiceMatchServiceParams,
         },
+        virtualization::ServiceVirtualization,
     },
     shared::types::metadata::TypeMetadataProvider,
     subnets::r#impl::types::SubnetType,
@@ -15,6 +14,7 @@ use crate::server::{
 use anyhow::{Error, anyhow};
 use itertools::Itertools;
 use mac_oui::Oui;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use std::{net::IpAddr, ops::Range};
@@ -172,6 +172,11 @@ pub enum Pattern<'a> {
     /// status_code: optional, defaults to 200..300 (any ok or redirect)
     Header(Option<PortType>, &'a str, &'a str, Option<Range<u16>>),
 
+    /// Whether the banner a TCP port sent on connect, or in reply to a generic probe, matches a regex
+    /// PortType
+    /// regex: &str - ie r"^SSH-\d+\.\d+-". Non-printable bytes in the banner are escaped as \xNN
+    Banner(PortType, &'a str),
+
     /// Whether the subnet that the host was found on matches a subnet type
     SubnetIsType(SubnetType),
 
@@ -179,7 +184,22 @@ pub enum Pattern<'a> {
     IsGateway,
 
     /// Whether the vendor derived from the mac address (https://gist.github.com/aallan/b4bb86db86079509e6159810ae9bd3e4) matches the provided str
-    MacVendor(&'static str),
+    MacVendor(&'a str),
+
+    /// Whether the host advertised a DNS-SD service type over mDNS
+    /// service_type: &str - ie "_googlecast._tcp", "_airplay._tcp"
+    MdnsService(&'a str),
+
+    /// Whether a TXT record of an mDNS service advertised by the host contains a value
+    /// service_type: &str - ie "_googlecast._tcp"
+    /// key: &str - TXT key, ie "md"
+    /// value: &str - string to match on in the TXT value
+    MdnsTxt(&'a str, &'a str, &'a str),
+
+    /// Whether a field of the host's UPnP device description (found via SSDP) contains a value
+    /// UpnpField - deviceType, manufacturer or modelName
+    /// value: &str - string to match on in the field
+    Upnp(UpnpField, &'a str),
 
     /// Custom evaluation of discovery match params
     /// fn - constraint function
@@ -235,9 +255,20 @@ impl PartialEq for Pattern<'_> {
             ) => {
                 port_a == port_b && header_a == header_b && value_a == value_b && range_a == range_b
             }
+            (Pattern::Banner(port_a, regex_a), Pattern::Banner(port_b, regex_b)) => {
+                port_a == port_b && regex_a == regex_b
+            }
             (Pattern::SubnetIsType(a), Pattern::SubnetIsType(b)) => a == b,
             (Pattern::IsGateway, Pattern::IsGateway) => true,
             (Pattern::MacVendor(a), Pattern::MacVendor(b)) => a == b,
+            (Pattern::MdnsService(a), Pattern::MdnsService(b)) => a == b,
+            (
+                Pattern::MdnsTxt(type_a, key_a, value_a),
+                Pattern::MdnsTxt(type_b, key_b, value_b),
+            ) => type_a == type_b && key_a == key_b && value_a == value_b,
+            (Pattern::Upnp(field_a, value_a), Pattern::Upnp(field_b, value_b)) => {
+                field_a == field_b && value_a == value_b
+            }
             (
                 Pattern::Custom(con_fn_a, port_fn_a, match_a, no_match_a, conf_a),
                 Pattern::Custom(con_fn_b, port_fn_b, match_b, no_match_b, conf_b),
@@ -312,12 +343,31 @@ impl Display for Pattern<'_> {
                     )
                 }
             }
+            Pattern::Banner(port_base, regex) => write!(
+                f,
+                "Banner from <ip>:{} matches \"{}\"",
+                port_base.number(),
+                regex
+            ),
             Pattern::SubnetIsType(subnet_type) => write!(f, "Subnet is type {:?}", subnet_type),
             Pattern::IsGateway => write!(
                 f,
                 "Host IP is a gateway in daemon's routing tables, or ends in .1 or .254."
             ),
             Pattern::MacVendor(vendor) => write!(f, "MAC Address belongs to {}", vendor),
+            Pattern::MdnsService(service_type) => {
+                write!(f, "Host advertises mDNS service {}", service_type)
+            }
+            Pattern::MdnsTxt(service_type, key, value) => write!(
+                f,
+                "Host advertises mDNS service {} with TXT record \"{}\" containing \"{}\"",
+                service_type, key, value
+            ),
+            Pattern::Upnp(field, value) => write!(
+                f,
+                "UPnP device description {} contains \"{}\"",
+                field, value
+            ),
             Pattern::Custom(_, _, _, _, _) => {
                 write!(f, "A custom match pattern evaluated at runtime")
             }
@@ -346,7 +396,10 @@ impl Pattern<'_> {
             subnet,
             interface,
             endpoint_responses,
+            banners,
+            advertisements,
             virtualization,
+            service_definitions,
             ..
         } = baseline_params;
 
@@ -356,15 +409,33 @@ impl Pattern<'_> {
             ..
         } = service_params;
 
+        // Bind the port an mDNS service was advertised on, if discovery found it open
+        let advertised_ports = |advertisement: &MdnsAdvertisement| -> Vec<PortType> {
+            advertisement
+                .port
+                .and_then(|number| {
+                    let port = if advertisement.service_type.ends_with("._udp") {
+                        PortType::new_udp(number)
+                    } else {
+                        PortType::new_tcp(number)
+                    };
+                    unbound_ports
+                        .iter()
+                        .find(|p| p.number() == port.number() && p.protocol() == port.protocol())
+                        .copied()
+                })
+                .into_iter()
+                .collect()
+        };
+
         match self {
             Pattern::Port(port_base) => {
                 if let Some(matched_port) = unbound_ports.iter().find(|p| **p == *port_base) {
-                    let mut all_other_services_ports: Vec<PortType> =
-                        ServiceDefinitionRegistry::all_service_definitions()
-                            .iter()
-                            .filter(|s| s.id() != service_definition.id())
-                            .flat_map(|s| s.discovery_pattern().ports())
-                            .collect();
+                    let mut all_other_services_ports: Vec<PortType> = service_definitions
+                        .iter()
+                        .filter(|s| s.id() != service_definition.id())
+                        .flat_map(|s| s.discovery_pattern().ports())
+                        .collect();
 
                     all_other_services_ports.sort_by_key(|p| (p.number(), p.protocol()));
                     all_other_services_ports.dedup();
@@ -595,6 +666,135 @@ impl Pattern<'_> {
                 }
             }
 
+            Pattern::Banner(port_base, expected_regex) => {
+                let Some(matched_port) = unbound_ports.iter().find(|p| **p == *port_base) else {
+                    return Err(anyhow!("Port {} is not open", port_base));
+                };
+
+                let Some(BannerResponse { banner, .. }) =
+                    banners.iter().find(|b| b.port_type == *port_base)
+                else {
+                    return Err(anyhow!("No banner received from port {}", port_base));
+                };
+
+                let regex = Regex::new(expected_regex)
+                    .map_err(|e| anyhow!("Invalid banner regex \"{}\": {}", expected_regex, e))?;
+
+                match regex.find(banner) {
+                    Some(found) => Ok(MatchResult {
+                        ports: vec![*matched_port],
+                        endpoint: None,
+                        mac_vendor: None,
+                        details: MatchDetails {
+                            reason: MatchReason::Reason(format!(
+                                "Banner from port {} contained \"{}\"",
+                                port_base.number(),
+                                found.as_str()
+                            )),
+                            confidence: MatchConfidence::High,
+                        },
+                    }),
+                    None => Err(anyhow!(
+                        "Banner from port {} did not match \"{}\"",
+                        port_base.number(),
+                        expected_regex
+                    )),
+                }
+            }
+
+            Pattern::MdnsService(expected_type) => {
+                match advertisements
+                    .mdns
+                    .iter()
+                    .find(|a| a.service_type.eq_ignore_ascii_case(expected_type))
+                {
+                    Some(advertisement) => Ok(MatchResult {
+                        ports: advertised_ports(advertisement),
+                        endpoint: None,
+                        mac_vendor: None,
+                        details: MatchDetails {
+                            reason: MatchReason::Reason(format!(
+                                "Host advertised mDNS service {}",
+                                advertisement.service_type
+                            )),
+                            confidence: MatchConfidence::High,
+                        },
+                    }),
+                    None => Err(anyhow!(
+                        "Host did not advertise mDNS service {}",
+                        expected_type
+                    )),
+                }
+            }
+
+            Pattern::MdnsTxt(expected_type, expected_key, expected_value) => {
+                let match_result = advertisements
+                    .mdns
+                    .iter()
+                    .filter(|a| a.service_type.eq_ignore_ascii_case(expected_type))
+                    .find_map(|a| {
+                        a.txt
+                            .get(&expected_key.to_lowercase())
+                            .filter(|value| {
+                                value
+                                    .to_lowercase()
+                                    .contains(&expected_value.to_lowercase())
+                            })
+                            .map(|value| (a, value))
+                    });
+
+                match match_result {
+                    Some((advertisement, value)) => Ok(MatchResult {
+                        ports: advertised_ports(advertisement),
+                        endpoint: None,
+                        mac_vendor: None,
+                        details: MatchDetails {
+                            reason: MatchReason::Reason(format!(
+                                "mDNS service {} TXT record {}={}",
+                                advertisement.service_type, expected_key, value
+                            )),
+                            confidence: MatchConfidence::High,
+                        },
+                    }),
+                    None => Err(anyhow!(
+                        "Host did not advertise mDNS service {} with TXT record {} containing {}",
+                        expected_type,
+                        expected_key,
+                        expected_value
+                    )),
+                }
+            }
+
+            Pattern::Upnp(field, expected_value) => {
+                let match_result = advertisements.upnp.iter().find_map(|description| {
+                    description.field(*field).filter(|value| {
+                        value
+                            .to_lowercase()
+                            .contains(&expected_value.to_lowercase())
+                    })
+                });
+
+                match match_result {
+                    Some(value) => Ok(MatchResult {
+                        ports: vec![],
+                        endpoint: None,
+                        mac_vendor: None,
+                        details: MatchDetails {
+                            reason: MatchReason::Reason(format!(
+                                "UPnP device description {} is \"{}\"",
+                                field, value
+                            )),
+                            confidence: MatchConfidence::High,
+                        },
+                    }),
+                    None => Err(anyhow!(
+                        "No UPnP device description with {} containing {}",
+                        field,
+                        expected_value
+                    )),
+                }
+            }
+
             Pattern::Not(pattern) => match pattern.matches(params) {
                 Ok(result) => Err(anyhow!("{}", result.details.reason)),
                 Err(e) => Ok(MatchResult {
@@ -849,9 +1049,10 @@ impl Pattern<'_> {
     /// Get all ports which need to be scanned for a given service's match pattern
     /// This skips ports from endpoints/headers because we don't want to scan a port if it's just being used in an endpoint (unnecessary network request)
     /// There's logic to add any endpoint-specific ports into scanning in scan_ports_and_endpoints and the docker discovery equivalent
+    /// Banner ports are included, a banner is only grabbed from a port the scan found open
     pub fn ports(&self) -> Vec<PortType> {
         match self {
-            Pattern::Port(port) => vec![*port],
+            Pattern::Port(port) | Pattern::Banner(port, _) => vec![*port],
             Pattern::AnyOf(patterns) | Pattern::AllOf(patterns) => {
                 patterns.iter().flat_map(|p| p.ports().to_vec()).collect()
             }
@@ -906,10 +1107,13 @@ impl Pattern<'_> {
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
     use std::net::IpAddr;
 
-    use crate::server::discovery::r#impl::types::{DiscoveryType, HostNamingFallback};
+    use crate::server::discovery::r#impl::scan_profile::ScanProfile;
+    use crate::server::discovery::r#impl::types::{
+        DiscoveryType, HostNamingFallback, HostnameSource,
+    };
     use crate::server::services::r#impl::base::Service;
     use crate::server::services::r#impl::virtualization::ServiceVirtualization;
     use crate::server::snmp_credentials::r#impl::discovery::SnmpCredentialMapping;
@@ -923,6 +1127,10 @@ mod tests {
             services::{
                 definitions::ServiceDefinitionRegistry,
                 r#impl::{
+                    advertisements::{
+                        MdnsAdvertisement, ServiceAdvertisements, UpnpDescription, UpnpField,
+                    },
+                    banners::BannerResponse,
                     base::{
                         DiscoverySessionServiceMatchParams, ServiceMatchBaselineParams,
                         ServiceMatchServiceParams,
@@ -938,6 +1146,7 @@ mod tests {
     };
 
     struct TestContext {
+        service_definitions: Vec<Box<dyn ServiceDefinition>>,
         subnet: Subnet,
         interface: Interface,
         pi: Box<dyn ServiceDefinition>,
@@ -947,6 +1156,8 @@ mod tests {
         discovery_type: DiscoveryType,
         gateway_ips: Vec<IpAddr>,
         endpoint_responses: Vec<EndpointResponse>,
+        banners: Vec<BannerResponse>,
+        advertisements: ServiceAdvertisements,
         virtualization: Option<ServiceVirtualization>,
         matched_services: Vec<Service>,
     }
@@ -968,6 +1179,7 @@ mod tests {
             }];
 
             Self {
+                service_definitions: ServiceDefinitionRegistry::all_service_definitions(),
                 subnet,
                 interface,
                 pi,
@@ -978,10 +1190,16 @@ mod tests {
                     subnet_ids: None,
                     host_naming_fallback: HostNamingFallback::BestService,
                     snmp_credentials: SnmpCredentialMapping::default(),
+                    custom_service_definitions: Vec::new(),
                     probe_raw_socket_ports: false,
+                    scan_profile: ScanProfile::default(),
+                    exclusions: Vec::new(),
+                    hostname_sources: HostnameSource::default_order(),
                 },
                 gateway_ips: vec![],
                 endpoint_responses,
+                banners: vec![],
+                advertisements: ServiceAdvertisements::default(),
                 virtualization: None,
                 matched_services: vec![],
             }
@@ -1012,10 +1230,13 @@ mod tests {
             all_ports: &'a Vec<PortType>,
         ) -> ServiceMatchBaselineParams<'a> {
             ServiceMatchBaselineParams {
+                service_definitions: &self.service_definitions,
                 subnet: &self.subnet,
                 interface: &self.interface,
                 all_ports,
                 endpoint_responses: &self.endpoint_responses,
+                banners: &self.banners,
+                advertisements: &self.advertisements,
                 virtualization: &self.virtualization,
             }
         }
@@ -1128,4 +1349,121 @@ mod tests {
             "OR pattern should not match when no conditions met"
         );
     }
+
+    #[test]
+    fn test_pattern_advertisement_matching() {
+        let mut ctx = TestContext::new();
+        ctx.advertisements = ServiceAdvertisements {
+            mdns: vec![MdnsAdvertisement {
+                service_type: "_googlecast._tcp".to_string(),
+                instance_name: Some("Chromecast-Living-Room".to_string()),
+                port: Some(8009),
+                txt: BTreeMap::from([("md".to_string(), "Chromecast Ultra".to_string())]),
+            }],
+            upnp: vec![UpnpDescription {
+                device_type: Some("urn:schemas-upnp-org:device:ZonePlayer:1".to_string()),
+                manufacturer: Some("Sonos, Inc.".to_string()),
+                ..Default::default()
+            }],
+        };
+
+        let ports = vec![PortType::new_tcp(8009)];
+        let baseline = ctx.create_baseline_params(&ports);
+        let params = ctx.create_params_with_ports(&baseline, &ports);
+
+        let result = Pattern::MdnsService("_googlecast._tcp")
+            .matches(&params)
+            .expect("Advertised service type should match");
+        assert_eq!(
+            result.ports,
+            vec![PortType::new_tcp(8009)],
+            "Advertised port should be bound when open"
+        );
+
+        assert!(
+            Pattern::MdnsTxt("_googlecast._tcp", "md", "chromecast")
+                .matches(&params)
+                .is_ok(),
+            "TXT values should match case-insensitively"
+        );
+        assert!(
+            Pattern::MdnsTxt("_googlecast._tcp", "md", "Google Home")
+                .matches(&params)
+                .is_err(),
+            "TXT value that doesn't contain the string should not match"
+        );
+        assert!(
+            Pattern::MdnsService("_airplay._tcp")
+                .matches(&params)
+                .is_err(),
+            "Service type that wasn't advertised should not match"
+        );
+
+        assert!(
+            Pattern::Upnp(UpnpField::DeviceType, "ZonePlayer")
+                .matches(&params)
+                .is_ok(),
+            "UPnP deviceType should match"
+        );
+        assert!(
+            Pattern::Upnp(UpnpField::ModelName, "Sonos")
+                .matches(&params)
+                .is_err(),
+            "Missing UPnP field should not match"
+        );
+    }
+
+    #[test]
+    fn test_pattern_banner_matching() {
+        let mut ctx = TestContext::new();
+        ctx.banners = vec![
+            BannerResponse::from_bytes(
+                PortType::Ssh,
+                b"SSH-2.0-OpenSSH_9.6p1 Ubuntu-3ubuntu13\r\n",
+            ),
+            // MySQL initial handshake packet, truncated after the auth plugin name
+            BannerResponse::from_bytes(
+                PortType::MySql,
+                b"J\x00\x00\x00\x0a8.0.36\x00\x0b\x00\x00\x00caching_sha2_password\x00",
+            ),
+        ];
+
+        let ports = vec![PortType::Ssh, PortType::MySql, PortType::Redis];
+        let baseline = ctx.create_baseline_params(&ports);
+        let params = ctx.create_params_with_ports(&baseline, &ports);
+
+        let result = Pattern::Banner(PortType::Ssh, r"^SSH-\d+\.\d+-")
+            .matches(&params)
+            .expect("SSH version string should match");
+        assert_eq!(result.ports, vec![PortType::Ssh]);
+
+        assert!(
+            Pattern::Banner(PortType::MySql, r"^.\\x00\\x00\\x00\n\d+\.\d+\.\d+")
+                .matches(&params)
+                .is_ok(),
+            "Escaped binary handshake should match"
+        );
+        assert!(
+            Pattern::Banner(PortType::MySql, "MariaDB")
+                .matches(&params)
+                .is_err(),
+            "Banner that doesn't match the regex should not match"
+        );
+        assert!(
+            Pattern::Banner(PortType::Redis, r"^\+PONG")
+                .matches(&params)
+                .is_err(),
+            "Port without a banner should not match"
+        );
+
+        let ports = vec![PortType::MySql];
+        let baseline = ctx.create_baseline_params(&ports);
+        let params = ctx.create_params_with_ports(&baseline, &ports);
+        assert!(
+            Pattern::Banner(PortType::Ssh, "^SSH-")
+                .matches(&params)
+                .is_err(),
+            "Banner on a port that is already bound should not match"
+        );
+    }
 }
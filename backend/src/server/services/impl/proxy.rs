@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// A backend a reverse proxy forwards requests to, as reported by the proxy's own API
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+pub struct ProxyTarget {
+    /// Name of the proxy route or service forwarding to this backend, when the proxy has one
+    pub route: Option<String>,
+    /// Backend address as configured in the proxy, e.g. `10.0.0.5:8080` or `http://app:3000`
+    pub address: String,
+}
+
+impl ProxyTarget {
+    /// Backends from Caddy's `/reverse_proxy/upstreams` admin endpoint
+    pub fn from_caddy_upstreams(body: &str) -> Vec<ProxyTarget> {
+        let Ok(upstreams) = serde_json::from_str::<Vec<serde_json::Value>>(body) else {
+            return Vec::new();
+        };
+
+        upstreams
+            .iter()
+            .filter_map(|upstream| upstream.get("address")?.as_str())
+            .map(|address| ProxyTarget {
+                route: None,
+                address: address.to_string(),
+            })
+            .collect()
+    }
+
+    /// Load balancer servers from Traefik's `/api/http/services` endpoint
+    pub fn from_traefik_services(body: &str) -> Vec<ProxyTarget> {
+        let Ok(services) = serde_json::from_str::<Vec<serde_json::Value>>(body) else {
+            return Vec::new();
+        };
+
+        services
+            .iter()
+            .flat_map(|service| {
+                let route = service
+                    .get("name")
+                    .and_then(|n| n.as_str())
+                    .map(str::to_string);
+
+                service
+                    .pointer("/loadBalancer/servers")
+                    .and_then(|s| s.as_array())
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|server| server.get("url")?.as_str())
+                    .map(move |url| ProxyTarget {
+                        route: route.clone(),
+                        address: url.to_string(),
+                    })
+            })
+            .collect()
+    }
+
+    /// Host and port of the backend. Addresses with a scheme but no port use the
+    /// scheme's default port.
+    pub fn host_port(&self) -> Option<(&str, Option<u16>)> {
+        let (scheme, rest) = match self.address.split_once("://") {
+            Some((scheme, rest)) => (Some(scheme), rest),
+            None => (None, self.address.as_str()),
+        };
+
+        let authority = rest.split(['/', '?', '#']).next()?;
+        let authority = authority
+            .rsplit_once('@')
+            .map_or(authority, |(_, host)| host);
+
+        let (host, port) = match authority.strip_prefix('[') {
+            // Bracketed IPv6 address
+            Some(bracketed) => {
+                let (host, after) = bracketed.split_once(']')?;
+                (host, after.strip_prefix(':').and_then(|p| p.parse().ok()))
+            }
+            None => match authority.rsplit_once(':') {
+                Some((host, port)) => (host, port.parse().ok()),
+                None => (authority, None),
+            },
+        };
+
+        if host.is_empty() {
+            return None;
+        }
+
+        let port = port.or(match scheme {
+            Some("http") | Some("h2c") => Some(80),
+            Some("https") => Some(443),
+            _ => None,
+        });
+
+        Some((host, port))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProxyTarget;
+
+    #[test]
+    fn parses_proxy_api_responses() {
+        let caddy = r#"[{"address":"10.0.0.5:8080","num_requests":0,"fails":0}]"#;
+        assert_eq!(
+            ProxyTarget::from_caddy_upstreams(caddy),
+            vec![ProxyTarget {
+                route: None,
+                address: "10.0.0.5:8080".to_string(),
+            }]
+        );
+
+        let traefik = r#"[
+            {"name":"api@internal","provider":"internal"},
+            {"name":"whoami@docker","loadBalancer":{"servers":[{"url":"http://172.18.0.3:80"},{"url":"http://172.18.0.4:80"}]}}
+        ]"#;
+        let targets = ProxyTarget::from_traefik_services(traefik);
+        assert_eq!(targets.len(), 2);
+        assert!(
+            targets
+                .iter()
+                .all(|t| t.route.as_deref() == Some("whoami@docker"))
+        );
+
+        assert!(ProxyTarget::from_traefik_services("not json").is_empty());
+    }
+
+    #[test]
+    fn host_port_handles_common_address_forms() {
+        let target = |address: &str| ProxyTarget {
+            route: None,
+            address: address.to_string(),
+        };
+
+        assert_eq!(
+            target("10.0.0.5:8080").host_port(),
+            Some(("10.0.0.5", Some(8080)))
+        );
+        assert_eq!(
+            target("http://app/health").host_port(),
+            Some(("app", Some(80)))
+        );
+        assert_eq!(
+            target("https://user@app.local:8443").host_port(),
+            Some(("app.local", Some(8443)))
+        );
+        assert_eq!(
+            target("http://[fd00::5]:3000").host_port(),
+            Some(("fd00::5", Some(3000)))
+        );
+        assert_eq!(target("backend").host_port(), Some(("backend", None)));
+        assert_eq!(target("http://").host_port(), None);
+    }
+}
@@ -8,6 +8,7 @@ use crate::server::{
     services::r#impl::{
         base::{Service, ServiceBase},
         definitions::ServiceDefinition,
+        proxy::ProxyTarget,
         virtualization::ServiceVirtualization,
     },
     shared::{
@@ -89,6 +90,7 @@ impl Storable for Service {
                     source,
                     tags: _, // Stored in entity_tags junction table
                     position,
+                    proxy_targets,
                 },
         } = self.clone();
 
@@ -104,6 +106,7 @@ impl Storable for Service {
                 "virtualization",
                 "source",
                 "position",
+                "proxy_targets",
             ],
             vec![
                 SqlValue::Uuid(id),
@@ -116,6 +119,7 @@ impl Storable for Service {
                 SqlValue::OptionalServiceVirtualization(virtualization),
                 SqlValue::EntitySource(source),
                 SqlValue::I32(position),
+                SqlValue::JsonValue(serde_json::to_value(&proxy_targets)?),
             ],
         ))
     }
@@ -130,6 +134,9 @@ impl Storable for Service {
         let source: EntitySource =
             serde_json::from_value(row.get::<serde_json::Value, _>("source"))
                 .map_err(|e| anyhow::anyhow!("Failed to deserialize source: {}", e))?;
+        let proxy_targets: Vec<ProxyTarget> =
+            serde_json::from_value(row.get::<serde_json::Value, _>("proxy_targets"))
+                .map_err(|e| anyhow::anyhow!("Failed to deserialize proxy_targets: {}", e))?;
 
         Ok(Service {
             id: row.get("id"),
@@ -145,6 +152,7 @@ impl Storable for Service {
                 tags: Vec::new(),     // Hydrated from entity_tags junction table
                 source,
                 position: row.get("position"),
+                proxy_targets,
             },
         })
     }
@@ -214,6 +222,8 @@ impl Entity for Service {
         if self.base.virtualization.is_none() {
             self.base.virtualization = existing.base.virtualization.clone();
         }
+        // Proxy targets are only read by discovery
+        self.base.proxy_targets = existing.base.proxy_targets.clone();
     }
 }
 
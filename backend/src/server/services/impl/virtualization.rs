@@ -30,6 +30,8 @@ use crate::server::shared::{
 pub enum ServiceVirtualization {
     #[schema(title = "Docker")]
     Docker(DockerVirtualization),
+    #[schema(title = "Kubernetes")]
+    Kubernetes(KubernetesVirtualization),
 }
 
 #[derive(Debug, Clone, Serialize, Validate, Deserialize, PartialEq, Eq, Hash, ToSchema)]
@@ -39,6 +41,41 @@ pub struct DockerVirtualization {
     pub service_id: Uuid,
 }
 
+#[derive(Debug, Clone, Serialize, Validate, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+pub struct KubernetesVirtualization {
+    pub namespace: String,
+    pub pod_name: Option<String>,
+    pub pod_uid: Option<String>,
+    /// Kubernetes service on the node the pod is scheduled on
+    pub service_id: Uuid,
+}
+
+impl ServiceVirtualization {
+    /// ID of the service the workload runs under (the Docker daemon or the node's Kubernetes service)
+    pub fn service_id(&self) -> Uuid {
+        match self {
+            ServiceVirtualization::Docker(v) => v.service_id,
+            ServiceVirtualization::Kubernetes(v) => v.service_id,
+        }
+    }
+
+    /// Container or pod name, which generic services are named after
+    pub fn workload_name(&self) -> Option<&str> {
+        match self {
+            ServiceVirtualization::Docker(v) => v.container_name.as_deref(),
+            ServiceVirtualization::Kubernetes(v) => v.pod_name.as_deref(),
+        }
+    }
+
+    /// Container or pod ID, set when the virtualization identifies a single workload
+    pub fn workload_id(&self) -> Option<&str> {
+        match self {
+            ServiceVirtualization::Docker(v) => v.container_id.as_deref(),
+            ServiceVirtualization::Kubernetes(v) => v.pod_uid.as_deref(),
+        }
+    }
+}
+
 impl HasId for ServiceVirtualization {
     fn id(&self) -> &'static str {
         self.into()
@@ -56,10 +93,16 @@ impl EntityMetadataProvider for ServiceVirtualization {
 
 impl TypeMetadataProvider for ServiceVirtualization {
     fn name(&self) -> &'static str {
-        "Docker"
+        match self {
+            ServiceVirtualization::Docker(..) => "Docker",
+            ServiceVirtualization::Kubernetes(..) => "Kubernetes",
+        }
     }
 
     fn description(&self) -> &'static str {
-        "A service running in a docker container"
+        match self {
+            ServiceVirtualization::Docker(..) => "A service running in a docker container",
+            ServiceVirtualization::Kubernetes(..) => "A service running in a Kubernetes pod",
+        }
     }
 }
@@ -953,6 +953,13 @@ impl ServiceService {
             existing_service.base.virtualization = Some(virtualization.clone())
         }
 
+        // A proxy whose routes couldn't be read this time keeps the last known targets
+        let proxy_targets_updated = !new_service_data.base.proxy_targets.is_empty()
+            && new_service_data.base.proxy_targets != existing_service.base.proxy_targets;
+        if proxy_targets_updated {
+            existing_service.base.proxy_targets = new_service_data.base.proxy_targets.clone();
+        }
+
         existing_service.base.source = match (
             existing_service.base.source,
             new_service_data.base.source.clone(),
@@ -1037,6 +1044,10 @@ impl ServiceService {
             data.push(format!("{} bindings", binding_updates))
         };
 
+        if proxy_targets_updated {
+            data.push("proxy targets".to_string())
+        };
+
         if !data.is_empty() {
             let trigger_stale = existing_service.triggers_staleness(Some(service_before_updates));
 
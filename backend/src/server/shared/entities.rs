@@ -1,4 +1,8 @@
+use crate::server::audit_logs::r#impl::base::AuditLogEntry;
 use crate::server::bindings::r#impl::base::Binding;
+use crate::server::custom_scan_profiles::r#impl::base::CustomScanProfile;
+use crate::server::custom_service_definitions::r#impl::base::CustomServiceDefinition;
+use crate::server::host_changes::r#impl::base::HostChange;
 use crate::server::if_entries::r#impl::base::IfEntry;
 use crate::server::interfaces::r#impl::base::Interface;
 use crate::server::invites::r#impl::base::Invite;
@@ -8,7 +12,10 @@ use crate::server::shared::concepts::Concept;
 use crate::server::shares::r#impl::base::Share;
 use crate::server::snmp_credentials::r#impl::base::SnmpCredential;
 use crate::server::subnets::r#impl::base::Subnet;
+use crate::server::tls_certificates::r#impl::base::TlsCertificate;
 use crate::server::topology::types::base::Topology;
+use crate::server::topology_snapshots::r#impl::base::TopologySnapshot;
+use crate::server::webhooks::r#impl::base::{Webhook, WebhookDelivery};
 use crate::server::{groups::r#impl::base::Group, tags::r#impl::base::Tag};
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumDiscriminants, EnumIter, IntoStaticStr};
@@ -82,6 +89,8 @@ pub enum Entity {
     UserApiKey(UserApiKey),
     User(User),
     Tag(Tag),
+    Webhook(Webhook),
+    WebhookDelivery(WebhookDelivery),
 
     Discovery(Discovery),
     Daemon(Daemon),
@@ -92,11 +101,17 @@ pub enum Entity {
     Binding(Binding),
     Interface(Interface),
     IfEntry(IfEntry),
+    HostChange(HostChange),
+    TlsCertificate(TlsCertificate),
 
     SnmpCredential(SnmpCredential),
+    CustomServiceDefinition(CustomServiceDefinition),
+    CustomScanProfile(CustomScanProfile),
     Subnet(Subnet),
     Group(Group),
     Topology(Box<Topology>),
+    TopologySnapshot(Box<TopologySnapshot>),
+    AuditLogEntry(AuditLogEntry),
 
     #[default]
     #[strum_discriminants(default)]
@@ -119,10 +134,14 @@ impl EntityMetadataProvider for EntityDiscriminants {
             EntityDiscriminants::DaemonApiKey => Color::Yellow,
             EntityDiscriminants::UserApiKey => Color::Yellow,
             EntityDiscriminants::SnmpCredential => Concept::SNMP.color(),
+            EntityDiscriminants::CustomServiceDefinition => Color::Purple,
+            EntityDiscriminants::CustomScanProfile => Color::Purple,
             EntityDiscriminants::User => Color::Blue,
             EntityDiscriminants::Invite => Color::Green,
             EntityDiscriminants::Share => Color::Teal,
             EntityDiscriminants::Tag => Color::Yellow,
+            EntityDiscriminants::Webhook => Color::Teal,
+            EntityDiscriminants::WebhookDelivery => Color::Teal,
 
             EntityDiscriminants::Host => Color::Blue,
             EntityDiscriminants::Service => Color::Purple,
@@ -130,10 +149,14 @@ impl EntityMetadataProvider for EntityDiscriminants {
             EntityDiscriminants::Port => Color::Cyan,
             EntityDiscriminants::Binding => Color::Purple,
             EntityDiscriminants::IfEntry => Color::Teal,
+            EntityDiscriminants::HostChange => Color::Indigo,
+            EntityDiscriminants::TlsCertificate => Color::Emerald,
 
             EntityDiscriminants::Subnet => Color::Orange,
             EntityDiscriminants::Group => Color::Rose,
             EntityDiscriminants::Topology => Color::Pink,
+            EntityDiscriminants::TopologySnapshot => Color::Pink,
+            EntityDiscriminants::AuditLogEntry => Color::Gray,
 
             EntityDiscriminants::Unknown => Color::Gray,
         }
@@ -147,6 +170,8 @@ impl EntityMetadataProvider for EntityDiscriminants {
             EntityDiscriminants::Tag => Icon::Tag,
             EntityDiscriminants::Invite => Icon::UserPlus,
             EntityDiscriminants::Share => Icon::Share2,
+            EntityDiscriminants::Webhook => Icon::Webhook,
+            EntityDiscriminants::WebhookDelivery => Icon::Send,
             EntityDiscriminants::DaemonApiKey => Icon::Key,
             EntityDiscriminants::UserApiKey => Icon::Key,
             EntityDiscriminants::Daemon => Icon::SatelliteDish,
@@ -157,10 +182,16 @@ impl EntityMetadataProvider for EntityDiscriminants {
             EntityDiscriminants::Port => Icon::EthernetPort,
             EntityDiscriminants::Binding => Icon::Link,
             EntityDiscriminants::IfEntry => Icon::Cable,
+            EntityDiscriminants::HostChange => Icon::History,
+            EntityDiscriminants::TlsCertificate => Icon::FileLock,
             EntityDiscriminants::SnmpCredential => Icon::Asterisk,
+            EntityDiscriminants::CustomServiceDefinition => Icon::Layers,
+            EntityDiscriminants::CustomScanProfile => Icon::SlidersHorizontal,
             EntityDiscriminants::Subnet => Icon::Network,
             EntityDiscriminants::Group => Icon::Group,
             EntityDiscriminants::Topology => Icon::ChartBarStacked,
+            EntityDiscriminants::TopologySnapshot => Icon::Camera,
+            EntityDiscriminants::AuditLogEntry => Icon::ScrollText,
 
             EntityDiscriminants::Unknown => Icon::CircleQuestionMark,
         }
@@ -269,6 +300,18 @@ impl From<Topology> for Entity {
     }
 }
 
+impl From<TopologySnapshot> for Entity {
+    fn from(value: TopologySnapshot) -> Self {
+        Self::TopologySnapshot(Box::new(value))
+    }
+}
+
+impl From<AuditLogEntry> for Entity {
+    fn from(value: AuditLogEntry) -> Self {
+        Self::AuditLogEntry(value)
+    }
+}
+
 impl From<Tag> for Entity {
     fn from(value: Tag) -> Self {
         Self::Tag(value)
@@ -281,8 +324,44 @@ impl From<SnmpCredential> for Entity {
     }
 }
 
+impl From<CustomServiceDefinition> for Entity {
+    fn from(value: CustomServiceDefinition) -> Self {
+        Self::CustomServiceDefinition(value)
+    }
+}
+
+impl From<CustomScanProfile> for Entity {
+    fn from(value: CustomScanProfile) -> Self {
+        Self::CustomScanProfile(value)
+    }
+}
+
+impl From<HostChange> for Entity {
+    fn from(value: HostChange) -> Self {
+        Self::HostChange(value)
+    }
+}
+
+impl From<TlsCertificate> for Entity {
+    fn from(value: TlsCertificate) -> Self {
+        Self::TlsCertificate(value)
+    }
+}
+
 impl From<IfEntry> for Entity {
     fn from(value: IfEntry) -> Self {
         Self::IfEntry(value)
     }
 }
+
+impl From<Webhook> for Entity {
+    fn from(value: Webhook) -> Self {
+        Self::Webhook(value)
+    }
+}
+
+impl From<WebhookDelivery> for Entity {
+    fn from(value: WebhookDelivery) -> Self {
+        Self::WebhookDelivery(value)
+    }
+}
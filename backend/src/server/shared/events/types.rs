@@ -319,7 +319,9 @@ impl Display for AuthEvent {
     }
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq, strum::Display)]
+#[derive(
+    Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, strum::Display, utoipa::ToSchema,
+)]
 #[strum(serialize_all = "snake_case")]
 pub enum EntityOperation {
     Get,
@@ -519,9 +521,13 @@ impl DiscoveryUpdatePayload {
             daemon_id: self.daemon_id,
             discovery_type: self.discovery_type.clone(),
             phase: self.phase,
+            started_at: self.started_at,
             timestamp: Utc::now(),
             authentication: AuthenticatedEntity::System,
-            metadata: json!({}),
+            metadata: match &self.error {
+                Some(error) => json!({ "error": error }),
+                None => json!({}),
+            },
         }
     }
 
@@ -543,6 +549,8 @@ pub struct DiscoverySessionEvent {
     pub daemon_id: Uuid,
     pub discovery_type: DiscoveryType,
     pub phase: DiscoveryPhase,
+    /// When the daemon started the session
+    pub started_at: Option<DateTime<Utc>>,
     pub timestamp: DateTime<Utc>,
     pub authentication: AuthenticatedEntity,
     pub metadata: serde_json::Value,
@@ -558,6 +566,7 @@ impl DiscoverySessionEvent {
         daemon_id: Uuid,
         phase: DiscoveryPhase,
         discovery_type: DiscoveryType,
+        started_at: Option<DateTime<Utc>>,
         timestamp: DateTime<Utc>,
         authentication: AuthenticatedEntity,
         metadata: serde_json::Value,
@@ -569,6 +578,7 @@ impl DiscoverySessionEvent {
             daemon_id,
             discovery_type,
             phase,
+            started_at,
             timestamp,
             authentication,
             metadata,
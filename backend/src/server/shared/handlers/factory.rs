@@ -6,18 +6,24 @@ use crate::server::openapi::create_docs_router;
 use crate::server::shared::types::api::ApiResponse;
 use crate::server::shared::types::metadata::{__path_get_metadata_registry, get_metadata_registry};
 use crate::server::{
-    auth::handlers as auth_handlers, billing::handlers as billing_handlers,
-    bindings::handlers as binding_handlers, config::AppState,
+    audit_logs::handlers as audit_log_handlers, auth::handlers as auth_handlers,
+    billing::handlers as billing_handlers, bindings::handlers as binding_handlers,
+    config::AppState, custom_scan_profiles::handlers as custom_scan_profile_handlers,
+    custom_service_definitions::handlers as custom_service_definition_handlers,
     daemon_api_keys::handlers as daemon_api_key_handlers, daemons::handlers as daemon_handlers,
     discovery::handlers as discovery_handlers, groups::handlers as group_handlers,
-    hosts::handlers as host_handlers, if_entries::handlers as if_entry_handlers,
-    interfaces::handlers as interface_handlers, invites::handlers as invite_handlers,
-    metrics::handlers as metrics_handlers, networks::handlers as network_handlers,
-    organizations::handlers as organization_handlers, ports::handlers as port_handlers,
-    services::handlers as service_handlers, shares::handlers as share_handlers,
-    snmp_credentials::handlers as snmp_credential_handlers, subnets::handlers as subnet_handlers,
-    tags::handlers as tag_handlers, topology::handlers as topology_handlers,
+    host_changes::handlers as host_change_handlers, hosts::handlers as host_handlers,
+    if_entries::handlers as if_entry_handlers, interfaces::handlers as interface_handlers,
+    invites::handlers as invite_handlers, metrics::handlers as metrics_handlers,
+    networks::handlers as network_handlers, organizations::handlers as organization_handlers,
+    ports::handlers as port_handlers, services::handlers as service_handlers,
+    shares::handlers as share_handlers, snmp_credentials::handlers as snmp_credential_handlers,
+    subnets::handlers as subnet_handlers, tags::handlers as tag_handlers,
+    tls_certificates::handlers as tls_certificate_handlers,
+    topology::handlers as topology_handlers,
+    topology_snapshots::handlers as topology_snapshot_handlers,
     user_api_keys::handlers as user_api_key_handlers, users::handlers as user_handlers,
+    webhooks::handlers as webhook_handlers,
 };
 use axum::Json;
 use axum::Router;
@@ -96,6 +102,28 @@ fn create_billed_openapi_routes() -> OpenApiRouter<Arc<AppState>> {
             snmp_credential_handlers::create_router(),
         )
         .nest("/api/v1/if-entries", if_entry_handlers::create_router())
+        .nest(
+            "/api/v1/custom-service-definitions",
+            custom_service_definition_handlers::create_router(),
+        )
+        .nest(
+            "/api/v1/custom-scan-profiles",
+            custom_scan_profile_handlers::create_router(),
+        )
+        .nest(
+            "/api/v1/host-changes",
+            host_change_handlers::create_router(),
+        )
+        .nest("/api/v1/webhooks", webhook_handlers::create_router())
+        .nest(
+            "/api/v1/tls-certificates",
+            tls_certificate_handlers::create_router(),
+        )
+        .nest(
+            "/api/v1/topology-snapshots",
+            topology_snapshot_handlers::create_router(),
+        )
+        .nest("/api/v1/audit-log", audit_log_handlers::create_router())
         // Topology endpoints (tagged as internal - hidden from public docs)
         .nest("/api/v1/topology", topology_handlers::create_router())
 }
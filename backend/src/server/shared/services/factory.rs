@@ -1,14 +1,18 @@
 use crate::server::{
+    audit_logs::service::AuditLogService,
     auth::{oidc::OidcService, service::AuthService},
     billing::service::{BillingService, BillingServiceParams},
     bindings::service::BindingService,
     brevo::service::BrevoService,
     config::ServerConfig,
+    custom_scan_profiles::service::CustomScanProfileService,
+    custom_service_definitions::service::CustomServiceDefinitionService,
     daemon_api_keys::service::DaemonApiKeyService,
     daemons::service::DaemonService,
     discovery::service::DiscoveryService,
     email::{brevo::BrevoEmailProvider, smtp::SmtpEmailProvider, traits::EmailService},
     groups::{group_bindings::GroupBindingStorage, service::GroupService},
+    host_changes::service::HostChangeService,
     hosts::service::HostService,
     if_entries::service::IfEntryService,
     interfaces::service::InterfaceService,
@@ -28,11 +32,14 @@ use crate::server::{
         entity_tags::{EntityTagService, EntityTagStorage},
         service::TagService,
     },
+    tls_certificates::{r#impl::base::DEFAULT_EXPIRY_WARNING_DAYS, service::TlsCertificateService},
     topology::service::main::TopologyService,
+    topology_snapshots::service::TopologySnapshotService,
     user_api_keys::{
         r#impl::network_access::UserApiKeyNetworkAccessStorage, service::UserApiKeyService,
     },
     users::{UserNetworkAccessStorage, service::UserService},
+    webhooks::service::WebhookService,
 };
 use anyhow::Result;
 use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
@@ -72,6 +79,13 @@ pub struct ServiceFactory {
     pub binding_service: Arc<BindingService>,
     pub snmp_credential_service: Arc<SnmpCredentialService>,
     pub if_entry_service: Arc<IfEntryService>,
+    pub custom_service_definition_service: Arc<CustomServiceDefinitionService>,
+    pub custom_scan_profile_service: Arc<CustomScanProfileService>,
+    pub host_change_service: Arc<HostChangeService>,
+    pub webhook_service: Arc<WebhookService>,
+    pub tls_certificate_service: Arc<TlsCertificateService>,
+    pub topology_snapshot_service: Arc<TopologySnapshotService>,
+    pub audit_log_service: Arc<AuditLogService>,
 }
 
 impl ServiceFactory {
@@ -187,12 +201,24 @@ impl ServiceFactory {
             interface_service.clone(),
         ));
 
+        let custom_service_definition_service = Arc::new(CustomServiceDefinitionService::new(
+            storage.custom_service_definitions.clone(),
+            event_bus.clone(),
+        ));
+
+        let custom_scan_profile_service = Arc::new(CustomScanProfileService::new(
+            storage.custom_scan_profiles.clone(),
+            event_bus.clone(),
+        ));
+
         // Already implements Arc internally due to scheduler + sessions
         let discovery_service = DiscoveryService::new(
             storage.discovery.clone(),
             event_bus.clone(),
             entity_tag_service.clone(),
             snmp_credential_service.clone(),
+            custom_service_definition_service.clone(),
+            custom_scan_profile_service.clone(),
             network_service.clone(),
             organization_service.clone(),
         )
@@ -211,7 +237,26 @@ impl ServiceFactory {
             daemon_api_key_service.clone(),
         ));
 
+        let tls_certificate_service = Arc::new(TlsCertificateService::new(
+            storage.tls_certificates.clone(),
+            event_bus.clone(),
+        ));
+
+        let host_change_service = Arc::new(HostChangeService::new(
+            storage.host_changes.clone(),
+            interface_service.clone(),
+            port_service.clone(),
+            service_service.clone(),
+            discovery_service.clone(),
+            tls_certificate_service.clone(),
+            event_bus.clone(),
+            config
+                .as_ref()
+                .map_or(DEFAULT_EXPIRY_WARNING_DAYS, |c| c.cert_expiry_warning_days),
+        ));
+
         // HostService needs DaemonService
+
         let host_service = Arc::new(HostService::new(
             storage.hosts.clone(),
             interface_service.clone(),
@@ -219,6 +264,9 @@ impl ServiceFactory {
             service_service.clone(),
             if_entry_service.clone(),
             daemon_service.clone(),
+            host_change_service.clone(),
+            tls_certificate_service.clone(),
+            network_service.clone(),
             event_bus.clone(),
             entity_tag_service.clone(),
         ));
@@ -227,6 +275,17 @@ impl ServiceFactory {
         let _ = service_service.set_host_service(host_service.clone());
         let _ = daemon_service.set_host_service(host_service.clone());
         let _ = snmp_credential_service.set_host_service(host_service.clone());
+        let _ = host_change_service.set_host_service(host_service.clone());
+
+        let webhook_service = Arc::new(WebhookService::new(
+            storage.webhooks.clone(),
+            storage.webhook_deliveries.clone(),
+            network_service.clone(),
+            event_bus.clone(),
+            config
+                .as_ref()
+                .is_some_and(|c| c.allow_private_webhook_targets),
+        ));
 
         let topology_service = Arc::new(TopologyService::new(
             host_service.clone(),
@@ -238,10 +297,25 @@ impl ServiceFactory {
             binding_service.clone(),
             if_entry_service.clone(),
             tag_service.clone(),
+            network_service.clone(),
+            custom_service_definition_service.clone(),
             storage.topologies.clone(),
             event_bus.clone(),
         ));
 
+        let topology_snapshot_service = Arc::new(TopologySnapshotService::new(
+            storage.topology_snapshots.clone(),
+            event_bus.clone(),
+        ));
+
+        let audit_log_service = Arc::new(AuditLogService::new(
+            storage.audit_log_entries.clone(),
+            network_service.clone(),
+            user_service.clone(),
+            organization_service.clone(),
+            event_bus.clone(),
+        ));
+
         let email_service = config.clone().and_then(|c| {
             // Prefer Brevo if API key is provided
             if let Some(ref brevo_api_key) = c.brevo_api_key {
@@ -365,6 +439,13 @@ impl ServiceFactory {
         }
 
         event_bus.register_subscriber(daemon_service.clone()).await;
+        event_bus
+            .register_subscriber(host_change_service.clone())
+            .await;
+        event_bus.register_subscriber(webhook_service.clone()).await;
+        event_bus
+            .register_subscriber(audit_log_service.clone())
+            .await;
 
         Ok(Self {
             user_service,
@@ -397,6 +478,13 @@ impl ServiceFactory {
             binding_service,
             snmp_credential_service,
             if_entry_service,
+            custom_service_definition_service,
+            custom_scan_profile_service,
+            host_change_service,
+            webhook_service,
+            tls_certificate_service,
+            topology_snapshot_service,
+            audit_log_service,
         })
     }
 }
@@ -5,15 +5,34 @@ use tower_sessions::{Expiry, SessionManagerLayer};
 use tower_sessions_sqlx_store::PostgresStore;
 
 use crate::server::{
-    bindings::r#impl::base::Binding, daemon_api_keys::r#impl::base::DaemonApiKey,
-    daemons::r#impl::base::Daemon, discovery::r#impl::base::Discovery, groups::r#impl::base::Group,
-    hosts::r#impl::base::Host, if_entries::r#impl::base::IfEntry,
-    interfaces::r#impl::base::Interface, invites::r#impl::base::Invite, networks::r#impl::Network,
-    organizations::r#impl::base::Organization, ports::r#impl::base::Port,
-    services::r#impl::base::Service, shared::storage::generic::GenericPostgresStorage,
-    shares::r#impl::base::Share, snmp_credentials::r#impl::base::SnmpCredential,
-    subnets::r#impl::base::Subnet, tags::r#impl::base::Tag, topology::types::base::Topology,
-    user_api_keys::r#impl::base::UserApiKey, users::r#impl::base::User,
+    audit_logs::r#impl::base::AuditLogEntry,
+    bindings::r#impl::base::Binding,
+    custom_scan_profiles::r#impl::base::CustomScanProfile,
+    custom_service_definitions::r#impl::base::CustomServiceDefinition,
+    daemon_api_keys::r#impl::base::DaemonApiKey,
+    daemons::r#impl::base::Daemon,
+    discovery::r#impl::base::Discovery,
+    groups::r#impl::base::Group,
+    host_changes::r#impl::base::HostChange,
+    hosts::r#impl::base::Host,
+    if_entries::r#impl::base::IfEntry,
+    interfaces::r#impl::base::Interface,
+    invites::r#impl::base::Invite,
+    networks::r#impl::Network,
+    organizations::r#impl::base::Organization,
+    ports::r#impl::base::Port,
+    services::r#impl::base::Service,
+    shared::storage::generic::GenericPostgresStorage,
+    shares::r#impl::base::Share,
+    snmp_credentials::r#impl::base::SnmpCredential,
+    subnets::r#impl::base::Subnet,
+    tags::r#impl::base::Tag,
+    tls_certificates::r#impl::base::TlsCertificate,
+    topology::types::base::Topology,
+    topology_snapshots::r#impl::base::TopologySnapshot,
+    user_api_keys::r#impl::base::UserApiKey,
+    users::r#impl::base::User,
+    webhooks::r#impl::base::{Webhook, WebhookDelivery},
 };
 
 pub struct StorageFactory {
@@ -39,6 +58,14 @@ pub struct StorageFactory {
     pub bindings: Arc<GenericPostgresStorage<Binding>>,
     pub snmp_credentials: Arc<GenericPostgresStorage<SnmpCredential>>,
     pub if_entries: Arc<GenericPostgresStorage<IfEntry>>,
+    pub custom_service_definitions: Arc<GenericPostgresStorage<CustomServiceDefinition>>,
+    pub custom_scan_profiles: Arc<GenericPostgresStorage<CustomScanProfile>>,
+    pub host_changes: Arc<GenericPostgresStorage<HostChange>>,
+    pub webhooks: Arc<GenericPostgresStorage<Webhook>>,
+    pub webhook_deliveries: Arc<GenericPostgresStorage<WebhookDelivery>>,
+    pub tls_certificates: Arc<GenericPostgresStorage<TlsCertificate>>,
+    pub topology_snapshots: Arc<GenericPostgresStorage<TopologySnapshot>>,
+    pub audit_log_entries: Arc<GenericPostgresStorage<AuditLogEntry>>,
 }
 
 pub async fn create_session_store(
@@ -88,6 +115,14 @@ impl StorageFactory {
             bindings: Arc::new(GenericPostgresStorage::new(pool.clone())),
             snmp_credentials: Arc::new(GenericPostgresStorage::new(pool.clone())),
             if_entries: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            custom_service_definitions: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            custom_scan_profiles: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            host_changes: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            webhooks: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            webhook_deliveries: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            tls_certificates: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            topology_snapshots: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            audit_log_entries: Arc::new(GenericPostgresStorage::new(pool.clone())),
         })
     }
 }
@@ -7,8 +7,11 @@ use uuid::Uuid;
 
 use crate::server::{
     daemons::r#impl::base::DaemonMode,
+    host_changes::r#impl::base::HostChangeType,
+    hosts::r#impl::os::OsFamily,
     shared::{entities::EntityDiscriminants, storage::traits::SqlValue},
     users::r#impl::permissions::UserOrgPermissions,
+    webhooks::r#impl::base::WebhookDeliveryStatus,
 };
 
 use super::traits::Storable;
@@ -137,6 +140,11 @@ impl<T: Storable> StorableFilter<T> {
         Self::new().expires_before(timestamp)
     }
 
+    /// All rows across organizations - for system jobs only, never request handlers
+    pub fn new_unscoped() -> Self {
+        Self::new()
+    }
+
     pub fn new_for_daemon_poller_system_job() -> Self {
         Self::new()
             .daemon_mode(DaemonMode::ServerPoll)
@@ -527,6 +535,15 @@ impl<T: Storable> StorableFilter<T> {
         self
     }
 
+    /// Generic TEXT equality filter for any column name.
+    pub fn string_column(mut self, column: &str, value: &str) -> Self {
+        let col = self.qualify_column(column);
+        self.conditions
+            .push(format!("{} = ${}", col, self.values.len() + 1));
+        self.values.push(SqlValue::String(value.to_string()));
+        self
+    }
+
     /// Filter by service_id (for bindings)
     pub fn service_id(mut self, id: &Uuid) -> Self {
         let col = self.qualify_column("service_id");
@@ -656,6 +673,117 @@ impl<T: Storable> StorableFilter<T> {
         self
     }
 
+    /// Filter by change_type (for host_changes table)
+    pub fn change_type(mut self, change_type: HostChangeType) -> Self {
+        let col = self.qualify_column("change_type");
+        self.conditions
+            .push(format!("{} = ${}", col, self.values.len() + 1));
+        self.values.push(SqlValue::String(change_type.to_string()));
+        self
+    }
+
+    /// Filter by not_after before a timestamp (for tls_certificates table). Includes expired rows.
+    pub fn not_after_before(mut self, timestamp: DateTime<Utc>) -> Self {
+        let col = self.qualify_column("not_after");
+        self.conditions
+            .push(format!("{} < ${}", col, self.values.len() + 1));
+        self.values.push(SqlValue::Timestamp(timestamp));
+        self
+    }
+
+    /// Filter by whether a newer change of the same type and subject exists on the host
+    /// (for host_changes table). Rows that aren't superseded are each host's latest state,
+    /// the same rows `DISTINCT ON (host_id, change_type, subject)` would pick.
+    pub fn superseded_is(mut self, superseded: bool) -> Self {
+        let table = T::table_name();
+        self.conditions.push(format!(
+            "{}EXISTS (SELECT 1 FROM {table} newer WHERE newer.host_id = {table}.host_id \
+             AND newer.change_type = {table}.change_type AND newer.subject = {table}.subject \
+             AND newer.created_at > {table}.created_at)",
+            if superseded { "" } else { "NOT " },
+        ));
+        self
+    }
+
+    /// Filter by self_signed flag (for tls_certificates table)
+    pub fn self_signed_is(mut self, self_signed: bool) -> Self {
+        let col = self.qualify_column("self_signed");
+        self.conditions
+            .push(format!("{} = ${}", col, self.values.len() + 1));
+        self.values.push(SqlValue::Bool(self_signed));
+        self
+    }
+
+    /// Filter by offline flag (for hosts table)
+    pub fn offline_is(mut self, offline: bool) -> Self {
+        let col = self.qualify_column("offline");
+        self.conditions
+            .push(format!("{} = ${}", col, self.values.len() + 1));
+        self.values.push(SqlValue::Bool(offline));
+        self
+    }
+
+    /// Filter by inferred OS family (for hosts table). Hosts without an OS don't match.
+    pub fn os_family_is(mut self, family: OsFamily) -> Self {
+        let col = self.qualify_column("os");
+        self.conditions
+            .push(format!("{}->>'family' = ${}", col, self.values.len() + 1));
+        self.values.push(SqlValue::String(family.to_string()));
+        self
+    }
+
+    /// Filter by last_seen before a timestamp (for hosts table). Never-seen rows don't match.
+    pub fn last_seen_before(mut self, timestamp: DateTime<Utc>) -> Self {
+        let col = self.qualify_column("last_seen");
+        self.conditions
+            .push(format!("{} < ${}", col, self.values.len() + 1));
+        self.values.push(SqlValue::Timestamp(timestamp));
+        self
+    }
+
+    /// Filter pending deliveries whose next attempt is due (for webhook_deliveries table)
+    pub fn delivery_due(mut self, now: DateTime<Utc>) -> Self {
+        let status_col = self.qualify_column("status");
+        let next_attempt_col = self.qualify_column("next_attempt_at");
+        self.conditions
+            .push(format!("{} = ${}", status_col, self.values.len() + 1));
+        self.values.push(SqlValue::String("Pending".to_string()));
+        self.conditions.push(format!(
+            "{} <= ${}",
+            next_attempt_col,
+            self.values.len() + 1
+        ));
+        self.values.push(SqlValue::Timestamp(now));
+        self
+    }
+
+    /// Filter by delivery status (for webhook_deliveries table)
+    pub fn delivery_status(mut self, status: WebhookDeliveryStatus) -> Self {
+        let col = self.qualify_column("status");
+        self.conditions
+            .push(format!("{} = ${}", col, self.values.len() + 1));
+        self.values.push(SqlValue::String(status.to_string()));
+        self
+    }
+
+    /// Filter by created_at before a timestamp
+    pub fn created_before(mut self, timestamp: DateTime<Utc>) -> Self {
+        let col = self.qualify_column("created_at");
+        self.conditions
+            .push(format!("{} < ${}", col, self.values.len() + 1));
+        self.values.push(SqlValue::Timestamp(timestamp));
+        self
+    }
+
+    /// Filter by created_at at or after a timestamp
+    pub fn created_after(mut self, timestamp: DateTime<Utc>) -> Self {
+        let col = self.qualify_column("created_at");
+        self.conditions
+            .push(format!("{} >= ${}", col, self.values.len() + 1));
+        self.values.push(SqlValue::Timestamp(timestamp));
+        self
+    }
+
     /// Filter by interface_id FK (for if_entries table)
     pub fn interface_id(mut self, interface_id: &Uuid) -> Self {
         let col = self.qualify_column("interface_id");
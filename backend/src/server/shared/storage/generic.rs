@@ -109,6 +109,7 @@ where
             SqlValue::String(v) => query.bind(v),
             SqlValue::U16(v) => query.bind(Into::<i32>::into(*v)),
             SqlValue::I32(v) => query.bind(v),
+            SqlValue::OptionalI32(v) => query.bind(v),
             SqlValue::Bool(v) => query.bind(v),
             SqlValue::Timestamp(v) => query.bind(v),
             SqlValue::OptionTimestamp(v) => query.bind(v),
@@ -134,7 +135,7 @@ where
                 query.bind(network)
             }
             SqlValue::RunType(v) => query.bind(serde_json::to_value(v)?),
-            SqlValue::DiscoveryType(v) => query.bind(serde_json::to_value(v)?),
+            SqlValue::DiscoveryType(v) => query.bind(v.to_stored_value()?),
             SqlValue::Email(v) => query.bind(v.as_str()),
             SqlValue::UserOrgPermissions(v) => query.bind(v.as_str()),
             SqlValue::DaemonMode(v) => query.bind(serde_json::to_string(v)?),
@@ -162,6 +163,7 @@ where
                 // sqlx mac_address feature supports MacAddress directly
                 query.bind(*v)
             }
+            SqlValue::MacAddressArray(v) => query.bind(v.clone()),
             SqlValue::EntityDiscriminant(v) => {
                 // Serialize to JSON string to match how it's stored/deserialized
                 query.bind(serde_json::to_string(v)?)
@@ -0,0 +1,206 @@
+//! At-rest encryption for credential columns.
+//!
+//! Secrets are sealed with AES-256-GCM under the server's `secret_key` and stored as
+//! `enc:v1:<base64(nonce || ciphertext)>`. Values without the prefix are read as
+//! plaintext so rows written before a key was configured keep working; they are
+//! encrypted the next time the row is saved.
+//!
+//! Without a key, secrets can only be written in plaintext if the operator opts in with
+//! `allow_unencrypted_secrets`. Otherwise saving a credential fails, and the server
+//! refuses to start while credentials are already stored.
+
+use aes_gcm::{
+    Aes256Gcm, Nonce,
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+};
+use anyhow::{Result, anyhow};
+use base64ct::{Base64, Encoding};
+use secrecy::{ExposeSecret, SecretString};
+use sqlx::PgPool;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+const ENCRYPTED_PREFIX: &str = "enc:v1:";
+const NONCE_LEN: usize = 12;
+
+const MISSING_KEY: &str = "SCANOPY_SECRET_KEY is not set, so credential secrets can't be stored \
+     encrypted. Generate one with `openssl rand -base64 32`, or set \
+     SCANOPY_ALLOW_UNENCRYPTED_SECRETS=true to store them in plaintext.";
+
+/// Tables holding credential secrets, with the condition matching rows that carry one,
+/// checked at startup when no key is configured
+const SECRET_TABLES: &[(&str, &str)] = &[
+    ("snmp_credentials", "TRUE"),
+    ("webhooks", "TRUE"),
+    ("discovery", "discovery_type->>'type' = 'Proxmox'"),
+];
+
+static SECRET_CIPHER: OnceLock<Aes256Gcm> = OnceLock::new();
+static ALLOW_UNENCRYPTED: AtomicBool = AtomicBool::new(false);
+
+/// Parse a base64-encoded 32-byte key
+fn parse_key(key: &str) -> Result<Aes256Gcm> {
+    let bytes =
+        Base64::decode_vec(key.trim()).map_err(|_| anyhow!("secret_key must be base64-encoded"))?;
+    if bytes.len() != 32 {
+        return Err(anyhow!(
+            "secret_key must decode to 32 bytes, got {}",
+            bytes.len()
+        ));
+    }
+    Aes256Gcm::new_from_slice(&bytes).map_err(|_| anyhow!("Invalid secret_key"))
+}
+
+/// Install the server secret key. Without one, secrets can only be written when
+/// `allow_unencrypted` is set, and are then stored in plaintext.
+pub fn init_secret_encryption(key: Option<&str>, allow_unencrypted: bool) -> Result<()> {
+    match key.filter(|k| !k.trim().is_empty()) {
+        Some(key) => {
+            let cipher = parse_key(key)?;
+            let _ = SECRET_CIPHER.set(cipher);
+        }
+        None if allow_unencrypted => {
+            ALLOW_UNENCRYPTED.store(true, Ordering::Relaxed);
+            tracing::warn!(
+                "SCANOPY_ALLOW_UNENCRYPTED_SECRETS is set; credential secrets will be stored \
+                 unencrypted. Set SCANOPY_SECRET_KEY to encrypt them."
+            );
+        }
+        None => {
+            tracing::warn!("{} Saving credentials will fail until then.", MISSING_KEY);
+        }
+    }
+    Ok(())
+}
+
+/// Refuse to start with stored credentials that could neither be kept encrypted nor, without
+/// the explicit opt-out, be saved again.
+pub async fn ensure_secret_storage(pool: &PgPool) -> Result<()> {
+    if SECRET_CIPHER.get().is_some() || ALLOW_UNENCRYPTED.load(Ordering::Relaxed) {
+        return Ok(());
+    }
+
+    for (table, condition) in SECRET_TABLES {
+        let in_use: bool = sqlx::query_scalar(&format!(
+            "SELECT EXISTS (SELECT 1 FROM {} WHERE {})",
+            table, condition
+        ))
+        .fetch_one(pool)
+        .await?;
+        if in_use {
+            return Err(anyhow!(
+                "Stored credentials found in {}. {}",
+                table,
+                MISSING_KEY
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn seal(cipher: &Aes256Gcm, plaintext: &str) -> Result<String> {
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext.as_bytes())
+        .map_err(|_| anyhow!("Failed to encrypt secret"))?;
+
+    let mut sealed = nonce.to_vec();
+    sealed.extend_from_slice(&ciphertext);
+    Ok(format!(
+        "{}{}",
+        ENCRYPTED_PREFIX,
+        Base64::encode_string(&sealed)
+    ))
+}
+
+fn open(cipher: Option<&Aes256Gcm>, stored: &str) -> Result<String> {
+    let Some(encoded) = stored.strip_prefix(ENCRYPTED_PREFIX) else {
+        return Ok(stored.to_string());
+    };
+    let cipher =
+        cipher.ok_or_else(|| anyhow!("Encrypted secret found but no secret_key is configured"))?;
+
+    let sealed = Base64::decode_vec(encoded).map_err(|_| anyhow!("Malformed encrypted secret"))?;
+    if sealed.len() < NONCE_LEN {
+        return Err(anyhow!("Malformed encrypted secret"));
+    }
+    let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+    let nonce: [u8; NONCE_LEN] = nonce.try_into()?;
+    let plaintext = cipher
+        .decrypt(&Nonce::from(nonce), ciphertext)
+        .map_err(|_| anyhow!("Failed to decrypt secret; was secret_key changed?"))?;
+
+    String::from_utf8(plaintext).map_err(|_| anyhow!("Decrypted secret is not valid UTF-8"))
+}
+
+fn encrypt(cipher: Option<&Aes256Gcm>, allow_unencrypted: bool, plaintext: &str) -> Result<String> {
+    match cipher {
+        Some(cipher) => seal(cipher, plaintext),
+        None if allow_unencrypted => Ok(plaintext.to_string()),
+        None => Err(anyhow!(MISSING_KEY)),
+    }
+}
+
+/// Value to write to a secret column
+pub fn encrypt_secret(secret: &SecretString) -> Result<String> {
+    encrypt(
+        SECRET_CIPHER.get(),
+        ALLOW_UNENCRYPTED.load(Ordering::Relaxed),
+        secret.expose_secret(),
+    )
+}
+
+/// Secret read back from a secret column
+pub fn decrypt_secret(stored: &str) -> Result<SecretString> {
+    open(SECRET_CIPHER.get(), stored).map(SecretString::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "MDEyMzQ1Njc4OWFiY2RlZjAxMjM0NTY3ODlhYmNkZWY=";
+
+    #[test]
+    fn test_seal_open_roundtrip() {
+        let cipher = parse_key(KEY).unwrap();
+        let sealed = seal(&cipher, "authPassphrase1").unwrap();
+
+        assert!(sealed.starts_with(ENCRYPTED_PREFIX));
+        assert!(!sealed.contains("authPassphrase1"));
+        assert_eq!(open(Some(&cipher), &sealed).unwrap(), "authPassphrase1");
+
+        // Fresh nonce per value
+        assert_ne!(sealed, seal(&cipher, "authPassphrase1").unwrap());
+    }
+
+    #[test]
+    fn test_plaintext_passthrough_and_wrong_key() {
+        let cipher = parse_key(KEY).unwrap();
+        assert_eq!(open(Some(&cipher), "public").unwrap(), "public");
+        assert_eq!(open(None, "public").unwrap(), "public");
+
+        let sealed = seal(&cipher, "secret").unwrap();
+        let other = parse_key("ZmVkY2JhOTg3NjU0MzIxMGZlZGNiYTk4NzY1NDMyMTA=").unwrap();
+        assert!(open(Some(&other), &sealed).is_err());
+        assert!(open(None, &sealed).is_err());
+    }
+
+    #[test]
+    fn test_plaintext_requires_opt_out() {
+        let cipher = parse_key(KEY).unwrap();
+        assert!(
+            encrypt(Some(&cipher), false, "public")
+                .unwrap()
+                .starts_with(ENCRYPTED_PREFIX)
+        );
+        assert_eq!(encrypt(None, true, "public").unwrap(), "public");
+        assert!(encrypt(None, false, "public").is_err());
+    }
+
+    #[test]
+    fn test_parse_key_rejects_wrong_length() {
+        assert!(parse_key("c2hvcnQ=").is_err());
+        assert!(parse_key("not base64!").is_err());
+    }
+}
@@ -104,6 +104,7 @@ pub fn create_remote_host(
         virtualization: None,
         source: EntitySource::System,
         position: 0,
+        proxy_targets: Vec::new(),
     });
 
     (host, vec![interface], vec![dynamic_port], client_service)
@@ -144,6 +145,7 @@ pub fn create_internet_connectivity_host(
         virtualization: None,
         source: EntitySource::System,
         position: 0,
+        proxy_targets: Vec::new(),
     });
 
     (host, vec![interface], vec![https_port], web_service)
@@ -184,6 +186,7 @@ pub fn create_public_dns_host(
         virtualization: None,
         source: EntitySource::System,
         position: 0,
+        proxy_targets: Vec::new(),
     });
 
     (host, vec![interface], vec![dns_udp_port], dns_service)
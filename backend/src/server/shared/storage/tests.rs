@@ -1,9 +1,13 @@
 use crate::server::{
+    audit_logs::r#impl::base::AuditLogEntry,
     bindings::r#impl::base::Binding,
+    custom_scan_profiles::r#impl::base::CustomScanProfile,
+    custom_service_definitions::r#impl::base::CustomServiceDefinition,
     daemon_api_keys::r#impl::base::DaemonApiKey,
     daemons::r#impl::base::Daemon,
     discovery::r#impl::base::Discovery,
     groups::{group_bindings::GroupBinding, r#impl::base::Group},
+    host_changes::r#impl::base::HostChange,
     hosts::r#impl::base::Host,
     if_entries::r#impl::base::IfEntry,
     interfaces::r#impl::base::Interface,
@@ -18,9 +22,12 @@ use crate::server::{
     subnets::r#impl::base::Subnet,
     tags::entity_tags::EntityTag,
     tags::r#impl::base::Tag,
+    tls_certificates::r#impl::base::TlsCertificate,
     topology::types::base::Topology,
+    topology_snapshots::r#impl::base::TopologySnapshot,
     user_api_keys::r#impl::base::UserApiKey,
     users::r#impl::base::User,
+    webhooks::r#impl::base::{Webhook, WebhookDelivery},
 };
 use sqlx::postgres::PgRow;
 use std::collections::HashMap;
@@ -213,6 +220,70 @@ fn get_entity_deserializers() -> HashMap<&'static str, DeserializeFn> {
         }),
     );
 
+    map.insert(
+        CustomServiceDefinition::table_name(),
+        Box::new(|row| {
+            CustomServiceDefinition::from_row(row)?;
+            Ok(())
+        }),
+    );
+
+    map.insert(
+        CustomScanProfile::table_name(),
+        Box::new(|row| {
+            CustomScanProfile::from_row(row)?;
+            Ok(())
+        }),
+    );
+
+    map.insert(
+        HostChange::table_name(),
+        Box::new(|row| {
+            HostChange::from_row(row)?;
+            Ok(())
+        }),
+    );
+
+    map.insert(
+        Webhook::table_name(),
+        Box::new(|row| {
+            Webhook::from_row(row)?;
+            Ok(())
+        }),
+    );
+
+    map.insert(
+        WebhookDelivery::table_name(),
+        Box::new(|row| {
+            WebhookDelivery::from_row(row)?;
+            Ok(())
+        }),
+    );
+
+    map.insert(
+        TlsCertificate::table_name(),
+        Box::new(|row| {
+            TlsCertificate::from_row(row)?;
+            Ok(())
+        }),
+    );
+
+    map.insert(
+        TopologySnapshot::table_name(),
+        Box::new(|row| {
+            TopologySnapshot::from_row(row)?;
+            Ok(())
+        }),
+    );
+
+    map.insert(
+        AuditLogEntry::table_name(),
+        Box::new(|row| {
+            AuditLogEntry::from_row(row)?;
+            Ok(())
+        }),
+    );
+
     map
 }
 
@@ -189,6 +189,7 @@ pub enum SqlValue {
     String(String),
     OptionalString(Option<String>),
     I32(i32),
+    OptionalI32(Option<i32>),
     U16(u16),
     Bool(bool),
     Email(EmailAddress),
@@ -227,6 +228,7 @@ pub enum SqlValue {
     JsonValue(serde_json::Value),
     MacAddress(MacAddress),
     OptionalMacAddress(Option<MacAddress>),
+    MacAddressArray(Vec<MacAddress>),
     IfEntries(Vec<IfEntry>),
     Tags(Vec<Tag>),
 }
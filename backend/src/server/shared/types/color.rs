@@ -35,3 +35,24 @@ pub enum Color {
     #[serde(other)]
     Yellow,
 }
+
+impl Color {
+    /// Hex value of the 400 shade the UI draws this color with, for server-rendered output
+    pub fn hex(&self) -> &'static str {
+        match self {
+            Color::Pink => "#f472b6",
+            Color::Rose => "#fb7185",
+            Color::Red => "#f87171",
+            Color::Orange => "#fb923c",
+            Color::Green => "#4ade80",
+            Color::Emerald => "#34d399",
+            Color::Teal => "#2dd4bf",
+            Color::Cyan => "#22d3ee",
+            Color::Blue => "#60a5fa",
+            Color::Indigo => "#818cf8",
+            Color::Purple => "#c4b5fd",
+            Color::Gray => "#9ca3af",
+            Color::Yellow => "#facc15",
+        }
+    }
+}
@@ -1,5 +1,6 @@
+use crate::server::discovery::r#impl::scan_profile::ScanProfile;
 use crate::server::discovery::r#impl::types::DiscoveryType;
-use crate::server::discovery::r#impl::types::HostNamingFallback;
+use crate::server::discovery::r#impl::types::{HostNamingFallback, HostnameSource};
 use crate::server::services::r#impl::patterns::MatchDetails;
 use crate::server::snmp_credentials::r#impl::discovery::SnmpCredentialMapping;
 use chrono::DateTime;
@@ -39,6 +40,15 @@ impl EntitySource {
             EntitySource::Discovery { .. } | EntitySource::DiscoveryWithMatch { .. }
         )
     }
+
+    /// Discovery runs that reported this entity. Empty if not from discovery.
+    pub fn discovery_metadata(&self) -> &[DiscoveryMetadata] {
+        match self {
+            EntitySource::Discovery { metadata }
+            | EntitySource::DiscoveryWithMatch { metadata, .. } => metadata,
+            _ => &[],
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, Hash, ToSchema)]
@@ -67,7 +77,11 @@ impl Default for DiscoveryMetadata {
                 subnet_ids: None,
                 host_naming_fallback: HostNamingFallback::BestService,
                 snmp_credentials: SnmpCredentialMapping::default(),
+                custom_service_definitions: Vec::new(),
                 probe_raw_socket_ports: false,
+                scan_profile: ScanProfile::default(),
+                exclusions: Vec::new(),
+                hostname_sources: HostnameSource::default_order(),
             },
             daemon_id: Uuid::new_v4(),
             date: Utc::now(),
@@ -21,7 +21,9 @@ use crate::server::{
     },
     discovery::r#impl::{
         base::{Discovery, DiscoveryBase},
-        types::{DiscoveryType, RunType},
+        exclusions::ScanExclusion,
+        scan_profile::ScanProfile,
+        types::{DiscoveryType, HostnameSource, RunType},
     },
     groups::r#impl::{
         base::{Group, GroupBase},
@@ -35,8 +37,10 @@ use crate::server::{
     },
     if_entries::r#impl::base::{IfAdminStatus, IfEntry, IfEntryBase, IfOperStatus},
     interfaces::r#impl::base::{Interface, InterfaceBase},
-    networks::r#impl::{Network, NetworkBase},
-    organizations::r#impl::base::{Organization, OrganizationBase},
+    networks::r#impl::{HostLivenessPolicy, Network, NetworkBase},
+    organizations::r#impl::base::{
+        DEFAULT_AUDIT_LOG_RETENTION_DAYS, Organization, OrganizationBase,
+    },
     ports::r#impl::base::{Port, PortBase, PortType, TransportProtocol},
     services::{
         definitions::ServiceDefinitionRegistry,
@@ -106,6 +110,8 @@ pub fn network() -> Network {
             organization_id: ids::ORGANIZATION,
             tags: vec![],
             snmp_credential_id: None,
+            host_liveness: HostLivenessPolicy::default(),
+            scan_exclusions: Vec::new(),
         },
     }
 }
@@ -132,6 +138,14 @@ pub fn host() -> Host {
             management_url: None,
             chassis_id: None,
             snmp_credential_id: None,
+            hardware_inventory: Vec::new(),
+            first_seen: None,
+            last_seen: None,
+            missed_scans: 0,
+            offline: false,
+            auto_hidden: false,
+            os: None,
+            hostname_source: None,
         },
     }
 }
@@ -168,6 +182,8 @@ pub fn interface() -> Interface {
             mac_address: Some(MacAddress::new([0xDE, 0xAD, 0xBE, 0xEF, 0xCA, 0xFE])),
             name: Some("eth0".to_string()),
             position: 0,
+            first_seen: None,
+            last_seen: None,
         },
     }
 }
@@ -225,6 +241,7 @@ pub fn service() -> Service {
             source: EntitySource::Manual,
             tags: vec![],
             position: 0,
+            proxy_targets: Vec::new(),
         },
     }
 }
@@ -337,6 +354,7 @@ pub fn organization() -> Organization {
             has_payment_method: false,
             trial_end_date: None,
             brevo_company_id: None,
+            audit_log_retention_days: DEFAULT_AUDIT_LOG_RETENTION_DAYS,
         },
     }
 }
@@ -355,7 +373,13 @@ pub fn discovery() -> Discovery {
                 subnet_ids: Some(vec![ids::SUBNET]),
                 host_naming_fallback: Default::default(),
                 snmp_credentials: SnmpCredentialMapping::default(),
+                custom_service_definitions: Vec::new(),
                 probe_raw_socket_ports: false,
+                scan_profile: ScanProfile::Standard,
+                exclusions: vec![ScanExclusion::Ip {
+                    ip: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 50)),
+                }],
+                hostname_sources: HostnameSource::default_order(),
             },
             run_type: RunType::AdHoc {
                 last_run: Some(example_timestamp()),
@@ -394,6 +418,7 @@ pub fn if_entry() -> IfEntry {
             cdp_port_id: None,
             cdp_platform: None,
             cdp_address: None,
+            fdb_macs: Vec::new(),
         },
     }
 }
@@ -410,6 +435,7 @@ pub fn snmp_credential() -> SnmpCredential {
             version: SnmpVersion::V2c,
             community: SecretString::from("public".to_string()),
             tags: Vec::new(),
+            ..Default::default()
         },
     }
 }
@@ -439,6 +465,7 @@ pub fn create_host_request() -> CreateHostRequest {
         management_url: None,
         chassis_id: None,
         snmp_credential_id: None,
+        hardware_inventory: Vec::new(),
         interfaces: vec![InterfaceInput {
             id: ids::INTERFACE,
             subnet_id: ids::SUBNET,
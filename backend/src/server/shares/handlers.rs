@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use axum::{
     Json,
+    body::Body,
     extract::{Path, Query, State},
     http::{HeaderMap, header},
     response::{IntoResponse, Response},
@@ -38,7 +39,13 @@ use crate::server::{
         api::{CreateUpdateShareRequest, PublicShareMetadata, ShareWithTopology},
         base::Share,
     },
-    topology::types::base::Topology,
+    topology::{
+        diff::{TopologyDiff, TopologyDiffSide},
+        export::TopologyExportQuery,
+        handlers::export_response,
+        types::base::Topology,
+    },
+    topology_snapshots::r#impl::base::TopologySnapshot,
 };
 
 // Generated handlers for generic CRUD operations
@@ -82,6 +89,10 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
             "/public/{id}/topology",
             axum::routing::post(get_share_topology),
         )
+        .route(
+            "/public/{id}/export",
+            axum::routing::post(export_share_topology),
+        )
 }
 
 // ============================================================================
@@ -119,6 +130,8 @@ async fn create_share(
 
     share.base.created_by = auth.user_id().ok_or_else(ApiError::user_required)?;
 
+    validate_compare_snapshot(&state, &share).await?;
+
     create_handler::<Share>(State(state), auth, Json(share)).await
 }
 
@@ -171,10 +184,32 @@ async fn update_share(
         }
     }
 
+    validate_compare_snapshot(&state, &share).await?;
+
     // Delegate to generic handler
     update_handler::<Share>(State(state), auth, Path(id), Json(share)).await
 }
 
+/// Ensure a share's comparison snapshot was taken of the shared topology
+async fn validate_compare_snapshot(state: &AppState, share: &Share) -> Result<(), ApiError> {
+    let Some(snapshot_id) = share.base.compare_snapshot_id else {
+        return Ok(());
+    };
+
+    let snapshot = TopologySnapshot::get_service(state)
+        .get_by_id(&snapshot_id)
+        .await?
+        .ok_or_else(|| ApiError::entity_not_found::<TopologySnapshot>(snapshot_id))?;
+
+    if snapshot.base.topology_id != share.base.topology_id {
+        return Err(ApiError::bad_request(
+            "Comparison snapshot must be taken of the shared topology",
+        ));
+    }
+
+    Ok(())
+}
+
 // ============================================================================
 // Public Routes (No Authentication Required)
 // ============================================================================
@@ -281,6 +316,55 @@ async fn verify_share_password(
     Ok(Json(ApiResponse::success(true)))
 }
 
+/// Check the password of a password-protected share
+fn verify_share_access(state: &AppState, share: &Share, password: Option<&str>) -> ApiResult<()> {
+    if share.requires_password() {
+        match password {
+            Some(password) => {
+                state
+                    .services
+                    .share_service
+                    .verify_share_password(share, password)
+                    .map_err(|_| ApiError::share_password_incorrect())?;
+            }
+            None => {
+                return Err(ApiError::share_password_required());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Load the topology a share points to
+async fn get_shared_topology(state: &AppState, share: &Share) -> ApiResult<Topology> {
+    state
+        .services
+        .topology_service
+        .storage()
+        .get_by_id(&share.base.topology_id)
+        .await
+        .map_err(|e| ApiError::internal_error(&e.to_string()))?
+        .ok_or_else(|| ApiError::entity_not_found::<Topology>(share.base.topology_id))
+}
+
+/// Snapshot a share compares its topology against, if it has one. A snapshot deleted
+/// since the share was saved leaves the share without a comparison.
+async fn get_share_comparison(
+    state: &AppState,
+    share: &Share,
+) -> ApiResult<Option<TopologySnapshot>> {
+    let Some(snapshot_id) = share.base.compare_snapshot_id else {
+        return Ok(None);
+    };
+
+    Ok(TopologySnapshot::get_service(state)
+        .get_by_id(&snapshot_id)
+        .await
+        .map_err(|e| ApiError::internal_error(&e.to_string()))?
+        .filter(|s| s.base.topology_id == share.base.topology_id))
+}
+
 /// Get topology data for a public share
 async fn get_share_topology(
     State(state): State<Arc<AppState>>,
@@ -313,20 +397,7 @@ async fn get_share_topology(
     }
 
     // Handle password-protected shares
-    if share.requires_password() {
-        match &body.password {
-            Some(password) => {
-                state
-                    .services
-                    .share_service
-                    .verify_share_password(&share, password)
-                    .map_err(|_| ApiError::share_password_incorrect())?;
-            }
-            None => {
-                return Err(ApiError::share_password_required());
-            }
-        }
-    }
+    verify_share_access(&state, &share, body.password.as_deref())?;
 
     // Validate allowed_domains only for embed requests
     if query.embed && share.has_domain_restrictions() {
@@ -348,19 +419,21 @@ async fn get_share_topology(
     }
 
     // Get topology data
-    let topology = state
-        .services
-        .topology_service
-        .storage()
-        .get_by_id(&share.base.topology_id)
-        .await
-        .map_err(|e| ApiError::internal_error(&e.to_string()))?
-        .ok_or_else(|| ApiError::entity_not_found::<Topology>(share.base.topology_id))?;
+    let topology = get_shared_topology(&state, &share).await?;
+    let diff = get_share_comparison(&state, &share).await?.map(|snapshot| {
+        TopologyDiff::between(
+            snapshot.diff_side(),
+            &snapshot.to_topology(),
+            TopologyDiffSide::from(&topology),
+            &topology,
+        )
+    });
 
     let response_data = ShareWithTopology {
         share: PublicShareMetadata::from(&share),
         topology: serde_json::to_value(&topology)
             .map_err(|e| ApiError::internal_error(&e.to_string()))?,
+        diff,
     };
 
     // Build response with appropriate headers
@@ -401,3 +474,37 @@ async fn get_share_topology(
 
     Ok(response)
 }
+
+/// Export the topology of a public share as a diagram file
+async fn export_share_topology(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<TopologyExportQuery>,
+    Json(body): Json<ShareTopologyRequest>,
+) -> ApiResult<(HeaderMap, Body)> {
+    let share = state
+        .services
+        .share_service
+        .get_by_id(&id)
+        .await
+        .map_err(|e| ApiError::internal_error(&e.to_string()))?
+        .ok_or_else(|| ApiError::entity_not_found::<Share>(id))?;
+
+    if !share.is_valid() {
+        return Err(ApiError::entity_disabled::<Share>());
+    }
+
+    if !share.base.options.show_export_button {
+        return Err(ApiError::forbidden("Export is disabled for this share"));
+    }
+
+    verify_share_access(&state, &share, body.password.as_deref())?;
+
+    let topology = get_shared_topology(&state, &share).await?;
+    // The comparison is part of the share, so callers can't pick a different snapshot
+    let previous = get_share_comparison(&state, &share)
+        .await?
+        .map(|snapshot| snapshot.to_topology());
+
+    Ok(export_response(&topology, previous.as_ref(), query.format))
+}
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::server::topology::diff::TopologyDiff;
+
 use super::base::{Share, ShareOptions};
 
 #[derive(Debug, Clone, Deserialize, ToSchema)]
@@ -35,4 +37,7 @@ impl From<&Share> for PublicShareMetadata {
 pub struct ShareWithTopology {
     pub share: PublicShareMetadata,
     pub topology: serde_json::Value,
+    /// Changes since the share's comparison snapshot, if it has one
+    #[schema(required)]
+    pub diff: Option<TopologyDiff>,
 }
@@ -65,6 +65,11 @@ pub struct ShareBase {
     pub password_hash: Option<String>,
     #[schema(required)]
     pub allowed_domains: Option<Vec<String>>,
+    /// Snapshot of the topology to compare against. Changes since the snapshot are
+    /// highlighted in the shared view and its exports.
+    #[serde(default)]
+    #[schema(required)]
+    pub compare_snapshot_id: Option<Uuid>,
     pub options: ShareOptions,
 }
 
@@ -175,6 +180,7 @@ impl Storable for Share {
                 "expires_at",
                 "password_hash",
                 "allowed_domains",
+                "compare_snapshot_id",
                 "options",
                 "created_at",
                 "updated_at",
@@ -189,6 +195,7 @@ impl Storable for Share {
                 SqlValue::OptionTimestamp(self.base.expires_at),
                 SqlValue::OptionalString(self.base.password_hash.clone()),
                 SqlValue::OptionalStringArray(self.base.allowed_domains.clone()),
+                SqlValue::OptionalUuid(self.base.compare_snapshot_id),
                 SqlValue::JsonValue(serde_json::to_value(&self.base.options)?),
                 SqlValue::Timestamp(self.created_at),
                 SqlValue::Timestamp(self.updated_at),
@@ -213,6 +220,7 @@ impl Storable for Share {
                 expires_at: row.get("expires_at"),
                 password_hash: row.get("password_hash"),
                 allowed_domains: row.get("allowed_domains"),
+                compare_snapshot_id: row.get("compare_snapshot_id"),
                 options,
             },
         })
@@ -35,6 +35,11 @@ impl CrudHandlers for SnmpCredential {
     fn get_service(state: &AppState) -> &Self::Service {
         &state.services.snmp_credential_service
     }
+
+    fn validate(&self) -> Result<(), String> {
+        validator::Validate::validate(self).map_err(|e| e.to_string())?;
+        self.base.validate_security()
+    }
 }
 
 // ============================================================================
@@ -273,7 +278,9 @@ async fn get_all_snmp_credentials(
 ///
 /// - Name must be 1-100 characters
 /// - Name must be unique within your organization
-/// - Community string is required
+/// - Community string is required for SNMPv2c
+/// - SNMPv3 requires a username; passphrases (min 8 characters) are required
+///   according to the security level
 #[utoipa::path(
     post,
     path = "",
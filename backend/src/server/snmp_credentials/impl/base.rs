@@ -11,21 +11,38 @@ fn default_tags() -> Vec<Uuid> {
     Vec::new()
 }
 
+/// Placeholder returned in place of secret values
+pub const REDACTED_SECRET: &str = "********";
+
 /// Serializer that redacts the secret value
-fn redact_secret<S>(_secret: &SecretString, serializer: S) -> Result<S::Ok, S::Error>
+pub fn redact_secret<S>(_secret: &SecretString, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
 {
-    serializer.serialize_str("********")
+    serializer.serialize_str(REDACTED_SECRET)
+}
+
+/// Serializer that redacts an optional secret value, preserving absence
+fn redact_optional_secret<S>(
+    secret: &Option<SecretString>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match secret {
+        Some(_) => serializer.serialize_some(REDACTED_SECRET),
+        None => serializer.serialize_none(),
+    }
 }
 
 /// SNMP protocol version
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Hash, Default, ToSchema)]
 pub enum SnmpVersion {
-    /// SNMPv2c (MVP - community string based)
+    /// SNMPv2c (community string based)
     #[default]
     V2c,
-    /// SNMPv3 (future - authentication + privacy)
+    /// SNMPv3 (USM authentication + privacy)
     V3,
 }
 
@@ -50,6 +67,134 @@ impl std::str::FromStr for SnmpVersion {
     }
 }
 
+/// SNMPv3 USM security level
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Hash, Default, ToSchema)]
+pub enum SnmpSecurityLevel {
+    /// No authentication, no privacy (username only)
+    #[default]
+    NoAuthNoPriv,
+    /// Authentication without privacy
+    AuthNoPriv,
+    /// Authentication and privacy (encrypted PDUs)
+    AuthPriv,
+}
+
+impl SnmpSecurityLevel {
+    pub fn requires_auth(&self) -> bool {
+        matches!(self, Self::AuthNoPriv | Self::AuthPriv)
+    }
+
+    pub fn requires_priv(&self) -> bool {
+        matches!(self, Self::AuthPriv)
+    }
+}
+
+impl Display for SnmpSecurityLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SnmpSecurityLevel::NoAuthNoPriv => write!(f, "NoAuthNoPriv"),
+            SnmpSecurityLevel::AuthNoPriv => write!(f, "AuthNoPriv"),
+            SnmpSecurityLevel::AuthPriv => write!(f, "AuthPriv"),
+        }
+    }
+}
+
+impl std::str::FromStr for SnmpSecurityLevel {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "NOAUTHNOPRIV" => Ok(SnmpSecurityLevel::NoAuthNoPriv),
+            "AUTHNOPRIV" => Ok(SnmpSecurityLevel::AuthNoPriv),
+            "AUTHPRIV" => Ok(SnmpSecurityLevel::AuthPriv),
+            _ => Err(anyhow::anyhow!("Invalid SNMP security level: {}", s)),
+        }
+    }
+}
+
+/// SNMPv3 USM authentication protocol
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Hash, Default, ToSchema)]
+pub enum SnmpAuthProtocol {
+    /// HMAC-MD5-96
+    Md5,
+    /// HMAC-SHA-96
+    #[default]
+    Sha,
+    /// HMAC-SHA-2 (RFC 7860)
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl Display for SnmpAuthProtocol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SnmpAuthProtocol::Md5 => write!(f, "Md5"),
+            SnmpAuthProtocol::Sha => write!(f, "Sha"),
+            SnmpAuthProtocol::Sha224 => write!(f, "Sha224"),
+            SnmpAuthProtocol::Sha256 => write!(f, "Sha256"),
+            SnmpAuthProtocol::Sha384 => write!(f, "Sha384"),
+            SnmpAuthProtocol::Sha512 => write!(f, "Sha512"),
+        }
+    }
+}
+
+impl std::str::FromStr for SnmpAuthProtocol {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().replace('-', "").as_str() {
+            "MD5" => Ok(SnmpAuthProtocol::Md5),
+            "SHA" | "SHA1" => Ok(SnmpAuthProtocol::Sha),
+            "SHA224" => Ok(SnmpAuthProtocol::Sha224),
+            "SHA256" => Ok(SnmpAuthProtocol::Sha256),
+            "SHA384" => Ok(SnmpAuthProtocol::Sha384),
+            "SHA512" => Ok(SnmpAuthProtocol::Sha512),
+            _ => Err(anyhow::anyhow!("Invalid SNMP auth protocol: {}", s)),
+        }
+    }
+}
+
+/// SNMPv3 USM privacy protocol
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Hash, Default, ToSchema)]
+pub enum SnmpPrivProtocol {
+    /// CBC-DES
+    Des,
+    /// CFB128-AES-128
+    #[default]
+    Aes,
+    /// CFB128-AES-192
+    Aes192,
+    /// CFB128-AES-256
+    Aes256,
+}
+
+impl Display for SnmpPrivProtocol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SnmpPrivProtocol::Des => write!(f, "Des"),
+            SnmpPrivProtocol::Aes => write!(f, "Aes"),
+            SnmpPrivProtocol::Aes192 => write!(f, "Aes192"),
+            SnmpPrivProtocol::Aes256 => write!(f, "Aes256"),
+        }
+    }
+}
+
+impl std::str::FromStr for SnmpPrivProtocol {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().replace('-', "").as_str() {
+            "DES" => Ok(SnmpPrivProtocol::Des),
+            "AES" | "AES128" => Ok(SnmpPrivProtocol::Aes),
+            "AES192" => Ok(SnmpPrivProtocol::Aes192),
+            "AES256" => Ok(SnmpPrivProtocol::Aes256),
+            _ => Err(anyhow::anyhow!("Invalid SNMP privacy protocol: {}", s)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Validate, Serialize, Deserialize, ToSchema)]
 pub struct SnmpCredentialBase {
     pub organization_id: Uuid,
@@ -62,13 +207,35 @@ pub struct SnmpCredentialBase {
     /// SNMP version (V2c or V3)
     #[serde(default)]
     pub version: SnmpVersion,
-    /// SNMPv2c community string (stored encrypted)
-    /// For V3, this would be extended with auth/priv credentials
+    /// SNMPv2c community string (stored encrypted, unused for V3)
     /// Redacted in API responses for security
     #[validate(skip)]
     #[serde(serialize_with = "redact_secret")]
     #[schema(value_type = String)]
     pub community: SecretString,
+    /// SNMPv3 USM username
+    #[serde(default)]
+    #[validate(length(max = 32, message = "SNMPv3 username must be at most 32 characters"))]
+    pub username: Option<String>,
+    /// SNMPv3 security level
+    #[serde(default)]
+    pub security_level: SnmpSecurityLevel,
+    /// SNMPv3 authentication protocol (used for AuthNoPriv and AuthPriv)
+    #[serde(default)]
+    pub auth_protocol: SnmpAuthProtocol,
+    /// SNMPv3 authentication passphrase (stored encrypted, redacted in API responses)
+    #[validate(skip)]
+    #[serde(default, serialize_with = "redact_optional_secret")]
+    #[schema(value_type = Option<String>)]
+    pub auth_passphrase: Option<SecretString>,
+    /// SNMPv3 privacy protocol (used for AuthPriv)
+    #[serde(default)]
+    pub priv_protocol: SnmpPrivProtocol,
+    /// SNMPv3 privacy passphrase (stored encrypted, redacted in API responses)
+    #[validate(skip)]
+    #[serde(default, serialize_with = "redact_optional_secret")]
+    #[schema(value_type = Option<String>)]
+    pub priv_passphrase: Option<SecretString>,
     #[serde(default = "default_tags")]
     #[schema(required)]
     pub tags: Vec<Uuid>,
@@ -81,17 +248,85 @@ impl Default for SnmpCredentialBase {
             name: "New SNMP Credential".to_string(),
             version: SnmpVersion::V2c,
             community: SecretString::from(String::new()),
+            username: None,
+            security_level: SnmpSecurityLevel::default(),
+            auth_protocol: SnmpAuthProtocol::default(),
+            auth_passphrase: None,
+            priv_protocol: SnmpPrivProtocol::default(),
+            priv_passphrase: None,
             tags: Vec::new(),
         }
     }
 }
 
+impl SnmpCredentialBase {
+    /// Check that the SNMPv3 fields needed to open a session are present.
+    /// Passphrases must be at least 8 characters (RFC 3414 key localization).
+    pub fn validate_security(&self) -> Result<(), String> {
+        if self.version != SnmpVersion::V3 {
+            return Ok(());
+        }
+
+        if self.username.as_deref().is_none_or(str::is_empty) {
+            return Err("Username is required for SNMPv3".to_string());
+        }
+
+        let passphrase_too_short =
+            |p: &Option<SecretString>| p.as_ref().is_none_or(|p| p.expose_secret().len() < 8);
+
+        if self.security_level.requires_auth() && passphrase_too_short(&self.auth_passphrase) {
+            return Err("Authentication passphrase must be at least 8 characters".to_string());
+        }
+
+        if self.security_level.requires_priv() && passphrase_too_short(&self.priv_passphrase) {
+            return Err("Privacy passphrase must be at least 8 characters".to_string());
+        }
+
+        Ok(())
+    }
+
+    /// Keep stored secrets when an update echoes back the redacted placeholder
+    pub fn keep_redacted_secrets(&mut self, existing: &SnmpCredentialBase) {
+        let is_redacted = |s: &SecretString| s.expose_secret() == REDACTED_SECRET;
+
+        if is_redacted(&self.community) {
+            self.community = existing.community.clone();
+        }
+        if self.auth_passphrase.as_ref().is_some_and(is_redacted) {
+            self.auth_passphrase = existing.auth_passphrase.clone();
+        }
+        if self.priv_passphrase.as_ref().is_some_and(is_redacted) {
+            self.priv_passphrase = existing.priv_passphrase.clone();
+        }
+    }
+}
+
 impl PartialEq for SnmpCredentialBase {
     fn eq(&self, other: &Self) -> bool {
         self.organization_id == other.organization_id
             && self.name == other.name
             && self.version == other.version
             && self.community.expose_secret() == other.community.expose_secret()
+            && self.username == other.username
+            && self.security_level == other.security_level
+            && self.auth_protocol == other.auth_protocol
+            && self
+                .auth_passphrase
+                .as_ref()
+                .map(ExposeSecret::expose_secret)
+                == other
+                    .auth_passphrase
+                    .as_ref()
+                    .map(ExposeSecret::expose_secret)
+            && self.priv_protocol == other.priv_protocol
+            && self
+                .priv_passphrase
+                .as_ref()
+                .map(ExposeSecret::expose_secret)
+                == other
+                    .priv_passphrase
+                    .as_ref()
+                    .map(ExposeSecret::expose_secret)
             && self.tags == other.tags
     }
 }
@@ -1,12 +1,14 @@
 use crate::server::snmp_credentials::r#impl::base::SnmpCredential;
-use crate::server::snmp_credentials::r#impl::base::SnmpVersion;
+use crate::server::snmp_credentials::r#impl::base::{
+    REDACTED_SECRET, SnmpAuthProtocol, SnmpPrivProtocol, SnmpSecurityLevel, SnmpVersion,
+};
 use secrecy::ExposeSecret;
 use serde::Deserialize;
 use serde::Serialize;
 use std::net::IpAddr;
 use utoipa::ToSchema;
 
-/// Minimal SNMP credential for daemon queries (version + community only)
+/// Minimal SNMP credential for daemon queries (version + community or USM parameters)
 /// Does not include organization_id, name, timestamps - just what's needed for SNMP queries
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, Hash, Default, ToSchema)]
 pub struct SnmpQueryCredential {
@@ -15,6 +17,37 @@ pub struct SnmpQueryCredential {
     pub version: SnmpVersion,
     /// SNMPv2c community string
     pub community: String,
+    /// SNMPv3 USM username
+    #[serde(default)]
+    pub username: Option<String>,
+    /// SNMPv3 security level
+    #[serde(default)]
+    pub security_level: SnmpSecurityLevel,
+    /// SNMPv3 authentication protocol
+    #[serde(default)]
+    pub auth_protocol: SnmpAuthProtocol,
+    /// SNMPv3 authentication passphrase
+    #[serde(default)]
+    pub auth_passphrase: Option<String>,
+    /// SNMPv3 privacy protocol
+    #[serde(default)]
+    pub priv_protocol: SnmpPrivProtocol,
+    /// SNMPv3 privacy passphrase
+    #[serde(default)]
+    pub priv_passphrase: Option<String>,
+}
+
+impl SnmpQueryCredential {
+    /// Copy with all secret material replaced by a redaction marker
+    fn redacted(&self) -> Self {
+        let redact = |s: &Option<String>| s.as_ref().map(|_| REDACTED_SECRET.to_string());
+        Self {
+            community: REDACTED_SECRET.to_string(),
+            auth_passphrase: redact(&self.auth_passphrase),
+            priv_passphrase: redact(&self.priv_passphrase),
+            ..self.clone()
+        }
+    }
 }
 
 impl From<SnmpCredential> for SnmpQueryCredential {
@@ -22,6 +55,18 @@ impl From<SnmpCredential> for SnmpQueryCredential {
         Self {
             version: value.base.version,
             community: value.base.community.expose_secret().to_string(),
+            username: value.base.username,
+            security_level: value.base.security_level,
+            auth_protocol: value.base.auth_protocol,
+            auth_passphrase: value
+                .base
+                .auth_passphrase
+                .map(|p| p.expose_secret().to_string()),
+            priv_protocol: value.base.priv_protocol,
+            priv_passphrase: value
+                .base
+                .priv_passphrase
+                .map(|p| p.expose_secret().to_string()),
         }
     }
 }
@@ -42,7 +87,7 @@ pub struct SnmpIpOverride {
 pub struct SnmpCredentialMapping {
     /// Network default credential (used when IP not in overrides)
     #[serde(default)]
-    pub default_credential: Option<SnmpQueryCredential>,
+    pub default_credential: Option<Box<SnmpQueryCredential>>,
     /// Per-IP overrides (from host.snmp_credential_id where host has known IPs)
     #[serde(default)]
     pub ip_overrides: Vec<SnmpIpOverride>,
@@ -55,7 +100,7 @@ impl SnmpCredentialMapping {
             .iter()
             .find(|o| &o.ip == ip)
             .map(|o| o.credential.clone())
-            .or(self.default_credential.clone())
+            .or_else(|| self.default_credential.as_deref().cloned())
     }
 
     /// Check if SNMP is enabled (has at least a default or override)
@@ -63,26 +108,20 @@ impl SnmpCredentialMapping {
         self.default_credential.is_some() || !self.ip_overrides.is_empty()
     }
 
-    /// Create a sanitized copy with community strings redacted.
+    /// Create a sanitized copy with community strings and passphrases redacted.
     /// Used when storing EntitySource to prevent credential leakage in API responses.
     pub fn sanitized(&self) -> Self {
         Self {
             default_credential: self
                 .default_credential
-                .as_ref()
-                .map(|c| SnmpQueryCredential {
-                    version: c.version,
-                    community: "********".to_string(),
-                }),
+                .as_deref()
+                .map(|c| Box::new(c.redacted())),
             ip_overrides: self
                 .ip_overrides
                 .iter()
                 .map(|o| SnmpIpOverride {
                     ip: o.ip,
-                    credential: SnmpQueryCredential {
-                        version: o.credential.version,
-                        community: "********".to_string(),
-                    },
+                    credential: o.credential.redacted(),
                 })
                 .collect(),
         }
@@ -1,5 +1,4 @@
 use chrono::{DateTime, Utc};
-use secrecy::{ExposeSecret, SecretString};
 use serde::Serialize;
 use sqlx::Row;
 use sqlx::postgres::PgRow;
@@ -9,7 +8,10 @@ use crate::server::{
     shared::{
         entities::EntityDiscriminants,
         entity_metadata::EntityCategory,
-        storage::traits::{Entity, SqlValue, Storable},
+        storage::{
+            secrets::{decrypt_secret, encrypt_secret},
+            traits::{Entity, SqlValue, Storable},
+        },
     },
     snmp_credentials::r#impl::base::{SnmpCredential, SnmpCredentialBase},
 };
@@ -74,6 +76,12 @@ impl Storable for SnmpCredential {
                     name,
                     version,
                     community,
+                    username,
+                    security_level,
+                    auth_protocol,
+                    auth_passphrase,
+                    priv_protocol,
+                    priv_passphrase,
                     tags: _, // Stored in entity_tags junction table
                 },
         } = self.clone();
@@ -85,6 +93,12 @@ impl Storable for SnmpCredential {
                 "name",
                 "version",
                 "community",
+                "username",
+                "security_level",
+                "auth_protocol",
+                "auth_passphrase",
+                "priv_protocol",
+                "priv_passphrase",
                 "created_at",
                 "updated_at",
             ],
@@ -93,7 +107,13 @@ impl Storable for SnmpCredential {
                 SqlValue::Uuid(organization_id),
                 SqlValue::String(name),
                 SqlValue::String(version.to_string()),
-                SqlValue::String(community.expose_secret().to_string()),
+                SqlValue::String(encrypt_secret(&community)?),
+                SqlValue::OptionalString(username),
+                SqlValue::String(security_level.to_string()),
+                SqlValue::String(auth_protocol.to_string()),
+                SqlValue::OptionalString(auth_passphrase.as_ref().map(encrypt_secret).transpose()?),
+                SqlValue::String(priv_protocol.to_string()),
+                SqlValue::OptionalString(priv_passphrase.as_ref().map(encrypt_secret).transpose()?),
                 SqlValue::Timestamp(created_at),
                 SqlValue::Timestamp(updated_at),
             ],
@@ -105,6 +125,12 @@ impl Storable for SnmpCredential {
         let version = version_str.parse().unwrap_or_default();
 
         let community_str: String = row.get("community");
+        let security_level_str: String = row.get("security_level");
+        let auth_protocol_str: String = row.get("auth_protocol");
+        let priv_protocol_str: String = row.get("priv_protocol");
+        let auth_passphrase: Option<String> = row.get("auth_passphrase");
+        let priv_passphrase: Option<String> = row.get("priv_passphrase");
+
         Ok(SnmpCredential {
             id: row.get("id"),
             created_at: row.get("created_at"),
@@ -113,7 +139,13 @@ impl Storable for SnmpCredential {
                 organization_id: row.get("organization_id"),
                 name: row.get("name"),
                 version,
-                community: SecretString::from(community_str),
+                community: decrypt_secret(&community_str)?,
+                username: row.get("username"),
+                security_level: security_level_str.parse().unwrap_or_default(),
+                auth_protocol: auth_protocol_str.parse().unwrap_or_default(),
+                auth_passphrase: auth_passphrase.as_deref().map(decrypt_secret).transpose()?,
+                priv_protocol: priv_protocol_str.parse().unwrap_or_default(),
+                priv_passphrase: priv_passphrase.as_deref().map(decrypt_secret).transpose()?,
                 tags: Vec::new(), // Hydrated from entity_tags junction table
             },
         })
@@ -162,6 +194,11 @@ impl Entity for SnmpCredential {
         self.updated_at = time;
     }
 
+    fn preserve_immutable_fields(&mut self, existing: &Self) {
+        // Secrets are redacted in responses, so clients send the placeholder back unchanged
+        self.base.keep_redacted_secrets(&existing.base);
+    }
+
     fn get_tags(&self) -> Option<&Vec<Uuid>> {
         Some(&self.base.tags)
     }
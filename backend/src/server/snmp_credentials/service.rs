@@ -126,7 +126,7 @@ impl SnmpCredentialService {
         }
 
         Ok(SnmpCredentialMapping {
-            default_credential: network_credential,
+            default_credential: network_credential.map(Box::new),
             ip_overrides: overrides,
         })
     }
@@ -42,6 +42,7 @@ pub enum SubnetType {
     Guest,
 
     DockerBridge,
+    KubernetesPod,
     MacVlan,
     IpVlan,
     Management,
@@ -67,6 +68,7 @@ impl FromStr for SubnetType {
             "IoT" => Ok(SubnetType::IoT),
             "Guest" => Ok(SubnetType::Guest),
             "DockerBridge" => Ok(SubnetType::DockerBridge),
+            "KubernetesPod" => Ok(SubnetType::KubernetesPod),
             "MacVlan" => Ok(SubnetType::MacVlan),
             "IpVlan" => Ok(SubnetType::IpVlan),
             "Management" => Ok(SubnetType::Management),
@@ -171,6 +173,11 @@ impl SubnetType {
         matches!(self, SubnetType::DockerBridge)
     }
 
+    /// Host-internal networks that only containers or pods live on
+    pub fn is_container_network(&self) -> bool {
+        matches!(self, SubnetType::DockerBridge | SubnetType::KubernetesPod)
+    }
+
     pub fn is_vlan_network(&self) -> bool {
         matches!(self, SubnetType::MacVlan | SubnetType::IpVlan)
     }
@@ -199,6 +206,7 @@ impl EntityMetadataProvider for SubnetType {
 
             SubnetType::Management => Color::Gray,
             SubnetType::DockerBridge => Concept::Virtualization.color(),
+            SubnetType::KubernetesPod => Concept::Virtualization.color(),
             SubnetType::MacVlan => Concept::Virtualization.color(),
             SubnetType::IpVlan => Concept::Virtualization.color(),
             SubnetType::Storage => Concept::Storage.color(),
@@ -223,6 +231,7 @@ impl EntityMetadataProvider for SubnetType {
 
             SubnetType::Management => Icon::ServerCog,
             SubnetType::DockerBridge => Icon::Box,
+            SubnetType::KubernetesPod => Icon::Box,
             SubnetType::MacVlan => Icon::Network,
             SubnetType::IpVlan => Icon::Network,
             SubnetType::Storage => Concept::Storage.icon(),
@@ -250,6 +259,7 @@ impl TypeMetadataProvider for SubnetType {
 
             SubnetType::Management => "Management",
             SubnetType::DockerBridge => "Docker Bridge",
+            SubnetType::KubernetesPod => "Kubernetes Pod Network",
             SubnetType::MacVlan => "MacVLAN",
             SubnetType::IpVlan => "IpVLAN",
             SubnetType::Storage => "Storage",
@@ -275,6 +285,7 @@ impl TypeMetadataProvider for SubnetType {
 
             SubnetType::Management => "Management network",
             SubnetType::DockerBridge => "Docker bridge network",
+            SubnetType::KubernetesPod => "Pod CIDR assigned to a Kubernetes node",
             SubnetType::MacVlan => "MacVLAN network",
             SubnetType::IpVlan => "IpVLAN network",
             SubnetType::Storage => "Storage network",
@@ -287,12 +298,18 @@ impl TypeMetadataProvider for SubnetType {
     fn metadata(&self) -> serde_json::Value {
         let network_scan_discovery_eligible = !matches!(
             &self,
-            SubnetType::Remote | SubnetType::Internet | SubnetType::DockerBridge
+            SubnetType::Remote
+                | SubnetType::Internet
+                | SubnetType::DockerBridge
+                | SubnetType::KubernetesPod
         );
 
         let is_for_containers = matches!(
             self,
-            SubnetType::DockerBridge | SubnetType::MacVlan | SubnetType::IpVlan
+            SubnetType::DockerBridge
+                | SubnetType::KubernetesPod
+                | SubnetType::MacVlan
+                | SubnetType::IpVlan
         );
 
         serde_json::json!({
@@ -0,0 +1,217 @@
+use crate::server::auth::middleware::permissions::{Authorized, Viewer};
+use crate::server::config::AppState;
+use crate::server::shared::handlers::ordering::OrderField;
+use crate::server::shared::handlers::query::{
+    FilterQueryExtractor, OrderDirection, PaginationParams,
+};
+use crate::server::shared::handlers::traits::CrudHandlers;
+use crate::server::shared::services::traits::CrudService;
+use crate::server::shared::storage::filter::StorableFilter;
+use crate::server::shared::storage::traits::{Entity, Storable};
+use crate::server::shared::types::api::{ApiError, ApiResponse, ApiResult, PaginatedApiResponse};
+use crate::server::tls_certificates::r#impl::base::TlsCertificate;
+use crate::server::tls_certificates::service::TlsCertificateService;
+use axum::Json;
+use axum::extract::State;
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::IntoParams;
+use utoipa_axum::{router::OpenApiRouter, routes};
+use uuid::Uuid;
+
+impl CrudHandlers for TlsCertificate {
+    type Service = TlsCertificateService;
+    type FilterQuery = TlsCertificateFilterQuery;
+
+    fn get_service(state: &AppState) -> &Self::Service {
+        &state.services.tls_certificate_service
+    }
+}
+
+// ============================================================================
+// TLS Certificate Ordering
+// ============================================================================
+
+/// Fields that TLS certificates can be ordered/grouped by.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TlsCertificateOrderField {
+    #[default]
+    NotAfter,
+    Subject,
+    Issuer,
+    HostId,
+    CreatedAt,
+}
+
+impl OrderField for TlsCertificateOrderField {
+    fn to_sql(&self) -> &'static str {
+        match self {
+            Self::NotAfter => "tls_certificates.not_after",
+            Self::Subject => "tls_certificates.subject",
+            Self::Issuer => "tls_certificates.issuer",
+            Self::HostId => "tls_certificates.host_id",
+            Self::CreatedAt => "tls_certificates.created_at",
+        }
+    }
+}
+
+// ============================================================================
+// TLS Certificate Filter Query
+// ============================================================================
+
+/// Query parameters for filtering and ordering TLS certificates.
+#[derive(Deserialize, Default, Debug, Clone, IntoParams)]
+pub struct TlsCertificateFilterQuery {
+    /// Filter by network ID
+    pub network_id: Option<Uuid>,
+    /// Filter by host ID
+    pub host_id: Option<Uuid>,
+    /// Filter by port ID
+    pub port_id: Option<Uuid>,
+    /// Filter by the service bound to the certificate's port
+    pub service_id: Option<Uuid>,
+    /// Only certificates that have expired or expire within this many days
+    #[param(minimum = 0, maximum = 3650)]
+    pub expiring_within_days: Option<u32>,
+    /// Filter by self-signed flag
+    pub self_signed: Option<bool>,
+    /// Primary ordering field (used for grouping). Always sorts ASC to keep groups together.
+    pub group_by: Option<TlsCertificateOrderField>,
+    /// Secondary ordering field (sorting within groups or standalone sort).
+    pub order_by: Option<TlsCertificateOrderField>,
+    /// Direction for order_by field (group_by always uses ASC).
+    pub order_direction: Option<OrderDirection>,
+    /// Maximum number of results to return (1-1000, default: 50). Use 0 for no limit.
+    #[param(minimum = 0, maximum = 1000)]
+    pub limit: Option<u32>,
+    /// Number of results to skip. Default: 0.
+    #[param(minimum = 0)]
+    pub offset: Option<u32>,
+}
+
+impl TlsCertificateFilterQuery {
+    /// Build the ORDER BY clause. Soonest expiry first unless specified.
+    pub fn apply_ordering(
+        &self,
+        filter: StorableFilter<TlsCertificate>,
+    ) -> (StorableFilter<TlsCertificate>, String) {
+        crate::server::shared::handlers::ordering::apply_ordering(
+            self.group_by,
+            self.order_by,
+            self.order_direction,
+            filter,
+            "tls_certificates.not_after ASC",
+        )
+    }
+}
+
+impl FilterQueryExtractor for TlsCertificateFilterQuery {
+    fn apply_to_filter<T: Storable>(
+        &self,
+        filter: StorableFilter<T>,
+        user_network_ids: &[Uuid],
+        _user_organization_id: Uuid,
+    ) -> StorableFilter<T> {
+        let filter = match self.host_id {
+            Some(id) => filter.host_id(&id),
+            None => filter,
+        };
+        let filter = match self.port_id {
+            Some(id) => filter.uuid_column("port_id", &id),
+            None => filter,
+        };
+        let filter = match self.service_id {
+            Some(id) => filter.service_id(&id),
+            None => filter,
+        };
+        let filter = match self.expiring_within_days {
+            Some(days) => {
+                filter.not_after_before(Utc::now() + Duration::days(i64::from(days.min(3650))))
+            }
+            None => filter,
+        };
+        let filter = match self.self_signed {
+            Some(self_signed) => filter.self_signed_is(self_signed),
+            None => filter,
+        };
+        match self.network_id {
+            Some(id) if user_network_ids.contains(&id) => filter.network_ids(&[id]),
+            Some(_) => filter.network_ids(&[]), // User doesn't have access - return empty
+            None => filter.network_ids(user_network_ids),
+        }
+    }
+
+    fn pagination(&self) -> PaginationParams {
+        PaginationParams {
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+// Generated handlers for read-only operations. Certificates are only recorded by discovery.
+mod generated {
+    use super::*;
+    crate::crud_get_by_id_handler!(TlsCertificate);
+    crate::crud_export_csv_handler!(TlsCertificate);
+}
+
+pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(get_all_tls_certificates))
+        .routes(routes!(generated::export_csv))
+        .routes(routes!(generated::get_by_id))
+}
+
+/// List all TLS Certificates
+///
+/// Returns the certificate currently presented on each TLS port, soonest expiry first.
+/// Use `expiring_within_days` to list certificates that have expired or expire within
+/// that many days.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = TlsCertificate::ENTITY_NAME_PLURAL,
+    params(TlsCertificateFilterQuery),
+    responses(
+        (status = 200, description = "List of TLS certificates", body = PaginatedApiResponse<TlsCertificate>),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_all_tls_certificates(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Viewer>,
+    crate::server::shared::extractors::Query(query): crate::server::shared::extractors::Query<
+        TlsCertificateFilterQuery,
+    >,
+) -> ApiResult<Json<PaginatedApiResponse<TlsCertificate>>> {
+    let network_ids = auth.network_ids();
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(ApiError::organization_required)?;
+
+    let base_filter = StorableFilter::<TlsCertificate>::new_from_network_ids(&network_ids);
+    let filter = query.apply_to_filter(base_filter, &network_ids, organization_id);
+
+    let pagination = query.pagination();
+    let filter = pagination.apply_to_filter(filter);
+    let (filter, order_by) = query.apply_ordering(filter);
+
+    let result = state
+        .services
+        .tls_certificate_service
+        .get_paginated_ordered(filter, &order_by)
+        .await?;
+
+    let limit = pagination.effective_limit().unwrap_or(0);
+    let offset = pagination.effective_offset();
+
+    Ok(Json(PaginatedApiResponse::success(
+        result.items,
+        result.total_count,
+        limit,
+        offset,
+    )))
+}
@@ -0,0 +1,104 @@
+use crate::server::shared::entities::ChangeTriggersTopologyStaleness;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Default for the server's `cert_expiry_warning_days`: certificates expiring within this many
+/// days are recorded as `CertificateExpiring` host changes
+pub const DEFAULT_EXPIRY_WARNING_DAYS: u32 = 30;
+
+/// A certificate presented after the leaf (intermediate or root)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema, Default)]
+pub struct CertificateChainEntry {
+    pub subject: String,
+    pub issuer: String,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+    pub fingerprint_sha256: String,
+}
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema, Default, Validate,
+)]
+pub struct TlsCertificateBase {
+    pub network_id: Uuid,
+    pub host_id: Uuid,
+    /// Port the certificate was presented on
+    pub port_id: Uuid,
+    /// Service bound to the port when the certificate was captured
+    pub service_id: Option<Uuid>,
+    /// SHA-256 of the leaf certificate's DER encoding, lowercase hex
+    pub fingerprint_sha256: String,
+    /// Subject distinguished name (RFC 4514), e.g. "CN=nas.example.com"
+    pub subject: String,
+    /// DNS names and IP addresses from the subjectAltName extension
+    pub subject_alt_names: Vec<String>,
+    /// Issuer distinguished name (RFC 4514)
+    pub issuer: String,
+    /// Serial number, lowercase hex
+    pub serial_number: String,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+    /// Public key algorithm and size, e.g. "RSA 2048", "EC P-256", "Ed25519"
+    pub key_type: String,
+    /// Subject and issuer are the same
+    pub self_signed: bool,
+    /// Certificates presented after the leaf, in the order the server sent them
+    pub chain: Vec<CertificateChainEntry>,
+}
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema, Default, Validate,
+)]
+pub struct TlsCertificate {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub base: TlsCertificateBase,
+}
+
+impl ChangeTriggersTopologyStaleness<TlsCertificate> for TlsCertificate {
+    fn triggers_staleness(&self, _other: Option<TlsCertificate>) -> bool {
+        false
+    }
+}
+
+impl Display for TlsCertificate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "TlsCertificate {}: {} (expires {}) on port {}",
+            self.id, self.base.subject, self.base.not_after, self.base.port_id
+        )
+    }
+}
+
+impl TlsCertificate {
+    pub fn new(base: TlsCertificateBase) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+}
+
+impl TlsCertificateBase {
+    /// Whether the certificate has expired or expires within `days` of `now`
+    pub fn expires_within(&self, days: i64, now: DateTime<Utc>) -> bool {
+        self.not_after <= now + Duration::days(days)
+    }
+}
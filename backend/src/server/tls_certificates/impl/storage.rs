@@ -0,0 +1,223 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+use crate::server::{
+    shared::{
+        entities::EntityDiscriminants,
+        entity_metadata::EntityCategory,
+        storage::traits::{Entity, SqlValue, Storable},
+    },
+    tls_certificates::r#impl::base::{CertificateChainEntry, TlsCertificate, TlsCertificateBase},
+};
+
+/// CSV row representation for TlsCertificate export
+#[derive(Serialize)]
+pub struct TlsCertificateCsvRow {
+    pub id: Uuid,
+    pub network_id: Uuid,
+    pub host_id: Uuid,
+    pub port_id: Uuid,
+    pub service_id: Option<Uuid>,
+    pub fingerprint_sha256: String,
+    pub subject: String,
+    pub subject_alt_names: String,
+    pub issuer: String,
+    pub serial_number: String,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+    pub key_type: String,
+    pub self_signed: bool,
+    pub chain_length: usize,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Storable for TlsCertificate {
+    type BaseData = TlsCertificateBase;
+
+    fn table_name() -> &'static str {
+        "tls_certificates"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            updated_at,
+            base:
+                Self::BaseData {
+                    network_id,
+                    host_id,
+                    port_id,
+                    service_id,
+                    fingerprint_sha256,
+                    subject,
+                    subject_alt_names,
+                    issuer,
+                    serial_number,
+                    not_before,
+                    not_after,
+                    key_type,
+                    self_signed,
+                    chain,
+                },
+        } = self.clone();
+
+        Ok((
+            vec![
+                "id",
+                "network_id",
+                "host_id",
+                "port_id",
+                "service_id",
+                "fingerprint_sha256",
+                "subject",
+                "subject_alt_names",
+                "issuer",
+                "serial_number",
+                "not_before",
+                "not_after",
+                "key_type",
+                "self_signed",
+                "chain",
+                "created_at",
+                "updated_at",
+            ],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::Uuid(network_id),
+                SqlValue::Uuid(host_id),
+                SqlValue::Uuid(port_id),
+                SqlValue::OptionalUuid(service_id),
+                SqlValue::String(fingerprint_sha256),
+                SqlValue::String(subject),
+                SqlValue::StringArray(subject_alt_names),
+                SqlValue::String(issuer),
+                SqlValue::String(serial_number),
+                SqlValue::Timestamp(not_before),
+                SqlValue::Timestamp(not_after),
+                SqlValue::String(key_type),
+                SqlValue::Bool(self_signed),
+                SqlValue::JsonValue(serde_json::to_value(&chain)?),
+                SqlValue::Timestamp(created_at),
+                SqlValue::Timestamp(updated_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        let chain: Vec<CertificateChainEntry> =
+            serde_json::from_value(row.get::<serde_json::Value, _>("chain"))
+                .map_err(|e| anyhow::anyhow!("Failed to deserialize chain: {}", e))?;
+
+        Ok(TlsCertificate {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            base: TlsCertificateBase {
+                network_id: row.get("network_id"),
+                host_id: row.get("host_id"),
+                port_id: row.get("port_id"),
+                service_id: row.get("service_id"),
+                fingerprint_sha256: row.get("fingerprint_sha256"),
+                subject: row.get("subject"),
+                subject_alt_names: row.get("subject_alt_names"),
+                issuer: row.get("issuer"),
+                serial_number: row.get("serial_number"),
+                not_before: row.get("not_before"),
+                not_after: row.get("not_after"),
+                key_type: row.get("key_type"),
+                self_signed: row.get("self_signed"),
+                chain,
+            },
+        })
+    }
+}
+
+impl Entity for TlsCertificate {
+    type CsvRow = TlsCertificateCsvRow;
+
+    fn to_csv_row(&self) -> Self::CsvRow {
+        TlsCertificateCsvRow {
+            id: self.id,
+            network_id: self.base.network_id,
+            host_id: self.base.host_id,
+            port_id: self.base.port_id,
+            service_id: self.base.service_id,
+            fingerprint_sha256: self.base.fingerprint_sha256.clone(),
+            subject: self.base.subject.clone(),
+            subject_alt_names: self.base.subject_alt_names.join(", "),
+            issuer: self.base.issuer.clone(),
+            serial_number: self.base.serial_number.clone(),
+            not_before: self.base.not_before,
+            not_after: self.base.not_after,
+            key_type: self.base.key_type.clone(),
+            self_signed: self.base.self_signed,
+            chain_length: self.base.chain.len(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::TlsCertificate
+    }
+
+    const ENTITY_NAME_SINGULAR: &'static str = "TLS Certificate";
+    const ENTITY_NAME_PLURAL: &'static str = "TLS Certificates";
+    const ENTITY_DESCRIPTION: &'static str = "Certificates presented on TLS ports, captured by network discovery. Subject, SANs, issuer, validity window, key type and the rest of the presented chain.";
+
+    fn entity_category() -> EntityCategory {
+        EntityCategory::NetworkInfrastructure
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        Some(self.base.network_id)
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    fn set_updated_at(&mut self, time: DateTime<Utc>) {
+        self.updated_at = time;
+    }
+}
@@ -0,0 +1,128 @@
+use crate::server::{
+    auth::middleware::auth::AuthenticatedEntity,
+    hosts::r#impl::api::HostResponse,
+    ports::r#impl::base::Port,
+    shared::{
+        events::bus::EventBus,
+        services::traits::{CrudService, EventBusService},
+        storage::{filter::StorableFilter, generic::GenericPostgresStorage, traits::Storage},
+    },
+    tags::entity_tags::EntityTagService,
+    tls_certificates::r#impl::base::TlsCertificate,
+};
+use anyhow::Result;
+use std::{collections::HashMap, sync::Arc};
+use uuid::Uuid;
+
+pub struct TlsCertificateService {
+    storage: Arc<GenericPostgresStorage<TlsCertificate>>,
+    event_bus: Arc<EventBus>,
+}
+
+impl EventBusService<TlsCertificate> for TlsCertificateService {
+    fn event_bus(&self) -> &Arc<EventBus> {
+        &self.event_bus
+    }
+
+    fn get_network_id(&self, entity: &TlsCertificate) -> Option<Uuid> {
+        Some(entity.base.network_id)
+    }
+
+    fn get_organization_id(&self, _entity: &TlsCertificate) -> Option<Uuid> {
+        None
+    }
+}
+
+impl CrudService<TlsCertificate> for TlsCertificateService {
+    fn storage(&self) -> &Arc<GenericPostgresStorage<TlsCertificate>> {
+        &self.storage
+    }
+
+    fn entity_tag_service(&self) -> Option<&Arc<EntityTagService>> {
+        None
+    }
+}
+
+impl TlsCertificateService {
+    pub fn new(
+        storage: Arc<GenericPostgresStorage<TlsCertificate>>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
+        Self { storage, event_bus }
+    }
+
+    pub async fn get_for_host(&self, host_id: &Uuid) -> Result<Vec<TlsCertificate>> {
+        self.storage
+            .get_all(StorableFilter::<TlsCertificate>::new_from_host_ids(&[
+                *host_id,
+            ]))
+            .await
+    }
+
+    /// Store the certificates a discovery report captured on a host.
+    ///
+    /// Reported certificates reference the daemon's port IDs, so each is resolved to the
+    /// stored port with the same port type, and to the service bound to that port. A port
+    /// keeps one certificate: a different one replaces it, an identical one is left alone.
+    pub async fn record_report(
+        &self,
+        host: &HostResponse,
+        reported_ports: &[Port],
+        certificates: Vec<TlsCertificate>,
+        authentication: AuthenticatedEntity,
+    ) -> Result<()> {
+        if certificates.is_empty() {
+            return Ok(());
+        }
+
+        let mut stored: HashMap<Uuid, TlsCertificate> = self
+            .get_for_host(&host.id)
+            .await?
+            .into_iter()
+            .map(|c| (c.base.port_id, c))
+            .collect();
+
+        for certificate in certificates {
+            let Some(port) = reported_ports
+                .iter()
+                .find(|p| p.id == certificate.base.port_id)
+                .and_then(|reported| {
+                    host.ports
+                        .iter()
+                        .find(|p| p.base.port_type == reported.base.port_type)
+                })
+            else {
+                tracing::debug!(
+                    host_id = %host.id,
+                    port_id = %certificate.base.port_id,
+                    "Certificate reported for a port the host doesn't have, skipping"
+                );
+                continue;
+            };
+
+            let mut base = certificate.base;
+            base.network_id = host.network_id;
+            base.host_id = host.id;
+            base.port_id = port.id;
+            base.service_id = host
+                .services
+                .iter()
+                .find(|s| s.base.bindings.iter().any(|b| b.port_id() == Some(port.id)))
+                .map(|s| s.id);
+
+            match stored.remove(&port.id) {
+                Some(existing) if existing.base == base => {}
+                Some(mut existing) => {
+                    existing.base = base;
+                    self.update(&mut existing, authentication.clone()).await?;
+                }
+                None => {
+                    self.create(TlsCertificate::new(base), authentication.clone())
+                        .await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,610 @@
+//! Comparison of two builds of a topology, e.g. a saved snapshot against the live graph.
+//!
+//! Node IDs are the IDs of the entities they stand for (subnet, interface, host, service),
+//! so nodes are matched by ID. Site nodes are matched by site name, since their IDs are
+//! only kept stable across rebuilds of the same topology. Edge IDs are regenerated on
+//! every build, so edges are matched by their endpoints and type.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::server::{
+    shared::types::Color,
+    topology::{
+        export::ExportGraph,
+        types::{
+            base::Topology,
+            edges::{Edge, EdgeType, EdgeTypeDiscriminants},
+            layout::Ixy,
+            nodes::{Node, NodeType, NodeTypeDiscriminants},
+        },
+    },
+};
+
+/// How a node or edge differs between the earlier and later side of a diff
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Display, ToSchema)]
+#[strum(serialize_all = "lowercase")]
+pub enum TopologyChangeType {
+    /// Only present on the later side
+    Added,
+    /// Only present on the earlier side
+    Removed,
+    /// Present on both sides, with a different position or parent
+    Moved,
+}
+
+impl TopologyChangeType {
+    /// Color used to highlight the change in exports
+    pub fn color(&self) -> Color {
+        match self {
+            TopologyChangeType::Added => Color::Green,
+            TopologyChangeType::Removed => Color::Red,
+            TopologyChangeType::Moved => Color::Orange,
+        }
+    }
+}
+
+/// One side of a diff: a saved snapshot, or the topology as currently built
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct TopologyDiffSide {
+    pub topology_id: Uuid,
+    /// Snapshot the side was read from. Null for the live topology.
+    #[schema(required)]
+    pub snapshot_id: Option<Uuid>,
+    pub name: String,
+    /// When the side's graph was built
+    pub captured_at: DateTime<Utc>,
+}
+
+impl From<&Topology> for TopologyDiffSide {
+    fn from(topology: &Topology) -> Self {
+        Self {
+            topology_id: topology.id,
+            snapshot_id: None,
+            name: topology.base.name.clone(),
+            captured_at: topology.base.last_refreshed,
+        }
+    }
+}
+
+/// Where a node was drawn. Positions of interface and device nodes are relative to their parent.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct NodePlacement {
+    /// Containing subnet or site node
+    #[schema(required)]
+    pub parent_id: Option<Uuid>,
+    pub position: Ixy,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct NodeChange {
+    /// Node ID on the side the node was read from (the earlier side for removals)
+    pub node_id: Uuid,
+    pub node_type: NodeTypeDiscriminants,
+    pub change_type: TopologyChangeType,
+    /// Label the node is drawn with
+    pub label: String,
+    /// Placement on the earlier side. Null for added nodes.
+    #[schema(required)]
+    pub previous: Option<NodePlacement>,
+    /// Placement on the later side. Null for removed nodes.
+    #[schema(required)]
+    pub current: Option<NodePlacement>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct EdgeChange {
+    /// Edge ID on the side the edge was read from (the earlier side for removals)
+    pub edge_id: Uuid,
+    pub source_id: Uuid,
+    pub target_id: Uuid,
+    pub edge_type: EdgeTypeDiscriminants,
+    /// Edges are only ever added or removed
+    pub change_type: TopologyChangeType,
+    #[schema(required)]
+    pub label: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct TopologyDiffSummary {
+    pub nodes_added: usize,
+    pub nodes_removed: usize,
+    pub nodes_moved: usize,
+    pub edges_added: usize,
+    pub edges_removed: usize,
+}
+
+/// Nodes and edges that differ between two builds of a topology
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct TopologyDiff {
+    pub from: TopologyDiffSide,
+    pub to: TopologyDiffSide,
+    pub summary: TopologyDiffSummary,
+    pub nodes: Vec<NodeChange>,
+    pub edges: Vec<EdgeChange>,
+}
+
+/// Identity of a node across builds
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum NodeKey {
+    Id(Uuid),
+    Site(Option<String>),
+}
+
+fn node_key(node: &Node) -> NodeKey {
+    match &node.node_type {
+        NodeType::SiteNode { site } => NodeKey::Site(site.clone()),
+        _ => NodeKey::Id(node.id),
+    }
+}
+
+fn parent_id(node: &Node) -> Option<Uuid> {
+    match &node.node_type {
+        NodeType::InterfaceNode { subnet_id, .. } => Some(*subnet_id),
+        NodeType::DeviceNode { site_id, .. } => Some(*site_id),
+        NodeType::SubnetNode { .. } | NodeType::SiteNode { .. } | NodeType::ServiceNode { .. } => {
+            None
+        }
+    }
+}
+
+fn placement(node: &Node) -> NodePlacement {
+    NodePlacement {
+        parent_id: parent_id(node),
+        position: node.position,
+    }
+}
+
+/// Nodes and edges of one side, keyed by their identity across builds
+struct Side<'a> {
+    nodes: HashMap<NodeKey, &'a Node>,
+    keys_by_id: HashMap<Uuid, NodeKey>,
+    edges: HashMap<(NodeKey, NodeKey, &'a EdgeType), &'a Edge>,
+    labels: HashMap<Uuid, String>,
+}
+
+impl<'a> Side<'a> {
+    fn new(topology: &'a Topology) -> Self {
+        let keys_by_id: HashMap<Uuid, NodeKey> = topology
+            .base
+            .nodes
+            .iter()
+            .map(|n| (n.id, node_key(n)))
+            .collect();
+
+        let edges = topology
+            .base
+            .edges
+            .iter()
+            .filter_map(|e| {
+                let source = keys_by_id.get(&e.source)?.clone();
+                let target = keys_by_id.get(&e.target)?.clone();
+                Some(((source, target, &e.edge_type), e))
+            })
+            .collect();
+
+        Self {
+            nodes: topology
+                .base
+                .nodes
+                .iter()
+                .map(|n| (node_key(n), n))
+                .collect(),
+            keys_by_id,
+            edges,
+            labels: ExportGraph::from_topology(topology)
+                .nodes
+                .into_iter()
+                .map(|n| (n.id, n.label))
+                .collect(),
+        }
+    }
+
+    fn parent_key(&self, node: &Node) -> Option<NodeKey> {
+        parent_id(node).map(|id| self.keys_by_id.get(&id).cloned().unwrap_or(NodeKey::Id(id)))
+    }
+
+    fn label(&self, node: &Node) -> String {
+        self.labels.get(&node.id).cloned().unwrap_or_default()
+    }
+}
+
+impl TopologyDiff {
+    /// Diff `from` (the earlier build) against `to` (the later build)
+    pub fn between(
+        from_side: TopologyDiffSide,
+        from: &Topology,
+        to_side: TopologyDiffSide,
+        to: &Topology,
+    ) -> Self {
+        let before = Side::new(from);
+        let after = Side::new(to);
+
+        let mut nodes = Vec::new();
+
+        for node in &from.base.nodes {
+            if !after.nodes.contains_key(&node_key(node)) {
+                nodes.push(NodeChange {
+                    node_id: node.id,
+                    node_type: NodeTypeDiscriminants::from(&node.node_type),
+                    change_type: TopologyChangeType::Removed,
+                    label: before.label(node),
+                    previous: Some(placement(node)),
+                    current: None,
+                });
+            }
+        }
+
+        for node in &to.base.nodes {
+            let change = match before.nodes.get(&node_key(node)) {
+                None => Some((TopologyChangeType::Added, None)),
+                Some(previous) => {
+                    let moved = previous.position != node.position
+                        || before.parent_key(previous) != after.parent_key(node);
+                    moved.then_some((TopologyChangeType::Moved, Some(placement(previous))))
+                }
+            };
+
+            if let Some((change_type, previous)) = change {
+                nodes.push(NodeChange {
+                    node_id: node.id,
+                    node_type: NodeTypeDiscriminants::from(&node.node_type),
+                    change_type,
+                    label: after.label(node),
+                    previous,
+                    current: Some(placement(node)),
+                });
+            }
+        }
+
+        let edge_change = |edge: &Edge, change_type: TopologyChangeType| EdgeChange {
+            edge_id: edge.id,
+            source_id: edge.source,
+            target_id: edge.target,
+            edge_type: EdgeTypeDiscriminants::from(&edge.edge_type),
+            change_type,
+            label: edge.label.clone().filter(|l| !l.is_empty()),
+        };
+
+        let mut edges: Vec<EdgeChange> = before
+            .edges
+            .iter()
+            .filter(|(key, _)| !after.edges.contains_key(*key))
+            .map(|(_, edge)| edge_change(edge, TopologyChangeType::Removed))
+            .collect();
+        edges.extend(
+            after
+                .edges
+                .iter()
+                .filter(|(key, _)| !before.edges.contains_key(*key))
+                .map(|(_, edge)| edge_change(edge, TopologyChangeType::Added)),
+        );
+        // Hash map iteration order isn't stable, keep the response deterministic
+        edges.sort_by_key(|e| (e.change_type != TopologyChangeType::Removed, e.edge_id));
+
+        let count = |changes: &[NodeChange], change_type| {
+            changes
+                .iter()
+                .filter(|c| c.change_type == change_type)
+                .count()
+        };
+        let summary = TopologyDiffSummary {
+            nodes_added: count(&nodes, TopologyChangeType::Added),
+            nodes_removed: count(&nodes, TopologyChangeType::Removed),
+            nodes_moved: count(&nodes, TopologyChangeType::Moved),
+            edges_added: edges
+                .iter()
+                .filter(|e| e.change_type == TopologyChangeType::Added)
+                .count(),
+            edges_removed: edges
+                .iter()
+                .filter(|e| e.change_type == TopologyChangeType::Removed)
+                .count(),
+        };
+
+        Self {
+            from: from_side,
+            to: to_side,
+            summary,
+            nodes,
+            edges,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty() && self.edges.is_empty()
+    }
+
+    /// The later build with removed nodes and edges added back where they were drawn in
+    /// the earlier build, so every change can be shown on one diagram
+    pub fn overlay(&self, from: &Topology, to: &Topology) -> Topology {
+        let mut overlay = to.clone();
+
+        let removed_nodes: HashSet<Uuid> = self
+            .nodes
+            .iter()
+            .filter(|c| c.change_type == TopologyChangeType::Removed)
+            .map(|c| c.node_id)
+            .collect();
+        let removed_edges: HashSet<Uuid> = self
+            .edges
+            .iter()
+            .filter(|c| c.change_type == TopologyChangeType::Removed)
+            .map(|c| c.edge_id)
+            .collect();
+
+        // Sites kept across builds may have been given new IDs
+        let site_ids: HashMap<Uuid, Uuid> = from
+            .base
+            .nodes
+            .iter()
+            .filter_map(|previous| {
+                let NodeType::SiteNode { site } = &previous.node_type else {
+                    return None;
+                };
+                to.base
+                    .nodes
+                    .iter()
+                    .find(|n| matches!(&n.node_type, NodeType::SiteNode { site: s } if s == site))
+                    .map(|current| (previous.id, current.id))
+            })
+            .collect();
+
+        for node in from
+            .base
+            .nodes
+            .iter()
+            .filter(|n| removed_nodes.contains(&n.id))
+        {
+            let mut node = node.clone();
+            if let NodeType::DeviceNode { site_id, .. } = &mut node.node_type
+                && let Some(current) = site_ids.get(site_id)
+            {
+                *site_id = *current;
+            }
+            overlay.base.nodes.push(node);
+        }
+        overlay.base.edges.extend(
+            from.base
+                .edges
+                .iter()
+                .filter(|e| removed_edges.contains(&e.id))
+                .cloned(),
+        );
+
+        // Entities only referenced by removed nodes, so they keep their labels
+        fn extend_missing<T: Clone>(current: &mut Vec<T>, previous: &[T], id: impl Fn(&T) -> Uuid) {
+            let ids: HashSet<Uuid> = current.iter().map(&id).collect();
+            current.extend(previous.iter().filter(|e| !ids.contains(&id(e))).cloned());
+        }
+        let base = &mut overlay.base;
+        extend_missing(&mut base.subnets, &from.base.subnets, |e| e.id);
+        extend_missing(&mut base.hosts, &from.base.hosts, |e| e.id);
+        extend_missing(&mut base.interfaces, &from.base.interfaces, |e| e.id);
+        extend_missing(&mut base.services, &from.base.services, |e| e.id);
+
+        overlay
+    }
+
+    pub fn node_change(&self, node_id: &Uuid) -> Option<TopologyChangeType> {
+        self.nodes
+            .iter()
+            .find(|c| c.node_id == *node_id)
+            .map(|c| c.change_type)
+    }
+
+    pub fn edge_change(&self, edge_id: &Uuid) -> Option<TopologyChangeType> {
+        self.edges
+            .iter()
+            .find(|c| c.edge_id == *edge_id)
+            .map(|c| c.change_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::{
+        shared::storage::traits::Storable,
+        topology::types::{base::TopologyBase, edges::EdgeHandle, layout::Uxy},
+    };
+
+    fn node(id: Uuid, node_type: NodeType, x: isize) -> Node {
+        Node {
+            node_type,
+            id,
+            position: Ixy { x, y: 0 },
+            size: Uxy { x: 250, y: 100 },
+            header: Some(format!("node at {}", x)),
+        }
+    }
+
+    fn interface_node(id: Uuid, subnet_id: Uuid, x: isize) -> Node {
+        node(
+            id,
+            NodeType::InterfaceNode {
+                subnet_id,
+                host_id: Uuid::new_v4(),
+                interface_id: Some(id),
+                is_infra: false,
+            },
+            x,
+        )
+    }
+
+    fn edge(source: Uuid, target: Uuid, host_id: Uuid) -> Edge {
+        Edge {
+            id: Uuid::new_v4(),
+            source,
+            target,
+            edge_type: EdgeType::Interface { host_id },
+            label: None,
+            source_handle: EdgeHandle::Right,
+            target_handle: EdgeHandle::Left,
+            is_multi_hop: false,
+        }
+    }
+
+    fn topology(nodes: Vec<Node>, edges: Vec<Edge>) -> Topology {
+        let mut base = TopologyBase::new("Lab".to_string(), Uuid::new_v4());
+        base.nodes = nodes;
+        base.edges = edges;
+        Topology::new(base)
+    }
+
+    fn diff(from: &Topology, to: &Topology) -> TopologyDiff {
+        TopologyDiff::between(from.into(), from, to.into(), to)
+    }
+
+    #[test]
+    fn unchanged_graph_has_empty_diff() {
+        let subnet_id = Uuid::new_v4();
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let host_id = Uuid::new_v4();
+        let from = topology(
+            vec![
+                node(subnet_id, NodeType::SubnetNode { infra_width: 0 }, 0),
+                interface_node(a, subnet_id, 10),
+                interface_node(b, subnet_id, 300),
+            ],
+            vec![edge(a, b, host_id)],
+        );
+
+        // Rebuilding regenerates edge IDs
+        let mut to = from.clone();
+        to.base.edges[0].id = Uuid::new_v4();
+
+        let diff = diff(&from, &to);
+        assert!(diff.is_empty());
+        assert_eq!(diff.summary, TopologyDiffSummary::default());
+    }
+
+    #[test]
+    fn diff_reports_added_removed_and_moved_nodes_and_edges() {
+        let (lan, dmz) = (Uuid::new_v4(), Uuid::new_v4());
+        let (kept, gone, new, moved) = (
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        );
+        let host_id = Uuid::new_v4();
+
+        let from = topology(
+            vec![
+                node(lan, NodeType::SubnetNode { infra_width: 0 }, 0),
+                node(dmz, NodeType::SubnetNode { infra_width: 0 }, 600),
+                interface_node(kept, lan, 10),
+                interface_node(gone, lan, 300),
+                interface_node(moved, lan, 600),
+            ],
+            vec![edge(kept, gone, host_id)],
+        );
+        let to = topology(
+            vec![
+                node(lan, NodeType::SubnetNode { infra_width: 0 }, 0),
+                node(dmz, NodeType::SubnetNode { infra_width: 0 }, 600),
+                interface_node(kept, lan, 10),
+                interface_node(new, lan, 300),
+                // Same relative position, different subnet
+                interface_node(moved, dmz, 600),
+            ],
+            vec![edge(kept, new, host_id)],
+        );
+
+        let diff = diff(&from, &to);
+
+        assert_eq!(
+            diff.summary,
+            TopologyDiffSummary {
+                nodes_added: 1,
+                nodes_removed: 1,
+                nodes_moved: 1,
+                edges_added: 1,
+                edges_removed: 1,
+            }
+        );
+        assert_eq!(diff.node_change(&gone), Some(TopologyChangeType::Removed));
+        assert_eq!(diff.node_change(&new), Some(TopologyChangeType::Added));
+        assert_eq!(diff.node_change(&kept), None);
+
+        let moved_change = diff.nodes.iter().find(|c| c.node_id == moved).unwrap();
+        assert_eq!(moved_change.change_type, TopologyChangeType::Moved);
+        assert_eq!(moved_change.previous.unwrap().parent_id, Some(lan));
+        assert_eq!(moved_change.current.unwrap().parent_id, Some(dmz));
+        assert_eq!(moved_change.label, "node at 600");
+
+        assert_eq!(diff.edges[0].change_type, TopologyChangeType::Removed);
+        assert_eq!(diff.edges[0].target_id, gone);
+        assert_eq!(diff.edges[1].change_type, TopologyChangeType::Added);
+        assert_eq!(diff.edges[1].target_id, new);
+    }
+
+    #[test]
+    fn sites_are_matched_by_name() {
+        let device = Uuid::new_v4();
+        let site = |id: Uuid| {
+            node(
+                id,
+                NodeType::SiteNode {
+                    site: Some("DC1".into()),
+                },
+                0,
+            )
+        };
+        let device_node = |site_id: Uuid| {
+            node(
+                device,
+                NodeType::DeviceNode {
+                    site_id,
+                    host_id: device,
+                    stack_size: 1,
+                    ports: Vec::new(),
+                },
+                20,
+            )
+        };
+
+        let (old_site, new_site) = (Uuid::new_v4(), Uuid::new_v4());
+        let from = topology(vec![site(old_site), device_node(old_site)], Vec::new());
+        let to = topology(vec![site(new_site), device_node(new_site)], Vec::new());
+
+        assert!(diff(&from, &to).is_empty());
+    }
+
+    #[test]
+    fn overlay_keeps_removed_nodes_and_edges() {
+        let subnet_id = Uuid::new_v4();
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let from = topology(
+            vec![
+                node(subnet_id, NodeType::SubnetNode { infra_width: 0 }, 0),
+                interface_node(a, subnet_id, 10),
+                interface_node(b, subnet_id, 300),
+            ],
+            vec![edge(a, b, Uuid::new_v4())],
+        );
+        let to = topology(
+            vec![
+                node(subnet_id, NodeType::SubnetNode { infra_width: 0 }, 0),
+                interface_node(a, subnet_id, 10),
+            ],
+            Vec::new(),
+        );
+
+        let diff = diff(&from, &to);
+        let overlay = diff.overlay(&from, &to);
+
+        assert_eq!(overlay.base.nodes.len(), 3);
+        assert_eq!(overlay.base.edges.len(), 1);
+        assert_eq!(
+            diff.edge_change(&overlay.base.edges[0].id),
+            Some(TopologyChangeType::Removed)
+        );
+    }
+}
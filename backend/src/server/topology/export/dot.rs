@@ -0,0 +1,138 @@
+use std::fmt::Write;
+
+use crate::server::topology::{
+    diff::TopologyChangeType,
+    export::{ExportGraph, ExportNode},
+};
+
+const BACKGROUND: &str = "#111827";
+const NODE_FILL: &str = "#1f2937";
+const TEXT_COLOR: &str = "#e5e7eb";
+/// Graphviz measures node sizes in inches and positions in points
+const POINTS_PER_INCH: f64 = 72.0;
+
+/// Render Graphviz DOT. Nodes carry pinned `pos` attributes matching the stored
+/// layout, so `neato -n` reproduces it while `dot` is free to lay out clusters itself.
+pub fn render(graph: &ExportGraph) -> String {
+    let mut out = String::new();
+
+    let _ = writeln!(out, "digraph {} {{", quote(&graph.name));
+    let _ = writeln!(
+        out,
+        r#"  graph [bgcolor="{}", fontname="Helvetica", fontcolor="{}", splines=true, outputorder=edgesfirst];"#,
+        BACKGROUND, TEXT_COLOR
+    );
+    let _ = writeln!(
+        out,
+        r#"  node [shape=box, style="rounded,filled", fillcolor="{}", fontcolor="{}", fontname="Helvetica", fontsize=10];"#,
+        NODE_FILL, TEXT_COLOR
+    );
+    let _ = writeln!(
+        out,
+        r#"  edge [fontname="Helvetica", fontsize=9, fontcolor="{}"];"#,
+        TEXT_COLOR
+    );
+
+    for subnet in graph.nodes.iter().filter(|n| n.kind.is_container()) {
+        let _ = writeln!(
+            out,
+            "  subgraph {} {{",
+            quote(&format!("cluster_{}", subnet.id))
+        );
+        let _ = writeln!(
+            out,
+            r#"    label={}; color="{}"; fontcolor="{}"; style="rounded"; labeljust="l";"#,
+            quote(&subnet.label),
+            subnet.color.hex(),
+            subnet.color.hex()
+        );
+        for child in graph.nodes.iter().filter(|n| n.parent == Some(subnet.id)) {
+            write_node(&mut out, graph, child, "    ");
+        }
+        let _ = writeln!(out, "  }}");
+    }
+
+    for node in graph
+        .nodes
+        .iter()
+        .filter(|n| !n.kind.is_container() && n.parent.is_none())
+    {
+        write_node(&mut out, graph, node, "  ");
+    }
+
+    for edge in &graph.edges {
+        let mut attrs = vec![
+            format!(r#"color="{}""#, edge.color.hex()),
+            format!(
+                "arrowhead={}",
+                if edge.directed { "normal" } else { "none" }
+            ),
+            format!("tooltip={}", quote(&edge.edge_type_name)),
+        ];
+        if edge.dashed {
+            attrs.push("style=dashed".to_string());
+        }
+        if edge.change.is_some() {
+            attrs.push("penwidth=3".to_string());
+        }
+        if let Some(label) = &edge.label {
+            attrs.push(format!("label={}", quote(label)));
+        }
+
+        let _ = writeln!(
+            out,
+            "  {} -> {} [{}];",
+            quote(&edge.source.to_string()),
+            quote(&edge.target.to_string()),
+            attrs.join(", ")
+        );
+    }
+
+    let _ = writeln!(out, "}}");
+    out
+}
+
+fn write_node(out: &mut String, graph: &ExportGraph, node: &ExportNode, indent: &str) {
+    let mut lines = vec![node.label.clone()];
+    lines.extend(node.details.iter().cloned());
+
+    // DOT's y axis points up, and positions refer to node centers
+    let center_x = node.x as f64 + node.width as f64 / 2.0;
+    let center_y = (graph.height - node.y) as f64 - node.height as f64 / 2.0;
+
+    // Changed nodes get a heavier border, and removed ones a dashed border
+    let change_attrs = match node.change {
+        None => "",
+        Some(TopologyChangeType::Removed) => r#", style="rounded,filled,dashed", penwidth=3"#,
+        Some(_) => ", penwidth=3",
+    };
+
+    let _ = writeln!(
+        out,
+        r#"{indent}{} [label={}, color="{}", pos="{},{}!", width={:.2}, height={:.2}{change_attrs}];"#,
+        quote(&node.id.to_string()),
+        quote(&lines.join("\n")),
+        node.color.hex(),
+        center_x,
+        center_y,
+        node.width as f64 / POINTS_PER_INCH,
+        node.height as f64 / POINTS_PER_INCH,
+    );
+}
+
+/// Quote a string as a DOT ID
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c if c.is_control() => {}
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
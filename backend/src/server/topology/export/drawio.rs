@@ -0,0 +1,126 @@
+use std::fmt::Write;
+
+use crate::server::topology::{
+    diff::TopologyChangeType,
+    export::{ExportGraph, escape_xml},
+    types::edges::EdgeHandle,
+};
+
+const SUBNET_FILL: &str = "#1a1d29";
+const NODE_FILL: &str = "#1f2937";
+const TEXT_COLOR: &str = "#e5e7eb";
+
+/// Render a diagrams.net document. Subnets are container cells and interface nodes
+/// are their children, so moving a subnet in the editor moves its hosts with it.
+pub fn render(graph: &ExportGraph) -> String {
+    let mut out = String::new();
+
+    let _ = writeln!(out, r#"<mxfile host="Scanopy" type="device">"#);
+    let _ = writeln!(
+        out,
+        r#"  <diagram id="{}" name="{}">"#,
+        graph.id,
+        escape_xml(&graph.name)
+    );
+    let _ = writeln!(
+        out,
+        r#"    <mxGraphModel dx="{w}" dy="{h}" grid="1" gridSize="10" guides="1" tooltips="1" connect="1" arrows="1" fold="1" page="0" pageScale="1" pageWidth="{w}" pageHeight="{h}" background="{}" math="0" shadow="0">"#,
+        SUBNET_FILL,
+        w = graph.width,
+        h = graph.height,
+    );
+    let _ = writeln!(out, "      <root>");
+    let _ = writeln!(out, r#"        <mxCell id="0"/>"#);
+    let _ = writeln!(out, r#"        <mxCell id="1" parent="0"/>"#);
+
+    for node in &graph.nodes {
+        let (parent, x, y) = match node.parent.and_then(|id| graph.node(&id)) {
+            Some(parent) => (parent.id.to_string(), node.x - parent.x, node.y - parent.y),
+            None => ("1".to_string(), node.x, node.y),
+        };
+
+        // Changed nodes get a heavier border, and removed ones a dashed border
+        let change_style = match node.change {
+            None => "",
+            Some(TopologyChangeType::Removed) => "strokeWidth=3;dashed=1;",
+            Some(_) => "strokeWidth=3;",
+        };
+
+        let (value, style) = if node.kind.is_container() {
+            (
+                escape_xml(&node.label),
+                format!(
+                    "rounded=1;arcSize=2;whiteSpace=wrap;html=0;container=1;collapsible=0;fillColor={};strokeColor={};fontColor={};fontStyle=1;align=left;verticalAlign=bottom;labelPosition=center;verticalLabelPosition=top;{}",
+                    SUBNET_FILL,
+                    node.color.hex(),
+                    node.color.hex(),
+                    change_style,
+                ),
+            )
+        } else {
+            let mut lines = vec![node.label.clone()];
+            lines.extend(node.details.iter().cloned());
+            (
+                escape_xml(&lines.join("\n")),
+                format!(
+                    "rounded=1;arcSize=6;whiteSpace=wrap;html=0;fillColor={};strokeColor={};fontColor={};align=left;verticalAlign=top;spacingLeft=8;spacingTop=4;{}",
+                    NODE_FILL,
+                    node.color.hex(),
+                    TEXT_COLOR,
+                    change_style,
+                ),
+            )
+        };
+
+        let _ = writeln!(
+            out,
+            r#"        <mxCell id="{}" value="{value}" style="{style}" vertex="1" parent="{parent}">"#,
+            node.id
+        );
+        let _ = writeln!(
+            out,
+            r#"          <mxGeometry x="{x}" y="{y}" width="{}" height="{}" as="geometry"/>"#,
+            node.width, node.height
+        );
+        let _ = writeln!(out, "        </mxCell>");
+    }
+
+    for edge in &graph.edges {
+        let (exit_x, exit_y) = constraint(edge.source_handle);
+        let (entry_x, entry_y) = constraint(edge.target_handle);
+        let style = format!(
+            "edgeStyle=orthogonalEdgeStyle;rounded=1;html=0;strokeColor={color};strokeWidth={width};fontColor={text};labelBackgroundColor={fill};dashed={dashed};endArrow={arrow};exitX={exit_x};exitY={exit_y};entryX={entry_x};entryY={entry_y};",
+            color = edge.color.hex(),
+            text = TEXT_COLOR,
+            fill = NODE_FILL,
+            width = if edge.change.is_some() { 3 } else { 2 },
+            dashed = u8::from(edge.dashed),
+            arrow = if edge.directed { "block" } else { "none" },
+        );
+        let value = edge.label.as_deref().map(escape_xml).unwrap_or_default();
+
+        let _ = writeln!(
+            out,
+            r#"        <mxCell id="{}" value="{value}" style="{style}" edge="1" parent="1" source="{}" target="{}">"#,
+            edge.id, edge.source, edge.target
+        );
+        let _ = writeln!(out, r#"          <mxGeometry relative="1" as="geometry"/>"#);
+        let _ = writeln!(out, "        </mxCell>");
+    }
+
+    let _ = writeln!(out, "      </root>");
+    let _ = writeln!(out, "    </mxGraphModel>");
+    let _ = writeln!(out, "  </diagram>");
+    let _ = writeln!(out, "</mxfile>");
+    out
+}
+
+/// Relative connection point on a cell's border for a handle
+fn constraint(handle: EdgeHandle) -> (&'static str, &'static str) {
+    match handle {
+        EdgeHandle::Top => ("0.5", "0"),
+        EdgeHandle::Bottom => ("0.5", "1"),
+        EdgeHandle::Left => ("0", "0.5"),
+        EdgeHandle::Right => ("1", "0.5"),
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt::Write;
+
+use crate::server::topology::export::{ExportGraph, ExportNode, escape_xml};
+
+/// (id, target, attr.name, attr.type) for every data key the export emits
+const KEYS: &[(&str, &str, &str, &str)] = &[
+    ("label", "node", "label", "string"),
+    ("kind", "node", "kind", "string"),
+    ("details", "node", "details", "string"),
+    ("x", "node", "x", "int"),
+    ("y", "node", "y", "int"),
+    ("width", "node", "width", "int"),
+    ("height", "node", "height", "int"),
+    ("color", "all", "color", "string"),
+    ("edge_label", "edge", "label", "string"),
+    ("edge_type", "edge", "edge_type", "string"),
+    ("dashed", "edge", "dashed", "boolean"),
+    ("change", "all", "change", "string"),
+];
+
+/// Render GraphML. Subnets become nodes holding a nested graph of their interface
+/// nodes; edges live in the top-level graph since they may cross subnets.
+pub fn render(graph: &ExportGraph) -> String {
+    let mut out = String::new();
+
+    let _ = writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = writeln!(
+        out,
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:schemaLocation="http://graphml.graphdrawing.org/xmlns http://graphml.graphdrawing.org/xmlns/1.0/graphml.xsd">"#
+    );
+    for (id, target, name, kind) in KEYS {
+        let _ = writeln!(
+            out,
+            r#"  <key id="{id}" for="{target}" attr.name="{name}" attr.type="{kind}"/>"#
+        );
+    }
+    let _ = writeln!(
+        out,
+        r#"  <graph id="{}" edgedefault="undirected">"#,
+        graph.id
+    );
+
+    for subnet in graph.nodes.iter().filter(|n| n.kind.is_container()) {
+        let _ = writeln!(out, r#"    <node id="{}">"#, subnet.id);
+        write_node_data(&mut out, subnet, "      ");
+        let _ = writeln!(
+            out,
+            r#"      <graph id="{}:children" edgedefault="undirected">"#,
+            subnet.id
+        );
+        for child in graph.nodes.iter().filter(|n| n.parent == Some(subnet.id)) {
+            write_leaf(&mut out, child, "        ");
+        }
+        let _ = writeln!(out, "      </graph>");
+        let _ = writeln!(out, "    </node>");
+    }
+
+    // Child nodes whose container isn't part of the topology graph
+    for node in graph
+        .nodes
+        .iter()
+        .filter(|n| !n.kind.is_container() && n.parent.is_none())
+    {
+        write_leaf(&mut out, node, "    ");
+    }
+
+    for edge in &graph.edges {
+        let _ = writeln!(
+            out,
+            r#"    <edge id="{}" source="{}" target="{}" directed="{}">"#,
+            edge.id, edge.source, edge.target, edge.directed
+        );
+        if let Some(label) = &edge.label {
+            let _ = writeln!(
+                out,
+                r#"      <data key="edge_label">{}</data>"#,
+                escape_xml(label)
+            );
+        }
+        let _ = writeln!(
+            out,
+            r#"      <data key="edge_type">{}</data>"#,
+            edge.edge_type
+        );
+        let _ = writeln!(
+            out,
+            r#"      <data key="color">{}</data>"#,
+            edge.color.hex()
+        );
+        let _ = writeln!(out, r#"      <data key="dashed">{}</data>"#, edge.dashed);
+        if let Some(change) = edge.change {
+            let _ = writeln!(out, r#"      <data key="change">{change}</data>"#);
+        }
+        let _ = writeln!(out, "    </edge>");
+    }
+
+    let _ = writeln!(out, "  </graph>");
+    let _ = writeln!(out, "</graphml>");
+    out
+}
+
+fn write_leaf(out: &mut String, node: &ExportNode, indent: &str) {
+    let _ = writeln!(out, r#"{indent}<node id="{}">"#, node.id);
+    write_node_data(out, node, &format!("{indent}  "));
+    let _ = writeln!(out, "{indent}</node>");
+}
+
+fn write_node_data(out: &mut String, node: &ExportNode, indent: &str) {
+    let kind = node.kind.name();
+
+    let _ = writeln!(
+        out,
+        r#"{indent}<data key="label">{}</data>"#,
+        escape_xml(&node.label)
+    );
+    let _ = writeln!(out, r#"{indent}<data key="kind">{kind}</data>"#);
+    if !node.details.is_empty() {
+        let _ = writeln!(
+            out,
+            r#"{indent}<data key="details">{}</data>"#,
+            escape_xml(&node.details.join("\n"))
+        );
+    }
+    let _ = writeln!(out, r#"{indent}<data key="x">{}</data>"#, node.x);
+    let _ = writeln!(out, r#"{indent}<data key="y">{}</data>"#, node.y);
+    let _ = writeln!(out, r#"{indent}<data key="width">{}</data>"#, node.width);
+    let _ = writeln!(out, r#"{indent}<data key="height">{}</data>"#, node.height);
+    let _ = writeln!(
+        out,
+        r#"{indent}<data key="color">{}</data>"#,
+        node.color.hex()
+    );
+    if let Some(change) = node.change {
+        let _ = writeln!(out, r#"{indent}<data key="change">{change}</data>"#);
+    }
+}
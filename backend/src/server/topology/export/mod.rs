@@ -0,0 +1,843 @@
+//! Server-side rendering of stored topologies into portable diagram formats.
+//!
+//! Every format is rendered from the same [`ExportGraph`], which flattens the
+//! topology's nodes into absolute coordinates and resolves the labels the UI
+//! would show, so diagrams line up with what users see in the browser.
+
+pub mod dot;
+pub mod drawio;
+pub mod graphml;
+pub mod svg;
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::server::{
+    services::r#impl::definitions::ServiceDefinition,
+    shared::{
+        entities::EntityDiscriminants,
+        types::{
+            Color,
+            metadata::{EntityMetadataProvider, TypeMetadataProvider},
+        },
+    },
+    topology::{
+        diff::{TopologyChangeType, TopologyDiff, TopologyDiffSide},
+        types::{
+            base::Topology,
+            edges::{EdgeHandle, EdgeTypeDiscriminants},
+            nodes::NodeType,
+        },
+    },
+};
+
+/// Space left around the diagram, including room for subnet labels above the top row
+const EXPORT_MARGIN: i64 = 40;
+
+/// Diagram format a topology can be exported to
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default, Display, ToSchema)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum TopologyExportFormat {
+    /// Standalone SVG with inline styles, suitable for direct PNG conversion
+    #[default]
+    Svg,
+    /// GraphML with subnets as nested graphs
+    #[serde(rename = "graphml")]
+    #[strum(serialize = "graphml")]
+    GraphMl,
+    /// diagrams.net / draw.io document
+    #[serde(rename = "drawio")]
+    #[strum(serialize = "drawio")]
+    DrawIo,
+    /// Graphviz DOT with subnets as clusters and pinned node positions
+    Dot,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TopologyExportQuery {
+    /// Output format (defaults to svg)
+    #[serde(default)]
+    #[param(inline)]
+    pub format: TopologyExportFormat,
+    /// Snapshot to compare against. Nodes and edges added, removed or moved since the
+    /// snapshot are highlighted.
+    pub compare_to: Option<Uuid>,
+}
+
+impl TopologyExportFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            TopologyExportFormat::Svg => "image/svg+xml",
+            TopologyExportFormat::GraphMl => "application/graphml+xml",
+            TopologyExportFormat::DrawIo => "application/vnd.jgraph.mxfile",
+            TopologyExportFormat::Dot => "text/vnd.graphviz",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            TopologyExportFormat::Svg => "svg",
+            TopologyExportFormat::GraphMl => "graphml",
+            TopologyExportFormat::DrawIo => "drawio",
+            TopologyExportFormat::Dot => "dot",
+        }
+    }
+
+    /// Render `topology`, highlighting changes since `previous` when given
+    pub fn render(&self, topology: &Topology, previous: Option<&Topology>) -> String {
+        let graph = match previous {
+            Some(previous) => ExportGraph::from_comparison(previous, topology),
+            None => ExportGraph::from_topology(topology),
+        };
+        match self {
+            TopologyExportFormat::Svg => svg::render(&graph),
+            TopologyExportFormat::GraphMl => graphml::render(&graph),
+            TopologyExportFormat::DrawIo => drawio::render(&graph),
+            TopologyExportFormat::Dot => dot::render(&graph),
+        }
+    }
+
+    /// Filename for the exported topology, keeping only characters that are
+    /// safe in a Content-Disposition header and on common filesystems
+    pub fn filename(&self, topology: &Topology) -> String {
+        let stem: String = topology
+            .base
+            .name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        let stem = stem.trim_matches('_');
+        let stem = if stem.is_empty() { "topology" } else { stem };
+        format!("{}.{}", stem, self.extension())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportNodeKind {
+    Subnet,
+    Interface,
+    Site,
+    Device,
+    Service,
+}
+
+impl ExportNodeKind {
+    /// Whether nodes of this kind contain other nodes
+    pub fn is_container(&self) -> bool {
+        matches!(self, ExportNodeKind::Subnet | ExportNodeKind::Site)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExportNodeKind::Subnet => "subnet",
+            ExportNodeKind::Interface => "interface",
+            ExportNodeKind::Site => "site",
+            ExportNodeKind::Device => "device",
+            ExportNodeKind::Service => "service",
+        }
+    }
+}
+
+/// A node positioned in absolute diagram coordinates
+#[derive(Debug, Clone)]
+pub struct ExportNode {
+    pub id: Uuid,
+    /// Containing subnet or site node, when it is part of the export
+    pub parent: Option<Uuid>,
+    pub kind: ExportNodeKind,
+    pub label: String,
+    pub details: Vec<String>,
+    pub x: i64,
+    pub y: i64,
+    pub width: i64,
+    pub height: i64,
+    pub color: Color,
+    /// Set when exporting a comparison and the node changed
+    pub change: Option<TopologyChangeType>,
+}
+
+impl ExportNode {
+    /// Point on the node's border where an edge attached to `handle` starts or ends
+    pub fn anchor(&self, handle: EdgeHandle) -> (i64, i64) {
+        match handle {
+            EdgeHandle::Top => (self.x + self.width / 2, self.y),
+            EdgeHandle::Bottom => (self.x + self.width / 2, self.y + self.height),
+            EdgeHandle::Left => (self.x, self.y + self.height / 2),
+            EdgeHandle::Right => (self.x + self.width, self.y + self.height / 2),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExportEdge {
+    pub id: Uuid,
+    pub source: Uuid,
+    pub target: Uuid,
+    pub source_handle: EdgeHandle,
+    pub target_handle: EdgeHandle,
+    pub label: Option<String>,
+    /// Edge type identifier, e.g. "RequestPath"
+    pub edge_type: &'static str,
+    /// Human-readable edge type name
+    pub edge_type_name: String,
+    pub color: Color,
+    pub dashed: bool,
+    pub directed: bool,
+    /// Set when exporting a comparison and the edge changed
+    pub change: Option<TopologyChangeType>,
+}
+
+/// Format-independent view of a topology used by all exporters
+#[derive(Debug, Clone)]
+pub struct ExportGraph {
+    pub id: Uuid,
+    pub name: String,
+    pub width: i64,
+    pub height: i64,
+    /// Container nodes first, then their children
+    pub nodes: Vec<ExportNode>,
+    pub edges: Vec<ExportEdge>,
+}
+
+impl ExportGraph {
+    pub fn from_topology(topology: &Topology) -> Self {
+        let base = &topology.base;
+
+        let subnets: HashMap<Uuid, _> = base.subnets.iter().map(|s| (s.id, s)).collect();
+        let hosts: HashMap<Uuid, _> = base.hosts.iter().map(|h| (h.id, h)).collect();
+        let interfaces: HashMap<Uuid, _> = base.interfaces.iter().map(|i| (i.id, i)).collect();
+
+        let container_positions: HashMap<Uuid, (i64, i64)> = base
+            .nodes
+            .iter()
+            .filter(|n| {
+                matches!(
+                    n.node_type,
+                    NodeType::SubnetNode { .. } | NodeType::SiteNode { .. }
+                )
+            })
+            .map(|n| (n.id, (n.position.x as i64, n.position.y as i64)))
+            .collect();
+
+        let mut container_nodes = Vec::new();
+        let mut child_nodes = Vec::new();
+
+        for node in &base.nodes {
+            let width = node.size.x as i64;
+            let height = node.size.y as i64;
+
+            match &node.node_type {
+                NodeType::SubnetNode { .. } => {
+                    let subnet = subnets.get(&node.id);
+                    let label = node.header.clone().unwrap_or_else(|| match subnet {
+                        Some(subnet) => {
+                            let cidr = subnet.base.cidr.to_string();
+                            let name = if subnet.base.name != cidr {
+                                subnet.base.name.clone()
+                            } else {
+                                subnet.base.subnet_type.name().to_string()
+                            };
+                            if subnet.base.subnet_type.is_container_network() {
+                                name
+                            } else {
+                                format!("{}: {}", name, cidr)
+                            }
+                        }
+                        None => "Subnet".to_string(),
+                    });
+
+                    container_nodes.push(ExportNode {
+                        id: node.id,
+                        parent: None,
+                        kind: ExportNodeKind::Subnet,
+                        label,
+                        details: Vec::new(),
+                        x: node.position.x as i64,
+                        y: node.position.y as i64,
+                        width,
+                        height,
+                        color: subnet
+                            .map(|s| s.base.subnet_type.color())
+                            .unwrap_or(Color::Gray),
+                        change: None,
+                    });
+                }
+                NodeType::InterfaceNode {
+                    subnet_id,
+                    host_id,
+                    interface_id,
+                    ..
+                } => {
+                    let host_name = hosts
+                        .get(host_id)
+                        .map(|h| h.base.name.clone())
+                        .unwrap_or_default();
+
+                    // Services shown on a node are those bound to this interface, or to all interfaces
+                    let mut details: Vec<String> = base
+                        .services
+                        .iter()
+                        .filter(|s| s.base.host_id == *host_id)
+                        .filter(|s| {
+                            s.base.bindings.iter().any(|b| {
+                                b.interface_id().is_none() || b.interface_id() == *interface_id
+                            })
+                        })
+                        .map(|s| s.base.name.clone())
+                        .collect();
+
+                    let label = match &node.header {
+                        Some(header) => {
+                            if details.is_empty() {
+                                details.push(host_name);
+                            }
+                            header.clone()
+                        }
+                        None => host_name,
+                    };
+
+                    let is_container_subnet = subnets
+                        .get(subnet_id)
+                        .is_some_and(|s| s.base.subnet_type.is_container_network());
+
+                    if let Some(interface) = interface_id.and_then(|id| interfaces.get(&id))
+                        && !is_container_subnet
+                    {
+                        details.push(match &interface.base.name {
+                            Some(name) => format!("{}: {}", name, interface.base.ip_address),
+                            None => interface.base.ip_address.to_string(),
+                        });
+                    }
+
+                    // Interface node positions are relative to their subnet node
+                    let parent_position = container_positions.get(subnet_id).copied();
+                    let (offset_x, offset_y) = parent_position.unwrap_or((0, 0));
+
+                    child_nodes.push(ExportNode {
+                        id: node.id,
+                        parent: parent_position.map(|_| *subnet_id),
+                        kind: ExportNodeKind::Interface,
+                        label,
+                        details,
+                        x: offset_x + node.position.x as i64,
+                        y: offset_y + node.position.y as i64,
+                        width,
+                        height,
+                        color: subnets
+                            .get(subnet_id)
+                            .map(|s| s.base.subnet_type.color())
+                            .unwrap_or(Color::Gray),
+                        change: None,
+                    });
+                }
+                NodeType::SiteNode { site } => {
+                    container_nodes.push(ExportNode {
+                        id: node.id,
+                        parent: None,
+                        kind: ExportNodeKind::Site,
+                        label: site.clone().unwrap_or_else(|| "Unassigned".to_string()),
+                        details: Vec::new(),
+                        x: node.position.x as i64,
+                        y: node.position.y as i64,
+                        width,
+                        height,
+                        color: EntityDiscriminants::IfEntry.color(),
+                        change: None,
+                    });
+                }
+                NodeType::DeviceNode {
+                    site_id,
+                    host_id,
+                    stack_size,
+                    ports,
+                } => {
+                    let mut details = Vec::new();
+                    if *stack_size > 1 {
+                        details.push(format!("Stack of {}", stack_size));
+                    }
+                    if let Some(interface) = base
+                        .interfaces
+                        .iter()
+                        .filter(|i| i.base.host_id == *host_id)
+                        .min_by_key(|i| i.base.position)
+                    {
+                        details.push(interface.base.ip_address.to_string());
+                    }
+                    if !ports.is_empty() {
+                        details.push(format!("{} linked ports", ports.len()));
+                    }
+
+                    // Device node positions are relative to their site node
+                    let parent_position = container_positions.get(site_id).copied();
+                    let (offset_x, offset_y) = parent_position.unwrap_or((0, 0));
+
+                    child_nodes.push(ExportNode {
+                        id: node.id,
+                        parent: parent_position.map(|_| *site_id),
+                        kind: ExportNodeKind::Device,
+                        label: hosts
+                            .get(host_id)
+                            .map(|h| h.base.name.clone())
+                            .unwrap_or_default(),
+                        details,
+                        x: offset_x + node.position.x as i64,
+                        y: offset_y + node.position.y as i64,
+                        width,
+                        height,
+                        color: EntityDiscriminants::Host.color(),
+                        change: None,
+                    });
+                }
+                NodeType::ServiceNode {
+                    service_id,
+                    host_id,
+                    subnet_ids,
+                } => {
+                    let service = base.services.iter().find(|s| s.id == *service_id);
+
+                    // Host and subnets are annotations, the service is what the node stands for
+                    let mut details = Vec::new();
+                    if let Some(host) = hosts.get(host_id) {
+                        details.push(format!("on {}", host.base.name));
+                    }
+                    let subnet_names: Vec<String> = subnet_ids
+                        .iter()
+                        .filter_map(|id| subnets.get(id))
+                        .map(|subnet| subnet.base.cidr.to_string())
+                        .collect();
+                    if !subnet_names.is_empty() {
+                        details.push(subnet_names.join(", "));
+                    }
+
+                    child_nodes.push(ExportNode {
+                        id: node.id,
+                        parent: None,
+                        kind: ExportNodeKind::Service,
+                        label: service.map(|s| s.base.name.clone()).unwrap_or_default(),
+                        details,
+                        x: node.position.x as i64,
+                        y: node.position.y as i64,
+                        width,
+                        height,
+                        color: service
+                            .map(|s| {
+                                ServiceDefinition::category(&s.base.service_definition).color()
+                            })
+                            .unwrap_or(EntityDiscriminants::Service.color()),
+                        change: None,
+                    });
+                }
+            }
+        }
+
+        let mut nodes = container_nodes;
+        nodes.extend(child_nodes);
+
+        // Shift everything so the diagram starts at the margin
+        let min_x = nodes.iter().map(|n| n.x).min().unwrap_or(0);
+        let min_y = nodes.iter().map(|n| n.y).min().unwrap_or(0);
+        for node in &mut nodes {
+            node.x += EXPORT_MARGIN - min_x;
+            node.y += EXPORT_MARGIN - min_y;
+        }
+
+        let width = nodes.iter().map(|n| n.x + n.width).max().unwrap_or(0) + EXPORT_MARGIN;
+        let height = nodes.iter().map(|n| n.y + n.height).max().unwrap_or(0) + EXPORT_MARGIN;
+
+        let node_ids: Vec<Uuid> = nodes.iter().map(|n| n.id).collect();
+        let hidden = &base.options.local.hide_edge_types;
+
+        let edges = base
+            .edges
+            .iter()
+            .filter(|e| node_ids.contains(&e.source) && node_ids.contains(&e.target))
+            .filter(|e| !hidden.contains(&EdgeTypeDiscriminants::from(&e.edge_type)))
+            .map(|e| {
+                let metadata = e.edge_type.metadata();
+                let flag = |key: &str| metadata.get(key).and_then(|v| v.as_bool()) == Some(true);
+
+                ExportEdge {
+                    id: e.id,
+                    source: e.source,
+                    target: e.target,
+                    source_handle: e.source_handle,
+                    target_handle: e.target_handle,
+                    label: e.label.clone().filter(|l| !l.is_empty()),
+                    edge_type: (&e.edge_type).into(),
+                    edge_type_name: e.edge_type.name().to_string(),
+                    color: e.edge_type.color(),
+                    dashed: flag("is_dashed"),
+                    directed: flag("has_end_marker"),
+                    change: None,
+                }
+            })
+            .collect();
+
+        Self {
+            id: topology.id,
+            name: base.name.clone(),
+            width: width.max(EXPORT_MARGIN * 2),
+            height: height.max(EXPORT_MARGIN * 2),
+            nodes,
+            edges,
+        }
+    }
+
+    /// Graph of `current` with nodes and edges removed since `previous` drawn where they
+    /// were, and every change recolored so it stands out
+    pub fn from_comparison(previous: &Topology, current: &Topology) -> Self {
+        let diff = TopologyDiff::between(
+            TopologyDiffSide::from(previous),
+            previous,
+            TopologyDiffSide::from(current),
+            current,
+        );
+        let mut graph = Self::from_topology(&diff.overlay(previous, current));
+
+        for node in &mut graph.nodes {
+            node.change = diff.node_change(&node.id);
+            if let Some(change) = node.change {
+                node.color = change.color();
+            }
+        }
+        for edge in &mut graph.edges {
+            edge.change = diff.edge_change(&edge.id);
+            if let Some(change) = edge.change {
+                edge.color = change.color();
+                edge.dashed |= change == TopologyChangeType::Removed;
+            }
+        }
+
+        graph
+    }
+
+    pub fn node(&self, id: &Uuid) -> Option<&ExportNode> {
+        self.nodes.iter().find(|n| n.id == *id)
+    }
+}
+
+/// Escape text for use in XML element content and attribute values
+pub(super) fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::{
+        bindings::r#impl::base::{Binding, BindingBase, BindingType},
+        shared::storage::traits::Storable,
+        topology::types::{
+            base::TopologyBase,
+            edges::{Edge, EdgeType},
+            layout::{Ixy, Uxy},
+            nodes::Node,
+        },
+    };
+    use crate::tests::{host, interface, service, subnet};
+
+    fn topology() -> Topology {
+        let network_id = Uuid::new_v4();
+        let mut subnet = subnet(&network_id);
+        subnet.base.name = "Office <LAN>".to_string();
+
+        let web = host(&network_id);
+        let mut db = host(&network_id);
+        db.base.name = "db & cache".to_string();
+
+        let mut web_iface = interface(&network_id, &subnet.id);
+        web_iface.base.host_id = web.id;
+        let mut db_iface = interface(&network_id, &subnet.id);
+        db_iface.base.host_id = db.id;
+
+        let mut svc = service(&network_id, &web.id);
+        svc.base.name = "nginx".to_string();
+        svc.base.bindings = vec![Binding::new(BindingBase {
+            service_id: svc.id,
+            network_id,
+            binding_type: BindingType::Interface {
+                interface_id: web_iface.id,
+            },
+        })];
+
+        let subnet_node = Node {
+            node_type: NodeType::SubnetNode { infra_width: 0 },
+            id: subnet.id,
+            position: Ixy { x: -100, y: 50 },
+            size: Uxy { x: 600, y: 300 },
+            header: None,
+        };
+        let interface_node = |id: Uuid, host_id: Uuid, interface_id: Uuid, x: isize| Node {
+            node_type: NodeType::InterfaceNode {
+                subnet_id: subnet.id,
+                host_id,
+                interface_id: Some(interface_id),
+                is_infra: false,
+            },
+            id,
+            position: Ixy { x, y: 50 },
+            size: Uxy { x: 250, y: 100 },
+            header: None,
+        };
+        let web_node = interface_node(Uuid::new_v4(), web.id, web_iface.id, 25);
+        let db_node = interface_node(Uuid::new_v4(), db.id, db_iface.id, 325);
+
+        let edge = |edge_type: EdgeType, label: Option<&str>| Edge {
+            id: Uuid::new_v4(),
+            source: web_node.id,
+            target: db_node.id,
+            edge_type,
+            label: label.map(str::to_string),
+            source_handle: EdgeHandle::Right,
+            target_handle: EdgeHandle::Left,
+            is_multi_hop: false,
+        };
+        let request_path = edge(
+            EdgeType::RequestPath {
+                group_id: Uuid::new_v4(),
+                source_binding_id: Uuid::new_v4(),
+                target_binding_id: Uuid::new_v4(),
+            },
+            Some("Web -> DB"),
+        );
+        let dangling = Edge {
+            target: Uuid::new_v4(),
+            ..edge(EdgeType::Interface { host_id: web.id }, None)
+        };
+
+        let mut base = TopologyBase::new("Home / Lab".to_string(), network_id);
+        base.nodes = vec![web_node, db_node, subnet_node];
+        base.edges = vec![request_path, dangling];
+        base.subnets = vec![subnet];
+        base.hosts = vec![web, db];
+        base.interfaces = vec![web_iface, db_iface];
+        base.services = vec![svc];
+        Topology::new(base)
+    }
+
+    #[test]
+    fn export_graph_resolves_labels_and_absolute_positions() {
+        let graph = ExportGraph::from_topology(&topology());
+
+        assert_eq!(graph.nodes.len(), 3);
+        assert_eq!(graph.nodes[0].kind, ExportNodeKind::Subnet);
+        assert_eq!(graph.nodes[0].label, "Office <LAN>: 192.168.1.0/24");
+        assert_eq!((graph.nodes[0].x, graph.nodes[0].y), (40, 40));
+
+        let web = &graph.nodes[1];
+        assert_eq!(web.parent, Some(graph.nodes[0].id));
+        assert_eq!((web.x, web.y), (65, 90));
+        assert_eq!(web.label, "Test Host");
+        assert_eq!(web.details, vec!["nginx", "eth0: 192.168.1.100"]);
+
+        // Edges pointing at nodes outside the graph are dropped
+        assert_eq!(graph.edges.len(), 1);
+        assert!(graph.edges[0].directed);
+        assert_eq!(graph.edges[0].edge_type, "RequestPath");
+        assert_eq!((graph.width, graph.height), (680, 380));
+    }
+
+    #[test]
+    fn export_graph_skips_hidden_edge_types() {
+        let mut topology = topology();
+        topology.base.options.local.hide_edge_types = vec![EdgeTypeDiscriminants::RequestPath];
+
+        assert!(ExportGraph::from_topology(&topology).edges.is_empty());
+    }
+
+    #[test]
+    fn all_formats_render_escaped_output() {
+        let topology = topology();
+
+        let svg = TopologyExportFormat::Svg.render(&topology, None);
+        assert!(svg.starts_with("<?xml"));
+        assert!(svg.contains("width=\"680\" height=\"380\""));
+        assert!(svg.contains("Office &lt;LAN&gt;: 192.168.1.0/24"));
+        assert!(svg.contains("db &amp; cache"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+
+        let graphml = TopologyExportFormat::GraphMl.render(&topology, None);
+        assert!(graphml.contains("<graphml"));
+        assert!(graphml.contains("Web -&gt; DB"));
+        assert_eq!(graphml.matches("<edge ").count(), 1);
+        assert_eq!(graphml.matches("<graph ").count(), 2);
+
+        let drawio = TopologyExportFormat::DrawIo.render(&topology, None);
+        assert!(drawio.contains("<mxfile"));
+        assert!(drawio.contains("container=1"));
+        assert!(drawio.contains("Test Host&#10;nginx"));
+        assert_eq!(drawio.matches("edge=\"1\"").count(), 1);
+
+        let dot = TopologyExportFormat::Dot.render(&topology, None);
+        assert!(dot.starts_with("digraph \"Home / Lab\""));
+        assert!(dot.contains("subgraph \"cluster_"));
+        assert!(dot.contains("arrowhead=normal"));
+    }
+
+    #[test]
+    fn export_graph_nests_devices_in_sites() {
+        let mut topology = topology();
+        let host_id = topology.base.hosts[1].id;
+        let site_id = Uuid::new_v4();
+
+        topology.base.nodes = vec![
+            Node {
+                node_type: NodeType::DeviceNode {
+                    site_id,
+                    host_id,
+                    stack_size: 2,
+                    ports: Vec::new(),
+                },
+                id: host_id,
+                position: Ixy { x: 50, y: 50 },
+                size: Uxy { x: 200, y: 100 },
+                header: None,
+            },
+            Node {
+                node_type: NodeType::SiteNode { site: None },
+                id: site_id,
+                position: Ixy { x: 0, y: 0 },
+                size: Uxy { x: 300, y: 200 },
+                header: None,
+            },
+        ];
+
+        let graph = ExportGraph::from_topology(&topology);
+
+        assert_eq!(graph.nodes[0].kind, ExportNodeKind::Site);
+        assert_eq!(graph.nodes[0].label, "Unassigned");
+
+        let device = &graph.nodes[1];
+        assert_eq!(device.kind, ExportNodeKind::Device);
+        assert_eq!(device.parent, Some(site_id));
+        assert_eq!((device.x, device.y), (90, 90));
+        assert_eq!(device.label, "db & cache");
+        assert_eq!(device.details, vec!["Stack of 2", "192.168.1.100"]);
+
+        let graphml = TopologyExportFormat::GraphMl.render(&topology, None);
+        assert!(graphml.contains(r#"<data key="kind">site</data>"#));
+        assert!(graphml.contains(r#"<data key="kind">device</data>"#));
+    }
+
+    #[test]
+    fn export_graph_annotates_service_nodes() {
+        let mut topology = topology();
+        let service = topology.base.services[0].clone();
+        let backend_id = Uuid::new_v4();
+        let service_node = |id: Uuid, host_id: Uuid, subnet_ids: Vec<Uuid>, y: isize| Node {
+            node_type: NodeType::ServiceNode {
+                service_id: id,
+                host_id,
+                subnet_ids,
+            },
+            id,
+            position: Ixy { x: 0, y },
+            size: Uxy { x: 250, y: 100 },
+            header: None,
+        };
+
+        topology.base.nodes = vec![
+            service_node(
+                service.id,
+                service.base.host_id,
+                vec![topology.base.subnets[0].id],
+                0,
+            ),
+            service_node(backend_id, Uuid::new_v4(), Vec::new(), 250),
+        ];
+        topology.base.edges = vec![Edge {
+            id: Uuid::new_v4(),
+            source: service.id,
+            target: backend_id,
+            edge_type: EdgeType::ProxyRoute {
+                routes: vec!["app@docker".to_string()],
+            },
+            label: Some("app@docker".to_string()),
+            source_handle: EdgeHandle::Bottom,
+            target_handle: EdgeHandle::Top,
+            is_multi_hop: false,
+        }];
+
+        let graph = ExportGraph::from_topology(&topology);
+
+        let node = graph.node(&service.id).unwrap();
+        assert_eq!(node.kind, ExportNodeKind::Service);
+        assert_eq!(node.parent, None);
+        assert_eq!(node.label, "nginx");
+        assert_eq!(
+            node.details,
+            vec![
+                format!("on {}", topology.base.hosts[0].base.name),
+                topology.base.subnets[0].base.cidr.to_string(),
+            ]
+        );
+
+        assert_eq!(graph.edges.len(), 1);
+        assert!(graph.edges[0].directed);
+        assert_eq!(graph.edges[0].edge_type, "ProxyRoute");
+    }
+
+    #[test]
+    fn comparison_highlights_changes() {
+        let previous = topology();
+        let mut current = previous.clone();
+        // Drop the db node along with its edge, and shift the web node
+        let db_node = current.base.nodes.remove(1);
+        current.base.edges.clear();
+        current.base.nodes[0].position.x += 50;
+        let web_node = current.base.nodes[0].id;
+
+        let graph = ExportGraph::from_comparison(&previous, &current);
+
+        assert_eq!(graph.nodes.len(), 3);
+        assert_eq!(graph.nodes[0].change, None);
+        let web = graph.node(&web_node).unwrap();
+        assert_eq!(web.change, Some(TopologyChangeType::Moved));
+        assert_eq!(web.color, Color::Orange);
+        let db = graph.node(&db_node.id).unwrap();
+        assert_eq!(db.change, Some(TopologyChangeType::Removed));
+        assert_eq!(db.label, "db & cache");
+
+        assert_eq!(graph.edges.len(), 1);
+        assert_eq!(graph.edges[0].change, Some(TopologyChangeType::Removed));
+        assert!(graph.edges[0].dashed);
+
+        let graphml = TopologyExportFormat::GraphMl.render(&current, Some(&previous));
+        assert!(graphml.contains(r#"<data key="change">removed</data>"#));
+        assert!(graphml.contains(r#"<data key="change">moved</data>"#));
+    }
+
+    #[test]
+    fn filename_is_header_safe() {
+        let topology = topology();
+        assert_eq!(
+            TopologyExportFormat::DrawIo.filename(&topology),
+            "Home___Lab.drawio"
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::server::topology::{
+    diff::TopologyChangeType,
+    export::{ExportGraph, ExportNode, escape_xml},
+    types::edges::EdgeHandle,
+};
+
+const BACKGROUND: &str = "#111827";
+const SUBNET_FILL: &str = "#1a1d29";
+const NODE_FILL: &str = "#1f2937";
+const TEXT_COLOR: &str = "#e5e7eb";
+const MUTED_TEXT_COLOR: &str = "#9ca3af";
+const FONT_FAMILY: &str = "Inter, Helvetica, Arial, sans-serif";
+const FONT_SIZE: i64 = 12;
+const LINE_HEIGHT: i64 = 16;
+const TEXT_PADDING: i64 = 10;
+/// Approximate glyph width used to truncate text that would overflow a node
+const CHAR_WIDTH: i64 = 7;
+/// Distance control points are pushed out from a handle, giving edges a smooth exit
+const CURVE_OFFSET: i64 = 60;
+
+/// Render a standalone SVG. Styles are inlined and dimensions are explicit so the
+/// output converts to PNG with any rasterizer (rsvg-convert, inkscape, cairosvg).
+pub fn render(graph: &ExportGraph) -> String {
+    let mut out = String::new();
+
+    let _ = writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="{font}" font-size="{size}">"#,
+        w = graph.width,
+        h = graph.height,
+        font = FONT_FAMILY,
+        size = FONT_SIZE,
+    );
+    let _ = writeln!(out, "  <title>{}</title>", escape_xml(&graph.name));
+
+    // One arrowhead marker per edge color, since markers can't inherit stroke color in SVG 1.1
+    let marker_colors: BTreeMap<&str, &str> = graph
+        .edges
+        .iter()
+        .filter(|e| e.directed)
+        .map(|e| (e.color.hex(), e.color.hex().trim_start_matches('#')))
+        .collect();
+    if !marker_colors.is_empty() {
+        let _ = writeln!(out, "  <defs>");
+        for (hex, suffix) in &marker_colors {
+            let _ = writeln!(
+                out,
+                r#"    <marker id="arrow-{suffix}" viewBox="0 0 10 10" refX="9" refY="5" markerWidth="8" markerHeight="8" orient="auto-start-reverse"><path d="M 0 0 L 10 5 L 0 10 z" fill="{hex}"/></marker>"#,
+            );
+        }
+        let _ = writeln!(out, "  </defs>");
+    }
+
+    let _ = writeln!(
+        out,
+        r#"  <rect width="100%" height="100%" fill="{}"/>"#,
+        BACKGROUND
+    );
+
+    for node in graph.nodes.iter().filter(|n| n.kind.is_container()) {
+        render_subnet(&mut out, node);
+    }
+
+    for edge in &graph.edges {
+        let (Some(source), Some(target)) = (graph.node(&edge.source), graph.node(&edge.target))
+        else {
+            continue;
+        };
+
+        let (sx, sy) = source.anchor(edge.source_handle);
+        let (tx, ty) = target.anchor(edge.target_handle);
+        let (c1x, c1y) = push_out(sx, sy, edge.source_handle);
+        let (c2x, c2y) = push_out(tx, ty, edge.target_handle);
+
+        let mut attrs = format!(
+            r#"fill="none" stroke="{}" stroke-width="2""#,
+            edge.color.hex()
+        );
+        if edge.dashed {
+            attrs.push_str(r#" stroke-dasharray="6 4""#);
+        }
+        if edge.directed {
+            let _ = write!(
+                attrs,
+                r#" marker-end="url(#arrow-{})""#,
+                edge.color.hex().trim_start_matches('#')
+            );
+        }
+
+        let _ = writeln!(
+            out,
+            r#"  <path d="M {sx} {sy} C {c1x} {c1y}, {c2x} {c2y}, {tx} {ty}" {attrs}><title>{}</title></path>"#,
+            escape_xml(&edge.edge_type_name),
+        );
+
+        if let Some(label) = &edge.label {
+            // Midpoint of the cubic bezier (t = 0.5)
+            let mx = (sx + 3 * c1x + 3 * c2x + tx) / 8;
+            let my = (sy + 3 * c1y + 3 * c2y + ty) / 8;
+            let label_width = label.chars().count() as i64 * CHAR_WIDTH + TEXT_PADDING;
+            let _ = writeln!(
+                out,
+                r#"  <rect x="{}" y="{}" width="{}" height="{}" rx="4" fill="{}" stroke="{}"/>"#,
+                mx - label_width / 2,
+                my - LINE_HEIGHT / 2 - 2,
+                label_width,
+                LINE_HEIGHT + 4,
+                NODE_FILL,
+                edge.color.hex(),
+            );
+            let _ = writeln!(
+                out,
+                r#"  <text x="{mx}" y="{my}" fill="{}" text-anchor="middle" dominant-baseline="central">{}</text>"#,
+                TEXT_COLOR,
+                escape_xml(label),
+            );
+        }
+    }
+
+    for node in graph.nodes.iter().filter(|n| !n.kind.is_container()) {
+        render_interface(&mut out, node);
+    }
+
+    let _ = writeln!(out, "</svg>");
+    out
+}
+
+fn render_subnet(out: &mut String, node: &ExportNode) {
+    let _ = writeln!(
+        out,
+        r#"  <rect x="{}" y="{}" width="{}" height="{}" rx="8" fill="{}" stroke="{}" {}/>"#,
+        node.x,
+        node.y,
+        node.width,
+        node.height,
+        SUBNET_FILL,
+        node.color.hex(),
+        stroke_attrs(node, "1.5"),
+    );
+    let _ = writeln!(
+        out,
+        r#"  <text x="{}" y="{}" fill="{}" font-weight="600">{}</text>"#,
+        node.x + 4,
+        node.y - 8,
+        node.color.hex(),
+        escape_xml(&node.label),
+    );
+}
+
+fn render_interface(out: &mut String, node: &ExportNode) {
+    let _ = writeln!(
+        out,
+        r#"  <rect x="{}" y="{}" width="{}" height="{}" rx="6" fill="{}" stroke="{}" {}/>"#,
+        node.x,
+        node.y,
+        node.width,
+        node.height,
+        NODE_FILL,
+        node.color.hex(),
+        stroke_attrs(node, "1"),
+    );
+
+    let max_chars = ((node.width - 2 * TEXT_PADDING) / CHAR_WIDTH).max(1) as usize;
+    let max_lines = ((node.height - TEXT_PADDING) / LINE_HEIGHT).max(1) as usize;
+    let x = node.x + TEXT_PADDING;
+    let mut y = node.y + TEXT_PADDING + FONT_SIZE;
+
+    let _ = writeln!(
+        out,
+        r#"  <text x="{x}" y="{y}" fill="{}" font-weight="600">{}</text>"#,
+        TEXT_COLOR,
+        escape_xml(&truncate(&node.label, max_chars)),
+    );
+
+    for detail in node.details.iter().take(max_lines.saturating_sub(1)) {
+        y += LINE_HEIGHT;
+        let _ = writeln!(
+            out,
+            r#"  <text x="{x}" y="{y}" fill="{}">{}</text>"#,
+            MUTED_TEXT_COLOR,
+            escape_xml(&truncate(detail, max_chars)),
+        );
+    }
+}
+
+/// Stroke width and dashing for a node's border. Changed nodes get a heavier border,
+/// and removed ones a dashed border.
+fn stroke_attrs(node: &ExportNode, width: &str) -> String {
+    match node.change {
+        None => format!(r#"stroke-width="{}""#, width),
+        Some(TopologyChangeType::Removed) => {
+            r#"stroke-width="3" stroke-dasharray="6 4""#.to_string()
+        }
+        Some(_) => r#"stroke-width="3""#.to_string(),
+    }
+}
+
+fn push_out(x: i64, y: i64, handle: EdgeHandle) -> (i64, i64) {
+    match handle {
+        EdgeHandle::Top => (x, y - CURVE_OFFSET),
+        EdgeHandle::Bottom => (x, y + CURVE_OFFSET),
+        EdgeHandle::Left => (x - CURVE_OFFSET, y),
+        EdgeHandle::Right => (x + CURVE_OFFSET, y),
+    }
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        text.to_string()
+    } else {
+        let kept: String = text.chars().take(max_chars.saturating_sub(1)).collect();
+        format!("{}…", kept)
+    }
+}
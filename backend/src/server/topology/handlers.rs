@@ -17,15 +17,19 @@ use crate::server::{
         },
     },
     topology::{
+        export::{TopologyExportFormat, TopologyExportQuery},
         service::main::BuildGraphParams,
         types::base::{
             SetEntitiesParams, Topology, TopologyEdgeHandleUpdate, TopologyMetadataUpdate,
             TopologyNodePositionUpdate, TopologyNodeResizeUpdate, TopologyRebuildRequest,
         },
     },
+    topology_snapshots::handlers::get_accessible_snapshot,
 };
 use axum::{
+    body::Body,
     extract::{Path, State},
+    http::{HeaderMap, HeaderValue, header},
     response::{
         Json, Sse,
         sse::{Event, KeepAlive},
@@ -56,6 +60,7 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
             generated::delete
         ))
         .routes(routes!(generated::export_csv))
+        .routes(routes!(export_topology))
         .routes(routes!(refresh))
         .routes(routes!(rebuild))
         .routes(routes!(update_node_position))
@@ -651,6 +656,88 @@ async fn update_metadata(
     Ok(Json(ApiResponse::success(())))
 }
 
+/// Export a topology diagram
+///
+/// Renders the stored layout server-side as SVG, GraphML, draw.io or Graphviz DOT.
+/// The topology is exported as last built; refresh or rebuild it first for current data.
+/// With `compare_to`, changes since that snapshot of the topology are highlighted.
+#[utoipa::path(
+    get,
+    path = "/{id}/export",
+    tags = [Topology::ENTITY_NAME_PLURAL],
+    params(("id" = Uuid, Path, description = "Topology ID"), TopologyExportQuery),
+    responses(
+        (status = 200, description = "Diagram file in the requested format", content(
+            (String = "image/svg+xml"),
+            (String = "application/graphml+xml"),
+            (String = "application/vnd.jgraph.mxfile"),
+            (String = "text/vnd.graphviz"),
+        )),
+        (status = 400, description = "Snapshot was taken of a different topology", body = ApiErrorResponse),
+        (status = 403, description = "Access denied", body = ApiErrorResponse),
+        (status = 404, description = "Topology or snapshot not found", body = ApiErrorResponse),
+    ),
+     security(("user_api_key" = []), ("session" = []))
+)]
+async fn export_topology(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Viewer>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<TopologyExportQuery>,
+) -> ApiResult<(HeaderMap, Body)> {
+    let service = Topology::get_service(&state);
+    let network_ids = auth.network_ids();
+
+    let topology = service
+        .get_by_id(&id)
+        .await?
+        .ok_or_else(|| ApiError::not_found(format!("Could not find topology {}", id)))?;
+
+    if !network_ids.contains(&topology.base.network_id) {
+        return Err(ApiError::forbidden(
+            "You don't have access to this topology",
+        ));
+    }
+
+    let previous = match query.compare_to {
+        Some(snapshot_id) => {
+            let snapshot = get_accessible_snapshot(&state, snapshot_id, &network_ids).await?;
+            if snapshot.base.topology_id != topology.id {
+                return Err(ApiError::bad_request(
+                    "Snapshot was taken of a different topology",
+                ));
+            }
+            Some(snapshot.to_topology())
+        }
+        None => None,
+    };
+
+    Ok(export_response(&topology, previous.as_ref(), query.format))
+}
+
+/// Build a file download response for a rendered topology, highlighting changes since
+/// `previous` when given
+pub fn export_response(
+    topology: &Topology,
+    previous: Option<&Topology>,
+    format: TopologyExportFormat,
+) -> (HeaderMap, Body) {
+    let filename = format.filename(topology);
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static(format.content_type()),
+    );
+    headers.insert(
+        header::CONTENT_DISPOSITION,
+        HeaderValue::from_str(&format!("attachment; filename=\"{}\"", filename))
+            .unwrap_or_else(|_| HeaderValue::from_static("attachment")),
+    );
+
+    (headers, Body::from(format.render(topology, previous)))
+}
+
 /// Lock a topology
 #[utoipa::path(
     post,
@@ -1,3 +1,5 @@
+pub mod diff;
+pub mod export;
 pub mod handlers;
 pub mod service;
 pub mod types;
@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+
+use mac_address::MacAddress;
 use uuid::Uuid;
 
 use crate::server::{
@@ -7,9 +10,7 @@ use crate::server::{
     if_entries::r#impl::base::IfEntry,
     interfaces::r#impl::base::Interface,
     ports::r#impl::base::Port,
-    services::r#impl::{
-        base::Service, definitions::ServiceDefinitionExt, virtualization::ServiceVirtualization,
-    },
+    services::r#impl::{base::Service, definitions::ServiceDefinitionExt},
     subnets::r#impl::base::Subnet,
     topology::types::{
         base::TopologyOptions,
@@ -103,7 +104,7 @@ impl<'a> TopologyContext<'a> {
                 return false;
             }
             if let Some(subnet) = self.get_subnet_by_id(interface.base.subnet_id) {
-                return !subnet.base.subnet_type.is_docker_bridge();
+                return !subnet.base.subnet_type.is_container_network();
             }
             false
         })
@@ -173,6 +174,38 @@ impl<'a> TopologyContext<'a> {
             .collect()
     }
 
+    /// Get all if_entries that learned MACs in the bridge FDB and have no LLDP/CDP
+    /// neighbor. Ports with a neighbor are uplinks/trunks, so their FDB holds every MAC
+    /// behind the neighboring device rather than directly attached hosts.
+    pub fn get_access_port_if_entries(&self) -> Vec<&'a IfEntry> {
+        self.if_entries
+            .iter()
+            .filter(|e| e.has_fdb_data() && !e.has_neighbor() && !e.has_neighbor_discovery_data())
+            .collect()
+    }
+
+    /// Map each MAC learned on an access port to the port that owns it.
+    /// When a MAC was learned on several ports, the port with the fewest learned MACs wins
+    /// since it is the one closest to the host.
+    pub fn get_fdb_mac_owners(&self) -> HashMap<MacAddress, &'a IfEntry> {
+        let mut mac_to_port: HashMap<MacAddress, &'a IfEntry> = HashMap::new();
+        for entry in self.get_access_port_if_entries() {
+            for mac in &entry.base.fdb_macs {
+                mac_to_port
+                    .entry(*mac)
+                    .and_modify(|current| {
+                        if (entry.base.fdb_macs.len(), entry.id)
+                            < (current.base.fdb_macs.len(), current.id)
+                        {
+                            *current = entry;
+                        }
+                    })
+                    .or_insert(entry);
+            }
+        }
+        mac_to_port
+    }
+
     // ============================================================================
     // Virtualization Relationship Methods
     // ============================================================================
@@ -192,13 +225,12 @@ impl<'a> TopologyContext<'a> {
 
     pub fn get_service_is_containerized_by(&self, service_id: &Uuid) -> Option<&Service> {
         if let Some(service) = self.get_service_by_id(*service_id)
-            && let Some(ServiceVirtualization::Docker(docker_virtualization)) =
-                &service.base.virtualization
+            && let Some(virtualization) = &service.base.virtualization
         {
             return self
                 .services
                 .iter()
-                .find(|s| s.id == docker_virtualization.service_id);
+                .find(|s| s.id == virtualization.service_id());
         }
         None
     }
@@ -210,6 +242,9 @@ impl<'a> TopologyContext<'a> {
             .map(|node| match node.node_type {
                 NodeType::InterfaceNode { subnet_id, .. } => subnet_id,
                 NodeType::SubnetNode { .. } => node.id,
+                NodeType::DeviceNode { site_id, .. } => site_id,
+                NodeType::SiteNode { .. } => node.id,
+                NodeType::ServiceNode { .. } => node.id,
             })
     }
 
@@ -1,15 +1,12 @@
 use itertools::Itertools;
 use petgraph::{Graph, graph::NodeIndex};
 use std::collections::{HashMap, HashSet};
-use strum::IntoDiscriminant;
 use uuid::Uuid;
 
 use crate::server::{
     groups::r#impl::{base::Group, types::GroupType},
     hosts::r#impl::virtualization::HostVirtualization,
     if_entries::r#impl::base::Neighbor,
-    services::r#impl::virtualization::ServiceVirtualization,
-    subnets::r#impl::types::{SubnetType, SubnetTypeDiscriminants},
     topology::{
         service::context::TopologyContext,
         types::{
@@ -72,11 +69,9 @@ impl EdgeBuilder {
             HashMap::new();
 
         ctx.services.iter().for_each(|s| {
-            if let Some(ServiceVirtualization::Docker(docker_virtualization)) =
-                &s.base.virtualization
-            {
+            if let Some(virtualization) = &s.base.virtualization {
                 let entry = docker_service_to_containerized_service_ids
-                    .entry(docker_virtualization.service_id)
+                    .entry(virtualization.service_id())
                     .or_default();
                 if !entry.contains(&s.id) {
                     entry.push(s.id);
@@ -104,7 +99,7 @@ impl EdgeBuilder {
                     .iter()
                     .filter_map(|i| ctx.get_subnet_by_id(i.base.subnet_id))
                     .filter_map(|s| {
-                        if s.base.subnet_type == SubnetType::DockerBridge {
+                        if s.base.subnet_type.is_container_network() {
                             return Some(s.id);
                         }
                         None
@@ -328,15 +323,13 @@ impl EdgeBuilder {
                             let target_subnet = ctx.get_subnet_by_id(interface.base.subnet_id);
 
                             if let Some(source_subnet) = source_subnet
-                                && source_subnet.base.subnet_type.discriminant()
-                                    == SubnetTypeDiscriminants::DockerBridge
+                                && source_subnet.base.subnet_type.is_container_network()
                             {
                                 return None;
                             }
 
                             if let Some(target_subnet) = target_subnet
-                                && target_subnet.base.subnet_type.discriminant()
-                                    == SubnetTypeDiscriminants::DockerBridge
+                                && target_subnet.base.subnet_type.is_container_network()
                             {
                                 return None;
                             }
@@ -453,6 +446,63 @@ impl EdgeBuilder {
             .collect()
     }
 
+    /// Create access-port edges from bridge FDB data
+    /// Connects a switch port to end hosts whose interface MAC was learned on that port.
+    pub fn create_access_port_edges(ctx: &TopologyContext) -> Vec<Edge> {
+        let mac_to_port = ctx.get_fdb_mac_owners();
+
+        // Avoid duplicate edges when a host has the same MAC on several interfaces
+        let mut processed_pairs: HashSet<(Uuid, Uuid)> = HashSet::new();
+
+        ctx.interfaces
+            .iter()
+            .filter_map(|interface| {
+                let mac = interface.base.mac_address?;
+                let port = *mac_to_port.get(&mac)?;
+
+                // The switch's own MACs show up in its FDB - skip them
+                if interface.base.host_id == port.base.host_id {
+                    return None;
+                }
+
+                if !processed_pairs.insert((port.id, interface.base.host_id)) {
+                    return None;
+                }
+
+                let source_interface_id = ctx.resolve_interface_for_if_entry(port)?;
+
+                if !ctx.interface_will_have_node(&source_interface_id)
+                    || !ctx.interface_will_have_node(&interface.id)
+                {
+                    return None;
+                }
+
+                let is_multi_hop = ctx.edge_is_multi_hop(&source_interface_id, &interface.id);
+
+                let (source_handle, target_handle) = EdgeBuilder::determine_interface_handles(
+                    ctx,
+                    &source_interface_id,
+                    &interface.id,
+                    is_multi_hop,
+                )?;
+
+                Some(Edge {
+                    id: Uuid::new_v4(),
+                    source: source_interface_id,
+                    target: interface.id,
+                    edge_type: EdgeType::AccessPort {
+                        if_entry_id: port.id,
+                        host_id: interface.base.host_id,
+                    },
+                    label: Some(port.display_name().to_string()),
+                    source_handle,
+                    target_handle,
+                    is_multi_hop,
+                })
+            })
+            .collect()
+    }
+
     /// Figure out handles for two interfaces
     pub fn determine_interface_handles(
         ctx: &TopologyContext,
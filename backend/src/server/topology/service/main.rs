@@ -10,10 +10,12 @@ use uuid::Uuid;
 use crate::server::{
     auth::middleware::auth::AuthenticatedEntity,
     bindings::{r#impl::base::Binding, service::BindingService},
+    custom_service_definitions::service::CustomServiceDefinitionService,
     groups::{r#impl::base::Group, service::GroupService},
     hosts::{r#impl::base::Host, service::HostService},
     if_entries::{r#impl::base::IfEntry, service::IfEntryService},
     interfaces::{r#impl::base::Interface, service::InterfaceService},
+    networks::service::NetworkService,
     ports::{r#impl::base::Port, service::PortService},
     services::{r#impl::base::Service, service::ServiceService},
     shared::{
@@ -32,12 +34,20 @@ use crate::server::{
     tags::{entity_tags::EntityTagService, r#impl::base::Tag, service::TagService},
     topology::{
         service::{
-            context::TopologyContext, edge_builder::EdgeBuilder,
-            optimizer::main::TopologyOptimizer,
-            planner::subnet_layout_planner::SubnetLayoutPlanner,
+            context::TopologyContext,
+            edge_builder::EdgeBuilder,
+            optimizer::{
+                dependency_positioner::DependencyPositioner, main::TopologyOptimizer,
+                physical_positioner::PhysicalPositioner,
+            },
+            planner::{
+                dependency_layout_planner::DependencyLayoutPlanner,
+                physical_layout_planner::PhysicalLayoutPlanner,
+                subnet_layout_planner::SubnetLayoutPlanner,
+            },
         },
         types::{
-            base::{SetEntitiesParams, Topology, TopologyOptions},
+            base::{SetEntitiesParams, Topology, TopologyOptions, TopologyView},
             edges::{Edge, EdgeHandle},
             nodes::Node,
         },
@@ -55,6 +65,8 @@ pub struct TopologyService {
     binding_service: Arc<BindingService>,
     if_entry_service: Arc<IfEntryService>,
     tag_service: Arc<TagService>,
+    network_service: Arc<NetworkService>,
+    custom_service_definition_service: Arc<CustomServiceDefinitionService>,
     event_bus: Arc<EventBus>,
     pub staleness_tx: broadcast::Sender<Topology>,
 }
@@ -184,6 +196,8 @@ impl TopologyService {
         binding_service: Arc<BindingService>,
         if_entry_service: Arc<IfEntryService>,
         tag_service: Arc<TagService>,
+        network_service: Arc<NetworkService>,
+        custom_service_definition_service: Arc<CustomServiceDefinitionService>,
         storage: Arc<GenericPostgresStorage<Topology>>,
         event_bus: Arc<EventBus>,
     ) -> Self {
@@ -199,6 +213,8 @@ impl TopologyService {
             binding_service,
             if_entry_service,
             tag_service,
+            network_service,
+            custom_service_definition_service,
             event_bus,
             staleness_tx,
         }
@@ -274,12 +290,21 @@ impl TopologyService {
         network_id: Uuid,
         options: &TopologyOptions,
     ) -> Result<Vec<Service>, Error> {
-        Ok(self
+        let mut services = self
             .service_service
             .get_all(StorableFilter::<Service>::new_from_network_ids(&[
                 network_id,
             ]))
-            .await?
+            .await?;
+
+        // Custom definitions are organization-scoped; stored services only carry their id
+        if let Some(network) = self.network_service.get_by_id(&network_id).await? {
+            self.custom_service_definition_service
+                .resolve_service_definitions(network.base.organization_id, &mut services)
+                .await?;
+        }
+
+        Ok(services
             .iter()
             .filter(|s| {
                 !options
@@ -347,40 +372,11 @@ impl TopologyService {
             hosts, interfaces, subnets, services, groups, ports, bindings, if_entries, options,
         );
 
-        // Create all edges (needed for anchor analysis)
-        let mut all_edges = Vec::new();
-
-        all_edges.extend(EdgeBuilder::create_interface_edges(&ctx));
-
-        all_edges.extend(EdgeBuilder::create_group_edges(&ctx));
-        all_edges.extend(EdgeBuilder::create_vm_host_edges(&ctx));
-        let (container_edges, docker_bridge_host_subnet_id_to_group_on) =
-            EdgeBuilder::create_containerized_service_edges(
-                &ctx,
-                options.request.group_docker_bridges_by_host,
-            );
-
-        all_edges.extend(container_edges);
-
-        // Create physical link edges from LLDP/CDP neighbor discovery
-        all_edges.extend(EdgeBuilder::create_physical_link_edges(&ctx));
-
-        // Create nodes with layout
-        let mut layout_planner = SubnetLayoutPlanner::new();
-        let (subnet_layouts, child_nodes) = layout_planner.create_subnet_child_nodes(
-            &ctx,
-            &mut all_edges,
-            options.request.group_docker_bridges_by_host,
-            docker_bridge_host_subnet_id_to_group_on,
-        );
-
-        let subnet_nodes = layout_planner.create_subnet_nodes(&ctx, &subnet_layouts);
-
-        // Optimize node positions and handle edge adjustments
-        let optimizer = TopologyOptimizer::new(&ctx);
-        let mut all_nodes: Vec<Node> = subnet_nodes.into_iter().chain(child_nodes).collect();
-
-        let optimized_edges = optimizer.optimize_graph(&mut all_nodes, &all_edges);
+        let (all_nodes, optimized_edges) = match options.request.view {
+            TopologyView::Subnet => self.build_subnet_layout(&ctx),
+            TopologyView::Physical => self.build_physical_layout(&ctx, old_nodes),
+            TopologyView::Dependency => self.build_dependency_layout(&ctx),
+        };
 
         // Build graph
         let mut graph: Graph<Node, Edge> = Graph::new();
@@ -460,4 +456,73 @@ impl TopologyService {
             graph.edge_weights().cloned().collect(),
         )
     }
+
+    /// Subnet view: interface nodes grouped into subnet nodes
+    fn build_subnet_layout(&self, ctx: &TopologyContext) -> (Vec<Node>, Vec<Edge>) {
+        // Create all edges (needed for anchor analysis)
+        let mut all_edges = Vec::new();
+
+        all_edges.extend(EdgeBuilder::create_interface_edges(ctx));
+
+        all_edges.extend(EdgeBuilder::create_group_edges(ctx));
+        all_edges.extend(EdgeBuilder::create_vm_host_edges(ctx));
+        let (container_edges, docker_bridge_host_subnet_id_to_group_on) =
+            EdgeBuilder::create_containerized_service_edges(
+                ctx,
+                ctx.options.request.group_docker_bridges_by_host,
+            );
+
+        all_edges.extend(container_edges);
+
+        // Create physical link edges from LLDP/CDP neighbor discovery
+        all_edges.extend(EdgeBuilder::create_physical_link_edges(ctx));
+
+        // Create access-port edges from bridge FDB (hosts without LLDP/CDP)
+        all_edges.extend(EdgeBuilder::create_access_port_edges(ctx));
+
+        // Create nodes with layout
+        let mut layout_planner = SubnetLayoutPlanner::new();
+        let (subnet_layouts, child_nodes) = layout_planner.create_subnet_child_nodes(
+            ctx,
+            &mut all_edges,
+            ctx.options.request.group_docker_bridges_by_host,
+            docker_bridge_host_subnet_id_to_group_on,
+        );
+
+        let subnet_nodes = layout_planner.create_subnet_nodes(ctx, &subnet_layouts);
+
+        // Optimize node positions and handle edge adjustments
+        let optimizer = TopologyOptimizer::new(ctx);
+        let mut all_nodes: Vec<Node> = subnet_nodes.into_iter().chain(child_nodes).collect();
+
+        let optimized_edges = optimizer.optimize_graph(&mut all_nodes, &all_edges);
+
+        (all_nodes, optimized_edges)
+    }
+
+    /// Physical view: devices grouped into sites, linked port to port
+    fn build_physical_layout(
+        &self,
+        ctx: &TopologyContext,
+        old_nodes: &[Node],
+    ) -> (Vec<Node>, Vec<Edge>) {
+        let planner = PhysicalLayoutPlanner::new();
+        let mut edges = planner.create_edges(ctx);
+        let (mut sites, mut nodes) = planner.create_nodes(ctx, &edges, old_nodes);
+
+        PhysicalPositioner::new().position(&mut sites, &mut nodes, &mut edges);
+
+        (nodes, edges)
+    }
+
+    /// Dependency view: service nodes in tiers, linked from each service to what it relies on
+    fn build_dependency_layout(&self, ctx: &TopologyContext) -> (Vec<Node>, Vec<Edge>) {
+        let planner = DependencyLayoutPlanner::new();
+        let mut edges = planner.create_edges(ctx);
+        let (mut tiers, mut nodes) = planner.create_nodes(ctx, &edges);
+
+        DependencyPositioner::new().position(&mut tiers, &mut nodes, &mut edges);
+
+        (nodes, edges)
+    }
 }
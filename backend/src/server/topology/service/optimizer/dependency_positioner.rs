@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::server::topology::{
+    service::optimizer::physical_positioner::PhysicalPositioner,
+    types::{
+        edges::{Edge, EdgeHandle},
+        layout::{Ixy, Uxy},
+        nodes::Node,
+    },
+};
+
+const GRID_SIZE: usize = 25;
+const SERVICE_WIDTH: usize = 250;
+const SERVICE_HEIGHT: usize = 100;
+const TIER_GAP: usize = 150;
+const SERVICE_GAP: usize = 50;
+
+/// Positions the dependency view planned by the dependency layout planner
+///
+/// Tiers are laid out as centered rows, from the services nothing depends on at the top
+/// down to the services everything else relies on:
+/// 1. Order services within each tier using the same barycenter sweeps as the physical view
+/// 2. Place tiers as centered rows of equally sized service nodes
+/// 3. Pick edge handles from the relative placement of both ends
+pub struct DependencyPositioner;
+
+impl Default for DependencyPositioner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DependencyPositioner {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn position(&self, tiers: &mut [Vec<Uuid>], nodes: &mut [Node], edges: &mut [Edge]) {
+        PhysicalPositioner::reduce_crossings(tiers, edges);
+
+        let widest = tiers.iter().map(Vec::len).max().unwrap_or(0);
+        let row_width =
+            |count: usize| count * SERVICE_WIDTH + count.saturating_sub(1) * SERVICE_GAP;
+
+        let mut slots: HashMap<Uuid, (usize, usize)> = HashMap::new();
+        let mut positions: HashMap<Uuid, Ixy> = HashMap::new();
+
+        for (tier, ids) in tiers.iter().enumerate() {
+            let offset = (row_width(widest) - row_width(ids.len())) / 2 / GRID_SIZE * GRID_SIZE;
+            let y = tier * (SERVICE_HEIGHT + TIER_GAP);
+
+            for (order, id) in ids.iter().enumerate() {
+                slots.insert(*id, (tier, order));
+                positions.insert(
+                    *id,
+                    Ixy {
+                        x: (offset + order * (SERVICE_WIDTH + SERVICE_GAP)) as isize,
+                        y: y as isize,
+                    },
+                );
+            }
+        }
+
+        for node in nodes.iter_mut() {
+            if let Some(position) = positions.get(&node.id) {
+                node.position = *position;
+                node.size = Uxy {
+                    x: SERVICE_WIDTH,
+                    y: SERVICE_HEIGHT,
+                };
+            }
+        }
+
+        for edge in edges.iter_mut() {
+            if let (Some(source), Some(target)) = (slots.get(&edge.source), slots.get(&edge.target))
+            {
+                (edge.source_handle, edge.target_handle) =
+                    Self::determine_handles(*source, *target);
+            }
+        }
+    }
+
+    /// Dependencies run downwards between tiers; edges within a tier, or back up a cycle,
+    /// leave from the side or top
+    fn determine_handles(
+        source: (usize, usize),
+        target: (usize, usize),
+    ) -> (EdgeHandle, EdgeHandle) {
+        let ((source_tier, source_order), (target_tier, target_order)) = (source, target);
+
+        match source_tier.cmp(&target_tier) {
+            std::cmp::Ordering::Less => (EdgeHandle::Bottom, EdgeHandle::Top),
+            std::cmp::Ordering::Greater => (EdgeHandle::Top, EdgeHandle::Bottom),
+            std::cmp::Ordering::Equal if source_order < target_order => {
+                (EdgeHandle::Right, EdgeHandle::Left)
+            }
+            std::cmp::Ordering::Equal => (EdgeHandle::Left, EdgeHandle::Right),
+        }
+    }
+}
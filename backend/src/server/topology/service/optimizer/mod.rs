@@ -1,4 +1,6 @@
 pub mod child_positioner;
+pub mod dependency_positioner;
 pub mod main;
+pub mod physical_positioner;
 pub mod subnet_positioner;
 pub mod utils;
@@ -0,0 +1,336 @@
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::server::topology::{
+    service::planner::physical_layout_planner::SiteLayout,
+    types::{
+        edges::{Edge, EdgeHandle, EdgeType},
+        layout::{Ixy, Uxy},
+        nodes::{DevicePort, Node, NodeType},
+    },
+};
+
+const GRID_SIZE: usize = 25;
+const DEVICE_MIN_WIDTH: usize = 200;
+const DEVICE_MIN_HEIGHT: usize = 100;
+/// Space taken by each port handle along a device's side
+const PORT_SPACING: usize = 25;
+/// Space kept clear at both ends of a side, so ports don't crowd the corners
+const PORT_MARGIN: usize = 25;
+const TIER_GAP: usize = 150;
+const DEVICE_GAP: usize = 50;
+const SITE_PADDING: usize = 50;
+const SITE_GAP: usize = 200;
+
+#[derive(Clone, Copy)]
+enum Sweep {
+    Down,
+    Up,
+}
+
+/// Where a device sits in the physical layout
+#[derive(Clone, Copy)]
+struct DeviceSlot {
+    site: usize,
+    tier: usize,
+    order: usize,
+}
+
+/// Positions the physical view planned by the physical layout planner
+///
+/// Each site is laid out as rows of tiers, with the site's core devices on top:
+/// 1. Order devices within each tier using barycenter sweeps (down, up, down) to reduce crossings
+/// 2. Pick edge handles from the relative placement of both ends
+/// 3. Size devices to fit their ports, then place tiers as centered rows and sites left to right
+/// 4. Order each device's ports along its sides by the position of the device they link to
+pub struct PhysicalPositioner;
+
+impl Default for PhysicalPositioner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhysicalPositioner {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn position(&self, sites: &mut [SiteLayout], nodes: &mut [Node], edges: &mut [Edge]) {
+        for site in sites.iter_mut() {
+            Self::reduce_crossings(&mut site.tiers, edges);
+        }
+
+        let slots: HashMap<Uuid, DeviceSlot> = sites
+            .iter()
+            .enumerate()
+            .flat_map(|(site, layout)| {
+                layout
+                    .tiers
+                    .iter()
+                    .enumerate()
+                    .flat_map(move |(tier, ids)| {
+                        ids.iter()
+                            .enumerate()
+                            .map(move |(order, id)| (*id, DeviceSlot { site, tier, order }))
+                    })
+            })
+            .collect();
+
+        for edge in edges.iter_mut() {
+            if let (Some(source), Some(target)) = (slots.get(&edge.source), slots.get(&edge.target))
+            {
+                (edge.source_handle, edge.target_handle) = Self::determine_handles(source, target);
+            }
+        }
+
+        let mut ports = Self::collect_ports(edges);
+        let node_index: HashMap<Uuid, usize> =
+            nodes.iter().enumerate().map(|(i, n)| (n.id, i)).collect();
+
+        for node in nodes.iter_mut() {
+            if matches!(node.node_type, NodeType::DeviceNode { .. }) {
+                node.size = Self::device_size(ports.get(&node.id).map_or(&[], Vec::as_slice));
+            }
+        }
+
+        Self::layout_sites(sites, nodes, &node_index);
+
+        // Absolute centers, now that sites and devices are placed
+        let centers: HashMap<Uuid, (isize, isize)> = nodes
+            .iter()
+            .filter_map(|n| match n.node_type {
+                NodeType::DeviceNode { site_id, .. } => {
+                    let site = &nodes[*node_index.get(&site_id)?];
+                    Some((
+                        n.id,
+                        (
+                            site.position.x + n.position.x + n.size.x as isize / 2,
+                            site.position.y + n.position.y + n.size.y as isize / 2,
+                        ),
+                    ))
+                }
+                _ => None,
+            })
+            .collect();
+
+        for node in nodes.iter_mut() {
+            if let NodeType::DeviceNode {
+                ports: node_ports, ..
+            } = &mut node.node_type
+                && let Some(mut device_ports) = ports.remove(&node.id)
+            {
+                device_ports.sort_by_key(|(port, partner)| {
+                    let (x, y) = centers.get(partner).copied().unwrap_or_default();
+                    let along = if port.side.is_vertical() { x } else { y };
+                    (port.side, along, port.if_entry_id)
+                });
+                *node_ports = device_ports.into_iter().map(|(port, _)| port).collect();
+            }
+        }
+    }
+
+    /// Reorder each tier by the mean position of its neighbors in the adjacent tier.
+    /// Only edges with both ends in `tiers` are considered.
+    pub fn reduce_crossings(tiers: &mut [Vec<Uuid>], edges: &[Edge]) {
+        let in_tiers: HashSet<Uuid> = tiers.iter().flatten().copied().collect();
+        let mut neighbors: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for edge in edges {
+            if in_tiers.contains(&edge.source) && in_tiers.contains(&edge.target) {
+                neighbors.entry(edge.source).or_default().push(edge.target);
+                neighbors.entry(edge.target).or_default().push(edge.source);
+            }
+        }
+
+        let tier_count = tiers.len();
+        for sweep in [Sweep::Down, Sweep::Up, Sweep::Down] {
+            let pairs: Vec<(usize, usize)> = match sweep {
+                Sweep::Down => (1..tier_count).map(|t| (t, t - 1)).collect(),
+                Sweep::Up => (0..tier_count.saturating_sub(1))
+                    .rev()
+                    .map(|t| (t, t + 1))
+                    .collect(),
+            };
+
+            for (tier, reference) in pairs {
+                let reference_order: HashMap<Uuid, usize> = tiers[reference]
+                    .iter()
+                    .enumerate()
+                    .map(|(i, id)| (*id, i))
+                    .collect();
+
+                let mut keyed: Vec<(f64, usize, Uuid)> = tiers[tier]
+                    .iter()
+                    .enumerate()
+                    .map(|(i, id)| {
+                        let positions: Vec<usize> = neighbors
+                            .get(id)
+                            .map(|n| {
+                                n.iter()
+                                    .filter_map(|n| reference_order.get(n))
+                                    .copied()
+                                    .collect()
+                            })
+                            .unwrap_or_default();
+                        // Devices with no neighbors in the reference tier keep their place
+                        let barycenter = if positions.is_empty() {
+                            i as f64
+                        } else {
+                            positions.iter().sum::<usize>() as f64 / positions.len() as f64
+                        };
+                        (barycenter, i, *id)
+                    })
+                    .collect();
+
+                keyed.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+                tiers[tier] = keyed.into_iter().map(|(_, _, id)| id).collect();
+            }
+        }
+    }
+
+    /// Links between tiers run vertically, links within a tier or between sites horizontally
+    fn determine_handles(source: &DeviceSlot, target: &DeviceSlot) -> (EdgeHandle, EdgeHandle) {
+        let source_is_first = if source.site != target.site {
+            source.site < target.site
+        } else if source.tier != target.tier {
+            return if source.tier < target.tier {
+                (EdgeHandle::Bottom, EdgeHandle::Top)
+            } else {
+                (EdgeHandle::Top, EdgeHandle::Bottom)
+            };
+        } else {
+            source.order < target.order
+        };
+
+        if source_is_first {
+            (EdgeHandle::Right, EdgeHandle::Left)
+        } else {
+            (EdgeHandle::Left, EdgeHandle::Right)
+        }
+    }
+
+    /// Ports used by each device, paired with the device on the other end of the link.
+    /// A port shared by several edges (an access port with several hosts) is listed once.
+    fn collect_ports(edges: &[Edge]) -> HashMap<Uuid, Vec<(DevicePort, Uuid)>> {
+        let mut ports: HashMap<Uuid, Vec<(DevicePort, Uuid)>> = HashMap::new();
+        let mut seen: HashSet<Uuid> = HashSet::new();
+
+        let mut add = |device: Uuid, if_entry_id: Uuid, side: EdgeHandle, partner: Uuid| {
+            if seen.insert(if_entry_id) {
+                ports
+                    .entry(device)
+                    .or_default()
+                    .push((DevicePort { if_entry_id, side }, partner));
+            }
+        };
+
+        for edge in edges {
+            match edge.edge_type {
+                EdgeType::PhysicalLink {
+                    source_if_entry_id,
+                    target_if_entry_id,
+                    ..
+                } => {
+                    add(
+                        edge.source,
+                        source_if_entry_id,
+                        edge.source_handle,
+                        edge.target,
+                    );
+                    add(
+                        edge.target,
+                        target_if_entry_id,
+                        edge.target_handle,
+                        edge.source,
+                    );
+                }
+                EdgeType::AccessPort { if_entry_id, .. } => {
+                    add(edge.source, if_entry_id, edge.source_handle, edge.target);
+                }
+                _ => {}
+            }
+        }
+
+        ports
+    }
+
+    /// Grow a device past its minimum size when a side has too many ports to fit
+    fn device_size(ports: &[(DevicePort, Uuid)]) -> Uxy {
+        let count = |side: EdgeHandle| ports.iter().filter(|(p, _)| p.side == side).count();
+        let horizontal = count(EdgeHandle::Top).max(count(EdgeHandle::Bottom));
+        let vertical = count(EdgeHandle::Left).max(count(EdgeHandle::Right));
+
+        Uxy {
+            x: DEVICE_MIN_WIDTH.max(horizontal * PORT_SPACING + 2 * PORT_MARGIN),
+            y: DEVICE_MIN_HEIGHT.max(vertical * PORT_SPACING + 2 * PORT_MARGIN),
+        }
+    }
+
+    /// Place tiers as centered rows inside each site, and sites left to right.
+    /// Device positions are relative to their site node.
+    fn layout_sites(sites: &[SiteLayout], nodes: &mut [Node], node_index: &HashMap<Uuid, usize>) {
+        let mut site_x = 0;
+
+        for site in sites {
+            let Some(&site_index) = node_index.get(&site.site_id) else {
+                continue;
+            };
+
+            let size_of = |id: &Uuid| {
+                node_index
+                    .get(id)
+                    .map(|&i| nodes[i].size)
+                    .unwrap_or_default()
+            };
+
+            let row_widths: Vec<usize> = site
+                .tiers
+                .iter()
+                .map(|tier| {
+                    tier.iter().map(|id| size_of(id).x).sum::<usize>()
+                        + DEVICE_GAP * tier.len().saturating_sub(1)
+                })
+                .collect();
+            let inner_width = row_widths.iter().copied().max().unwrap_or(0);
+
+            let mut y = SITE_PADDING;
+            for (tier, row_width) in site.tiers.iter().zip(&row_widths) {
+                let mut x = SITE_PADDING + (inner_width - row_width) / 2 / GRID_SIZE * GRID_SIZE;
+                let mut row_height = 0;
+
+                for id in tier {
+                    let Some(&i) = node_index.get(id) else {
+                        continue;
+                    };
+                    nodes[i].position = Ixy {
+                        x: x as isize,
+                        y: y as isize,
+                    };
+                    x += nodes[i].size.x + DEVICE_GAP;
+                    row_height = row_height.max(nodes[i].size.y);
+                }
+
+                y += row_height + TIER_GAP;
+            }
+
+            let height = if site.tiers.is_empty() {
+                2 * SITE_PADDING
+            } else {
+                y - TIER_GAP + SITE_PADDING
+            };
+            let width = inner_width + 2 * SITE_PADDING;
+
+            let site_node = &mut nodes[site_index];
+            site_node.position = Ixy {
+                x: site_x as isize,
+                y: 0,
+            };
+            site_node.size = Uxy {
+                x: width,
+                y: height,
+            };
+
+            site_x += width + SITE_GAP;
+        }
+    }
+}
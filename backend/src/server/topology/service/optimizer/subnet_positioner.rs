@@ -276,7 +276,9 @@ impl<'a> SubnetPositioner<'a> {
                             // Calculate the absolute X position of the other node's handle
                             // Check if other_node is a SubnetNode or InterfaceNode
                             let other_handle_absolute_x = match &other_node.node_type {
-                                NodeType::SubnetNode { .. } => {
+                                NodeType::SubnetNode { .. }
+                                | NodeType::SiteNode { .. }
+                                | NodeType::ServiceNode { .. } => {
                                     // SubnetNode: position is already absolute, no parent offset
                                     match other_handle {
                                         crate::server::topology::types::edges::EdgeHandle::Left => {
@@ -291,7 +293,7 @@ impl<'a> SubnetPositioner<'a> {
                                         }
                                     }
                                 }
-                                NodeType::InterfaceNode { .. } => {
+                                NodeType::InterfaceNode { .. } | NodeType::DeviceNode { .. } => {
                                     // InterfaceNode: position is relative to parent subnet
                                     match other_handle {
                                         crate::server::topology::types::edges::EdgeHandle::Left => {
@@ -311,7 +313,9 @@ impl<'a> SubnetPositioner<'a> {
                             // Calculate what our subnet.x should be to align our node's handle
                             // Check if my_node is a SubnetNode or InterfaceNode
                             let desired_subnet_x = match &my_node.node_type {
-                                NodeType::SubnetNode { .. } => {
+                                NodeType::SubnetNode { .. }
+                                | NodeType::SiteNode { .. }
+                                | NodeType::ServiceNode { .. } => {
                                     // SubnetNode: we ARE the subnet, just align our center
                                     match my_handle {
                                         crate::server::topology::types::edges::EdgeHandle::Left => {
@@ -326,7 +330,7 @@ impl<'a> SubnetPositioner<'a> {
                                         }
                                     }
                                 }
-                                NodeType::InterfaceNode { .. } => {
+                                NodeType::InterfaceNode { .. } | NodeType::DeviceNode { .. } => {
                                     // InterfaceNode: we need to account for position within parent subnet
                                     let my_half_width = my_node.size.x as isize / 2;
                                     match my_handle {
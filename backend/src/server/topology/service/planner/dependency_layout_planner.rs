@@ -0,0 +1,394 @@
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
+use uuid::Uuid;
+
+use crate::server::{
+    groups::r#impl::{base::Group, types::GroupType},
+    services::r#impl::{base::Service, proxy::ProxyTarget},
+    topology::{
+        service::context::TopologyContext,
+        types::{
+            edges::{Edge, EdgeHandle, EdgeType},
+            layout::{Ixy, Uxy},
+            nodes::{Node, NodeType},
+        },
+    },
+};
+
+/// Plans the dependency view: one node per service, linked by directed edges that point
+/// from a service to the services it relies on
+///
+/// Edges come from groups (consecutive bindings of a request path, hub to spokes) and from
+/// the backends reverse proxies forward to. Services without any dependency in either
+/// direction are left out. Node IDs are service IDs.
+pub struct DependencyLayoutPlanner;
+
+impl Default for DependencyLayoutPlanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DependencyLayoutPlanner {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Create service-to-service edges from groups and reverse proxy targets
+    pub fn create_edges(&self, ctx: &TopologyContext) -> Vec<Edge> {
+        let mut edges: Vec<Edge> = ctx
+            .groups
+            .iter()
+            .flat_map(|group| self.group_edges(ctx, group))
+            .collect();
+
+        edges.extend(self.proxy_edges(ctx));
+        edges
+    }
+
+    /// Create service nodes for every service with an edge, and split them into tiers so
+    /// each service sits below everything that depends on it.
+    /// Positions and sizes are left for the dependency positioner.
+    pub fn create_nodes(
+        &self,
+        ctx: &TopologyContext,
+        edges: &[Edge],
+    ) -> (Vec<Vec<Uuid>>, Vec<Node>) {
+        let linked: HashSet<Uuid> = edges.iter().flat_map(|e| [e.source, e.target]).collect();
+
+        let mut services: Vec<&Service> = ctx
+            .services
+            .iter()
+            .filter(|s| linked.contains(&s.id))
+            .collect();
+        services.sort_by(|a, b| a.base.name.cmp(&b.base.name).then(a.id.cmp(&b.id)));
+
+        let nodes = services
+            .iter()
+            .map(|service| Node {
+                id: service.id,
+                node_type: NodeType::ServiceNode {
+                    service_id: service.id,
+                    host_id: service.base.host_id,
+                    subnet_ids: Self::subnet_ids(ctx, service),
+                },
+                position: Ixy::default(),
+                size: Uxy::default(),
+                header: None,
+            })
+            .collect();
+
+        let ids: Vec<Uuid> = services.iter().map(|s| s.id).collect();
+        (self.plan_tiers(&ids, edges), nodes)
+    }
+
+    /// Edges between the services of a group's bindings, in the direction requests flow
+    fn group_edges(&self, ctx: &TopologyContext, group: &Group) -> Vec<Edge> {
+        let binding_ids = &group.base.binding_ids;
+        let pairs: Vec<(Uuid, Uuid)> = match group.base.group_type {
+            GroupType::RequestPath => binding_ids.windows(2).map(|w| (w[0], w[1])).collect(),
+            GroupType::HubAndSpoke => match binding_ids.split_first() {
+                Some((hub, spokes)) => spokes.iter().map(|spoke| (*hub, *spoke)).collect(),
+                None => Vec::new(),
+            },
+        };
+
+        let service_for_binding = |binding_id: Uuid| {
+            ctx.services
+                .iter()
+                .find(|s| s.get_binding(binding_id).is_some())
+                .map(|s| s.id)
+        };
+
+        pairs
+            .into_iter()
+            .filter_map(|(source_binding_id, target_binding_id)| {
+                let source = service_for_binding(source_binding_id)?;
+                let target = service_for_binding(target_binding_id)?;
+                if source == target {
+                    return None;
+                }
+
+                Some(Edge {
+                    id: Uuid::new_v4(),
+                    source,
+                    target,
+                    edge_type: match group.base.group_type {
+                        GroupType::RequestPath => EdgeType::RequestPath {
+                            group_id: group.id,
+                            source_binding_id,
+                            target_binding_id,
+                        },
+                        GroupType::HubAndSpoke => EdgeType::HubAndSpoke {
+                            group_id: group.id,
+                            source_binding_id,
+                            target_binding_id,
+                        },
+                    },
+                    label: Some(group.base.name.clone()),
+                    source_handle: EdgeHandle::Bottom,
+                    target_handle: EdgeHandle::Top,
+                    is_multi_hop: false,
+                })
+            })
+            .collect()
+    }
+
+    /// One edge per proxy and backend, listing every route between them
+    fn proxy_edges(&self, ctx: &TopologyContext) -> Vec<Edge> {
+        let mut edges = Vec::new();
+
+        for proxy in ctx
+            .services
+            .iter()
+            .filter(|s| !s.base.proxy_targets.is_empty())
+        {
+            let mut routes_by_backend: Vec<(Uuid, Vec<String>)> = Vec::new();
+
+            for target in &proxy.base.proxy_targets {
+                let Some(backend) = self.resolve_proxy_target(ctx, proxy, target) else {
+                    tracing::trace!(
+                        proxy = %proxy.base.name,
+                        address = %target.address,
+                        "Proxy target did not resolve to a service"
+                    );
+                    continue;
+                };
+
+                let index = match routes_by_backend.iter().position(|(id, _)| *id == backend) {
+                    Some(index) => index,
+                    None => {
+                        routes_by_backend.push((backend, Vec::new()));
+                        routes_by_backend.len() - 1
+                    }
+                };
+                let routes = &mut routes_by_backend[index].1;
+                if let Some(route) = &target.route
+                    && !routes.contains(route)
+                {
+                    routes.push(route.clone());
+                }
+            }
+
+            for (backend, routes) in routes_by_backend {
+                edges.push(Edge {
+                    id: Uuid::new_v4(),
+                    source: proxy.id,
+                    target: backend,
+                    label: (!routes.is_empty()).then(|| routes.join(", ")),
+                    edge_type: EdgeType::ProxyRoute { routes },
+                    source_handle: EdgeHandle::Bottom,
+                    target_handle: EdgeHandle::Top,
+                    is_multi_hop: false,
+                });
+            }
+        }
+
+        edges
+    }
+
+    /// Service a proxy target points at. The address host is matched against interface IPs,
+    /// container names and host names, then the port against the candidates' port bindings.
+    /// Without a port, a single candidate service is taken as the backend.
+    fn resolve_proxy_target(
+        &self,
+        ctx: &TopologyContext,
+        proxy: &Service,
+        target: &ProxyTarget,
+    ) -> Option<Uuid> {
+        let (host, port) = target.host_port()?;
+
+        let candidates: Vec<&Service> = match host.parse::<IpAddr>() {
+            Ok(ip) if ip.is_loopback() => ctx.get_services_for_host(proxy.base.host_id),
+            Ok(ip) => {
+                let host_ids: HashSet<Uuid> = ctx
+                    .interfaces
+                    .iter()
+                    .filter(|i| i.base.ip_address == ip)
+                    .map(|i| i.base.host_id)
+                    .collect();
+                ctx.services
+                    .iter()
+                    .filter(|s| host_ids.contains(&s.base.host_id))
+                    .collect()
+            }
+            Err(_) if host.eq_ignore_ascii_case("localhost") => {
+                ctx.get_services_for_host(proxy.base.host_id)
+            }
+            Err(_) => {
+                // Container names resolve on the proxy's Docker network, so they win over host names
+                let containers: Vec<&Service> = ctx
+                    .services
+                    .iter()
+                    .filter(|s| {
+                        s.base
+                            .virtualization
+                            .as_ref()
+                            .and_then(|v| v.workload_name())
+                            .is_some_and(|name| name.eq_ignore_ascii_case(host))
+                    })
+                    .collect();
+
+                if containers.is_empty() {
+                    let host_ids: HashSet<Uuid> =
+                        ctx.hosts
+                            .iter()
+                            .filter(|h| {
+                                h.base.name.eq_ignore_ascii_case(host)
+                                    || h.base.hostname.as_deref().is_some_and(|hostname| {
+                                        hostname.eq_ignore_ascii_case(host)
+                                            || hostname.split('.').next().is_some_and(|short| {
+                                                short.eq_ignore_ascii_case(host)
+                                            })
+                                    })
+                            })
+                            .map(|h| h.id)
+                            .collect();
+                    ctx.services
+                        .iter()
+                        .filter(|s| host_ids.contains(&s.base.host_id))
+                        .collect()
+                } else {
+                    containers
+                }
+            }
+        };
+
+        let candidates: Vec<&Service> = candidates
+            .into_iter()
+            .filter(|s| s.id != proxy.id)
+            .collect();
+
+        match port {
+            Some(port) => {
+                let port_numbers: HashMap<Uuid, u16> = ctx
+                    .ports
+                    .iter()
+                    .map(|p| (p.id, p.base.port_type.number()))
+                    .collect();
+
+                candidates
+                    .iter()
+                    .find(|s| {
+                        s.base.bindings.iter().any(|b| {
+                            b.port_id()
+                                .and_then(|id| port_numbers.get(&id))
+                                .is_some_and(|number| *number == port)
+                        })
+                    })
+                    .or_else(|| {
+                        // Container targets often use the internal port, which isn't always bound
+                        (candidates.len() == 1 && candidates[0].base.virtualization.is_some())
+                            .then(|| &candidates[0])
+                    })
+                    .map(|s| s.id)
+            }
+            None => (candidates.len() == 1).then(|| candidates[0].id),
+        }
+    }
+
+    /// Subnets a service is reachable in, from its bindings. Bindings to all interfaces
+    /// count every subnet the host is in.
+    fn subnet_ids(ctx: &TopologyContext, service: &Service) -> Vec<Uuid> {
+        let mut subnet_ids: Vec<Uuid> = service
+            .base
+            .bindings
+            .iter()
+            .flat_map(|binding| match binding.interface_id() {
+                Some(interface_id) => ctx
+                    .get_interface_by_id(Some(interface_id))
+                    .into_iter()
+                    .collect(),
+                None => ctx.get_interfaces_for_host(service.base.host_id),
+            })
+            .map(|interface| interface.base.subnet_id)
+            .collect();
+
+        subnet_ids.sort();
+        subnet_ids.dedup();
+        subnet_ids
+    }
+
+    /// Longest-path layering: services that nothing depends on form the top tier, and each
+    /// service sits one tier below the lowest service depending on it. Cycles are broken at
+    /// the service with the fewest dependents left to place.
+    fn plan_tiers(&self, ids: &[Uuid], edges: &[Edge]) -> Vec<Vec<Uuid>> {
+        let mut dependents: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        let mut dependencies: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for edge in edges {
+            dependents.entry(edge.target).or_default().push(edge.source);
+            dependencies
+                .entry(edge.source)
+                .or_default()
+                .push(edge.target);
+        }
+
+        let mut remaining: HashMap<Uuid, usize> = ids
+            .iter()
+            .map(|id| {
+                let count = dependents.get(id).map_or(0, |d| {
+                    d.iter().filter(|s| *s != id).collect::<HashSet<_>>().len()
+                });
+                (*id, count)
+            })
+            .collect();
+
+        let mut tier_of: HashMap<Uuid, usize> = HashMap::new();
+        // IDs arrive sorted by service name, which keeps tiers in a stable order
+        let mut ready: Vec<Uuid> = ids
+            .iter()
+            .filter(|id| remaining[*id] == 0)
+            .copied()
+            .collect();
+
+        while tier_of.len() < ids.len() {
+            if ready.is_empty() {
+                let Some(next) = ids
+                    .iter()
+                    .filter(|id| !tier_of.contains_key(*id))
+                    .min_by_key(|id| remaining[*id])
+                else {
+                    break;
+                };
+                ready.push(*next);
+            }
+
+            for id in std::mem::take(&mut ready) {
+                if tier_of.contains_key(&id) {
+                    continue;
+                }
+
+                let tier = dependents
+                    .get(&id)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|d| tier_of.get(d))
+                    .max()
+                    .map_or(0, |t| t + 1);
+                tier_of.insert(id, tier);
+
+                let mut unlocked: HashSet<Uuid> = HashSet::new();
+                for dependency in dependencies.get(&id).into_iter().flatten() {
+                    if tier_of.contains_key(dependency) || !unlocked.insert(*dependency) {
+                        continue;
+                    }
+                    if let Some(count) = remaining.get_mut(dependency) {
+                        *count = count.saturating_sub(1);
+                        if *count == 0 {
+                            ready.push(*dependency);
+                        }
+                    }
+                }
+            }
+        }
+
+        let tier_count = tier_of.values().max().map_or(0, |t| t + 1);
+        let mut tiers = vec![Vec::new(); tier_count];
+        for id in ids {
+            if let Some(tier) = tier_of.get(id) {
+                tiers[*tier].push(*id);
+            }
+        }
+        tiers
+    }
+}
@@ -1,4 +1,6 @@
 pub mod anchor_planner;
 pub mod child_planner;
+pub mod dependency_layout_planner;
+pub mod physical_layout_planner;
 pub mod subnet_layout_planner;
 pub mod utils;
@@ -0,0 +1,330 @@
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use uuid::Uuid;
+
+use crate::server::{
+    hosts::r#impl::{base::Host, hardware::HardwareComponentClass},
+    if_entries::r#impl::base::Neighbor,
+    topology::{
+        service::context::TopologyContext,
+        types::{
+            edges::{DiscoveryProtocol, Edge, EdgeHandle, EdgeType},
+            layout::{Ixy, Uxy},
+            nodes::{Node, NodeType},
+        },
+    },
+};
+
+/// Devices of one site, split into tiers by hop distance from the site's core devices
+#[derive(Debug, Clone)]
+pub struct SiteLayout {
+    pub site_id: Uuid,
+    pub tiers: Vec<Vec<Uuid>>,
+}
+
+/// Plans the physical (layer 2) view: one node per device, grouped into sites
+///
+/// Devices are hosts with an SNMP interface table, plus any host attached to one through
+/// a physical link or a bridge FDB entry. Node IDs are host IDs, so edges connect hosts
+/// and the ports on either end are carried by the edge type.
+pub struct PhysicalLayoutPlanner;
+
+impl Default for PhysicalLayoutPlanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhysicalLayoutPlanner {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Create host-to-host edges from LLDP/CDP neighbors and bridge FDB attachments
+    pub fn create_edges(&self, ctx: &TopologyContext) -> Vec<Edge> {
+        let host_ids: HashSet<Uuid> = ctx.hosts.iter().map(|h| h.id).collect();
+        let mut edges = Vec::new();
+
+        // Track processed pairs to avoid duplicate edges (A→B and B→A)
+        let mut processed_links: HashSet<(Uuid, Uuid)> = HashSet::new();
+
+        for source_entry in ctx.get_if_entries_with_neighbor() {
+            let Some(Neighbor::IfEntry(target_if_entry_id)) = source_entry.base.neighbor else {
+                continue;
+            };
+
+            let pair_key = if source_entry.id < target_if_entry_id {
+                (source_entry.id, target_if_entry_id)
+            } else {
+                (target_if_entry_id, source_entry.id)
+            };
+            if !processed_links.insert(pair_key) {
+                continue;
+            }
+
+            let Some(target_entry) = ctx.get_if_entry_by_id(target_if_entry_id) else {
+                continue;
+            };
+
+            let (source, target) = (source_entry.base.host_id, target_entry.base.host_id);
+            if source == target || !host_ids.contains(&source) || !host_ids.contains(&target) {
+                continue;
+            }
+
+            edges.push(Edge {
+                id: Uuid::new_v4(),
+                source,
+                target,
+                edge_type: EdgeType::PhysicalLink {
+                    source_if_entry_id: source_entry.id,
+                    target_if_entry_id: target_entry.id,
+                    protocol: DiscoveryProtocol::LLDP, // TODO: Support CDP when implemented
+                },
+                label: Some(format!(
+                    "{} ↔ {}",
+                    source_entry.display_name(),
+                    target_entry.display_name()
+                )),
+                source_handle: EdgeHandle::Bottom,
+                target_handle: EdgeHandle::Top,
+                is_multi_hop: false,
+            });
+        }
+
+        let mac_to_port = ctx.get_fdb_mac_owners();
+
+        // Avoid duplicate edges when a host has the same MAC on several interfaces
+        let mut processed_attachments: HashSet<(Uuid, Uuid)> = HashSet::new();
+
+        for interface in ctx.interfaces {
+            let Some(port) = interface
+                .base
+                .mac_address
+                .and_then(|mac| mac_to_port.get(&mac).copied())
+            else {
+                continue;
+            };
+
+            let (source, target) = (port.base.host_id, interface.base.host_id);
+
+            // The switch's own MACs show up in its FDB - skip them
+            if source == target || !host_ids.contains(&source) || !host_ids.contains(&target) {
+                continue;
+            }
+
+            if !processed_attachments.insert((port.id, target)) {
+                continue;
+            }
+
+            edges.push(Edge {
+                id: Uuid::new_v4(),
+                source,
+                target,
+                edge_type: EdgeType::AccessPort {
+                    if_entry_id: port.id,
+                    host_id: target,
+                },
+                label: Some(port.display_name().to_string()),
+                source_handle: EdgeHandle::Bottom,
+                target_handle: EdgeHandle::Top,
+                is_multi_hop: false,
+            });
+        }
+
+        edges
+    }
+
+    /// Create site and device nodes, and split each site's devices into tiers.
+    /// Positions, sizes and ports are left for the physical positioner.
+    pub fn create_nodes(
+        &self,
+        ctx: &TopologyContext,
+        edges: &[Edge],
+        old_nodes: &[Node],
+    ) -> (Vec<SiteLayout>, Vec<Node>) {
+        let mut device_ids: HashSet<Uuid> = ctx.if_entries.iter().map(|e| e.base.host_id).collect();
+        for edge in edges {
+            device_ids.insert(edge.source);
+            device_ids.insert(edge.target);
+        }
+
+        let mut devices: Vec<&Host> = ctx
+            .hosts
+            .iter()
+            .filter(|h| device_ids.contains(&h.id))
+            .collect();
+        devices.sort_by(|a, b| a.base.name.cmp(&b.base.name).then(a.id.cmp(&b.id)));
+
+        let mut neighbors: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for edge in edges {
+            neighbors.entry(edge.source).or_default().push(edge.target);
+            neighbors.entry(edge.target).or_default().push(edge.source);
+        }
+
+        let sites = self.assign_sites(&devices, &neighbors);
+
+        // Keep site node IDs stable across rebuilds so the UI can keep its selection
+        let old_site_ids: HashMap<Option<&str>, Uuid> = old_nodes
+            .iter()
+            .filter_map(|n| match &n.node_type {
+                NodeType::SiteNode { site } => Some((site.as_deref(), n.id)),
+                _ => None,
+            })
+            .collect();
+
+        // Named sites in alphabetical order, devices without a location last
+        let mut devices_by_site: BTreeMap<(bool, Option<String>), Vec<&Host>> = BTreeMap::new();
+        for device in &devices {
+            let site = sites.get(&device.id).cloned().flatten();
+            devices_by_site
+                .entry((site.is_none(), site))
+                .or_default()
+                .push(device);
+        }
+
+        let mut site_layouts = Vec::new();
+        let mut nodes = Vec::new();
+
+        for ((_, site), site_devices) in devices_by_site {
+            let site_id = old_site_ids
+                .get(&site.as_deref())
+                .copied()
+                .unwrap_or_else(Uuid::new_v4);
+
+            nodes.push(Node {
+                id: site_id,
+                node_type: NodeType::SiteNode { site },
+                position: Ixy::default(),
+                size: Uxy::default(),
+                header: None,
+            });
+
+            for device in &site_devices {
+                nodes.push(Node {
+                    id: device.id,
+                    node_type: NodeType::DeviceNode {
+                        site_id,
+                        host_id: device.id,
+                        stack_size: Self::stack_size(device),
+                        ports: Vec::new(),
+                    },
+                    position: Ixy::default(),
+                    size: Uxy::default(),
+                    header: None,
+                });
+            }
+
+            site_layouts.push(SiteLayout {
+                site_id,
+                tiers: self.plan_tiers(&site_devices, &neighbors),
+            });
+        }
+
+        (site_layouts, nodes)
+    }
+
+    /// Site of each device, from its SNMP sysLocation. Devices without one, such as
+    /// end hosts behind an access port, take the site of a linked device.
+    fn assign_sites(
+        &self,
+        devices: &[&Host],
+        neighbors: &HashMap<Uuid, Vec<Uuid>>,
+    ) -> HashMap<Uuid, Option<String>> {
+        let mut sites: HashMap<Uuid, Option<String>> = devices
+            .iter()
+            .map(|d| {
+                let site = d
+                    .base
+                    .sys_location
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(str::to_string);
+                (d.id, site)
+            })
+            .collect();
+
+        // Spread sites one hop per pass, so devices inherit from the closest located device
+        loop {
+            let inherited: Vec<(Uuid, String)> = devices
+                .iter()
+                .filter(|d| sites.get(&d.id).is_some_and(Option::is_none))
+                .filter_map(|d| {
+                    neighbors
+                        .get(&d.id)?
+                        .iter()
+                        .filter_map(|n| sites.get(n).cloned().flatten())
+                        .min()
+                        .map(|site| (d.id, site))
+                })
+                .collect();
+
+            if inherited.is_empty() {
+                break;
+            }
+
+            for (id, site) in inherited {
+                sites.insert(id, Some(site));
+            }
+        }
+
+        sites
+    }
+
+    /// Split a site's devices into tiers by breadth-first search over links within the site.
+    /// Each connected group starts from its best connected device, so core switches sit on top.
+    fn plan_tiers(
+        &self,
+        site_devices: &[&Host],
+        neighbors: &HashMap<Uuid, Vec<Uuid>>,
+    ) -> Vec<Vec<Uuid>> {
+        let in_site: HashSet<Uuid> = site_devices.iter().map(|d| d.id).collect();
+        let site_neighbors = |id: &Uuid| -> Vec<Uuid> {
+            let mut ids: Vec<Uuid> = neighbors
+                .get(id)
+                .map(|n| n.iter().filter(|n| in_site.contains(n)).copied().collect())
+                .unwrap_or_default();
+            ids.sort();
+            ids.dedup();
+            ids
+        };
+
+        // Devices arrive sorted by name, so a stable sort breaks degree ties by name
+        let mut roots: Vec<Uuid> = site_devices.iter().map(|d| d.id).collect();
+        roots.sort_by_key(|id| std::cmp::Reverse(site_neighbors(id).len()));
+
+        let mut tiers: Vec<Vec<Uuid>> = Vec::new();
+        let mut visited: HashSet<Uuid> = HashSet::new();
+
+        for root in roots {
+            if !visited.insert(root) {
+                continue;
+            }
+
+            let mut queue = VecDeque::from([(root, 0)]);
+            while let Some((id, depth)) = queue.pop_front() {
+                if tiers.len() <= depth {
+                    tiers.push(Vec::new());
+                }
+                tiers[depth].push(id);
+
+                for neighbor in site_neighbors(&id) {
+                    if visited.insert(neighbor) {
+                        queue.push_back((neighbor, depth + 1));
+                    }
+                }
+            }
+        }
+
+        tiers
+    }
+
+    /// Number of stack members, counted from chassis in the hardware inventory
+    fn stack_size(host: &Host) -> usize {
+        host.base
+            .hardware_inventory
+            .iter()
+            .filter(|c| c.class == HardwareComponentClass::Chassis)
+            .count()
+            .max(1)
+    }
+}
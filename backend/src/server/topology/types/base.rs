@@ -238,8 +238,24 @@ impl Default for TopologyLocalOptions {
     }
 }
 
+/// Layout a topology is built with
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, ToSchema)]
+pub enum TopologyView {
+    /// IP view: subnets containing the host interfaces attached to them
+    #[default]
+    Subnet,
+    /// Cabling view: devices grouped by site, connected by LLDP/CDP links and FDB attachments
+    Physical,
+    /// Service view: services connected by directed edges from each service to the ones it
+    /// relies on, taken from groups and reverse proxy targets
+    Dependency,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, ToSchema)]
 pub struct TopologyRequestOptions {
+    #[serde(default)]
+    #[schema(required)]
+    pub view: TopologyView,
     pub group_docker_bridges_by_host: bool,
     pub hide_vm_title_on_docker_container: bool,
     pub hide_ports: bool,
@@ -251,6 +267,7 @@ pub struct TopologyRequestOptions {
 impl Default for TopologyRequestOptions {
     fn default() -> Self {
         Self {
+            view: TopologyView::default(),
             group_docker_bridges_by_host: true,
             hide_vm_title_on_docker_container: false,
             hide_ports: false,
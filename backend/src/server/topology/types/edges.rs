@@ -298,6 +298,16 @@ pub enum EdgeType {
         target_if_entry_id: Uuid,
         protocol: DiscoveryProtocol,
     },
+    /// End host attached to a switch port, learned from the bridge forwarding table
+    AccessPort {
+        if_entry_id: Uuid,
+        host_id: Uuid,
+    },
+    /// Reverse proxy forwarding to a backend service, read from the proxy's routes
+    ProxyRoute {
+        /// Proxy routes or services that forward to the backend
+        routes: Vec<String>,
+    },
 }
 
 impl HasId for EdgeType {
@@ -315,6 +325,8 @@ impl EntityMetadataProvider for EdgeType {
             EdgeType::HostVirtualization { .. } => Concept::Virtualization.color(),
             EdgeType::ServiceVirtualization { .. } => Concept::Virtualization.color(),
             EdgeType::PhysicalLink { .. } => EntityDiscriminants::IfEntry.color(),
+            EdgeType::AccessPort { .. } => EntityDiscriminants::IfEntry.color(),
+            EdgeType::ProxyRoute { .. } => Concept::ReverseProxy.color(),
         }
     }
 
@@ -326,6 +338,8 @@ impl EntityMetadataProvider for EdgeType {
             EdgeType::HostVirtualization { .. } => Concept::Virtualization.icon(),
             EdgeType::ServiceVirtualization { .. } => Concept::Virtualization.icon(),
             EdgeType::PhysicalLink { .. } => EntityDiscriminants::IfEntry.icon(),
+            EdgeType::AccessPort { .. } => EntityDiscriminants::IfEntry.icon(),
+            EdgeType::ProxyRoute { .. } => Concept::ReverseProxy.icon(),
         }
     }
 }
@@ -339,6 +353,8 @@ impl TypeMetadataProvider for EdgeType {
             EdgeType::HostVirtualization { .. } => "Virtualized Host",
             EdgeType::ServiceVirtualization { .. } => "Virtualized Service",
             EdgeType::PhysicalLink { .. } => "Physical Link",
+            EdgeType::AccessPort { .. } => "Access Port",
+            EdgeType::ProxyRoute { .. } => "Proxy Route",
         }
     }
 
@@ -350,6 +366,8 @@ impl TypeMetadataProvider for EdgeType {
             EdgeType::HostVirtualization { .. } => EdgeStyle::Straight.into(),
             EdgeType::ServiceVirtualization { .. } => EdgeStyle::SmoothStep.into(),
             EdgeType::PhysicalLink { .. } => EdgeStyle::SmoothStep.into(),
+            EdgeType::AccessPort { .. } => EdgeStyle::SmoothStep.into(),
+            EdgeType::ProxyRoute { .. } => EdgeStyle::SmoothStep.into(),
         };
 
         let is_dashed = match &self {
@@ -359,6 +377,8 @@ impl TypeMetadataProvider for EdgeType {
             EdgeType::HostVirtualization { .. } => true,
             EdgeType::ServiceVirtualization { .. } => true,
             EdgeType::PhysicalLink { .. } => false, // Solid line for physical links
+            EdgeType::AccessPort { .. } => false,
+            EdgeType::ProxyRoute { .. } => false,
         };
 
         let has_start_marker = false;
@@ -370,6 +390,8 @@ impl TypeMetadataProvider for EdgeType {
             EdgeType::HostVirtualization { .. } => false,
             EdgeType::ServiceVirtualization { .. } => false,
             EdgeType::PhysicalLink { .. } => false, // No markers - bidirectional link
+            EdgeType::AccessPort { .. } => false,
+            EdgeType::ProxyRoute { .. } => true,
         };
 
         let is_host_edge = matches!(
@@ -380,7 +402,10 @@ impl TypeMetadataProvider for EdgeType {
             self,
             EdgeType::RequestPath { .. } | EdgeType::HubAndSpoke { .. }
         );
-        let is_physical_edge = matches!(self, EdgeType::PhysicalLink { .. });
+        let is_physical_edge = matches!(
+            self,
+            EdgeType::PhysicalLink { .. } | EdgeType::AccessPort { .. }
+        );
 
         serde_json::json!({
             "is_dashed": is_dashed,
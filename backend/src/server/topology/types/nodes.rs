@@ -1,5 +1,5 @@
 use crate::server::subnets::r#impl::types::SubnetType;
-use crate::server::topology::types::edges::Edge;
+use crate::server::topology::types::edges::{Edge, EdgeHandle};
 use crate::server::topology::types::layout::{Ixy, Uxy};
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumDiscriminants, EnumIter, IntoStaticStr};
@@ -29,7 +29,7 @@ pub struct Node {
     ToSchema,
 )]
 #[serde(tag = "node_type")]
-#[strum_discriminants(derive(Display, Hash, Serialize, Deserialize, EnumIter))]
+#[strum_discriminants(derive(Display, Hash, Serialize, Deserialize, EnumIter, ToSchema))]
 pub enum NodeType {
     SubnetNode {
         infra_width: usize,
@@ -40,6 +40,34 @@ pub enum NodeType {
         interface_id: Option<Uuid>,
         is_infra: bool,
     },
+    /// Physical view: devices sharing an SNMP sysLocation
+    SiteNode {
+        site: Option<String>,
+    },
+    /// Physical view: a device, positioned relative to its site node
+    DeviceNode {
+        site_id: Uuid,
+        host_id: Uuid,
+        /// Number of stacked chassis reported in the device's hardware inventory
+        stack_size: usize,
+        /// Ports with a link in this view, rendered as edge handles
+        ports: Vec<DevicePort>,
+    },
+    /// Dependency view: a service, annotated with the host running it and the subnets
+    /// it's bound in
+    ServiceNode {
+        service_id: Uuid,
+        host_id: Uuid,
+        subnet_ids: Vec<Uuid>,
+    },
+}
+
+/// A device port used as an edge endpoint in the physical view
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, ToSchema)]
+pub struct DevicePort {
+    pub if_entry_id: Uuid,
+    /// Side of the device node the port is drawn on
+    pub side: EdgeHandle,
 }
 
 #[derive(Debug, Clone)]
@@ -72,6 +100,7 @@ impl SubnetType {
 
             // Layer 3: Infrastructure
             SubnetType::DockerBridge => 3,
+            SubnetType::KubernetesPod => 3,
             SubnetType::MacVlan => 3,
             SubnetType::IpVlan => 3,
             SubnetType::Management => 3,
@@ -106,6 +135,7 @@ impl SubnetType {
             SubnetType::DockerBridge => 2,
             SubnetType::MacVlan => 3,
             SubnetType::IpVlan => 4,
+            SubnetType::KubernetesPod => 5,
 
             // Special
             SubnetType::Unknown => 999,
@@ -0,0 +1,400 @@
+use crate::server::auth::middleware::permissions::{Authorized, Member, Viewer};
+use crate::server::config::AppState;
+use crate::server::shared::extractors::Query;
+use crate::server::shared::handlers::ordering::OrderField;
+use crate::server::shared::handlers::query::{
+    FilterQueryExtractor, OrderDirection, PaginationParams,
+};
+use crate::server::shared::handlers::traits::CrudHandlers;
+use crate::server::shared::services::traits::CrudService;
+use crate::server::shared::storage::filter::StorableFilter;
+use crate::server::shared::storage::traits::{Entity, Storable};
+use crate::server::shared::types::api::{
+    ApiError, ApiErrorResponse, ApiResponse, ApiResult, PaginatedApiResponse,
+};
+use crate::server::topology::diff::{TopologyDiff, TopologyDiffSide};
+use crate::server::topology::export::TopologyExportQuery;
+use crate::server::topology::handlers::export_response;
+use crate::server::topology::types::base::Topology;
+use crate::server::topology_snapshots::r#impl::base::TopologySnapshot;
+use crate::server::topology_snapshots::service::TopologySnapshotService;
+use axum::Json;
+use axum::body::Body;
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::{IntoParams, ToSchema};
+use utoipa_axum::{router::OpenApiRouter, routes};
+use uuid::Uuid;
+use validator::Validate;
+
+impl CrudHandlers for TopologySnapshot {
+    type Service = TopologySnapshotService;
+    type FilterQuery = TopologySnapshotFilterQuery;
+
+    fn get_service(state: &AppState) -> &Self::Service {
+        &state.services.topology_snapshot_service
+    }
+}
+
+// ============================================================================
+// Topology Snapshot Ordering
+// ============================================================================
+
+/// Fields that topology snapshots can be ordered/grouped by.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TopologySnapshotOrderField {
+    #[default]
+    CreatedAt,
+    CapturedAt,
+    Name,
+    TopologyId,
+}
+
+impl OrderField for TopologySnapshotOrderField {
+    fn to_sql(&self) -> &'static str {
+        match self {
+            Self::CreatedAt => "topology_snapshots.created_at",
+            Self::CapturedAt => "topology_snapshots.captured_at",
+            Self::Name => "topology_snapshots.name",
+            Self::TopologyId => "topology_snapshots.topology_id",
+        }
+    }
+}
+
+// ============================================================================
+// Topology Snapshot Filter Query
+// ============================================================================
+
+/// Query parameters for filtering and ordering topology snapshots.
+#[derive(Deserialize, Default, Debug, Clone, IntoParams)]
+pub struct TopologySnapshotFilterQuery {
+    /// Filter by network ID
+    pub network_id: Option<Uuid>,
+    /// Filter by the topology the snapshots were taken of
+    pub topology_id: Option<Uuid>,
+    /// Primary ordering field (used for grouping). Always sorts ASC to keep groups together.
+    pub group_by: Option<TopologySnapshotOrderField>,
+    /// Secondary ordering field (sorting within groups or standalone sort).
+    pub order_by: Option<TopologySnapshotOrderField>,
+    /// Direction for order_by field (group_by always uses ASC).
+    pub order_direction: Option<OrderDirection>,
+    /// Maximum number of results to return (1-1000, default: 50). Use 0 for no limit.
+    #[param(minimum = 0, maximum = 1000)]
+    pub limit: Option<u32>,
+    /// Number of results to skip. Default: 0.
+    #[param(minimum = 0)]
+    pub offset: Option<u32>,
+}
+
+impl TopologySnapshotFilterQuery {
+    /// Build the ORDER BY clause. Newest snapshots first unless specified.
+    pub fn apply_ordering(
+        &self,
+        filter: StorableFilter<TopologySnapshot>,
+    ) -> (StorableFilter<TopologySnapshot>, String) {
+        crate::server::shared::handlers::ordering::apply_ordering(
+            self.group_by,
+            self.order_by,
+            self.order_direction,
+            filter,
+            "topology_snapshots.created_at DESC",
+        )
+    }
+}
+
+impl FilterQueryExtractor for TopologySnapshotFilterQuery {
+    fn apply_to_filter<T: Storable>(
+        &self,
+        filter: StorableFilter<T>,
+        user_network_ids: &[Uuid],
+        _user_organization_id: Uuid,
+    ) -> StorableFilter<T> {
+        let filter = match self.topology_id {
+            Some(id) => filter.topology_id(&id),
+            None => filter,
+        };
+        match self.network_id {
+            Some(id) if user_network_ids.contains(&id) => filter.network_ids(&[id]),
+            Some(_) => filter.network_ids(&[]), // User doesn't have access - return empty
+            None => filter.network_ids(user_network_ids),
+        }
+    }
+
+    fn pagination(&self) -> PaginationParams {
+        PaginationParams {
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+// ============================================================================
+// Request / Query Types
+// ============================================================================
+
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct CreateTopologySnapshotRequest {
+    /// Topology to capture as currently built
+    pub topology_id: Uuid,
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    #[validate(length(max = 1000))]
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TopologyDiffQuery {
+    /// Earlier snapshot
+    pub from: Uuid,
+    /// Later snapshot of the same topology. Omit to compare against the live topology.
+    pub to: Option<Uuid>,
+}
+
+// Generated handlers for read/delete operations. Snapshots are immutable once saved.
+mod generated {
+    use super::*;
+    crate::crud_get_by_id_handler!(TopologySnapshot);
+    crate::crud_delete_handler!(TopologySnapshot);
+    crate::crud_export_csv_handler!(TopologySnapshot);
+}
+
+pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(
+            get_all_topology_snapshots,
+            create_topology_snapshot
+        ))
+        .routes(routes!(generated::get_by_id, generated::delete))
+        .routes(routes!(generated::export_csv))
+        .routes(routes!(diff_topology_snapshots))
+        .routes(routes!(export_topology_snapshot))
+}
+
+/// Fetch a snapshot the caller's networks give access to
+pub async fn get_accessible_snapshot(
+    state: &AppState,
+    id: Uuid,
+    network_ids: &[Uuid],
+) -> ApiResult<TopologySnapshot> {
+    let snapshot = TopologySnapshot::get_service(state)
+        .get_by_id(&id)
+        .await?
+        .ok_or_else(|| ApiError::entity_not_found::<TopologySnapshot>(id))?;
+
+    if !network_ids.contains(&snapshot.base.network_id) {
+        return Err(ApiError::forbidden(
+            "You don't have access to this topology snapshot",
+        ));
+    }
+
+    Ok(snapshot)
+}
+
+/// List all Topology Snapshots
+///
+/// Returns saved snapshots, newest first. Filter by `topology_id` for the history
+/// of one topology.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = TopologySnapshot::ENTITY_NAME_PLURAL,
+    params(TopologySnapshotFilterQuery),
+    responses(
+        (status = 200, description = "List of topology snapshots", body = PaginatedApiResponse<TopologySnapshot>),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_all_topology_snapshots(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Viewer>,
+    Query(query): Query<TopologySnapshotFilterQuery>,
+) -> ApiResult<Json<PaginatedApiResponse<TopologySnapshot>>> {
+    let network_ids = auth.network_ids();
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(ApiError::organization_required)?;
+
+    let base_filter = StorableFilter::<TopologySnapshot>::new_from_network_ids(&network_ids);
+    let filter = query.apply_to_filter(base_filter, &network_ids, organization_id);
+
+    let pagination = query.pagination();
+    let filter = pagination.apply_to_filter(filter);
+    let (filter, order_by) = query.apply_ordering(filter);
+
+    let result = state
+        .services
+        .topology_snapshot_service
+        .get_paginated_ordered(filter, &order_by)
+        .await?;
+
+    let limit = pagination.effective_limit().unwrap_or(0);
+    let offset = pagination.effective_offset();
+
+    Ok(Json(PaginatedApiResponse::success(
+        result.items,
+        result.total_count,
+        limit,
+        offset,
+    )))
+}
+
+/// Create a Topology Snapshot
+///
+/// Saves the topology's nodes, edges and the entities they were built from as they
+/// currently are. The topology is captured as last built; refresh or rebuild it first
+/// to capture current data.
+#[utoipa::path(
+    post,
+    path = "",
+    tag = TopologySnapshot::ENTITY_NAME_PLURAL,
+    request_body = CreateTopologySnapshotRequest,
+    responses(
+        (status = 200, description = "Topology snapshot created", body = ApiResponse<TopologySnapshot>),
+        (status = 400, description = "Invalid request", body = ApiErrorResponse),
+        (status = 403, description = "Access denied", body = ApiErrorResponse),
+        (status = 404, description = "Topology not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn create_topology_snapshot(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Member>,
+    Json(request): Json<CreateTopologySnapshotRequest>,
+) -> ApiResult<Json<ApiResponse<TopologySnapshot>>> {
+    request
+        .validate()
+        .map_err(|e| ApiError::bad_request(&e.to_string()))?;
+
+    let topology = Topology::get_service(&state)
+        .get_by_id(&request.topology_id)
+        .await?
+        .ok_or_else(|| ApiError::entity_not_found::<Topology>(request.topology_id))?;
+
+    if !auth.network_ids().contains(&topology.base.network_id) {
+        return Err(ApiError::forbidden(
+            "You don't have access to this topology",
+        ));
+    }
+
+    let snapshot = state
+        .services
+        .topology_snapshot_service
+        .capture(
+            &topology,
+            request.name,
+            request.description,
+            auth.into_entity(),
+        )
+        .await?;
+
+    Ok(Json(ApiResponse::success(snapshot)))
+}
+
+/// Diff Topology Snapshots
+///
+/// Compares a snapshot with a later snapshot of the same topology, or with the live
+/// topology when `to` is omitted. Lists nodes and edges added, removed or moved between
+/// the two.
+#[utoipa::path(
+    get,
+    path = "/diff",
+    tag = TopologySnapshot::ENTITY_NAME_PLURAL,
+    params(TopologyDiffQuery),
+    responses(
+        (status = 200, description = "Changes between the two sides", body = ApiResponse<TopologyDiff>),
+        (status = 400, description = "Snapshots belong to different topologies", body = ApiErrorResponse),
+        (status = 403, description = "Access denied", body = ApiErrorResponse),
+        (status = 404, description = "Snapshot or topology not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn diff_topology_snapshots(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Viewer>,
+    Query(query): Query<TopologyDiffQuery>,
+) -> ApiResult<Json<ApiResponse<TopologyDiff>>> {
+    let network_ids = auth.network_ids();
+    let from = get_accessible_snapshot(&state, query.from, &network_ids).await?;
+
+    let (to_side, to) = match query.to {
+        Some(id) => {
+            let snapshot = get_accessible_snapshot(&state, id, &network_ids).await?;
+            if snapshot.base.topology_id != from.base.topology_id {
+                return Err(ApiError::bad_request(
+                    "Snapshots must be taken of the same topology",
+                ));
+            }
+            (snapshot.diff_side(), snapshot.to_topology())
+        }
+        None => {
+            let topology = Topology::get_service(&state)
+                .get_by_id(&from.base.topology_id)
+                .await?
+                .ok_or_else(|| ApiError::entity_not_found::<Topology>(from.base.topology_id))?;
+            (TopologyDiffSide::from(&topology), topology)
+        }
+    };
+
+    Ok(Json(ApiResponse::success(TopologyDiff::between(
+        from.diff_side(),
+        &from.to_topology(),
+        to_side,
+        &to,
+    ))))
+}
+
+/// Export a Topology Snapshot diagram
+///
+/// Renders the snapshot as SVG, GraphML, draw.io or Graphviz DOT. With `compare_to`,
+/// changes since that earlier snapshot of the same topology are highlighted.
+#[utoipa::path(
+    get,
+    path = "/{id}/export",
+    tag = TopologySnapshot::ENTITY_NAME_PLURAL,
+    params(("id" = Uuid, Path, description = "Topology Snapshot ID"), TopologyExportQuery),
+    responses(
+        (status = 200, description = "Diagram file in the requested format", content(
+            (String = "image/svg+xml"),
+            (String = "application/graphml+xml"),
+            (String = "application/vnd.jgraph.mxfile"),
+            (String = "text/vnd.graphviz"),
+        )),
+        (status = 400, description = "Snapshots belong to different topologies", body = ApiErrorResponse),
+        (status = 403, description = "Access denied", body = ApiErrorResponse),
+        (status = 404, description = "Snapshot not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn export_topology_snapshot(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Viewer>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<TopologyExportQuery>,
+) -> ApiResult<(HeaderMap, Body)> {
+    let network_ids = auth.network_ids();
+    let snapshot = get_accessible_snapshot(&state, id, &network_ids).await?;
+
+    let previous = match query.compare_to {
+        Some(compare_id) => {
+            let previous = get_accessible_snapshot(&state, compare_id, &network_ids).await?;
+            if previous.base.topology_id != snapshot.base.topology_id {
+                return Err(ApiError::bad_request(
+                    "Snapshots must be taken of the same topology",
+                ));
+            }
+            Some(previous.to_topology())
+        }
+        None => None,
+    };
+
+    Ok(export_response(
+        &snapshot.to_topology(),
+        previous.as_ref(),
+        query.format,
+    ))
+}
@@ -0,0 +1,162 @@
+use crate::server::bindings::r#impl::base::Binding;
+use crate::server::groups::r#impl::base::Group;
+use crate::server::hosts::r#impl::base::Host;
+use crate::server::if_entries::r#impl::base::IfEntry;
+use crate::server::interfaces::r#impl::base::Interface;
+use crate::server::ports::r#impl::base::Port;
+use crate::server::services::r#impl::base::Service;
+use crate::server::shared::entities::ChangeTriggersTopologyStaleness;
+use crate::server::subnets::r#impl::base::Subnet;
+use crate::server::tags::r#impl::base::Tag;
+use crate::server::topology::diff::TopologyDiffSide;
+use crate::server::topology::types::base::{Topology, TopologyBase, TopologyOptions};
+use crate::server::topology::types::edges::Edge;
+use crate::server::topology::types::nodes::Node;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Named, immutable copy of a topology's graph and the entities it was built from
+#[derive(
+    Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default, ToSchema, Validate,
+)]
+pub struct TopologySnapshotBase {
+    pub topology_id: Uuid,
+    pub network_id: Uuid,
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    #[validate(length(max = 1000))]
+    #[schema(required)]
+    pub description: Option<String>,
+    /// User who saved the snapshot
+    #[schema(required)]
+    pub created_by: Option<Uuid>,
+    /// When the captured graph was last built
+    pub captured_at: DateTime<Utc>,
+    pub options: TopologyOptions,
+
+    // Graph
+    pub nodes: Vec<Node>,
+    pub edges: Vec<Edge>,
+
+    // Entities
+    pub hosts: Vec<Host>,
+    pub interfaces: Vec<Interface>,
+    pub ports: Vec<Port>,
+    pub bindings: Vec<Binding>,
+    pub subnets: Vec<Subnet>,
+    pub services: Vec<Service>,
+    pub groups: Vec<Group>,
+    pub if_entries: Vec<IfEntry>,
+    pub entity_tags: Vec<Tag>,
+}
+
+impl TopologySnapshotBase {
+    /// Capture the topology as currently built
+    pub fn capture(
+        topology: &Topology,
+        name: String,
+        description: Option<String>,
+        created_by: Option<Uuid>,
+    ) -> Self {
+        let base = topology.base.clone();
+        Self {
+            topology_id: topology.id,
+            network_id: base.network_id,
+            name,
+            description,
+            created_by,
+            captured_at: base.last_refreshed,
+            options: base.options,
+            nodes: base.nodes,
+            edges: base.edges,
+            hosts: base.hosts,
+            interfaces: base.interfaces,
+            ports: base.ports,
+            bindings: base.bindings,
+            subnets: base.subnets,
+            services: base.services,
+            groups: base.groups,
+            if_entries: base.if_entries,
+            entity_tags: base.entity_tags,
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default, ToSchema, Validate,
+)]
+pub struct TopologySnapshot {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub base: TopologySnapshotBase,
+}
+
+impl TopologySnapshot {
+    /// The snapshot as a locked topology named after the snapshot, for diffing and export
+    pub fn to_topology(&self) -> Topology {
+        let base = self.base.clone();
+        let mut topology_base = TopologyBase::new(base.name, base.network_id);
+        topology_base.options = base.options;
+        topology_base.nodes = base.nodes;
+        topology_base.edges = base.edges;
+        topology_base.hosts = base.hosts;
+        topology_base.interfaces = base.interfaces;
+        topology_base.ports = base.ports;
+        topology_base.bindings = base.bindings;
+        topology_base.subnets = base.subnets;
+        topology_base.services = base.services;
+        topology_base.groups = base.groups;
+        topology_base.if_entries = base.if_entries;
+        topology_base.entity_tags = base.entity_tags;
+        topology_base.is_stale = false;
+        topology_base.last_refreshed = base.captured_at;
+        topology_base.is_locked = true;
+        topology_base.locked_at = Some(self.created_at);
+        topology_base.locked_by = base.created_by;
+
+        Topology {
+            id: base.topology_id,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            base: topology_base,
+        }
+    }
+
+    pub fn diff_side(&self) -> TopologyDiffSide {
+        TopologyDiffSide {
+            topology_id: self.base.topology_id,
+            snapshot_id: Some(self.id),
+            name: self.base.name.clone(),
+            captured_at: self.base.captured_at,
+        }
+    }
+}
+
+impl ChangeTriggersTopologyStaleness<TopologySnapshot> for TopologySnapshot {
+    fn triggers_staleness(&self, _other: Option<TopologySnapshot>) -> bool {
+        false
+    }
+}
+
+impl Display for TopologySnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "TopologySnapshot {{ id: {}, name: {}, topology: {} }}",
+            self.id, self.base.name, self.base.topology_id
+        )
+    }
+}
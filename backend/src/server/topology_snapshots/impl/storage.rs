@@ -0,0 +1,227 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+use crate::server::{
+    shared::{
+        entities::EntityDiscriminants,
+        entity_metadata::EntityCategory,
+        storage::traits::{Entity, SqlValue, Storable},
+    },
+    topology_snapshots::r#impl::base::{TopologySnapshot, TopologySnapshotBase},
+};
+
+/// CSV row representation for TopologySnapshot export (metadata only, excludes the graph)
+#[derive(Serialize)]
+pub struct TopologySnapshotCsvRow {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub topology_id: Uuid,
+    pub network_id: Uuid,
+    pub created_by: Option<Uuid>,
+    pub captured_at: DateTime<Utc>,
+    pub node_count: usize,
+    pub edge_count: usize,
+    pub created_at: DateTime<Utc>,
+}
+
+fn json_column<T: DeserializeOwned>(row: &PgRow, column: &str) -> Result<T, anyhow::Error> {
+    serde_json::from_value(row.get::<serde_json::Value, _>(column))
+        .map_err(|e| anyhow::anyhow!("Failed to deserialize {}: {}", column, e))
+}
+
+impl Storable for TopologySnapshot {
+    type BaseData = TopologySnapshotBase;
+
+    fn table_name() -> &'static str {
+        "topology_snapshots"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            updated_at,
+            base:
+                Self::BaseData {
+                    topology_id,
+                    network_id,
+                    name,
+                    description,
+                    created_by,
+                    captured_at,
+                    options,
+                    nodes,
+                    edges,
+                    hosts,
+                    interfaces,
+                    ports,
+                    bindings,
+                    subnets,
+                    services,
+                    groups,
+                    if_entries,
+                    entity_tags,
+                },
+        } = self.clone();
+
+        Ok((
+            vec![
+                "id",
+                "created_at",
+                "updated_at",
+                "topology_id",
+                "network_id",
+                "name",
+                "description",
+                "created_by",
+                "captured_at",
+                "options",
+                "nodes",
+                "edges",
+                "hosts",
+                "interfaces",
+                "subnets",
+                "groups",
+                "services",
+                "bindings",
+                "ports",
+                "if_entries",
+                "entity_tags",
+            ],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::Timestamp(created_at),
+                SqlValue::Timestamp(updated_at),
+                SqlValue::Uuid(topology_id),
+                SqlValue::Uuid(network_id),
+                SqlValue::String(name),
+                SqlValue::OptionalString(description),
+                SqlValue::OptionalUuid(created_by),
+                SqlValue::Timestamp(captured_at),
+                SqlValue::TopologyOptions(options),
+                SqlValue::Nodes(nodes),
+                SqlValue::Edges(edges),
+                SqlValue::Hosts(hosts),
+                SqlValue::Interfaces(interfaces),
+                SqlValue::Subnets(subnets),
+                SqlValue::Groups(groups),
+                SqlValue::Services(services),
+                SqlValue::Bindings(bindings),
+                SqlValue::Ports(ports),
+                SqlValue::IfEntries(if_entries),
+                SqlValue::Tags(entity_tags),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        Ok(TopologySnapshot {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            base: TopologySnapshotBase {
+                topology_id: row.get("topology_id"),
+                network_id: row.get("network_id"),
+                name: row.get("name"),
+                description: row.get("description"),
+                created_by: row.get("created_by"),
+                captured_at: row.get("captured_at"),
+                options: json_column(row, "options")?,
+                nodes: json_column(row, "nodes")?,
+                edges: json_column(row, "edges")?,
+                hosts: json_column(row, "hosts")?,
+                interfaces: json_column(row, "interfaces")?,
+                ports: json_column(row, "ports")?,
+                bindings: json_column(row, "bindings")?,
+                subnets: json_column(row, "subnets")?,
+                services: json_column(row, "services")?,
+                groups: json_column(row, "groups")?,
+                if_entries: json_column(row, "if_entries")?,
+                entity_tags: json_column(row, "entity_tags")?,
+            },
+        })
+    }
+}
+
+impl Entity for TopologySnapshot {
+    type CsvRow = TopologySnapshotCsvRow;
+
+    fn to_csv_row(&self) -> Self::CsvRow {
+        TopologySnapshotCsvRow {
+            id: self.id,
+            name: self.base.name.clone(),
+            description: self.base.description.clone(),
+            topology_id: self.base.topology_id,
+            network_id: self.base.network_id,
+            created_by: self.base.created_by,
+            captured_at: self.base.captured_at,
+            node_count: self.base.nodes.len(),
+            edge_count: self.base.edges.len(),
+            created_at: self.created_at,
+        }
+    }
+
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::TopologySnapshot
+    }
+
+    const ENTITY_NAME_SINGULAR: &'static str = "Topology Snapshot";
+    const ENTITY_NAME_PLURAL: &'static str = "Topology Snapshots";
+    const ENTITY_DESCRIPTION: &'static str = "Named, immutable copies of a topology at a point in time. Compare snapshots with each other or with the live topology to see which nodes and edges were added, removed or moved.";
+
+    fn entity_category() -> EntityCategory {
+        EntityCategory::Visualization
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        Some(self.base.network_id)
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    fn set_updated_at(&mut self, time: DateTime<Utc>) {
+        self.updated_at = time;
+    }
+}
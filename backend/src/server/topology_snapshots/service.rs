@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::server::{
+    auth::middleware::auth::AuthenticatedEntity,
+    shared::{
+        events::bus::EventBus,
+        services::traits::{CrudService, EventBusService},
+        storage::{generic::GenericPostgresStorage, traits::Storable},
+    },
+    tags::entity_tags::EntityTagService,
+    topology::types::base::Topology,
+    topology_snapshots::r#impl::base::{TopologySnapshot, TopologySnapshotBase},
+};
+
+pub struct TopologySnapshotService {
+    storage: Arc<GenericPostgresStorage<TopologySnapshot>>,
+    event_bus: Arc<EventBus>,
+}
+
+impl EventBusService<TopologySnapshot> for TopologySnapshotService {
+    fn event_bus(&self) -> &Arc<EventBus> {
+        &self.event_bus
+    }
+
+    fn get_network_id(&self, entity: &TopologySnapshot) -> Option<Uuid> {
+        Some(entity.base.network_id)
+    }
+
+    fn get_organization_id(&self, _entity: &TopologySnapshot) -> Option<Uuid> {
+        None
+    }
+}
+
+impl CrudService<TopologySnapshot> for TopologySnapshotService {
+    fn storage(&self) -> &Arc<GenericPostgresStorage<TopologySnapshot>> {
+        &self.storage
+    }
+
+    fn entity_tag_service(&self) -> Option<&Arc<EntityTagService>> {
+        None
+    }
+}
+
+impl TopologySnapshotService {
+    pub fn new(
+        storage: Arc<GenericPostgresStorage<TopologySnapshot>>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
+        Self { storage, event_bus }
+    }
+
+    /// Save the topology as currently built under a name
+    pub async fn capture(
+        &self,
+        topology: &Topology,
+        name: String,
+        description: Option<String>,
+        authentication: AuthenticatedEntity,
+    ) -> Result<TopologySnapshot, anyhow::Error> {
+        let base =
+            TopologySnapshotBase::capture(topology, name, description, authentication.user_id());
+        self.create(TopologySnapshot::new(base), authentication)
+            .await
+    }
+}
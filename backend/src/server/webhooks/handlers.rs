@@ -0,0 +1,470 @@
+use crate::server::auth::middleware::permissions::{Admin, Authorized, Member, Viewer};
+use crate::server::shared::handlers::ordering::OrderField;
+use crate::server::shared::handlers::query::{
+    FilterQueryExtractor, OrderDirection, PaginationParams,
+};
+use crate::server::shared::handlers::traits::{
+    BulkDeleteResponse, CrudHandlers, bulk_delete_handler, create_handler, delete_handler,
+    update_handler,
+};
+use crate::server::shared::services::traits::CrudService;
+use crate::server::shared::storage::filter::StorableFilter;
+use crate::server::shared::storage::traits::{Entity, Storable};
+use crate::server::shared::types::api::{
+    ApiError, ApiErrorResponse, EmptyApiResponse, PaginatedApiResponse,
+};
+use crate::server::snmp_credentials::r#impl::base::REDACTED_SECRET;
+use crate::server::webhooks::r#impl::base::{Webhook, WebhookDelivery, WebhookDeliveryStatus};
+use crate::server::webhooks::service::WebhookService;
+use crate::server::{
+    config::AppState,
+    shared::types::api::{ApiResponse, ApiResult},
+};
+use axum::extract::Path;
+use axum::{extract::State, response::Json};
+use secrecy::ExposeSecret;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::IntoParams;
+use utoipa_axum::{router::OpenApiRouter, routes};
+use uuid::Uuid;
+
+impl CrudHandlers for Webhook {
+    type Service = WebhookService;
+    type FilterQuery = WebhookFilterQuery;
+
+    fn get_service(state: &AppState) -> &Self::Service {
+        &state.services.webhook_service
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        validator::Validate::validate(self).map_err(|e| e.to_string())?;
+        self.base.validate_webhook()
+    }
+}
+
+// ============================================================================
+// Webhook Ordering
+// ============================================================================
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookOrderField {
+    #[default]
+    CreatedAt,
+    Name,
+    UpdatedAt,
+}
+
+impl OrderField for WebhookOrderField {
+    fn to_sql(&self) -> &'static str {
+        match self {
+            Self::CreatedAt => "webhooks.created_at",
+            Self::Name => "webhooks.name",
+            Self::UpdatedAt => "webhooks.updated_at",
+        }
+    }
+}
+
+// ============================================================================
+// Webhook Filter Query
+// ============================================================================
+
+#[derive(Deserialize, Default, Debug, Clone, IntoParams)]
+pub struct WebhookFilterQuery {
+    /// Primary ordering field (used for grouping). Always sorts ASC to keep groups together.
+    pub group_by: Option<WebhookOrderField>,
+    /// Secondary ordering field (sorting within groups or standalone sort).
+    pub order_by: Option<WebhookOrderField>,
+    /// Direction for order_by field (group_by always uses ASC).
+    pub order_direction: Option<OrderDirection>,
+    /// Maximum number of results to return (1-1000, default: 50). Use 0 for no limit.
+    #[param(minimum = 0, maximum = 1000)]
+    pub limit: Option<u32>,
+    /// Number of results to skip. Default: 0.
+    #[param(minimum = 0)]
+    pub offset: Option<u32>,
+}
+
+impl WebhookFilterQuery {
+    pub fn apply_ordering(
+        &self,
+        filter: StorableFilter<Webhook>,
+    ) -> (StorableFilter<Webhook>, String) {
+        crate::server::shared::handlers::ordering::apply_ordering(
+            self.group_by,
+            self.order_by,
+            self.order_direction,
+            filter,
+            "webhooks.created_at ASC",
+        )
+    }
+}
+
+impl FilterQueryExtractor for WebhookFilterQuery {
+    fn apply_to_filter<T: Storable>(
+        &self,
+        filter: StorableFilter<T>,
+        _user_network_ids: &[Uuid],
+        _user_organization_id: Uuid,
+    ) -> StorableFilter<T> {
+        filter
+    }
+
+    fn pagination(&self) -> PaginationParams {
+        PaginationParams {
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+/// Query parameters for a webhook's delivery log
+#[derive(Deserialize, Default, Debug, Clone, IntoParams)]
+pub struct WebhookDeliveryQuery {
+    /// Only return deliveries with this status
+    pub status: Option<WebhookDeliveryStatus>,
+    /// Maximum number of results to return (1-1000, default: 50). Use 0 for no limit.
+    #[param(minimum = 0, maximum = 1000)]
+    pub limit: Option<u32>,
+    /// Number of results to skip. Default: 0.
+    #[param(minimum = 0)]
+    pub offset: Option<u32>,
+}
+
+// Generated handler for read-only operations
+mod generated {
+    use super::*;
+    crate::crud_get_by_id_handler!(Webhook);
+    crate::crud_export_csv_handler!(Webhook);
+}
+
+pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(get_all_webhooks, create_webhook))
+        .routes(routes!(generated::export_csv))
+        .routes(routes!(
+            generated::get_by_id,
+            update_webhook,
+            delete_webhook
+        ))
+        .routes(routes!(bulk_delete_webhooks))
+        .routes(routes!(test_webhook))
+        .routes(routes!(get_webhook_deliveries))
+}
+
+/// Fetch a webhook and check it belongs to the caller's organization
+async fn get_org_webhook(state: &AppState, organization_id: Uuid, id: Uuid) -> ApiResult<Webhook> {
+    state
+        .services
+        .webhook_service
+        .get_by_id(&id)
+        .await?
+        .filter(|w| w.base.organization_id == organization_id)
+        .ok_or_else(|| ApiError::entity_not_found::<Webhook>(id))
+}
+
+/// Update Webhook
+///
+/// Send the secret back as returned (redacted) to keep the current secret.
+#[utoipa::path(
+    put,
+    path = "/{id}",
+    tag = Webhook::ENTITY_NAME_PLURAL,
+    params(
+        ("id" = Uuid, Path, description = "webhook ID")
+    ),
+    request_body = Webhook,
+    responses(
+        (status = 200, description = "webhook updated successfully", body = ApiResponse<Webhook>),
+        (status = 400, description = "Validation error", body = ApiErrorResponse),
+        (status = 404, description = "webhook not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn update_webhook(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    id: Path<Uuid>,
+    entity: Json<Webhook>,
+) -> ApiResult<Json<ApiResponse<Webhook>>> {
+    state
+        .services
+        .webhook_service
+        .check_target(&entity.base.url)
+        .await
+        .map_err(|e| ApiError::bad_request(&e))?;
+
+    update_handler::<Webhook>(state, auth.into_permission::<Member>(), id, entity).await
+}
+
+/// Delete Webhook
+///
+/// Also deletes the webhook's delivery log.
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    tag = Webhook::ENTITY_NAME_PLURAL,
+    params(
+        ("id" = Uuid, Path, description = "webhook ID")
+    ),
+    responses(
+        (status = 200, description = "webhook deleted successfully", body = EmptyApiResponse),
+        (status = 404, description = "webhook not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn delete_webhook(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    id: Path<Uuid>,
+) -> ApiResult<Json<ApiResponse<()>>> {
+    delete_handler::<Webhook>(state, auth.into_permission::<Member>(), id).await
+}
+
+/// Bulk delete Webhooks
+#[utoipa::path(
+    post,
+    path = "/bulk-delete",
+    tag = Webhook::ENTITY_NAME_PLURAL,
+    request_body = Vec<Uuid>,
+    responses(
+        (status = 200, description = "Webhooks deleted successfully", body = ApiResponse<BulkDeleteResponse>),
+        (status = 400, description = "Validation error", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn bulk_delete_webhooks(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    ids: Json<Vec<Uuid>>,
+) -> ApiResult<Json<ApiResponse<BulkDeleteResponse>>> {
+    bulk_delete_handler::<Webhook>(state, auth.into_permission::<Member>(), ids).await
+}
+
+/// List all Webhooks
+///
+/// Returns all webhooks in the authenticated user's organization. Secrets are redacted.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = Webhook::ENTITY_NAME_PLURAL,
+    params(WebhookFilterQuery),
+    responses(
+        (status = 200, description = "List of webhooks", body = PaginatedApiResponse<Webhook>),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_all_webhooks(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Viewer>,
+    crate::server::shared::extractors::Query(query): crate::server::shared::extractors::Query<
+        WebhookFilterQuery,
+    >,
+) -> ApiResult<Json<PaginatedApiResponse<Webhook>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+    let base_filter = StorableFilter::<Webhook>::new_from_org_id(&organization_id);
+
+    let pagination = query.pagination();
+    let filter = pagination.apply_to_filter(base_filter);
+    let (filter, order_by) = query.apply_ordering(filter);
+
+    let result = state
+        .services
+        .webhook_service
+        .get_paginated_ordered(filter, &order_by)
+        .await?;
+
+    let limit = pagination.effective_limit().unwrap_or(0);
+    let offset = pagination.effective_offset();
+
+    Ok(Json(PaginatedApiResponse::success(
+        result.items,
+        result.total_count,
+        limit,
+        offset,
+    )))
+}
+
+/// Create a new Webhook
+///
+/// Subscribes an HTTP endpoint to entity and discovery events in your organization.
+/// Each matching event is POSTed as JSON:
+///
+/// ```json
+/// {
+///   "id": "<event id>",
+///   "type": "host.created",
+///   "timestamp": "2026-01-01T00:00:00Z",
+///   "organization_id": "<uuid>",
+///   "network_id": "<uuid or null>",
+///   "data": { "entity_type": "Host", "entity_id": "<uuid>", "entity": { } }
+/// }
+/// ```
+///
+/// Discovery events use the types `discovery.complete` and `discovery.failed`.
+///
+/// ### Signature verification
+///
+/// Requests carry `X-Scanopy-Event`, `X-Scanopy-Delivery`, `X-Scanopy-Timestamp` and
+/// `X-Scanopy-Signature` headers. The signature is `sha256=` followed by the hex
+/// HMAC-SHA256 of `{timestamp}.{raw body}` keyed with the webhook secret.
+///
+/// ### Delivery
+///
+/// Any 2xx response counts as delivered; redirects are not followed. Failed
+/// deliveries are retried with exponential backoff, up to 5 attempts.
+///
+/// ### Validation
+///
+/// - Name must be 1-100 characters and unique within your organization
+/// - URL must be an http or https URL. Loopback, link-local and cloud metadata targets
+///   (e.g. 169.254.169.254) are rejected unless the server allows private webhook targets.
+/// - Secret must be at least 16 characters
+/// - The event filter must select at least one entity type or discovery phase
+#[utoipa::path(
+    post,
+    path = "",
+    tag = Webhook::ENTITY_NAME_PLURAL,
+    request_body = Webhook,
+    responses(
+        (status = 200, description = "Webhook created successfully", body = ApiResponse<Webhook>),
+        (status = 400, description = "Validation error", body = ApiErrorResponse),
+        (status = 409, description = "Webhook name already exists in this organization", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+pub async fn create_webhook(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    Json(webhook): Json<Webhook>,
+) -> ApiResult<Json<ApiResponse<Webhook>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+    if webhook.base.secret.expose_secret() == REDACTED_SECRET {
+        return Err(ApiError::bad_request("A secret is required"));
+    }
+
+    state
+        .services
+        .webhook_service
+        .check_target(&webhook.base.url)
+        .await
+        .map_err(|e| ApiError::bad_request(&e))?;
+
+    // Check for duplicate name
+    let name_filter = StorableFilter::<Webhook>::new_from_org_id(&organization_id)
+        .name(webhook.base.name.clone());
+
+    if let Some(existing) = state.services.webhook_service.get_one(name_filter).await? {
+        return Err(ApiError::conflict(&format!(
+            "Webhook names must be unique; a webhook named \"{}\" already exists",
+            existing.base.name
+        )));
+    }
+
+    create_handler::<Webhook>(
+        State(state),
+        auth.into_permission::<Member>(),
+        Json(webhook),
+    )
+    .await
+}
+
+/// Send a test delivery
+///
+/// POSTs a signed `webhook.test` payload to the webhook's URL once, without retries,
+/// and returns the resulting delivery. Works for disabled webhooks too.
+#[utoipa::path(
+    post,
+    path = "/{id}/test",
+    tag = Webhook::ENTITY_NAME_PLURAL,
+    params(
+        ("id" = Uuid, Path, description = "webhook ID")
+    ),
+    responses(
+        (status = 200, description = "Test delivery attempted", body = ApiResponse<WebhookDelivery>),
+        (status = 404, description = "webhook not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn test_webhook(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<ApiResponse<WebhookDelivery>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+    let webhook = get_org_webhook(&state, organization_id, id).await?;
+    let delivery = state.services.webhook_service.send_test(&webhook).await?;
+
+    Ok(Json(ApiResponse::success(delivery)))
+}
+
+/// List a Webhook's deliveries
+///
+/// Returns the webhook's delivery log, newest first, including payloads, response
+/// status codes and errors. Deliveries are kept for 30 days.
+#[utoipa::path(
+    get,
+    path = "/{id}/deliveries",
+    tag = Webhook::ENTITY_NAME_PLURAL,
+    params(
+        ("id" = Uuid, Path, description = "webhook ID"),
+        WebhookDeliveryQuery
+    ),
+    responses(
+        (status = 200, description = "Webhook delivery log", body = PaginatedApiResponse<WebhookDelivery>),
+        (status = 404, description = "webhook not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_webhook_deliveries(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    Path(id): Path<Uuid>,
+    crate::server::shared::extractors::Query(query): crate::server::shared::extractors::Query<
+        WebhookDeliveryQuery,
+    >,
+) -> ApiResult<Json<PaginatedApiResponse<WebhookDelivery>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+    let webhook = get_org_webhook(&state, organization_id, id).await?;
+
+    let mut filter = StorableFilter::<WebhookDelivery>::new_from_org_id(&organization_id)
+        .uuid_column("webhook_id", &webhook.id);
+    if let Some(status) = query.status {
+        filter = filter.delivery_status(status);
+    }
+
+    let pagination = PaginationParams {
+        limit: query.limit,
+        offset: query.offset,
+    };
+    let filter = pagination.apply_to_filter(filter);
+
+    let result = state
+        .services
+        .webhook_service
+        .get_deliveries(filter)
+        .await?;
+
+    let limit = pagination.effective_limit().unwrap_or(0);
+    let offset = pagination.effective_offset();
+
+    Ok(Json(PaginatedApiResponse::success(
+        result.items,
+        result.total_count,
+        limit,
+        offset,
+    )))
+}
@@ -0,0 +1,327 @@
+use crate::{
+    daemon::discovery::types::base::DiscoveryPhase,
+    server::{
+        shared::{
+            entities::{ChangeTriggersTopologyStaleness, EntityDiscriminants},
+            events::types::{EntityOperation, Event},
+        },
+        snmp_credentials::r#impl::base::{REDACTED_SECRET, redact_secret},
+    },
+};
+use chrono::{DateTime, Utc};
+use secrecy::{ExposeSecret, SecretString};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use strum::IntoDiscriminant;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Entity types whose events can be delivered to webhooks. Credentials, users and
+/// other account-level entities are excluded so payloads never carry them.
+pub fn is_webhook_subscribable(entity_type: EntityDiscriminants) -> bool {
+    matches!(
+        entity_type,
+        EntityDiscriminants::Network
+            | EntityDiscriminants::Daemon
+            | EntityDiscriminants::Discovery
+            | EntityDiscriminants::Host
+            | EntityDiscriminants::Service
+            | EntityDiscriminants::Port
+            | EntityDiscriminants::Interface
+            | EntityDiscriminants::HostChange
+            | EntityDiscriminants::TlsCertificate
+            | EntityDiscriminants::Subnet
+            | EntityDiscriminants::Group
+            | EntityDiscriminants::Tag
+    )
+}
+
+/// Which events a webhook receives
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize, ToSchema)]
+pub struct WebhookEventFilter {
+    /// Entity types to deliver events for
+    #[serde(default)]
+    pub entity_types: Vec<EntityDiscriminants>,
+    /// Entity operations to deliver. Empty delivers created, updated and deleted.
+    #[serde(default)]
+    pub entity_operations: Vec<EntityOperation>,
+    /// Discovery session phases to deliver (e.g. Complete, Failed)
+    #[serde(default)]
+    pub discovery_phases: Vec<DiscoveryPhase>,
+    /// Networks to deliver events for. Empty delivers events from all networks.
+    #[serde(default)]
+    pub network_ids: Vec<Uuid>,
+}
+
+impl WebhookEventFilter {
+    pub fn validate_filter(&self) -> Result<(), String> {
+        if self.entity_types.is_empty() && self.discovery_phases.is_empty() {
+            return Err("Select at least one entity type or discovery phase".to_string());
+        }
+
+        if let Some(entity_type) = self
+            .entity_types
+            .iter()
+            .find(|t| !is_webhook_subscribable(**t))
+        {
+            return Err(format!(
+                "{} events can't be delivered to webhooks",
+                entity_type
+            ));
+        }
+
+        if self
+            .entity_operations
+            .iter()
+            .any(|op| matches!(op, EntityOperation::Get | EntityOperation::GetAll))
+        {
+            return Err("Only created, updated and deleted operations can be selected".to_string());
+        }
+
+        Ok(())
+    }
+
+    pub fn matches(&self, event: &Event) -> bool {
+        if !self.network_ids.is_empty()
+            && let Some(network_id) = event.network_id()
+            && !self.network_ids.contains(&network_id)
+        {
+            return false;
+        }
+
+        match event {
+            Event::Entity(entity_event) => {
+                let entity_type = entity_event.entity_type.discriminant();
+                let operation_selected = if self.entity_operations.is_empty() {
+                    matches!(
+                        entity_event.operation,
+                        EntityOperation::Created
+                            | EntityOperation::Updated
+                            | EntityOperation::Deleted
+                    )
+                } else {
+                    self.entity_operations.contains(&entity_event.operation)
+                };
+
+                is_webhook_subscribable(entity_type)
+                    && self.entity_types.contains(&entity_type)
+                    && operation_selected
+            }
+            Event::Discovery(discovery_event) => {
+                self.discovery_phases.contains(&discovery_event.phase)
+            }
+            Event::Auth(_) | Event::Telemetry(_) => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Validate, Serialize, Deserialize, ToSchema)]
+pub struct WebhookBase {
+    pub organization_id: Uuid,
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Webhook name must be between 1 and 100 characters"
+    ))]
+    pub name: String,
+    /// Endpoint that receives POSTed JSON payloads (http or https)
+    #[validate(url(message = "URL must be a valid http(s) URL"))]
+    #[validate(length(max = 2048, message = "URL must be at most 2048 characters"))]
+    pub url: String,
+    /// Shared secret used to sign payloads with HMAC-SHA256 (`X-Scanopy-Signature` header).
+    /// Redacted in API responses.
+    #[validate(skip)]
+    #[serde(serialize_with = "redact_secret")]
+    #[schema(value_type = String)]
+    pub secret: SecretString,
+    /// Disabled webhooks keep their configuration but receive no deliveries
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub event_filter: WebhookEventFilter,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl Default for WebhookBase {
+    fn default() -> Self {
+        Self {
+            organization_id: Uuid::nil(),
+            name: "New Webhook".to_string(),
+            url: String::new(),
+            secret: SecretString::from(String::new()),
+            enabled: true,
+            event_filter: WebhookEventFilter::default(),
+        }
+    }
+}
+
+impl PartialEq for WebhookBase {
+    fn eq(&self, other: &Self) -> bool {
+        self.organization_id == other.organization_id
+            && self.name == other.name
+            && self.url == other.url
+            && self.secret.expose_secret() == other.secret.expose_secret()
+            && self.enabled == other.enabled
+            && self.event_filter == other.event_filter
+    }
+}
+
+impl WebhookBase {
+    /// Check fields that can't be expressed as validator attributes
+    pub fn validate_webhook(&self) -> Result<(), String> {
+        if !(self.url.starts_with("https://") || self.url.starts_with("http://")) {
+            return Err("URL must use http or https".to_string());
+        }
+
+        let secret = self.secret.expose_secret();
+        if secret != REDACTED_SECRET && secret.len() < 16 {
+            return Err("Secret must be at least 16 characters".to_string());
+        }
+
+        self.event_filter.validate_filter()
+    }
+
+    /// Keep the stored secret when an update echoes back the redacted placeholder
+    pub fn keep_redacted_secret(&mut self, existing: &WebhookBase) {
+        if self.secret.expose_secret() == REDACTED_SECRET {
+            self.secret = existing.secret.clone();
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default, ToSchema, Validate)]
+pub struct Webhook {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub base: WebhookBase,
+}
+
+impl ChangeTriggersTopologyStaleness<Webhook> for Webhook {
+    fn triggers_staleness(&self, _other: Option<Webhook>) -> bool {
+        false
+    }
+}
+
+impl Webhook {
+    pub fn new(base: WebhookBase) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+}
+
+impl Display for Webhook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Webhook {}: {} ({})",
+            self.id, self.base.name, self.base.url
+        )
+    }
+}
+
+/// State of a webhook delivery
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    ToSchema,
+    strum::Display,
+    strum::EnumString,
+)]
+pub enum WebhookDeliveryStatus {
+    /// Not delivered yet; retried at `next_attempt_at`
+    #[default]
+    Pending,
+    /// Endpoint responded with a 2xx status
+    Succeeded,
+    /// Gave up after the maximum number of attempts
+    Failed,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, Default, Validate)]
+pub struct WebhookDeliveryBase {
+    pub webhook_id: Uuid,
+    pub organization_id: Uuid,
+    /// Event that triggered the delivery (random for test deliveries)
+    pub event_id: Uuid,
+    /// Event type, e.g. `host.created`, `discovery.failed` or `webhook.test`
+    pub event_type: String,
+    /// JSON body sent to the endpoint
+    pub payload: serde_json::Value,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: u32,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    /// HTTP status of the last attempt, if the endpoint responded
+    pub response_status: Option<u16>,
+    /// Error from the last failed attempt
+    pub error: Option<String>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, Default, Validate)]
+pub struct WebhookDelivery {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub base: WebhookDeliveryBase,
+}
+
+impl ChangeTriggersTopologyStaleness<WebhookDelivery> for WebhookDelivery {
+    fn triggers_staleness(&self, _other: Option<WebhookDelivery>) -> bool {
+        false
+    }
+}
+
+impl WebhookDelivery {
+    pub fn new(base: WebhookDeliveryBase) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+}
+
+impl Display for WebhookDelivery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "WebhookDelivery {}: {} to webhook {} ({})",
+            self.id, self.base.event_type, self.base.webhook_id, self.base.status
+        )
+    }
+}
@@ -0,0 +1,365 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+use crate::server::{
+    shared::{
+        entities::EntityDiscriminants,
+        entity_metadata::EntityCategory,
+        storage::{
+            secrets::{decrypt_secret, encrypt_secret},
+            traits::{Entity, SqlValue, Storable},
+        },
+    },
+    webhooks::r#impl::base::{Webhook, WebhookBase, WebhookDelivery, WebhookDeliveryBase},
+};
+
+/// CSV row representation for Webhook export
+#[derive(Serialize)]
+pub struct WebhookCsvRow {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub name: String,
+    pub url: String,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Storable for Webhook {
+    type BaseData = WebhookBase;
+
+    fn table_name() -> &'static str {
+        "webhooks"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            updated_at,
+            base:
+                Self::BaseData {
+                    organization_id,
+                    name,
+                    url,
+                    secret,
+                    enabled,
+                    event_filter,
+                },
+        } = self.clone();
+
+        Ok((
+            vec![
+                "id",
+                "organization_id",
+                "name",
+                "url",
+                "secret",
+                "enabled",
+                "event_filter",
+                "created_at",
+                "updated_at",
+            ],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::Uuid(organization_id),
+                SqlValue::String(name),
+                SqlValue::String(url),
+                SqlValue::String(encrypt_secret(&secret)?),
+                SqlValue::Bool(enabled),
+                SqlValue::JsonValue(serde_json::to_value(&event_filter)?),
+                SqlValue::Timestamp(created_at),
+                SqlValue::Timestamp(updated_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        let event_filter = serde_json::from_value(row.get("event_filter"))
+            .map_err(|e| anyhow::anyhow!("Failed to deserialize event_filter: {}", e))?;
+        let secret: String = row.get("secret");
+
+        Ok(Webhook {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            base: WebhookBase {
+                organization_id: row.get("organization_id"),
+                name: row.get("name"),
+                url: row.get("url"),
+                secret: decrypt_secret(&secret)?,
+                enabled: row.get("enabled"),
+                event_filter,
+            },
+        })
+    }
+}
+
+impl Entity for Webhook {
+    type CsvRow = WebhookCsvRow;
+
+    fn to_csv_row(&self) -> Self::CsvRow {
+        WebhookCsvRow {
+            id: self.id,
+            organization_id: self.base.organization_id,
+            name: self.base.name.clone(),
+            url: self.base.url.clone(),
+            enabled: self.base.enabled,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::Webhook
+    }
+
+    const ENTITY_NAME_SINGULAR: &'static str = "Webhook";
+    const ENTITY_NAME_PLURAL: &'static str = "Webhooks";
+    const ENTITY_DESCRIPTION: &'static str = "Outbound webhooks. POST signed JSON payloads to your endpoints when hosts, services or other entities change, or when discovery sessions complete or fail.";
+
+    fn entity_category() -> EntityCategory {
+        EntityCategory::OrganizationsAndUsers
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        Some(self.base.organization_id)
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    fn set_updated_at(&mut self, time: DateTime<Utc>) {
+        self.updated_at = time;
+    }
+
+    fn preserve_immutable_fields(&mut self, existing: &Self) {
+        // Secret is redacted in responses, so clients send the placeholder back unchanged
+        self.base.keep_redacted_secret(&existing.base);
+    }
+}
+
+/// CSV row representation for WebhookDelivery export
+#[derive(Serialize)]
+pub struct WebhookDeliveryCsvRow {
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub status: String,
+    pub attempts: u32,
+    pub response_status: Option<u16>,
+    pub error: Option<String>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Storable for WebhookDelivery {
+    type BaseData = WebhookDeliveryBase;
+
+    fn table_name() -> &'static str {
+        "webhook_deliveries"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            updated_at,
+            base:
+                Self::BaseData {
+                    webhook_id,
+                    organization_id,
+                    event_id,
+                    event_type,
+                    payload,
+                    status,
+                    attempts,
+                    next_attempt_at,
+                    response_status,
+                    error,
+                    delivered_at,
+                },
+        } = self.clone();
+
+        Ok((
+            vec![
+                "id",
+                "webhook_id",
+                "organization_id",
+                "event_id",
+                "event_type",
+                "payload",
+                "status",
+                "attempts",
+                "next_attempt_at",
+                "response_status",
+                "error",
+                "delivered_at",
+                "created_at",
+                "updated_at",
+            ],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::Uuid(webhook_id),
+                SqlValue::Uuid(organization_id),
+                SqlValue::Uuid(event_id),
+                SqlValue::String(event_type),
+                SqlValue::JsonValue(payload),
+                SqlValue::String(status.to_string()),
+                SqlValue::I32(attempts.try_into().unwrap_or(i32::MAX)),
+                SqlValue::OptionTimestamp(next_attempt_at),
+                SqlValue::OptionalI32(response_status.map(i32::from)),
+                SqlValue::OptionalString(error),
+                SqlValue::OptionTimestamp(delivered_at),
+                SqlValue::Timestamp(created_at),
+                SqlValue::Timestamp(updated_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        let status: String = row.get("status");
+        let status = status
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Failed to parse status: {}", e))?;
+        let response_status: Option<i32> = row.get("response_status");
+
+        Ok(WebhookDelivery {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            base: WebhookDeliveryBase {
+                webhook_id: row.get("webhook_id"),
+                organization_id: row.get("organization_id"),
+                event_id: row.get("event_id"),
+                event_type: row.get("event_type"),
+                payload: row.get("payload"),
+                status,
+                attempts: row.get::<i32, _>("attempts").max(0) as u32,
+                next_attempt_at: row.get("next_attempt_at"),
+                response_status: response_status.and_then(|s| u16::try_from(s).ok()),
+                error: row.get("error"),
+                delivered_at: row.get("delivered_at"),
+            },
+        })
+    }
+}
+
+impl Entity for WebhookDelivery {
+    type CsvRow = WebhookDeliveryCsvRow;
+
+    fn to_csv_row(&self) -> Self::CsvRow {
+        WebhookDeliveryCsvRow {
+            id: self.id,
+            webhook_id: self.base.webhook_id,
+            event_id: self.base.event_id,
+            event_type: self.base.event_type.clone(),
+            status: self.base.status.to_string(),
+            attempts: self.base.attempts,
+            response_status: self.base.response_status,
+            error: self.base.error.clone(),
+            delivered_at: self.base.delivered_at,
+            created_at: self.created_at,
+        }
+    }
+
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::WebhookDelivery
+    }
+
+    const ENTITY_NAME_SINGULAR: &'static str = "Webhook Delivery";
+    const ENTITY_NAME_PLURAL: &'static str = "Webhook Deliveries";
+    const ENTITY_DESCRIPTION: &'static str =
+        "Webhook delivery log. Inspect payloads sent to webhooks, their responses and retries.";
+
+    fn entity_category() -> EntityCategory {
+        EntityCategory::OrganizationsAndUsers
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        Some(self.base.organization_id)
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    fn set_updated_at(&mut self, time: DateTime<Utc>) {
+        self.updated_at = time;
+    }
+}
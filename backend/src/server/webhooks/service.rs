@@ -0,0 +1,756 @@
+use crate::server::{
+    networks::{r#impl::Network, service::NetworkService},
+    posthog::subscriber::to_snake_case,
+    shared::{
+        entities::Entity,
+        events::{bus::EventBus, types::Event},
+        services::traits::{CrudService, EventBusService},
+        storage::{
+            filter::StorableFilter,
+            generic::GenericPostgresStorage,
+            traits::{PaginatedResult, Storage},
+        },
+    },
+    tags::entity_tags::EntityTagService,
+    webhooks::r#impl::base::{
+        Webhook, WebhookDelivery, WebhookDeliveryBase, WebhookDeliveryStatus,
+    },
+};
+use anyhow::{Error, Result};
+use chrono::{Duration as ChronoDuration, Utc};
+use futures::future::join_all;
+use hmac::{Hmac, Mac};
+use secrecy::ExposeSecret;
+use serde_json::{Value, json};
+use sha2::Sha256;
+use std::{
+    collections::{HashMap, HashSet},
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
+use strum::IntoDiscriminant;
+use uuid::Uuid;
+
+/// Attempts before a delivery is marked failed
+pub const MAX_DELIVERY_ATTEMPTS: u32 = 5;
+/// Delivery log retention
+const DELIVERY_RETENTION_DAYS: i64 = 30;
+/// Due deliveries retried per run of the retry task
+const RETRY_BATCH_SIZE: u32 = 100;
+
+pub const SIGNATURE_HEADER: &str = "X-Scanopy-Signature";
+pub const TIMESTAMP_HEADER: &str = "X-Scanopy-Timestamp";
+pub const EVENT_HEADER: &str = "X-Scanopy-Event";
+pub const DELIVERY_HEADER: &str = "X-Scanopy-Delivery";
+
+/// Delay before the next attempt: 30s, 2m, 8m, 32m
+fn retry_backoff(attempts: u32) -> ChronoDuration {
+    ChronoDuration::seconds(30 * 4_i64.pow(attempts.saturating_sub(1).min(4)))
+}
+
+/// Addresses webhooks may not target unless private targets are allowed: the server itself
+/// (loopback, unspecified) and link-local, which includes cloud metadata at 169.254.169.254.
+/// AWS also serves metadata at fd00:ec2::254.
+pub fn is_blocked_webhook_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => ip.is_loopback() || ip.is_link_local() || ip.is_unspecified(),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(mapped) => is_blocked_webhook_ip(IpAddr::V4(mapped)),
+            None => {
+                ip.is_loopback()
+                    || ip.is_unicast_link_local()
+                    || ip.is_unspecified()
+                    || ip.segments() == [0xfd00, 0xec2, 0, 0, 0, 0, 0, 0x254]
+            }
+        },
+    }
+}
+
+/// Reject webhook URLs whose host is, or resolves to, a blocked address.
+/// Hosts that don't resolve are let through for delivery to report.
+pub async fn check_webhook_target(url: &str, allow_private_targets: bool) -> Result<(), String> {
+    if allow_private_targets {
+        return Ok(());
+    }
+
+    let url = url::Url::parse(url).map_err(|_| "URL must be a valid http(s) URL".to_string())?;
+    let blocked = |host: &str| {
+        Err(format!(
+            "Webhooks can't target {} (loopback, link-local and cloud metadata addresses are blocked)",
+            host
+        ))
+    };
+
+    match url.host() {
+        None => Err("URL must include a host".to_string()),
+        Some(url::Host::Ipv4(ip)) if is_blocked_webhook_ip(ip.into()) => blocked(&ip.to_string()),
+        Some(url::Host::Ipv6(ip)) if is_blocked_webhook_ip(ip.into()) => blocked(&ip.to_string()),
+        Some(url::Host::Ipv4(_) | url::Host::Ipv6(_)) => Ok(()),
+        Some(url::Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+            if domain == "localhost"
+                || domain.ends_with(".localhost")
+                || domain == "metadata.google.internal"
+            {
+                return blocked(&domain);
+            }
+
+            let port = url.port_or_known_default().unwrap_or(80);
+            if let Ok(mut addrs) = tokio::net::lookup_host((domain.as_str(), port)).await
+                && addrs.any(|addr| is_blocked_webhook_ip(addr.ip()))
+            {
+                return blocked(&domain);
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Resolver for webhook deliveries that drops blocked addresses, so the connection goes to an
+/// address that passed the check. Checking the URL up front isn't enough on its own: the
+/// client resolves again, and a rebinding host can answer differently the second time.
+struct WebhookResolver;
+
+impl reqwest::dns::Resolve for WebhookResolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        Box::pin(async move {
+            let host = name.as_str();
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host, 0))
+                .await?
+                .filter(|addr| !is_blocked_webhook_ip(addr.ip()))
+                .collect();
+            if addrs.is_empty() {
+                return Err(format!("{} only resolves to blocked addresses", host).into());
+            }
+            Ok(Box::new(addrs.into_iter()) as reqwest::dns::Addrs)
+        })
+    }
+}
+
+/// HMAC-SHA256 over `{timestamp}.{body}`, hex encoded
+pub fn sign_payload(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Event type and payload delivered for a bus event, e.g. `host.created`
+pub fn build_payload(event: &Event, organization_id: Uuid) -> Option<(String, Value)> {
+    match event {
+        Event::Entity(entity_event) => {
+            let entity_type = entity_event.entity_type.discriminant();
+            let event_type = format!(
+                "{}.{}",
+                to_snake_case(&entity_type.to_string()),
+                entity_event.operation
+            );
+
+            // Entity serializes as {"Host": {...}} - unwrap to the entity itself
+            let entity = match &entity_event.entity_type {
+                Entity::Unknown => Value::Null,
+                entity => serde_json::to_value(entity)
+                    .ok()
+                    .and_then(|v| v.as_object().and_then(|o| o.values().next().cloned()))
+                    .unwrap_or(Value::Null),
+            };
+
+            Some((
+                event_type.clone(),
+                json!({
+                    "id": entity_event.id,
+                    "type": event_type,
+                    "timestamp": entity_event.timestamp,
+                    "organization_id": organization_id,
+                    "network_id": entity_event.network_id,
+                    "data": {
+                        "entity_type": entity_type,
+                        "entity_id": entity_event.entity_id,
+                        "entity": entity,
+                    },
+                }),
+            ))
+        }
+        Event::Discovery(discovery_event) => {
+            let event_type = format!(
+                "discovery.{}",
+                to_snake_case(&format!("{:?}", discovery_event.phase))
+            );
+
+            Some((
+                event_type.clone(),
+                json!({
+                    "id": discovery_event.id,
+                    "type": event_type,
+                    "timestamp": discovery_event.timestamp,
+                    "organization_id": organization_id,
+                    "network_id": discovery_event.network_id,
+                    "data": {
+                        "session_id": discovery_event.session_id,
+                        "daemon_id": discovery_event.daemon_id,
+                        "discovery_type": discovery_event.discovery_type.to_string(),
+                        "phase": discovery_event.phase,
+                        "started_at": discovery_event.started_at,
+                        "error": discovery_event.metadata.get("error"),
+                    },
+                }),
+            ))
+        }
+        Event::Auth(_) | Event::Telemetry(_) => None,
+    }
+}
+
+pub struct WebhookService {
+    storage: Arc<GenericPostgresStorage<Webhook>>,
+    delivery_storage: Arc<GenericPostgresStorage<WebhookDelivery>>,
+    network_service: Arc<NetworkService>,
+    event_bus: Arc<EventBus>,
+    client: reqwest::Client,
+    /// Allow loopback, link-local and metadata targets (`allow_private_webhook_targets`)
+    allow_private_targets: bool,
+}
+
+impl EventBusService<Webhook> for WebhookService {
+    fn event_bus(&self) -> &Arc<EventBus> {
+        &self.event_bus
+    }
+
+    fn get_network_id(&self, _entity: &Webhook) -> Option<Uuid> {
+        None
+    }
+
+    fn get_organization_id(&self, entity: &Webhook) -> Option<Uuid> {
+        Some(entity.base.organization_id)
+    }
+}
+
+impl CrudService<Webhook> for WebhookService {
+    fn storage(&self) -> &Arc<GenericPostgresStorage<Webhook>> {
+        &self.storage
+    }
+
+    fn entity_tag_service(&self) -> Option<&Arc<EntityTagService>> {
+        None
+    }
+}
+
+impl WebhookService {
+    pub fn new(
+        storage: Arc<GenericPostgresStorage<Webhook>>,
+        delivery_storage: Arc<GenericPostgresStorage<WebhookDelivery>>,
+        network_service: Arc<NetworkService>,
+        event_bus: Arc<EventBus>,
+        allow_private_targets: bool,
+    ) -> Self {
+        Self {
+            storage,
+            delivery_storage,
+            network_service,
+            event_bus,
+            client: Self::build_client(allow_private_targets),
+            allow_private_targets,
+        }
+    }
+
+    /// Redirects aren't followed, so they can't lead past the target check
+    fn build_client(allow_private_targets: bool) -> reqwest::Client {
+        let builder = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .redirect(reqwest::redirect::Policy::none())
+            .user_agent(concat!("Scanopy-Webhooks/", env!("CARGO_PKG_VERSION")));
+        let builder = if allow_private_targets {
+            builder
+        } else {
+            builder.dns_resolver(Arc::new(WebhookResolver))
+        };
+        builder.build().expect("Failed to create HTTP client")
+    }
+
+    /// Check a webhook URL against the server's private target setting
+    pub async fn check_target(&self, url: &str) -> Result<(), String> {
+        check_webhook_target(url, self.allow_private_targets).await
+    }
+
+    /// Organization each event belongs to. Network-scoped events are resolved via their
+    /// network, with one lookup for the whole batch.
+    async fn organizations_for_events(&self, events: &[Event]) -> Result<Vec<Option<Uuid>>> {
+        let network_ids: Vec<Uuid> = events
+            .iter()
+            .filter(|e| e.org_id().is_none())
+            .filter_map(|e| e.network_id())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+
+        let network_orgs: HashMap<Uuid, Uuid> = if network_ids.is_empty() {
+            HashMap::new()
+        } else {
+            self.network_service
+                .get_all(StorableFilter::<Network>::new_from_entity_ids(&network_ids))
+                .await?
+                .into_iter()
+                .map(|n| (n.id, n.base.organization_id))
+                .collect()
+        };
+
+        Ok(events
+            .iter()
+            .map(|e| {
+                e.org_id()
+                    .or_else(|| e.network_id().and_then(|id| network_orgs.get(&id).copied()))
+            })
+            .collect())
+    }
+
+    /// Enabled webhooks of the given organizations, keyed by organization.
+    /// Organizations without any are left out.
+    async fn enabled_webhooks_by_org(
+        &self,
+        organization_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Vec<Webhook>>> {
+        let mut by_org: HashMap<Uuid, Vec<Webhook>> = HashMap::new();
+        if organization_ids.is_empty() {
+            return Ok(by_org);
+        }
+
+        for webhook in self
+            .storage
+            .get_all(StorableFilter::<Webhook>::new_from_uuids_column(
+                "organization_id",
+                organization_ids,
+            ))
+            .await?
+            .into_iter()
+            .filter(|w| w.base.enabled)
+        {
+            by_org
+                .entry(webhook.base.organization_id)
+                .or_default()
+                .push(webhook);
+        }
+
+        Ok(by_org)
+    }
+
+    /// Record a pending delivery for each of the organization's enabled `webhooks` whose
+    /// filter matches. Deliveries are written to storage directly so the log doesn't feed
+    /// back into the event bus.
+    async fn enqueue(
+        &self,
+        event: &Event,
+        organization_id: Uuid,
+        webhooks: &[Webhook],
+    ) -> Result<Vec<(Webhook, WebhookDelivery)>> {
+        let mut queued = Vec::new();
+        for webhook in webhooks
+            .iter()
+            .filter(|w| w.base.event_filter.matches(event))
+        {
+            let Some((event_type, payload)) = build_payload(event, organization_id) else {
+                continue;
+            };
+
+            let delivery = self
+                .delivery_storage
+                .create(&WebhookDelivery::new(WebhookDeliveryBase {
+                    webhook_id: webhook.id,
+                    organization_id,
+                    event_id: event.id(),
+                    event_type,
+                    payload,
+                    status: WebhookDeliveryStatus::Pending,
+                    attempts: 0,
+                    next_attempt_at: Some(Utc::now()),
+                    response_status: None,
+                    error: None,
+                    delivered_at: None,
+                }))
+                .await?;
+
+            queued.push((webhook.clone(), delivery));
+        }
+
+        Ok(queued)
+    }
+
+    /// POST the delivery's payload and record the outcome. Failed attempts are
+    /// scheduled for retry with backoff until `MAX_DELIVERY_ATTEMPTS`, unless `retry` is false.
+    pub async fn attempt_delivery(
+        &self,
+        webhook: &Webhook,
+        mut delivery: WebhookDelivery,
+        retry: bool,
+    ) -> Result<WebhookDelivery> {
+        let body = serde_json::to_vec(&delivery.base.payload)?;
+        let now = Utc::now();
+        let signature = sign_payload(webhook.base.secret.expose_secret(), now.timestamp(), &body);
+
+        // Checked again on every attempt, since DNS may have changed since the webhook was
+        // saved. The client's resolver applies the same check to the address it connects to.
+        let result = match self.check_target(&webhook.base.url).await {
+            Ok(()) => Ok(self
+                .client
+                .post(&webhook.base.url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .header(EVENT_HEADER, &delivery.base.event_type)
+                .header(DELIVERY_HEADER, delivery.id.to_string())
+                .header(TIMESTAMP_HEADER, now.timestamp().to_string())
+                .header(SIGNATURE_HEADER, signature)
+                .body(body)
+                .send()
+                .await),
+            Err(e) => Err(e),
+        };
+
+        delivery.base.attempts += 1;
+
+        let error = match result {
+            Err(blocked) => {
+                delivery.base.response_status = None;
+                Some(blocked)
+            }
+            Ok(Ok(response)) => {
+                let status = response.status();
+                delivery.base.response_status = Some(status.as_u16());
+                if status.is_success() {
+                    None
+                } else {
+                    Some(format!("Endpoint responded with {}", status))
+                }
+            }
+            Ok(Err(e)) => {
+                delivery.base.response_status = None;
+                Some(e.to_string())
+            }
+        };
+
+        match error {
+            None => {
+                delivery.base.status = WebhookDeliveryStatus::Succeeded;
+                delivery.base.error = None;
+                delivery.base.next_attempt_at = None;
+                delivery.base.delivered_at = Some(Utc::now());
+            }
+            Some(error) => {
+                let exhausted = !retry || delivery.base.attempts >= MAX_DELIVERY_ATTEMPTS;
+                delivery.base.error = Some(error);
+                if exhausted {
+                    delivery.base.status = WebhookDeliveryStatus::Failed;
+                    delivery.base.next_attempt_at = None;
+                } else {
+                    delivery.base.next_attempt_at =
+                        Some(Utc::now() + retry_backoff(delivery.base.attempts));
+                }
+            }
+        }
+
+        let delivery = self.delivery_storage.update(&mut delivery).await?;
+
+        if delivery.base.status == WebhookDeliveryStatus::Failed {
+            tracing::warn!(
+                webhook_id = %webhook.id,
+                delivery_id = %delivery.id,
+                event_type = %delivery.base.event_type,
+                error = ?delivery.base.error,
+                "Webhook delivery failed"
+            );
+        }
+
+        Ok(delivery)
+    }
+
+    /// Enqueue and attempt deliveries for a batch of bus events
+    pub async fn dispatch(&self, events: &[Event]) -> Result<()> {
+        let organization_ids = self.organizations_for_events(events).await?;
+        let unique_organization_ids: Vec<Uuid> = organization_ids
+            .iter()
+            .flatten()
+            .copied()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let webhooks = self
+            .enabled_webhooks_by_org(&unique_organization_ids)
+            .await?;
+
+        let mut queued = Vec::new();
+        for (event, organization_id) in events.iter().zip(organization_ids) {
+            let Some((organization_id, org_webhooks)) =
+                organization_id.and_then(|id| webhooks.get(&id).map(|w| (id, w)))
+            else {
+                continue;
+            };
+            queued.extend(self.enqueue(event, organization_id, org_webhooks).await?);
+        }
+
+        let results = join_all(
+            queued
+                .iter()
+                .map(|(webhook, delivery)| self.attempt_delivery(webhook, delivery.clone(), true)),
+        )
+        .await;
+
+        for result in results {
+            if let Err(e) = result {
+                tracing::error!(error = %e, "Failed to record webhook delivery attempt");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Send a `webhook.test` payload once, without retries
+    pub async fn send_test(&self, webhook: &Webhook) -> Result<WebhookDelivery> {
+        let event_id = Uuid::new_v4();
+        let now = Utc::now();
+
+        let delivery = self
+            .delivery_storage
+            .create(&WebhookDelivery::new(WebhookDeliveryBase {
+                webhook_id: webhook.id,
+                organization_id: webhook.base.organization_id,
+                event_id,
+                event_type: "webhook.test".to_string(),
+                payload: json!({
+                    "id": event_id,
+                    "type": "webhook.test",
+                    "timestamp": now,
+                    "organization_id": webhook.base.organization_id,
+                    "network_id": null,
+                    "data": {
+                        "webhook_id": webhook.id,
+                        "name": webhook.base.name,
+                    },
+                }),
+                status: WebhookDeliveryStatus::Pending,
+                attempts: 0,
+                next_attempt_at: None,
+                response_status: None,
+                error: None,
+                delivered_at: None,
+            }))
+            .await?;
+
+        self.attempt_delivery(webhook, delivery, false).await
+    }
+
+    /// Delivery log for a webhook, newest first
+    pub async fn get_deliveries(
+        &self,
+        filter: StorableFilter<WebhookDelivery>,
+    ) -> Result<PaginatedResult<WebhookDelivery>> {
+        self.delivery_storage
+            .get_paginated(filter, "webhook_deliveries.created_at DESC")
+            .await
+    }
+
+    /// Retry deliveries whose backoff has elapsed and prune old log entries.
+    /// Called periodically from the server's background tasks.
+    pub async fn retry_due_deliveries(&self) {
+        if let Err(e) = self.retry_due_deliveries_inner().await {
+            tracing::error!("Failed to retry webhook deliveries: {}", e);
+        }
+
+        let cutoff = Utc::now() - ChronoDuration::days(DELIVERY_RETENTION_DAYS);
+        match self
+            .delivery_storage
+            .delete_by_filter(
+                StorableFilter::<WebhookDelivery>::new_unscoped().created_before(cutoff),
+            )
+            .await
+        {
+            Ok(0) => {}
+            Ok(count) => tracing::debug!("Pruned {} old webhook deliveries.", count),
+            Err(e) => tracing::error!("Failed to prune webhook deliveries: {}", e),
+        }
+    }
+
+    async fn retry_due_deliveries_inner(&self) -> Result<(), Error> {
+        let due = self
+            .delivery_storage
+            .get_all_ordered(
+                StorableFilter::<WebhookDelivery>::new_unscoped()
+                    .delivery_due(Utc::now())
+                    .limit(RETRY_BATCH_SIZE),
+                "webhook_deliveries.next_attempt_at ASC",
+            )
+            .await?;
+
+        if due.is_empty() {
+            return Ok(());
+        }
+
+        let webhook_ids: Vec<Uuid> = due.iter().map(|d| d.base.webhook_id).collect();
+        let webhooks: HashMap<Uuid, Webhook> = self
+            .storage
+            .get_all(StorableFilter::<Webhook>::new_from_entity_ids(&webhook_ids))
+            .await?
+            .into_iter()
+            .map(|w| (w.id, w))
+            .collect();
+
+        let mut attempts = Vec::new();
+        for mut delivery in due {
+            match webhooks.get(&delivery.base.webhook_id) {
+                Some(webhook) if webhook.base.enabled => {
+                    attempts.push(self.attempt_delivery(webhook, delivery, true));
+                }
+                _ => {
+                    // Disabled since the delivery was queued
+                    delivery.base.status = WebhookDeliveryStatus::Failed;
+                    delivery.base.next_attempt_at = None;
+                    delivery.base.error = Some("Webhook disabled".to_string());
+                    self.delivery_storage.update(&mut delivery).await?;
+                }
+            }
+        }
+
+        for result in join_all(attempts).await {
+            if let Err(e) = result {
+                tracing::error!(error = %e, "Failed to record webhook delivery attempt");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::{
+        auth::middleware::auth::AuthenticatedEntity,
+        shared::{
+            entities::EntityDiscriminants,
+            events::types::{EntityEvent, EntityOperation},
+        },
+        tags::r#impl::base::Tag,
+        webhooks::r#impl::base::WebhookEventFilter,
+    };
+
+    fn tag_event(operation: EntityOperation) -> Event {
+        let tag = Tag::default();
+        Event::Entity(Box::new(EntityEvent {
+            id: Uuid::new_v4(),
+            entity_id: tag.id,
+            entity_type: tag.into(),
+            network_id: None,
+            organization_id: None,
+            operation,
+            timestamp: Utc::now(),
+            authentication: AuthenticatedEntity::System,
+            metadata: json!({}),
+        }))
+    }
+
+    #[test]
+    fn test_sign_payload() {
+        assert_eq!(
+            sign_payload(
+                "0123456789abcdef",
+                1_700_000_000,
+                br#"{"type":"webhook.test"}"#
+            ),
+            "sha256=e76f0fda0be973bb360c6ab5b0767bd2dd6d4368d8d80495638a9389c28c4190"
+        );
+    }
+
+    #[test]
+    fn test_blocked_webhook_ips() {
+        for ip in [
+            "127.0.0.1",
+            "169.254.169.254",
+            "0.0.0.0",
+            "::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+            "fd00:ec2::254",
+        ] {
+            assert!(is_blocked_webhook_ip(ip.parse().unwrap()), "{}", ip);
+        }
+        for ip in ["192.168.1.10", "10.0.0.5", "203.0.113.7", "2001:db8::1"] {
+            assert!(!is_blocked_webhook_ip(ip.parse().unwrap()), "{}", ip);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_check_webhook_target() {
+        assert!(
+            check_webhook_target("http://169.254.169.254/latest/meta-data", false)
+                .await
+                .is_err()
+        );
+        assert!(
+            check_webhook_target("http://[::1]:8080/hook", false)
+                .await
+                .is_err()
+        );
+        assert!(
+            check_webhook_target("http://localhost:3000/hook", false)
+                .await
+                .is_err()
+        );
+        assert!(
+            check_webhook_target("https://192.168.1.20/hook", false)
+                .await
+                .is_ok()
+        );
+
+        // Allowed when the server opts in
+        assert!(
+            check_webhook_target("http://127.0.0.1:3000/hook", true)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_webhook_resolver_drops_blocked_addresses() {
+        use reqwest::dns::Resolve;
+
+        let name: reqwest::dns::Name = "localhost".parse().unwrap();
+        assert!(WebhookResolver.resolve(name).await.is_err());
+    }
+
+    #[test]
+    fn test_retry_backoff() {
+        assert_eq!(retry_backoff(1), ChronoDuration::seconds(30));
+        assert_eq!(retry_backoff(2), ChronoDuration::minutes(2));
+        assert_eq!(retry_backoff(4), ChronoDuration::minutes(32));
+    }
+
+    #[test]
+    fn test_event_filter_matches() {
+        let filter = WebhookEventFilter {
+            entity_types: vec![EntityDiscriminants::Tag],
+            ..Default::default()
+        };
+
+        assert!(filter.matches(&tag_event(EntityOperation::Created)));
+        assert!(filter.matches(&tag_event(EntityOperation::Deleted)));
+        assert!(!filter.matches(&tag_event(EntityOperation::Get)));
+
+        let updates_only = WebhookEventFilter {
+            entity_operations: vec![EntityOperation::Updated],
+            ..filter
+        };
+        assert!(!updates_only.matches(&tag_event(EntityOperation::Created)));
+        assert!(updates_only.matches(&tag_event(EntityOperation::Updated)));
+    }
+
+    #[test]
+    fn test_build_payload_unwraps_entity() {
+        let org_id = Uuid::new_v4();
+        let (event_type, payload) =
+            build_payload(&tag_event(EntityOperation::Created), org_id).unwrap();
+
+        assert_eq!(event_type, "tag.created");
+        assert_eq!(payload["type"], "tag.created");
+        assert_eq!(payload["organization_id"], json!(org_id));
+        assert_eq!(payload["data"]["entity_type"], "Tag");
+        assert!(payload["data"]["entity"].get("id").is_some());
+    }
+}
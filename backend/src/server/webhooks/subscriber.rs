@@ -0,0 +1,53 @@
+//! Event subscriber implementation for WebhookService.
+//!
+//! Turns bus events into webhook deliveries. Events are batched so slow endpoints
+//! never hold up the request that published the event.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use strum::IntoEnumIterator;
+
+use crate::server::shared::entities::EntityDiscriminants;
+use crate::server::shared::events::bus::{EventFilter, EventSubscriber};
+use crate::server::shared::events::types::{EntityOperation, Event};
+use crate::server::webhooks::r#impl::base::is_webhook_subscribable;
+use crate::server::webhooks::service::WebhookService;
+
+#[async_trait]
+impl EventSubscriber for WebhookService {
+    fn event_filter(&self) -> EventFilter {
+        let entity_operations = EntityDiscriminants::iter()
+            .filter(|t| is_webhook_subscribable(*t))
+            .map(|t| {
+                (
+                    t,
+                    Some(vec![
+                        EntityOperation::Created,
+                        EntityOperation::Updated,
+                        EntityOperation::Deleted,
+                    ]),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+
+        EventFilter {
+            entity_operations: Some(entity_operations),
+            auth_operations: Some(vec![]),
+            telemetry_operations: Some(vec![]),
+            discovery_phases: None,
+            network_ids: None,
+        }
+    }
+
+    async fn handle_events(&self, events: Vec<Event>) -> Result<(), anyhow::Error> {
+        self.dispatch(&events).await
+    }
+
+    fn debounce_window_ms(&self) -> u64 {
+        1000
+    }
+
+    fn name(&self) -> &str {
+        "webhooks"
+    }
+}
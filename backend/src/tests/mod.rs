@@ -108,6 +108,8 @@ pub fn interface(network_id: &Uuid, subnet_id: &Uuid) -> Interface {
         ip_address: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 100)),
         mac_address: None, // MAC populated during ARP discovery
         position: 0,
+        first_seen: None,
+        last_seen: None,
         name: Some("eth0".to_string()),
         host_id: Uuid::nil(), // Placeholder - tests will set correct host_id
     })
@@ -147,6 +149,7 @@ pub fn service(network_id: &Uuid, host_id: &Uuid) -> Service {
         source: EntitySource::System,
         tags: Vec::new(),
         position: 0,
+        proxy_targets: Vec::new(),
     })
 }
 
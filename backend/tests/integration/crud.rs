@@ -1,15 +1,27 @@
 use crate::infra::{BASE_URL, TestContext};
 use cidr::{IpCidr, Ipv4Cidr};
 use reqwest::StatusCode;
+use scanopy::server::custom_scan_profiles::r#impl::base::{
+    CustomScanProfile, CustomScanProfileBase,
+};
+use scanopy::server::custom_service_definitions::r#impl::base::{
+    CustomServiceDefinition, CustomServiceDefinitionBase,
+};
+use scanopy::server::custom_service_definitions::r#impl::patterns::CustomPattern;
 use scanopy::server::daemon_api_keys::r#impl::api::DaemonApiKeyResponse;
 use scanopy::server::daemon_api_keys::r#impl::base::{DaemonApiKey, DaemonApiKeyBase};
 use scanopy::server::discovery::r#impl::base::{Discovery, DiscoveryBase};
-use scanopy::server::discovery::r#impl::types::{DiscoveryType, HostNamingFallback, RunType};
+use scanopy::server::discovery::r#impl::scan_profile::{PortRange, ScanProfile};
+use scanopy::server::discovery::r#impl::types::{
+    DiscoveryType, HostNamingFallback, HostnameSource, RunType,
+};
 use scanopy::server::groups::r#impl::base::{Group, GroupBase};
 use scanopy::server::groups::r#impl::types::GroupType;
 use scanopy::server::hosts::r#impl::api::{CreateHostRequest, HostResponse, UpdateHostRequest};
+use scanopy::server::ports::r#impl::base::PortType;
 use scanopy::server::services::definitions::ServiceDefinitionRegistry;
 use scanopy::server::services::r#impl::base::{Service, ServiceBase};
+use scanopy::server::services::r#impl::categories::ServiceCategory;
 use scanopy::server::shared::storage::traits::Storable;
 use scanopy::server::shared::types::Color;
 use scanopy::server::shared::types::entities::EntitySource;
@@ -38,6 +50,8 @@ pub async fn run_crud_tests(ctx: &TestContext) -> Result<(), String> {
     test_group_crud(ctx).await?;
     test_tag_crud(ctx).await?;
     test_snmp_credential_crud(ctx).await?;
+    test_custom_service_definition_crud(ctx).await?;
+    test_custom_scan_profile_crud(ctx).await?;
     test_discovery_crud(ctx).await?;
     test_api_key_crud(ctx).await?;
     test_user_api_key_crud(ctx).await?;
@@ -128,6 +142,7 @@ async fn test_host_crud(ctx: &TestContext) -> Result<(), String> {
         management_url: None,
         chassis_id: None,
         snmp_credential_id: None,
+        hardware_inventory: Vec::new(),
         interfaces: vec![],
         ports: vec![],
         services: vec![],
@@ -198,6 +213,7 @@ async fn test_service_crud(ctx: &TestContext) -> Result<(), String> {
         management_url: None,
         chassis_id: None,
         snmp_credential_id: None,
+        hardware_inventory: Vec::new(),
         interfaces: vec![],
         ports: vec![],
         services: vec![],
@@ -218,6 +234,7 @@ async fn test_service_crud(ctx: &TestContext) -> Result<(), String> {
         source: EntitySource::System,
         tags: Vec::new(),
         position: 0,
+        proxy_targets: Vec::new(),
     });
 
     let created: Service = ctx.client.post("/api/v1/services", &service).await?;
@@ -357,6 +374,7 @@ async fn test_snmp_credential_crud(ctx: &TestContext) -> Result<(), String> {
         version: SnmpVersion::V2c,
         community: SecretString::from("test-community"),
         tags: Vec::new(),
+        ..Default::default()
     });
 
     let created: SnmpCredential = ctx
@@ -410,6 +428,228 @@ async fn test_snmp_credential_crud(ctx: &TestContext) -> Result<(), String> {
     Ok(())
 }
 
+async fn test_custom_service_definition_crud(ctx: &TestContext) -> Result<(), String> {
+    println!("Testing Custom Service Definition CRUD...");
+
+    let definition = CustomServiceDefinition::new(CustomServiceDefinitionBase {
+        organization_id: ctx.organization_id,
+        name: "Billing API".to_string(),
+        category: ServiceCategory::Custom,
+        pattern: CustomPattern::AllOf {
+            patterns: vec![
+                CustomPattern::Port {
+                    port: PortType::new_tcp(8765),
+                },
+                CustomPattern::Endpoint {
+                    port: PortType::new_tcp(8765),
+                    path: "/health".to_string(),
+                    response: "billing-api".to_string(),
+                    status_codes: None,
+                },
+            ],
+        },
+        ..Default::default()
+    });
+
+    let created: CustomServiceDefinition = ctx
+        .client
+        .post("/api/v1/custom-service-definitions", &definition)
+        .await?;
+    assert!(!created.id.is_nil());
+    assert_eq!(created.base.pattern, definition.base.pattern);
+    println!("  ✓ Create custom service definition");
+
+    let metadata: Vec<serde_json::Value> = ctx
+        .client
+        .get("/api/v1/custom-service-definitions/metadata")
+        .await?;
+    let definition_id = format!("Custom:{}", created.id);
+    assert!(metadata.iter().any(|m| m["id"] == definition_id.as_str()));
+    println!("  ✓ Definition metadata");
+
+    let result = ctx
+        .client
+        .post_expect_status(
+            "/api/v1/custom-service-definitions",
+            &definition,
+            StatusCode::CONFLICT,
+        )
+        .await;
+    assert!(result.is_ok(), "Duplicate name should return 409");
+    println!("  ✓ Reject duplicate name");
+
+    let mut invalid = definition.clone();
+    invalid.base.name = "Subnet Only".to_string();
+    invalid.base.pattern = CustomPattern::SubnetIsType {
+        subnet_type: SubnetType::Lan,
+    };
+    let result = ctx
+        .client
+        .post_expect_status(
+            "/api/v1/custom-service-definitions",
+            &invalid,
+            StatusCode::BAD_REQUEST,
+        )
+        .await;
+    assert!(
+        result.is_ok(),
+        "Pattern without a positive match should return 400"
+    );
+    println!("  ✓ Reject pattern that matches every host");
+
+    let mut updated = created.clone();
+    updated.base.description = "Internal billing service".to_string();
+    let updated: CustomServiceDefinition = ctx
+        .client
+        .put(
+            &format!("/api/v1/custom-service-definitions/{}", updated.id),
+            &updated,
+        )
+        .await?;
+    assert_eq!(updated.base.description, "Internal billing service");
+    println!("  ✓ Update custom service definition");
+
+    let definitions: Vec<CustomServiceDefinition> =
+        ctx.client.get("/api/v1/custom-service-definitions").await?;
+    assert!(definitions.iter().any(|d| d.id == created.id));
+    println!("  ✓ List custom service definitions");
+
+    ctx.client
+        .delete_no_content(&format!(
+            "/api/v1/custom-service-definitions/{}",
+            created.id
+        ))
+        .await?;
+    println!("  ✓ Delete custom service definition");
+
+    println!("✅ Custom Service Definition CRUD passed");
+    Ok(())
+}
+
+async fn test_custom_scan_profile_crud(ctx: &TestContext) -> Result<(), String> {
+    println!("Testing Custom Scan Profile CRUD...");
+
+    let profile = CustomScanProfile::new(CustomScanProfileBase {
+        organization_id: ctx.organization_id,
+        name: "Hourly Quick".to_string(),
+        settings: ScanProfile::Quick.settings(),
+        ..Default::default()
+    });
+
+    let created: CustomScanProfile = ctx
+        .client
+        .post("/api/v1/custom-scan-profiles", &profile)
+        .await?;
+    assert!(!created.id.is_nil());
+    assert_eq!(created.base.settings, profile.base.settings);
+    println!("  ✓ Create custom scan profile");
+
+    let result = ctx
+        .client
+        .post_expect_status(
+            "/api/v1/custom-scan-profiles",
+            &profile,
+            StatusCode::CONFLICT,
+        )
+        .await;
+    assert!(result.is_ok(), "Duplicate name should return 409");
+    println!("  ✓ Reject duplicate name");
+
+    let mut invalid = profile.clone();
+    invalid.base.name = "Backwards Range".to_string();
+    invalid.base.settings.tcp_ports.ranges = vec![PortRange {
+        start: 1000,
+        end: 10,
+    }];
+    let result = ctx
+        .client
+        .post_expect_status(
+            "/api/v1/custom-scan-profiles",
+            &invalid,
+            StatusCode::BAD_REQUEST,
+        )
+        .await;
+    assert!(result.is_ok(), "Invalid port range should return 400");
+    println!("  ✓ Reject invalid port range");
+
+    let mut updated = created.clone();
+    updated.base.settings.host_timeout_secs = Some(30);
+    let updated: CustomScanProfile = ctx
+        .client
+        .put(
+            &format!("/api/v1/custom-scan-profiles/{}", updated.id),
+            &updated,
+        )
+        .await?;
+    assert_eq!(updated.base.settings.host_timeout_secs, Some(30));
+    println!("  ✓ Update custom scan profile");
+
+    let daemons: Vec<serde_json::Value> = ctx.client.get("/api/v1/daemons").await?;
+    let daemon_id = daemons
+        .first()
+        .and_then(|d| d.get("id"))
+        .and_then(|id| id.as_str())
+        .and_then(|s| Uuid::parse_str(s).ok())
+        .ok_or("No daemon found for scan profile test")?;
+
+    let discovery = Discovery {
+        id: Uuid::nil(),
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+        base: DiscoveryBase {
+            discovery_type: DiscoveryType::Network {
+                subnet_ids: None,
+                host_naming_fallback: HostNamingFallback::BestService,
+                snmp_credentials: SnmpCredentialMapping::default(),
+                custom_service_definitions: Vec::new(),
+                probe_raw_socket_ports: false,
+                scan_profile: ScanProfile::Custom {
+                    profile_id: created.id,
+                    settings: Some(Box::new(ScanProfile::Full.settings())),
+                },
+                exclusions: Vec::new(),
+                hostname_sources: HostnameSource::default_order(),
+            },
+            run_type: RunType::AdHoc { last_run: None },
+            name: "Scan Profile Test Discovery".to_string(),
+            daemon_id,
+            network_id: ctx.network_id,
+            tags: vec![],
+        },
+    };
+
+    let discovery: Discovery = ctx.client.post("/api/v1/discovery", &discovery).await?;
+    assert!(matches!(
+        discovery.base.discovery_type,
+        DiscoveryType::Network {
+            scan_profile: ScanProfile::Custom { settings: None, .. },
+            ..
+        }
+    ));
+    println!("  ✓ Discovery stores only the profile reference");
+
+    let result = ctx
+        .client
+        .delete_no_content(&format!("/api/v1/custom-scan-profiles/{}", created.id))
+        .await;
+    assert!(
+        result.is_err_and(|e| e.contains("409")),
+        "Deleting a profile used by a discovery should return 409"
+    );
+    println!("  ✓ Reject deleting a profile in use");
+
+    ctx.client
+        .delete_no_content(&format!("/api/v1/discovery/{}", discovery.id))
+        .await?;
+    ctx.client
+        .delete_no_content(&format!("/api/v1/custom-scan-profiles/{}", created.id))
+        .await?;
+    println!("  ✓ Delete custom scan profile");
+
+    println!("✅ Custom Scan Profile CRUD passed");
+    Ok(())
+}
+
 async fn test_discovery_crud(ctx: &TestContext) -> Result<(), String> {
     println!("Testing Discovery CRUD...");
 
@@ -432,7 +672,11 @@ async fn test_discovery_crud(ctx: &TestContext) -> Result<(), String> {
                 subnet_ids: None,
                 host_naming_fallback: HostNamingFallback::BestService,
                 snmp_credentials: SnmpCredentialMapping::default(),
+                custom_service_definitions: Vec::new(),
                 probe_raw_socket_ports: false,
+                scan_profile: ScanProfile::default(),
+                exclusions: Vec::new(),
+                hostname_sources: HostnameSource::default_order(),
             },
             run_type: RunType::AdHoc { last_run: None },
             name: "CRUD Test Discovery".to_string(),
@@ -3,8 +3,13 @@
 use crate::infra::{BASE_URL, TestClient, retry};
 use scanopy::server::daemons::r#impl::api::DiscoveryUpdatePayload;
 use scanopy::server::discovery::r#impl::base::{Discovery, DiscoveryBase};
-use scanopy::server::discovery::r#impl::types::{DiscoveryType, HostNamingFallback, RunType};
+use scanopy::server::discovery::r#impl::scan_profile::ScanProfile;
+use scanopy::server::discovery::r#impl::types::{
+    DiscoveryType, HostNamingFallback, HostnameSource, RunType,
+};
 use scanopy::server::groups::r#impl::base::{Group, GroupBase};
+use scanopy::server::host_changes::r#impl::base::{HostChange, HostChangeType};
+use scanopy::server::hosts::r#impl::api::HostResponse;
 use scanopy::server::services::definitions::home_assistant::HomeAssistant;
 use scanopy::server::services::r#impl::base::Service;
 use scanopy::server::shared::entities::EntityDiscriminants;
@@ -34,7 +39,11 @@ pub async fn trigger_discovery(
                 subnet_ids: None, // Discover all subnets on the network
                 host_naming_fallback: HostNamingFallback::BestService,
                 snmp_credentials: SnmpCredentialMapping::default(),
+                custom_service_definitions: Vec::new(),
                 probe_raw_socket_ports: false,
+                scan_profile: ScanProfile::default(),
+                exclusions: Vec::new(),
+                hostname_sources: HostnameSource::default_order(),
             },
             run_type: RunType::AdHoc { last_run: None },
             name: "ServerPoll Integration Test Discovery".to_string(),
@@ -157,6 +166,62 @@ pub async fn verify_home_assistant_discovered(client: &TestClient) -> Result<Ser
     .await
 }
 
+pub async fn verify_host_changes_recorded(
+    client: &TestClient,
+    host_id: Uuid,
+) -> Result<Vec<HostChange>, String> {
+    println!("\n=== Verifying Host Change History ===");
+
+    let changes: Vec<HostChange> = client
+        .get(&format!("/api/v1/host-changes?host_id={}", host_id))
+        .await?;
+
+    if !changes
+        .iter()
+        .any(|c| c.base.change_type == HostChangeType::HostAppeared)
+    {
+        return Err(format!(
+            "Expected HostAppeared change for host {}, got {:?}",
+            host_id,
+            changes
+                .iter()
+                .map(|c| c.base.change_type)
+                .collect::<Vec<_>>()
+        ));
+    }
+
+    println!("✅ Found {} change(s) for host", changes.len());
+    Ok(changes)
+}
+
+pub async fn verify_host_liveness(client: &TestClient, host_id: Uuid) -> Result<(), String> {
+    println!("\n=== Verifying Host Liveness ===");
+
+    let host: HostResponse = client.get(&format!("/api/v1/hosts/{}", host_id)).await?;
+
+    let (Some(first_seen), Some(last_seen)) = (host.first_seen, host.last_seen) else {
+        return Err(format!(
+            "Expected discovered host {} to have first_seen/last_seen, got {:?}/{:?}",
+            host_id, host.first_seen, host.last_seen
+        ));
+    };
+    if first_seen > last_seen || host.offline || host.missed_scans != 0 {
+        return Err(format!(
+            "Unexpected liveness for freshly discovered host {}: first_seen={}, last_seen={}, missed_scans={}, offline={}",
+            host_id, first_seen, last_seen, host.missed_scans, host.offline
+        ));
+    }
+    if !host.interfaces.iter().any(|i| i.base.last_seen.is_some()) {
+        return Err(format!(
+            "Expected an interface on host {} with last_seen",
+            host_id
+        ));
+    }
+
+    println!("✅ Host last seen at {}", last_seen);
+    Ok(())
+}
+
 pub async fn create_group(client: &TestClient, network_id: Uuid) -> Result<Group, String> {
     println!("\n=== Creating Group ===");
 
@@ -1,8 +1,12 @@
+use scanopy::server::audit_logs::r#impl::base::AuditLogEntry;
 use scanopy::server::bindings::r#impl::base::Binding;
+use scanopy::server::custom_scan_profiles::r#impl::base::CustomScanProfile;
+use scanopy::server::custom_service_definitions::r#impl::base::CustomServiceDefinition;
 use scanopy::server::daemon_api_keys::r#impl::base::DaemonApiKey;
 use scanopy::server::daemons::r#impl::base::Daemon;
 use scanopy::server::discovery::r#impl::base::Discovery;
 use scanopy::server::groups::r#impl::base::Group;
+use scanopy::server::host_changes::r#impl::base::HostChange;
 use scanopy::server::hosts::r#impl::base::Host;
 use scanopy::server::if_entries::r#impl::base::IfEntry;
 use scanopy::server::interfaces::r#impl::base::Interface;
@@ -20,9 +24,12 @@ use scanopy::server::shares::r#impl::base::Share;
 use scanopy::server::snmp_credentials::r#impl::base::SnmpCredential;
 use scanopy::server::subnets::r#impl::base::Subnet;
 use scanopy::server::tags::r#impl::base::Tag;
+use scanopy::server::tls_certificates::r#impl::base::TlsCertificate;
 use scanopy::server::topology::types::base::Topology;
+use scanopy::server::topology_snapshots::r#impl::base::TopologySnapshot;
 use scanopy::server::user_api_keys::r#impl::base::UserApiKey;
 use scanopy::server::users::r#impl::base::User;
+use scanopy::server::webhooks::r#impl::base::{Webhook, WebhookDelivery};
 use serde::Serialize;
 
 /// Generate all fixtures (requires Docker containers to be running, except OpenAPI)
@@ -349,6 +356,9 @@ async fn generate_entity_metadata_json() -> Result<(), Box<dyn std::error::Error
         EntityMetadataEntry::new::<User>("user"),
         EntityMetadataEntry::new::<Invite>("invite"),
         EntityMetadataEntry::new::<UserApiKey>("user_api_key"),
+        EntityMetadataEntry::new::<Webhook>("webhook"),
+        EntityMetadataEntry::new::<WebhookDelivery>("webhook_delivery"),
+        EntityMetadataEntry::new::<AuditLogEntry>("audit_log_entry"),
         // Network Infrastructure
         EntityMetadataEntry::new::<Network>("network"),
         EntityMetadataEntry::new::<Host>("host"),
@@ -358,14 +368,19 @@ async fn generate_entity_metadata_json() -> Result<(), Box<dyn std::error::Error
         EntityMetadataEntry::new::<Service>("service"),
         EntityMetadataEntry::new::<Binding>("binding"),
         EntityMetadataEntry::new::<IfEntry>("if_entry"),
+        EntityMetadataEntry::new::<TlsCertificate>("tls_certificate"),
         // Discovery & Daemons
         EntityMetadataEntry::new::<Daemon>("daemon"),
         EntityMetadataEntry::new::<DaemonApiKey>("daemon_api_key"),
         EntityMetadataEntry::new::<Discovery>("discovery"),
         EntityMetadataEntry::new::<SnmpCredential>("snmp_credential"),
+        EntityMetadataEntry::new::<CustomServiceDefinition>("custom_service_definition"),
+        EntityMetadataEntry::new::<CustomScanProfile>("custom_scan_profile"),
+        EntityMetadataEntry::new::<HostChange>("host_change"),
         // Visualization
         EntityMetadataEntry::new::<Group>("group"),
         EntityMetadataEntry::new::<Topology>("topology"),
+        EntityMetadataEntry::new::<TopologySnapshot>("topology_snapshot"),
         EntityMetadataEntry::new::<Share>("share"),
         // Metadata
         EntityMetadataEntry::new::<Tag>("tag"),
@@ -134,6 +134,14 @@ async fn integration_tests() {
         .await
         .expect("Failed to find Home Assistant after ServerPoll discovery");
 
+    discovery::verify_host_changes_recorded(&client, service.base.host_id)
+        .await
+        .expect("Host changes not recorded by discovery");
+
+    discovery::verify_host_liveness(&client, service.base.host_id)
+        .await
+        .expect("Host liveness not tracked by discovery");
+
     // Test creating user entities that reference discovered data
     let tag = discovery::create_tag(&client, organization.id)
         .await
@@ -139,6 +139,7 @@ async fn test_cannot_create_host_on_other_network(
         management_url: None,
         chassis_id: None,
         snmp_credential_id: None,
+        hardware_inventory: Vec::new(),
         interfaces: vec![],
         ports: vec![],
         services: vec![],
@@ -50,6 +50,7 @@ async fn test_service_network_validation(ctx: &TestContext) -> Result<(), String
         management_url: None,
         chassis_id: None,
         snmp_credential_id: None,
+        hardware_inventory: Vec::new(),
         interfaces: vec![],
         ports: vec![],
         services: vec![],
@@ -69,6 +70,7 @@ async fn test_service_network_validation(ctx: &TestContext) -> Result<(), String
         source: EntitySource::System,
         tags: Vec::new(),
         position: 0,
+        proxy_targets: Vec::new(),
     });
 
     let result = ctx
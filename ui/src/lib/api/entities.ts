@@ -27,6 +27,8 @@ export const entityToExportPath: Record<EntityDiscriminants, string | null> = {
 	Share: 'shares',
 	Discovery: 'discoveries',
 	Topology: 'topologies',
+	TopologySnapshot: 'topology-snapshots',
+	AuditLogEntry: 'audit-log',
 	User: 'users',
 	IfEntry: 'if-entries',
 	SnmpCredential: 'snmp-credentials',
@@ -131,6 +131,9 @@ export const queryKeys = {
 		all: ['snmpCredentials'] as const,
 		detail: (id: string) => [...queryKeys.snmpCredentials.all, 'detail', id] as const
 	},
+	customScanProfiles: {
+		all: ['customScanProfiles'] as const
+	},
 	ifEntries: {
 		all: ['ifEntries'] as const,
 		byHost: (hostId: string) => [...queryKeys.ifEntries.all, 'host', hostId] as const
@@ -539,6 +539,109 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/v1/audit-log": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * List all Audit Log Entries
+         * @description Returns the organization's audit log, newest first. Filter by `entity_id` for an
+         *     entity's history, by `user_id` or `actor_id` for what someone did, and by `from`/`to`
+         *     for a time range. Requires admin permissions.
+         */
+        get: operations["get_all_audit_log_entries"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/audit-log/export/csv": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Export Audit Log Entries to CSV
+         * @description Exports all entries matching the filter criteria. Ignores pagination parameters
+         *     (limit/offset). Requires admin permissions.
+         */
+        get: operations["export_audit_log_csv"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/audit-log/export/json": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Export Audit Log Entries to JSON
+         * @description Exports all entries matching the filter criteria as a JSON array, newest first.
+         *     Ignores pagination parameters (limit/offset). Requires admin permissions.
+         */
+        get: operations["export_audit_log_json"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/audit-log/settings": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Get audit log settings */
+        get: operations["get_audit_log_settings"];
+        /**
+         * Update audit log settings
+         * @description Entries older than the retention period are pruned hourly. The change is itself
+         *     recorded in the audit log as an organization update.
+         */
+        put: operations["update_audit_log_settings"];
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/audit-log/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Get Audit Log Entry by ID */
+        get: operations["get_audit_log_entry"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/v1/auth/daemon": {
         parameters: {
             query?: never;
@@ -822,6 +925,213 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/v1/custom-scan-profiles": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * List all Custom Scan Profiles
+         * @description Returns all custom scan profiles in the authenticated user's organization.
+         */
+        get: operations["get_all_custom_scan_profiles"];
+        put?: never;
+        /**
+         * Create a new Custom Scan Profile
+         * @description Creates a scan profile scoped to your organization. Network discoveries reference it with
+         *     a `Custom` scan profile, and the server sends its current settings to the daemon each
+         *     time one of them runs.
+         *
+         *     ### Validation
+         *
+         *     - Name must be 1-40 characters and unique within your organization
+         *     - Description must be at most 100 characters
+         *     - Port ranges must be within 1-65535 with start not exceeding end
+         *     - Host timeout, scan rate and batch size must be greater than 0 when set
+         */
+        post: operations["create_custom_scan_profile"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/custom-scan-profiles/bulk-delete": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /** Bulk delete Custom Scan Profiles */
+        post: operations["bulk_delete_custom_scan_profiles"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/custom-scan-profiles/export/csv": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Export Custom Scan Profiles to CSV
+         * @description Export all Custom Scan Profiles matching the filter criteria to CSV format. Ignores pagination parameters (limit/offset) and exports all matching records.
+         */
+        get: operations["export_Custom Scan Profiles_csv"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/custom-scan-profiles/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Get Custom Scan Profile by ID */
+        get: operations["get_Custom Scan Profile_by_id"];
+        /** Update Custom Scan Profile */
+        put: operations["update_Custom Scan Profile"];
+        post?: never;
+        /**
+         * Delete Custom Scan Profile
+         * @description Profiles used by a discovery can't be deleted until the discovery uses a different profile.
+         */
+        delete: operations["delete_custom_scan_profile"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/custom-service-definitions": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * List all Custom Service Definitions
+         * @description Returns all custom service definitions in the authenticated user's organization.
+         */
+        get: operations["get_all_custom_service_definitions"];
+        put?: never;
+        /**
+         * Create a new Custom Service Definition
+         * @description Creates a service definition scoped to your organization. It is shipped to daemons
+         *     with each network discovery and evaluated alongside the built-in definitions.
+         *
+         *     ### Validation
+         *
+         *     - Name must be 1-40 characters and unique within your organization
+         *     - Description must be at most 100 characters
+         *     - Scanopy and OpenPorts categories are reserved
+         *     - Pattern must include a Port, Endpoint, Header, Banner, MacVendor, mDNS or UPnP match, and
+         *       AnyOf/AllOf groups must not be empty
+         */
+        post: operations["create_custom_service_definition"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/custom-service-definitions/bulk-delete": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /** Bulk delete Custom Service Definitions */
+        post: operations["bulk_delete_custom_service_definitions"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/custom-service-definitions/export/csv": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Export Custom Service Definitions to CSV
+         * @description Export all Custom Service Definitions matching the filter criteria to CSV format. Ignores pagination parameters (limit/offset) and exports all matching records.
+         */
+        get: operations["export_Custom Service Definitions_csv"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/custom-service-definitions/metadata": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Get Custom Service Definition metadata
+         * @description Returns the organization's custom service definitions in the same shape as
+         *     `service_definitions` in `/api/metadata`, so clients can resolve services
+         *     matched by them.
+         */
+        get: operations["get_custom_service_definition_metadata"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/custom-service-definitions/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Get Custom Service Definition by ID */
+        get: operations["get_Custom Service Definition_by_id"];
+        /** Update Custom Service Definition */
+        put: operations["update_custom_service_definition"];
+        post?: never;
+        /**
+         * Delete Custom Service Definition
+         * @description Services already matched by this definition keep their bindings but are shown
+         *     as an unknown service.
+         */
+        delete: operations["delete_custom_service_definition"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/v1/daemons": {
         parameters: {
             query?: never;
@@ -1172,7 +1482,7 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
-    "/api/v1/hosts": {
+    "/api/v1/host-changes": {
         parameters: {
             query?: never;
             header?: never;
@@ -1180,66 +1490,124 @@ export interface paths {
             cookie?: never;
         };
         /**
-         * List all hosts
-         * @description Returns all hosts the authenticated user has access to, with their
-         *     interfaces, ports, and services included. Supports pagination via
-         *     `limit` and `offset` query parameters, and ordering via `group_by`,
-         *     `order_by`, and `order_direction`.
+         * List all Host Changes
+         * @description Returns changes observed on hosts by discovery, newest first. Filter by `host_id`
+         *     for a host's timeline, or by `session_id` for what a discovery session changed.
          */
-        get: operations["get_all_hosts"];
+        get: operations["get_all_host_changes"];
         put?: never;
-        /**
-         * Create a new host
-         * @description Creates a host with optional interfaces, ports, and services.
-         *     The `source` field is automatically set to `Manual`.
-         *
-         *     ### Tag Validation
-         *
-         *     - Tags must exist and belong to your organization
-         *     - Duplicate tag UUIDs are automatically deduplicated
-         *     - Invalid or cross-organization tag UUIDs return a 400 error
-         */
-        post: operations["create_host"];
+        post?: never;
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/hosts/bulk-delete": {
+    "/api/v1/host-changes/export/csv": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        get?: never;
-        put?: never;
         /**
-         * Bulk delete hosts
-         * @description Deletes multiple hosts in a single request. The request body should be
-         *     an array of host IDs to delete. Fails if any host has an associated daemon.
+         * Export Host Changes to CSV
+         * @description Export all Host Changes matching the filter criteria to CSV format. Ignores pagination parameters (limit/offset) and exports all matching records.
          */
-        post: operations["bulk_delete_hosts"];
+        get: operations["export_Host Changes_csv"];
+        put?: never;
+        post?: never;
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/hosts/discovery": {
+    "/api/v1/host-changes/{id}": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        get?: never;
+        /** Get Host Change by ID */
+        get: operations["get_Host Change_by_id"];
         put?: never;
-        /**
-         * Internal endpoint for daemon discovery
-         * @description Used by daemons to report discovered hosts. Accepts full entities with
-         *     pre-generated IDs. Uses upsert behavior to merge with existing hosts.
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/hosts": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * List all hosts
+         * @description Returns all hosts the authenticated user has access to, with their
+         *     interfaces, ports, and services included. Supports pagination via
+         *     `limit` and `offset` query parameters, and ordering via `group_by`,
+         *     `order_by`, and `order_direction`.
+         */
+        get: operations["get_all_hosts"];
+        put?: never;
+        /**
+         * Create a new host
+         * @description Creates a host with optional interfaces, ports, and services.
+         *     The `source` field is automatically set to `Manual`.
+         *
+         *     ### Tag Validation
+         *
+         *     - Tags must exist and belong to your organization
+         *     - Duplicate tag UUIDs are automatically deduplicated
+         *     - Invalid or cross-organization tag UUIDs return a 400 error
+         */
+        post: operations["create_host"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/hosts/bulk-delete": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /**
+         * Bulk delete hosts
+         * @description Deletes multiple hosts in a single request. The request body should be
+         *     an array of host IDs to delete. Fails if any host has an associated daemon.
+         */
+        post: operations["bulk_delete_hosts"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/hosts/discovery": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /**
+         * Internal endpoint for daemon discovery
+         * @description Used by daemons to report discovered hosts. Accepts full entities with
+         *     pre-generated IDs. Uses upsert behavior to merge with existing hosts.
          *
          *     Tagged as "internal" - included in OpenAPI spec for client generation
          *     but hidden from public documentation.
@@ -2035,7 +2403,9 @@ export interface paths {
          *
          *     - Name must be 1-100 characters
          *     - Name must be unique within your organization
-         *     - Community string is required
+         *     - Community string is required for SNMPv2c
+         *     - SNMPv3 requires a username; passphrases (min 8 characters) are required
+         *       according to the security level
          */
         post: operations["create_snmp_credential"];
         delete?: never;
@@ -2347,6 +2717,65 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/v1/tls-certificates": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * List all TLS Certificates
+         * @description Returns the certificate currently presented on each TLS port, soonest expiry first.
+         *     Use `expiring_within_days` to list certificates that have expired or expire within
+         *     that many days.
+         */
+        get: operations["get_all_tls_certificates"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/tls-certificates/export/csv": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Export TLS Certificates to CSV
+         * @description Export all TLS Certificates matching the filter criteria to CSV format. Ignores pagination parameters (limit/offset) and exports all matching records.
+         */
+        get: operations["export_TLS Certificates_csv"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/tls-certificates/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Get TLS Certificate by ID */
+        get: operations["get_TLS Certificate_by_id"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/v1/topology": {
         parameters: {
             query?: never;
@@ -2365,6 +2794,114 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/v1/topology-snapshots": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * List all Topology Snapshots
+         * @description Returns saved snapshots, newest first. Filter by `topology_id` for the history
+         *     of one topology.
+         */
+        get: operations["get_all_topology_snapshots"];
+        put?: never;
+        /**
+         * Create a Topology Snapshot
+         * @description Saves the topology's nodes, edges and the entities they were built from as they
+         *     currently are. The topology is captured as last built; refresh or rebuild it first
+         *     to capture current data.
+         */
+        post: operations["create_topology_snapshot"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/topology-snapshots/diff": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Diff Topology Snapshots
+         * @description Compares a snapshot with a later snapshot of the same topology, or with the live
+         *     topology when `to` is omitted. Lists nodes and edges added, removed or moved between
+         *     the two.
+         */
+        get: operations["diff_topology_snapshots"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/topology-snapshots/export/csv": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Export Topology Snapshots to CSV
+         * @description Export all Topology Snapshots matching the filter criteria to CSV format. Ignores pagination parameters (limit/offset) and exports all matching records.
+         */
+        get: operations["export_Topology Snapshots_csv"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/topology-snapshots/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Get Topology Snapshot by ID */
+        get: operations["get_Topology Snapshot_by_id"];
+        put?: never;
+        post?: never;
+        /** Delete Topology Snapshot */
+        delete: operations["delete_Topology Snapshot"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/topology-snapshots/{id}/export": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Export a Topology Snapshot diagram
+         * @description Renders the snapshot as SVG, GraphML, draw.io or Graphviz DOT. With `compare_to`,
+         *     changes since that earlier snapshot of the same topology are highlighted.
+         */
+        get: operations["export_topology_snapshot"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/v1/topology/export/csv": {
         parameters: {
             query?: never;
@@ -2425,6 +2962,28 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/v1/topology/{id}/export": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Export a topology diagram
+         * @description Renders the stored layout server-side as SVG, GraphML, draw.io or Graphviz DOT.
+         *     The topology is exported as last built; refresh or rebuild it first for current data.
+         *     With `compare_to`, changes since that snapshot of the topology are highlighted.
+         */
+        get: operations["export_topology"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/v1/topology/{id}/lock": {
         parameters: {
             query?: never;
@@ -2650,42 +3209,203 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
-    "/api/version": {
+    "/api/v1/webhooks": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** Get API version information */
-        get: operations["get_version"];
+        /**
+         * List all Webhooks
+         * @description Returns all webhooks in the authenticated user's organization. Secrets are redacted.
+         */
+        get: operations["get_all_webhooks"];
         put?: never;
-        post?: never;
+        /**
+         * Create a new Webhook
+         * @description Subscribes an HTTP endpoint to entity and discovery events in your organization.
+         *     Each matching event is POSTed as JSON:
+         *
+         *     ```json
+         *     {
+         *       "id": "<event id>",
+         *       "type": "host.created",
+         *       "timestamp": "2026-01-01T00:00:00Z",
+         *       "organization_id": "<uuid>",
+         *       "network_id": "<uuid or null>",
+         *       "data": { "entity_type": "Host", "entity_id": "<uuid>", "entity": { } }
+         *     }
+         *     ```
+         *
+         *     Discovery events use the types `discovery.complete` and `discovery.failed`.
+         *
+         *     ### Signature verification
+         *
+         *     Requests carry `X-Scanopy-Event`, `X-Scanopy-Delivery`, `X-Scanopy-Timestamp` and
+         *     `X-Scanopy-Signature` headers. The signature is `sha256=` followed by the hex
+         *     HMAC-SHA256 of `{timestamp}.{raw body}` keyed with the webhook secret.
+         *
+         *     ### Delivery
+         *
+         *     Any 2xx response counts as delivered; redirects are not followed. Failed
+         *     deliveries are retried with exponential backoff, up to 5 attempts.
+         *
+         *     ### Validation
+         *
+         *     - Name must be 1-100 characters and unique within your organization
+         *     - URL must be an http or https URL. Loopback, link-local and cloud metadata targets
+         *       (e.g. 169.254.169.254) are rejected unless the server allows private webhook targets.
+         *     - Secret must be at least 16 characters
+         *     - The event filter must select at least one entity type or discovery phase
+         */
+        post: operations["create_webhook"];
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-}
-export type webhooks = Record<string, never>;
-export interface components {
-    schemas: {
-        /** @description Error response type for API errors (no data field) */
-        ApiErrorResponse: {
-            /** @description Machine-readable error code for i18n translation */
-            code?: string | null;
-            error?: string | null;
-            /** @description API metadata (version info) */
-            meta: components["schemas"]["ApiMeta"];
-            /** @description Parameters for interpolating into the translated error message */
-            params?: {
-                [key: string]: unknown;
-            } | null;
-            success: boolean;
+    "/api/v1/webhooks/bulk-delete": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
         };
-        /**
-         * @description API metadata included in all responses
+        get?: never;
+        put?: never;
+        /** Bulk delete Webhooks */
+        post: operations["bulk_delete_webhooks"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/webhooks/export/csv": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Export Webhooks to CSV
+         * @description Export all Webhooks matching the filter criteria to CSV format. Ignores pagination parameters (limit/offset) and exports all matching records.
+         */
+        get: operations["export_Webhooks_csv"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/webhooks/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Get Webhook by ID */
+        get: operations["get_Webhook_by_id"];
+        /**
+         * Update Webhook
+         * @description Send the secret back as returned (redacted) to keep the current secret.
+         */
+        put: operations["update_webhook"];
+        post?: never;
+        /**
+         * Delete Webhook
+         * @description Also deletes the webhook's delivery log.
+         */
+        delete: operations["delete_webhook"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/webhooks/{id}/deliveries": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * List a Webhook's deliveries
+         * @description Returns the webhook's delivery log, newest first, including payloads, response
+         *     status codes and errors. Deliveries are kept for 30 days.
+         */
+        get: operations["get_webhook_deliveries"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/webhooks/{id}/test": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /**
+         * Send a test delivery
+         * @description POSTs a signed `webhook.test` payload to the webhook's URL once, without retries,
+         *     and returns the resulting delivery. Works for disabled webhooks too.
+         */
+        post: operations["test_webhook"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/version": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Get API version information */
+        get: operations["get_version"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+}
+export type webhooks = Record<string, never>;
+export interface components {
+    schemas: {
+        /** @description Error response type for API errors (no data field) */
+        ApiErrorResponse: {
+            /** @description Machine-readable error code for i18n translation */
+            code?: string | null;
+            error?: string | null;
+            /** @description API metadata (version info) */
+            meta: components["schemas"]["ApiMeta"];
+            /** @description Parameters for interpolating into the translated error message */
+            params?: {
+                [key: string]: unknown;
+            } | null;
+            success: boolean;
+        };
+        /**
+         * @description API metadata included in all responses
          * @example {
          *       "api_version": 1,
          *       "server_version": "0.14.4"
@@ -2709,6 +3429,32 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_AuditLogEntry: {
+            data?: components["schemas"]["AuditLogEntryBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_AuditLogSettings: {
+            /** @description Organization-wide audit log settings */
+            data?: {
+                /**
+                 * Format: int32
+                 * @description Days entries are kept before being pruned
+                 */
+                retention_days: number;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_Binding: {
             /**
              * @description Association between a service and a port / interface that the service is listening on
@@ -2767,6 +3513,32 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_CustomScanProfile: {
+            data?: components["schemas"]["CustomScanProfileBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_CustomServiceDefinition: {
+            data?: components["schemas"]["CustomServiceDefinitionBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_DaemonApiKey: {
             data?: components["schemas"]["DaemonApiKeyBase"] & {
                 /** Format: date-time */
@@ -2837,6 +3609,8 @@ export interface components {
                 daemon_id: string;
                 discovery_type: components["schemas"]["DiscoveryType"];
                 error?: string | null;
+                /** @description Targets skipped so far because a scan exclusion covered them, grouped by exclusion */
+                excluded_targets: components["schemas"]["ExcludedTargetSummary"][];
                 /** Format: date-time */
                 finished_at?: string | null;
                 /** Format: uuid */
@@ -2884,15 +3658,32 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_HostChange: {
+            data?: components["schemas"]["HostChangeBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_HostResponse: {
             /**
              * @description Response type for host endpoints.
              *     Includes children (interfaces, ports, services, if_entries).
              * @example {
+             *       "auto_hidden": false,
              *       "created_at": "2026-01-15T10:30:00Z",
              *       "description": "Primary web server",
+             *       "first_seen": null,
+             *       "hardware_inventory": [],
              *       "hidden": false,
              *       "hostname": "web-server-01.local",
+             *       "hostname_source": null,
              *       "id": "550e8400-e29b-41d4-a716-446655440003",
              *       "if_entries": [
              *         {
@@ -2902,6 +3693,7 @@ export interface components {
              *           "cdp_platform": null,
              *           "cdp_port_id": null,
              *           "created_at": "2026-01-15T10:30:00Z",
+             *           "fdb_macs": [],
              *           "host_id": "550e8400-e29b-41d4-a716-446655440003",
              *           "id": "550e8400-e29b-41d4-a716-44665544000f",
              *           "if_alias": "Uplink to Core Switch",
@@ -2926,9 +3718,11 @@ export interface components {
              *       "interfaces": [
              *         {
              *           "created_at": "2026-01-15T10:30:00Z",
+             *           "first_seen": null,
              *           "host_id": "550e8400-e29b-41d4-a716-446655440003",
              *           "id": "550e8400-e29b-41d4-a716-446655440005",
              *           "ip_address": "192.168.1.100",
+             *           "last_seen": null,
              *           "mac_address": "DE:AD:BE:EF:CA:FE",
              *           "name": "eth0",
              *           "network_id": "550e8400-e29b-41d4-a716-446655440002",
@@ -2937,8 +3731,12 @@ export interface components {
              *           "updated_at": "2026-01-15T10:30:00Z"
              *         }
              *       ],
+             *       "last_seen": null,
+             *       "missed_scans": 0,
              *       "name": "web-server-01",
              *       "network_id": "550e8400-e29b-41d4-a716-446655440002",
+             *       "offline": false,
+             *       "os": null,
              *       "ports": [
              *         {
              *           "created_at": "2026-01-15T10:30:00Z",
@@ -2955,14 +3753,14 @@ export interface components {
              *         {
              *           "bindings": [
              *             {
-             *               "created_at": "2026-02-10T16:23:59.196981Z",
-             *               "id": "3d5a7810-e4b4-4ce5-bd9e-fa263999c7b4",
+             *               "created_at": "2026-10-18T17:31:08.748082582Z",
+             *               "id": "c5adb95d-2de6-4e0a-92a4-61517afdcbb5",
              *               "interface_id": "550e8400-e29b-41d4-a716-446655440005",
              *               "network_id": "550e8400-e29b-41d4-a716-446655440002",
              *               "port_id": "550e8400-e29b-41d4-a716-446655440006",
              *               "service_id": "550e8400-e29b-41d4-a716-446655440007",
              *               "type": "Port",
-             *               "updated_at": "2026-02-10T16:23:59.196981Z"
+             *               "updated_at": "2026-10-18T17:31:08.748082582Z"
              *             }
              *           ],
              *           "created_at": "2026-01-15T10:30:00Z",
@@ -2971,7 +3769,7 @@ export interface components {
              *           "name": "nginx",
              *           "network_id": "550e8400-e29b-41d4-a716-446655440002",
              *           "position": 0,
-             *           "service_definition": "NATS",
+             *           "service_definition": "Gitea",
              *           "source": {
              *             "type": "Manual"
              *           },
@@ -2989,21 +3787,34 @@ export interface components {
              *     }
              */
             data?: {
+                /** @description Hidden for staying offline past retention; un-hidden when seen again */
+                auto_hidden: boolean;
                 chassis_id?: string | null;
                 /** Format: date-time */
                 created_at: string;
                 description?: string | null;
+                /** Format: date-time */
+                first_seen?: string | null;
+                /** @description Hardware inventory from ENTITY-MIB (chassis, modules, PSUs, transceivers) */
+                hardware_inventory?: components["schemas"]["HardwareComponent"][];
                 hidden: boolean;
                 hostname?: string | null;
+                hostname_source?: null | components["schemas"]["HostnameSource"];
                 /** Format: uuid */
                 id: string;
                 /** @description SNMP ifTable entries */
                 if_entries: components["schemas"]["IfEntry"][];
                 interfaces: components["schemas"]["Interface"][];
+                /** Format: date-time */
+                last_seen?: string | null;
                 management_url?: string | null;
+                /** Format: int32 */
+                missed_scans: number;
                 name: string;
                 /** Format: uuid */
                 network_id: string;
+                offline: boolean;
+                os?: null | components["schemas"]["OsFingerprint"];
                 ports: components["schemas"]["Port"][];
                 services: components["schemas"]["Service"][];
                 /** Format: uuid */
@@ -3376,6 +4187,19 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_TlsCertificate: {
+            data?: components["schemas"]["TlsCertificateBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_Topology: {
             data?: components["schemas"]["TopologyBase"] & {
                 /** Format: date-time */
@@ -3389,6 +4213,32 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_TopologyDiff: {
+            /** @description Nodes and edges that differ between two builds of a topology */
+            data?: {
+                edges: components["schemas"]["EdgeChange"][];
+                from: components["schemas"]["TopologyDiffSide"];
+                nodes: components["schemas"]["NodeChange"][];
+                summary: components["schemas"]["TopologyDiffSummary"];
+                to: components["schemas"]["TopologyDiffSide"];
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_TopologySnapshot: {
+            data?: components["schemas"]["TopologySnapshotBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_User: {
             data?: components["schemas"]["UserBase"] & {
                 /** Format: date-time */
@@ -3468,6 +4318,8 @@ export interface components {
                 daemon_id: string;
                 discovery_type: components["schemas"]["DiscoveryType"];
                 error?: string | null;
+                /** @description Targets skipped so far because a scan exclusion covered them, grouped by exclusion */
+                excluded_targets: components["schemas"]["ExcludedTargetSummary"][];
                 /** Format: date-time */
                 finished_at?: string | null;
                 /** Format: uuid */
@@ -3497,6 +4349,20 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_Vec_TypeMetadata: {
+            data?: {
+                category: string | null;
+                color: components["schemas"]["Color"];
+                description: string | null;
+                icon: string | null;
+                id: string;
+                metadata: unknown;
+                name: string | null;
+            }[];
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_VersionInfo: {
             /** @description Version information for API compatibility checking */
             data?: {
@@ -3517,6 +4383,32 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_Webhook: {
+            data?: components["schemas"]["WebhookBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_WebhookDelivery: {
+            data?: components["schemas"]["WebhookDeliveryBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_bool: {
             data?: boolean;
             error?: string | null;
@@ -3530,42 +4422,116 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
-        BillingPlan: (components["schemas"]["PlanConfig"] & {
-            /** @enum {string} */
-            type: "Community";
-        }) | (components["schemas"]["PlanConfig"] & {
-            /** @enum {string} */
-            type: "Free";
-        }) | (components["schemas"]["PlanConfig"] & {
-            /** @enum {string} */
-            type: "Starter";
-        }) | (components["schemas"]["PlanConfig"] & {
-            /** @enum {string} */
-            type: "Pro";
-        }) | (components["schemas"]["PlanConfig"] & {
-            /** @enum {string} */
-            type: "Team";
-        }) | (components["schemas"]["PlanConfig"] & {
-            /** @enum {string} */
-            type: "Business";
-        }) | (components["schemas"]["PlanConfig"] & {
-            /** @enum {string} */
-            type: "Enterprise";
-        }) | (components["schemas"]["PlanConfig"] & {
-            /** @enum {string} */
-            type: "Demo";
-        }) | (components["schemas"]["PlanConfig"] & {
-            /** @enum {string} */
-            type: "CommercialSelfHosted";
-        });
-        /** @enum {string} */
-        BillingRate: "Month" | "Year";
         /**
-         * @description Association between a service and a port / interface that the service is listening on
-         * @example {
-         *       "created_at": "2026-02-10T16:23:59.197187Z",
-         *       "id": "2133bb44-e4fe-4c74-a062-5590c4d658af",
-         *       "interface_id": "550e8400-e29b-41d4-a716-446655440005",
+         * @description Kind of event an audit log entry was recorded from
+         * @enum {string}
+         */
+        AuditLogCategory: "auth" | "entity";
+        AuditLogEntry: components["schemas"]["AuditLogEntryBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+            /** Format: date-time */
+            readonly updated_at: string;
+        };
+        AuditLogEntryBase: {
+            actor_email?: string | null;
+            /**
+             * Format: uuid
+             * @description User, API key or daemon that acted
+             */
+            actor_id?: string | null;
+            /** @description How the actor authenticated */
+            actor_type: components["schemas"]["AuthMethod"];
+            category: components["schemas"]["AuditLogCategory"];
+            /** Format: uuid */
+            entity_id?: string | null;
+            /** @description Name of the entity when the event was recorded */
+            entity_name?: string | null;
+            entity_type?: null | components["schemas"]["EntityDiscriminants"];
+            /**
+             * Format: uuid
+             * @description Event the entry was recorded from
+             */
+            event_id: string;
+            /** @description Client IP of the request. Empty for background jobs. */
+            ip_address?: string | null;
+            /** @description Operation details, e.g. the login method or the email of a failed login */
+            metadata: Record<string, never>;
+            /**
+             * Format: uuid
+             * @description Network of the changed entity, if it belongs to one
+             */
+            network_id?: string | null;
+            /**
+             * @description Auth operation (e.g. `login_failed`, `rotate_key`) or entity operation
+             *     (`created`, `updated`, `deleted`)
+             */
+            operation: string;
+            /** Format: uuid */
+            organization_id: string;
+            user_agent?: string | null;
+            /**
+             * Format: uuid
+             * @description User that acted, directly or through one of their API keys
+             */
+            user_id?: string | null;
+        };
+        /**
+         * @description Fields that audit log entries can be ordered/grouped by.
+         * @enum {string}
+         */
+        AuditLogEntryOrderField: "created_at" | "category" | "operation" | "entity_type" | "actor_type" | "user_id";
+        /** @description Organization-wide audit log settings */
+        AuditLogSettings: {
+            /**
+             * Format: int32
+             * @description Days entries are kept before being pruned
+             */
+            retention_days: number;
+        };
+        /**
+         * @description Represents how an entity authenticated - used for audit logging
+         * @enum {string}
+         */
+        AuthMethod: "session" | "user_api_key" | "daemon_api_key" | "external_service" | "system" | "anonymous";
+        BillingPlan: (components["schemas"]["PlanConfig"] & {
+            /** @enum {string} */
+            type: "Community";
+        }) | (components["schemas"]["PlanConfig"] & {
+            /** @enum {string} */
+            type: "Free";
+        }) | (components["schemas"]["PlanConfig"] & {
+            /** @enum {string} */
+            type: "Starter";
+        }) | (components["schemas"]["PlanConfig"] & {
+            /** @enum {string} */
+            type: "Pro";
+        }) | (components["schemas"]["PlanConfig"] & {
+            /** @enum {string} */
+            type: "Team";
+        }) | (components["schemas"]["PlanConfig"] & {
+            /** @enum {string} */
+            type: "Business";
+        }) | (components["schemas"]["PlanConfig"] & {
+            /** @enum {string} */
+            type: "Enterprise";
+        }) | (components["schemas"]["PlanConfig"] & {
+            /** @enum {string} */
+            type: "Demo";
+        }) | (components["schemas"]["PlanConfig"] & {
+            /** @enum {string} */
+            type: "CommercialSelfHosted";
+        });
+        /** @enum {string} */
+        BillingRate: "Month" | "Year";
+        /**
+         * @description Association between a service and a port / interface that the service is listening on
+         * @example {
+         *       "created_at": "2026-02-10T16:23:59.197187Z",
+         *       "id": "2133bb44-e4fe-4c74-a062-5590c4d658af",
+         *       "interface_id": "550e8400-e29b-41d4-a716-446655440005",
          *       "network_id": "550e8400-e29b-41d4-a716-446655440002",
          *       "port_id": "550e8400-e29b-41d4-a716-446655440006",
          *       "service_id": "550e8400-e29b-41d4-a716-446655440007",
@@ -3670,6 +4636,16 @@ export interface components {
             /** @description Number of entities affected */
             affected_count: number;
         };
+        /** @description A certificate presented after the leaf (intermediate or root) */
+        CertificateChainEntry: {
+            fingerprint_sha256: string;
+            issuer: string;
+            /** Format: date-time */
+            not_after: string;
+            /** Format: date-time */
+            not_before: string;
+            subject: string;
+        };
         ChangePlanPreview: {
             /** Format: int64 */
             excess_hosts: number;
@@ -3717,6 +4693,7 @@ export interface components {
          *     interfaces/ports by ID in the same request.
          * @example {
          *       "description": "Primary web server",
+         *       "hardware_inventory": [],
          *       "hidden": false,
          *       "hostname": "web-server-01.local",
          *       "if_entries": [],
@@ -3764,6 +4741,8 @@ export interface components {
         CreateHostRequest: {
             chassis_id?: string | null;
             description?: string | null;
+            /** @description Hardware inventory from ENTITY-MIB (chassis, modules, PSUs, transceivers) */
+            hardware_inventory?: components["schemas"]["HardwareComponent"][];
             hidden?: boolean;
             hostname?: string | null;
             /** @description SNMP interface entries (ifTable data) - server assigns UUIDs */
@@ -3814,6 +4793,15 @@ export interface components {
             tags: string[];
             virtualization?: null | components["schemas"]["ServiceVirtualization"];
         };
+        CreateTopologySnapshotRequest: {
+            description?: string | null;
+            name: string;
+            /**
+             * Format: uuid
+             * @description Topology to capture as currently built
+             */
+            topology_id: string;
+        };
         CreateUpdateShareRequest: {
             password?: string | null;
             share: components["schemas"]["Share"];
@@ -3826,6 +4814,111 @@ export interface components {
             /** Format: date-time */
             readonly updated_at: string;
         };
+        /**
+         * @description Declarative match pattern for a user-defined service.
+         *     Mirrors the subset of the built-in `Pattern` that can be expressed as data.
+         */
+        CustomPattern: {
+            patterns: components["schemas"]["CustomPattern"][];
+            /** @enum {string} */
+            type: "AnyOf";
+        } | {
+            patterns: components["schemas"]["CustomPattern"][];
+            /** @enum {string} */
+            type: "AllOf";
+        } | {
+            pattern: components["schemas"]["CustomPattern"];
+            /** @enum {string} */
+            type: "Not";
+        } | {
+            port: components["schemas"]["PortType"];
+            /** @enum {string} */
+            type: "Port";
+        } | {
+            /** @description Request path, e.g. "/" or "/api/health" */
+            path: string;
+            port: components["schemas"]["PortType"];
+            /** @description String to match in the response body */
+            response: string;
+            status_codes?: null | components["schemas"]["StatusCodeRange"];
+            /** @enum {string} */
+            type: "Endpoint";
+        } | {
+            header: string;
+            port?: null | components["schemas"]["PortType"];
+            status_codes?: null | components["schemas"]["StatusCodeRange"];
+            /** @enum {string} */
+            type: "Header";
+            value: string;
+        } | {
+            port: components["schemas"]["PortType"];
+            /** @description Regular expression; non-printable bytes in the banner are escaped as `\xNN` */
+            regex: string;
+            /** @enum {string} */
+            type: "Banner";
+        } | {
+            /** @enum {string} */
+            type: "MacVendor";
+            vendor: string;
+        } | {
+            service_type: string;
+            /** @enum {string} */
+            type: "MdnsService";
+        } | {
+            key: string;
+            service_type: string;
+            /** @enum {string} */
+            type: "MdnsTxt";
+            value: string;
+        } | {
+            field: components["schemas"]["UpnpField"];
+            /** @enum {string} */
+            type: "Upnp";
+            value: string;
+        } | {
+            subnet_type: components["schemas"]["SubnetType"];
+            /** @enum {string} */
+            type: "SubnetIsType";
+        };
+        CustomScanProfile: components["schemas"]["CustomScanProfileBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+            /** Format: date-time */
+            readonly updated_at: string;
+        };
+        CustomScanProfileBase: {
+            description?: string;
+            name: string;
+            /** Format: uuid */
+            organization_id: string;
+            /** @description Ports, probing depth, and timing used when scanning each host */
+            settings: components["schemas"]["ScanProfileSettings"];
+        };
+        /** @enum {string} */
+        CustomScanProfileOrderField: "created_at" | "name" | "updated_at";
+        CustomServiceDefinition: components["schemas"]["CustomServiceDefinitionBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+            /** Format: date-time */
+            readonly updated_at: string;
+        };
+        CustomServiceDefinitionBase: {
+            category: components["schemas"]["ServiceCategory"];
+            description?: string;
+            /** @description URL of icon, or static path if serving from /logos. Empty for the category icon. */
+            logo_url?: string;
+            name: string;
+            /** Format: uuid */
+            organization_id: string;
+            /** @description How the service is identified during discovery */
+            pattern: components["schemas"]["CustomPattern"];
+        };
+        /** @enum {string} */
+        CustomServiceDefinitionOrderField: "created_at" | "name" | "category" | "updated_at";
         DaemonApiKey: components["schemas"]["DaemonApiKeyBase"] & {
             /** Format: date-time */
             readonly created_at: string;
@@ -3996,6 +5089,13 @@ export interface components {
             severity: components["schemas"]["DeprecationSeverity"];
             sunset_date?: string | null;
         };
+        /** @description A device port used as an edge endpoint in the physical view */
+        DevicePort: {
+            /** Format: uuid */
+            if_entry_id: string;
+            /** @description Side of the device node the port is drawn on */
+            side: components["schemas"]["EdgeHandle"];
+        };
         Discovery: components["schemas"]["DiscoveryBase"] & {
             /** Format: date-time */
             readonly created_at: string;
@@ -4020,6 +5120,8 @@ export interface components {
          *     This supports the discovery workflow where daemons manage entity IDs.
          */
         DiscoveryHostRequest: {
+            /** @description TLS certificates presented on the host's ports, referencing ports by their IDs in this request */
+            certificates?: components["schemas"]["TlsCertificate"][];
             host: components["schemas"]["Host"];
             /** @description SNMP interface entries (ifTable data) - optional, populated when SNMP is enabled */
             if_entries?: components["schemas"]["IfEntry"][];
@@ -4046,12 +5148,29 @@ export interface components {
             /** @enum {string} */
             type: "SelfReport";
         } | {
+            /**
+             * @description Organization's custom service definitions, matched alongside the built-in ones
+             *     Server builds this list before initiating discovery
+             */
+            custom_service_definitions?: components["schemas"]["CustomServiceDefinition"][];
+            /**
+             * @description Addresses and devices this discovery must never probe
+             *     Server adds the network's exclusions before initiating discovery
+             */
+            exclusions: components["schemas"]["ScanExclusion"][];
             host_naming_fallback: components["schemas"]["HostNamingFallback"];
+            /**
+             * @description Where to look up each host's hostname, in order. The first source that returns
+             *     a name wins; sources left out are never queried.
+             */
+            hostname_sources: components["schemas"]["HostnameSource"][];
             /**
              * @description Whether to probe raw-socket ports (9100-9107) during endpoint scanning.
              *     Disabled by default to prevent ghost printing on JetDirect printers.
              */
             probe_raw_socket_ports?: boolean;
+            /** @description Ports, probing depth, and timing used when scanning each host */
+            scan_profile: components["schemas"]["ScanProfile"];
             /**
              * @description SNMP credentials for querying devices during discovery
              *     Server builds this mapping before initiating discovery
@@ -4060,19 +5179,45 @@ export interface components {
             subnet_ids: string[] | null;
             /** @enum {string} */
             type: "Network";
+        } | {
+            host_naming_fallback: components["schemas"]["HostNamingFallback"];
+            /**
+             * Format: int64
+             * @description How long to listen for traffic before reporting the hosts that were heard
+             */
+            listen_duration_secs: number;
+            subnet_ids: string[] | null;
+            /** @enum {string} */
+            type: "Passive";
         } | {
             /** Format: uuid */
             host_id: string;
             host_naming_fallback: components["schemas"]["HostNamingFallback"];
             /** @enum {string} */
             type: "Docker";
-        };
+        } | {
+            /** @description Kubeconfig context to use instead of the file's current context */
+            context: string | null;
+            host_naming_fallback: components["schemas"]["HostNamingFallback"];
+            /**
+             * @description Kubeconfig file on the daemon's host. When unset the daemon uses its in-cluster
+             *     service account, falling back to `$KUBECONFIG` and then `~/.kube/config`.
+             */
+            kubeconfig_path: string | null;
+            /** @enum {string} */
+            type: "Kubernetes";
+        } | (components["schemas"]["ProxmoxConnection"] & {
+            /** @enum {string} */
+            type: "Proxmox";
+        });
         /** @description Progress update from daemon to server during discovery */
         DiscoveryUpdatePayload: {
             /** Format: uuid */
             daemon_id: string;
             discovery_type: components["schemas"]["DiscoveryType"];
             error?: string | null;
+            /** @description Targets skipped so far because a scan exclusion covered them, grouped by exclusion */
+            excluded_targets: components["schemas"]["ExcludedTargetSummary"][];
             /** Format: date-time */
             finished_at?: string | null;
             /** Format: uuid */
@@ -4103,6 +5248,21 @@ export interface components {
             target: string;
             target_handle: components["schemas"]["EdgeHandle"];
         };
+        EdgeChange: {
+            /** @description Edges are only ever added or removed */
+            change_type: components["schemas"]["TopologyChangeType"];
+            /**
+             * Format: uuid
+             * @description Edge ID on the side the edge was read from (the earlier side for removals)
+             */
+            edge_id: string;
+            edge_type: components["schemas"]["EdgeTypeDiscriminants"];
+            label: string | null;
+            /** Format: uuid */
+            source_id: string;
+            /** Format: uuid */
+            target_id: string;
+        };
         /** @enum {string} */
         EdgeHandle: "Top" | "Bottom" | "Left" | "Right";
         /** @enum {string} */
@@ -4150,9 +5310,21 @@ export interface components {
             source_if_entry_id: string;
             /** Format: uuid */
             target_if_entry_id: string;
+        } | {
+            /** @enum {string} */
+            edge_type: "AccessPort";
+            /** Format: uuid */
+            host_id: string;
+            /** Format: uuid */
+            if_entry_id: string;
+        } | {
+            /** @enum {string} */
+            edge_type: "ProxyRoute";
+            /** @description Proxy routes or services that forward to the backend */
+            routes: string[];
         };
         /** @enum {string} */
-        EdgeTypeDiscriminants: "Interface" | "HostVirtualization" | "ServiceVirtualization" | "RequestPath" | "HubAndSpoke" | "PhysicalLink";
+        EdgeTypeDiscriminants: "Interface" | "HostVirtualization" | "ServiceVirtualization" | "RequestPath" | "HubAndSpoke" | "PhysicalLink" | "AccessPort" | "ProxyRoute";
         /** @description Enterprise plan inquiry request */
         EnterpriseInquiryRequest: {
             /** @description Company name */
@@ -4176,12 +5348,14 @@ export interface components {
             urgency?: string | null;
         };
         /** @enum {string} */
-        EntityDiscriminants: "Organization" | "Invite" | "Share" | "Network" | "DaemonApiKey" | "UserApiKey" | "User" | "Tag" | "Discovery" | "Daemon" | "Host" | "Service" | "Port" | "Binding" | "Interface" | "IfEntry" | "SnmpCredential" | "Subnet" | "Group" | "Topology" | "Unknown";
+        EntityDiscriminants: "Organization" | "Invite" | "Share" | "Network" | "DaemonApiKey" | "UserApiKey" | "User" | "Tag" | "Webhook" | "WebhookDelivery" | "Discovery" | "Daemon" | "Host" | "Service" | "Port" | "Binding" | "Interface" | "IfEntry" | "HostChange" | "TlsCertificate" | "SnmpCredential" | "CustomServiceDefinition" | "CustomScanProfile" | "Subnet" | "Group" | "Topology" | "TopologySnapshot" | "AuditLogEntry" | "Unknown";
         EntityMetadata: {
             color: components["schemas"]["Color"];
             icon: string;
             id: string;
         };
+        /** @enum {string} */
+        EntityOperation: "Get" | "GetAll" | "Created" | "Updated" | "Deleted";
         EntitySource: {
             /** @enum {string} */
             type: "Manual";
@@ -4201,6 +5375,26 @@ export interface components {
             /** @enum {string} */
             type: "Unknown";
         };
+        /** @description A host that discovery skipped because an exclusion covered it */
+        ExcludedTarget: {
+            ip: string;
+            /** @description Known when the host was skipped after ARP because of a MAC OUI exclusion */
+            mac_address?: string | null;
+        };
+        /** @description Hosts skipped by a single exclusion during a session */
+        ExcludedTargetSummary: {
+            /**
+             * Format: int32
+             * @description Total number of hosts this exclusion caused discovery to skip
+             */
+            count: number;
+            exclusion: components["schemas"]["ScanExclusion"];
+            /**
+             * @description Skipped hosts. Progress updates carry only the first few so they stay small; the
+             *     final update, which is kept on the historical run, lists every one.
+             */
+            targets: components["schemas"]["ExcludedTarget"][];
+        };
         ForgotPasswordRequest: {
             /** Format: email */
             email: string;
@@ -4252,10 +5446,36 @@ export interface components {
         GroupOrderField: "created_at" | "name" | "group_type" | "updated_at" | "network_id";
         /** @enum {string} */
         GroupType: "RequestPath" | "HubAndSpoke";
+        /** @description Physical component from a device's ENTITY-MIB (entPhysicalTable) */
+        HardwareComponent: {
+            class: components["schemas"]["HardwareComponentClass"];
+            /** @description entPhysicalDescr */
+            description?: string | null;
+            /** @description entPhysicalMfgName */
+            manufacturer?: string | null;
+            /** @description entPhysicalModelName */
+            model_name?: string | null;
+            /** @description entPhysicalName (e.g., "Switch 1 - Power Supply A") */
+            name?: string | null;
+            /**
+             * Format: int32
+             * @description entPhysicalIndex - stable identifier within the device
+             */
+            physical_index: number;
+            /** @description entPhysicalSerialNum */
+            serial_number?: string | null;
+        };
+        /**
+         * @description Kind of physical component, derived from ENTITY-MIB entPhysicalClass
+         * @enum {string}
+         */
+        HardwareComponentClass: "Chassis" | "Module" | "PowerSupply" | "Transceiver";
         /**
          * @example {
+         *       "auto_hidden": false,
          *       "created_at": "2026-01-15T10:30:00Z",
          *       "description": "Primary web server",
+         *       "hardware_inventory": [],
          *       "hidden": false,
          *       "hostname": "web-server-01.local",
          *       "id": "550e8400-e29b-41d4-a716-446655440003",
@@ -4283,16 +5503,42 @@ export interface components {
          *     and queried by `host_id`. They are NOT stored on the host.
          */
         HostBase: {
+            /**
+             * @description Set when the host was hidden for staying offline past the network's retention,
+             *     rather than by hand. Seeing the host again un-hides it and clears this.
+             */
+            readonly auto_hidden: boolean;
             /** @description LLDP lldpLocChassisId - globally unique device identifier for deduplication */
             chassis_id?: string | null;
             description: string | null;
+            /**
+             * Format: date-time
+             * @description When discovery first observed this host (null for hosts never discovered)
+             */
+            readonly first_seen: string | null;
+            /** @description Hardware inventory from ENTITY-MIB (chassis, modules, PSUs, transceivers) */
+            hardware_inventory?: components["schemas"]["HardwareComponent"][];
             hidden: boolean;
             hostname: string | null;
+            hostname_source: null | components["schemas"]["HostnameSource"];
+            /**
+             * Format: date-time
+             * @description When discovery last observed this host (network scan, ARP/NDP, Docker or self-report)
+             */
+            readonly last_seen: string | null;
             /** @description URL for device management interface (manual or discovered) */
             management_url?: string | null;
+            /**
+             * Format: int32
+             * @description Consecutive network scans covering this host's subnets that didn't find it
+             */
+            readonly missed_scans: number;
             name: string;
             /** Format: uuid */
             network_id: string;
+            /** @description Set once `missed_scans` reaches the network's liveness threshold, cleared when seen again */
+            readonly offline: boolean;
+            os: null | components["schemas"]["OsFingerprint"];
             /**
              * Format: uuid
              * @description Per-host SNMP credential override (null = use network default)
@@ -4310,21 +5556,79 @@ export interface components {
             tags: string[];
             virtualization: null | components["schemas"]["HostVirtualization"];
         };
+        HostChange: components["schemas"]["HostChangeBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+            /** Format: date-time */
+            readonly updated_at: string;
+        };
+        HostChangeBase: {
+            change_type: components["schemas"]["HostChangeType"];
+            /** Format: uuid */
+            host_id: string;
+            /** Format: uuid */
+            network_id: string;
+            new_value?: string | null;
+            previous_value?: string | null;
+            /**
+             * Format: uuid
+             * @description Discovery session that observed the change, if it came from one
+             */
+            session_id?: string | null;
+            /**
+             * @description What changed: port ("443/tcp"), service definition ID, or interface IP.
+             *     Empty for host-level changes.
+             */
+            subject: string;
+        };
+        /**
+         * @description Fields that host changes can be ordered/grouped by.
+         * @enum {string}
+         */
+        HostChangeOrderField: "created_at" | "change_type" | "host_id" | "session_id";
+        /**
+         * @description Kind of change observed on a host between discoveries
+         * @enum {string}
+         */
+        HostChangeType: "HostAppeared" | "HostDisappeared" | "PortOpened" | "PortClosed" | "ServiceAdded" | "ServiceRemoved" | "HostnameChanged" | "MacAddressChanged" | "CertificateChanged" | "CertificateExpiring";
+        /**
+         * @description Stale-host lifecycle for a network. Hosts that network scans stop finding are first
+         *     marked offline, then hidden once they've been gone for the retention period.
+         */
+        HostLivenessPolicy: {
+            /**
+             * Format: int32
+             * @description Days since an offline host was last seen before it's hidden, until discovery sees it again.
+             *     Null keeps offline hosts visible.
+             */
+            hide_offline_after_days: number | null;
+            /**
+             * Format: int32
+             * @description Consecutive missed network scans before a host is marked offline. Null never marks hosts offline.
+             */
+            offline_after_missed_scans: number | null;
+        };
         /** @enum {string} */
         HostNamingFallback: "Ip" | "BestService";
         /**
          * @description Fields that hosts can be ordered/grouped by.
          * @enum {string}
          */
-        HostOrderField: "created_at" | "name" | "hostname" | "updated_at" | "virtualized_by" | "network_id";
+        HostOrderField: "created_at" | "name" | "hostname" | "updated_at" | "virtualized_by" | "network_id" | "last_seen" | "os_family";
         /**
          * @description Response type for host endpoints.
          *     Includes children (interfaces, ports, services, if_entries).
          * @example {
+         *       "auto_hidden": false,
          *       "created_at": "2026-01-15T10:30:00Z",
          *       "description": "Primary web server",
+         *       "first_seen": null,
+         *       "hardware_inventory": [],
          *       "hidden": false,
          *       "hostname": "web-server-01.local",
+         *       "hostname_source": null,
          *       "id": "550e8400-e29b-41d4-a716-446655440003",
          *       "if_entries": [
          *         {
@@ -4334,6 +5638,7 @@ export interface components {
          *           "cdp_platform": null,
          *           "cdp_port_id": null,
          *           "created_at": "2026-01-15T10:30:00Z",
+         *           "fdb_macs": [],
          *           "host_id": "550e8400-e29b-41d4-a716-446655440003",
          *           "id": "550e8400-e29b-41d4-a716-44665544000f",
          *           "if_alias": "Uplink to Core Switch",
@@ -4358,9 +5663,11 @@ export interface components {
          *       "interfaces": [
          *         {
          *           "created_at": "2026-01-15T10:30:00Z",
+         *           "first_seen": null,
          *           "host_id": "550e8400-e29b-41d4-a716-446655440003",
          *           "id": "550e8400-e29b-41d4-a716-446655440005",
          *           "ip_address": "192.168.1.100",
+         *           "last_seen": null,
          *           "mac_address": "DE:AD:BE:EF:CA:FE",
          *           "name": "eth0",
          *           "network_id": "550e8400-e29b-41d4-a716-446655440002",
@@ -4369,8 +5676,12 @@ export interface components {
          *           "updated_at": "2026-01-15T10:30:00Z"
          *         }
          *       ],
+         *       "last_seen": null,
+         *       "missed_scans": 0,
          *       "name": "web-server-01",
          *       "network_id": "550e8400-e29b-41d4-a716-446655440002",
+         *       "offline": false,
+         *       "os": null,
          *       "ports": [
          *         {
          *           "created_at": "2026-01-15T10:30:00Z",
@@ -4387,14 +5698,14 @@ export interface components {
          *         {
          *           "bindings": [
          *             {
-         *               "created_at": "2026-02-10T16:23:59.196697Z",
-         *               "id": "c4f88351-a1ff-4c40-9597-39037f0959f9",
+         *               "created_at": "2026-10-18T17:31:08.744887632Z",
+         *               "id": "323952d4-0cf2-46e9-815d-98dbedd44a0f",
          *               "interface_id": "550e8400-e29b-41d4-a716-446655440005",
          *               "network_id": "550e8400-e29b-41d4-a716-446655440002",
          *               "port_id": "550e8400-e29b-41d4-a716-446655440006",
          *               "service_id": "550e8400-e29b-41d4-a716-446655440007",
          *               "type": "Port",
-         *               "updated_at": "2026-02-10T16:23:59.196697Z"
+         *               "updated_at": "2026-10-18T17:31:08.744887632Z"
          *             }
          *           ],
          *           "created_at": "2026-01-15T10:30:00Z",
@@ -4403,7 +5714,7 @@ export interface components {
          *           "name": "nginx",
          *           "network_id": "550e8400-e29b-41d4-a716-446655440002",
          *           "position": 0,
-         *           "service_definition": "NATS",
+         *           "service_definition": "Gitea",
          *           "source": {
          *             "type": "Manual"
          *           },
@@ -4421,21 +5732,34 @@ export interface components {
          *     }
          */
         HostResponse: {
+            /** @description Hidden for staying offline past retention; un-hidden when seen again */
+            auto_hidden: boolean;
             chassis_id?: string | null;
             /** Format: date-time */
             created_at: string;
             description?: string | null;
+            /** Format: date-time */
+            first_seen?: string | null;
+            /** @description Hardware inventory from ENTITY-MIB (chassis, modules, PSUs, transceivers) */
+            hardware_inventory?: components["schemas"]["HardwareComponent"][];
             hidden: boolean;
             hostname?: string | null;
+            hostname_source?: null | components["schemas"]["HostnameSource"];
             /** Format: uuid */
             id: string;
             /** @description SNMP ifTable entries */
             if_entries: components["schemas"]["IfEntry"][];
             interfaces: components["schemas"]["Interface"][];
+            /** Format: date-time */
+            last_seen?: string | null;
             management_url?: string | null;
+            /** Format: int32 */
+            missed_scans: number;
             name: string;
             /** Format: uuid */
             network_id: string;
+            offline: boolean;
+            os?: null | components["schemas"]["OsFingerprint"];
             ports: components["schemas"]["Port"][];
             services: components["schemas"]["Service"][];
             /** Format: uuid */
@@ -4456,6 +5780,11 @@ export interface components {
             /** @enum {string} */
             type: "Proxmox";
         };
+        /**
+         * @description Where network discovery got a host's hostname from
+         * @enum {string}
+         */
+        HostnameSource: "Dns" | "NetBios" | "Smb" | "Llmnr" | "Mdns" | "Snmp";
         /**
          * @description SNMP ifAdminStatus values per IF-MIB RFC 2863
          * @enum {string}
@@ -4480,6 +5809,8 @@ export interface components {
             cdp_platform?: string | null;
             /** @description Remote port ID from CDP */
             cdp_port_id?: string | null;
+            /** @description MAC addresses learned on this port - used to place end hosts on access ports */
+            fdb_macs?: string[];
             /** Format: uuid */
             host_id: string;
             /** @description SNMP ifAlias - user-configured description */
@@ -4587,9 +5918,19 @@ export interface components {
             readonly updated_at: string;
         };
         InterfaceBase: {
+            /**
+             * Format: date-time
+             * @description When discovery first observed this interface (null if never discovered)
+             */
+            readonly first_seen: string | null;
             /** Format: uuid */
             host_id: string;
             ip_address: string;
+            /**
+             * Format: date-time
+             * @description When discovery last observed this interface
+             */
+            readonly last_seen: string | null;
             /** @description MAC address discovered from ARP, SNMP, or Docker - immutable once set */
             mac_address?: string | null;
             name: string | null;
@@ -4653,8 +5994,18 @@ export interface components {
             x: number;
             y: number;
         };
-        /**
-         * @description LLDP Chassis ID subtypes per IEEE 802.1AB.
+        KubernetesVirtualization: {
+            namespace: string;
+            pod_name?: string | null;
+            pod_uid?: string | null;
+            /**
+             * Format: uuid
+             * @description Kubernetes service on the node the pod is scheduled on
+             */
+            service_id: string;
+        };
+        /**
+         * @description LLDP Chassis ID subtypes per IEEE 802.1AB.
          *
          *     The chassis ID identifies the remote device. Different network equipment
          *     may use different subtypes depending on configuration and capabilities.
@@ -4814,9 +6165,13 @@ export interface components {
             readonly updated_at: string;
         };
         NetworkBase: {
+            /** @description When discovered hosts are marked offline and hidden */
+            host_liveness?: components["schemas"]["HostLivenessPolicy"];
             name: string;
             /** Format: uuid */
             organization_id: string;
+            /** @description Addresses and devices that no discovery on this network may probe */
+            scan_exclusions: components["schemas"]["ScanExclusion"][];
             /**
              * Format: uuid
              * @description Default SNMP credential for this network (hosts can override).
@@ -4842,11 +6197,35 @@ export interface components {
             position: components["schemas"]["Ixy"];
             size: components["schemas"]["Uxy"];
         };
+        NodeChange: {
+            change_type: components["schemas"]["TopologyChangeType"];
+            current: null | components["schemas"]["NodePlacement"];
+            /** @description Label the node is drawn with */
+            label: string;
+            /**
+             * Format: uuid
+             * @description Node ID on the side the node was read from (the earlier side for removals)
+             */
+            node_id: string;
+            node_type: components["schemas"]["NodeTypeDiscriminants"];
+            previous: null | components["schemas"]["NodePlacement"];
+        };
+        /** @description Where a node was drawn. Positions of interface and device nodes are relative to their parent. */
+        NodePlacement: {
+            /**
+             * Format: uuid
+             * @description Containing subnet or site node
+             */
+            parent_id: string | null;
+            position: components["schemas"]["Ixy"];
+        };
         NodeType: {
             infra_width: number;
             /** @enum {string} */
             node_type: "SubnetNode";
         } | {
+        /** @enum {string} */
+        NodeTypeDiscriminants: "SubnetNode" | "InterfaceNode" | "SiteNode" | "DeviceNode" | "ServiceNode";
             /** Format: uuid */
             host_id: string;
             /** Format: uuid */
@@ -4856,7 +6235,32 @@ export interface components {
             node_type: "InterfaceNode";
             /** Format: uuid */
             subnet_id: string;
+        } | {
+            /** @enum {string} */
+            node_type: "SiteNode";
+            site?: string | null;
+        } | {
+            /** Format: uuid */
+            host_id: string;
+            /** @enum {string} */
+            node_type: "DeviceNode";
+            /** @description Ports with a link in this view, rendered as edge handles */
+            ports: components["schemas"]["DevicePort"][];
+            /** Format: uuid */
+            site_id: string;
+            /** @description Number of stacked chassis reported in the device's hardware inventory */
+            stack_size: number;
+        } | {
+            /** Format: uuid */
+            host_id: string;
+            /** @enum {string} */
+            node_type: "ServiceNode";
+            /** Format: uuid */
+            service_id: string;
+            subnet_ids: string[];
         };
+        /** @enum {string} */
+        NodeTypeDiscriminants: "SubnetNode" | "InterfaceNode" | "SiteNode" | "DeviceNode" | "ServiceNode";
         OidcProviderMetadata: {
             logo?: string | null;
             name: string;
@@ -4921,6 +6325,11 @@ export interface components {
             readonly updated_at: string;
         };
         OrganizationBase: {
+            /**
+             * Format: int32
+             * @description Days audit log entries are kept before being pruned
+             */
+            audit_log_retention_days: number;
             readonly has_payment_method?: boolean;
             name: string;
             onboarding: components["schemas"]["TelemetryOperation"][];
@@ -4929,6 +6338,21 @@ export interface components {
             /** Format: date-time */
             readonly trial_end_date?: string | null;
         };
+        /**
+         * @description Operating system family of a host
+         * @enum {string}
+         */
+        OsFamily: "Linux" | "Windows" | "MacOs" | "FreeBsd" | "OpenBsd" | "Solaris" | "CiscoIos" | "JunOs" | "RouterOs" | "Esxi";
+        /** @description Operating system inferred by discovery from what a host exposes on the network */
+        OsFingerprint: {
+            /** @description How strongly the signals agree on the family */
+            confidence: components["schemas"]["MatchConfidence"];
+            /** @description Signals that voted for the family, strongest first */
+            evidence: string[];
+            family: components["schemas"]["OsFamily"];
+            /** @description Release or version when a signal carries one (e.g. "Ubuntu 24.04", "11 (10.0.22631)") */
+            version?: string | null;
+        };
         /**
          * @description API metadata for paginated list responses (pagination is always present)
          * @example {
@@ -4957,6 +6381,48 @@ export interface components {
             server_version: string;
         };
         /** @description Response type for paginated list endpoints (pagination is always present in meta) */
+        PaginatedApiResponse_AuditLogEntry: {
+            data: (components["schemas"]["AuditLogEntryBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            })[];
+            error?: string | null;
+            meta: components["schemas"]["PaginatedApiMeta"];
+            success: boolean;
+        };
+        /** @description Response type for paginated list endpoints (pagination is always present in meta) */
+        PaginatedApiResponse_CustomScanProfile: {
+            data: (components["schemas"]["CustomScanProfileBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            })[];
+            error?: string | null;
+            meta: components["schemas"]["PaginatedApiMeta"];
+            success: boolean;
+        };
+        /** @description Response type for paginated list endpoints (pagination is always present in meta) */
+        PaginatedApiResponse_CustomServiceDefinition: {
+            data: (components["schemas"]["CustomServiceDefinitionBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            })[];
+            error?: string | null;
+            meta: components["schemas"]["PaginatedApiMeta"];
+            success: boolean;
+        };
+        /** @description Response type for paginated list endpoints (pagination is always present in meta) */
         PaginatedApiResponse_DaemonResponse: {
             data: (components["schemas"]["DaemonBase"] & {
                 /** Format: date-time */
@@ -4987,23 +6453,50 @@ export interface components {
             success: boolean;
         };
         /** @description Response type for paginated list endpoints (pagination is always present in meta) */
+        PaginatedApiResponse_HostChange: {
+            data: (components["schemas"]["HostChangeBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            })[];
+            error?: string | null;
+            meta: components["schemas"]["PaginatedApiMeta"];
+            success: boolean;
+        };
+        /** @description Response type for paginated list endpoints (pagination is always present in meta) */
         PaginatedApiResponse_HostResponse: {
             data: {
+                /** @description Hidden for staying offline past retention; un-hidden when seen again */
+                auto_hidden: boolean;
                 chassis_id?: string | null;
                 /** Format: date-time */
                 created_at: string;
                 description?: string | null;
+                /** Format: date-time */
+                first_seen?: string | null;
+                /** @description Hardware inventory from ENTITY-MIB (chassis, modules, PSUs, transceivers) */
+                hardware_inventory?: components["schemas"]["HardwareComponent"][];
                 hidden: boolean;
                 hostname?: string | null;
+                hostname_source?: null | components["schemas"]["HostnameSource"];
                 /** Format: uuid */
                 id: string;
                 /** @description SNMP ifTable entries */
                 if_entries: components["schemas"]["IfEntry"][];
                 interfaces: components["schemas"]["Interface"][];
+                /** Format: date-time */
+                last_seen?: string | null;
                 management_url?: string | null;
+                /** Format: int32 */
+                missed_scans: number;
                 name: string;
                 /** Format: uuid */
                 network_id: string;
+                offline: boolean;
+                os?: null | components["schemas"]["OsFingerprint"];
                 ports: components["schemas"]["Port"][];
                 services: components["schemas"]["Service"][];
                 /** Format: uuid */
@@ -5079,6 +6572,20 @@ export interface components {
             success: boolean;
         };
         /** @description Response type for paginated list endpoints (pagination is always present in meta) */
+        PaginatedApiResponse_TlsCertificate: {
+            data: (components["schemas"]["TlsCertificateBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            })[];
+            error?: string | null;
+            meta: components["schemas"]["PaginatedApiMeta"];
+            success: boolean;
+        };
+        /** @description Response type for paginated list endpoints (pagination is always present in meta) */
         PaginatedApiResponse_Topology: {
             data: (components["schemas"]["TopologyBase"] & {
                 /** Format: date-time */
@@ -5093,6 +6600,20 @@ export interface components {
             success: boolean;
         };
         /** @description Response type for paginated list endpoints (pagination is always present in meta) */
+        PaginatedApiResponse_TopologySnapshot: {
+            data: (components["schemas"]["TopologySnapshotBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            })[];
+            error?: string | null;
+            meta: components["schemas"]["PaginatedApiMeta"];
+            success: boolean;
+        };
+        /** @description Response type for paginated list endpoints (pagination is always present in meta) */
         PaginatedApiResponse_User: {
             data: (components["schemas"]["UserBase"] & {
                 /** Format: date-time */
@@ -5120,6 +6641,34 @@ export interface components {
             meta: components["schemas"]["PaginatedApiMeta"];
             success: boolean;
         };
+        /** @description Response type for paginated list endpoints (pagination is always present in meta) */
+        PaginatedApiResponse_Webhook: {
+            data: (components["schemas"]["WebhookBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            })[];
+            error?: string | null;
+            meta: components["schemas"]["PaginatedApiMeta"];
+            success: boolean;
+        };
+        /** @description Response type for paginated list endpoints (pagination is always present in meta) */
+        PaginatedApiResponse_WebhookDelivery: {
+            data: (components["schemas"]["WebhookDeliveryBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            })[];
+            error?: string | null;
+            meta: components["schemas"]["PaginatedApiMeta"];
+            success: boolean;
+        };
         /**
          * @description Pagination metadata returned with paginated responses.
          * @example {
@@ -5235,6 +6784,20 @@ export interface components {
             /** @description Transport protocol (Tcp or Udp) */
             protocol: components["schemas"]["TransportProtocol"];
         };
+        /** @description Inclusive range of port numbers. */
+        PortRange: {
+            /** Format: int32 */
+            end: number;
+            /** Format: int32 */
+            start: number;
+        };
+        /** @description A set of ports: those used by known service definitions and/or explicit ranges. */
+        PortSelection: {
+            /** @description Include the ports used by built-in and custom service definitions */
+            known?: boolean;
+            /** @description Additional ports, as inclusive ranges (use the same start and end for a single port) */
+            ranges?: components["schemas"]["PortRange"][];
+        };
         /** @description Port type with number, protocol, and optional type identifier */
         PortType: {
             number: number;
@@ -5271,12 +6834,30 @@ export interface components {
              */
             daemon_api_key: string;
         };
+        /** @description How the daemon reaches the Proxmox VE API */
+        ProxmoxConnection: {
+            /** @description API token ID in `user@realm!token` form */
+            token_id: string;
+            /** @description API token secret (redacted in API responses) */
+            token_secret: string;
+            /** @description Base URL of the Proxmox VE API, e.g. `https://pve.local:8006` */
+            url: string;
+            /** @description Verify the API's TLS certificate. Off by default since Proxmox ships a self-signed one. */
+            verify_tls: boolean;
+        };
         ProxmoxVirtualization: {
             /** Format: uuid */
             service_id: string;
             vm_id?: string | null;
             vm_name?: string | null;
         };
+        /** @description A backend a reverse proxy forwards requests to, as reported by the proxy's own API */
+        ProxyTarget: {
+            /** @description Backend address as configured in the proxy, e.g. `10.0.0.5:8080` or `http://app:3000` */
+            address: string;
+            /** @description Name of the proxy route or service forwarding to this backend, when the proxy has one */
+            route?: string | null;
+        };
         PublicConfigResponse: {
             billing_enabled: boolean;
             deployment_type: components["schemas"]["DeploymentType"];
@@ -5333,6 +6914,81 @@ export interface components {
             /** @enum {string} */
             type: "AdHoc";
         };
+        /**
+         * @description A target that network discovery must never probe.
+         *
+         *     IP-based exclusions are enforced before ARP and port scanning. A MAC OUI is only known
+         *     once a host has answered ARP (or appears in a gateway's ARP cache), so OUI exclusions
+         *     stop port scanning and service probing but can't keep a host out of the ARP sweep.
+         */
+        ScanExclusion: {
+            ip: string;
+            /** @enum {string} */
+            type: "Ip";
+        } | {
+            end: string;
+            start: string;
+            /** @enum {string} */
+            type: "Range";
+        } | {
+            cidr: string;
+            /** @enum {string} */
+            type: "Cidr";
+        } | {
+            oui: string;
+            /** @enum {string} */
+            type: "MacOui";
+        };
+        /**
+         * @description How thoroughly network discovery scans each host.
+         *
+         *     The presets trade coverage for speed: `Quick` only checks ports used by known services,
+         *     `Standard` adds the well-known range, and `Full` sweeps every TCP port. `Custom` uses an
+         *     organization's saved scan profile, so several discoveries can share the same settings.
+         */
+        ScanProfile: {
+            /** @enum {string} */
+            type: "Quick";
+        } | {
+            /** @enum {string} */
+            type: "Standard";
+        } | {
+            /** @enum {string} */
+            type: "Full";
+        } | {
+            /**
+             * Format: uuid
+             * @description ID of the organization's custom scan profile
+             */
+            profile_id: string;
+            settings?: null | components["schemas"]["ScanProfileSettings"];
+            /** @enum {string} */
+            type: "Custom";
+        };
+        /** @description Concrete scan settings a profile resolves to. */
+        ScanProfileSettings: {
+            /**
+             * Format: int64
+             * @description Abandon a host if scanning it takes longer than this many seconds
+             */
+            host_timeout_secs?: number | null;
+            /** @description Override the daemon's port scan batch size (ports scanned concurrently per host) */
+            port_scan_batch_size?: number | null;
+            /** @description Probe HTTP endpoints and headers to identify services */
+            probe_endpoints: boolean;
+            /**
+             * Format: int32
+             * @description Override the daemon's port scan rate (probes per second)
+             */
+            scan_rate_pps?: number | null;
+            /** @description TCP ports to scan */
+            tcp_ports: components["schemas"]["PortSelection"];
+            /**
+             * @description UDP ports to scan. Only ports with a protocol-specific probe (DNS, NTP, SNMP, DHCP,
+             *     BACnet) can be detected; others in the selection are skipped.
+             */
+            udp_ports: components["schemas"]["PortSelection"];
+        };
         /** @description Server capabilities returned on startup/registration */
         ServerCapabilities: {
             /** @description Deprecation warnings for the daemon */
@@ -5362,6 +7018,7 @@ export interface components {
          *       "name": "nginx",
          *       "network_id": "550e8400-e29b-41d4-a716-446655440002",
          *       "position": 0,
+         *       "proxy_targets": [],
          *       "service_definition": "NATS",
          *       "source": {
          *         "type": "Manual"
@@ -5391,6 +7048,11 @@ export interface components {
              * @description Position of this service in the host's service list (for ordering)
              */
             position: number;
+            /**
+             * @description Backends this service forwards requests to, for reverse proxies whose routes
+             *     discovery could read
+             */
+            readonly proxy_targets: components["schemas"]["ProxyTarget"][];
             service_definition: string;
             /** @description Will be automatically set to Manual for creation through API */
             source: components["schemas"]["EntitySource"];
@@ -5433,11 +7095,14 @@ export interface components {
          * @enum {string}
          */
         ServiceOrderField: "created_at" | "name" | "updated_at" | "host" | "network_id" | "position";
-        /** ServiceVirtualization */
         ServiceVirtualization: {
             details: components["schemas"]["DockerVirtualization"];
             /** @enum {string} */
             type: "Docker";
+        } | {
+            details: components["schemas"]["KubernetesVirtualization"];
+            /** @enum {string} */
+            type: "Kubernetes";
         };
         /** @description Request body for setting all tags on an entity */
         SetTagsRequest: {
@@ -5474,6 +7139,12 @@ export interface components {
         };
         ShareBase: {
             allowed_domains: string[] | null;
+            /**
+             * Format: uuid
+             * @description Snapshot of the topology to compare against. Changes since the snapshot are
+             *     highlighted in the shared view and its exports.
+             */
+            compare_snapshot_id: string | null;
             /** Format: uuid */
             created_by: string;
             /** Format: date-time */
@@ -5492,6 +7163,11 @@ export interface components {
             show_inspect_panel: boolean;
             show_zoom_controls: boolean;
         };
+        /**
+         * @description SNMPv3 USM authentication protocol
+         * @enum {string}
+         */
+        SnmpAuthProtocol: "Md5" | "Sha" | "Sha224" | "Sha256" | "Sha384" | "Sha512";
         SnmpCredential: components["schemas"]["SnmpCredentialBase"] & {
             /** Format: date-time */
             readonly created_at: string;
@@ -5501,16 +7177,27 @@ export interface components {
             readonly updated_at: string;
         };
         SnmpCredentialBase: {
+            /** @description SNMPv3 authentication passphrase (stored encrypted, redacted in API responses) */
+            auth_passphrase?: string | null;
+            /** @description SNMPv3 authentication protocol (used for AuthNoPriv and AuthPriv) */
+            auth_protocol?: components["schemas"]["SnmpAuthProtocol"];
             /**
-             * @description SNMPv2c community string (stored encrypted)
-             *     For V3, this would be extended with auth/priv credentials
+             * @description SNMPv2c community string (stored encrypted, unused for V3)
              *     Redacted in API responses for security
              */
             community: string;
             name: string;
             /** Format: uuid */
             organization_id: string;
+            /** @description SNMPv3 privacy passphrase (stored encrypted, redacted in API responses) */
+            priv_passphrase?: string | null;
+            /** @description SNMPv3 privacy protocol (used for AuthPriv) */
+            priv_protocol?: components["schemas"]["SnmpPrivProtocol"];
+            /** @description SNMPv3 security level */
+            security_level?: components["schemas"]["SnmpSecurityLevel"];
             tags: string[];
+            /** @description SNMPv3 USM username */
+            username?: string | null;
             /** @description SNMP version (V2c or V3) */
             version?: components["schemas"]["SnmpVersion"];
         };
@@ -5533,20 +7220,49 @@ export interface components {
             ip: string;
         };
         /**
-         * @description Minimal SNMP credential for daemon queries (version + community only)
+         * @description SNMPv3 USM privacy protocol
+         * @enum {string}
+         */
+        SnmpPrivProtocol: "Des" | "Aes" | "Aes192" | "Aes256";
+        /**
+         * @description Minimal SNMP credential for daemon queries (version + community or USM parameters)
          *     Does not include organization_id, name, timestamps - just what's needed for SNMP queries
          */
         SnmpQueryCredential: {
+            /** @description SNMPv3 authentication passphrase */
+            auth_passphrase?: string | null;
+            /** @description SNMPv3 authentication protocol */
+            auth_protocol?: components["schemas"]["SnmpAuthProtocol"];
             /** @description SNMPv2c community string */
             community: string;
+            /** @description SNMPv3 privacy passphrase */
+            priv_passphrase?: string | null;
+            /** @description SNMPv3 privacy protocol */
+            priv_protocol?: components["schemas"]["SnmpPrivProtocol"];
+            /** @description SNMPv3 security level */
+            security_level?: components["schemas"]["SnmpSecurityLevel"];
+            /** @description SNMPv3 USM username */
+            username?: string | null;
             /** @description SNMP version (V2c or V3) */
             version?: components["schemas"]["SnmpVersion"];
         };
+        /**
+         * @description SNMPv3 USM security level
+         * @enum {string}
+         */
+        SnmpSecurityLevel: "NoAuthNoPriv" | "AuthNoPriv" | "AuthPriv";
         /**
          * @description SNMP protocol version
          * @enum {string}
          */
         SnmpVersion: "V2c" | "V3";
+        /** @description HTTP status code range, end exclusive (e.g. 200..300) */
+        StatusCodeRange: {
+            /** Format: int32 */
+            end: number;
+            /** Format: int32 */
+            start: number;
+        };
         /**
          * @example {
          *       "cidr": "192.168.1.0/24",
@@ -5588,7 +7304,7 @@ export interface components {
          */
         SubnetOrderField: "created_at" | "name" | "cidr" | "subnet_type" | "updated_at" | "network_id";
         /** @enum {string} */
-        SubnetType: "Internet" | "Remote" | "Gateway" | "VpnTunnel" | "Dmz" | "Lan" | "WiFi" | "IoT" | "Guest" | "DockerBridge" | "MacVlan" | "IpVlan" | "Management" | "Storage" | "Unknown" | "None";
+        SubnetType: "Internet" | "Remote" | "Gateway" | "VpnTunnel" | "Dmz" | "Lan" | "WiFi" | "IoT" | "Guest" | "DockerBridge" | "KubernetesPod" | "MacVlan" | "IpVlan" | "Management" | "Storage" | "Unknown" | "None";
         /**
          * @example {
          *       "color": "Green",
@@ -5622,6 +7338,55 @@ export interface components {
         TagOrderField: "created_at" | "name" | "color" | "updated_at";
         /** @enum {string} */
         TelemetryOperation: "OrgCreated" | "OnboardingModalCompleted" | "PlanSelected" | "PersonalPlanSelected" | "CommercialPlanSelected" | "CheckoutStarted" | "CheckoutCompleted" | "TrialStarted" | "TrialEnded" | "TrialWillEnd" | "SubscriptionCancelled" | "PlanChanged" | "PaymentFailed" | "PaymentActionRequired" | "PaymentRecovered" | "FirstDaemonRegistered" | "FirstTopologyRebuild" | "FirstDiscoveryCompleted" | "FirstHostDiscovered" | "SecondNetworkCreated" | "FirstTagCreated" | "FirstUserApiKeyCreated" | "FirstSnmpCredentialCreated" | "InviteSent" | "InviteAccepted" | "FirstApiKeyCreated" | "FirstNetworkCreated";
+        TlsCertificate: components["schemas"]["TlsCertificateBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+            /** Format: date-time */
+            readonly updated_at: string;
+        };
+        TlsCertificateBase: {
+            /** @description Certificates presented after the leaf, in the order the server sent them */
+            chain: components["schemas"]["CertificateChainEntry"][];
+            /** @description SHA-256 of the leaf certificate's DER encoding, lowercase hex */
+            fingerprint_sha256: string;
+            /** Format: uuid */
+            host_id: string;
+            /** @description Issuer distinguished name (RFC 4514) */
+            issuer: string;
+            /** @description Public key algorithm and size, e.g. "RSA 2048", "EC P-256", "Ed25519" */
+            key_type: string;
+            /** Format: uuid */
+            network_id: string;
+            /** Format: date-time */
+            not_after: string;
+            /** Format: date-time */
+            not_before: string;
+            /**
+             * Format: uuid
+             * @description Port the certificate was presented on
+             */
+            port_id: string;
+            /** @description Subject and issuer are the same */
+            self_signed: boolean;
+            /** @description Serial number, lowercase hex */
+            serial_number: string;
+            /**
+             * Format: uuid
+             * @description Service bound to the port when the certificate was captured
+             */
+            service_id?: string | null;
+            /** @description Subject distinguished name (RFC 4514), e.g. "CN=nas.example.com" */
+            subject: string;
+            /** @description DNS names and IP addresses from the subjectAltName extension */
+            subject_alt_names: string[];
+        };
+        /**
+         * @description Fields that TLS certificates can be ordered/grouped by.
+         * @enum {string}
+         */
+        TlsCertificateOrderField: "not_after" | "subject" | "issuer" | "host_id" | "created_at";
         Topology: components["schemas"]["TopologyBase"] & {
             /** Format: date-time */
             readonly created_at: string;
@@ -5666,6 +7431,42 @@ export interface components {
             subnets: components["schemas"]["Subnet"][];
             tags: string[];
         };
+        /**
+         * @description How a node or edge differs between the earlier and later side of a diff
+         * @enum {string}
+         */
+        TopologyChangeType: "Added" | "Removed" | "Moved";
+        /** @description Nodes and edges that differ between two builds of a topology */
+        TopologyDiff: {
+            edges: components["schemas"]["EdgeChange"][];
+            from: components["schemas"]["TopologyDiffSide"];
+            nodes: components["schemas"]["NodeChange"][];
+            summary: components["schemas"]["TopologyDiffSummary"];
+            to: components["schemas"]["TopologyDiffSide"];
+        };
+        /** @description One side of a diff: a saved snapshot, or the topology as currently built */
+        TopologyDiffSide: {
+            /**
+             * Format: date-time
+             * @description When the side's graph was built
+             */
+            captured_at: string;
+            name: string;
+            /**
+             * Format: uuid
+             * @description Snapshot the side was read from. Null for the live topology.
+             */
+            snapshot_id: string | null;
+            /** Format: uuid */
+            topology_id: string;
+        };
+        TopologyDiffSummary: {
+            edges_added: number;
+            edges_removed: number;
+            nodes_added: number;
+            nodes_moved: number;
+            nodes_removed: number;
+        };
         /**
          * @description Lightweight request type for updating an edge's handles.
          *
@@ -5793,18 +7594,68 @@ export interface components {
             hide_vm_title_on_docker_container: boolean;
             left_zone_service_categories: components["schemas"]["ServiceCategory"][];
             show_gateway_in_left_zone: boolean;
+            view: components["schemas"]["TopologyView"];
         };
-        /** @description Filter settings for hiding entities by tag in topology visualization. */
-        TopologyTagFilter: {
-            /** @description Host tag IDs to hide (hosts with these tags will fade out) */
-            hidden_host_tag_ids?: string[];
-            /** @description Service tag IDs to hide (services with these tags will be hidden from nodes) */
-            hidden_service_tag_ids?: string[];
-            /** @description Subnet tag IDs to hide (subnets with these tags will fade out) */
-            hidden_subnet_tag_ids?: string[];
+        TopologySnapshot: components["schemas"]["TopologySnapshotBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+            /** Format: date-time */
+            readonly updated_at: string;
         };
-        /** @enum {string} */
-        TransportProtocol: "Udp" | "Tcp";
+        /** @description Named, immutable copy of a topology's graph and the entities it was built from */
+        TopologySnapshotBase: {
+            bindings: components["schemas"]["Binding"][];
+            /**
+             * Format: date-time
+             * @description When the captured graph was last built
+             */
+            captured_at: string;
+            /**
+             * Format: uuid
+             * @description User who saved the snapshot
+             */
+            created_by: string | null;
+            description: string | null;
+            edges: components["schemas"]["Edge"][];
+            entity_tags: components["schemas"]["Tag"][];
+            groups: components["schemas"]["Group"][];
+            hosts: components["schemas"]["Host"][];
+            if_entries: components["schemas"]["IfEntry"][];
+            interfaces: components["schemas"]["Interface"][];
+            name: string;
+            /** Format: uuid */
+            network_id: string;
+            nodes: components["schemas"]["Node"][];
+            options: components["schemas"]["TopologyOptions"];
+            ports: components["schemas"]["Port"][];
+            services: components["schemas"]["Service"][];
+            subnets: components["schemas"]["Subnet"][];
+            /** Format: uuid */
+            topology_id: string;
+        };
+        /**
+         * @description Fields that topology snapshots can be ordered/grouped by.
+         * @enum {string}
+         */
+        TopologySnapshotOrderField: "created_at" | "captured_at" | "name" | "topology_id";
+        /** @description Filter settings for hiding entities by tag in topology visualization. */
+        TopologyTagFilter: {
+            /** @description Host tag IDs to hide (hosts with these tags will fade out) */
+            hidden_host_tag_ids?: string[];
+            /** @description Service tag IDs to hide (services with these tags will be hidden from nodes) */
+            hidden_service_tag_ids?: string[];
+            /** @description Subnet tag IDs to hide (subnets with these tags will fade out) */
+            hidden_subnet_tag_ids?: string[];
+        };
+        /**
+         * @description Layout a topology is built with
+         * @enum {string}
+         */
+        TopologyView: "Subnet" | "Physical" | "Dependency";
+        /** @enum {string} */
+        TransportProtocol: "Udp" | "Tcp";
         TypeMetadata: {
             category: string | null;
             color: components["schemas"]["Color"];
@@ -5857,6 +7708,11 @@ export interface components {
             tags: string[];
             virtualization?: null | components["schemas"]["HostVirtualization"];
         };
+        /**
+         * @description UPnP description fields that service patterns can match on
+         * @enum {string}
+         */
+        UpnpField: "DeviceType" | "Manufacturer" | "ModelName";
         User: components["schemas"]["UserBase"] & {
             /** Format: date-time */
             readonly created_at: string;
@@ -5944,6 +7800,84 @@ export interface components {
              */
             server_version: string;
         };
+        Webhook: components["schemas"]["WebhookBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+            /** Format: date-time */
+            readonly updated_at: string;
+        };
+        WebhookBase: {
+            /** @description Disabled webhooks keep their configuration but receive no deliveries */
+            enabled?: boolean;
+            event_filter?: components["schemas"]["WebhookEventFilter"];
+            name: string;
+            /** Format: uuid */
+            organization_id: string;
+            /**
+             * @description Shared secret used to sign payloads with HMAC-SHA256 (`X-Scanopy-Signature` header).
+             *     Redacted in API responses.
+             */
+            secret: string;
+            /** @description Endpoint that receives POSTed JSON payloads (http or https) */
+            url: string;
+        };
+        WebhookDelivery: components["schemas"]["WebhookDeliveryBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+            /** Format: date-time */
+            readonly updated_at: string;
+        };
+        WebhookDeliveryBase: {
+            /** Format: int32 */
+            attempts: number;
+            /** Format: date-time */
+            delivered_at?: string | null;
+            /** @description Error from the last failed attempt */
+            error?: string | null;
+            /**
+             * Format: uuid
+             * @description Event that triggered the delivery (random for test deliveries)
+             */
+            event_id: string;
+            /** @description Event type, e.g. `host.created`, `discovery.failed` or `webhook.test` */
+            event_type: string;
+            /** Format: date-time */
+            next_attempt_at?: string | null;
+            /** Format: uuid */
+            organization_id: string;
+            /** @description JSON body sent to the endpoint */
+            payload: unknown;
+            /**
+             * Format: int32
+             * @description HTTP status of the last attempt, if the endpoint responded
+             */
+            response_status?: number | null;
+            status: components["schemas"]["WebhookDeliveryStatus"];
+            /** Format: uuid */
+            webhook_id: string;
+        };
+        /**
+         * @description State of a webhook delivery
+         * @enum {string}
+         */
+        WebhookDeliveryStatus: "Pending" | "Succeeded" | "Failed";
+        /** @description Which events a webhook receives */
+        WebhookEventFilter: {
+            /** @description Discovery session phases to deliver (e.g. Complete, Failed) */
+            discovery_phases?: components["schemas"]["DiscoveryPhase"][];
+            /** @description Entity operations to deliver. Empty delivers created, updated and deleted. */
+            entity_operations?: components["schemas"]["EntityOperation"][];
+            /** @description Entity types to deliver events for */
+            entity_types?: components["schemas"]["EntityDiscriminants"][];
+            /** @description Networks to deliver events for. Empty delivers events from all networks. */
+            network_ids?: string[];
+        };
+        /** @enum {string} */
+        WebhookOrderField: "created_at" | "name" | "updated_at";
     };
     responses: never;
     parameters: never;
@@ -6928,13 +8862,37 @@ export interface operations {
             };
         };
     };
-    "list_Daemon API Keys": {
+    get_all_audit_log_entries: {
         parameters: {
             query?: {
+                /** @description Filter by acting user, including actions through their API keys */
+                user_id?: string | null;
+                /** @description Filter by acting user, API key or daemon ID */
+                actor_id?: string | null;
+                /** @description Filter by how the actor authenticated */
+                actor_type?: null | components["schemas"]["AuthMethod"];
+                /** @description Filter by event category */
+                category?: null | components["schemas"]["AuditLogCategory"];
+                /** @description Filter by operation, e.g. `deleted` or `login_failed` */
+                operation?: string | null;
+                /** @description Filter by entity type */
+                entity_type?: null | components["schemas"]["EntityDiscriminants"];
+                /** @description Filter by entity ID (entity history) */
+                entity_id?: string | null;
                 /** @description Filter by network ID */
                 network_id?: string | null;
-                /** @description Filter by specific entity IDs (for selective loading) */
-                ids?: string[] | null;
+                /** @description Filter by client IP address */
+                ip_address?: string | null;
+                /** @description Only entries recorded at or after this time */
+                from?: string | null;
+                /** @description Only entries recorded before this time */
+                to?: string | null;
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["AuditLogEntryOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["AuditLogEntryOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -6946,54 +8904,16 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of Daemon API Keys */
-            200: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": {
-                        data: components["schemas"]["DaemonApiKey"][];
-                        error?: string | null;
-                        meta: components["schemas"]["PaginatedApiMeta"];
-                        success: boolean;
-                    };
-                };
-            };
-        };
-    };
-    create_daemon_api_key: {
-        parameters: {
-            query?: never;
-            header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["DaemonApiKey"];
-            };
-        };
-        responses: {
-            /** @description Daemon API key created */
+            /** @description List of audit log entries */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_DaemonApiKeyResponse"];
-                };
-            };
-            /** @description Bad request */
-            400: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["PaginatedApiResponse_AuditLogEntry"];
                 };
             };
-            /** @description Insufficient permissions (member+ required) */
+            /** @description Admin permissions required */
             403: {
                 headers: {
                     [name: string]: unknown;
@@ -7002,49 +8922,101 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Internal server error */
-            500: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
         };
     };
-    "bulk_delete_Daemon API Keys": {
+    export_audit_log_csv: {
         parameters: {
-            query?: never;
+            query?: {
+                /** @description Filter by acting user, including actions through their API keys */
+                user_id?: string | null;
+                /** @description Filter by acting user, API key or daemon ID */
+                actor_id?: string | null;
+                /** @description Filter by how the actor authenticated */
+                actor_type?: null | components["schemas"]["AuthMethod"];
+                /** @description Filter by event category */
+                category?: null | components["schemas"]["AuditLogCategory"];
+                /** @description Filter by operation, e.g. `deleted` or `login_failed` */
+                operation?: string | null;
+                /** @description Filter by entity type */
+                entity_type?: null | components["schemas"]["EntityDiscriminants"];
+                /** @description Filter by entity ID (entity history) */
+                entity_id?: string | null;
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by client IP address */
+                ip_address?: string | null;
+                /** @description Only entries recorded at or after this time */
+                from?: string | null;
+                /** @description Only entries recorded before this time */
+                to?: string | null;
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["AuditLogEntryOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["AuditLogEntryOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of Daemon API Key IDs to delete */
-        requestBody: {
-            content: {
-                "application/json": string[];
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description Daemon API Keys deleted */
+            /** @description CSV file containing audit log entries */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                    "text/csv": unknown;
+                };
+            };
+            /** @description Admin permissions required */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    "export_Daemon API Keys_csv": {
+    export_audit_log_json: {
         parameters: {
             query?: {
+                /** @description Filter by acting user, including actions through their API keys */
+                user_id?: string | null;
+                /** @description Filter by acting user, API key or daemon ID */
+                actor_id?: string | null;
+                /** @description Filter by how the actor authenticated */
+                actor_type?: null | components["schemas"]["AuthMethod"];
+                /** @description Filter by event category */
+                category?: null | components["schemas"]["AuditLogCategory"];
+                /** @description Filter by operation, e.g. `deleted` or `login_failed` */
+                operation?: string | null;
+                /** @description Filter by entity type */
+                entity_type?: null | components["schemas"]["EntityDiscriminants"];
+                /** @description Filter by entity ID (entity history) */
+                entity_id?: string | null;
                 /** @description Filter by network ID */
                 network_id?: string | null;
-                /** @description Filter by specific entity IDs (for selective loading) */
-                ids?: string[] | null;
+                /** @description Filter by client IP address */
+                ip_address?: string | null;
+                /** @description Only entries recorded at or after this time */
+                from?: string | null;
+                /** @description Only entries recorded before this time */
+                to?: string | null;
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["AuditLogEntryOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["AuditLogEntryOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -7056,40 +9028,46 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description CSV file containing Daemon API Keys */
+            /** @description JSON file containing audit log entries */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "text/csv": unknown;
+                    "application/json": components["schemas"]["AuditLogEntry"][];
+                };
+            };
+            /** @description Admin permissions required */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    "get_Daemon API Key_by_id": {
+    get_audit_log_settings: {
         parameters: {
             query?: never;
             header?: never;
-            path: {
-                /** @description Daemon API Key ID */
-                id: string;
-            };
+            path?: never;
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Daemon API Key found */
+            /** @description Audit log settings */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_DaemonApiKey"];
+                    "application/json": components["schemas"]["ApiResponse_AuditLogSettings"];
                 };
             };
-            /** @description Daemon API Key not found */
-            404: {
+            /** @description Admin permissions required */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -7099,33 +9077,39 @@ export interface operations {
             };
         };
     };
-    update_daemon_api_key: {
+    update_audit_log_settings: {
         parameters: {
             query?: never;
             header?: never;
-            path: {
-                /** @description Daemon API key ID */
-                id: string;
-            };
+            path?: never;
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["DaemonApiKey"];
+                "application/json": components["schemas"]["AuditLogSettings"];
             };
         };
         responses: {
-            /** @description Daemon API key updated */
+            /** @description Audit log settings updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_DaemonApiKey"];
+                    "application/json": components["schemas"]["ApiResponse_AuditLogSettings"];
                 };
             };
-            /** @description Daemon API key not found */
-            404: {
+            /** @description Invalid retention period */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Admin permissions required */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -7135,28 +9119,37 @@ export interface operations {
             };
         };
     };
-    "delete_Daemon API Key": {
+    get_audit_log_entry: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Daemon API Key ID */
+                /** @description Audit log entry ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Daemon API Key deleted */
+            /** @description Audit log entry */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiResponse_AuditLogEntry"];
                 };
             };
-            /** @description Daemon API Key not found */
+            /** @description Admin permissions required */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Audit log entry not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7167,19 +9160,258 @@ export interface operations {
             };
         };
     };
-    rotate_key_handler: {
+    "list_Daemon API Keys": {
         parameters: {
-            query?: never;
-            header?: never;
-            path: {
-                /** @description Daemon API key ID */
-                id: string;
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
             };
+            header?: never;
+            path?: never;
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Daemon API key rotated, returns new key */
+            /** @description List of Daemon API Keys */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": {
+                        data: components["schemas"]["DaemonApiKey"][];
+                        error?: string | null;
+                        meta: components["schemas"]["PaginatedApiMeta"];
+                        success: boolean;
+                    };
+                };
+            };
+        };
+    };
+    create_daemon_api_key: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["DaemonApiKey"];
+            };
+        };
+        responses: {
+            /** @description Daemon API key created */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_DaemonApiKeyResponse"];
+                };
+            };
+            /** @description Bad request */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Insufficient permissions (member+ required) */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Internal server error */
+            500: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    "bulk_delete_Daemon API Keys": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** @description Array of Daemon API Key IDs to delete */
+        requestBody: {
+            content: {
+                "application/json": string[];
+            };
+        };
+        responses: {
+            /** @description Daemon API Keys deleted */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                };
+            };
+        };
+    };
+    "export_Daemon API Keys_csv": {
+        parameters: {
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description CSV file containing Daemon API Keys */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "text/csv": unknown;
+                };
+            };
+        };
+    };
+    "get_Daemon API Key_by_id": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Daemon API Key ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Daemon API Key found */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_DaemonApiKey"];
+                };
+            };
+            /** @description Daemon API Key not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    update_daemon_api_key: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Daemon API key ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["DaemonApiKey"];
+            };
+        };
+        responses: {
+            /** @description Daemon API key updated */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_DaemonApiKey"];
+                };
+            };
+            /** @description Daemon API key not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    "delete_Daemon API Key": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Daemon API Key ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Daemon API Key deleted */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Daemon API Key not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    rotate_key_handler: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Daemon API key ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Daemon API key rotated, returns new key */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -7740,15 +9972,13 @@ export interface operations {
             };
         };
     };
-    get_daemons: {
+    get_all_custom_scan_profiles: {
         parameters: {
             query?: {
-                /** @description Filter by network ID */
-                network_id?: string | null;
                 /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
-                group_by?: null | components["schemas"]["DaemonOrderField"];
+                group_by?: null | components["schemas"]["CustomScanProfileOrderField"];
                 /** @description Secondary ordering field (sorting within groups or standalone sort). */
-                order_by?: null | components["schemas"]["DaemonOrderField"];
+                order_by?: null | components["schemas"]["CustomScanProfileOrderField"];
                 /** @description Direction for order_by field (group_by always uses ASC). */
                 order_direction?: null | components["schemas"]["OrderDirection"];
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
@@ -7762,76 +9992,60 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of daemons */
+            /** @description List of custom scan profiles */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["PaginatedApiResponse_DaemonResponse"];
+                    "application/json": components["schemas"]["PaginatedApiResponse_CustomScanProfile"];
                 };
             };
         };
     };
-    bulk_delete_Daemons: {
+    create_custom_scan_profile: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of Daemon IDs to delete */
         requestBody: {
             content: {
-                "application/json": string[];
+                "application/json": components["schemas"]["CustomScanProfile"];
             };
         };
         responses: {
-            /** @description Daemons deleted */
+            /** @description Custom scan profile created successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                    "application/json": components["schemas"]["ApiResponse_CustomScanProfile"];
                 };
             };
-        };
-    };
-    export_Daemons_csv: {
-        parameters: {
-            query?: {
-                /** @description Filter by network ID */
-                network_id?: string | null;
-                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
-                group_by?: null | components["schemas"]["DaemonOrderField"];
-                /** @description Secondary ordering field (sorting within groups or standalone sort). */
-                order_by?: null | components["schemas"]["DaemonOrderField"];
-                /** @description Direction for order_by field (group_by always uses ASC). */
-                order_direction?: null | components["schemas"]["OrderDirection"];
-                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
-                limit?: number | null;
-                /** @description Number of results to skip. Default: 0. */
-                offset?: number | null;
+            /** @description Validation error */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
             };
-            header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        requestBody?: never;
-        responses: {
-            /** @description CSV file containing Daemons */
-            200: {
+            /** @description Profile name already exists in this organization */
+            409: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "text/csv": unknown;
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    provision_daemon: {
+    bulk_delete_custom_scan_profiles: {
         parameters: {
             query?: never;
             header?: never;
@@ -7840,20 +10054,20 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["ProvisionDaemonRequest"];
+                "application/json": string[];
             };
         };
         responses: {
-            /** @description Daemon provisioned successfully */
-            201: {
+            /** @description Custom scan profiles deleted successfully */
+            200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_ProvisionDaemonResponse"];
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
-            /** @description Invalid request */
+            /** @description Validation error */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -7862,8 +10076,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Forbidden */
-            403: {
+            /** @description A scan profile is used by a discovery */
+            409: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -7873,37 +10087,59 @@ export interface operations {
             };
         };
     };
-    get_daemon_by_id: {
+    "export_Custom Scan Profiles_csv": {
         parameters: {
-            query?: never;
-            header?: never;
-            path: {
-                /** @description Daemon ID */
-                id: string;
+            query?: {
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["CustomScanProfileOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["CustomScanProfileOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
             };
+            header?: never;
+            path?: never;
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Daemon found */
+            /** @description CSV file containing Custom Scan Profiles */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_DaemonResponse"];
+                    "text/csv": unknown;
                 };
             };
-            /** @description Access denied */
-            403: {
+        };
+    };
+    "get_Custom Scan Profile_by_id": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Custom Scan Profile ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Custom Scan Profile found */
+            200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_CustomScanProfile"];
                 };
             };
-            /** @description Daemon not found */
+            /** @description Custom Scan Profile not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7914,28 +10150,32 @@ export interface operations {
             };
         };
     };
-    delete_Daemon: {
+    "update_Custom Scan Profile": {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Daemon ID */
+                /** @description Custom Scan Profile ID */
                 id: string;
             };
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["CustomScanProfile"];
+            };
+        };
         responses: {
-            /** @description Daemon deleted */
+            /** @description Custom Scan Profile updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiResponse_CustomScanProfile"];
                 };
             };
-            /** @description Daemon not found */
+            /** @description Custom Scan Profile not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7946,19 +10186,19 @@ export interface operations {
             };
         };
     };
-    retry_daemon_connection: {
+    delete_custom_scan_profile: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Daemon ID */
+                /** @description custom_scan_profile ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Connection retry initiated */
+            /** @description custom_scan_profile deleted successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -7967,8 +10207,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Access denied */
-            403: {
+            /** @description custom_scan_profile not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -7976,8 +10216,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Daemon not found */
-            404: {
+            /** @description Scan profile is used by a discovery */
+            409: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -7987,13 +10227,15 @@ export interface operations {
             };
         };
     };
-    list_Discoveries: {
+    get_all_custom_service_definitions: {
         parameters: {
             query?: {
-                /** @description Filter by network ID */
-                network_id?: string | null;
-                /** @description Filter by daemon ID */
-                daemon_id?: string | null;
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["CustomServiceDefinitionOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["CustomServiceDefinitionOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -8005,23 +10247,18 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of Discoveries */
+            /** @description List of custom service definitions */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": {
-                        data: components["schemas"]["Discovery"][];
-                        error?: string | null;
-                        meta: components["schemas"]["PaginatedApiMeta"];
-                        success: boolean;
-                    };
+                    "application/json": components["schemas"]["PaginatedApiResponse_CustomServiceDefinition"];
                 };
             };
         };
     };
-    create_discovery: {
+    create_custom_service_definition: {
         parameters: {
             query?: never;
             header?: never;
@@ -8030,20 +10267,20 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Discovery"];
+                "application/json": components["schemas"]["CustomServiceDefinition"];
             };
         };
         responses: {
-            /** @description Discovery created successfully */
+            /** @description Custom service definition created successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Discovery"];
+                    "application/json": components["schemas"]["ApiResponse_CustomServiceDefinition"];
                 };
             };
-            /** @description Can't create historical discovery */
+            /** @description Validation error */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -8052,43 +10289,31 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-        };
-    };
-    get_active_sessions: {
-        parameters: {
-            query?: never;
-            header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        requestBody?: never;
-        responses: {
-            /** @description List of active discovery sessions */
-            200: {
+            /** @description Definition name already exists in this organization */
+            409: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Vec_DiscoveryUpdatePayload"];
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    bulk_delete_Discoveries: {
+    bulk_delete_custom_service_definitions: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of Discovery IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description Discoveries deleted */
+            /** @description Custom service definitions deleted successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -8097,15 +10322,26 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
+            /** @description Validation error */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
         };
     };
-    export_Discoveries_csv: {
+    "export_Custom Service Definitions_csv": {
         parameters: {
             query?: {
-                /** @description Filter by network ID */
-                network_id?: string | null;
-                /** @description Filter by daemon ID */
-                daemon_id?: string | null;
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["CustomServiceDefinitionOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["CustomServiceDefinitionOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -8117,7 +10353,7 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description CSV file containing Discoveries */
+            /** @description CSV file containing Custom Service Definitions */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -8128,61 +10364,48 @@ export interface operations {
             };
         };
     };
-    start_session: {
+    get_custom_service_definition_metadata: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "text/plain": string;
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description Discovery session started */
+            /** @description Custom service definition metadata */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_DiscoveryUpdatePayload"];
-                };
-            };
-            /** @description Discovery not found */
-            404: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Vec_TypeMetadata"];
                 };
             };
         };
     };
-    get_Discovery_by_id: {
+    "get_Custom Service Definition_by_id": {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Discovery ID */
+                /** @description Custom Service Definition ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Discovery found */
+            /** @description Custom Service Definition found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Discovery"];
+                    "application/json": components["schemas"]["ApiResponse_CustomServiceDefinition"];
                 };
             };
-            /** @description Discovery not found */
+            /** @description Custom Service Definition not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -8193,32 +10416,32 @@ export interface operations {
             };
         };
     };
-    update_discovery: {
+    update_custom_service_definition: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Discovery ID */
+                /** @description custom_service_definition ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Discovery"];
+                "application/json": components["schemas"]["CustomServiceDefinition"];
             };
         };
         responses: {
-            /** @description Discovery updated successfully */
+            /** @description custom_service_definition updated successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Discovery"];
+                    "application/json": components["schemas"]["ApiResponse_CustomServiceDefinition"];
                 };
             };
-            /** @description Can't update historical discovery */
+            /** @description Validation error */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -8227,21 +10450,30 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
+            /** @description custom_service_definition not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
         };
     };
-    delete_Discovery: {
+    delete_custom_service_definition: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Discovery ID */
+                /** @description custom_service_definition ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Discovery deleted */
+            /** @description custom_service_definition deleted successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -8250,7 +10482,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Discovery not found */
+            /** @description custom_service_definition not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -8261,65 +10493,73 @@ export interface operations {
             };
         };
     };
-    cancel_discovery: {
+    get_daemons: {
         parameters: {
-            query?: never;
-            header?: never;
-            path: {
-                /** @description Session ID */
-                session_id: string;
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["DaemonOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["DaemonOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
             };
+            header?: never;
+            path?: never;
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Discovery session cancelled */
+            /** @description List of daemons */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["PaginatedApiResponse_DaemonResponse"];
                 };
             };
         };
     };
-    receive_discovery_update: {
+    bulk_delete_Daemons: {
         parameters: {
             query?: never;
             header?: never;
-            path: {
-                /** @description Discovery session ID */
-                session_id: string;
-            };
+            path?: never;
             cookie?: never;
         };
+        /** @description Array of Daemon IDs to delete */
         requestBody: {
             content: {
-                "application/json": components["schemas"]["DiscoveryUpdatePayload"];
+                "application/json": string[];
             };
         };
         responses: {
-            /** @description Update received */
+            /** @description Daemons deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
         };
     };
-    get_all_groups: {
+    export_Daemons_csv: {
         parameters: {
             query?: {
                 /** @description Filter by network ID */
                 network_id?: string | null;
                 /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
-                group_by?: null | components["schemas"]["GroupOrderField"];
+                group_by?: null | components["schemas"]["DaemonOrderField"];
                 /** @description Secondary ordering field (sorting within groups or standalone sort). */
-                order_by?: null | components["schemas"]["GroupOrderField"];
+                order_by?: null | components["schemas"]["DaemonOrderField"];
                 /** @description Direction for order_by field (group_by always uses ASC). */
                 order_direction?: null | components["schemas"]["OrderDirection"];
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
@@ -8333,18 +10573,18 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of groups */
+            /** @description CSV file containing Daemons */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["PaginatedApiResponse_Group"];
+                    "text/csv": unknown;
                 };
             };
         };
     };
-    create_group: {
+    provision_daemon: {
         parameters: {
             query?: never;
             header?: never;
@@ -8353,17 +10593,17 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Group"];
+                "application/json": components["schemas"]["ProvisionDaemonRequest"];
             };
         };
         responses: {
-            /** @description Group created successfully */
-            200: {
+            /** @description Daemon provisioned successfully */
+            201: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Group"];
+                    "application/json": components["schemas"]["ApiResponse_ProvisionDaemonResponse"];
                 };
             };
             /** @description Invalid request */
@@ -8375,23 +10615,1275 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
+            /** @description Forbidden */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_daemon_by_id: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Daemon ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Daemon found */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_DaemonResponse"];
+                };
+            };
+            /** @description Access denied */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Daemon not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    delete_Daemon: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Daemon ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Daemon deleted */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Daemon not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    retry_daemon_connection: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Daemon ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Connection retry initiated */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Access denied */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Daemon not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    list_Discoveries: {
+        parameters: {
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by daemon ID */
+                daemon_id?: string | null;
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of Discoveries */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": {
+                        data: components["schemas"]["Discovery"][];
+                        error?: string | null;
+                        meta: components["schemas"]["PaginatedApiMeta"];
+                        success: boolean;
+                    };
+                };
+            };
+        };
+    };
+    create_discovery: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["Discovery"];
+            };
+        };
+        responses: {
+            /** @description Discovery created successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Discovery"];
+                };
+            };
+            /** @description Can't create historical discovery */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_active_sessions: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of active discovery sessions */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Vec_DiscoveryUpdatePayload"];
+                };
+            };
+        };
+    };
+    bulk_delete_Discoveries: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** @description Array of Discovery IDs to delete */
+        requestBody: {
+            content: {
+                "application/json": string[];
+            };
+        };
+        responses: {
+            /** @description Discoveries deleted */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                };
+            };
+        };
+    };
+    export_Discoveries_csv: {
+        parameters: {
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by daemon ID */
+                daemon_id?: string | null;
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description CSV file containing Discoveries */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "text/csv": unknown;
+                };
+            };
+        };
+    };
+    start_session: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "text/plain": string;
+            };
+        };
+        responses: {
+            /** @description Discovery session started */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_DiscoveryUpdatePayload"];
+                };
+            };
+            /** @description Discovery not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_Discovery_by_id: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Discovery ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Discovery found */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Discovery"];
+                };
+            };
+            /** @description Discovery not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    update_discovery: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Discovery ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["Discovery"];
+            };
+        };
+        responses: {
+            /** @description Discovery updated successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Discovery"];
+                };
+            };
+            /** @description Can't update historical discovery */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    delete_Discovery: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Discovery ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Discovery deleted */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Discovery not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    cancel_discovery: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Session ID */
+                session_id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Discovery session cancelled */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+        };
+    };
+    receive_discovery_update: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Discovery session ID */
+                session_id: string;
+            };
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["DiscoveryUpdatePayload"];
+            };
+        };
+        responses: {
+            /** @description Update received */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+        };
+    };
+    get_all_groups: {
+        parameters: {
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["GroupOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["GroupOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of groups */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["PaginatedApiResponse_Group"];
+                };
+            };
+        };
+    };
+    create_group: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["Group"];
+            };
+        };
+        responses: {
+            /** @description Group created successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Group"];
+                };
+            };
+            /** @description Invalid request */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    bulk_delete_Groups: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** @description Array of Group IDs to delete */
+        requestBody: {
+            content: {
+                "application/json": string[];
+            };
+        };
+        responses: {
+            /** @description Groups deleted */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                };
+            };
+        };
+    };
+    export_Groups_csv: {
+        parameters: {
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["GroupOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["GroupOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description CSV file containing Groups */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "text/csv": unknown;
+                };
+            };
+        };
+    };
+    get_Group_by_id: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Group ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Group found */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Group"];
+                };
+            };
+            /** @description Group not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    update_group: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Group ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["Group"];
+            };
+        };
+        responses: {
+            /** @description Group updated successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Group"];
+                };
+            };
+            /** @description Invalid request */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Group not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    delete_Group: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Group ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Group deleted */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Group not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_all_host_changes: {
+        parameters: {
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by host ID (host timeline) */
+                host_id?: string | null;
+                /** @description Filter by discovery session ID (session diff) */
+                session_id?: string | null;
+                /** @description Filter by change type */
+                change_type?: null | components["schemas"]["HostChangeType"];
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["HostChangeOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["HostChangeOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of host changes */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["PaginatedApiResponse_HostChange"];
+                };
+            };
+        };
+    };
+    "export_Host Changes_csv": {
+        parameters: {
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by host ID (host timeline) */
+                host_id?: string | null;
+                /** @description Filter by discovery session ID (session diff) */
+                session_id?: string | null;
+                /** @description Filter by change type */
+                change_type?: null | components["schemas"]["HostChangeType"];
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["HostChangeOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["HostChangeOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description CSV file containing Host Changes */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "text/csv": unknown;
+                };
+            };
+        };
+    };
+    "get_Host Change_by_id": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Host Change ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Host Change found */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_HostChange"];
+                };
+            };
+            /** @description Host Change not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_all_hosts: {
+        parameters: {
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
+                /** @description Filter by tag IDs (returns hosts that have ANY of the specified tags) */
+                tag_ids?: string[] | null;
+                /** @description Filter by liveness (true returns hosts marked offline after missed scans) */
+                offline?: boolean | null;
+                /** @description Filter by the OS family inferred by discovery */
+                os_family?: null | components["schemas"]["OsFamily"];
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["HostOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["HostOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of hosts with their children */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["PaginatedApiResponse_HostResponse"];
+                };
+            };
+        };
+    };
+    create_host: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["CreateHostRequest"];
+            };
+        };
+        responses: {
+            /** @description Host created successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                };
+            };
+            /** @description Validation error: network not found, subnet mismatch, or invalid tags */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description No access to the specified network */
+            401: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    bulk_delete_hosts: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** @description Array of host IDs to delete */
+        requestBody: {
+            content: {
+                "application/json": string[];
+            };
+        };
+        responses: {
+            /** @description Hosts deleted successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                };
+            };
+            /** @description One or more hosts has an associated daemon - delete daemons first */
+            409: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    create_host_discovery: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["DiscoveryHostRequest"];
+            };
+        };
+        responses: {
+            /** @description Host discovered/updated successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                };
+            };
+            /** @description Daemon cannot create hosts on other networks */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    export_Hosts_csv: {
+        parameters: {
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
+                /** @description Filter by tag IDs (returns hosts that have ANY of the specified tags) */
+                tag_ids?: string[] | null;
+                /** @description Filter by liveness (true returns hosts marked offline after missed scans) */
+                offline?: boolean | null;
+                /** @description Filter by the OS family inferred by discovery */
+                os_family?: null | components["schemas"]["OsFamily"];
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["HostOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["HostOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description CSV file containing Hosts */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "text/csv": unknown;
+                };
+            };
+        };
+    };
+    export_hosts_zip: {
+        parameters: {
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
+                /** @description Filter by tag IDs (returns hosts that have ANY of the specified tags) */
+                tag_ids?: string[] | null;
+                /** @description Filter by liveness (true returns hosts marked offline after missed scans) */
+                offline?: boolean | null;
+                /** @description Filter by the OS family inferred by discovery */
+                os_family?: null | components["schemas"]["OsFamily"];
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["HostOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["HostOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description ZIP file containing CSVs */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/zip": unknown;
+                };
+            };
+        };
+    };
+    consolidate_hosts: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Destination host ID - will receive all children */
+                destination_host: string;
+                /** @description Host to merge into destination - will be deleted */
+                other_host: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Hosts consolidated successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                };
+            };
+            /** @description Validation error: same host, has daemon, or different networks */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description One or both hosts not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_host_by_id: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Host ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Host found */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                };
+            };
+            /** @description Host not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    update_host: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Host ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["UpdateHostRequest"];
+            };
+        };
+        responses: {
+            /** @description Host updated */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                };
+            };
+            /** @description Validation error: invalid tags */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Host not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    delete_host: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Host ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Host deleted */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Host not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Host has associated daemon */
+            409: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    "list_ifTable Entries": {
+        parameters: {
+            query?: {
+                /** @description Filter by host ID */
+                host_id?: string | null;
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of ifTable Entries */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": {
+                        data: components["schemas"]["IfEntry"][];
+                        error?: string | null;
+                        meta: components["schemas"]["PaginatedApiMeta"];
+                        success: boolean;
+                    };
+                };
+            };
+        };
+    };
+    create_if_entry: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["IfEntry"];
+            };
+        };
+        responses: {
+            /** @description If entry created successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_IfEntry"];
+                };
+            };
+            /** @description Network mismatch or duplicate if_index */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
         };
     };
-    bulk_delete_Groups: {
+    "bulk_delete_ifTable Entries": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of Group IDs to delete */
+        /** @description Array of ifTable Entry IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description Groups deleted */
+            /** @description ifTable Entries deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -8402,17 +11894,15 @@ export interface operations {
             };
         };
     };
-    export_Groups_csv: {
+    "export_ifTable Entries_csv": {
         parameters: {
             query?: {
+                /** @description Filter by host ID */
+                host_id?: string | null;
                 /** @description Filter by network ID */
                 network_id?: string | null;
-                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
-                group_by?: null | components["schemas"]["GroupOrderField"];
-                /** @description Secondary ordering field (sorting within groups or standalone sort). */
-                order_by?: null | components["schemas"]["GroupOrderField"];
-                /** @description Direction for order_by field (group_by always uses ASC). */
-                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -8424,7 +11914,7 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description CSV file containing Groups */
+            /** @description CSV file containing ifTable Entries */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -8435,28 +11925,28 @@ export interface operations {
             };
         };
     };
-    get_Group_by_id: {
+    "get_ifTable Entry_by_id": {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Group ID */
+                /** @description ifTable Entry ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Group found */
+            /** @description ifTable Entry found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Group"];
+                    "application/json": components["schemas"]["ApiResponse_IfEntry"];
                 };
             };
-            /** @description Group not found */
+            /** @description ifTable Entry not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -8467,32 +11957,32 @@ export interface operations {
             };
         };
     };
-    update_group: {
+    update_if_entry: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Group ID */
+                /** @description If entry ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Group"];
+                "application/json": components["schemas"]["IfEntry"];
             };
         };
         responses: {
-            /** @description Group updated successfully */
+            /** @description If entry updated successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Group"];
+                    "application/json": components["schemas"]["ApiResponse_IfEntry"];
                 };
             };
-            /** @description Invalid request */
+            /** @description Network mismatch or invalid request */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -8501,7 +11991,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Group not found */
+            /** @description If entry not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -8512,19 +12002,19 @@ export interface operations {
             };
         };
     };
-    delete_Group: {
+    "delete_ifTable Entry": {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Group ID */
+                /** @description ifTable Entry ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Group deleted */
+            /** @description ifTable Entry deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -8533,7 +12023,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Group not found */
+            /** @description ifTable Entry not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -8544,21 +12034,15 @@ export interface operations {
             };
         };
     };
-    get_all_hosts: {
+    list_Interfaces: {
         parameters: {
             query?: {
+                /** @description Filter by host ID */
+                host_id?: string | null;
+                /** @description Filter by subnet ID */
+                subnet_id?: string | null;
                 /** @description Filter by network ID */
                 network_id?: string | null;
-                /** @description Filter by specific entity IDs (for selective loading) */
-                ids?: string[] | null;
-                /** @description Filter by tag IDs (returns hosts that have ANY of the specified tags) */
-                tag_ids?: string[] | null;
-                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
-                group_by?: null | components["schemas"]["HostOrderField"];
-                /** @description Secondary ordering field (sorting within groups or standalone sort). */
-                order_by?: null | components["schemas"]["HostOrderField"];
-                /** @description Direction for order_by field (group_by always uses ASC). */
-                order_direction?: null | components["schemas"]["OrderDirection"];
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -8570,18 +12054,23 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of hosts with their children */
+            /** @description List of Interfaces */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["PaginatedApiResponse_HostResponse"];
+                    "application/json": {
+                        data: components["schemas"]["Interface"][];
+                        error?: string | null;
+                        meta: components["schemas"]["PaginatedApiMeta"];
+                        success: boolean;
+                    };
                 };
             };
         };
     };
-    create_host: {
+    create_interface: {
         parameters: {
             query?: never;
             header?: never;
@@ -8590,20 +12079,20 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["CreateHostRequest"];
+                "application/json": components["schemas"]["Interface"];
             };
         };
         responses: {
-            /** @description Host created successfully */
+            /** @description Interface created successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Interface"];
                 };
             };
-            /** @description Validation error: network not found, subnet mismatch, or invalid tags */
+            /** @description Network mismatch or invalid request */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -8612,32 +12101,22 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description No access to the specified network */
-            401: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
         };
     };
-    bulk_delete_hosts: {
+    bulk_delete_interfaces: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of host IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description Hosts deleted successfully */
+            /** @description Interfaces deleted successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -8646,41 +12125,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
-            /** @description One or more hosts has an associated daemon - delete daemons first */
-            409: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
-        };
-    };
-    create_host_discovery: {
-        parameters: {
-            query?: never;
-            header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["DiscoveryHostRequest"];
-            };
-        };
-        responses: {
-            /** @description Host discovered/updated successfully */
-            200: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
-                };
-            };
-            /** @description Daemon cannot create hosts on other networks */
-            403: {
+            /** @description No IDs provided */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -8690,58 +12136,15 @@ export interface operations {
             };
         };
     };
-    export_Hosts_csv: {
-        parameters: {
-            query?: {
-                /** @description Filter by network ID */
-                network_id?: string | null;
-                /** @description Filter by specific entity IDs (for selective loading) */
-                ids?: string[] | null;
-                /** @description Filter by tag IDs (returns hosts that have ANY of the specified tags) */
-                tag_ids?: string[] | null;
-                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
-                group_by?: null | components["schemas"]["HostOrderField"];
-                /** @description Secondary ordering field (sorting within groups or standalone sort). */
-                order_by?: null | components["schemas"]["HostOrderField"];
-                /** @description Direction for order_by field (group_by always uses ASC). */
-                order_direction?: null | components["schemas"]["OrderDirection"];
-                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
-                limit?: number | null;
-                /** @description Number of results to skip. Default: 0. */
-                offset?: number | null;
-            };
-            header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        requestBody?: never;
-        responses: {
-            /** @description CSV file containing Hosts */
-            200: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "text/csv": unknown;
-                };
-            };
-        };
-    };
-    export_hosts_zip: {
+    export_Interfaces_csv: {
         parameters: {
             query?: {
+                /** @description Filter by host ID */
+                host_id?: string | null;
+                /** @description Filter by subnet ID */
+                subnet_id?: string | null;
                 /** @description Filter by network ID */
                 network_id?: string | null;
-                /** @description Filter by specific entity IDs (for selective loading) */
-                ids?: string[] | null;
-                /** @description Filter by tag IDs (returns hosts that have ANY of the specified tags) */
-                tag_ids?: string[] | null;
-                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
-                group_by?: null | components["schemas"]["HostOrderField"];
-                /** @description Secondary ordering field (sorting within groups or standalone sort). */
-                order_by?: null | components["schemas"]["HostOrderField"];
-                /** @description Direction for order_by field (group_by always uses ASC). */
-                order_direction?: null | components["schemas"]["OrderDirection"];
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -8751,84 +12154,41 @@ export interface operations {
             path?: never;
             cookie?: never;
         };
-        requestBody?: never;
-        responses: {
-            /** @description ZIP file containing CSVs */
-            200: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/zip": unknown;
-                };
-            };
-        };
-    };
-    consolidate_hosts: {
-        parameters: {
-            query?: never;
-            header?: never;
-            path: {
-                /** @description Destination host ID - will receive all children */
-                destination_host: string;
-                /** @description Host to merge into destination - will be deleted */
-                other_host: string;
-            };
-            cookie?: never;
-        };
-        requestBody?: never;
-        responses: {
-            /** @description Hosts consolidated successfully */
-            200: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
-                };
-            };
-            /** @description Validation error: same host, has daemon, or different networks */
-            400: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
-            /** @description One or both hosts not found */
-            404: {
+        requestBody?: never;
+        responses: {
+            /** @description CSV file containing Interfaces */
+            200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "text/csv": unknown;
                 };
             };
         };
     };
-    get_host_by_id: {
+    get_Interface_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Host ID */
+                /** @description Interface ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Host found */
+            /** @description Interface found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Interface"];
                 };
             };
-            /** @description Host not found */
+            /** @description Interface not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -8839,32 +12199,32 @@ export interface operations {
             };
         };
     };
-    update_host: {
+    update_interface: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Host ID */
+                /** @description Interface ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["UpdateHostRequest"];
+                "application/json": components["schemas"]["Interface"];
             };
         };
         responses: {
-            /** @description Host updated */
+            /** @description Interface updated successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Interface"];
                 };
             };
-            /** @description Validation error: invalid tags */
+            /** @description Network mismatch or invalid request */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -8873,7 +12233,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Host not found */
+            /** @description Interface not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -8884,19 +12244,19 @@ export interface operations {
             };
         };
     };
-    delete_host: {
+    delete_interface: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Host ID */
+                /** @description Interface ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Host deleted */
+            /** @description Interface deleted successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -8905,7 +12265,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Host not found */
+            /** @description Interface not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -8914,54 +12274,29 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Host has associated daemon */
-            409: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
         };
     };
-    "list_ifTable Entries": {
+    get_invites: {
         parameters: {
-            query?: {
-                /** @description Filter by host ID */
-                host_id?: string | null;
-                /** @description Filter by network ID */
-                network_id?: string | null;
-                /** @description Filter by specific entity IDs (for selective loading) */
-                ids?: string[] | null;
-                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
-                limit?: number | null;
-                /** @description Number of results to skip. Default: 0. */
-                offset?: number | null;
-            };
+            query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description List of ifTable Entries */
+            /** @description List of active invites */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": {
-                        data: components["schemas"]["IfEntry"][];
-                        error?: string | null;
-                        meta: components["schemas"]["PaginatedApiMeta"];
-                        success: boolean;
-                    };
+                    "application/json": components["schemas"]["ApiResponse_Vec_Invite"];
                 };
             };
         };
     };
-    create_if_entry: {
+    create_invite: {
         parameters: {
             query?: never;
             header?: never;
@@ -8970,21 +12305,21 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["IfEntry"];
+                "application/json": components["schemas"]["CreateInviteRequest"];
             };
         };
         responses: {
-            /** @description If entry created successfully */
+            /** @description Invite created */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_IfEntry"];
+                    "application/json": components["schemas"]["ApiResponse_Invite"];
                 };
             };
-            /** @description Network mismatch or duplicate if_index */
-            400: {
+            /** @description Cannot create invite with higher permissions */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -8994,85 +12329,38 @@ export interface operations {
             };
         };
     };
-    "bulk_delete_ifTable Entries": {
+    get_invite: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        /** @description Array of ifTable Entry IDs to delete */
-        requestBody: {
-            content: {
-                "application/json": string[];
-            };
-        };
-        responses: {
-            /** @description ifTable Entries deleted */
-            200: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
-                };
-            };
-        };
-    };
-    "export_ifTable Entries_csv": {
-        parameters: {
-            query?: {
-                /** @description Filter by host ID */
-                host_id?: string | null;
-                /** @description Filter by network ID */
-                network_id?: string | null;
-                /** @description Filter by specific entity IDs (for selective loading) */
-                ids?: string[] | null;
-                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
-                limit?: number | null;
-                /** @description Number of results to skip. Default: 0. */
-                offset?: number | null;
+            path: {
+                /** @description Invite ID */
+                id: string;
             };
-            header?: never;
-            path?: never;
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description CSV file containing ifTable Entries */
+            /** @description Invite details */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "text/csv": unknown;
+                    "application/json": components["schemas"]["ApiResponse_Invite"];
                 };
             };
-        };
-    };
-    "get_ifTable Entry_by_id": {
-        parameters: {
-            query?: never;
-            header?: never;
-            path: {
-                /** @description ifTable Entry ID */
-                id: string;
-            };
-            cookie?: never;
-        };
-        requestBody?: never;
-        responses: {
-            /** @description ifTable Entry found */
-            200: {
+            /** @description Invalid or expired invite */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_IfEntry"];
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description ifTable Entry not found */
-            404: {
+            /** @description Access denied */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -9082,32 +12370,28 @@ export interface operations {
             };
         };
     };
-    update_if_entry: {
+    revoke_invite: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description If entry ID */
+                /** @description Invite ID */
                 id: string;
             };
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["IfEntry"];
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description If entry updated successfully */
+            /** @description Invite revoked */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_IfEntry"];
+                    "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Network mismatch or invalid request */
+            /** @description Invalid invite */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -9116,40 +12400,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description If entry not found */
-            404: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
-        };
-    };
-    "delete_ifTable Entry": {
-        parameters: {
-            query?: never;
-            header?: never;
-            path: {
-                /** @description ifTable Entry ID */
-                id: string;
-            };
-            cookie?: never;
-        };
-        requestBody?: never;
-        responses: {
-            /** @description ifTable Entry deleted */
-            200: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiResponse"];
-                };
-            };
-            /** @description ifTable Entry not found */
-            404: {
+            /** @description Cannot revoke this invite */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -9159,15 +12411,9 @@ export interface operations {
             };
         };
     };
-    list_Interfaces: {
+    list_Networks: {
         parameters: {
             query?: {
-                /** @description Filter by host ID */
-                host_id?: string | null;
-                /** @description Filter by subnet ID */
-                subnet_id?: string | null;
-                /** @description Filter by network ID */
-                network_id?: string | null;
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -9179,14 +12425,14 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of Interfaces */
+            /** @description List of Networks */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
                     "application/json": {
-                        data: components["schemas"]["Interface"][];
+                        data: components["schemas"]["Network"][];
                         error?: string | null;
                         meta: components["schemas"]["PaginatedApiMeta"];
                         success: boolean;
@@ -9195,7 +12441,7 @@ export interface operations {
             };
         };
     };
-    create_interface: {
+    create_network: {
         parameters: {
             query?: never;
             header?: never;
@@ -9204,44 +12450,36 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Interface"];
+                "application/json": components["schemas"]["Network"];
             };
         };
         responses: {
-            /** @description Interface created successfully */
+            /** @description Network created */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Interface"];
-                };
-            };
-            /** @description Network mismatch or invalid request */
-            400: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Network"];
                 };
             };
         };
     };
-    bulk_delete_interfaces: {
+    bulk_delete_networks: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
+        /** @description Array of network IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description Interfaces deleted successfully */
+            /** @description Networks deleted successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -9250,8 +12488,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
-            /** @description No IDs provided */
-            400: {
+            /** @description User not admin */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -9261,15 +12499,9 @@ export interface operations {
             };
         };
     };
-    export_Interfaces_csv: {
+    export_Networks_csv: {
         parameters: {
             query?: {
-                /** @description Filter by host ID */
-                host_id?: string | null;
-                /** @description Filter by subnet ID */
-                subnet_id?: string | null;
-                /** @description Filter by network ID */
-                network_id?: string | null;
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -9281,7 +12513,7 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description CSV file containing Interfaces */
+            /** @description CSV file containing Networks */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -9292,28 +12524,28 @@ export interface operations {
             };
         };
     };
-    get_Interface_by_id: {
+    get_Network_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Interface ID */
+                /** @description Network ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Interface found */
+            /** @description Network found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Interface"];
+                    "application/json": components["schemas"]["ApiResponse_Network"];
                 };
             };
-            /** @description Interface not found */
+            /** @description Network not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -9324,33 +12556,33 @@ export interface operations {
             };
         };
     };
-    update_interface: {
+    update_network: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Interface ID */
+                /** @description Network ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Interface"];
+                "application/json": components["schemas"]["Network"];
             };
         };
         responses: {
-            /** @description Interface updated successfully */
+            /** @description Network updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Interface"];
+                    "application/json": components["schemas"]["ApiResponse_Network"];
                 };
             };
-            /** @description Network mismatch or invalid request */
-            400: {
+            /** @description User not admin */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -9358,7 +12590,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Interface not found */
+            /** @description Network not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -9369,19 +12601,19 @@ export interface operations {
             };
         };
     };
-    delete_interface: {
+    delete_network: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Interface ID */
+                /** @description Network ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Interface deleted successfully */
+            /** @description Network deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -9390,7 +12622,16 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Interface not found */
+            /** @description User not admin */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Network not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -9401,7 +12642,7 @@ export interface operations {
             };
         };
     };
-    get_invites: {
+    get_organization: {
         parameters: {
             query?: never;
             header?: never;
@@ -9410,40 +12651,52 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of active invites */
+            /** @description Organization details */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Vec_Invite"];
+                    "application/json": components["schemas"]["ApiResponse_Organization"];
+                };
+            };
+            /** @description Organization not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    create_invite: {
+    update_org_name: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description Organization ID */
+                id: string;
+            };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["CreateInviteRequest"];
+                "text/plain": string;
             };
         };
         responses: {
-            /** @description Invite created */
+            /** @description Organization updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Invite"];
+                    "application/json": components["schemas"]["ApiResponse_Organization"];
                 };
             };
-            /** @description Cannot create invite with higher permissions */
+            /** @description Only owners can update organization */
             403: {
                 headers: {
                     [name: string]: unknown;
@@ -9452,31 +12705,40 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
+            /** @description Organization not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
         };
     };
-    get_invite: {
+    populate_demo_data: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Invite ID */
+                /** @description Organization ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Invite details */
+            /** @description Demo data populated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Invite"];
+                    "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Invalid or expired invite */
-            400: {
+            /** @description Only available for demo organizations */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -9484,8 +12746,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Access denied */
-            403: {
+            /** @description Organization not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -9495,19 +12757,19 @@ export interface operations {
             };
         };
     };
-    revoke_invite: {
+    reset: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Invite ID */
+                /** @description Organization ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Invite revoked */
+            /** @description Organization reset */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -9516,8 +12778,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Invalid invite */
-            400: {
+            /** @description Cannot reset another organization */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -9525,8 +12787,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Cannot revoke this invite */
-            403: {
+            /** @description Organization not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -9536,9 +12798,15 @@ export interface operations {
             };
         };
     };
-    list_Networks: {
+    list_Ports: {
         parameters: {
             query?: {
+                /** @description Filter by host ID */
+                host_id?: string | null;
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -9550,14 +12818,14 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of Networks */
+            /** @description List of Ports */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
                     "application/json": {
-                        data: components["schemas"]["Network"][];
+                        data: components["schemas"]["Port"][];
                         error?: string | null;
                         meta: components["schemas"]["PaginatedApiMeta"];
                         success: boolean;
@@ -9566,7 +12834,7 @@ export interface operations {
             };
         };
     };
-    create_network: {
+    create_port: {
         parameters: {
             query?: never;
             header?: never;
@@ -9575,36 +12843,45 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Network"];
+                "application/json": components["schemas"]["Port"];
             };
         };
         responses: {
-            /** @description Network created */
+            /** @description Port created successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Network"];
+                    "application/json": components["schemas"]["ApiResponse_Port"];
+                };
+            };
+            /** @description Network mismatch or duplicate port */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    bulk_delete_networks: {
+    bulk_delete_Ports: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of network IDs to delete */
+        /** @description Array of Port IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description Networks deleted successfully */
+            /** @description Ports deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -9613,20 +12890,17 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
-            /** @description User not admin */
-            403: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
         };
     };
-    export_Networks_csv: {
+    export_Ports_csv: {
         parameters: {
             query?: {
+                /** @description Filter by host ID */
+                host_id?: string | null;
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -9638,7 +12912,7 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description CSV file containing Networks */
+            /** @description CSV file containing Ports */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -9649,28 +12923,28 @@ export interface operations {
             };
         };
     };
-    get_Network_by_id: {
+    get_Port_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Network ID */
+                /** @description Port ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Network found */
+            /** @description Port found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Network"];
+                    "application/json": components["schemas"]["ApiResponse_Port"];
                 };
             };
-            /** @description Network not found */
+            /** @description Port not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -9681,33 +12955,33 @@ export interface operations {
             };
         };
     };
-    update_network: {
+    update_port: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Network ID */
+                /** @description Port ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Network"];
+                "application/json": components["schemas"]["Port"];
             };
         };
         responses: {
-            /** @description Network updated */
+            /** @description Port updated successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Network"];
+                    "application/json": components["schemas"]["ApiResponse_Port"];
                 };
             };
-            /** @description User not admin */
-            403: {
+            /** @description Network mismatch or invalid request */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -9715,7 +12989,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Network not found */
+            /** @description Port not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -9726,19 +13000,19 @@ export interface operations {
             };
         };
     };
-    delete_network: {
+    delete_Port: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Network ID */
+                /** @description Port ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Network deleted */
+            /** @description Port deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -9747,17 +13021,80 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description User not admin */
-            403: {
+            /** @description Port not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_all_services: {
+        parameters: {
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by host ID */
+                host_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
+                /** @description Filter by tag IDs (returns services that have ANY of the specified tags) */
+                tag_ids?: string[] | null;
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["ServiceOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["ServiceOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of services */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["PaginatedApiResponse_Service"];
+                };
+            };
+        };
+    };
+    create_service: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["CreateServiceRequest"];
+            };
+        };
+        responses: {
+            /** @description Service created successfully */
+            200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Service"];
                 };
             };
-            /** @description Network not found */
-            404: {
+            /** @description Validation error: host network mismatch, cross-host binding, or binding conflict */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -9767,70 +13104,92 @@ export interface operations {
             };
         };
     };
-    get_organization: {
+    bulk_delete_Services: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        requestBody?: never;
+        /** @description Array of Service IDs to delete */
+        requestBody: {
+            content: {
+                "application/json": string[];
+            };
+        };
         responses: {
-            /** @description Organization details */
+            /** @description Services deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Organization"];
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
-            /** @description Organization not found */
-            404: {
+        };
+    };
+    export_Services_csv: {
+        parameters: {
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by host ID */
+                host_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
+                /** @description Filter by tag IDs (returns services that have ANY of the specified tags) */
+                tag_ids?: string[] | null;
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["ServiceOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["ServiceOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description CSV file containing Services */
+            200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "text/csv": unknown;
                 };
             };
         };
     };
-    update_org_name: {
+    get_Service_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Organization ID */
+                /** @description Service ID */
                 id: string;
             };
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "text/plain": string;
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description Organization updated */
+            /** @description Service found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Organization"];
-                };
-            };
-            /** @description Only owners can update organization */
-            403: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Service"];
                 };
             };
-            /** @description Organization not found */
+            /** @description Service not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -9841,29 +13200,33 @@ export interface operations {
             };
         };
     };
-    populate_demo_data: {
+    update_service: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Organization ID */
+                /** @description Service ID */
                 id: string;
             };
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["Service"];
+            };
+        };
         responses: {
-            /** @description Demo data populated */
+            /** @description Service updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Service"];
                 };
             };
-            /** @description Only available for demo organizations */
-            403: {
+            /** @description Validation error: host network mismatch, cross-host binding, or binding conflict */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -9871,7 +13234,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Organization not found */
+            /** @description Service not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -9882,19 +13245,19 @@ export interface operations {
             };
         };
     };
-    reset: {
+    delete_Service: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Organization ID */
+                /** @description Service ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Organization reset */
+            /** @description Service deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -9903,16 +13266,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Cannot reset another organization */
-            403: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
-            /** @description Organization not found */
+            /** @description Service not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -9923,15 +13277,13 @@ export interface operations {
             };
         };
     };
-    list_Ports: {
+    list_Shares: {
         parameters: {
             query?: {
-                /** @description Filter by host ID */
-                host_id?: string | null;
                 /** @description Filter by network ID */
                 network_id?: string | null;
-                /** @description Filter by specific entity IDs (for selective loading) */
-                ids?: string[] | null;
+                /** @description Filter by topology ID */
+                topology_id?: string | null;
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -9943,14 +13295,14 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of Ports */
+            /** @description List of Shares */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
                     "application/json": {
-                        data: components["schemas"]["Port"][];
+                        data: components["schemas"]["Share"][];
                         error?: string | null;
                         meta: components["schemas"]["PaginatedApiMeta"];
                         success: boolean;
@@ -9959,7 +13311,7 @@ export interface operations {
             };
         };
     };
-    create_port: {
+    create_share: {
         parameters: {
             query?: never;
             header?: never;
@@ -9968,20 +13320,20 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Port"];
+                "application/json": components["schemas"]["CreateUpdateShareRequest"];
             };
         };
         responses: {
-            /** @description Port created successfully */
+            /** @description Share created */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Port"];
+                    "application/json": components["schemas"]["ApiResponse_Share"];
                 };
             };
-            /** @description Network mismatch or duplicate port */
+            /** @description Invalid request */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -9992,21 +13344,21 @@ export interface operations {
             };
         };
     };
-    bulk_delete_Ports: {
+    bulk_delete_Shares: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of Port IDs to delete */
+        /** @description Array of Share IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description Ports deleted */
+            /** @description Shares deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -10017,15 +13369,13 @@ export interface operations {
             };
         };
     };
-    export_Ports_csv: {
+    export_Shares_csv: {
         parameters: {
             query?: {
-                /** @description Filter by host ID */
-                host_id?: string | null;
                 /** @description Filter by network ID */
                 network_id?: string | null;
-                /** @description Filter by specific entity IDs (for selective loading) */
-                ids?: string[] | null;
+                /** @description Filter by topology ID */
+                topology_id?: string | null;
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -10037,7 +13387,7 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description CSV file containing Ports */
+            /** @description CSV file containing Shares */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -10048,28 +13398,28 @@ export interface operations {
             };
         };
     };
-    get_Port_by_id: {
+    get_public_share_metadata: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Port ID */
+                /** @description Share ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Port found */
+            /** @description Share metadata */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Port"];
+                    "application/json": components["schemas"]["ApiResponse_PublicShareMetadata"];
                 };
             };
-            /** @description Port not found */
+            /** @description Share not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -10080,33 +13430,33 @@ export interface operations {
             };
         };
     };
-    update_port: {
+    verify_share_password: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Port ID */
+                /** @description Share ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Port"];
+                "text/plain": string;
             };
         };
         responses: {
-            /** @description Port updated successfully */
+            /** @description Password verified */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Port"];
+                    "application/json": components["schemas"]["ApiResponse_bool"];
                 };
             };
-            /** @description Network mismatch or invalid request */
-            400: {
+            /** @description Invalid password */
+            401: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -10114,7 +13464,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Port not found */
+            /** @description Share not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -10125,19 +13475,87 @@ export interface operations {
             };
         };
     };
-    delete_Port: {
+    get_Share_by_id: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Share ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Share found */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Share"];
+                };
+            };
+            /** @description Share not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    update_share: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Share ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["CreateUpdateShareRequest"];
+            };
+        };
+        responses: {
+            /** @description Share updated */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Share"];
+                };
+            };
+            /** @description Share not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    delete_Share: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Port ID */
+                /** @description Share ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Port deleted */
+            /** @description Share deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -10146,7 +13564,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Port not found */
+            /** @description Share not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -10157,21 +13575,13 @@ export interface operations {
             };
         };
     };
-    get_all_services: {
+    get_all_snmp_credentials: {
         parameters: {
             query?: {
-                /** @description Filter by network ID */
-                network_id?: string | null;
-                /** @description Filter by host ID */
-                host_id?: string | null;
-                /** @description Filter by specific entity IDs (for selective loading) */
-                ids?: string[] | null;
-                /** @description Filter by tag IDs (returns services that have ANY of the specified tags) */
-                tag_ids?: string[] | null;
                 /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
-                group_by?: null | components["schemas"]["ServiceOrderField"];
+                group_by?: null | components["schemas"]["SnmpCredentialOrderField"];
                 /** @description Secondary ordering field (sorting within groups or standalone sort). */
-                order_by?: null | components["schemas"]["ServiceOrderField"];
+                order_by?: null | components["schemas"]["SnmpCredentialOrderField"];
                 /** @description Direction for order_by field (group_by always uses ASC). */
                 order_direction?: null | components["schemas"]["OrderDirection"];
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
@@ -10185,18 +13595,18 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of services */
+            /** @description List of SNMP credentials */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["PaginatedApiResponse_Service"];
+                    "application/json": components["schemas"]["PaginatedApiResponse_SnmpCredential"];
                 };
             };
         };
     };
-    create_service: {
+    create_snmp_credential: {
         parameters: {
             query?: never;
             header?: never;
@@ -10205,20 +13615,20 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["CreateServiceRequest"];
+                "application/json": components["schemas"]["SnmpCredential"];
             };
         };
         responses: {
-            /** @description Service created successfully */
+            /** @description SNMP credential created successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Service"];
+                    "application/json": components["schemas"]["ApiResponse_SnmpCredential"];
                 };
             };
-            /** @description Validation error: host network mismatch, cross-host binding, or binding conflict */
+            /** @description Validation error */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -10227,23 +13637,31 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
+            /** @description Credential name already exists in this organization */
+            409: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
         };
     };
-    bulk_delete_Services: {
+    bulk_delete_snmp_credentials: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of Service IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description Services deleted */
+            /** @description SNMP Credentials deleted successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -10252,23 +13670,24 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
+            /** @description Validation error */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
         };
     };
-    export_Services_csv: {
+    "export_SNMP Credentials_csv": {
         parameters: {
             query?: {
-                /** @description Filter by network ID */
-                network_id?: string | null;
-                /** @description Filter by host ID */
-                host_id?: string | null;
-                /** @description Filter by specific entity IDs (for selective loading) */
-                ids?: string[] | null;
-                /** @description Filter by tag IDs (returns services that have ANY of the specified tags) */
-                tag_ids?: string[] | null;
                 /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
-                group_by?: null | components["schemas"]["ServiceOrderField"];
+                group_by?: null | components["schemas"]["SnmpCredentialOrderField"];
                 /** @description Secondary ordering field (sorting within groups or standalone sort). */
-                order_by?: null | components["schemas"]["ServiceOrderField"];
+                order_by?: null | components["schemas"]["SnmpCredentialOrderField"];
                 /** @description Direction for order_by field (group_by always uses ASC). */
                 order_direction?: null | components["schemas"]["OrderDirection"];
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
@@ -10282,7 +13701,7 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description CSV file containing Services */
+            /** @description CSV file containing SNMP Credentials */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -10293,28 +13712,28 @@ export interface operations {
             };
         };
     };
-    get_Service_by_id: {
+    "get_SNMP Credential_by_id": {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Service ID */
+                /** @description SNMP Credential ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Service found */
+            /** @description SNMP Credential found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Service"];
+                    "application/json": components["schemas"]["ApiResponse_SnmpCredential"];
                 };
             };
-            /** @description Service not found */
+            /** @description SNMP Credential not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -10325,32 +13744,32 @@ export interface operations {
             };
         };
     };
-    update_service: {
+    update_snmp_credential: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Service ID */
+                /** @description snmp_credential ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Service"];
+                "application/json": components["schemas"]["SnmpCredential"];
             };
         };
         responses: {
-            /** @description Service updated */
+            /** @description snmp_credential updated successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Service"];
+                    "application/json": components["schemas"]["ApiResponse_SnmpCredential"];
                 };
             };
-            /** @description Validation error: host network mismatch, cross-host binding, or binding conflict */
+            /** @description Validation error */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -10359,7 +13778,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Service not found */
+            /** @description snmp_credential not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -10370,19 +13789,19 @@ export interface operations {
             };
         };
     };
-    delete_Service: {
+    delete_snmp_credential: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Service ID */
+                /** @description snmp_credential ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Service deleted */
+            /** @description snmp_credential deleted successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -10391,7 +13810,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Service not found */
+            /** @description snmp_credential not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -10402,13 +13821,17 @@ export interface operations {
             };
         };
     };
-    list_Shares: {
+    list_subnets: {
         parameters: {
             query?: {
                 /** @description Filter by network ID */
                 network_id?: string | null;
-                /** @description Filter by topology ID */
-                topology_id?: string | null;
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["SubnetOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["SubnetOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -10420,23 +13843,18 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of Shares */
+            /** @description List of subnets */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": {
-                        data: components["schemas"]["Share"][];
-                        error?: string | null;
-                        meta: components["schemas"]["PaginatedApiMeta"];
-                        success: boolean;
-                    };
+                    "application/json": components["schemas"]["PaginatedApiResponse_Subnet"];
                 };
             };
         };
     };
-    create_share: {
+    create_subnet: {
         parameters: {
             query?: never;
             header?: never;
@@ -10445,17 +13863,17 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["CreateUpdateShareRequest"];
+                "application/json": components["schemas"]["Subnet"];
             };
         };
         responses: {
-            /** @description Share created */
+            /** @description Subnet created successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Share"];
+                    "application/json": components["schemas"]["ApiResponse_Subnet"];
                 };
             };
             /** @description Invalid request */
@@ -10469,21 +13887,21 @@ export interface operations {
             };
         };
     };
-    bulk_delete_Shares: {
+    bulk_delete_Subnets: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of Share IDs to delete */
+        /** @description Array of Subnet IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description Shares deleted */
+            /** @description Subnets deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -10494,13 +13912,17 @@ export interface operations {
             };
         };
     };
-    export_Shares_csv: {
+    export_Subnets_csv: {
         parameters: {
             query?: {
                 /** @description Filter by network ID */
                 network_id?: string | null;
-                /** @description Filter by topology ID */
-                topology_id?: string | null;
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["SubnetOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["SubnetOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -10512,7 +13934,7 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description CSV file containing Shares */
+            /** @description CSV file containing Subnets */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -10523,28 +13945,28 @@ export interface operations {
             };
         };
     };
-    get_public_share_metadata: {
+    get_Subnet_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Share ID */
+                /** @description Subnet ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Share metadata */
+            /** @description Subnet found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_PublicShareMetadata"];
+                    "application/json": components["schemas"]["ApiResponse_Subnet"];
                 };
             };
-            /** @description Share not found */
+            /** @description Subnet not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -10555,33 +13977,33 @@ export interface operations {
             };
         };
     };
-    verify_share_password: {
+    update_subnet: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Share ID */
+                /** @description Subnet ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "text/plain": string;
+                "application/json": components["schemas"]["Subnet"];
             };
         };
         responses: {
-            /** @description Password verified */
+            /** @description Subnet updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_bool"];
+                    "application/json": components["schemas"]["ApiResponse_Subnet"];
                 };
             };
-            /** @description Invalid password */
-            401: {
+            /** @description CIDR change would orphan existing interfaces */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -10589,7 +14011,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Share not found */
+            /** @description Subnet not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -10600,28 +14022,28 @@ export interface operations {
             };
         };
     };
-    get_Share_by_id: {
+    delete_Subnet: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Share ID */
+                /** @description Subnet ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Share found */
+            /** @description Subnet deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Share"];
+                    "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Share not found */
+            /** @description Subnet not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -10632,32 +14054,111 @@ export interface operations {
             };
         };
     };
-    update_share: {
+    get_all_tags: {
+        parameters: {
+            query?: {
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["TagOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["TagOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of tags */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["PaginatedApiResponse_Tag"];
+                };
+            };
+        };
+    };
+    create_tag: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["Tag"];
+            };
+        };
+        responses: {
+            /** @description Tag created successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Tag"];
+                };
+            };
+            /** @description Validation error: name empty or too long */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Tag name already exists in this organization */
+            409: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    set_entity_tags: {
         parameters: {
             query?: never;
             header?: never;
-            path: {
-                /** @description Share ID */
-                id: string;
-            };
+            path?: never;
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["CreateUpdateShareRequest"];
+                "application/json": components["schemas"]["SetTagsRequest"];
             };
         };
         responses: {
-            /** @description Share updated */
+            /** @description Tags set successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Share"];
+                    "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Share not found */
+            /** @description Invalid entity type or tag */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Tag not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -10668,29 +14169,30 @@ export interface operations {
             };
         };
     };
-    delete_Share: {
+    bulk_add_tag: {
         parameters: {
             query?: never;
             header?: never;
-            path: {
-                /** @description Share ID */
-                id: string;
-            };
+            path?: never;
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["BulkTagRequest"];
+            };
+        };
         responses: {
-            /** @description Share deleted */
+            /** @description Tag added successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiResponse_BulkTagResponse"];
                 };
             };
-            /** @description Share not found */
-            404: {
+            /** @description Invalid entity type or tag */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -10698,40 +14200,18 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-        };
-    };
-    get_all_snmp_credentials: {
-        parameters: {
-            query?: {
-                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
-                group_by?: null | components["schemas"]["SnmpCredentialOrderField"];
-                /** @description Secondary ordering field (sorting within groups or standalone sort). */
-                order_by?: null | components["schemas"]["SnmpCredentialOrderField"];
-                /** @description Direction for order_by field (group_by always uses ASC). */
-                order_direction?: null | components["schemas"]["OrderDirection"];
-                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
-                limit?: number | null;
-                /** @description Number of results to skip. Default: 0. */
-                offset?: number | null;
-            };
-            header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        requestBody?: never;
-        responses: {
-            /** @description List of SNMP credentials */
-            200: {
+            /** @description Tag not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["PaginatedApiResponse_SnmpCredential"];
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    create_snmp_credential: {
+    bulk_remove_tag: {
         parameters: {
             query?: never;
             header?: never;
@@ -10740,20 +14220,20 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["SnmpCredential"];
+                "application/json": components["schemas"]["BulkTagRequest"];
             };
         };
         responses: {
-            /** @description SNMP credential created successfully */
+            /** @description Tag removed successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_SnmpCredential"];
+                    "application/json": components["schemas"]["ApiResponse_BulkTagResponse"];
                 };
             };
-            /** @description Validation error */
+            /** @description Invalid entity type */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -10762,31 +14242,23 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Credential name already exists in this organization */
-            409: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
         };
     };
-    bulk_delete_snmp_credentials: {
+    bulk_delete_Tags: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
+        /** @description Array of Tag IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description SNMP Credentials deleted successfully */
+            /** @description Tags deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -10795,24 +14267,15 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
-            /** @description Validation error */
-            400: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
         };
     };
-    "export_SNMP Credentials_csv": {
+    export_Tags_csv: {
         parameters: {
             query?: {
                 /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
-                group_by?: null | components["schemas"]["SnmpCredentialOrderField"];
+                group_by?: null | components["schemas"]["TagOrderField"];
                 /** @description Secondary ordering field (sorting within groups or standalone sort). */
-                order_by?: null | components["schemas"]["SnmpCredentialOrderField"];
+                order_by?: null | components["schemas"]["TagOrderField"];
                 /** @description Direction for order_by field (group_by always uses ASC). */
                 order_direction?: null | components["schemas"]["OrderDirection"];
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
@@ -10826,7 +14289,7 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description CSV file containing SNMP Credentials */
+            /** @description CSV file containing Tags */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -10837,28 +14300,28 @@ export interface operations {
             };
         };
     };
-    "get_SNMP Credential_by_id": {
+    get_Tag_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description SNMP Credential ID */
+                /** @description Tag ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description SNMP Credential found */
+            /** @description Tag found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_SnmpCredential"];
+                    "application/json": components["schemas"]["ApiResponse_Tag"];
                 };
             };
-            /** @description SNMP Credential not found */
+            /** @description Tag not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -10869,41 +14332,32 @@ export interface operations {
             };
         };
     };
-    update_snmp_credential: {
+    update_Tag: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description snmp_credential ID */
+                /** @description Tag ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["SnmpCredential"];
+                "application/json": components["schemas"]["Tag"];
             };
         };
         responses: {
-            /** @description snmp_credential updated successfully */
+            /** @description Tag updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_SnmpCredential"];
-                };
-            };
-            /** @description Validation error */
-            400: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Tag"];
                 };
             };
-            /** @description snmp_credential not found */
+            /** @description Tag not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -10914,19 +14368,19 @@ export interface operations {
             };
         };
     };
-    delete_snmp_credential: {
+    delete_Tag: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description snmp_credential ID */
+                /** @description Tag ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description snmp_credential deleted successfully */
+            /** @description Tag deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -10935,7 +14389,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description snmp_credential not found */
+            /** @description Tag not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -10946,15 +14400,25 @@ export interface operations {
             };
         };
     };
-    list_subnets: {
+    get_all_tls_certificates: {
         parameters: {
             query?: {
                 /** @description Filter by network ID */
                 network_id?: string | null;
+                /** @description Filter by host ID */
+                host_id?: string | null;
+                /** @description Filter by port ID */
+                port_id?: string | null;
+                /** @description Filter by the service bound to the certificate's port */
+                service_id?: string | null;
+                /** @description Only certificates that have expired or expire within this many days */
+                expiring_within_days?: number | null;
+                /** @description Filter by self-signed flag */
+                self_signed?: boolean | null;
                 /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
-                group_by?: null | components["schemas"]["SubnetOrderField"];
+                group_by?: null | components["schemas"]["TlsCertificateOrderField"];
                 /** @description Secondary ordering field (sorting within groups or standalone sort). */
-                order_by?: null | components["schemas"]["SubnetOrderField"];
+                order_by?: null | components["schemas"]["TlsCertificateOrderField"];
                 /** @description Direction for order_by field (group_by always uses ASC). */
                 order_direction?: null | components["schemas"]["OrderDirection"];
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
@@ -10968,41 +14432,83 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of subnets */
+            /** @description List of TLS certificates */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["PaginatedApiResponse_Subnet"];
+                    "application/json": components["schemas"]["PaginatedApiResponse_TlsCertificate"];
                 };
             };
         };
     };
-    create_subnet: {
+    "export_TLS Certificates_csv": {
         parameters: {
-            query?: never;
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by host ID */
+                host_id?: string | null;
+                /** @description Filter by port ID */
+                port_id?: string | null;
+                /** @description Filter by the service bound to the certificate's port */
+                service_id?: string | null;
+                /** @description Only certificates that have expired or expire within this many days */
+                expiring_within_days?: number | null;
+                /** @description Filter by self-signed flag */
+                self_signed?: boolean | null;
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["TlsCertificateOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["TlsCertificateOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
             header?: never;
             path?: never;
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["Subnet"];
+        requestBody?: never;
+        responses: {
+            /** @description CSV file containing TLS Certificates */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "text/csv": unknown;
+                };
+            };
+        };
+    };
+    "get_TLS Certificate_by_id": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description TLS Certificate ID */
+                id: string;
             };
+            cookie?: never;
         };
+        requestBody?: never;
         responses: {
-            /** @description Subnet created successfully */
+            /** @description TLS Certificate found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Subnet"];
+                    "application/json": components["schemas"]["ApiResponse_TlsCertificate"];
                 };
             };
-            /** @description Invalid request */
-            400: {
+            /** @description TLS Certificate not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -11012,40 +14518,79 @@ export interface operations {
             };
         };
     };
-    bulk_delete_Subnets: {
+    get_all_topologies: {
+        parameters: {
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of topologies */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["PaginatedApiResponse_Topology"];
+                };
+            };
+        };
+    };
+    create_topology: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of Subnet IDs to delete */
         requestBody: {
             content: {
-                "application/json": string[];
+                "application/json": components["schemas"]["Topology"];
+            };
+        };
+        responses: {
+            /** @description Topology created */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Topology"];
+                };
             };
-        };
-        responses: {
-            /** @description Subnets deleted */
-            200: {
+            /** @description Validation failed */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    export_Subnets_csv: {
+    get_all_topology_snapshots: {
         parameters: {
             query?: {
                 /** @description Filter by network ID */
                 network_id?: string | null;
+                /** @description Filter by the topology the snapshots were taken of */
+                topology_id?: string | null;
                 /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
-                group_by?: null | components["schemas"]["SubnetOrderField"];
+                group_by?: null | components["schemas"]["TopologySnapshotOrderField"];
                 /** @description Secondary ordering field (sorting within groups or standalone sort). */
-                order_by?: null | components["schemas"]["SubnetOrderField"];
+                order_by?: null | components["schemas"]["TopologySnapshotOrderField"];
                 /** @description Direction for order_by field (group_by always uses ASC). */
                 order_direction?: null | components["schemas"]["OrderDirection"];
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
@@ -11059,40 +14604,41 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description CSV file containing Subnets */
+            /** @description List of topology snapshots */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "text/csv": unknown;
+                    "application/json": components["schemas"]["PaginatedApiResponse_TopologySnapshot"];
                 };
             };
         };
     };
-    get_Subnet_by_id: {
+    create_topology_snapshot: {
         parameters: {
             query?: never;
             header?: never;
-            path: {
-                /** @description Subnet ID */
-                id: string;
-            };
+            path?: never;
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["CreateTopologySnapshotRequest"];
+            };
+        };
         responses: {
-            /** @description Subnet found */
+            /** @description Topology snapshot created */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Subnet"];
+                    "application/json": components["schemas"]["ApiResponse_TopologySnapshot"];
                 };
             };
-            /** @description Subnet not found */
-            404: {
+            /** @description Invalid request */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -11100,35 +14646,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-        };
-    };
-    update_subnet: {
-        parameters: {
-            query?: never;
-            header?: never;
-            path: {
-                /** @description Subnet ID */
-                id: string;
-            };
-            cookie?: never;
-        };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["Subnet"];
-            };
-        };
-        responses: {
-            /** @description Subnet updated */
-            200: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiResponse_Subnet"];
-                };
-            };
-            /** @description CIDR change would orphan existing interfaces */
-            400: {
+            /** @description Access denied */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -11136,7 +14655,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Subnet not found */
+            /** @description Topology not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -11147,28 +14666,48 @@ export interface operations {
             };
         };
     };
-    delete_Subnet: {
+    diff_topology_snapshots: {
         parameters: {
-            query?: never;
-            header?: never;
-            path: {
-                /** @description Subnet ID */
-                id: string;
+            query: {
+                /** @description Earlier snapshot */
+                from: string;
+                /** @description Later snapshot of the same topology. Omit to compare against the live topology. */
+                to?: string;
             };
+            header?: never;
+            path?: never;
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Subnet deleted */
+            /** @description Changes between the two sides */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiResponse_TopologyDiff"];
                 };
             };
-            /** @description Subnet not found */
+            /** @description Snapshots belong to different topologies */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Access denied */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Snapshot or topology not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -11179,13 +14718,17 @@ export interface operations {
             };
         };
     };
-    get_all_tags: {
+    "export_Topology Snapshots_csv": {
         parameters: {
             query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by the topology the snapshots were taken of */
+                topology_id?: string | null;
                 /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
-                group_by?: null | components["schemas"]["TagOrderField"];
+                group_by?: null | components["schemas"]["TopologySnapshotOrderField"];
                 /** @description Secondary ordering field (sorting within groups or standalone sort). */
-                order_by?: null | components["schemas"]["TagOrderField"];
+                order_by?: null | components["schemas"]["TopologySnapshotOrderField"];
                 /** @description Direction for order_by field (group_by always uses ASC). */
                 order_direction?: null | components["schemas"]["OrderDirection"];
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
@@ -11199,50 +14742,40 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of tags */
+            /** @description CSV file containing Topology Snapshots */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["PaginatedApiResponse_Tag"];
+                    "text/csv": unknown;
                 };
             };
         };
     };
-    create_tag: {
+    "get_Topology Snapshot_by_id": {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["Tag"];
+            path: {
+                /** @description Topology Snapshot ID */
+                id: string;
             };
+            cookie?: never;
         };
+        requestBody?: never;
         responses: {
-            /** @description Tag created successfully */
+            /** @description Topology Snapshot found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Tag"];
-                };
-            };
-            /** @description Validation error: name empty or too long */
-            400: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_TopologySnapshot"];
                 };
             };
-            /** @description Tag name already exists in this organization */
-            409: {
+            /** @description Topology Snapshot not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -11252,20 +14785,19 @@ export interface operations {
             };
         };
     };
-    set_entity_tags: {
+    "delete_Topology Snapshot": {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["SetTagsRequest"];
+            path: {
+                /** @description Topology Snapshot ID */
+                id: string;
             };
+            cookie?: never;
         };
+        requestBody?: never;
         responses: {
-            /** @description Tags set successfully */
+            /** @description Topology Snapshot deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -11274,16 +14806,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Invalid entity type or tag */
-            400: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
-            /** @description Tag not found */
+            /** @description Topology Snapshot not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -11294,29 +14817,37 @@ export interface operations {
             };
         };
     };
-    bulk_add_tag: {
+    export_topology_snapshot: {
         parameters: {
-            query?: never;
+            query?: {
+                /** @description Output format (defaults to svg) */
+                format?: "svg" | "graphml" | "drawio" | "dot";
+                /** @description Snapshot to compare against. Nodes and edges added, removed or moved since the
+snapshot are highlighted. */
+                compare_to?: string;
+            };
             header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["BulkTagRequest"];
+            path: {
+                /** @description Topology Snapshot ID */
+                id: string;
             };
+            cookie?: never;
         };
+        requestBody?: never;
         responses: {
-            /** @description Tag added successfully */
+            /** @description Diagram file in the requested format */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_BulkTagResponse"];
+                    "image/svg+xml": string;
+                    "application/graphml+xml": string;
+                    "application/vnd.jgraph.mxfile": string;
+                    "text/vnd.graphviz": string;
                 };
             };
-            /** @description Invalid entity type or tag */
+            /** @description Snapshots belong to different topologies */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -11325,7 +14856,16 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Tag not found */
+            /** @description Access denied */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Snapshot not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -11336,117 +14876,125 @@ export interface operations {
             };
         };
     };
-    bulk_remove_tag: {
+    export_Topologies_csv: {
         parameters: {
-            query?: never;
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
             header?: never;
             path?: never;
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["BulkTagRequest"];
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description Tag removed successfully */
+            /** @description CSV file containing Topologies */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_BulkTagResponse"];
-                };
-            };
-            /** @description Invalid entity type */
-            400: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "text/csv": unknown;
                 };
             };
         };
     };
-    bulk_delete_Tags: {
+    get_Topology_by_id: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        /** @description Array of Tag IDs to delete */
-        requestBody: {
-            content: {
-                "application/json": string[];
+            path: {
+                /** @description Topology ID */
+                id: string;
             };
+            cookie?: never;
         };
+        requestBody?: never;
         responses: {
-            /** @description Tags deleted */
+            /** @description Topology found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Topology"];
+                };
+            };
+            /** @description Topology not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    export_Tags_csv: {
+    update_topology: {
         parameters: {
-            query?: {
-                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
-                group_by?: null | components["schemas"]["TagOrderField"];
-                /** @description Secondary ordering field (sorting within groups or standalone sort). */
-                order_by?: null | components["schemas"]["TagOrderField"];
-                /** @description Direction for order_by field (group_by always uses ASC). */
-                order_direction?: null | components["schemas"]["OrderDirection"];
-                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
-                limit?: number | null;
-                /** @description Number of results to skip. Default: 0. */
-                offset?: number | null;
-            };
+            query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description Topology ID */
+                id: string;
+            };
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["Topology"];
+            };
+        };
         responses: {
-            /** @description CSV file containing Tags */
+            /** @description Topology updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "text/csv": unknown;
+                    "application/json": components["schemas"]["ApiResponse_Topology"];
+                };
+            };
+            /** @description Topology not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    get_Tag_by_id: {
+    delete_Topology: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Tag ID */
+                /** @description Topology ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Tag found */
+            /** @description Topology deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Tag"];
+                    "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Tag not found */
+            /** @description Topology not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -11457,32 +15005,41 @@ export interface operations {
             };
         };
     };
-    update_Tag: {
+    update_edge_handles: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Tag ID */
+                /** @description Topology ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Tag"];
+                "application/json": components["schemas"]["TopologyEdgeHandleUpdate"];
             };
         };
         responses: {
-            /** @description Tag updated */
+            /** @description Edge handles updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Tag"];
+                    "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Tag not found */
+            /** @description Access denied */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Topology or edge not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -11493,28 +15050,55 @@ export interface operations {
             };
         };
     };
-    delete_Tag: {
+    export_topology: {
         parameters: {
-            query?: never;
+            query?: {
+                /** @description Output format (defaults to svg) */
+                format?: "svg" | "graphml" | "drawio" | "dot";
+                /** @description Snapshot to compare against. Nodes and edges added, removed or moved since the
+snapshot are highlighted. */
+                compare_to?: string;
+            };
             header?: never;
             path: {
-                /** @description Tag ID */
+                /** @description Topology ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Tag deleted */
+            /** @description Diagram file in the requested format */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "image/svg+xml": string;
+                    "application/graphml+xml": string;
+                    "application/vnd.jgraph.mxfile": string;
+                    "text/vnd.graphviz": string;
                 };
             };
-            /** @description Tag not found */
+            /** @description Snapshot was taken of a different topology */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Access denied */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Topology or snapshot not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -11525,59 +15109,74 @@ export interface operations {
             };
         };
     };
-    get_all_topologies: {
+    lock: {
         parameters: {
-            query?: {
-                /** @description Filter by network ID */
-                network_id?: string | null;
-                /** @description Filter by specific entity IDs (for selective loading) */
-                ids?: string[] | null;
-                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
-                limit?: number | null;
-                /** @description Number of results to skip. Default: 0. */
-                offset?: number | null;
-            };
+            query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description Topology ID */
+                id: string;
+            };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description List of topologies */
+            /** @description Topology locked */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["PaginatedApiResponse_Topology"];
+                    "application/json": components["schemas"]["ApiResponse_Topology"];
+                };
+            };
+            /** @description Access denied */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Topology not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    create_topology: {
+    update_metadata: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description Topology ID */
+                id: string;
+            };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Topology"];
+                "application/json": components["schemas"]["TopologyMetadataUpdate"];
             };
         };
         responses: {
-            /** @description Topology created */
+            /** @description Metadata updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Topology"];
+                    "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Validation failed */
-            400: {
+            /** @description Access denied */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -11585,38 +15184,18 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-        };
-    };
-    export_Topologies_csv: {
-        parameters: {
-            query?: {
-                /** @description Filter by network ID */
-                network_id?: string | null;
-                /** @description Filter by specific entity IDs (for selective loading) */
-                ids?: string[] | null;
-                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
-                limit?: number | null;
-                /** @description Number of results to skip. Default: 0. */
-                offset?: number | null;
-            };
-            header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        requestBody?: never;
-        responses: {
-            /** @description CSV file containing Topologies */
-            200: {
+            /** @description Topology not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "text/csv": unknown;
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    get_Topology_by_id: {
+    update_node_position: {
         parameters: {
             query?: never;
             header?: never;
@@ -11626,18 +15205,31 @@ export interface operations {
             };
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["TopologyNodePositionUpdate"];
+            };
+        };
         responses: {
-            /** @description Topology found */
+            /** @description Node position updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Topology"];
+                    "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Topology not found */
+            /** @description Access denied */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Topology or node not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -11648,7 +15240,7 @@ export interface operations {
             };
         };
     };
-    update_topology: {
+    update_node_resize: {
         parameters: {
             query?: never;
             header?: never;
@@ -11660,20 +15252,29 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Topology"];
+                "application/json": components["schemas"]["TopologyNodeResizeUpdate"];
             };
         };
         responses: {
-            /** @description Topology updated */
+            /** @description Node resized */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Topology"];
+                    "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Topology not found */
+            /** @description Access denied */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Topology or node not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -11684,7 +15285,7 @@ export interface operations {
             };
         };
     };
-    delete_Topology: {
+    rebuild: {
         parameters: {
             query?: never;
             header?: never;
@@ -11694,9 +15295,13 @@ export interface operations {
             };
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["TopologyRebuildRequest"];
+            };
+        };
         responses: {
-            /** @description Topology deleted */
+            /** @description Topology rebuilt */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -11705,6 +15310,15 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
+            /** @description Access denied */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
             /** @description Topology not found */
             404: {
                 headers: {
@@ -11716,7 +15330,7 @@ export interface operations {
             };
         };
     };
-    update_edge_handles: {
+    refresh: {
         parameters: {
             query?: never;
             header?: never;
@@ -11728,11 +15342,11 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["TopologyEdgeHandleUpdate"];
+                "application/json": components["schemas"]["TopologyRebuildRequest"];
             };
         };
         responses: {
-            /** @description Edge handles updated */
+            /** @description Topology refreshed */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -11750,7 +15364,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Topology or edge not found */
+            /** @description Topology not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -11761,7 +15375,7 @@ export interface operations {
             };
         };
     };
-    lock: {
+    unlock: {
         parameters: {
             query?: never;
             header?: never;
@@ -11773,7 +15387,7 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description Topology locked */
+            /** @description Topology unlocked */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -11802,32 +15416,55 @@ export interface operations {
             };
         };
     };
-    update_metadata: {
+    get_all_users: {
+        parameters: {
+            query?: {
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of users */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["PaginatedApiResponse_User"];
+                };
+            };
+        };
+    };
+    bulk_delete_users: {
         parameters: {
             query?: never;
             header?: never;
-            path: {
-                /** @description Topology ID */
-                id: string;
-            };
+            path?: never;
             cookie?: never;
         };
+        /** @description Array of user IDs to delete */
         requestBody: {
             content: {
-                "application/json": components["schemas"]["TopologyMetadataUpdate"];
+                "application/json": string[];
             };
         };
         responses: {
-            /** @description Metadata updated */
+            /** @description Users deleted successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
-            /** @description Access denied */
+            /** @description Cannot delete users with higher permissions */
             403: {
                 headers: {
                     [name: string]: unknown;
@@ -11836,40 +15473,52 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Topology not found */
-            404: {
+        };
+    };
+    export_Users_csv: {
+        parameters: {
+            query?: {
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description CSV file containing Users */
+            200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "text/csv": unknown;
                 };
             };
         };
     };
-    update_node_position: {
+    get_user_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Topology ID */
+                /** @description User ID */
                 id: string;
             };
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["TopologyNodePositionUpdate"];
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description Node position updated */
+            /** @description User found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiResponse_User"];
                 };
             };
             /** @description Access denied */
@@ -11881,7 +15530,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Topology or node not found */
+            /** @description User not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -11892,32 +15541,32 @@ export interface operations {
             };
         };
     };
-    update_node_resize: {
+    update_user: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Topology ID */
+                /** @description User ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["TopologyNodeResizeUpdate"];
+                "application/json": components["schemas"]["User"];
             };
         };
         responses: {
-            /** @description Node resized */
+            /** @description User updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiResponse_User"];
                 };
             };
-            /** @description Access denied */
+            /** @description Cannot update another user's record */
             403: {
                 headers: {
                     [name: string]: unknown;
@@ -11926,7 +15575,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Topology or node not found */
+            /** @description User not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -11937,23 +15586,19 @@ export interface operations {
             };
         };
     };
-    rebuild: {
+    delete_user: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Topology ID */
+                /** @description User ID */
                 id: string;
             };
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["TopologyRebuildRequest"];
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description Topology rebuilt */
+            /** @description User deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -11962,7 +15607,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Access denied */
+            /** @description Cannot delete user with higher permissions */
             403: {
                 headers: {
                     [name: string]: unknown;
@@ -11971,7 +15616,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Topology not found */
+            /** @description User not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -11980,34 +15625,43 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
+            /** @description Cannot delete the only owner */
+            409: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
         };
     };
-    refresh: {
+    admin_update_user: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Topology ID */
+                /** @description User ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["TopologyRebuildRequest"];
+                "application/json": components["schemas"]["User"];
             };
         };
         responses: {
-            /** @description Topology refreshed */
+            /** @description User updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiResponse_User"];
                 };
             };
-            /** @description Access denied */
+            /** @description Cannot update user with higher permissions */
             403: {
                 headers: {
                     [name: string]: unknown;
@@ -12016,7 +15670,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Topology not found */
+            /** @description User not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -12027,38 +15681,61 @@ export interface operations {
             };
         };
     };
-    unlock: {
+    get_all_webhooks: {
         parameters: {
-            query?: never;
-            header?: never;
-            path: {
-                /** @description Topology ID */
-                id: string;
+            query?: {
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["WebhookOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["WebhookOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
             };
+            header?: never;
+            path?: never;
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Topology unlocked */
+            /** @description List of webhooks */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Topology"];
+                    "application/json": components["schemas"]["PaginatedApiResponse_Webhook"];
                 };
             };
-            /** @description Access denied */
-            403: {
+        };
+    };
+    create_webhook: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["Webhook"];
+            };
+        };
+        responses: {
+            /** @description Webhook created successfully */
+            200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Webhook"];
                 };
             };
-            /** @description Topology not found */
-            404: {
+            /** @description Validation error */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -12066,48 +15743,31 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-        };
-    };
-    get_all_users: {
-        parameters: {
-            query?: {
-                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
-                limit?: number | null;
-                /** @description Number of results to skip. Default: 0. */
-                offset?: number | null;
-            };
-            header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        requestBody?: never;
-        responses: {
-            /** @description List of users */
-            200: {
+            /** @description Webhook name already exists in this organization */
+            409: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["PaginatedApiResponse_User"];
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    bulk_delete_users: {
+    bulk_delete_webhooks: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of user IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description Users deleted successfully */
+            /** @description Webhooks deleted successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -12116,8 +15776,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
-            /** @description Cannot delete users with higher permissions */
-            403: {
+            /** @description Validation error */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -12127,9 +15787,15 @@ export interface operations {
             };
         };
     };
-    export_Users_csv: {
+    export_Webhooks_csv: {
         parameters: {
             query?: {
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["WebhookOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["WebhookOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -12141,7 +15807,7 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description CSV file containing Users */
+            /** @description CSV file containing Webhooks */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -12152,37 +15818,28 @@ export interface operations {
             };
         };
     };
-    get_user_by_id: {
+    get_Webhook_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description User ID */
+                /** @description Webhook ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description User found */
+            /** @description Webhook found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_User"];
-                };
-            };
-            /** @description Access denied */
-            403: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Webhook"];
                 };
             };
-            /** @description User not found */
+            /** @description Webhook not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -12193,33 +15850,33 @@ export interface operations {
             };
         };
     };
-    update_user: {
+    update_webhook: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description User ID */
+                /** @description webhook ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["User"];
+                "application/json": components["schemas"]["Webhook"];
             };
         };
         responses: {
-            /** @description User updated */
+            /** @description webhook updated successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_User"];
+                    "application/json": components["schemas"]["ApiResponse_Webhook"];
                 };
             };
-            /** @description Cannot update another user's record */
-            403: {
+            /** @description Validation error */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -12227,7 +15884,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description User not found */
+            /** @description webhook not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -12238,19 +15895,19 @@ export interface operations {
             };
         };
     };
-    delete_user: {
+    delete_webhook: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description User ID */
+                /** @description webhook ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description User deleted */
+            /** @description webhook deleted successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -12259,8 +15916,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Cannot delete user with higher permissions */
-            403: {
+            /** @description webhook not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -12268,17 +15925,38 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description User not found */
-            404: {
+        };
+    };
+    get_webhook_deliveries: {
+        parameters: {
+            query?: {
+                /** @description Only return deliveries with this status */
+                status?: null | components["schemas"]["WebhookDeliveryStatus"];
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path: {
+                /** @description webhook ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Webhook delivery log */
+            200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["PaginatedApiResponse_WebhookDelivery"];
                 };
             };
-            /** @description Cannot delete the only owner */
-            409: {
+            /** @description webhook not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -12288,41 +15966,28 @@ export interface operations {
             };
         };
     };
-    admin_update_user: {
+    test_webhook: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description User ID */
+                /** @description webhook ID */
                 id: string;
             };
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["User"];
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description User updated */
+            /** @description Test delivery attempted */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_User"];
-                };
-            };
-            /** @description Cannot update user with higher permissions */
-            403: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_WebhookDelivery"];
                 };
             };
-            /** @description User not found */
+            /** @description webhook not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -133,6 +133,16 @@ export const fieldDefs: FieldDef[] = [
 		section: () => m.daemons_config_sectionServerConnection(),
 		showWhen: (values) => values.mode === 'daemon_poll'
 	},
+	{
+		id: 'persistEntityBuffer',
+		label: () => m.daemons_config_persistEntityBuffer(),
+		type: 'boolean',
+		defaultValue: false,
+		cliFlag: '--persist-entity-buffer',
+		envVar: 'SCANOPY_PERSIST_ENTITY_BUFFER',
+		helpText: () => m.daemons_config_persistEntityBufferHelp(),
+		section: () => m.daemons_config_sectionServerConnection()
+	},
 	// Performance section
 	{
 		id: 'logLevel',
@@ -6,7 +6,15 @@ import { createQuery, createMutation, useQueryClient } from '@tanstack/svelte-qu
 import { queryClient, queryKeys } from '$lib/api/query-client';
 import { apiClient } from '$lib/api/client';
 import type { Discovery } from './types/base';
-import type { DiscoveryUpdatePayload } from './types/api';
+import type {
+	CustomScanProfile,
+	DiscoveryUpdatePayload,
+	HostnameSource,
+	PortRange,
+	ScanExclusion,
+	ScanProfileSettings
+} from './types/api';
+import { macOuiRegex, portList } from '$lib/shared/components/forms/validators';
 import { pushError, pushSuccess, pushWarning } from '$lib/shared/stores/feedback';
 import { BaseSSEManager, type SSEConfig } from '$lib/shared/utils/sse';
 import { writable } from 'svelte/store';
@@ -134,6 +142,93 @@ import type { FieldConfig } from '$lib/shared/components/data/types';
 // Utility Functions
 // ============================================================================
 
+/**
+ * Query hook for fetching the organization's custom scan profiles
+ */
+export function useCustomScanProfilesQuery() {
+	return createQuery(() => ({
+		queryKey: queryKeys.customScanProfiles.all,
+		queryFn: async () => {
+			const { data } = await apiClient.GET('/api/v1/custom-scan-profiles', {
+				params: { query: { limit: 0 } }
+			});
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Failed to fetch scan profiles');
+			}
+			return data.data;
+		}
+	}));
+}
+
+/**
+ * Mutation hook for creating a custom scan profile
+ */
+export function useCreateCustomScanProfileMutation() {
+	const queryClient = useQueryClient();
+
+	return createMutation(() => ({
+		mutationFn: async (profile: CustomScanProfile) => {
+			const { data } = await apiClient.POST('/api/v1/custom-scan-profiles', { body: profile });
+			if (!data?.success || !data.data) {
+				throw new Error(data?.error || 'Failed to create scan profile');
+			}
+			return data.data;
+		},
+		onSuccess: (newProfile: CustomScanProfile) => {
+			queryClient.setQueryData<CustomScanProfile[]>(queryKeys.customScanProfiles.all, (old) =>
+				old ? [...old, newProfile] : [newProfile]
+			);
+		}
+	}));
+}
+
+/** Scan profile select value for creating a new custom profile from the discovery form */
+export const NEW_SCAN_PROFILE = 'new';
+
+/**
+ * Build custom scan profile settings from the discovery form's profile fields
+ */
+export function buildScanProfileSettings(values: {
+	scan_profile_tcp_ports: string;
+	scan_profile_known_ports: boolean;
+	scan_profile_probe_endpoints: boolean;
+	scan_profile_host_timeout: number | '';
+}): ScanProfileSettings {
+	const tcpPorts = values.scan_profile_tcp_ports;
+	const hostTimeout = values.scan_profile_host_timeout;
+	return {
+		tcp_ports: {
+			known: values.scan_profile_known_ports,
+			ranges: portList(tcpPorts) ? [] : parsePortRanges(tcpPorts)
+		},
+		udp_ports: { known: true, ranges: [] },
+		probe_endpoints: values.scan_profile_probe_endpoints,
+		host_timeout_secs: typeof hostTimeout === 'number' && hostTimeout > 0 ? hostTimeout : null
+	};
+}
+
+/** Default passive discovery listening window, matching the server's default */
+export const PASSIVE_DEFAULT_LISTEN_SECS = 900;
+
+/** Default hostname resolution order, matching the server's default */
+export const DEFAULT_HOSTNAME_SOURCES: HostnameSource[] = [
+	'Dns',
+	'NetBios',
+	'Smb',
+	'Llmnr',
+	'Mdns',
+	'Snmp'
+];
+
+export const hostnameSourceLabels: Record<HostnameSource, string> = {
+	Dns: 'Reverse DNS',
+	NetBios: 'NetBIOS',
+	Smb: 'SMB',
+	Llmnr: 'LLMNR',
+	Mdns: 'mDNS',
+	Snmp: 'SNMP sysName'
+};
+
 /**
  * Create empty form data for a new discovery
  */
@@ -147,7 +242,10 @@ export function createEmptyDiscoveryFormData(daemon: Daemon | null): Discovery {
 			type: 'Network',
 			subnet_ids: daemon ? daemon.capabilities.interfaced_subnet_ids : [],
 			host_naming_fallback: 'Ip',
-			probe_raw_socket_ports: false
+			probe_raw_socket_ports: false,
+			scan_profile: { type: 'Full' },
+			exclusions: [],
+			hostname_sources: [...DEFAULT_HOSTNAME_SOURCES]
 		},
 		run_type: {
 			type: 'Scheduled',
@@ -218,6 +316,61 @@ export function generateCronSchedule(hours: number): string {
 	return `0 0 */${hours} * * *`;
 }
 
+/**
+ * Parse a comma-separated list of ports and ranges, e.g. "22,80,8000-9000"
+ * Assumes the input passed the portList validator
+ */
+export function parsePortRanges(value: string): PortRange[] {
+	return value
+		.split(',')
+		.map((part) => part.trim())
+		.filter((part) => part.length > 0)
+		.map((part) => {
+			const [start, end = start] = part.split('-');
+			return { start: Number(start), end: Number(end) };
+		});
+}
+
+/**
+ * Format a scan exclusion for display and editing, e.g. "10.0.0.1-10.0.0.9"
+ */
+export function formatScanExclusion(exclusion: ScanExclusion): string {
+	switch (exclusion.type) {
+		case 'Ip':
+			return exclusion.ip;
+		case 'Range':
+			return `${exclusion.start}-${exclusion.end}`;
+		case 'Cidr':
+			return exclusion.cidr;
+		case 'MacOui':
+			return exclusion.oui;
+	}
+}
+
+/**
+ * Format scan exclusions one per line
+ */
+export function formatScanExclusions(exclusions: ScanExclusion[]): string {
+	return exclusions.map(formatScanExclusion).join('\n');
+}
+
+/**
+ * Parse scan exclusions from a newline- or comma-separated list
+ * Assumes the input passed the scanExclusionList validator
+ */
+export function parseScanExclusions(value: string): ScanExclusion[] {
+	return value
+		.split(/[\n,]/)
+		.map((part) => part.trim())
+		.filter((part) => part.length > 0)
+		.map((part): ScanExclusion => {
+			if (macOuiRegex.test(part)) return { type: 'MacOui', oui: part.toUpperCase() };
+			if (part.includes('/')) return { type: 'Cidr', cidr: part };
+			const [start, end] = part.split('-').map((p) => p.trim());
+			return end ? { type: 'Range', start, end } : { type: 'Ip', ip: start };
+		});
+}
+
 /**
  * Field configuration for the DataTableControls
  */
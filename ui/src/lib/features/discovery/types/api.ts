@@ -4,11 +4,22 @@ import type { components } from '$lib/api/schema';
 export type DiscoveryType = components['schemas']['DiscoveryType'];
 export type DiscoveryPhase = components['schemas']['DiscoveryPhase'];
 export type HostNamingFallback = components['schemas']['HostNamingFallback'];
+export type HostnameSource = components['schemas']['HostnameSource'];
+export type ScanProfile = components['schemas']['ScanProfile'];
+export type ScanProfileSettings = components['schemas']['ScanProfileSettings'];
+export type CustomScanProfile = components['schemas']['CustomScanProfile'];
+export type PortRange = components['schemas']['PortRange'];
+export type ScanExclusion = components['schemas']['ScanExclusion'];
+export type ExcludedTarget = components['schemas']['ExcludedTarget'];
+export type ExcludedTargetSummary = components['schemas']['ExcludedTargetSummary'];
 
 // Variant types from DiscoveryType union for type guards
 export type SelfReportDiscovery = Extract<DiscoveryType, { type: 'SelfReport' }>;
 export type NetworkDiscovery = Extract<DiscoveryType, { type: 'Network' }>;
 export type DockerDiscovery = Extract<DiscoveryType, { type: 'Docker' }>;
+export type PassiveDiscovery = Extract<DiscoveryType, { type: 'Passive' }>;
+export type KubernetesDiscovery = Extract<DiscoveryType, { type: 'Kubernetes' }>;
+export type ProxmoxDiscovery = Extract<DiscoveryType, { type: 'Proxmox' }>;
 
 // Frontend-specific types for WebSocket updates (not from backend API schema)
 export interface DiscoveryUpdatePayload {
@@ -20,4 +31,5 @@ export interface DiscoveryUpdatePayload {
 	error?: string | null;
 	started_at?: string | null;
 	finished_at?: string | null;
+	excluded_targets?: ExcludedTargetSummary[];
 }
@@ -28,7 +28,9 @@ import type {
 	ServiceInput,
 	BindingInput,
 	AllInterfaces,
-	IfEntry
+	IfEntry,
+	OsFamily,
+	OsFingerprint
 } from './types/base';
 import type { Service } from '$lib/features/services/types/base';
 import type { components } from '$lib/api/schema';
@@ -569,6 +571,27 @@ export function formatInterface(
 		: (i.name ? i.name + ': ' : '') + i.ip_address;
 }
 
+const osFamilyLabels: Record<OsFamily, string> = {
+	Linux: 'Linux',
+	Windows: 'Windows',
+	MacOs: 'macOS',
+	FreeBsd: 'FreeBSD',
+	OpenBsd: 'OpenBSD',
+	Solaris: 'Solaris',
+	CiscoIos: 'Cisco IOS',
+	JunOs: 'Junos',
+	RouterOs: 'RouterOS',
+	Esxi: 'VMware ESXi'
+};
+
+/**
+ * Format an inferred OS for display, e.g. "Linux Ubuntu 24.04"
+ */
+export function formatOs(os: OsFingerprint): string {
+	const family = osFamilyLabels[os.family];
+	return os.version ? `${family} ${os.version}` : family;
+}
+
 /**
  * Hydrate a Host primitive to HostFormData using TanStack Query cache.
  * Used for form editing where the full form structure is needed.
@@ -595,6 +618,7 @@ export function hydrateHostToFormData(
 		sys_contact: host.sys_contact ?? null,
 		management_url: host.management_url ?? null,
 		chassis_id: host.chassis_id ?? null,
+		hardware_inventory: host.hardware_inventory ?? [],
 		snmp_credential_id: host.snmp_credential_id ?? null
 	};
 }
@@ -634,6 +658,7 @@ export function createEmptyHostFormData(defaultNetworkId?: string): HostFormData
 		sys_contact: null,
 		management_url: null,
 		chassis_id: null,
+		hardware_inventory: [],
 		snmp_credential_id: null,
 		if_entries: []
 	};
@@ -25,6 +25,11 @@ export type BindingInput = components['schemas']['BindingInput'];
 export type IfEntry = components['schemas']['IfEntry'];
 export type IfAdminStatus = components['schemas']['IfAdminStatus'];
 export type IfOperStatus = components['schemas']['IfOperStatus'];
+export type HardwareComponent = components['schemas']['HardwareComponent'];
+
+// OS fingerprint (inferred by discovery, read-only)
+export type OsFingerprint = components['schemas']['OsFingerprint'];
+export type OsFamily = components['schemas']['OsFamily'];
 
 // Form state type for creating/editing hosts
 // Includes children arrays for form editing - distinct from HostResponse (API response type)
@@ -49,6 +54,7 @@ export interface HostFormData {
 	sys_contact: string | null;
 	management_url: string | null;
 	chassis_id: string | null;
+	hardware_inventory: HardwareComponent[];
 
 	// SNMP credential override (user-editable)
 	snmp_credential_id: string | null;
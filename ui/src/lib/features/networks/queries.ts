@@ -144,6 +144,8 @@ export function createEmptyNetworkFormData(): Network {
 		updated_at: utcTimeZoneSentinel,
 		organization_id: uuidv4Sentinel,
 		tags: [],
-		snmp_credential_id: null
+		snmp_credential_id: null,
+		host_liveness: { offline_after_missed_scans: 3, hide_offline_after_days: null },
+		scan_exclusions: []
 	};
 }
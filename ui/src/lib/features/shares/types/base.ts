@@ -30,6 +30,7 @@ export function createEmptyShare(topology_id: string, network_id: string): Share
 		created_by: uuidv4Sentinel,
 		expires_at: null,
 		allowed_domains: null,
+		compare_snapshot_id: null,
 		name: '',
 		is_enabled: true,
 		options: { ...defaultShareOptions }
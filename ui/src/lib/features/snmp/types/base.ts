@@ -10,12 +10,18 @@ import {
 	snmp_operStatusLowerLayerDown,
 	snmp_operStatusNotPresent,
 	snmp_operStatusTesting,
-	snmp_operStatusUp
+	snmp_operStatusUp,
+	snmp_securityLevelAuthNoPriv,
+	snmp_securityLevelAuthPriv,
+	snmp_securityLevelNoAuthNoPriv
 } from '$lib/paraglide/messages';
 
 export type SnmpCredential = components['schemas']['SnmpCredential'];
 export type SnmpCredentialBase = components['schemas']['SnmpCredentialBase'];
 export type SnmpVersion = components['schemas']['SnmpVersion'];
+export type SnmpSecurityLevel = components['schemas']['SnmpSecurityLevel'];
+export type SnmpAuthProtocol = components['schemas']['SnmpAuthProtocol'];
+export type SnmpPrivProtocol = components['schemas']['SnmpPrivProtocol'];
 export type IfEntry = components['schemas']['IfEntry'];
 export type IfAdminStatus = components['schemas']['IfAdminStatus'];
 export type IfOperStatus = components['schemas']['IfOperStatus'];
@@ -25,6 +31,12 @@ export function createDefaultSnmpCredential(organization_id: string): SnmpCreden
 		name: '',
 		version: 'V2c',
 		community: '',
+		username: null,
+		security_level: 'AuthPriv',
+		auth_protocol: 'Sha',
+		auth_passphrase: null,
+		priv_protocol: 'Aes',
+		priv_passphrase: null,
 		organization_id,
 		tags: [],
 		id: uuidv4Sentinel,
@@ -33,6 +45,43 @@ export function createDefaultSnmpCredential(organization_id: string): SnmpCreden
 	};
 }
 
+/**
+ * Get human-readable labels for SNMPv3 security levels
+ */
+export function getSecurityLevelLabels(): Record<SnmpSecurityLevel, string> {
+	return {
+		NoAuthNoPriv: snmp_securityLevelNoAuthNoPriv(),
+		AuthNoPriv: snmp_securityLevelAuthNoPriv(),
+		AuthPriv: snmp_securityLevelAuthPriv()
+	};
+}
+
+/**
+ * SNMPv3 authentication protocol options (protocol names are not translated)
+ */
+export function getAuthProtocolOptions(): { value: SnmpAuthProtocol; label: string }[] {
+	return [
+		{ value: 'Md5', label: 'MD5' },
+		{ value: 'Sha', label: 'SHA-1' },
+		{ value: 'Sha224', label: 'SHA-224' },
+		{ value: 'Sha256', label: 'SHA-256' },
+		{ value: 'Sha384', label: 'SHA-384' },
+		{ value: 'Sha512', label: 'SHA-512' }
+	];
+}
+
+/**
+ * SNMPv3 privacy protocol options (protocol names are not translated)
+ */
+export function getPrivProtocolOptions(): { value: SnmpPrivProtocol; label: string }[] {
+	return [
+		{ value: 'Des', label: 'DES' },
+		{ value: 'Aes', label: 'AES-128' },
+		{ value: 'Aes192', label: 'AES-192' },
+		{ value: 'Aes256', label: 'AES-256' }
+	];
+}
+
 /**
  * Get human-readable labels for SNMP admin status
  */
@@ -40,8 +40,9 @@ interface TagFilter {
 
 /**
  * Update hidden nodes/services based on tag filter settings.
- * - Hosts with hidden tags -> their InterfaceNodes fade out
- * - Services with hidden tags -> hidden from node display (node does NOT fade)
+ * - Hosts with hidden tags -> their InterfaceNodes (or DeviceNode) fade out
+ * - Services with hidden tags -> hidden from node display (node does NOT fade), except
+ *   ServiceNodes in the dependency view, which fade out
  * - Subnets with hidden tags -> SubnetNodes fade out
  * - UNTAGGED_SENTINEL in hidden arrays -> hide entities with no tags
  */
@@ -69,7 +70,7 @@ export function updateTagFilter(topology: Topology | undefined, tagFilter: TagFi
 	const hiddenNodeIds = new Set<string>();
 	const hiddenServiceIds = new Set<string>();
 
-	// Host tags -> fade InterfaceNodes
+	// Host tags -> fade InterfaceNodes, and the host's DeviceNode in the physical view
 	for (const host of topology.hosts) {
 		const isUntagged = host.tags.length === 0;
 		const hostHasHiddenTag = host.tags.some((t) => hiddenHostTagIds.includes(t));
@@ -77,6 +78,8 @@ export function updateTagFilter(topology: Topology | undefined, tagFilter: TagFi
 			// Add all InterfaceNodes for this host to hidden set
 			const hostInterfaces = topology.interfaces.filter((i) => i.host_id === host.id);
 			hostInterfaces.forEach((i) => hiddenNodeIds.add(i.id));
+			// DeviceNodes use the host ID as their node ID
+			hiddenNodeIds.add(host.id);
 		}
 	}
 
@@ -86,6 +89,8 @@ export function updateTagFilter(topology: Topology | undefined, tagFilter: TagFi
 		const serviceHasHiddenTag = service.tags.some((t) => hiddenServiceTagIds.includes(t));
 		if (serviceHasHiddenTag || (isUntagged && hideUntaggedServices)) {
 			hiddenServiceIds.add(service.id);
+			// ServiceNodes use the service ID as their node ID
+			hiddenNodeIds.add(service.id);
 		}
 	}
 
@@ -206,6 +211,13 @@ export function updateConnectedNodes(
 					connected.add(nd.id);
 				}
 			});
+		} else if (nodeData.node_type == 'SiteNode') {
+			allNodes.forEach((n) => {
+				const nd = n.data as TopologyNode;
+				if (nd.node_type == 'DeviceNode' && nd.site_id == nodeData.id) {
+					connected.add(nd.id);
+				}
+			});
 		}
 
 		for (const edge of allEdges) {
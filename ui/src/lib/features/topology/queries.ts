@@ -27,6 +27,7 @@ export const defaultTopologyOptions: TopologyOptions = {
 		}
 	},
 	request: {
+		view: 'Subnet',
 		group_docker_bridges_by_host: true,
 		hide_ports: false,
 		hide_vm_title_on_docker_container: false,
@@ -175,7 +176,8 @@ export function useRebuildTopologyMutation() {
 				params: { path: { id: topology.id } },
 				body: {
 					network_id: topology.network_id,
-					options: topology.options,
+					// Build with the options panel's current request options (e.g. a changed layout)
+					options: { ...topology.options, request: get(topologyOptions).request },
 					nodes: topology.nodes,
 					edges: topology.edges
 				}
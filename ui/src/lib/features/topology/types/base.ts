@@ -12,10 +12,15 @@ export type TopologyRequestOptions = components['schemas']['TopologyRequestOptio
 export type TopologyEdge = components['schemas']['Edge'];
 export type TopologyNode = components['schemas']['Node'];
 export type EdgeHandle = components['schemas']['EdgeHandle'];
+export type TopologyView = components['schemas']['TopologyView'];
+export type DevicePort = components['schemas']['DevicePort'];
 
 // Variant types from Node union
 export type InterfaceNode = Extract<TopologyNode, { node_type: 'InterfaceNode' }>;
 export type SubnetNode = Extract<TopologyNode, { node_type: 'SubnetNode' }>;
+export type SiteNode = Extract<TopologyNode, { node_type: 'SiteNode' }>;
+export type DeviceNode = Extract<TopologyNode, { node_type: 'DeviceNode' }>;
+export type ServiceNode = Extract<TopologyNode, { node_type: 'ServiceNode' }>;
 
 // Frontend-specific render types (not from backend)
 export interface NodeRenderData {
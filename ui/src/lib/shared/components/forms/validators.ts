@@ -99,6 +99,48 @@ export function port(value: FormValue): string | undefined {
 /** Port range validation (alias for port) */
 export const portRangeValidation = port;
 
+/** Comma-separated list of ports and ranges validator (e.g., 22,80,8000-9000) */
+export function portList(value: FormValue): string | undefined {
+	if (!value || typeof value !== 'string') return undefined;
+	const valid = value.split(',').every((part) => {
+		const [start, end = start] = part.trim().split('-');
+		const startNum = Number(start);
+		const endNum = Number(end);
+		return (
+			/^\d+(-\d+)?$/.test(part.trim()) && startNum >= 1 && endNum <= 65535 && startNum <= endNum
+		);
+	});
+	return !valid ? 'Use comma-separated ports or ranges (e.g., 22,80,8000-9000)' : undefined;
+}
+
+/** MAC vendor prefix, e.g. 00:1A:2B, 00-1A-2B or 001A2B */
+export const macOuiRegex = /^[0-9A-Fa-f]{2}([:-]?)[0-9A-Fa-f]{2}\1[0-9A-Fa-f]{2}$/;
+
+/** Scan exclusion list validator: one IP, IP range, CIDR or MAC OUI per line */
+export function scanExclusionList(value: FormValue): string | undefined {
+	if (!value || typeof value !== 'string') return undefined;
+	const invalid = value
+		.split(/[\n,]/)
+		.map((part) => part.trim())
+		.filter((part) => part.length > 0)
+		.find((part) => {
+			if (macOuiRegex.test(part)) return false;
+			if (part.includes('/')) return !isValidCIDR(part);
+			const [start, end = start] = part.split('-').map((p) => p.trim());
+			if (!isValid(start) || !isValid(end)) return true;
+			const startAddr = parse(start);
+			const endAddr = parse(end);
+			if (startAddr.kind() !== endAddr.kind()) return true;
+			const startBytes = startAddr.toByteArray();
+			const endBytes = endAddr.toByteArray();
+			const firstDiff = startBytes.findIndex((b, i) => b !== endBytes[i]);
+			return firstDiff !== -1 && startBytes[firstDiff] > endBytes[firstDiff];
+		});
+	return invalid
+		? `Invalid exclusion '${invalid}' - use an IP, range (10.0.0.1-10.0.0.9), CIDR or MAC OUI (00:1A:2B)`
+		: undefined;
+}
+
 /** URL format validator */
 export function url(value: FormValue): string | undefined {
 	if (!value || typeof value !== 'string') return undefined;
@@ -141,6 +183,17 @@ export function numeric(value: FormValue): string | undefined {
 	return isNaN(Number(value)) ? 'Must be a number' : undefined;
 }
 
+/** Whole number within an inclusive range */
+export function integerRange(minValue: number, maxValue: number): Validator {
+	return (value: FormValue) => {
+		if (value === '' || value === null || value === undefined) return undefined;
+		const n = Number(value);
+		return !Number.isInteger(n) || n < minValue || n > maxValue
+			? `Must be a whole number between ${minValue} and ${maxValue}`
+			: undefined;
+	};
+}
+
 /** Password complexity validator */
 export function password(value: FormValue): string | undefined {
 	if (!value || typeof value !== 'string') return undefined;
@@ -320,3 +320,19 @@ export async function getMetadata() {
 		metadata.set(data.data as MetadataRegistry);
 	}
 }
+
+/**
+ * Merge the organization's custom service definitions into the registry.
+ * Served separately because /api/metadata is shared across organizations.
+ */
+export async function getCustomServiceDefinitionMetadata() {
+	const { data } = await apiClient.GET('/api/v1/custom-service-definitions/metadata', {});
+	if (data?.success && data.data) {
+		const custom = data.data;
+		metadata.update(($registry) => {
+			if (!$registry) return $registry;
+			const builtin = $registry.service_definitions.filter((d) => !d.id.startsWith('Custom:'));
+			return { ...$registry, service_definitions: [...builtin, ...custom] };
+		});
+	}
+}